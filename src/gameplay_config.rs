@@ -0,0 +1,842 @@
+//! Gameplay tuning that is not physics: powerup drop tables, difficulty, remix mode, paddle size
+//! stacking, the survival mode pace, the paddle energy meter, assist mode, reduced motion, paddle english, and related knobs.
+//! Per-type brick surfaces refine `BrickPhysicsConfig` (see `systems::brick_surfaces`).
+//!
+//! # Usage
+//! [`GameplayConfig`] is registered as a Bevy resource. On native builds it is read once at startup
+//! from `config/gameplay.ron` when that file exists; otherwise the source defaults apply.
+//! Levels may override individual tables (see `LevelDefinition::drops`).
+//!
+//! # Validation
+//! Call `validate()` after loading; invalid files are rejected with a warning and the defaults are
+//! kept, mirroring how the physics configs guard against bad values.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::systems::powerups::PowerupKind;
+
+/// Native path of the optional gameplay config file.
+pub const GAMEPLAY_CONFIG_PATH: &str = "config/gameplay.ron";
+
+/// Upper bound for a drop chance; 1.0 means every eligible brick drops a capsule.
+pub const MAX_DROP_CHANCE: f32 = 1.0;
+
+/// One weighted entry in a [`DropTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DropEntry {
+    pub kind: PowerupKind,
+    /// Relative weight; entries with weight 0 never drop.
+    pub weight: u32,
+}
+
+/// Chance that a destroyed brick releases a capsule, and which capsule it releases.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DropTable {
+    /// Probability in `[0, 1]` that a destructible brick drops anything at all.
+    pub chance: f32,
+    /// Weighted list of capsules to choose from once a drop happens.
+    #[serde(default)]
+    pub entries: Vec<DropEntry>,
+}
+
+impl DropTable {
+    /// A table that never drops anything.
+    pub fn disabled() -> Self {
+        Self {
+            chance: 0.0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sum of all entry weights.
+    pub fn total_weight(&self) -> u32 {
+        self.entries.iter().map(|e| e.weight).sum()
+    }
+
+    /// Pick a capsule kind from `roll`, a value in `[0, total_weight)`.
+    ///
+    /// Returns `None` for an empty table or an out-of-range roll.
+    pub fn pick(&self, roll: u32) -> Option<PowerupKind> {
+        let mut acc = 0;
+        for entry in &self.entries {
+            acc += entry.weight;
+            if roll < acc {
+                return Some(entry.kind);
+            }
+        }
+        None
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.chance.is_finite() || self.chance < 0.0 || self.chance > MAX_DROP_CHANCE {
+            return Err(format!("Drop chance out of bounds: {}", self.chance));
+        }
+        if self.chance > 0.0 && self.total_weight() == 0 {
+            return Err("Drop table has a non-zero chance but no weighted entries".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl Default for DropTable {
+    fn default() -> Self {
+        Self {
+            chance: 0.1,
+            entries: vec![
+                DropEntry {
+                    kind: PowerupKind::EnlargePaddle,
+                    weight: 6,
+                },
+                DropEntry {
+                    kind: PowerupKind::ExtraLife,
+                    weight: 1,
+                },
+                DropEntry {
+                    kind: PowerupKind::ShrinkPaddle,
+                    weight: 3,
+                },
+                DropEntry {
+                    kind: PowerupKind::ReverseControls,
+                    weight: 2,
+                },
+                DropEntry {
+                    kind: PowerupKind::SpeedUpBall,
+                    weight: 2,
+                },
+                DropEntry {
+                    kind: PowerupKind::GrowBall,
+                    weight: 2,
+                },
+                DropEntry {
+                    kind: PowerupKind::ShrinkBall,
+                    weight: 2,
+                },
+                DropEntry {
+                    kind: PowerupKind::WreckingBall,
+                    weight: 1,
+                },
+            ],
+        }
+    }
+}
+
+/// Overall difficulty setting; selects per-difficulty values elsewhere in the config.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+/// Largest allowed minimum bounce angle; anything steeper would distort normal play.
+pub const MAX_MIN_BOUNCE_ANGLE_DEG: f32 = 45.0;
+
+/// Minimum angle (degrees) between the ball's path and a wall after it bounces off that wall.
+///
+/// Shallower bounces are rotated up to this angle so the ball cannot end up travelling (almost)
+/// parallel to a wall. `0` disables the guard for that difficulty.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AngleGuardConfig {
+    pub easy: f32,
+    pub normal: f32,
+    pub hard: f32,
+}
+
+impl AngleGuardConfig {
+    /// Minimum bounce angle in degrees for `difficulty`.
+    pub fn min_angle_deg(&self, difficulty: Difficulty) -> f32 {
+        match difficulty {
+            Difficulty::Easy => self.easy,
+            Difficulty::Normal => self.normal,
+            Difficulty::Hard => self.hard,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, angle) in [
+            ("easy", self.easy),
+            ("normal", self.normal),
+            ("hard", self.hard),
+        ] {
+            if !angle.is_finite() || !(0.0..=MAX_MIN_BOUNCE_ANGLE_DEG).contains(&angle) {
+                return Err(format!(
+                    "Minimum bounce angle ({}) out of bounds: {}",
+                    name, angle
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for AngleGuardConfig {
+    fn default() -> Self {
+        // Easier settings keep the ball away from long, slow wall-hugging rallies
+        Self {
+            easy: 20.0,
+            normal: 15.0,
+            hard: 10.0,
+        }
+    }
+}
+
+/// Brick types a remix may substitute for simple bricks: multi-hit bricks (10-13), the paddle
+/// size bricks (30, 32) and the extra life brick (41).
+pub fn is_remix_brick(type_id: u8) -> bool {
+    crate::level_format::is_multi_hit_brick(type_id)
+        || matches!(
+            type_id,
+            crate::systems::paddle_size::BRICK_TYPE_30
+                | crate::systems::paddle_size::BRICK_TYPE_32
+                | crate::level_format::EXTRA_LIFE_BRICK
+        )
+}
+
+/// "Remix" mode: replays existing levels with a share of their simple bricks swapped for
+/// multi-hit or powerup bricks (see `systems::remix`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemixConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Share of simple bricks replaced, in `[0, 1]`.
+    #[serde(default = "default_remix_fraction")]
+    pub fraction: f32,
+    /// Seed of the substitutions; the gameplay seed (`GameRng`) when omitted.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Brick types to substitute, picked uniformly (see [`is_remix_brick`]).
+    #[serde(default = "default_remix_bricks")]
+    pub bricks: Vec<u8>,
+}
+
+fn default_remix_fraction() -> f32 {
+    0.2
+}
+
+fn default_remix_bricks() -> Vec<u8> {
+    vec![10, 11, 12, 30, 32]
+}
+
+impl Default for RemixConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fraction: default_remix_fraction(),
+            seed: None,
+            bricks: default_remix_bricks(),
+        }
+    }
+}
+
+impl RemixConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.fraction.is_finite() || !(0.0..=1.0).contains(&self.fraction) {
+            return Err(format!("Remix fraction out of [0, 1]: {}", self.fraction));
+        }
+        if self.bricks.is_empty() {
+            return Err("Remix brick list is empty".to_string());
+        }
+        match self.bricks.iter().find(|&&id| !is_remix_brick(id)) {
+            Some(id) => Err(format!("Brick type {} cannot be used in a remix", id)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// What catching a paddle size effect does while the same effect is still running.
+///
+/// An opposite effect (shrink while enlarged, or the reverse) always replaces the running one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SizeStackPolicy {
+    /// Restart the effect's timer at its full duration
+    #[default]
+    Refresh,
+    /// Add a full duration to the time left, up to `systems::paddle_size::MAX_EFFECT_DURATION`
+    Extend,
+    /// Apply the size change once more, up to `max_stacks` times, and restart the timer
+    Stack,
+}
+
+/// Stacking of the paddle size effects (see `systems::paddle_size`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PaddleSizeConfig {
+    #[serde(default)]
+    pub policy: SizeStackPolicy,
+    /// Most times an effect can stack under [`SizeStackPolicy::Stack`].
+    #[serde(default = "default_max_stacks")]
+    pub max_stacks: u32,
+}
+
+fn default_max_stacks() -> u32 {
+    3
+}
+
+impl Default for PaddleSizeConfig {
+    fn default() -> Self {
+        Self {
+            policy: SizeStackPolicy::default(),
+            max_stacks: default_max_stacks(),
+        }
+    }
+}
+
+impl PaddleSizeConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_stacks == 0 {
+            return Err("Paddle size max_stacks must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Pace of the survival mode (see `systems::survival`): every `stage_secs` of play the run reaches
+/// the next stage, making the balls faster and the brick waves more frequent.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SurvivalConfig {
+    /// Length of a stage in seconds of level clock time.
+    pub stage_secs: f32,
+    /// Minimum speed of a moving ball in the first stage, in units per second.
+    pub ball_speed: f32,
+    /// Factor applied to the minimum ball speed at every stage.
+    pub ball_speed_step: f32,
+    /// Highest minimum ball speed, in units per second.
+    pub max_ball_speed: f32,
+    /// Seconds between brick waves in the first stage.
+    pub wave_interval_secs: f32,
+    /// Factor applied to the wave interval at every stage.
+    pub wave_interval_step: f32,
+    /// Shortest wave interval in seconds.
+    pub min_wave_interval_secs: f32,
+}
+
+impl Default for SurvivalConfig {
+    fn default() -> Self {
+        Self {
+            stage_secs: 30.0,
+            ball_speed: 8.0,
+            ball_speed_step: 1.1,
+            max_ball_speed: 20.0,
+            wave_interval_secs: 15.0,
+            wave_interval_step: 0.8,
+            min_wave_interval_secs: 3.0,
+        }
+    }
+}
+
+impl SurvivalConfig {
+    /// Stage reached after `elapsed_secs` of play, starting at 0.
+    pub fn stage_at(&self, elapsed_secs: f32) -> u32 {
+        (elapsed_secs / self.stage_secs).floor() as u32
+    }
+
+    /// Minimum speed of a moving ball in `stage`.
+    pub fn ball_speed_at(&self, stage: u32) -> f32 {
+        (self.ball_speed * self.ball_speed_step.powi(stage as i32)).min(self.max_ball_speed)
+    }
+
+    /// Seconds between brick waves in `stage`.
+    pub fn wave_interval_at(&self, stage: u32) -> f32 {
+        (self.wave_interval_secs * self.wave_interval_step.powi(stage as i32))
+            .max(self.min_wave_interval_secs)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("stage_secs", self.stage_secs),
+            ("ball_speed", self.ball_speed),
+            ("max_ball_speed", self.max_ball_speed),
+            ("min_wave_interval_secs", self.min_wave_interval_secs),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(format!("Survival {} must be positive: {}", name, value));
+            }
+        }
+        if !self.ball_speed_step.is_finite() || self.ball_speed_step < 1.0 {
+            return Err(format!(
+                "Survival ball_speed_step must be at least 1: {}",
+                self.ball_speed_step
+            ));
+        }
+        if !(self.wave_interval_step > 0.0 && self.wave_interval_step <= 1.0) {
+            return Err(format!(
+                "Survival wave_interval_step out of (0, 1]: {}",
+                self.wave_interval_step
+            ));
+        }
+        if !self.wave_interval_secs.is_finite()
+            || self.wave_interval_secs < self.min_wave_interval_secs
+        {
+            return Err(format!(
+                "Survival wave_interval_secs below min_wave_interval_secs: {}",
+                self.wave_interval_secs
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Paddle energy meter (see `systems::energy`): brick hits charge it, and the charge is spent on
+/// a ball-save shield or a paddle dash.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EnergyConfig {
+    /// Full meter.
+    pub max: f32,
+    /// Energy gained per brick hit.
+    pub per_hit: f32,
+    /// Cost of a ball-save shield.
+    pub shield_cost: f32,
+    /// Cost of a paddle dash.
+    pub dash_cost: f32,
+    /// Paddle speed during a dash, in units per second.
+    pub dash_speed: f32,
+    /// Length of a dash in seconds.
+    pub dash_secs: f32,
+}
+
+impl Default for EnergyConfig {
+    fn default() -> Self {
+        Self {
+            max: 100.0,
+            per_hit: 5.0,
+            shield_cost: 60.0,
+            dash_cost: 25.0,
+            dash_speed: 40.0,
+            dash_secs: 0.15,
+        }
+    }
+}
+
+impl EnergyConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("max", self.max),
+            ("dash_speed", self.dash_speed),
+            ("dash_secs", self.dash_secs),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(format!("Energy {} must be positive: {}", name, value));
+            }
+        }
+        for (name, value) in [
+            ("per_hit", self.per_hit),
+            ("shield_cost", self.shield_cost),
+            ("dash_cost", self.dash_cost),
+        ] {
+            if !(0.0..=self.max).contains(&value) {
+                return Err(format!("Energy {} out of [0, max]: {}", name, value));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How strongly the paddle's movement steers the ball on contact ("english").
+///
+/// Scales the paddle surface's `spin_transfer` (`PaddlePhysicsConfig`); switchable from the pause
+/// menu.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EnglishLevel {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl EnglishLevel {
+    /// The level after this one in the pause menu.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Low => Self::Normal,
+            Self::Normal => Self::High,
+            Self::High => Self::Low,
+        }
+    }
+
+    /// Factor applied to the spin transfer.
+    pub fn factor(self) -> f32 {
+        match self {
+            Self::Low => 0.5,
+            Self::Normal => 1.0,
+            Self::High => 2.0,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Normal => "Normal",
+            Self::High => "High",
+        }
+    }
+}
+
+/// Largest configurable blast radius, in world units.
+pub const MAX_BLAST_RADIUS: f32 = 15.0;
+
+/// Blast of the blast brick (index 50, see `systems::explosion`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BlastConfig {
+    /// Bricks whose centre lies within this distance of the exploding brick's centre are
+    /// destroyed, in world units (a grid cell is 1.5 x 2.0).
+    pub radius: f32,
+}
+
+impl Default for BlastConfig {
+    fn default() -> Self {
+        Self { radius: 4.0 }
+    }
+}
+
+impl BlastConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.radius.is_finite() || self.radius <= 0.0 || self.radius > MAX_BLAST_RADIUS {
+            return Err(format!("Blast radius out of bounds: {}", self.radius));
+        }
+        Ok(())
+    }
+}
+
+/// Slowest game speed, in percent.
+pub const MIN_GAME_SPEED_PERCENT: u32 = 80;
+/// Fastest game speed, in percent.
+pub const MAX_GAME_SPEED_PERCENT: u32 = 120;
+/// Step between the game speeds offered in the pause menu, in percent.
+pub const GAME_SPEED_STEP_PERCENT: u32 = 10;
+
+/// Casual game speed, in percent of normal (see `systems::sim_speed`).
+///
+/// Scales the virtual clock and the physics step together, so the whole game slows down or
+/// speeds up without touching individual tuning constants. Ignored in ranked modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GameSpeed(pub u32);
+
+impl Default for GameSpeed {
+    fn default() -> Self {
+        Self(100)
+    }
+}
+
+impl GameSpeed {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(MIN_GAME_SPEED_PERCENT..=MAX_GAME_SPEED_PERCENT).contains(&self.0) {
+            return Err(format!(
+                "Game speed must be between {}% and {}%: {}%",
+                MIN_GAME_SPEED_PERCENT, MAX_GAME_SPEED_PERCENT, self.0
+            ));
+        }
+        Ok(())
+    }
+
+    /// The speed as a multiplier.
+    pub fn factor(&self) -> f32 {
+        self.0 as f32 / 100.0
+    }
+
+    /// The speed after this one in the pause menu, wrapping from the fastest to the slowest.
+    pub fn next(self) -> Self {
+        let next = self.0 + GAME_SPEED_STEP_PERCENT;
+        if next > MAX_GAME_SPEED_PERCENT {
+            Self(MIN_GAME_SPEED_PERCENT)
+        } else {
+            Self(next)
+        }
+    }
+
+    pub fn label(self) -> String {
+        format!("{}%", self.0)
+    }
+}
+
+/// Cheat-mode level switching (see `systems::level_switch`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelSwitchConfig {
+    /// Switching past the last level goes to the first, and back from the first to the last.
+    pub wrap: bool,
+}
+
+impl Default for LevelSwitchConfig {
+    fn default() -> Self {
+        Self { wrap: true }
+    }
+}
+
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameplayConfig {
+    /// Global drop table used when the current level does not define its own.
+    #[serde(default)]
+    pub drops: DropTable,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Minimum bounce angles off the walls, per difficulty.
+    #[serde(default)]
+    pub angle_guard: AngleGuardConfig,
+    /// Show the per-level clock in the HUD.
+    #[serde(default)]
+    pub show_level_clock: bool,
+    /// Randomly substitute simple bricks at load time.
+    #[serde(default)]
+    pub remix: RemixConfig,
+    /// How repeated paddle size effects combine.
+    #[serde(default)]
+    pub paddle_size: PaddleSizeConfig,
+    /// Pace of the survival mode.
+    #[serde(default)]
+    pub survival: SurvivalConfig,
+    /// Charge and costs of the paddle energy meter.
+    #[serde(default)]
+    pub energy: EnergyConfig,
+    /// Accessibility assist: the first ball lost on every level bounces back off an emergency
+    /// barrier instead of costing a life.
+    #[serde(default)]
+    pub assist: bool,
+    /// Do not open the first run with the tutorial levels (`systems::tutorial`).
+    #[serde(default)]
+    pub skip_tutorial: bool,
+    /// Accessibility: no camera shake or FOV kick on impacts.
+    #[serde(default)]
+    pub reduced_motion: bool,
+    /// Assist: how strongly paddle movement steers the ball.
+    #[serde(default)]
+    pub english: EnglishLevel,
+    /// Cheat-mode level switching.
+    #[serde(default)]
+    pub level_switch: LevelSwitchConfig,
+    /// Reach of the blast brick's explosion.
+    #[serde(default)]
+    pub blast: BlastConfig,
+    /// Casual game speed, 80 to 120 percent; not applied in survival runs.
+    #[serde(default)]
+    pub game_speed: GameSpeed,
+    /// Restitution and friction by brick type index, over `BrickPhysicsConfig`.
+    #[serde(default)]
+    pub brick_surfaces:
+        std::collections::BTreeMap<u8, crate::systems::brick_surfaces::BrickSurface>,
+}
+
+impl GameplayConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        self.drops.validate()?;
+        self.angle_guard.validate()?;
+        self.remix.validate()?;
+        self.paddle_size.validate()?;
+        self.survival.validate()?;
+        self.energy.validate()?;
+        self.blast.validate()?;
+        self.game_speed.validate()?;
+        for (type_id, surface) in &self.brick_surfaces {
+            surface
+                .validate()
+                .map_err(|e| format!("Surface of brick type {}: {}", type_id, e))?;
+        }
+        Ok(())
+    }
+
+    /// Minimum bounce angle in degrees for the configured difficulty.
+    pub fn min_bounce_angle_deg(&self) -> f32 {
+        self.angle_guard.min_angle_deg(self.difficulty)
+    }
+}
+
+/// Load `config/gameplay.ron` (native only) into the [`GameplayConfig`] resource.
+pub fn load_gameplay_config(mut commands: Commands) {
+    #[cfg(not(target_arch = "wasm32"))]
+    let config = match std::fs::read_to_string(GAMEPLAY_CONFIG_PATH) {
+        Ok(content) => match ron::de::from_str::<GameplayConfig>(&content) {
+            Ok(loaded) => match loaded.validate() {
+                Ok(()) => {
+                    info!("Loaded gameplay config from {}", GAMEPLAY_CONFIG_PATH);
+                    loaded
+                }
+                Err(e) => {
+                    warn!("Invalid gameplay config ({}), using defaults", e);
+                    GameplayConfig::default()
+                }
+            },
+            Err(e) => {
+                warn!("Failed to parse gameplay config: {}; using defaults", e);
+                GameplayConfig::default()
+            }
+        },
+        Err(_) => GameplayConfig::default(),
+    };
+
+    #[cfg(target_arch = "wasm32")]
+    let config = GameplayConfig::default();
+
+    commands.insert_resource(config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(GameplayConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn pick_respects_weights() {
+        let table = DropTable::default();
+        assert_eq!(table.total_weight(), 19);
+        assert_eq!(table.pick(0), Some(PowerupKind::EnlargePaddle));
+        assert_eq!(table.pick(5), Some(PowerupKind::EnlargePaddle));
+        assert_eq!(table.pick(6), Some(PowerupKind::ExtraLife));
+        assert_eq!(table.pick(7), Some(PowerupKind::ShrinkPaddle));
+        assert_eq!(table.pick(13), Some(PowerupKind::SpeedUpBall));
+        assert_eq!(table.pick(14), Some(PowerupKind::GrowBall));
+        assert_eq!(table.pick(17), Some(PowerupKind::ShrinkBall));
+        assert_eq!(table.pick(18), Some(PowerupKind::WreckingBall));
+        assert_eq!(table.pick(19), None);
+    }
+
+    #[test]
+    fn validate_rejects_bad_tables() {
+        let table = DropTable {
+            chance: 1.5,
+            ..DropTable::default()
+        };
+        assert!(table.validate().is_err());
+
+        let table = DropTable {
+            chance: 0.5,
+            entries: Vec::new(),
+        };
+        assert!(table.validate().is_err());
+
+        assert!(DropTable::disabled().validate().is_ok());
+    }
+
+    #[test]
+    fn parses_from_ron() {
+        let src = "(drops: (chance: 0.25, entries: [(kind: ExtraLife, weight: 3)]))";
+        let config: GameplayConfig = ron::de::from_str(src).unwrap();
+        assert_eq!(config.drops.chance, 0.25);
+        assert_eq!(config.drops.pick(2), Some(PowerupKind::ExtraLife));
+    }
+
+    #[test]
+    fn min_bounce_angle_follows_difficulty() {
+        let src = "(difficulty: Hard, angle_guard: (easy: 25.0, normal: 15.0, hard: 5.0))";
+        let config: GameplayConfig = ron::de::from_str(src).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.min_bounce_angle_deg(), 5.0);
+        assert_eq!(GameplayConfig::default().difficulty, Difficulty::Normal);
+
+        let steep = AngleGuardConfig {
+            hard: 60.0,
+            ..AngleGuardConfig::default()
+        };
+        assert!(steep.validate().is_err());
+    }
+
+    #[test]
+    fn remix_rejects_structural_bricks() {
+        let src = "(remix: (enabled: true, fraction: 0.5))";
+        let config: GameplayConfig = ron::de::from_str(src).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.remix.bricks, RemixConfig::default().bricks);
+
+        let indestructible = RemixConfig {
+            bricks: vec![10, 90],
+            ..RemixConfig::default()
+        };
+        assert!(indestructible.validate().is_err());
+        let too_much = RemixConfig {
+            fraction: 1.5,
+            ..RemixConfig::default()
+        };
+        assert!(too_much.validate().is_err());
+    }
+
+    #[test]
+    fn paddle_size_policy_parses_from_ron() {
+        let config: GameplayConfig = ron::de::from_str("(paddle_size: (policy: Stack))").unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.paddle_size.policy, SizeStackPolicy::Stack);
+        assert_eq!(config.paddle_size.max_stacks, 3);
+        assert_eq!(
+            GameplayConfig::default().paddle_size.policy,
+            SizeStackPolicy::Refresh
+        );
+
+        let no_stacks = PaddleSizeConfig {
+            policy: SizeStackPolicy::Stack,
+            max_stacks: 0,
+        };
+        assert!(no_stacks.validate().is_err());
+    }
+
+    #[test]
+    fn survival_speeds_up_every_stage() {
+        let survival = SurvivalConfig::default();
+        assert!(survival.validate().is_ok());
+        assert_eq!(survival.stage_at(29.9), 0);
+        assert_eq!(survival.stage_at(30.0), 1);
+        assert_eq!(survival.ball_speed_at(0), 8.0);
+        assert!(survival.ball_speed_at(1) > survival.ball_speed_at(0));
+        assert_eq!(survival.ball_speed_at(50), survival.max_ball_speed);
+        assert_eq!(survival.wave_interval_at(0), 15.0);
+        assert!(survival.wave_interval_at(1) < 15.0);
+        assert_eq!(
+            survival.wave_interval_at(50),
+            survival.min_wave_interval_secs
+        );
+
+        let slowing = SurvivalConfig {
+            ball_speed_step: 0.9,
+            ..SurvivalConfig::default()
+        };
+        assert!(slowing.validate().is_err());
+        let endless = SurvivalConfig {
+            stage_secs: 0.0,
+            ..SurvivalConfig::default()
+        };
+        assert!(endless.validate().is_err());
+    }
+
+    #[test]
+    fn energy_costs_must_fit_the_meter() {
+        assert!(EnergyConfig::default().validate().is_ok());
+        let unaffordable = EnergyConfig {
+            shield_cost: 150.0,
+            ..EnergyConfig::default()
+        };
+        assert!(unaffordable.validate().is_err());
+        let draining = EnergyConfig {
+            per_hit: -1.0,
+            ..EnergyConfig::default()
+        };
+        assert!(draining.validate().is_err());
+    }
+
+    #[test]
+    fn blast_radius_is_bounded() {
+        assert!(BlastConfig::default().validate().is_ok());
+        for radius in [0.0, -2.0, MAX_BLAST_RADIUS + 1.0, f32::NAN] {
+            assert!(BlastConfig { radius }.validate().is_err(), "{radius}");
+        }
+        let config: GameplayConfig = ron::de::from_str("(blast: (radius: 6.5))").unwrap();
+        assert_eq!(config.blast.radius, 6.5);
+    }
+
+    #[test]
+    fn game_speed_is_bounded() {
+        assert_eq!(GameplayConfig::default().game_speed.factor(), 1.0);
+        for percent in [MIN_GAME_SPEED_PERCENT, 95, MAX_GAME_SPEED_PERCENT] {
+            assert!(GameSpeed(percent).validate().is_ok(), "{percent}");
+        }
+        for percent in [0, MIN_GAME_SPEED_PERCENT - 1, MAX_GAME_SPEED_PERCENT + 1] {
+            assert!(GameSpeed(percent).validate().is_err(), "{percent}");
+        }
+        let config: GameplayConfig = ron::de::from_str("(game_speed: 90)").unwrap();
+        assert_eq!(config.game_speed, GameSpeed(90));
+
+        assert_eq!(GameSpeed(100).next(), GameSpeed(110));
+        assert_eq!(GameSpeed(MAX_GAME_SPEED_PERCENT).next(), GameSpeed(80));
+        assert_eq!(GameSpeed(115).next(), GameSpeed(MIN_GAME_SPEED_PERCENT));
+    }
+}
@@ -0,0 +1,253 @@
+//! Keyboard bindings.
+//!
+//! [`InputMap`] lists every key binding of the game: what it does, the keys that trigger it and
+//! whether it needs cheat mode. The help overlay (`ui::help_overlay`, `F1` or `?`) is generated
+//! from it, so a new binding only has to be added here to show up there.
+//!
+//! The input systems read their keys directly; bindings whose keys are module constants
+//! (`energy::SHIELD_KEY`, `energy::DASH_KEY`, `help_overlay::HELP_KEYS`, `focus::FOCUS_NEXT_KEYS`
+//! and friends, `brick_legend::BRICK_LEGEND_KEY`, `level_intro::INTRO_ADVANCE_KEYS`,
+//! `loss_slowmo::SKIP_KEYS`, `logging::LOG_CONSOLE_KEY`, `WIREFRAME_KEY`) refer to them here so the
+//! two cannot drift apart. TAS files (`systems::tas`) can hold down every key listed here.
+
+use bevy::prelude::*;
+
+use crate::systems::energy::{DASH_KEY, SHIELD_KEY};
+use crate::systems::logging::LOG_CONSOLE_KEY;
+use crate::systems::loss_slowmo;
+use crate::ui::brick_legend::BRICK_LEGEND_KEY;
+use crate::ui::focus::{ACTIVATE_KEYS, FOCUS_NEXT_KEYS, FOCUS_PREVIOUS_KEYS};
+use crate::ui::level_intro::{INTRO_ADVANCE_KEYS, INTRO_SKIP_KEY};
+
+/// A key, optionally held together with a modifier (`Shift` + `L`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    pub modifier: Option<KeyCode>,
+    pub key: KeyCode,
+}
+
+impl KeyChord {
+    pub const fn key(key: KeyCode) -> Self {
+        Self {
+            modifier: None,
+            key,
+        }
+    }
+
+    pub const fn with(modifier: KeyCode, key: KeyCode) -> Self {
+        Self {
+            modifier: Some(modifier),
+            key,
+        }
+    }
+
+    /// How the chord is written in the help, e.g. `Shift + L`.
+    pub fn label(&self) -> String {
+        match self.modifier {
+            Some(modifier) => format!("{} + {}", key_label(modifier), key_label(self.key)),
+            None => key_label(self.key),
+        }
+    }
+}
+
+/// One action and the keys bound to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBinding {
+    pub action: &'static str,
+    pub keys: Vec<KeyChord>,
+    /// Only works while cheat mode is on.
+    pub cheat: bool,
+}
+
+impl KeyBinding {
+    fn new(action: &'static str, keys: &[KeyChord]) -> Self {
+        Self {
+            action,
+            keys: keys.to_vec(),
+            cheat: false,
+        }
+    }
+
+    fn cheat(action: &'static str, keys: &[KeyChord]) -> Self {
+        Self {
+            cheat: true,
+            ..Self::new(action, keys)
+        }
+    }
+
+    /// The keys as written in the help, e.g. `P / Shift + L`.
+    pub fn keys_label(&self) -> String {
+        self.keys
+            .iter()
+            .map(KeyChord::label)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+/// Every key binding of the game, in the order the help lists them.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct InputMap {
+    pub bindings: Vec<KeyBinding>,
+}
+
+impl InputMap {
+    /// Bindings that work without cheat mode.
+    pub fn gameplay(&self) -> impl Iterator<Item = &KeyBinding> {
+        self.bindings.iter().filter(|binding| !binding.cheat)
+    }
+
+    /// Bindings that need cheat mode.
+    pub fn cheats(&self) -> impl Iterator<Item = &KeyBinding> {
+        self.bindings.iter().filter(|binding| binding.cheat)
+    }
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        use KeyCode::*;
+        let key = KeyChord::key;
+        let shift = |k| KeyChord::with(ShiftLeft, k);
+        let keys = |codes: &[KeyCode]| codes.iter().copied().map(KeyChord::key).collect::<Vec<_>>();
+        let help_keys: Vec<KeyChord> = crate::ui::help_overlay::HELP_KEYS.to_vec();
+        let mut focus_keys = keys(&FOCUS_NEXT_KEYS);
+        focus_keys.push(shift(Tab));
+        focus_keys.extend(keys(&FOCUS_PREVIOUS_KEYS));
+
+        let mut bindings = vec![
+            KeyBinding::new("Show or hide this help", &help_keys),
+            KeyBinding::new("Pause", &[key(Escape)]),
+            KeyBinding::new("Ball-save shield (costs energy)", &[key(SHIELD_KEY)]),
+            KeyBinding::new("Paddle dash (costs energy)", &[key(DASH_KEY)]),
+            KeyBinding::new("Menus: move focus", &focus_keys),
+            KeyBinding::new("Menus: activate", &keys(&ACTIVATE_KEYS)),
+            KeyBinding::new("Brick legend (outside menus)", &[key(BRICK_LEGEND_KEY)]),
+            KeyBinding::new(
+                "Level intro: next line (or click)",
+                &keys(&INTRO_ADVANCE_KEYS),
+            ),
+            KeyBinding::new("Level intro: skip", &[key(INTRO_SKIP_KEY)]),
+            KeyBinding::new(
+                "Lost ball slow motion: skip (or click)",
+                &keys(&loss_slowmo::SKIP_KEYS),
+            ),
+            KeyBinding::new("Playlist editor", &[key(KeyL)]),
+            KeyBinding::new("Toggle cheat mode", &[key(KeyG)]),
+        ];
+        #[cfg(not(target_arch = "wasm32"))]
+        bindings.extend([
+            KeyBinding::new("Toggle fullscreen", &[key(F11)]),
+            KeyBinding::new("Quit", &[key(KeyQ)]),
+        ]);
+        bindings.extend([
+            KeyBinding::cheat("Restart level", &[key(KeyR)]),
+            KeyBinding::cheat("Next level", &[key(KeyN)]),
+            KeyBinding::cheat("Previous level", &[key(KeyP), shift(KeyL)]),
+            KeyBinding::cheat("Destroy all bricks", &[key(KeyK)]),
+            KeyBinding::cheat("Cheat console", &[key(Backquote)]),
+            KeyBinding::cheat(
+                "Simulation speed 0.25x / 0.5x / 1x / 2x",
+                &[key(Digit1), key(Digit2), key(Digit3), key(Digit4)],
+            ),
+            KeyBinding::cheat("Step one frame while paused", &[key(Period)]),
+            KeyBinding::cheat("Entity inspector", &[key(KeyI)]),
+            KeyBinding::cheat("Ball heatmap", &[key(KeyH)]),
+            KeyBinding::cheat("Ghost path", &[key(KeyB)]),
+            KeyBinding::cheat("Log console", &[key(LOG_CONSOLE_KEY)]),
+            KeyBinding::cheat(
+                "Log console: scroll back / forward / to the newest line",
+                &[key(PageUp), key(PageDown), key(End)],
+            ),
+        ]);
+        #[cfg(not(target_arch = "wasm32"))]
+        bindings.push(KeyBinding::cheat(
+            "Toggle wireframe",
+            &[key(crate::WIREFRAME_KEY)],
+        ));
+        Self { bindings }
+    }
+}
+
+/// Short name of a key for the help.
+pub fn key_label(key: KeyCode) -> String {
+    use KeyCode::*;
+    let label = match key {
+        Escape => "Esc",
+        Enter => "Enter",
+        NumpadEnter => "Numpad Enter",
+        Space => "Space",
+        Tab => "Tab",
+        ShiftLeft | ShiftRight => "Shift",
+        ControlLeft | ControlRight => "Ctrl",
+        AltLeft | AltRight => "Alt",
+        ArrowUp => "Up",
+        ArrowDown => "Down",
+        ArrowLeft => "Left",
+        ArrowRight => "Right",
+        Backquote => "`",
+        BracketLeft => "[",
+        BracketRight => "]",
+        Period => ".",
+        Comma => ",",
+        Slash => "/",
+        Minus => "-",
+        Equal => "=",
+        other => {
+            let name = format!("{:?}", other);
+            return name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .unwrap_or(&name)
+                .to_string();
+        }
+    };
+    label.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bindings_are_labelled_for_the_help() {
+        assert_eq!(key_label(KeyCode::KeyS), "S");
+        assert_eq!(key_label(KeyCode::Digit3), "3");
+        assert_eq!(key_label(KeyCode::F1), "F1");
+        assert_eq!(
+            KeyChord::with(KeyCode::ShiftRight, KeyCode::KeyL).label(),
+            "Shift + L"
+        );
+
+        let map = InputMap::default();
+        assert!(map.bindings.iter().all(|binding| !binding.keys.is_empty()));
+        let previous = map
+            .cheats()
+            .find(|binding| binding.action == "Previous level")
+            .unwrap();
+        assert_eq!(previous.keys_label(), "P / Shift + L");
+        assert!(map.gameplay().any(|binding| binding.keys_label() == "S"));
+    }
+
+    #[test]
+    fn cheat_keys_do_not_shadow_gameplay_keys() {
+        let map = InputMap::default();
+        let gameplay: Vec<KeyChord> = map.gameplay().flat_map(|b| b.keys.clone()).collect();
+        for binding in map.cheats() {
+            for chord in &binding.keys {
+                assert!(
+                    !gameplay.contains(chord),
+                    "{} is bound to '{}' and a gameplay action",
+                    chord.label(),
+                    binding.action
+                );
+            }
+        }
+        // Menus own focus navigation, so the legend needs a key of its own
+        let focus = &map
+            .gameplay()
+            .find(|binding| binding.action == "Menus: move focus")
+            .unwrap()
+            .keys;
+        assert!(!focus.iter().any(|chord| chord.key == BRICK_LEGEND_KEY));
+    }
+}
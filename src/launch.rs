@@ -0,0 +1,261 @@
+//! Launch options: how the game was started.
+//!
+//! The binary parses its command line (`brkrs --help`) into [`LaunchOptions`] and hands them to
+//! [`BrkrsConfig::with_launch_options`](crate::BrkrsConfig::with_launch_options). Without a
+//! command line (embedding apps, tests) the options come from the `BK_LEVEL` and `BK_LEVEL_PATH`
+//! environment variables; the browser build reads the page's query string instead, so links like
+//! `?level=5&seed=42` start a given level, `?survival` starts the survival mode and `?versus` the
+//! two-player score race. See
+//! [`LaunchOptions::from_env`].
+//!
+//! The options are inserted as a resource by [`crate::BrkrsPlugins`] and read at startup: the
+//! level loader picks the first level from them, autosave does not resume saved progress when a
+//! level was chosen, and [`apply_launch_options`] applies the seed and mute options. The TAS and
+//! replay options are read when `systems::tas::TasPlugin` is built, which may fill in the level
+//! and seed from the file played back.
+
+use std::path::{Path, PathBuf};
+
+use bevy::audio::{GlobalVolume, Volume};
+use bevy::prelude::*;
+
+use crate::systems::rng::GameRng;
+
+/// Directory of the bundled level files.
+pub const DEFAULT_LEVELS_DIR: &str = "assets/levels";
+/// Level file of the survival mode arena.
+pub const SURVIVAL_ARENA_PATH: &str = "assets/levels/survival_arena.ron";
+
+/// Options chosen when the game was launched.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct LaunchOptions {
+    /// Level to start with (`--level N`, `BK_LEVEL`)
+    pub level: Option<u32>,
+    /// Level file to start with, instead of a numbered level (`--level FILE`, `BK_LEVEL_PATH`)
+    pub level_path: Option<PathBuf>,
+    /// Directory of the numbered `level_NNN.ron` files (`--levels-dir`)
+    pub levels_dir: PathBuf,
+    /// Open a window instead of going fullscreen (`--windowed`)
+    pub windowed: bool,
+    /// Silence all audio for this session, leaving the saved audio settings alone (`--mute`)
+    pub mute: bool,
+    /// Recorded session to play back (`--replay`): a file recorded with `--record-tas`
+    pub replay: Option<PathBuf>,
+    /// TAS input file to play back (`--tas`), see `systems::tas`
+    pub tas: Option<PathBuf>,
+    /// TAS input file to record this session into (`--record-tas`)
+    pub record_tas: Option<PathBuf>,
+    /// Run without a window or GPU, e.g. simulations on a server (`--headless`)
+    pub headless: bool,
+    /// Gameplay seed of [`GameRng`] (`--seed`, `BK_SEED`)
+    pub seed: Option<u64>,
+    /// Play the survival mode instead of the campaign (`--survival`)
+    pub survival: bool,
+    /// Two players take turns with the paddle, racing for points (`--versus`)
+    pub versus: bool,
+    /// Theme pack to use (`--theme ID`), `off` for none; picked by calendar date when unset
+    pub theme: Option<String>,
+}
+
+impl Default for LaunchOptions {
+    fn default() -> Self {
+        Self {
+            level: None,
+            level_path: None,
+            levels_dir: PathBuf::from(DEFAULT_LEVELS_DIR),
+            windowed: false,
+            mute: false,
+            replay: None,
+            tas: None,
+            record_tas: None,
+            headless: false,
+            seed: None,
+            survival: false,
+            versus: false,
+            theme: None,
+        }
+    }
+}
+
+impl LaunchOptions {
+    /// Options from the `BK_LEVEL` and `BK_LEVEL_PATH` environment variables on native builds,
+    /// from the page's query string ([`LaunchOptions::from_query`]) on wasm.
+    ///
+    /// `BK_SEED` is read by [`GameRng`] itself.
+    pub fn from_env() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let options = web_sys::window()
+            .and_then(|window| window.location().search().ok())
+            .map_or_else(Self::default, |query| Self::from_query(&query));
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut options = Self::default();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            // Tests pass explicit level files so they don't have to write into assets/levels/
+            options.level_path = std::env::var_os("BK_LEVEL_PATH").map(PathBuf::from);
+            if let Ok(value) = std::env::var("BK_LEVEL") {
+                match value.trim().parse::<u32>() {
+                    Ok(number) => options.level = Some(number),
+                    Err(_) => warn!("BK_LEVEL='{}' not a number; defaulting to level_001", value),
+                }
+            }
+        }
+        options
+    }
+
+    /// Options from a URL query string such as `?level=5&seed=42&mute`, for deep links into the
+    /// browser build. Unknown parameters are ignored.
+    pub fn from_query(query: &str) -> Self {
+        let mut options = Self::default();
+        let pairs = query.trim_start_matches('?').split('&');
+        for pair in pairs.filter(|pair| !pair.is_empty()) {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            match name {
+                "level" => match value.parse::<u32>() {
+                    Ok(number) => options.level = Some(number),
+                    Err(_) => warn!("level='{}' not a number; ignoring it", value),
+                },
+                "seed" => match value.parse::<u64>() {
+                    Ok(seed) => options.seed = Some(seed),
+                    Err(_) => warn!("seed='{}' not a number; ignoring it", value),
+                },
+                "mute" => options.mute = !matches!(value, "0" | "false"),
+                "survival" => options.survival = !matches!(value, "0" | "false"),
+                "versus" => options.versus = !matches!(value, "0" | "false"),
+                "theme" if !value.is_empty() => options.theme = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        options
+    }
+
+    /// Whether the starting level was chosen, which takes precedence over saved progress. The
+    /// survival mode always starts in its arena.
+    pub fn level_chosen(&self) -> bool {
+        self.level.is_some() || self.level_path.is_some() || self.survival
+    }
+
+    /// Input file to play back: `--tas`, or else `--replay`.
+    pub fn playback_path(&self) -> Option<&Path> {
+        self.tas.as_deref().or(self.replay.as_deref())
+    }
+
+    /// Path of the level file to load at startup: the survival arena in survival mode, unless
+    /// another level was chosen.
+    pub fn start_level_path(&self) -> String {
+        match (&self.level_path, self.level) {
+            (Some(path), _) => path.display().to_string(),
+            (None, None) if self.survival => SURVIVAL_ARENA_PATH.to_string(),
+            (None, level) => level_file_path(&self.levels_dir, level.unwrap_or(1)),
+        }
+    }
+}
+
+/// Path of the numbered level file `number` in `dir`.
+pub fn level_file_path(dir: &Path, number: u32) -> String {
+    format!("{}/level_{:03}.ron", dir.display(), number)
+}
+
+/// Directory of the numbered level files: the launch option, or the bundled levels in apps
+/// without launch options.
+pub fn levels_dir(launch: Option<&LaunchOptions>) -> &Path {
+    launch.map_or(Path::new(DEFAULT_LEVELS_DIR), |launch| &launch.levels_dir)
+}
+
+/// Apply the seed and mute options.
+pub fn apply_launch_options(
+    launch: Res<LaunchOptions>,
+    rng: Option<ResMut<GameRng>>,
+    global_volume: Option<ResMut<GlobalVolume>>,
+) {
+    if let (Some(seed), Some(mut rng)) = (launch.seed, rng) {
+        rng.reseed(seed);
+        info!("Using gameplay seed {} from the command line", seed);
+    }
+    if launch.mute {
+        if let Some(mut global_volume) = global_volume {
+            global_volume.volume = Volume::SILENT;
+        }
+        info!("Audio muted for this session");
+    }
+}
+
+/// Command line of the `brkrs` binary.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(clap::Parser, Debug, Clone, PartialEq)]
+#[command(
+    name = "brkrs",
+    version,
+    about = "A brick-breaker game built with Bevy"
+)]
+pub struct Cli {
+    /// Start with level N, or with the level file FILE
+    #[arg(long, value_name = "N|FILE", env = "BK_LEVEL")]
+    pub level: Option<String>,
+    /// Level file to start with when `--level` is not given (kept for scripts using the environment
+    /// variable)
+    #[arg(long, value_name = "FILE", env = "BK_LEVEL_PATH", hide = true)]
+    pub level_path: Option<PathBuf>,
+    /// Directory of the numbered level files
+    #[arg(long, value_name = "DIR", default_value = DEFAULT_LEVELS_DIR)]
+    pub levels_dir: PathBuf,
+    /// Open a window instead of going fullscreen
+    #[arg(long)]
+    pub windowed: bool,
+    /// Silence all audio for this session
+    #[arg(long)]
+    pub mute: bool,
+    /// Play back a session recorded with --record-tas
+    #[arg(long, value_name = "FILE", conflicts_with = "tas")]
+    pub replay: Option<PathBuf>,
+    /// Play back a TAS input file, frame by frame
+    #[arg(long, value_name = "FILE")]
+    pub tas: Option<PathBuf>,
+    /// Record every frame's input into a TAS file, written on exit
+    #[arg(long, value_name = "FILE")]
+    pub record_tas: Option<PathBuf>,
+    /// Run without a window or GPU
+    #[arg(long, conflicts_with = "windowed")]
+    pub headless: bool,
+    /// Gameplay seed, for reproducible runs
+    #[arg(long, env = "BK_SEED")]
+    pub seed: Option<u64>,
+    /// Play the survival mode: one arena, faster and faster, until the last life is lost
+    #[arg(long)]
+    pub survival: bool,
+    /// Two players take turns with the paddle, each losing a ball passes it on; most points wins
+    #[arg(long)]
+    pub versus: bool,
+    /// Theme pack to use, or `off`; by default the pack of the current date, if any
+    #[arg(long, value_name = "ID", env = "BK_THEME")]
+    pub theme: Option<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<Cli> for LaunchOptions {
+    fn from(cli: Cli) -> Self {
+        // `--level` takes a number or a file, and either replaces `BK_LEVEL_PATH`
+        let (level, level_path) = match cli.level {
+            Some(level) => match level.trim().parse::<u32>() {
+                Ok(number) => (Some(number), None),
+                Err(_) => (None, Some(PathBuf::from(level))),
+            },
+            None => (None, cli.level_path),
+        };
+        Self {
+            level,
+            level_path,
+            levels_dir: cli.levels_dir,
+            windowed: cli.windowed,
+            mute: cli.mute,
+            replay: cli.replay,
+            tas: cli.tas,
+            record_tas: cli.record_tas,
+            headless: cli.headless,
+            seed: cli.seed,
+            survival: cli.survival,
+            versus: cli.versus,
+            theme: cli.theme,
+        }
+    }
+}
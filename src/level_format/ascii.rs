@@ -0,0 +1,190 @@
+//! ASCII layouts: a brick grid as plain text, for sharing layouts in issues and chat.
+//!
+//! A layout is a block of grid lines, one character per cell, optionally preceded by legend lines
+//! of the form `<char> = <brick type>`:
+//!
+//! ```text
+//! a = 57
+//! ####....####
+//! #aa#....X..X
+//! ```
+//!
+//! Characters without a legend line use [`DEFAULT_LEGEND`]; `.` and spaces are empty cells. Rows
+//! and columns beyond the layout are empty. [`matrix_to_ascii`] writes the legend lines for any
+//! brick type the default legend does not cover, so its output always reads back unchanged.
+
+use super::{
+    BOMB_BRICK, BUMPER, INDESTRUCTIBLE_BRICK, MULTI_HIT_BRICK_1, MULTI_HIT_BRICK_2,
+    MULTI_HIT_BRICK_3, MULTI_HIT_BRICK_4, SIMPLE_BRICK, TARGET_COLS, TARGET_ROWS,
+};
+
+/// Character of an empty cell.
+pub const EMPTY_CELL: char = '.';
+
+/// Characters understood without a legend line.
+pub const DEFAULT_LEGEND: &[(char, u8)] = &[
+    ('#', SIMPLE_BRICK),
+    ('X', INDESTRUCTIBLE_BRICK),
+    ('1', MULTI_HIT_BRICK_1),
+    ('2', MULTI_HIT_BRICK_2),
+    ('3', MULTI_HIT_BRICK_3),
+    ('4', MULTI_HIT_BRICK_4),
+    ('O', BUMPER),
+    ('*', BOMB_BRICK),
+];
+
+/// Characters handed out to brick types missing from [`DEFAULT_LEGEND`].
+const SPARE_CHARS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNPQRSTUVWYZ";
+
+/// Parse an ASCII layout into a `TARGET_ROWS` x `TARGET_COLS` brick matrix (0 = empty).
+pub fn matrix_from_ascii(text: &str) -> Result<Vec<Vec<u8>>, String> {
+    matrix_from_ascii_in(text, (TARGET_ROWS, TARGET_COLS))
+}
+
+/// Parse an ASCII layout into a brick matrix of `resolution` `(rows, cols)` (0 = empty).
+pub fn matrix_from_ascii_in(
+    text: &str,
+    resolution: (usize, usize),
+) -> Result<Vec<Vec<u8>>, String> {
+    let (rows, cols) = resolution;
+    let mut legend: Vec<(char, u8)> = DEFAULT_LEGEND.to_vec();
+    let mut grid = Vec::new();
+    for line in text.lines() {
+        if let Some((key, value)) = line.split_once('=') {
+            let mut chars = key.trim().chars();
+            let (Some(key), None) = (chars.next(), chars.next()) else {
+                return Err(format!("legend line '{line}' must name a single character"));
+            };
+            let brick_type = match value.trim().parse::<u8>() {
+                Ok(brick_type @ 3..=255) => brick_type,
+                _ => {
+                    return Err(format!(
+                        "legend '{key}' has invalid brick type '{}' (3-255)",
+                        value.trim()
+                    ))
+                }
+            };
+            if key == EMPTY_CELL || key == ' ' {
+                return Err(format!("'{key}' is reserved for empty cells"));
+            }
+            legend.retain(|(c, _)| *c != key);
+            legend.push((key, brick_type));
+        } else if !grid.is_empty() || !line.trim().is_empty() {
+            grid.push(line.trim_end());
+        }
+    }
+    while grid.last().is_some_and(|line| line.is_empty()) {
+        grid.pop();
+    }
+    if grid.len() > rows {
+        return Err(format!(
+            "layout has {} rows; the grid has {rows}",
+            grid.len()
+        ));
+    }
+
+    let mut matrix = vec![vec![0; cols]; rows];
+    for (row, line) in grid.iter().enumerate() {
+        if line.chars().count() > cols {
+            return Err(format!(
+                "row {row} has {} columns; the grid has {cols}",
+                line.chars().count()
+            ));
+        }
+        for (col, c) in line.chars().enumerate() {
+            if c == EMPTY_CELL || c == ' ' {
+                continue;
+            }
+            let Some(&(_, brick_type)) = legend.iter().find(|(key, _)| *key == c) else {
+                return Err(format!(
+                    "unknown character '{c}' at row {row}, column {col}"
+                ));
+            };
+            matrix[row][col] = brick_type;
+        }
+    }
+    Ok(matrix)
+}
+
+/// Write a brick matrix as an ASCII layout that [`matrix_from_ascii`] reads back.
+///
+/// Values below 3 (empty, ball and paddle spawns) are written as empty cells.
+pub fn matrix_to_ascii(matrix: &[Vec<u8>]) -> String {
+    let mut extra: Vec<(char, u8)> = Vec::new();
+    let mut spare = SPARE_CHARS.chars();
+    let mut lines = Vec::with_capacity(matrix.len());
+    for row in matrix {
+        let mut line = String::with_capacity(row.len());
+        for &value in row {
+            let c = if value < 3 {
+                Some(EMPTY_CELL)
+            } else if let Some(&(c, _)) = DEFAULT_LEGEND
+                .iter()
+                .chain(extra.iter())
+                .find(|(_, brick_type)| *brick_type == value)
+            {
+                Some(c)
+            } else {
+                spare.next().inspect(|&c| extra.push((c, value)))
+            };
+            // Out of spare characters (more than 50 unusual types): leave the cell empty
+            line.push(c.unwrap_or(EMPTY_CELL));
+        }
+        lines.push(line);
+    }
+
+    let mut text = String::new();
+    for (c, brick_type) in &extra {
+        text.push_str(&format!("{c} = {brick_type}\n"));
+    }
+    for line in lines {
+        text.push_str(&line);
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layouts_read_with_the_default_legend_and_legend_lines() {
+        let matrix = matrix_from_ascii("a = 57\n\n#.X\n 1a\n").unwrap();
+        assert_eq!(matrix.len(), TARGET_ROWS);
+        assert_eq!(matrix[0][..3], [SIMPLE_BRICK, 0, INDESTRUCTIBLE_BRICK]);
+        assert_eq!(matrix[1][..3], [0, MULTI_HIT_BRICK_1, 57]);
+        assert!(matrix[2..].iter().all(|row| row.iter().all(|&v| v == 0)));
+    }
+
+    #[test]
+    fn bad_layouts_are_rejected() {
+        assert!(matrix_from_ascii("#?#").unwrap_err().contains("'?'"));
+        assert!(matrix_from_ascii("a = 1\na").is_err());
+        assert!(matrix_from_ascii(&"#".repeat(TARGET_COLS + 1)).is_err());
+        assert!(matrix_from_ascii(&"#\n".repeat(TARGET_ROWS + 1)).is_err());
+    }
+
+    #[test]
+    fn layouts_fit_the_declared_resolution() {
+        let wide = "#".repeat(40);
+        let matrix = matrix_from_ascii_in(&wide, (40, 40)).unwrap();
+        assert_eq!((matrix.len(), matrix[0].len()), (40, 40));
+        assert!(matrix[0].iter().all(|&v| v == SIMPLE_BRICK));
+        assert!(matrix_from_ascii_in(&wide, (40, 39)).is_err());
+    }
+
+    #[test]
+    fn written_layouts_read_back_unchanged() {
+        let mut matrix = vec![vec![0; TARGET_COLS]; TARGET_ROWS];
+        matrix[0][0] = SIMPLE_BRICK;
+        matrix[3][7] = 57;
+        matrix[19][19] = 31;
+        matrix[5][5] = 2; // paddle spawn is not part of the layout
+        let text = matrix_to_ascii(&matrix);
+        assert!(text.starts_with("a = 57\nb = 31\n#..."));
+
+        matrix[5][5] = 0;
+        assert_eq!(matrix_from_ascii(&text).unwrap(), matrix);
+    }
+}
@@ -1,14 +1,20 @@
 //! Level format normalization module
 //!
 //! Provides matrix normalization utilities for level loading,
-//! ensuring level matrices conform to expected 20x20 dimensions.
+//! ensuring level matrices conform to expected 20x20 dimensions, or to the resolution a level
+//! declares (up to [`MAX_RESOLUTION`] rows and columns).
 
 use bevy::prelude::*;
 
+pub mod ascii;
+
 /// Target dimensions for normalized level matrices.
 pub const TARGET_ROWS: usize = 20;
 pub const TARGET_COLS: usize = 20;
 
+/// Largest number of rows or columns a level may declare with `resolution`.
+pub const MAX_RESOLUTION: usize = 40;
+
 /// Canonical tile index used for simple (destructible) bricks when authoring new levels.
 /// Historically the project used `3` for a simple brick; new levels should prefer `20`.
 pub const SIMPLE_BRICK: u8 = 20;
@@ -29,13 +35,50 @@ pub const MULTI_HIT_BRICK_3: u8 = 12;
 /// Multi-hit brick index 13: needs 4 more hits to be destroyed (maximum durability).
 pub const MULTI_HIT_BRICK_4: u8 = 13;
 
+/// Bumper index 16: a round, indestructible pinball-style kicker that pushes balls away and
+/// scores on every hit (see `systems::bumpers`).
+pub const BUMPER: u8 = 16;
+
+/// Secret exit brick index 17: an ordinary destructible brick that, once destroyed, makes the
+/// level lead to its campaign `secret_exit` level when completed.
+pub const SECRET_EXIT_BRICK: u8 = 17;
+
+/// Invisible brick index 18: solid but not drawn until a ball passes close by or hits it.
+pub const INVISIBLE_BRICK: u8 = 18;
+
+/// Timed bomb brick index 19: the first ball hit lights a fuse; the brick explodes when it runs
+/// out, destroying its grid neighbours.
+pub const TIMED_BOMB_BRICK: u8 = 19;
+
+/// Bomb brick index 40: explodes as soon as it is destroyed, taking its grid neighbours with it.
+pub const BOMB_BRICK: u8 = 40;
+
+/// Blast brick index 50: explodes as soon as it is destroyed, taking every brick within the
+/// configured blast radius (world units, not grid cells) with it.
+pub const BLAST_BRICK: u8 = 50;
+
 /// Extra Life brick index 41: awards +1 player life when destroyed, no points.
 /// This brick is destructible (durability 1) and plays a unique destruction sound.
 pub const EXTRA_LIFE_BRICK: u8 = 41;
 
+/// Armored brick index 43: destroyed only by ball hits on its bottom side (facing row 19).
+pub const ARMORED_BRICK_BOTTOM: u8 = 43;
+
+/// Armored brick index 44: destroyed only by ball hits on its top side (facing row 0).
+pub const ARMORED_BRICK_TOP: u8 = 44;
+
+/// Armored brick index 45: destroyed only by ball hits on its left side (facing column 0).
+pub const ARMORED_BRICK_LEFT: u8 = 45;
+
+/// Armored brick index 46: destroyed only by ball hits on its right side (facing column 19).
+pub const ARMORED_BRICK_RIGHT: u8 = 46;
+
 /// Paddle-destroyable brick index 57: destroyed by paddle contact only.
 pub const PADDLE_DESTROYABLE_BRICK: u8 = 57;
 
+/// Glue brick index 58: drops a catch capsule that makes the paddle sticky for a while.
+pub const GLUE_BRICK: u8 = 58;
+
 /// Brick type 42: destructible hazard that causes life loss on paddle collision.
 /// Ball collisions destroy it and award 90 points.
 pub const HAZARD_BRICK_42: u8 = 42;
@@ -64,6 +107,12 @@ pub fn is_multi_hit_brick(type_id: u8) -> bool {
     (MULTI_HIT_BRICK_1..=MULTI_HIT_BRICK_4).contains(&type_id)
 }
 
+/// Returns `true` if the brick is armored on all sides but one (indices 43-46).
+#[inline]
+pub fn is_armored_brick(type_id: u8) -> bool {
+    (ARMORED_BRICK_BOTTOM..=ARMORED_BRICK_RIGHT).contains(&type_id)
+}
+
 /// Returns true if the brick type is paddle-destroyable (type 57).
 ///
 /// Paddle-destroyable bricks are destroyed only by paddle contact,
@@ -95,6 +144,14 @@ pub fn is_hazard_brick(type_id: u8) -> bool {
     type_id == HAZARD_BRICK_42 || type_id == HAZARD_BRICK_91
 }
 
+/// Returns `true` if the brick explodes when destroyed (bomb 40, timed bomb 19 or blast brick 50).
+///
+/// Explosive bricks caught in another explosion detonate immediately, so blasts chain.
+#[inline]
+pub fn is_explosive_brick(type_id: u8) -> bool {
+    type_id == BOMB_BRICK || type_id == TIMED_BOMB_BRICK || type_id == BLAST_BRICK
+}
+
 #[cfg(test)]
 mod hazard_tests {
     use super::*;
@@ -121,6 +178,46 @@ mod hazard_tests {
     }
 }
 
+/// Validate a declared level resolution `(rows, cols)`: each between 1 and [`MAX_RESOLUTION`].
+pub fn validate_resolution(resolution: (usize, usize)) -> Result<(), String> {
+    let (rows, cols) = resolution;
+    if !(1..=MAX_RESOLUTION).contains(&rows) || !(1..=MAX_RESOLUTION).contains(&cols) {
+        return Err(format!(
+            "resolution {rows}x{cols} is outside 1x1..{MAX_RESOLUTION}x{MAX_RESOLUTION}"
+        ));
+    }
+    Ok(())
+}
+
+/// Grid spanned by a level `matrix`, and at least the standard 20x20 grid, for validating cell
+/// references against levels of any resolution.
+pub fn matrix_resolution(matrix: &[Vec<u8>]) -> (usize, usize) {
+    let cols = matrix.iter().map(Vec::len).max().unwrap_or(0);
+    (matrix.len().max(TARGET_ROWS), cols.max(TARGET_COLS))
+}
+
+/// Validate an inclusive `(first, last)` row and column range of a grid with `resolution`
+/// `(rows, cols)`, as used by level-defined zones and cell references.
+pub fn validate_cell_range_in(
+    rows: (usize, usize),
+    cols: (usize, usize),
+    resolution: (usize, usize),
+) -> Result<(), String> {
+    if rows.0 > rows.1 || cols.0 > cols.1 {
+        return Err(format!(
+            "cell range is reversed: rows {:?}, cols {:?}",
+            rows, cols
+        ));
+    }
+    if rows.1 >= resolution.0 || cols.1 >= resolution.1 {
+        return Err(format!(
+            "cell range lies outside the grid: rows {:?}, cols {:?}",
+            rows, cols
+        ));
+    }
+    Ok(())
+}
+
 /// Metrics collected during matrix normalization.
 ///
 /// These metrics indicate how the input matrix was adjusted to fit
@@ -158,37 +255,47 @@ pub struct NormalizationResult {
 /// # Returns
 ///
 /// A [`NormalizationResult`] containing the normalized 20x20 matrix and metrics.
-pub fn normalize_matrix(mut matrix: Vec<Vec<u8>>) -> NormalizationResult {
+pub fn normalize_matrix(matrix: Vec<Vec<u8>>) -> NormalizationResult {
+    normalize_matrix_to(matrix, (TARGET_ROWS, TARGET_COLS))
+}
+
+/// Normalize a level matrix to `resolution` `(rows, cols)` with padding/truncation, as
+/// [`normalize_matrix`] does for the standard 20x20 grid.
+pub fn normalize_matrix_to(
+    mut matrix: Vec<Vec<u8>>,
+    resolution: (usize, usize),
+) -> NormalizationResult {
+    let (target_rows, target_cols) = resolution;
     let mut metrics = NormalizationMetrics::default();
 
     let original_rows = matrix.len();
     let original_cols = matrix.first().map_or(0, |r| r.len());
 
     // Log warning if dimensions don't match
-    if original_rows != TARGET_ROWS || original_cols != TARGET_COLS {
+    if original_rows != target_rows || original_cols != target_cols {
         warn!(
-            "Level matrix wrong dimensions; expected 20x20, got {}x{}",
-            original_rows, original_cols
+            "Level matrix wrong dimensions; expected {}x{}, got {}x{}",
+            target_rows, target_cols, original_rows, original_cols
         );
     }
 
     // Pad rows if needed
-    if matrix.len() < TARGET_ROWS {
-        metrics.padded_rows = TARGET_ROWS - matrix.len();
-        while matrix.len() < TARGET_ROWS {
-            matrix.push(vec![0; TARGET_COLS]);
+    if matrix.len() < target_rows {
+        metrics.padded_rows = target_rows - matrix.len();
+        while matrix.len() < target_rows {
+            matrix.push(vec![0; target_cols]);
         }
     }
 
     // Truncate rows if needed
-    if matrix.len() > TARGET_ROWS {
-        metrics.truncated_rows = matrix.len() - TARGET_ROWS;
+    if matrix.len() > target_rows {
+        metrics.truncated_rows = matrix.len() - target_rows;
         warn!(
             "Level matrix has {} rows; truncating to {}",
             matrix.len(),
-            TARGET_ROWS
+            target_rows
         );
-        matrix.truncate(TARGET_ROWS);
+        matrix.truncate(target_rows);
     }
 
     // Pad/truncate columns
@@ -196,21 +303,21 @@ pub fn normalize_matrix(mut matrix: Vec<Vec<u8>>) -> NormalizationResult {
         let original_row_len = row.len();
 
         // Pad columns if needed
-        if row.len() < TARGET_COLS {
-            metrics.padded_cols += TARGET_COLS - row.len();
-            while row.len() < TARGET_COLS {
+        if row.len() < target_cols {
+            metrics.padded_cols += target_cols - row.len();
+            while row.len() < target_cols {
                 row.push(0);
             }
         }
 
         // Truncate columns if needed
-        if original_row_len > TARGET_COLS {
-            metrics.truncated_cols += original_row_len - TARGET_COLS;
+        if original_row_len > target_cols {
+            metrics.truncated_cols += original_row_len - target_cols;
             warn!(
                 "Row {} has {} columns; truncating to {}",
-                i, original_row_len, TARGET_COLS
+                i, original_row_len, target_cols
             );
-            row.truncate(TARGET_COLS);
+            row.truncate(target_cols);
         }
     }
 
@@ -397,4 +504,66 @@ mod tests {
         assert_eq!(result.metrics.truncated_rows, 0);
         assert_eq!(result.metrics.truncated_cols, 25); // 5 rows * 5 truncation
     }
+
+    #[test]
+    fn declared_resolutions_normalize_and_bound_cells() {
+        let result = normalize_matrix_to(vec![vec![3u8; 45]; 30], (40, 40));
+        assert_eq!(result.matrix.len(), 40);
+        assert!(result.matrix.iter().all(|row| row.len() == 40));
+        assert_eq!(result.metrics.padded_rows, 10);
+        assert_eq!(result.metrics.truncated_cols, 30 * 5);
+
+        assert!(validate_resolution((40, 40)).is_ok());
+        assert!(validate_resolution((1, 20)).is_ok());
+        assert!(validate_resolution((0, 20)).is_err());
+        assert!(validate_resolution((20, MAX_RESOLUTION + 1)).is_err());
+
+        assert_eq!(matrix_resolution(&[vec![0; 5]]), (20, 20));
+        assert_eq!(matrix_resolution(&result.matrix), (40, 40));
+        assert!(validate_cell_range_in((39, 39), (0, 39), (40, 40)).is_ok());
+        assert!(validate_cell_range_in((39, 39), (0, 0), (20, 20)).is_err());
+    }
+
+    // Property-based tests: arbitrary ragged matrices
+
+    use proptest::prelude::*;
+
+    fn ragged_matrix() -> impl Strategy<Value = Vec<Vec<u8>>> {
+        prop::collection::vec(prop::collection::vec(any::<u8>(), 0..30), 0..30)
+    }
+
+    proptest! {
+        #[test]
+        fn normalized_matrix_is_20x20_and_keeps_the_leading_cells(input in ragged_matrix()) {
+            let result = normalize_matrix(input.clone());
+            prop_assert_eq!(result.matrix.len(), TARGET_ROWS);
+            for (r, row) in result.matrix.iter().enumerate() {
+                prop_assert_eq!(row.len(), TARGET_COLS);
+                for (c, &value) in row.iter().enumerate() {
+                    let original = input.get(r).and_then(|row| row.get(c)).copied().unwrap_or(0);
+                    prop_assert_eq!(value, original, "cell ({}, {})", r, c);
+                }
+            }
+        }
+
+        #[test]
+        fn normalization_metrics_count_the_adjustments(input in ragged_matrix()) {
+            let kept = &input[..input.len().min(TARGET_ROWS)];
+            let expected = NormalizationMetrics {
+                padded_rows: TARGET_ROWS.saturating_sub(input.len()),
+                truncated_rows: input.len().saturating_sub(TARGET_ROWS),
+                padded_cols: kept.iter().map(|row| TARGET_COLS.saturating_sub(row.len())).sum(),
+                truncated_cols: kept.iter().map(|row| row.len().saturating_sub(TARGET_COLS)).sum(),
+            };
+            prop_assert_eq!(normalize_matrix(input).metrics, expected);
+        }
+
+        #[test]
+        fn normalization_is_idempotent(input in ragged_matrix()) {
+            let once = normalize_matrix(input).matrix;
+            let twice = normalize_matrix(once.clone());
+            prop_assert_eq!(&twice.matrix, &once);
+            prop_assert_eq!(twice.metrics, NormalizationMetrics::default());
+        }
+    }
 }
@@ -1,5 +1,11 @@
-use crate::level_format::{normalize_matrix_simple, HAZARD_BRICK_91, INDESTRUCTIBLE_BRICK};
-use crate::systems::level_switch::{LevelSwitchRequested, LevelSwitchState};
+use crate::level_format::{
+    normalize_matrix_to, validate_resolution, BUMPER, HAZARD_BRICK_91, INDESTRUCTIBLE_BRICK,
+    INVISIBLE_BRICK,
+};
+#[cfg(target_arch = "wasm32")]
+use crate::systems::level_fetch::LevelFileStatus;
+use crate::systems::level_fetch::{level_file_listed, read_level_file, LevelFiles};
+use crate::systems::level_switch::{LevelSlot, LevelSwitchRequested, LevelSwitchState};
 use crate::systems::merkaba::Merkaba;
 use crate::systems::respawn::{RespawnEntityKind, RespawnHandle, SpawnPoints, SpawnTransform};
 #[cfg(feature = "texture_manifest")]
@@ -13,10 +19,11 @@ use bevy::prelude::*;
 use ron::de::from_str;
 use serde::Deserialize;
 
+use crate::launch::{level_file_path, levels_dir, LaunchOptions};
 use crate::{
-    Ball, BallTypeId, Brick, BrickTypeId, CountsTowardsCompletion, GameProgress, GravityConfig,
-    LowerGoal, Paddle, BALL_RADIUS, CELL_HEIGHT, CELL_WIDTH, PADDLE_HEIGHT, PADDLE_RADIUS, PLANE_H,
-    PLANE_W,
+    Ball, BallTypeId, Brick, BrickGridPos, BrickTypeId, CountsTowardsCompletion, GameProgress,
+    GravityConfig, GridResolution, LowerGoal, Paddle, BALL_RADIUS, PADDLE_HEIGHT, PADDLE_RADIUS,
+    PLANE_H, PLANE_W,
 };
 use bevy_rapier3d::prelude::*;
 
@@ -28,6 +35,8 @@ pub(crate) struct LevelContext<'w> {
     pub gravity_cfg: ResMut<'w, GravityConfig>,
     pub game_progress: ResMut<'w, GameProgress>,
     pub level_advance: ResMut<'w, LevelAdvanceState>,
+    pub launch: Option<Res<'w, LaunchOptions>>,
+    pub level_files: Option<ResMut<'w, LevelFiles>>,
 }
 /// Helper function to create GravityBrick component for gravity brick types (21-25).
 ///
@@ -87,12 +96,16 @@ pub struct TextureResources<'w> {
     pub type_registry: Option<Res<'w, TypeVariantRegistry>>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, Default)]
 pub struct LevelDefinition {
     pub number: u32,
     /// Optional gravity override for this level (x,y,z). If omitted the existing GravityConfig value is used.
     pub gravity: Option<(f32, f32, f32)>,
-    pub matrix: Vec<Vec<u8>>, // expect 20 x 20
+    pub matrix: Vec<Vec<u8>>, // expect 20 x 20, or `resolution`
+    /// Optional grid resolution `(rows, cols)`, up to 40x40; the standard 20x20 grid if omitted.
+    /// Cell sizes follow from the play area, which the grid always covers.
+    #[serde(default)]
+    pub resolution: Option<(usize, usize)>,
     #[cfg(feature = "texture_manifest")]
     #[serde(default)]
     pub presentation: Option<crate::systems::textures::loader::LevelTextureSet>,
@@ -105,11 +118,117 @@ pub struct LevelDefinition {
     /// When using markdown format, only the display name is extracted.
     #[serde(default)]
     pub author: Option<String>,
+    /// Optional powerup drop table for this level. If omitted the global `GameplayConfig` table is used.
+    #[serde(default)]
+    pub drops: Option<crate::gameplay_config::DropTable>,
+    /// Optional ice/sticky floor zones (inclusive row/column ranges).
+    #[serde(default)]
+    pub floor_zones: Option<Vec<crate::systems::floor_zones::FloorZoneDef>>,
+    /// Optional wind/force fields pushing balls inside them.
+    #[serde(default)]
+    pub force_fields: Option<Vec<crate::systems::force_fields::ForceFieldDef>>,
+    /// Optional intro text shown line by line before gameplay begins (campaign storytelling).
+    #[serde(default)]
+    pub intro: Vec<String>,
+    /// What completes the level; destroying all counted bricks if omitted.
+    #[serde(default)]
+    pub completion: crate::systems::completion::CompletionRule,
+    /// Optional checkpoints: brick groups that, once cleared, become the restart point on game over.
+    #[serde(default)]
+    pub checkpoints: Vec<crate::systems::checkpoints::CheckpointDef>,
+    /// Optional named brick groups (`group id -> [(row, col)]`) used by `group_locks`.
+    #[serde(default)]
+    pub groups: std::collections::BTreeMap<u32, Vec<(usize, usize)>>,
+    /// Optional locks keeping a brick group indestructible until another group is cleared.
+    #[serde(default)]
+    pub group_locks: Vec<crate::systems::brick_groups::GroupLock>,
+    /// Optional one-way gates balls can cross in one direction only.
+    #[serde(default)]
+    pub gates: Option<Vec<crate::systems::one_way_gates::GateDef>>,
+    /// Optional hit counts for bricks needing more hits than the multi-hit indices (10-13) allow.
+    #[serde(default)]
+    pub durability: Vec<crate::systems::durability::DurabilityDef>,
+    /// Optional team colors for versus score races: a team brick only scores for its own team.
+    #[serde(default)]
+    pub teams: Vec<crate::systems::team_bricks::TeamBricksDef>,
+    /// Optional wrap-around side walls: balls leaving through the left or right wall come back in
+    /// through the other one.
+    #[serde(default)]
+    pub wrap_sides: bool,
+    /// Optional gravity wells pulling balls towards them.
+    #[serde(default)]
+    pub gravity_wells: Vec<crate::systems::gravity_wells::GravityWellDef>,
+    /// Optional brick waves spawned during play.
+    #[serde(default)]
+    pub waves: Vec<crate::systems::waves::Wave>,
+    /// Optional restitution and friction by brick type index, over the game-wide ones.
+    #[serde(default)]
+    pub brick_surfaces:
+        std::collections::BTreeMap<u8, crate::systems::brick_surfaces::BrickSurface>,
+    /// Optional tutorial prompts, each waiting for the player to do what it asks; the level cannot
+    /// be completed before the last one is done.
+    #[serde(default)]
+    pub tutorial: Vec<crate::systems::tutorial::TutorialStep>,
+}
+
+impl LevelDefinition {
+    /// Grid the level is laid out on: its declared `resolution` if valid, otherwise the standard
+    /// 20x20 grid.
+    pub fn grid(&self) -> GridResolution {
+        self.resolution
+            .filter(|&resolution| validate_resolution(resolution).is_ok())
+            .map(|(rows, cols)| GridResolution::new(rows, cols))
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Resource, Debug)]
 pub struct CurrentLevel(pub LevelDefinition);
 
+/// Pristine parsed copy of the level file behind [`CurrentLevel`], used to restart the level
+/// from memory instead of re-reading and re-parsing the file.
+///
+/// `CurrentLevel` may be edited in place (e.g. by the designer palette); the snapshot is not, so a
+/// restart always rebuilds the level as authored.
+#[derive(Resource, Debug, Clone)]
+pub struct LevelSnapshot {
+    /// Level file the definition was parsed from.
+    pub path: String,
+    pub definition: LevelDefinition,
+    /// File modification time when parsed (native only; `None` for embedded levels).
+    pub modified: Option<std::time::SystemTime>,
+}
+
+impl LevelSnapshot {
+    /// Snapshot `definition`, recording the current modification time of `path`.
+    pub fn capture(path: impl Into<String>, definition: LevelDefinition) -> Self {
+        let path = path.into();
+        let modified = file_modified(&path);
+        Self {
+            path,
+            definition,
+            modified,
+        }
+    }
+
+    /// Whether the file changed on disk since the snapshot was taken.
+    ///
+    /// Only a cheap metadata lookup; always `false` on wasm, where each level file is fetched once.
+    pub fn is_stale(&self) -> bool {
+        file_modified(&self.path) != self.modified
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn file_modified(path: &str) -> Option<std::time::SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn file_modified(_path: &str) -> Option<std::time::SystemTime> {
+    None
+}
+
 /// Extract display name from author field (handles plain text and markdown link formats)
 ///
 /// Converts:
@@ -169,8 +288,19 @@ pub struct LevelLoaderPlugin;
 impl Plugin for LevelLoaderPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GravityConfig>();
+        app.init_resource::<GridResolution>();
         app.add_message::<RestartRequested>();
+        app.add_systems(PreUpdate, sync_grid_resolution);
+        #[cfg(not(target_arch = "wasm32"))]
         app.add_systems(Startup, (load_level, spawn_level_entities).chain());
+        // The first level file is fetched over HTTP on wasm, so it arrives a few frames later
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(
+            Update,
+            (load_level, spawn_level_entities)
+                .chain()
+                .run_if(not(resource_exists::<CurrentLevel>)),
+        );
         #[cfg(feature = "texture_manifest")]
         {
             use crate::systems::sets::LevelFadeInStartSystems;
@@ -250,13 +380,19 @@ impl Plugin for LevelLoaderPlugin {
 }
 
 /// State machine for delayed level advancement and growth animation.
+///
+/// Each stage is handled by one system in [`LevelAdvanceSystems`], ordered so a stage's commands
+/// are applied before the next stage's system runs:
+/// 1. [`LevelAdvanceStage::Delay`]: `handle_level_advance_delay` ticks the fade-out timer, then
+///    spawns bricks, a growing paddle and a frozen ball.
+/// 2. [`LevelAdvanceStage::Growing`]: once no paddle is growing, `finalize_level_advance` unfreezes
+///    the ball in a single command batch and returns to [`LevelAdvanceStage::Idle`].
 #[derive(Resource)]
 pub struct LevelAdvanceState {
     pub timer: Timer,                     // initial delay before spawning growth paddle
     pub active: bool,                     // transition in progress
     pub growth_spawned: bool,             // tiny paddle+ball spawned, waiting for growth completion
     pub pending: Option<LevelDefinition>, // next level definition awaiting brick spawn
-    pub unfreezing: bool, // BallFrozen removed, waiting one frame for Velocity cleanup
 }
 
 impl Default for LevelAdvanceState {
@@ -266,11 +402,37 @@ impl Default for LevelAdvanceState {
             active: false,
             growth_spawned: false,
             pending: None,
-            unfreezing: false,
         }
     }
 }
 
+/// Stage of a level advance, derived from [`LevelAdvanceState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelAdvanceStage {
+    /// No advance in progress.
+    Idle,
+    /// Fading out before the next level is spawned.
+    Delay,
+    /// Next level spawned; waiting for the paddle to finish growing.
+    Growing,
+}
+
+impl LevelAdvanceState {
+    pub fn stage(&self) -> LevelAdvanceStage {
+        match (self.active && self.pending.is_some(), self.growth_spawned) {
+            (false, _) => LevelAdvanceStage::Idle,
+            (true, false) => LevelAdvanceStage::Delay,
+            (true, true) => LevelAdvanceStage::Growing,
+        }
+    }
+
+    fn finish(&mut self) {
+        self.active = false;
+        self.growth_spawned = false;
+        self.pending = None;
+    }
+}
+
 /// Full-screen UI overlay used for fade in/out during level transitions.
 #[derive(Component)]
 struct FadeOverlay;
@@ -321,47 +483,52 @@ fn load_level(
     mut commands: Commands,
     mut gravity_cfg: ResMut<GravityConfig>,
     mut rapier_config: Query<&mut RapierConfiguration>,
+    launch: Option<Res<LaunchOptions>>,
+    mut level_files: Option<ResMut<LevelFiles>>,
+    // On wasm, a level that cannot be fetched or parsed is replaced by level_001
+    #[cfg(target_arch = "wasm32")] mut fell_back: Local<bool>,
 ) {
-    #[cfg(not(target_arch = "wasm32"))]
-    let chosen_path = {
-        use std::env;
-        // Allow tests to provide an explicit path via BK_LEVEL_PATH. If set, use it
-        // directly (this helps tests avoid writing to repo assets/levels/).
-        if let Ok(path) = env::var("BK_LEVEL_PATH") {
-            path
-        } else if let Ok(num) = env::var("BK_LEVEL") {
-            let trimmed = num.trim();
-            if let Ok(n) = trimmed.parse::<u32>() {
-                format!("assets/levels/level_{:03}.ron", n)
-            } else {
-                warn!("BK_LEVEL='{}' not a number; defaulting to level_001", num);
-                "assets/levels/level_001.ron".to_string()
-            }
-        } else {
-            "assets/levels/level_001.ron".to_string()
-        }
+    // Apps without launch options (tests) choose the level through the environment, or the
+    // page's query string on wasm
+    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_mut))]
+    let mut chosen_path = match launch {
+        Some(launch) => launch.start_level_path(),
+        None => LaunchOptions::from_env().start_level_path(),
     };
-    #[cfg(not(target_arch = "wasm32"))]
-    let level_str: &str = match std::fs::read_to_string(&chosen_path) {
-        Ok(s) => {
+    #[cfg(target_arch = "wasm32")]
+    if *fell_back {
+        chosen_path = level_file_path(levels_dir(None), 1);
+    }
+    let level_str = match read_level_file(&chosen_path, level_files.as_deref_mut()) {
+        Ok(Some(s)) => {
             info!("Loading level file: {}", chosen_path);
-            Box::leak(s.into_boxed_str())
+            s
+        }
+        // Still being fetched (wasm); tried again next frame
+        Ok(None) => return,
+        #[cfg(target_arch = "wasm32")]
+        Err(e) if !*fell_back => {
+            warn!("{e}; starting with level_001");
+            *fell_back = true;
+            return;
         }
         Err(e) => {
-            warn!(
-                "Failed to read level file '{}': {e}. Falling back to empty level",
-                chosen_path
-            );
-            "LevelDefinition(number:0,matrix:[])"
+            warn!("{e}. Falling back to empty level");
+            "LevelDefinition(number:0,matrix:[])".to_string()
         }
     };
-    #[cfg(target_arch = "wasm32")]
-    let level_str: &str = include_str!("../assets/levels/level_001.ron"); // initial level only; subsequent loads use embedded helper
 
-    match from_str::<LevelDefinition>(level_str) {
+    match from_str::<LevelDefinition>(&level_str) {
         Ok(mut def) => {
-            // Normalize matrix to 20x20 with padding/truncation
-            def.matrix = normalize_matrix_simple(def.matrix);
+            if let Some(resolution) = def.resolution {
+                if let Err(e) = validate_resolution(resolution) {
+                    warn!("Level {}: {e}; using the standard 20x20 grid", def.number);
+                    def.resolution = None;
+                }
+            }
+            // Normalize matrix to the level grid (20x20 by default) with padding/truncation
+            let grid = def.grid();
+            def.matrix = normalize_matrix_to(def.matrix, (grid.rows, grid.cols)).matrix;
             info!("Loaded level {}", def.number);
             // Apply per-level gravity if present
             if let Some((x, y, z)) = def.gravity {
@@ -376,10 +543,15 @@ fn load_level(
                     gravity_cfg.normal
                 );
             }
+            commands.insert_resource(LevelSnapshot::capture(chosen_path, def.clone()));
             commands.insert_resource(CurrentLevel(def));
         }
         Err(e) => {
             warn!("Failed to parse level: {e}");
+            #[cfg(target_arch = "wasm32")]
+            {
+                *fell_back = true;
+            }
         }
     }
 }
@@ -505,10 +677,11 @@ fn spawn_level_entities_impl(
     spawn_points.paddle = None;
     spawn_points.ball = None;
 
-    for (row, row_data) in def.matrix.iter().enumerate() {
-        for (col, value) in row_data.iter().enumerate() {
-            let x = -PLANE_H / 2.0 + (row as f32 + 0.5) * CELL_HEIGHT;
-            let z = PLANE_W / 2.0 - (col as f32 + 0.5) * CELL_WIDTH;
+    // Cells outside the grid (matrices that skipped normalization) would land off the field
+    let grid = def.grid();
+    for (row, row_data) in def.matrix.iter().take(grid.rows).enumerate() {
+        for (col, value) in row_data.iter().take(grid.cols).enumerate() {
+            let Vec3 { x, z, .. } = grid.cell_center(row, col);
             match value {
                 0 => {}
                 2 => {
@@ -589,6 +762,11 @@ fn spawn_level_entities_impl(
                             .insert(ball_respawn_handle(position));
                     }
                 }
+                &BUMPER => {
+                    crate::systems::bumpers::spawn_bumper(
+                        commands, meshes, materials, &grid, row, col,
+                    );
+                }
                 brick_type @ 3..=255 => {
                     // Brick with type ID (value 3+ maps to brick types)
                     let brick_type_id = *brick_type;
@@ -611,46 +789,16 @@ fn spawn_level_entities_impl(
                     };
                     #[cfg(not(feature = "texture_manifest"))]
                     let brick_mat = brick_material.clone();
-                    let brick_config = &*brick_config_res;
-                    if let Err(err) = brick_config.validate() {
-                        bevy::log::error!("Invalid BrickPhysicsConfig during brick spawn: {}", err);
-                    }
-                    let mut mesh =
-                        Mesh::from(Cuboid::new(CELL_HEIGHT * 0.9, 0.5, CELL_WIDTH * 0.9));
-                    // this computes normals AND tangents so normal maps work
-                    mesh.compute_area_weighted_normals(); // normals
-                    mesh.generate_tangents().unwrap(); // tangents
-
-                    let mesh_handle = meshes.add(mesh);
-                    let mut entity = commands.spawn((
-                        Mesh3d(mesh_handle),
-                        MeshMaterial3d(brick_mat),
-                        Transform::from_xyz(x, 2.0, z),
-                        Brick,
-                        BrickTypeId(brick_type_id),
-                        RigidBody::Fixed,
-                        Collider::cuboid(CELL_HEIGHT * 0.9 / 2.0, 0.25, CELL_WIDTH * 0.9 / 2.0),
-                        Restitution {
-                            coefficient: brick_config.restitution,
-                            combine_rule: CoefficientCombineRule::Max,
-                        },
-                        Friction {
-                            coefficient: brick_config.friction,
-                            combine_rule: CoefficientCombineRule::Max,
-                        },
-                        CollidingEntities::default(),
-                        ActiveEvents::COLLISION_EVENTS,
-                    ));
-                    // Only destructible bricks contribute to level completion.
-                    // Type 91 (hazard) bricks do not count toward completion.
-                    if brick_type_id != INDESTRUCTIBLE_BRICK && brick_type_id != HAZARD_BRICK_91 {
-                        entity.insert(CountsTowardsCompletion);
-                    }
-
-                    // Attach GravityBrick component for gravity bricks (21-25)
-                    if let Some(gravity_brick) = create_gravity_brick_component(brick_type_id) {
-                        entity.insert(gravity_brick);
-                    }
+                    spawn_brick(
+                        commands,
+                        meshes,
+                        brick_mat,
+                        brick_type_id,
+                        &grid,
+                        row,
+                        col,
+                        &brick_config_res,
+                    );
                 }
             }
         }
@@ -728,15 +876,18 @@ fn spawn_level_entities_impl(
     }
 }
 
-/// Only spawn bricks for a level (used after paddle growth completes during level advance).
-fn spawn_bricks_only(
-    def: &LevelDefinition,
+/// Only spawn the bricks of a level `matrix` laid out on `grid` (used during level advance and
+/// for brick waves).
+pub(crate) fn spawn_bricks_only(
+    matrix: &[Vec<u8>],
+    grid: &GridResolution,
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     #[cfg(feature = "texture_manifest")] canonical: Option<&CanonicalMaterialHandles>,
     #[cfg(feature = "texture_manifest")] mut fallback: Option<&mut FallbackRegistry>,
     #[cfg(feature = "texture_manifest")] type_registry: Option<&TypeVariantRegistry>,
+    brick_config: &crate::physics_config::BrickPhysicsConfig,
 ) {
     let default_brick_material = materials.add(StandardMaterial {
         base_color: Color::srgb(0.9, 0.1, 0.1),
@@ -764,14 +915,16 @@ fn spawn_bricks_only(
         }
     };
 
-    for (row, row_data) in def.matrix.iter().enumerate() {
-        for (col, value) in row_data.iter().enumerate() {
+    for (row, row_data) in matrix.iter().take(grid.rows).enumerate() {
+        for (col, value) in row_data.iter().take(grid.cols).enumerate() {
             if *value < 3 {
                 continue;
             }
             let brick_type_id = *value;
-            let x = -PLANE_H / 2.0 + (row as f32 + 0.5) * CELL_HEIGHT;
-            let z = PLANE_W / 2.0 - (col as f32 + 0.5) * CELL_WIDTH;
+            if brick_type_id == BUMPER {
+                crate::systems::bumpers::spawn_bumper(commands, meshes, materials, grid, row, col);
+                continue;
+            }
             #[cfg(feature = "texture_manifest")]
             let brick_mat = {
                 type_registry
@@ -791,39 +944,95 @@ fn spawn_bricks_only(
             #[cfg(not(feature = "texture_manifest"))]
             let brick_mat = brick_material.clone();
 
-            let mut mesh = Mesh::from(Cuboid::new(CELL_HEIGHT * 0.9, 0.5, CELL_WIDTH * 0.9));
-            // this computes normals AND tangents so normal maps work
-            mesh.compute_area_weighted_normals(); // normals
-            mesh.generate_tangents().unwrap(); // tangents
-
-            let mesh_handle = meshes.add(mesh);
-            let mut entity = commands.spawn((
-                Mesh3d(mesh_handle),
-                MeshMaterial3d(brick_mat),
-                Transform::from_xyz(x, 2.0, z),
-                Brick,
-                BrickTypeId(brick_type_id),
-                RigidBody::Fixed,
-                Collider::cuboid(CELL_HEIGHT * 0.9 / 2.0, 0.25, CELL_WIDTH * 0.9 / 2.0),
-                Restitution {
-                    coefficient: 1.0,
-                    combine_rule: CoefficientCombineRule::Max,
-                },
-                CollidingEntities::default(),
-                ActiveEvents::COLLISION_EVENTS,
-            ));
-            if brick_type_id != INDESTRUCTIBLE_BRICK {
-                entity.insert(crate::CountsTowardsCompletion);
-            }
-
-            // Attach GravityBrick component for gravity bricks (21-25) when spawning bricks-only flow
-            if let Some(gravity_brick) = create_gravity_brick_component(brick_type_id) {
-                entity.insert(gravity_brick);
-            }
+            spawn_brick(
+                commands,
+                meshes,
+                brick_mat,
+                brick_type_id,
+                grid,
+                row,
+                col,
+                brick_config,
+            );
         }
     }
 }
 
+/// Keep [`GridResolution`] in step with the grid of the current level.
+pub fn sync_grid_resolution(level: Option<Res<CurrentLevel>>, mut grid: ResMut<GridResolution>) {
+    let Some(level) = level.filter(|level| level.is_changed()) else {
+        return;
+    };
+    let level_grid = level.0.grid();
+    if *grid != level_grid {
+        debug!("Level grid is {}x{}", level_grid.rows, level_grid.cols);
+        *grid = level_grid;
+    }
+}
+
+/// Spawn one brick of type `brick_type_id` (3..=255, not a bumper) in cell (`row`, `col`) of
+/// `grid`, sized to fit the cell.
+///
+/// This is the single brick spawning path: level loading, the bricks-only level advance, brick
+/// waves (`systems::waves`) and the cheat console (`systems::cheat_console`) all go through it, so
+/// every brick gets the same collider, physics and type components.
+pub fn spawn_brick(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<StandardMaterial>,
+    brick_type_id: u8,
+    grid: &GridResolution,
+    row: usize,
+    col: usize,
+    brick_config: &crate::physics_config::BrickPhysicsConfig,
+) -> Entity {
+    if let Err(err) = brick_config.validate() {
+        bevy::log::error!("Invalid BrickPhysicsConfig during brick spawn: {}", err);
+    }
+    let (half_x, half_z) = (grid.cell_height() * 0.45, grid.cell_width() * 0.45);
+    let mut mesh = Mesh::from(Cuboid::new(half_x * 2.0, 0.5, half_z * 2.0));
+    // this computes normals AND tangents so normal maps work
+    mesh.compute_area_weighted_normals(); // normals
+    mesh.generate_tangents().unwrap(); // tangents
+
+    let mesh_handle = meshes.add(mesh);
+    let mut entity = commands.spawn((
+        Mesh3d(mesh_handle),
+        MeshMaterial3d(material),
+        Transform::from_translation(grid.cell_center(row, col)),
+        Brick,
+        BrickTypeId(brick_type_id),
+        BrickGridPos { row, col },
+        RigidBody::Fixed,
+        Collider::cuboid(half_x, 0.25, half_z),
+        Restitution {
+            coefficient: brick_config.restitution,
+            combine_rule: CoefficientCombineRule::Max,
+        },
+        Friction {
+            coefficient: brick_config.friction,
+            combine_rule: CoefficientCombineRule::Max,
+        },
+        CollidingEntities::default(),
+        ActiveEvents::COLLISION_EVENTS,
+    ));
+    // Only destructible bricks contribute to level completion.
+    // Type 91 (hazard) bricks do not count toward completion.
+    if brick_type_id != INDESTRUCTIBLE_BRICK && brick_type_id != HAZARD_BRICK_91 {
+        entity.insert(CountsTowardsCompletion);
+    }
+
+    // Attach GravityBrick component for gravity bricks (21-25)
+    if let Some(gravity_brick) = create_gravity_brick_component(brick_type_id) {
+        entity.insert(gravity_brick);
+    }
+
+    if brick_type_id == INVISIBLE_BRICK {
+        entity.insert((crate::systems::Concealed, Visibility::Hidden));
+    }
+    entity.id()
+}
+
 /// Extract and set spawn points for paddle & ball from a level definition (without spawning bricks).
 /// Recomputes paddle and ball spawn points from a level definition.
 ///
@@ -834,18 +1043,17 @@ pub fn set_spawn_points_only(def: &LevelDefinition, spawn_points: &mut SpawnPoin
     spawn_points.ball = None;
     let mut paddle_set = false;
     let mut ball_set = false;
-    for (row, row_data) in def.matrix.iter().enumerate() {
-        for (col, value) in row_data.iter().enumerate() {
-            let x = -PLANE_H / 2.0 + (row as f32 + 0.5) * CELL_HEIGHT;
-            let z = PLANE_W / 2.0 - (col as f32 + 0.5) * CELL_WIDTH;
+    let grid = def.grid();
+    for (row, row_data) in def.matrix.iter().take(grid.rows).enumerate() {
+        for (col, value) in row_data.iter().take(grid.cols).enumerate() {
             match value {
                 2 if !paddle_set => {
                     paddle_set = true;
-                    spawn_points.paddle = Some(Vec3::new(x, 2.0, z));
+                    spawn_points.paddle = Some(grid.cell_center(row, col));
                 }
                 1 if !ball_set => {
                     ball_set = true;
-                    spawn_points.ball = Some(Vec3::new(x, 2.0, z));
+                    spawn_points.ball = Some(grid.cell_center(row, col));
                 }
                 _ => {}
             }
@@ -859,12 +1067,17 @@ pub fn set_spawn_points_only(def: &LevelDefinition, spawn_points: &mut SpawnPoin
     }
 }
 
-/// Advance to the next level when all bricks have been cleared.
+/// Advance to the next level when the level's completion rule is met.
 ///
-/// Only bricks with the `CountsTowardsCompletion` marker are counted.
+/// The rule (`LevelDefinition::completion`) is evaluated by
+/// [`CompletionInputs`](crate::systems::completion::CompletionInputs); by default it requires
+/// clearing every brick with the `CountsTowardsCompletion` marker.
 /// Type 91 (hazard) bricks are excluded from this marker and do not block level completion.
+/// Any bricks left when the rule is met are despawned with the rest of the field.
 fn advance_level_when_cleared(
     destructible_bricks: Query<Entity, (With<Brick>, With<crate::CountsTowardsCompletion>)>,
+    completion: crate::systems::completion::CompletionInputs,
+    campaign: crate::systems::campaign::CampaignRoute,
     bricks: Query<Entity, With<Brick>>,
     paddle_q: Query<Entity, With<Paddle>>,
     ball_q: Query<Entity, With<Ball>>,
@@ -873,19 +1086,45 @@ fn advance_level_when_cleared(
     mut commands: Commands,
     mut game_progress: ResMut<GameProgress>,
     mut level_advance: ResMut<LevelAdvanceState>,
-    ui_fonts: Option<Res<crate::ui::fonts::UiFonts>>,
     pending_merkaba_spawns: Option<ResMut<crate::systems::merkaba::PendingMerkabaSpawns>>,
+    launch: Option<Res<LaunchOptions>>,
+    mut level_files: Option<ResMut<LevelFiles>>,
 ) {
     let Some(curr) = current_level else {
         return;
     };
-    if !destructible_bricks.is_empty() {
-        return; // still destructible bricks remaining
-    }
     // If already transitioning, don't restart it.
     if level_advance.active {
         return;
     }
+    if !completion.is_complete(destructible_bricks.iter().count()) {
+        return; // completion rule not met yet
+    }
+
+    // Usually the following level; the campaign manifest can reroute it (e.g. secret exits) and
+    // the last entry of a playlist run ends the game
+    let dir = levels_dir(launch.as_deref());
+    let path = campaign
+        .next_level(curr.0.number)
+        .map(|number| level_file_path(dir, number));
+    let next_path = match path {
+        Some(path) => match level_file_listed(&path, level_files.as_deref()) {
+            Some(listed) => listed.then_some(path),
+            // The level manifest is still being fetched (wasm); tried again next frame
+            None => return,
+        },
+        None => None,
+    };
+    let file_content_result = match next_path
+        .as_deref()
+        .map(|path| read_level_file(path, level_files.as_deref_mut()))
+    {
+        Some(Ok(Some(content))) => Some(Ok(content)),
+        // The next level file is still being fetched (wasm); tried again next frame
+        Some(Ok(None)) => return,
+        Some(Err(e)) => Some(Err(e)),
+        None => None,
+    };
 
     // Emit LevelCompleted event for audio system
     commands.trigger(crate::systems::LevelCompleted {
@@ -897,58 +1136,18 @@ fn advance_level_when_cleared(
         commands.entity(entity).despawn();
     }
 
-    let next_number = curr.0.number + 1;
-    let path = format!("assets/levels/level_{:03}.ron", next_number);
-    #[cfg(not(target_arch = "wasm32"))]
-    let level_exists = std::path::Path::new(&path).exists();
-    #[cfg(target_arch = "wasm32")]
-    let level_exists = embedded_level_str(&path).is_some();
-    if !level_exists {
+    let (Some(path), Some(file_content_result)) = (next_path, file_content_result) else {
         if !game_progress.finished {
-            info!(
-                "All bricks cleared; no next level file {}. Game complete.",
-                path
-            );
+            info!("Level complete; no next level. Game complete.");
             game_progress.finished = true;
-            // Despawn remaining paddle, ball, and merkaba to freeze gameplay
-            for p in paddle_q.iter() {
-                commands.entity(p).despawn();
-            }
-            for b in ball_q.iter() {
-                commands.entity(b).despawn();
-            }
-            for m in merkaba_q.iter() {
-                commands.entity(m).despawn();
-            }
-            // Clear pending merkaba spawns to prevent spawning after game completion
-            if let Some(mut spawns) = pending_merkaba_spawns {
-                spawns.entries.clear();
-            }
-            // Spawn completion text (desktop only UI style similar to wireframe text)
-            #[cfg(not(target_arch = "wasm32"))]
-            if let Some(ui_fonts) = ui_fonts {
-                let font = ui_fonts.orbitron.clone();
-                commands.spawn((
-                    Text::new("GAME COMPLETE - Press Q to Quit"),
-                    TextFont { font, ..default() },
-                    Node {
-                        position_type: PositionType::Absolute,
-                        top: Val::Px(60.0),
-                        left: Val::Px(60.0),
-                        ..default()
-                    },
-                ));
-            }
+            // Ending the run and the score screen are up to `GameCompletePlugin`
+            commands.trigger(crate::systems::game_complete::GameCompleted {
+                last_level: curr.0.number,
+            });
         }
         return;
-    }
+    };
     // Parse and store next level; delay spawning via LevelAdvanceState.
-    #[cfg(not(target_arch = "wasm32"))]
-    let file_content_result = std::fs::read_to_string(&path);
-    #[cfg(target_arch = "wasm32")]
-    let file_content_result = embedded_level_str(&path)
-        .map(|s| s.to_string())
-        .ok_or_else(|| format!("failed to read level file '{path}': embedded asset missing"));
     match file_content_result {
         Ok(content) => match from_str::<LevelDefinition>(&content) {
             Ok(def) => {
@@ -974,15 +1173,19 @@ fn advance_level_when_cleared(
             }
             Err(e) => warn!("Failed to parse next level '{}': {e}", path),
         },
-        Err(e) => warn!("Failed to read next level file '{}': {e}", path),
+        Err(e) => warn!("Failed to read next level: {e}"),
     }
 }
 
 /// Restart the current level when the user presses R.
 // Restart messaging: small producer + heavy consumer split so registration is reliable
 
-#[derive(Message, Debug, Clone, Copy)]
-pub struct RestartRequested;
+#[derive(Message, Debug, Clone, Copy, Default)]
+pub struct RestartRequested {
+    /// Rebuild the level from its latest checkpoint (see `systems::checkpoints`) instead of from
+    /// scratch. Ignored when no checkpoint has been reached.
+    pub from_checkpoint: bool,
+}
 
 /// Producer: queue restart requests when 'R' is pressed; emits UiBeep when blocked.
 fn queue_restart_requests(
@@ -997,7 +1200,7 @@ fn queue_restart_requests(
     if let Some(cheat) = cheat.as_ref() {
         if cheat.is_active() {
             if let Some(r) = restart.as_mut() {
-                r.write(RestartRequested);
+                r.write(RestartRequested::default());
             }
         } else if let Some(b) = beep.as_mut() {
             b.write(crate::signals::UiBeep);
@@ -1023,6 +1226,9 @@ fn process_restart_requests(
     merkaba_q: Query<Entity, With<Merkaba>>,
     lives_state: Option<ResMut<crate::systems::respawn::LivesState>>,
     mut pending_merkaba_spawns: Option<ResMut<crate::systems::merkaba::PendingMerkabaSpawns>>,
+    snapshot: Option<Res<LevelSnapshot>>,
+    score_state: Option<ResMut<crate::systems::scoring::ScoreState>>,
+    checkpoints: Option<ResMut<crate::systems::checkpoints::LevelCheckpoints>>,
     #[cfg(feature = "texture_manifest")] mut tex_res: TextureResources,
     brick_config_res: Res<crate::physics_config::BrickPhysicsConfig>,
 ) {
@@ -1031,10 +1237,23 @@ fn process_restart_requests(
     }
 
     // Only process the first request per frame
-    let Some(_) = requests.read().next() else {
+    let Some(request) = requests.read().next().copied() else {
         requests.clear();
         return;
     };
+    let level_number = current_level.as_ref().map(|cl| cl.0.number).unwrap_or(1);
+    let checkpoint = match checkpoints {
+        Some(mut checkpoints) if checkpoints.level_number == Some(level_number) => {
+            if request.from_checkpoint {
+                checkpoints.reached.clone()
+            } else {
+                // A full restart starts the checkpoint run over too
+                checkpoints.clear();
+                None
+            }
+        }
+        _ => None,
+    };
 
     // Reset lives to 3 when restarting level
     if let Some(mut lives_state) = lives_state {
@@ -1042,15 +1261,54 @@ fn process_restart_requests(
     } else {
         warn!("LivesState resource missing during level restart; skipping lives reset");
     }
+    // Points earned in the abandoned attempt don't count; a checkpoint keeps those earned up to it
+    if let Some(mut score_state) = score_state {
+        match &checkpoint {
+            Some(checkpoint) => score_state.current_score = checkpoint.score,
+            None => {
+                let dropped = score_state.restart_level();
+                info!("Level restart dropped {dropped} points from this attempt");
+            }
+        }
+    }
 
-    let level_number = current_level.map(|cl| cl.0.number).unwrap_or(1);
-    let path = format!("assets/levels/level_{:03}.ron", level_number);
+    // Restart from the cached definition unless its file changed since it was parsed
+    let (path, cached) = match snapshot.as_deref() {
+        Some(snap) if snap.definition.number == level_number => {
+            let cached = (!snap.is_stale()).then(|| snap.definition.clone());
+            (snap.path.clone(), cached)
+        }
+        _ => (
+            level_file_path(levels_dir(ctx.launch.as_deref()), level_number),
+            None,
+        ),
+    };
+    if cached.is_none() {
+        info!("Level {level_number} not cached or changed on disk; reloading '{path}'");
+    }
+    // A checkpoint only replaces the bricks, so it can be laid over the authored definition
+    let cached = match (checkpoint, current_level.as_deref()) {
+        (Some(checkpoint), Some(current)) => {
+            let base = snapshot
+                .as_deref()
+                .filter(|snap| snap.definition.number == level_number)
+                .map_or_else(|| current.0.clone(), |snap| snap.definition.clone());
+            info!(
+                "Restarting level {level_number} from checkpoint {}",
+                checkpoint.index + 1
+            );
+            Some(checkpoint.apply_to(base))
+        }
+        _ => cached,
+    };
     // Despawn all bricks before restarting the level
     for entity in bricks.iter() {
         commands.entity(entity).despawn();
     }
     match force_load_level_from_path(
         &path,
+        cached,
+        ctx.level_files.as_deref_mut(),
         &mut commands,
         &mut ctx.meshes,
         &mut ctx.materials,
@@ -1124,8 +1382,14 @@ pub(crate) fn process_level_switch_requests(
     mut pending_merkaba_spawns: Option<ResMut<crate::systems::merkaba::PendingMerkabaSpawns>>,
     #[cfg(feature = "texture_manifest")] mut tex_res: TextureResources,
     brick_config_res: Res<crate::physics_config::BrickPhysicsConfig>,
+    // On wasm, a switch to a level file that is still being fetched waits for it
+    #[cfg(target_arch = "wasm32")] mut awaiting: Local<Option<LevelSlot>>,
 ) {
-    if requests.is_empty() {
+    #[cfg(target_arch = "wasm32")]
+    let awaited = awaiting.take();
+    #[cfg(not(target_arch = "wasm32"))]
+    let awaited: Option<LevelSlot> = None;
+    if requests.is_empty() && awaited.is_none() {
         return;
     }
     if switch_state.is_transition_pending() || ctx.level_advance.active {
@@ -1137,18 +1401,18 @@ pub(crate) fn process_level_switch_requests(
         return;
     }
     let current_number = current_level.map(|c| c.0.number).unwrap_or(0);
-    let Some(request) = requests.read().next() else {
-        requests.clear();
-        return;
-    };
-
-    let maybe_slot = match request.direction {
-        crate::systems::level_switch::LevelSwitchDirection::Next => {
+    // A new request replaces one waiting for its level file
+    let maybe_slot = match requests.read().next().map(|request| request.direction) {
+        Some(crate::systems::level_switch::LevelSwitchDirection::Next) => {
             switch_state.next_level_after(current_number).cloned()
         }
-        crate::systems::level_switch::LevelSwitchDirection::Previous => {
+        Some(crate::systems::level_switch::LevelSwitchDirection::Previous) => {
             switch_state.previous_level_before(current_number).cloned()
         }
+        Some(crate::systems::level_switch::LevelSwitchDirection::Jump(number)) => {
+            switch_state.level(number).cloned()
+        }
+        None => awaited,
     };
 
     let Some(target_slot) = maybe_slot else {
@@ -1156,6 +1420,15 @@ pub(crate) fn process_level_switch_requests(
         requests.clear();
         return;
     };
+    #[cfg(target_arch = "wasm32")]
+    if let Some(files) = ctx.level_files.as_deref_mut() {
+        if files.status(&target_slot.path) == LevelFileStatus::Pending {
+            files.request(&target_slot.path);
+            *awaiting = Some(target_slot);
+            requests.clear();
+            return;
+        }
+    }
     switch_state.mark_transition_start();
     // Despawn all bricks before loading the new level
     for entity in bricks.iter() {
@@ -1163,6 +1436,8 @@ pub(crate) fn process_level_switch_requests(
     }
     match force_load_level_from_path(
         &target_slot.path,
+        None,
+        ctx.level_files.as_deref_mut(),
         &mut commands,
         &mut ctx.meshes,
         &mut ctx.materials,
@@ -1213,8 +1488,10 @@ fn handle_level_advance_delay(
     #[cfg(feature = "texture_manifest")] canonical: Option<Res<CanonicalMaterialHandles>>,
     #[cfg(feature = "texture_manifest")] mut fallback: Option<ResMut<FallbackRegistry>>,
     #[cfg(feature = "texture_manifest")] type_registry: Option<Res<TypeVariantRegistry>>,
+    brick_config_res: Res<crate::physics_config::BrickPhysicsConfig>,
+    launch: Option<Res<LaunchOptions>>,
 ) {
-    if !level_advance.active || level_advance.pending.is_none() || level_advance.growth_spawned {
+    if level_advance.stage() != LevelAdvanceStage::Delay {
         return;
     }
     level_advance.timer.tick(time.delta());
@@ -1230,7 +1507,8 @@ fn handle_level_advance_delay(
     let canonical_handles = canonical.as_deref();
 
     spawn_bricks_only(
-        def,
+        &def.matrix,
+        &def.grid(),
         &mut commands,
         &mut meshes,
         &mut materials,
@@ -1240,6 +1518,7 @@ fn handle_level_advance_delay(
         fallback.as_deref_mut(),
         #[cfg(feature = "texture_manifest")]
         type_registry.as_deref(),
+        &brick_config_res,
     );
 
     // Apply per-level gravity (or keep current) immediately so it is ready when the ball unfreezes.
@@ -1376,71 +1655,49 @@ fn handle_level_advance_delay(
     }
     // At the end of fade-out, before fade-in, set CurrentLevel to the new level
     if let Some(def) = level_advance.pending.as_ref() {
+        commands.insert_resource(LevelSnapshot::capture(
+            level_file_path(levels_dir(launch.as_deref()), def.number),
+            def.clone(),
+        ));
         commands.insert_resource(CurrentLevel(def.clone()));
     }
     level_advance.growth_spawned = true;
 }
 
-/// After paddle growth ends, spawn bricks and finalize level change.
+/// After paddle growth ends, unfreeze the ball and finish the level change.
+///
+/// Runs after `handle_level_advance_delay` (so the spawned paddle and ball are visible) and after
+/// `stabilize_frozen_balls`, so removing `BallFrozen` and `Velocity` together in one batch leaves
+/// nothing that could re-freeze the ball next frame.
 fn finalize_level_advance(
     paddles_growing: Query<&crate::PaddleGrowing>,
     mut level_advance: ResMut<LevelAdvanceState>,
     mut commands: Commands,
     mut rapier_config: Query<&mut RapierConfiguration>,
     gravity_cfg: Res<GravityConfig>,
-    mut balls: Query<(Entity, &mut GravityScale, Option<&Velocity>), With<Ball>>,
+    mut balls: Query<(Entity, &mut GravityScale), With<Ball>>,
 ) {
-    if !level_advance.active
-        || !level_advance.growth_spawned
-        || level_advance.pending.is_none()
-        // If paddles are still growing, wait
-        || !paddles_growing.is_empty()
-    {
-        return;
-    }
-
-    // Check if we can actually see the spawned ball (commands have been applied)
-    let ball_count = balls.iter().count();
-    if ball_count == 0 {
-        // Ball hasn't been spawned yet (commands not applied), wait for next frame
+    if level_advance.stage() != LevelAdvanceStage::Growing || !paddles_growing.is_empty() {
         return;
     }
 
-    // Two-stage unfreezing process:
-    // Stage 1: Remove BallFrozen, wait one frame for stabilize_frozen_balls to stop acting
-    // Stage 2: Remove Velocity component and activate gravity
-
-    if !level_advance.unfreezing {
-        // Stage 1: Remove BallFrozen marker
-        for (entity, _gravity_scale, _velocity) in balls.iter_mut() {
-            commands.entity(entity).remove::<crate::BallFrozen>();
-        }
-        level_advance.unfreezing = true;
-        return; // Wait one frame for BallFrozen removal to take effect
-    }
-
-    // Stage 2: Now BallFrozen is removed, stabilize_frozen_balls won't act anymore
-    let _ = level_advance.pending.take();
-
-    for (entity, mut gravity_scale, _velocity) in balls.iter_mut() {
-        // Remove Velocity component so Rapier manages it internally (like R/L do)
-        commands.entity(entity).remove::<Velocity>();
-        // Give tiny impulse to wake up Rapier's internal physics state
-        // (Rapier stored zero velocity internally while we were freezing the ball)
-        commands.entity(entity).insert(ExternalImpulse {
-            impulse: Vec3::new(0.0001, 0.0, 0.0001),
-            torque_impulse: Vec3::ZERO,
-        });
+    for (entity, mut gravity_scale) in balls.iter_mut() {
+        // Remove Velocity so Rapier manages it internally (like R/L do), and give a tiny impulse
+        // to wake up Rapier's internal state (it stored zero velocity while the ball was frozen)
+        commands
+            .entity(entity)
+            .remove::<(crate::BallFrozen, Velocity)>()
+            .insert(ExternalImpulse {
+                impulse: Vec3::new(0.0001, 0.0, 0.0001),
+                torque_impulse: Vec3::ZERO,
+            });
         gravity_scale.0 = 1.0; // Activate gravity
     }
     // Restore gravity to new level's normal.
     if let Ok(mut config) = rapier_config.single_mut() {
         config.gravity = gravity_cfg.normal;
     }
-    // Reset state.
-    level_advance.active = false;
-    level_advance.growth_spawned = false;
-    level_advance.unfreezing = false;
+    level_advance.finish();
 }
 
 /// Spawn fade overlay once when level advancement begins.
@@ -1495,8 +1752,13 @@ fn update_fade_overlay(
     }
 }
 
+/// Reset the level and load it from `path`, or from `cached` when given (no file IO).
+///
+/// On wasm the file must have been fetched already (see `systems::level_fetch`).
 fn force_load_level_from_path(
     path: &str,
+    cached: Option<LevelDefinition>,
+    level_files: Option<&mut LevelFiles>,
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
@@ -1526,14 +1788,17 @@ fn force_load_level_from_path(
         level_advance,
         pending_merkaba_spawns,
     );
-    #[cfg(not(target_arch = "wasm32"))]
-    let content = std::fs::read_to_string(path)
-        .map_err(|err| format!("failed to read level file '{path}': {err}"))?;
-    #[cfg(target_arch = "wasm32")]
-    let content = embedded_level_str(path)
-        .ok_or_else(|| format!("failed to read level file '{path}': embedded asset missing"))?;
-    let def = from_str::<LevelDefinition>(&content)
-        .map_err(|err| format!("failed to parse level '{path}': {err}"))?;
+    let def = match cached {
+        Some(def) => def,
+        None => {
+            let content = read_level_file(path, level_files)?
+                .ok_or_else(|| format!("level file '{path}' is still being fetched"))?;
+            let def = from_str::<LevelDefinition>(&content)
+                .map_err(|err| format!("failed to parse level '{path}': {err}"))?;
+            commands.insert_resource(LevelSnapshot::capture(path, def.clone()));
+            def
+        }
+    };
     apply_level_definition(
         &def,
         commands,
@@ -1554,91 +1819,6 @@ fn force_load_level_from_path(
     Ok(def)
 }
 
-// Embedded level RON contents for WASM builds (no filesystem access).
-#[cfg(target_arch = "wasm32")]
-fn embedded_level_str(path: &str) -> Option<&'static str> {
-    match path {
-        "assets/levels/level_001.ron" => Some(include_str!("../assets/levels/level_001.ron")),
-        "assets/levels/level_002.ron" => Some(include_str!("../assets/levels/level_002.ron")),
-        "assets/levels/level_003.ron" => Some(include_str!("../assets/levels/level_003.ron")),
-        "assets/levels/level_004.ron" => Some(include_str!("../assets/levels/level_004.ron")),
-        "assets/levels/level_005.ron" => Some(include_str!("../assets/levels/level_005.ron")),
-        "assets/levels/level_006.ron" => Some(include_str!("../assets/levels/level_006.ron")),
-        "assets/levels/level_007.ron" => Some(include_str!("../assets/levels/level_007.ron")),
-        "assets/levels/level_008.ron" => Some(include_str!("../assets/levels/level_008.ron")),
-        "assets/levels/level_009.ron" => Some(include_str!("../assets/levels/level_009.ron")),
-        "assets/levels/level_010.ron" => Some(include_str!("../assets/levels/level_010.ron")),
-        "assets/levels/level_011.ron" => Some(include_str!("../assets/levels/level_011.ron")),
-        "assets/levels/level_012.ron" => Some(include_str!("../assets/levels/level_012.ron")),
-        "assets/levels/level_013.ron" => Some(include_str!("../assets/levels/level_013.ron")),
-        "assets/levels/level_014.ron" => Some(include_str!("../assets/levels/level_014.ron")),
-        "assets/levels/level_015.ron" => Some(include_str!("../assets/levels/level_015.ron")),
-        "assets/levels/level_016.ron" => Some(include_str!("../assets/levels/level_016.ron")),
-        "assets/levels/level_017.ron" => Some(include_str!("../assets/levels/level_017.ron")),
-        "assets/levels/level_018.ron" => Some(include_str!("../assets/levels/level_018.ron")),
-        "assets/levels/level_019.ron" => Some(include_str!("../assets/levels/level_019.ron")),
-        "assets/levels/level_020.ron" => Some(include_str!("../assets/levels/level_020.ron")),
-        "assets/levels/level_021.ron" => Some(include_str!("../assets/levels/level_021.ron")),
-        "assets/levels/level_022.ron" => Some(include_str!("../assets/levels/level_022.ron")),
-        "assets/levels/level_023.ron" => Some(include_str!("../assets/levels/level_023.ron")),
-        "assets/levels/level_024.ron" => Some(include_str!("../assets/levels/level_024.ron")),
-        "assets/levels/level_025.ron" => Some(include_str!("../assets/levels/level_025.ron")),
-        "assets/levels/level_026.ron" => Some(include_str!("../assets/levels/level_026.ron")),
-        "assets/levels/level_027.ron" => Some(include_str!("../assets/levels/level_027.ron")),
-        "assets/levels/level_028.ron" => Some(include_str!("../assets/levels/level_028.ron")),
-        "assets/levels/level_029.ron" => Some(include_str!("../assets/levels/level_029.ron")),
-        "assets/levels/level_030.ron" => Some(include_str!("../assets/levels/level_030.ron")),
-        "assets/levels/level_031.ron" => Some(include_str!("../assets/levels/level_031.ron")),
-        "assets/levels/level_032.ron" => Some(include_str!("../assets/levels/level_032.ron")),
-        "assets/levels/level_033.ron" => Some(include_str!("../assets/levels/level_033.ron")),
-        "assets/levels/level_034.ron" => Some(include_str!("../assets/levels/level_034.ron")),
-        "assets/levels/level_035.ron" => Some(include_str!("../assets/levels/level_035.ron")),
-        "assets/levels/level_036.ron" => Some(include_str!("../assets/levels/level_036.ron")),
-        "assets/levels/level_037.ron" => Some(include_str!("../assets/levels/level_037.ron")),
-        "assets/levels/level_038.ron" => Some(include_str!("../assets/levels/level_038.ron")),
-        "assets/levels/level_039.ron" => Some(include_str!("../assets/levels/level_039.ron")),
-        "assets/levels/level_040.ron" => Some(include_str!("../assets/levels/level_040.ron")),
-        "assets/levels/level_041.ron" => Some(include_str!("../assets/levels/level_041.ron")),
-        "assets/levels/level_042.ron" => Some(include_str!("../assets/levels/level_042.ron")),
-        "assets/levels/level_043.ron" => Some(include_str!("../assets/levels/level_043.ron")),
-        "assets/levels/level_044.ron" => Some(include_str!("../assets/levels/level_044.ron")),
-        "assets/levels/level_045.ron" => Some(include_str!("../assets/levels/level_045.ron")),
-        "assets/levels/level_046.ron" => Some(include_str!("../assets/levels/level_046.ron")),
-        "assets/levels/level_047.ron" => Some(include_str!("../assets/levels/level_047.ron")),
-        "assets/levels/level_048.ron" => Some(include_str!("../assets/levels/level_048.ron")),
-        "assets/levels/level_049.ron" => Some(include_str!("../assets/levels/level_049.ron")),
-        "assets/levels/level_050.ron" => Some(include_str!("../assets/levels/level_050.ron")),
-        "assets/levels/level_051.ron" => Some(include_str!("../assets/levels/level_051.ron")),
-        "assets/levels/level_052.ron" => Some(include_str!("../assets/levels/level_052.ron")),
-        "assets/levels/level_053.ron" => Some(include_str!("../assets/levels/level_053.ron")),
-        "assets/levels/level_054.ron" => Some(include_str!("../assets/levels/level_054.ron")),
-        "assets/levels/level_055.ron" => Some(include_str!("../assets/levels/level_055.ron")),
-        "assets/levels/level_056.ron" => Some(include_str!("../assets/levels/level_056.ron")),
-        "assets/levels/level_057.ron" => Some(include_str!("../assets/levels/level_057.ron")),
-        "assets/levels/level_058.ron" => Some(include_str!("../assets/levels/level_058.ron")),
-        "assets/levels/level_059.ron" => Some(include_str!("../assets/levels/level_059.ron")),
-        "assets/levels/level_060.ron" => Some(include_str!("../assets/levels/level_060.ron")),
-        "assets/levels/level_061.ron" => Some(include_str!("../assets/levels/level_061.ron")),
-        "assets/levels/level_062.ron" => Some(include_str!("../assets/levels/level_062.ron")),
-        "assets/levels/level_063.ron" => Some(include_str!("../assets/levels/level_063.ron")),
-        "assets/levels/level_064.ron" => Some(include_str!("../assets/levels/level_064.ron")),
-        "assets/levels/level_065.ron" => Some(include_str!("../assets/levels/level_065.ron")),
-        "assets/levels/level_066.ron" => Some(include_str!("../assets/levels/level_066.ron")),
-        "assets/levels/level_067.ron" => Some(include_str!("../assets/levels/level_067.ron")),
-        "assets/levels/level_068.ron" => Some(include_str!("../assets/levels/level_068.ron")),
-        "assets/levels/level_069.ron" => Some(include_str!("../assets/levels/level_069.ron")),
-        "assets/levels/level_070.ron" => Some(include_str!("../assets/levels/level_070.ron")),
-        "assets/levels/level_071.ron" => Some(include_str!("../assets/levels/level_071.ron")),
-        "assets/levels/level_072.ron" => Some(include_str!("../assets/levels/level_072.ron")),
-        "assets/levels/level_073.ron" => Some(include_str!("../assets/levels/level_073.ron")),
-        "assets/levels/level_074.ron" => Some(include_str!("../assets/levels/level_074.ron")),
-        "assets/levels/level_997.ron" => Some(include_str!("../assets/levels/level_997.ron")),
-        "assets/levels/level_998.ron" => Some(include_str!("../assets/levels/level_998.ron")),
-        "assets/levels/level_999.ron" => Some(include_str!("../assets/levels/level_999.ron")),
-        _ => None,
-    }
-}
-
 fn reset_level_state(
     commands: &mut Commands,
     bricks: &Query<Entity, With<Brick>>,
@@ -1669,9 +1849,7 @@ fn reset_level_state(
     spawn_points.paddle = None;
     spawn_points.ball = None;
     game_progress.finished = false;
-    level_advance.active = false;
-    level_advance.pending = None;
-    level_advance.growth_spawned = false;
+    level_advance.finish();
 }
 
 fn apply_level_definition(
@@ -1859,6 +2037,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn snapshot_goes_stale_when_the_file_changes() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+        let def: super::LevelDefinition =
+            ron::de::from_str("LevelDefinition(number: 7, matrix: [])").unwrap();
+        let snapshot = super::LevelSnapshot::capture(&path, def);
+        assert!(snapshot.modified.is_some());
+        assert!(!snapshot.is_stale());
+
+        // Bump the modification time explicitly; some filesystems have coarse timestamps
+        let later = snapshot.modified.unwrap() + std::time::Duration::from_secs(5);
+        file.as_file().set_modified(later).unwrap();
+        assert!(snapshot.is_stale());
+
+        drop(file);
+        assert!(snapshot.is_stale(), "a deleted file is treated as changed");
+    }
+
     // Unit tests for restart gating
 
     use super::*;
@@ -1946,4 +2143,218 @@ mod tests {
         let beep = app.world().resource::<BeepCount>();
         assert_eq!(beep.0, 0, "Allowed restart should not emit a UI beep");
     }
+
+    // Level advance determinism
+
+    /// Per-frame trace of (stage, ball count, any ball still frozen, any paddle still growing),
+    /// from clearing level 1 until well after level 2 has started.
+    ///
+    /// Runs the game's own schedule: `BrkrsCorePlugin` and `LevelLoaderPlugin`, on two small level
+    /// files.
+    fn run_level_advance() -> Vec<(LevelAdvanceStage, usize, bool, bool)> {
+        let levels = tempfile::tempdir().unwrap();
+        for number in [1, 2] {
+            std::fs::write(
+                levels.path().join(format!("level_{number:03}.ron")),
+                format!("LevelDefinition(number: {number}, matrix: [[2, 1, 20]])"),
+            )
+            .unwrap();
+        }
+        let config = crate::BrkrsConfig::default()
+            .with_window_management(false)
+            .with_launch_options(LaunchOptions {
+                levels_dir: levels.path().to_path_buf(),
+                ..default()
+            });
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::input::InputPlugin));
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(0.25),
+        ));
+        app.insert_resource(Assets::<Mesh>::default());
+        app.insert_resource(Assets::<StandardMaterial>::default());
+        app.insert_resource(Assets::<Image>::default());
+        app.insert_resource(SpawnPoints::default());
+        app.init_resource::<crate::systems::respawn::FrameLossState>();
+        app.init_resource::<crate::systems::respawn::LivesState>();
+        app.add_message::<crate::systems::respawn::LifeLostEvent>();
+        #[cfg(feature = "texture_manifest")]
+        app.init_resource::<TypeVariantRegistry>();
+        app.add_plugins(crate::systems::level_switch::LevelSwitchPlugin);
+        app.add_plugins(crate::BrkrsCorePlugin { config });
+        app.add_plugins(LevelLoaderPlugin);
+        app.update();
+
+        // Clear level 1: the loader starts the advance to level 2 by itself
+        let world = app.world_mut();
+        let bricks: Vec<Entity> = world
+            .query_filtered::<Entity, With<crate::CountsTowardsCompletion>>()
+            .iter(world)
+            .collect();
+        assert!(!bricks.is_empty(), "level 1 was loaded");
+        for brick in bricks {
+            world.despawn(brick);
+        }
+
+        let mut trace = Vec::new();
+        for _ in 0..24 {
+            app.update();
+            let stage = app.world().resource::<LevelAdvanceState>().stage();
+            let world = app.world_mut();
+            let balls = world
+                .query_filtered::<Entity, With<Ball>>()
+                .iter(world)
+                .count();
+            let frozen = world
+                .query_filtered::<Entity, With<crate::BallFrozen>>()
+                .iter(world)
+                .count();
+            let growing = world
+                .query_filtered::<Entity, With<crate::PaddleGrowing>>()
+                .iter(world)
+                .count();
+            trace.push((stage, balls, frozen > 0, growing > 0));
+        }
+        trace
+    }
+
+    #[test]
+    fn level_advance_unfreezes_in_the_frame_growth_ends() {
+        let trace = run_level_advance();
+        let growing = trace
+            .iter()
+            .position(|(stage, ..)| *stage == LevelAdvanceStage::Growing)
+            .expect("advance reaches the growing stage");
+        let idle = growing
+            + trace[growing..]
+                .iter()
+                .position(|(stage, ..)| *stage == LevelAdvanceStage::Idle)
+                .expect("advance finishes");
+        assert!(trace[..growing]
+            .iter()
+            .all(|(stage, balls, ..)| *stage == LevelAdvanceStage::Delay && *balls == 0));
+        // The ball exists from the first growing frame and stays frozen while the paddle grows
+        assert!(trace[growing..idle]
+            .iter()
+            .all(|(_, balls, frozen, growing)| *balls == 1 && *frozen && *growing));
+        // The frame the growth completes also unfreezes the ball
+        assert!(trace[idle..]
+            .iter()
+            .all(
+                |(stage, balls, frozen, growing)| *stage == LevelAdvanceStage::Idle
+                    && *balls == 1
+                    && !*frozen
+                    && !*growing
+            ));
+    }
+
+    #[test]
+    fn level_advance_is_deterministic() {
+        assert_eq!(run_level_advance(), run_level_advance());
+    }
+
+    // Property-based tests: spawning arbitrary ragged matrices
+
+    use bevy::ecs::system::RunSystemOnce;
+    use proptest::prelude::*;
+
+    /// Spawn `matrix` into a fresh world through `spawn_level_entities_impl`.
+    fn spawn_matrix(matrix: Vec<Vec<u8>>) -> World {
+        let mut def: LevelDefinition =
+            ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+        def.matrix = matrix;
+        spawn_definition(def)
+    }
+
+    fn spawn_definition(def: LevelDefinition) -> World {
+        let mut world = World::new();
+        world.insert_resource(Assets::<Mesh>::default());
+        world.insert_resource(Assets::<StandardMaterial>::default());
+        world.insert_resource(SpawnPoints::default());
+        world.insert_resource(crate::physics_config::BrickPhysicsConfig::default());
+        world
+            .run_system_once(
+                move |mut commands: Commands,
+                      mut meshes: ResMut<Assets<Mesh>>,
+                      mut materials: ResMut<Assets<StandardMaterial>>,
+                      mut spawn_points: ResMut<SpawnPoints>,
+                      brick_config: Res<crate::physics_config::BrickPhysicsConfig>| {
+                    spawn_level_entities_impl(
+                        &def,
+                        &mut commands,
+                        &mut meshes,
+                        &mut materials,
+                        &mut spawn_points,
+                        #[cfg(feature = "texture_manifest")]
+                        None,
+                        #[cfg(feature = "texture_manifest")]
+                        None,
+                        #[cfg(feature = "texture_manifest")]
+                        None,
+                        brick_config,
+                    );
+                },
+            )
+            .unwrap();
+        world
+    }
+
+    #[test]
+    fn declared_resolutions_lay_bricks_out_on_finer_cells() {
+        let mut def: LevelDefinition =
+            ron::de::from_str("LevelDefinition(number: 1, resolution: Some((40, 40)), matrix: [])")
+                .unwrap();
+        let grid = def.grid();
+        assert_eq!(grid, GridResolution::new(40, 40));
+        def.matrix = normalize_matrix_to(vec![vec![20; 40]; 40], (40, 40)).matrix;
+        let mut world = spawn_definition(def);
+
+        let mut bricks = world.query_filtered::<(&Transform, &BrickGridPos), With<Brick>>();
+        assert_eq!(bricks.iter(&world).count(), 40 * 40);
+        for (transform, pos) in bricks.iter(&world) {
+            assert_eq!(transform.translation, grid.cell_center(pos.row, pos.col));
+        }
+        let corner = bricks
+            .iter(&world)
+            .find(|(_, pos)| pos.row == 39 && pos.col == 39)
+            .unwrap();
+        assert!((corner.0.translation.x - (PLANE_H / 2.0 - grid.cell_height() / 2.0)).abs() < 1e-4);
+        assert_eq!(grid.cell_at(corner.0.translation), Some((39, 39)));
+    }
+
+    proptest! {
+        // Each case builds a world; fewer cases keep the suite fast
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn spawning_ragged_matrices_keeps_entities_on_the_field(
+            matrix in prop::collection::vec(prop::collection::vec(any::<u8>(), 0..30), 0..30),
+            normalize in any::<bool>(),
+        ) {
+            let matrix = if normalize {
+                crate::level_format::normalize_matrix_simple(matrix)
+            } else {
+                matrix
+            };
+            let mut world = spawn_matrix(matrix);
+
+            let paddles = world.query_filtered::<(), With<Paddle>>().iter(&world).count();
+            let balls = world.query_filtered::<(), With<Ball>>().iter(&world).count();
+            prop_assert_eq!(paddles, 1);
+            prop_assert_eq!(balls, 1);
+
+            let mut bricks = world.query_filtered::<(&Transform, &BrickGridPos), With<Brick>>();
+            for (transform, pos) in bricks.iter(&world) {
+                prop_assert!(
+                    pos.row < crate::level_format::TARGET_ROWS
+                        && pos.col < crate::level_format::TARGET_COLS,
+                    "{:?}",
+                    pos
+                );
+                let t = transform.translation;
+                prop_assert!(t.x.abs() <= PLANE_H / 2.0 && t.z.abs() <= PLANE_W / 2.0, "{:?}", t);
+            }
+        }
+    }
 }
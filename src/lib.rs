@@ -1,32 +1,31 @@
+pub mod gameplay_config;
 pub mod physics_config;
 // brkrs: see README.md for usage and controls.
 
+pub mod input_map;
+pub mod launch;
 pub mod level_format;
 pub mod level_loader;
 pub mod pause;
+pub mod plugins;
 pub mod signals;
 pub mod systems;
 pub mod ui;
 
+pub use launch::LaunchOptions;
 pub use level_loader::extract_author_name;
+pub use plugins::{BrkrsConfig, BrkrsPlugins};
 
-#[cfg(feature = "texture_manifest")]
-use crate::systems::TextureManifestPlugin;
-use crate::systems::{
-    AudioPlugin, InputLocked, LevelSwitchPlugin, MerkabaPlugin, PaddleSizePlugin, RespawnPlugin,
-    RespawnSystems,
-};
+use crate::systems::{InputLocked, RespawnSystems};
 
 #[cfg(not(target_arch = "wasm32"))]
-use bevy::pbr::wireframe::{WireframeConfig, WireframePlugin};
-#[cfg(not(target_arch = "wasm32"))]
-use bevy::window::MonitorSelection;
+use bevy::pbr::wireframe::WireframeConfig;
 use bevy::{
     color::palettes::css::RED,
     ecs::message::MessageWriter,
     input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
     prelude::*,
-    window::{CursorGrabMode, CursorOptions, PrimaryWindow, Window, WindowMode, WindowPlugin},
+    window::{CursorGrabMode, CursorOptions, PrimaryWindow, Window},
 };
 use bevy_rapier3d::prelude::*;
 
@@ -55,7 +54,8 @@ const CAMERA_SHAKE_MAX_INTENSITY: f32 = 10.0;
 // Paddle growth animation duration
 const PADDLE_GROWTH_DURATION: f32 = 2.0;
 
-// Grid debug overlay constants (20x20 grid covering PLANE_H × PLANE_W)
+// Standard level grid (20x20 cells covering PLANE_H × PLANE_W); see `GridResolution` for levels
+// declaring another resolution
 const GRID_WIDTH: usize = 20; // Columns (Z-axis)
 const GRID_HEIGHT: usize = 20; // Rows (X-axis)
 const CELL_WIDTH: f32 = PLANE_W / GRID_WIDTH as f32; // 2.0 (Z dimension)
@@ -80,6 +80,11 @@ pub struct BallTypeId(pub u8);
 #[require(Transform, Visibility)]
 pub struct Border;
 
+/// Marker for the left and right walls, which levels with `wrap_sides` turn into portals
+/// (see `systems::portal_edges`).
+#[derive(Component)]
+pub struct SideWall;
+
 pub use systems::spawning::{GroundPlane, MainCamera};
 
 #[derive(Component)]
@@ -95,6 +100,66 @@ pub struct Brick;
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct BrickTypeId(pub u8);
 
+/// Row/column of a brick in the level matrix, used for neighbour lookups (e.g. explosions).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BrickGridPos {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// Rows and columns of the current level grid, which always covers the whole play area.
+///
+/// Levels use the standard 20x20 grid unless they declare a `resolution`; cell sizes follow from
+/// the play area, so a 40x40 level has cells half as large on each side.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridResolution {
+    /// Cells along X
+    pub rows: usize,
+    /// Cells along Z
+    pub cols: usize,
+}
+
+impl Default for GridResolution {
+    fn default() -> Self {
+        Self::new(GRID_HEIGHT, GRID_WIDTH)
+    }
+}
+
+impl GridResolution {
+    pub const fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols }
+    }
+
+    /// Cell size along X.
+    pub fn cell_height(&self) -> f32 {
+        PLANE_H / self.rows as f32
+    }
+
+    /// Cell size along Z.
+    pub fn cell_width(&self) -> f32 {
+        PLANE_W / self.cols as f32
+    }
+
+    /// World-space centre of grid cell (`row`, `col`) at brick height.
+    pub fn cell_center(&self, row: usize, col: usize) -> Vec3 {
+        Vec3::new(
+            -PLANE_H / 2.0 + (row as f32 + 0.5) * self.cell_height(),
+            2.0,
+            PLANE_W / 2.0 - (col as f32 + 0.5) * self.cell_width(),
+        )
+    }
+
+    /// Grid cell (`row`, `col`) containing `position`, or `None` outside the grid; the inverse of
+    /// [`GridResolution::cell_center`].
+    pub fn cell_at(&self, position: Vec3) -> Option<(usize, usize)> {
+        let row = ((position.x + PLANE_H / 2.0) / self.cell_height()).floor();
+        let col = ((PLANE_W / 2.0 - position.z) / self.cell_width()).floor();
+        let inside =
+            (0.0..self.rows as f32).contains(&row) && (0.0..self.cols as f32).contains(&col);
+        inside.then_some((row as usize, col as usize))
+    }
+}
+
 #[derive(Component)]
 pub struct MarkedForDespawn;
 #[derive(Component)]
@@ -209,126 +274,111 @@ impl Default for GravityConfiguration {
     }
 }
 
+/// Run brkrs as a standalone game: a borderless fullscreen window on native platforms.
+///
+/// Other Bevy apps embed the game through [`BrkrsPlugins`] instead, see [`plugins`].
 pub fn run() {
-    let mut app = App::new();
-    // Register BallWallHit as an event so the observer is active from the start
-    // ...existing code...
-
-    app.init_resource::<GravityConfiguration>();
-    app.insert_resource(GravityConfig::default());
-    app.insert_resource(GameProgress::default());
-    // Physics config resources
-    app.insert_resource(crate::physics_config::BallPhysicsConfig::default());
-    app.insert_resource(crate::physics_config::PaddlePhysicsConfig::default());
-    app.insert_resource(crate::physics_config::BrickPhysicsConfig::default());
-    // Scoring system state
-    app.init_resource::<systems::scoring::ScoreState>();
-    app.add_message::<crate::signals::BrickDestroyed>();
-    // Per-frame dedupe set for BrickDestroyed emissions
-    app.init_resource::<EmittedBrickDestroyed>();
-    // Clear the dedupe set at the start of each frame before collision systems run
-    app.add_systems(
-        Update,
-        clear_emitted_brick_destroyed.before(mark_brick_on_ball_collision),
-    );
-    app.add_message::<crate::signals::SpawnMerkabaMessage>();
-    app.add_message::<crate::signals::LifeAwardMessage>();
-    app.add_message::<systems::scoring::MilestoneReached>();
-    app.add_message::<bevy_rapier3d::prelude::CollisionEvent>();
-    app.insert_resource(level_loader::LevelAdvanceState::default());
-    app.add_plugins((
-        DefaultPlugins
-            .set(ImagePlugin::default_nearest())
-            .set(WindowPlugin {
-                primary_window: Some(Window {
-                    title: "brkrs".to_string(),
-                    #[cfg(not(target_arch = "wasm32"))]
-                    mode: WindowMode::BorderlessFullscreen(MonitorSelection::Current),
-                    #[cfg(target_arch = "wasm32")]
-                    mode: WindowMode::Windowed,
-                    ..default()
-                }),
-                ..default()
-            }),
-        #[cfg(not(target_arch = "wasm32"))]
-        WireframePlugin::default(),
-    ));
-    app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default());
-    app.add_plugins(LevelSwitchPlugin);
-    app.add_plugins(crate::level_loader::LevelLoaderPlugin);
-    // app.add_plugins(RapierDebugRenderPlugin::default());
-    app.add_plugins(RespawnPlugin);
-    app.add_plugins(crate::pause::PausePlugin);
-    // Register BallWallHit as an event so the observer is active before AudioPlugin
-    app.add_message::<crate::signals::BallWallHit>();
-    app.add_plugins(AudioPlugin);
-    app.add_plugins(MerkabaPlugin);
-    app.add_plugins(PaddleSizePlugin);
-    // Cheat mode plugin (feature: toggle, indicator, gated level controls)
-    app.add_plugins(systems::CheatModePlugin);
-
-    #[cfg(feature = "texture_manifest")]
-    {
-        app.add_plugins(TextureManifestPlugin);
-    }
-
-    // FontsPlugin wires platform-appropriate font loading systems
-    app.add_plugins(crate::ui::fonts::FontsPlugin);
-    // UI plugin (Constitution VIII: Plugin-Based Architecture)
-    app.add_plugins(crate::ui::UiPlugin);
+    BrkrsConfig::default().run();
+}
 
-    app.add_systems(
-        Startup,
-        (
-            setup,
-            spawn_border,
-            systems::grid_debug::spawn_grid_overlay,
-            systems::spawning::spawn_camera,
-            systems::spawning::spawn_ground_plane,
-            systems::spawning::spawn_light,
-        )
-            .chain(),
-    );
+/// Core gameplay: shared resources and messages, the paddle, ball, brick and camera systems, and
+/// the field spawned at startup. First member of [`BrkrsPlugins`].
+pub(crate) struct BrkrsCorePlugin {
+    pub config: BrkrsConfig,
+}
 
-    app.add_systems(
-        Update,
-        (
-            move_paddle
-                .after(RespawnSystems::Control)
-                .run_if(crate::pause::not_paused),
-            limit_ball_velocity,
-            update_camera_shake,
-            update_paddle_growth,
-            stabilize_frozen_balls.before(crate::level_loader::LevelAdvanceSystems),
-            restore_gravity_post_growth,
-            #[cfg(not(target_arch = "wasm32"))]
-            toggle_wireframe,
-            #[cfg(not(target_arch = "wasm32"))]
-            systems::grid_debug::toggle_grid_visibility,
-            grab_mouse,
-            crate::systems::respawn::clear_life_loss_frame_flag,
-            read_character_controller_collisions,
-            detect_ball_wall_collisions,
-            // Chain brick-hit handling, despawn, and life award application to guarantee ordering
+impl Plugin for BrkrsCorePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone());
+        app.insert_resource(self.config.launch.clone());
+        app.add_systems(Startup, launch::apply_launch_options);
+        app.init_resource::<GravityConfiguration>();
+        app.init_resource::<GridResolution>();
+        app.insert_resource(GravityConfig::default());
+        app.insert_resource(GameProgress::default());
+        // Physics config resources
+        app.insert_resource(crate::physics_config::BallPhysicsConfig::default());
+        app.insert_resource(crate::physics_config::PaddlePhysicsConfig::default());
+        app.insert_resource(crate::physics_config::BrickPhysicsConfig::default());
+        // Scoring system state
+        app.init_resource::<systems::scoring::ScoreState>();
+        app.init_resource::<systems::scoring::ChainTracker>();
+        app.add_message::<crate::signals::BrickDestroyed>();
+        // Per-frame dedupe set for BrickDestroyed emissions
+        app.init_resource::<EmittedBrickDestroyed>();
+        // Clear the dedupe set at the start of each frame before collision systems run
+        app.add_systems(
+            Update,
+            clear_emitted_brick_destroyed.before(mark_brick_on_ball_collision),
+        );
+        app.add_message::<crate::signals::SpawnMerkabaMessage>();
+        app.add_message::<crate::signals::LifeAwardMessage>();
+        app.add_message::<systems::scoring::MilestoneReached>();
+        app.add_message::<systems::scoring::ChainBonusAwarded>();
+        app.add_message::<bevy_rapier3d::prelude::CollisionEvent>();
+        app.insert_resource(level_loader::LevelAdvanceState::default());
+        // Register BallWallHit as an event so the observer is active before AudioPlugin
+        app.add_message::<crate::signals::BallWallHit>();
+
+        app.add_systems(
+            Startup,
             (
-                mark_brick_on_ball_collision,
-                despawn_marked_entities,
-                crate::systems::respawn::apply_life_awards,
+                setup,
+                spawn_border,
+                systems::spawning::spawn_camera,
+                systems::spawning::spawn_ground_plane,
+                systems::spawning::spawn_light,
             )
                 .chain(),
-        ),
-    );
+        );
 
-    add_scoring_systems(&mut app);
+        app.add_systems(
+            Update,
+            (
+                move_paddle
+                    .after(RespawnSystems::Control)
+                    .run_if(crate::pause::not_paused),
+                limit_ball_velocity,
+                update_camera_shake,
+                // Growth ends before the level advance looks for growing paddles, so the ball is
+                // unfrozen in the frame the growth completes
+                update_paddle_growth.before(crate::level_loader::LevelAdvanceSystems),
+                stabilize_frozen_balls.before(crate::level_loader::LevelAdvanceSystems),
+                restore_gravity_post_growth,
+                systems::grid_debug::spawn_grid_overlay,
+                // The wireframe toggles need `WireframePlugin`, which embedding apps may disable
+                #[cfg(not(target_arch = "wasm32"))]
+                toggle_wireframe.run_if(resource_exists::<WireframeConfig>),
+                #[cfg(not(target_arch = "wasm32"))]
+                systems::grid_debug::toggle_grid_visibility
+                    .after(systems::grid_debug::spawn_grid_overlay)
+                    .run_if(resource_exists::<WireframeConfig>),
+                grab_mouse.run_if(plugins::manages_window),
+                crate::systems::respawn::clear_life_loss_frame_flag,
+                read_character_controller_collisions,
+                detect_ball_wall_collisions,
+                // Chain brick-hit handling, despawn, and life award application to guarantee ordering
+                (
+                    mark_brick_on_ball_collision,
+                    // After the hit handling, which marks the destroyed bricks
+                    detect_ball_brick_bounces,
+                    despawn_marked_entities,
+                    crate::systems::respawn::apply_life_awards,
+                )
+                    .chain(),
+            ),
+        );
 
-    // Texture manifest system (conditional on feature flag)
-    #[cfg(feature = "texture_manifest")]
-    app.add_systems(Update, systems::multi_hit::watch_brick_type_changes);
+        add_scoring_systems(app);
 
-    add_core_observers(&mut app);
-    add_gravity_feature(&mut app);
-    // Note: Multi-hit brick sound observer is now registered by AudioPlugin
-    app.run();
+        // Texture manifest system (conditional on feature flag)
+        #[cfg(feature = "texture_manifest")]
+        app.add_systems(Update, systems::multi_hit::watch_brick_type_changes);
+
+        add_core_observers(app);
+        add_gravity_feature(app);
+        // Note: Multi-hit brick sound observer is now registered by AudioPlugin
+    }
 }
 
 fn add_scoring_systems(app: &mut App) {
@@ -336,7 +386,9 @@ fn add_scoring_systems(app: &mut App) {
     app.add_systems(
         Update,
         (
+            systems::scoring::track_level_scores,
             systems::scoring::award_points_system,
+            systems::scoring::track_brick_chains,
             systems::scoring::detect_milestone_system,
             systems::respawn::award_milestone_ball_system,
         )
@@ -455,9 +507,13 @@ fn update_camera_shake(
 /// Observer to start camera shake
 fn start_camera_shake(
     trigger: On<StartCameraShake>,
+    gameplay: Option<Res<gameplay_config::GameplayConfig>>,
     mut cameras: Query<(Entity, &Transform), (With<Camera3d>, Without<CameraShake>)>,
     mut commands: Commands,
 ) {
+    if gameplay.is_some_and(|config| config.reduced_motion) {
+        return;
+    }
     let event = trigger.event();
     // Calculate intensity based on impulse magnitude
     let impulse_magnitude = event.impulse.length();
@@ -557,35 +613,61 @@ fn stabilize_frozen_balls(
     }
 }
 
+/// Map a mouse movement onto the play plane: vertical movement drives X, horizontal movement -Z.
+///
+/// Paddle movement and the english it gives the ball both go through here, so the ball is always
+/// steered the way the paddle moves.
+pub fn mouse_to_plane(delta: Vec2) -> Vec3 {
+    Vec3::new(delta.y, 0.0, -delta.x)
+}
+
+/// Paddle translation for one frame of mouse movement, as `move_paddle` applies it.
+///
+/// The reversed-controls debuff inverts the mouse direction.
+pub fn paddle_mouse_translation(delta: Vec2, delta_secs: f32, reversed: bool) -> Vec3 {
+    let direction = if reversed { -1.0 } else { 1.0 };
+    mouse_to_plane(delta) * 0.000_4 * direction / delta_secs
+}
+
 fn move_paddle(
     mut query: Query<&mut Transform, (With<Paddle>, Without<InputLocked>)>,
     time: Res<Time>,
-    mut controllers: Query<&mut KinematicCharacterController, (With<Paddle>, Without<InputLocked>)>,
+    mut controllers: Query<
+        (
+            &mut KinematicCharacterController,
+            Has<systems::powerups::ReversedControls>,
+            Option<&systems::energy::PaddleDash>,
+            Option<&mut systems::input_buffer::PaddleCatchUp>,
+        ),
+        (With<Paddle>, Without<InputLocked>),
+    >,
     accumulated_mouse_motion: Res<AccumulatedMouseMotion>,
     accumulated_mouse_scroll: Res<AccumulatedMouseScroll>,
-    window: Single<&Window, With<PrimaryWindow>>,
+    window: Option<Single<&Window, With<PrimaryWindow>>>,
     growing: Query<&PaddleGrowing>,
+    tas: Option<Res<systems::tas::TasPlayback>>,
 ) {
-    if !window.focused {
+    // A TAS file being played back drives the paddle without a focused window (`systems::tas`)
+    if !window.is_some_and(|window| window.focused) && tas.is_none() {
         return;
     }
     // If paddle is currently growing, ignore input and movement entirely.
     if !growing.is_empty() {
         return;
     }
-    let _sensitivity = 100.0 / window.height().min(window.width());
     if query.is_empty() {
         return;
     }
 
-    for mut controller in controllers.iter_mut() {
+    for (mut controller, reversed, dash, catch_up) in controllers.iter_mut() {
+        // An energy dash carries the paddle on top of the mouse movement
+        let dash = dash.map_or(Vec3::ZERO, |dash| dash.velocity * time.delta_secs());
+        // So does movement buffered while input was locked (`systems::input_buffer`)
+        let catch_up = catch_up.map_or(Vec3::ZERO, |mut catch_up| catch_up.step(time.delta_secs()));
         controller.translation = Some(
-            Vec3::new(
-                accumulated_mouse_motion.delta.y,
-                0.0,
-                -accumulated_mouse_motion.delta.x,
-            ) * 0.000_4
-                / time.delta_secs(),
+            paddle_mouse_translation(accumulated_mouse_motion.delta, time.delta_secs(), reversed)
+                + dash
+                + catch_up,
         );
     }
     for mut transform in &mut query {
@@ -635,6 +717,7 @@ fn spawn_border(
         Collider::cuboid(PLANE_H / 2.0, 2.5, 2.5),
         ActiveEvents::COLLISION_EVENTS,
         Border,
+        SideWall,
     ));
     commands.spawn((
         Mesh3d(meshes.add(Cuboid::new(PLANE_H, 5.0, 5.0).mesh())),
@@ -644,6 +727,7 @@ fn spawn_border(
         Collider::cuboid(PLANE_H / 2.0, 2.5, 2.5),
         ActiveEvents::COLLISION_EVENTS,
         Border,
+        SideWall,
     ));
     //  lower border
     commands.spawn((
@@ -704,6 +788,8 @@ fn try_emit_brick_destroyed(
 /// Multi-hit bricks (indices 10-13) transition to the next lower index instead of
 /// being despawned immediately. When a multi-hit brick at index 10 is hit, it
 /// transitions to index 20 (simple stone), which can then be destroyed on the next hit.
+/// Bricks with more than one hit of [`Durability`](systems::durability::Durability) left lose a
+/// hit instead. Armored bricks (indices 43-46) ignore hits that do not land on their weak side.
 ///
 /// This allows the physics collision response to complete before removal.
 pub fn mark_brick_on_ball_collision(
@@ -717,6 +803,7 @@ pub fn mark_brick_on_ball_collision(
                 &BrickTypeId,
                 Option<&GlobalTransform>,
                 Option<&Transform>,
+                Has<systems::invisible_bricks::Concealed>,
             ),
             (
                 With<Brick>,
@@ -727,14 +814,17 @@ pub fn mark_brick_on_ball_collision(
         Query<(Entity, &mut BrickTypeId), With<Brick>>,
     )>,
     transforms: Query<&Transform>,
+    mut durable_bricks: Query<&mut systems::durability::Durability>,
     mut commands: Commands,
     mut processed_bricks: Local<std::collections::HashSet<Entity>>,
     mut spawn_msgs: Option<MessageWriter<crate::signals::SpawnMerkabaMessage>>,
     mut brick_destroyed_msgs: Option<MessageWriter<crate::signals::BrickDestroyed>>,
     mut life_award_msgs: Option<MessageWriter<crate::signals::LifeAwardMessage>>,
     mut emitted: Option<ResMut<EmittedBrickDestroyed>>,
+    grid: Option<Res<GridResolution>>,
 ) {
     use crate::level_format::{is_multi_hit_brick, MULTI_HIT_BRICK_1, SIMPLE_BRICK};
+    let grid = grid.as_deref().copied().unwrap_or_default();
     // Track bricks already processed this frame to avoid double-awards on multi-ball collisions
     processed_bricks.clear();
 
@@ -754,7 +844,7 @@ pub fn mark_brick_on_ball_collision(
                 None
             };
 
-            if let Some((entity, brick_type_ro, gt_opt, t_opt)) = brick_info {
+            if let Some((entity, brick_type_ro, gt_opt, t_opt, concealed)) = brick_info {
                 if processed_bricks.contains(&entity) {
                     debug!("Skipping already-processed brick entity {:?}", entity);
                     continue;
@@ -772,6 +862,16 @@ pub fn mark_brick_on_ball_collision(
                     continue;
                 }
 
+                // A hit on a concealed invisible brick only reveals it (see `systems::invisible_bricks`)
+                if concealed {
+                    continue;
+                }
+
+                // Timed bombs are not destroyed by the hit; `systems::explosion` lights their fuse
+                if current_type == crate::level_format::TIMED_BOMB_BRICK {
+                    continue;
+                }
+
                 // Skip hazard brick type 91 - indestructible by ball collision
                 // Use trace-level logging to avoid flooding logs during frequent grazing collisions
                 if current_type == crate::level_format::HAZARD_BRICK_91 {
@@ -794,6 +894,34 @@ pub fn mark_brick_on_ball_collision(
                         .unwrap_or(Vec3::ZERO)
                 };
 
+                // Armored bricks only take hits on their weak side (see `systems::armored_bricks`)
+                let ball = if e1_is_ball { *e1 } else { *e2 };
+                if let Ok(ball_transform) = transforms.get(ball) {
+                    if !systems::armored_bricks::breaks_armor(
+                        current_type,
+                        brick_pos,
+                        ball_transform.translation,
+                        &grid,
+                    ) {
+                        debug!("Armored brick {:?} hit on an armored side", entity);
+                        continue;
+                    }
+                }
+
+                // Bricks with hits left from their level's durability only wear down; the last
+                // hit destroys them like any brick of their type
+                if let Ok(mut durability) = durable_bricks.get_mut(entity) {
+                    if durability.0 > 1 {
+                        durability.0 -= 1;
+                        commands.trigger(systems::durability::DurableBrickHit {
+                            entity,
+                            hits_left: durability.0,
+                        });
+                        debug!("Durable brick {:?} hit: {} hits left", entity, durability.0);
+                        continue;
+                    }
+                }
+
                 if is_multi_hit_brick(current_type) {
                     // Multi-hit brick: transition to next state (requires mutable borrow)
                     let new_type = if current_type == MULTI_HIT_BRICK_1 {
@@ -872,7 +1000,8 @@ pub fn mark_brick_on_ball_collision(
 fn detect_ball_wall_collisions(
     mut collision_events: MessageReader<CollisionEvent>,
     balls: Query<(Entity, &Velocity), With<Ball>>,
-    borders: Query<Entity, With<Border>>,
+    // Wrapping side walls are sensors the ball passes into, not walls it bounces off
+    borders: Query<Entity, (With<Border>, Without<Sensor>)>,
     mut commands: Commands,
 ) {
     for event in collision_events.read() {
@@ -902,6 +1031,42 @@ fn detect_ball_wall_collisions(
     }
 }
 
+/// Detect balls bouncing off bricks that survive the hit and emit BallBrickBounce events for
+/// audio.
+///
+/// Runs after `mark_brick_on_ball_collision`: bricks it destroyed are marked for despawn (or
+/// already gone), every other brick hit by a ball (indestructible, hazard, multi-hit, durable,
+/// armored side, ...) bounced the ball.
+pub fn detect_ball_brick_bounces(
+    mut collision_events: MessageReader<CollisionEvent>,
+    balls: Query<(), With<Ball>>,
+    bricks: Query<(&BrickTypeId, Has<MarkedForDespawn>), With<Brick>>,
+    mut commands: Commands,
+) {
+    for event in collision_events.read() {
+        if let CollisionEvent::Started(e1, e2, _) = event {
+            let (ball_entity, brick_entity) = if balls.contains(*e1) {
+                (*e1, *e2)
+            } else if balls.contains(*e2) {
+                (*e2, *e1)
+            } else {
+                continue;
+            };
+            let Ok((brick_type, destroyed)) = bricks.get(brick_entity) else {
+                continue;
+            };
+            if destroyed {
+                continue;
+            }
+            commands.trigger(crate::signals::BallBrickBounce {
+                ball_entity,
+                brick_entity,
+                brick_type: brick_type.0,
+            });
+        }
+    }
+}
+
 /// Despawn entities marked for removal (runs after physics step).
 /// Emits BrickDestroyed messages for audio/scoring integration.
 /// Despawn entities marked with `MarkedForDespawn` component and emit `BrickDestroyed` messages.
@@ -988,6 +1153,10 @@ pub fn register_brick_collision_systems(app: &mut App) {
     );
 }
 
+/// Key toggling wireframe rendering in cheat mode.
+#[cfg(not(target_arch = "wasm32"))]
+pub const WIREFRAME_KEY: KeyCode = KeyCode::KeyW;
+
 #[cfg(not(target_arch = "wasm32"))]
 fn toggle_wireframe(
     mut wireframe_config: ResMut<WireframeConfig>,
@@ -995,7 +1164,7 @@ fn toggle_wireframe(
     cheat_mode: Option<Res<systems::cheat_mode::CheatModeState>>,
 ) {
     let cheat_active = cheat_mode.map(|c| c.active).unwrap_or(false);
-    if cheat_active && keyboard.just_pressed(KeyCode::Space) {
+    if cheat_active && keyboard.just_pressed(WIREFRAME_KEY) {
         wireframe_config.global = !wireframe_config.global;
     }
 }
@@ -1060,7 +1229,7 @@ pub fn read_character_controller_collisions(
     paddle_outputs: Query<&KinematicCharacterControllerOutput, With<Paddle>>,
     walls: Query<Entity, With<Border>>,
     bricks: Query<Entity, With<Brick>>,
-    brick_types: Query<&BrickTypeId, With<Brick>>,
+    brick_types: Query<(&BrickTypeId, Has<systems::GroupLocked>), With<Brick>>,
     balls: Query<Entity, With<Ball>>,
     time: Res<Time>,
     accumulated_mouse_motion: Res<AccumulatedMouseMotion>,
@@ -1089,8 +1258,9 @@ pub fn read_character_controller_collisions(
         for brick in bricks.iter() {
             if collision.entity == brick {
                 // Check if this is a paddle-destroyable brick (type 57)
-                if let Ok(brick_type) = brick_types.get(brick) {
-                    if brick_type.0 == crate::level_format::PADDLE_DESTROYABLE_BRICK {
+                if let Ok((brick_type, locked)) = brick_types.get(brick) {
+                    // Bricks of a locked group (see `systems::brick_groups`) cannot be destroyed
+                    if brick_type.0 == crate::level_format::PADDLE_DESTROYABLE_BRICK && !locked {
                         debug!(
                             target: "paddle_destroyable",
                             event = "paddle_collision_mark",
@@ -1129,11 +1299,7 @@ pub fn read_character_controller_collisions(
                 // println!("hit ball {:?}", ball);
                 // println!("collision {:?}", collision);
                 commands.trigger(BallHit {
-                    impulse: Vec3::new(
-                        accumulated_mouse_motion.delta.y,
-                        0.0,
-                        -accumulated_mouse_motion.delta.x,
-                    ) / time.delta_secs(),
+                    impulse: mouse_to_plane(accumulated_mouse_motion.delta) / time.delta_secs(),
                     ball,
                 });
             }
@@ -1194,18 +1360,63 @@ fn on_brick_hit(
     });
 }
 
-fn on_paddle_ball_hit(
+/// Give the ball hit by the paddle "english": an impulse along the paddle's mouse movement.
+///
+/// A sticky paddle (`systems::sticky_paddle`) catches the ball instead; balls it already holds
+/// get no english.
+#[allow(clippy::type_complexity)]
+pub fn on_paddle_ball_hit(
     trigger: On<BallHit>,
     mut balls: Query<(Entity, &mut ExternalImpulse), With<Ball>>,
+    ball_states: Query<
+        (
+            &Transform,
+            Option<&Velocity>,
+            Has<systems::sticky_paddle::BallStuck>,
+        ),
+        With<Ball>,
+    >,
+    paddle_config: Option<Res<crate::physics_config::PaddlePhysicsConfig>>,
+    gameplay: Option<Res<gameplay_config::GameplayConfig>>,
+    paddles: Query<Has<systems::powerups::RubberPaddle>, With<Paddle>>,
+    sticky_paddles: Query<
+        (Entity, &Transform),
+        (With<Paddle>, With<systems::sticky_paddle::StickyPaddle>),
+    >,
+    mut commands: Commands,
 ) {
     let event = trigger.event();
     println!("Received ball hit event: {:?}", event.impulse);
 
+    if let Ok((ball_transform, velocity, stuck)) = ball_states.get(event.ball) {
+        if stuck {
+            return;
+        }
+        if let Some((paddle, paddle_transform)) = sticky_paddles.iter().next() {
+            let speed = velocity.map_or(0.0, |v| v.linvel.length());
+            systems::sticky_paddle::stick_ball(
+                &mut commands,
+                event.ball,
+                ball_transform,
+                speed,
+                paddle,
+                paddle_transform,
+            );
+            return;
+        }
+    }
+
     // give the balls an impulse with "english" - paddle rotation affects ball trajectory
-    // Tuned multiplier for noticeable but controlled steering effect
+    // The share passed on is the paddle surface's spin transfer (`PaddlePhysicsConfig`, raised by
+    // the rubber paddle), scaled by the player's english setting
+    let config = paddle_config.map(|c| c.clone()).unwrap_or_default();
+    let rubber = paddles.iter().any(|rubber| rubber);
+    let english = gameplay.map_or(1.0, |g| g.english.factor());
+    let spin_transfer =
+        systems::powerups::PaddleSurface::of(&config, rubber).spin_transfer * english;
     for (ball, mut impulse) in balls.iter_mut() {
         if ball == event.ball {
-            impulse.impulse = event.impulse * 0.001; // Increased from 0.000_2 for more noticeable effect
+            impulse.impulse = event.impulse * spin_transfer;
         }
     }
 }
@@ -1,3 +1,14 @@
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    use clap::Parser;
+
+    let options = brkrs::launch::Cli::parse().into();
+    brkrs::BrkrsConfig::default()
+        .with_launch_options(options)
+        .run();
+}
+
+#[cfg(target_arch = "wasm32")]
 fn main() {
     brkrs::run();
 }
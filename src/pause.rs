@@ -2,7 +2,7 @@
 //!
 //! This module implements the pause/resume functionality including:
 //! - Pause state management (PauseState resource)
-//! - Input handling (ESC to pause, mouse click to resume)
+//! - Input handling (ESC to pause, mouse click or the overlay's Resume button to resume)
 //! - Physics control (freeze/resume via RapierConfiguration)
 //! - Window mode switching (fullscreen ↔ windowed on native platforms)
 //!
@@ -15,7 +15,9 @@ use bevy::window::{Window, WindowMode};
 use bevy_rapier3d::prelude::*;
 
 use crate::level_loader::LevelAdvanceState;
-use crate::ui::pause_overlay::{despawn_pause_overlay, spawn_pause_overlay};
+use crate::ui::level_intro::LevelIntro;
+use crate::ui::pause_overlay::{despawn_pause_overlay, spawn_pause_overlay, PauseMenuButton};
+use crate::ui::profile_select::ProfileSelectionOpen;
 
 /// Global pause state resource.
 ///
@@ -45,7 +47,10 @@ impl Plugin for PausePlugin {
         app.init_resource::<PauseState>();
 
         // Hide cursor on startup
-        app.add_systems(Startup, hide_cursor_on_startup);
+        app.add_systems(
+            Startup,
+            hide_cursor_on_startup.run_if(crate::plugins::manages_window),
+        );
 
         // Register pause/resume systems with explicit ordering
         // Execution order: input handling → state effects (physics, window, cursor) → UI updates
@@ -54,14 +59,23 @@ impl Plugin for PausePlugin {
             Update,
             (
                 // Input handling systems (can run in parallel)
-                (handle_pause_input, handle_resume_input),
+                (
+                    handle_pause_input,
+                    handle_resume_input,
+                    crate::ui::pause_overlay::handle_pause_menu_buttons,
+                ),
                 // State-dependent systems (run after input, before UI)
                 // Physics control runs after LevelAdvanceSystems to avoid race conditions
                 apply_pause_to_physics.after(crate::level_loader::LevelAdvanceSystems),
-                apply_pause_to_window_mode,
-                apply_pause_to_cursor,
+                // Left alone when an embedding app owns the window
+                apply_pause_to_window_mode.run_if(crate::plugins::manages_window),
+                apply_pause_to_cursor.run_if(crate::plugins::manages_window),
                 // UI systems (run last, after all state changes)
-                (spawn_pause_overlay, despawn_pause_overlay),
+                (
+                    spawn_pause_overlay,
+                    despawn_pause_overlay,
+                    crate::ui::pause_overlay::update_pause_menu_labels,
+                ),
             )
                 .chain(),
         );
@@ -78,25 +92,28 @@ fn handle_pause_input(
     mut pause_state: ResMut<PauseState>,
     level_advance: Res<LevelAdvanceState>,
     lives_state: Res<crate::systems::respawn::LivesState>,
-    #[cfg(not(target_arch = "wasm32"))] window: Single<&Window, With<PrimaryWindow>>,
+    intro: Option<Res<LevelIntro>>,
+    profile_selection: Option<Res<ProfileSelectionOpen>>,
+    #[cfg(not(target_arch = "wasm32"))] window: Option<Single<&Window, With<PrimaryWindow>>>,
 ) {
-    // Disable pause input when game is over (lives = 0)
-    if lives_state.lives_remaining == 0 {
-        return;
-    }
-
     // Only allow pause if:
     // 1. ESC was just pressed (frame-level debouncing via just_pressed)
     // 2. Game is currently Active (not already paused)
-    // 3. No level transition in progress (FR-012)
+    // 3. Pausing is allowed right now (see `pause_allowed`)
     if keyboard.just_pressed(KeyCode::Escape)
         && matches!(*pause_state, PauseState::Active)
-        && !level_advance.active
+        && pause_allowed(
+            &lives_state,
+            &level_advance,
+            intro.is_some(),
+            profile_selection.is_some(),
+        )
     {
         #[cfg(not(target_arch = "wasm32"))]
         {
+            // Without a primary window (embedded or headless) there is no mode to restore
             *pause_state = PauseState::Paused {
-                window_mode_before_pause: window.mode,
+                window_mode_before_pause: window.map_or(WindowMode::Windowed, |window| window.mode),
             };
         }
         #[cfg(target_arch = "wasm32")]
@@ -106,12 +123,29 @@ fn handle_pause_input(
     }
 }
 
+/// Whether the game may be paused: not over (lives = 0), no level transition in progress
+/// (FR-012), and no level intro (Escape skips it instead) or profile selection showing.
+pub(crate) fn pause_allowed(
+    lives_state: &crate::systems::respawn::LivesState,
+    level_advance: &LevelAdvanceState,
+    intro_showing: bool,
+    profile_selection_showing: bool,
+) -> bool {
+    lives_state.lives_remaining > 0
+        && !level_advance.active
+        && !intro_showing
+        && !profile_selection_showing
+}
+
 /// System that controls physics simulation based on pause state.
 ///
-/// Freezes physics when paused, resumes when active.
+/// Freezes physics when paused or while a level intro or the profile selection is showing, resumes
+/// when active.
 /// Uses Rapier's physics_pipeline_active flag to preserve all physics state.
-fn apply_pause_to_physics(
+pub(crate) fn apply_pause_to_physics(
     pause_state: Res<PauseState>,
+    intro: Option<Res<LevelIntro>>,
+    profile_selection: Option<Res<ProfileSelectionOpen>>,
     mut rapier_config: Query<&mut RapierConfiguration>,
 ) {
     let Ok(mut config) = rapier_config.single_mut() else {
@@ -119,7 +153,7 @@ fn apply_pause_to_physics(
     };
     match *pause_state {
         PauseState::Active => {
-            config.physics_pipeline_active = true;
+            config.physics_pipeline_active = intro.is_none() && profile_selection.is_none();
         }
         PauseState::Paused { .. } => {
             config.physics_pipeline_active = false;
@@ -130,11 +164,22 @@ fn apply_pause_to_physics(
 /// System that handles mouse click input to resume the game.
 ///
 /// Transitions from Paused to Active state when left mouse button is clicked.
-fn handle_resume_input(mouse: Res<ButtonInput<MouseButton>>, mut pause_state: ResMut<PauseState>) {
+/// Clicks on the pause menu buttons are left to the buttons.
+fn handle_resume_input(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut pause_state: ResMut<PauseState>,
+    menu_buttons: Query<&Interaction, With<PauseMenuButton>>,
+) {
     // Only allow resume if:
     // 1. Left mouse button was just pressed (frame-level debouncing via just_pressed)
     // 2. Game is currently Paused
-    if mouse.just_pressed(MouseButton::Left) && matches!(*pause_state, PauseState::Paused { .. }) {
+    // 3. The click did not land on a pause menu button (e.g. the display settings)
+    if mouse.just_pressed(MouseButton::Left)
+        && matches!(*pause_state, PauseState::Paused { .. })
+        && !menu_buttons
+            .iter()
+            .any(|interaction| *interaction == Interaction::Pressed)
+    {
         *pause_state = PauseState::Active;
     }
 }
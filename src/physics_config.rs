@@ -66,12 +66,18 @@ impl Default for BallPhysicsConfig {
     }
 }
 
+/// Highest accepted `PaddlePhysicsConfig::spin_transfer`.
+pub const MAX_SPIN_TRANSFER: f32 = 0.01;
+
 #[derive(Resource, Debug, Clone)]
 pub struct PaddlePhysicsConfig {
     pub restitution: f32,
     pub friction: f32,
     pub linear_damping: f32,
     pub angular_damping: f32,
+    /// Share of the paddle's movement passed on to the ball as "english" on contact: the ball gets
+    /// an impulse of the paddle's mouse velocity times this factor.
+    pub spin_transfer: f32,
 }
 
 impl PaddlePhysicsConfig {
@@ -103,6 +109,15 @@ impl PaddlePhysicsConfig {
                 self.angular_damping
             ));
         }
+        if !self.spin_transfer.is_finite()
+            || self.spin_transfer < 0.0
+            || self.spin_transfer > MAX_SPIN_TRANSFER
+        {
+            return Err(format!(
+                "Paddle spin_transfer out of bounds: {}",
+                self.spin_transfer
+            ));
+        }
         Ok(())
     }
 }
@@ -114,6 +129,7 @@ impl Default for PaddlePhysicsConfig {
             friction: 2.0,
             linear_damping: 0.5,
             angular_damping: 0.5,
+            spin_transfer: 0.001,
         }
     }
 }
@@ -0,0 +1,324 @@
+//! Public API for running brkrs on its own or embedding it into another Bevy app.
+//!
+//! [`crate::run`] builds a standalone app from the default [`BrkrsConfig`]. Other apps (e.g. a
+//! launcher hosting brkrs as a mini-game) add [`BrkrsPlugins`] next to their own plugins:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use brkrs::{BrkrsConfig, BrkrsPlugins};
+//!
+//! let config = BrkrsConfig::default().with_window_management(false);
+//! App::new()
+//!     // The host owns the window and the rendering setup
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(BrkrsPlugins::new(config))
+//!     .run();
+//! ```
+//!
+//! `BrkrsPlugins` is a regular plugin group, so members the host already provides (such as
+//! Rapier's physics plugin or the wireframe plugin) can be disabled:
+//!
+//! ```no_run
+//! # use bevy::prelude::*;
+//! # use bevy_rapier3d::prelude::*;
+//! # use brkrs::BrkrsPlugins;
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+//!     .add_plugins(BrkrsPlugins::default().build().disable::<RapierPhysicsPlugin<NoUserData>>())
+//!     .run();
+//! ```
+//!
+//! The game expects the rendering, asset, input, audio and UI plugins from `DefaultPlugins` (or
+//! equivalents) to be present.
+
+use std::time::Duration;
+
+use bevy::app::{PluginGroupBuilder, ScheduleRunnerPlugin};
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::pbr::wireframe::WireframePlugin;
+use bevy::prelude::*;
+use bevy::render::settings::WgpuSettings;
+use bevy::render::RenderPlugin;
+use bevy::window::{ExitCondition, WindowMode, WindowPlugin};
+use bevy::winit::WinitPlugin;
+use bevy_rapier3d::prelude::*;
+
+use crate::launch::LaunchOptions;
+use crate::systems;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::systems::display::{DisplaySettings, DISPLAY_CONFIG_PATH};
+use crate::systems::logging::LoggingConfig;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::systems::logging::LOGGING_CONFIG_PATH;
+use crate::BrkrsCorePlugin;
+
+/// How brkrs is hosted: the window it asks for and what it may do with it.
+///
+/// Built with the `with_*` methods on top of [`Default`], which matches the standalone game.
+/// Inserted as a resource by [`BrkrsPlugins`].
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct BrkrsConfig {
+    /// Title of the primary window created by [`BrkrsConfig::default_plugins`]
+    pub title: String,
+    /// Mode of that window: the saved display settings on native platforms (borderless
+    /// fullscreen by default, see `systems::display`), windowed on wasm
+    pub window_mode: WindowMode,
+    /// Whether [`BrkrsConfig::app`] adds `DefaultPlugins`; embedding apps bring their own
+    pub add_default_plugins: bool,
+    /// Whether brkrs manages the primary window: it leaves fullscreen while paused, hides and
+    /// grabs the cursor, and `Q` quits the app. Hosts that own the window turn this off.
+    pub manage_window: bool,
+    /// Command line choices, inserted as a resource (from the environment by default)
+    pub launch: LaunchOptions,
+    /// Log levels and outputs for the `LogPlugin` of [`BrkrsConfig::default_plugins`]:
+    /// `config/logging.ron` on native platforms (see `systems::logging`)
+    pub logging: LoggingConfig,
+}
+
+impl Default for BrkrsConfig {
+    fn default() -> Self {
+        Self {
+            title: "brkrs".to_string(),
+            #[cfg(not(target_arch = "wasm32"))]
+            window_mode: DisplaySettings::load(std::path::Path::new(DISPLAY_CONFIG_PATH))
+                .window_mode(),
+            #[cfg(target_arch = "wasm32")]
+            window_mode: WindowMode::Windowed,
+            add_default_plugins: true,
+            manage_window: true,
+            launch: LaunchOptions::from_env(),
+            #[cfg(not(target_arch = "wasm32"))]
+            logging: LoggingConfig::load(std::path::Path::new(LOGGING_CONFIG_PATH)),
+            #[cfg(target_arch = "wasm32")]
+            logging: LoggingConfig::default(),
+        }
+    }
+}
+
+impl BrkrsConfig {
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_window_mode(mut self, window_mode: WindowMode) -> Self {
+        self.window_mode = window_mode;
+        self
+    }
+
+    pub fn with_default_plugins(mut self, add_default_plugins: bool) -> Self {
+        self.add_default_plugins = add_default_plugins;
+        self
+    }
+
+    pub fn with_window_management(mut self, manage_window: bool) -> Self {
+        self.manage_window = manage_window;
+        self
+    }
+
+    pub fn with_logging(mut self, logging: LoggingConfig) -> Self {
+        self.logging = logging;
+        self
+    }
+
+    /// Use `launch`; `--windowed` switches to a window and `--headless` leaves windows alone.
+    pub fn with_launch_options(mut self, launch: LaunchOptions) -> Self {
+        if launch.windowed {
+            self.window_mode = WindowMode::Windowed;
+        }
+        if launch.headless {
+            self.manage_window = false;
+        }
+        self.launch = launch;
+        self
+    }
+
+    /// `DefaultPlugins` set up for the game: logging as configured, nearest-neighbour image
+    /// sampling and a primary window with the configured title and mode.
+    ///
+    /// Headless launches get no window and no GPU; the app then runs at a fixed 60 updates per
+    /// second until it exits.
+    pub fn default_plugins(&self) -> PluginGroupBuilder {
+        if self.launch.headless {
+            return DefaultPlugins
+                .set(self.logging.log_plugin())
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    close_when_requested: false,
+                    ..default()
+                })
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }
+                    .into(),
+                    ..default()
+                })
+                .set(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(
+                    1.0 / 60.0,
+                )))
+                .disable::<WinitPlugin>();
+        }
+        DefaultPlugins
+            .set(self.logging.log_plugin())
+            .set(ImagePlugin::default_nearest())
+            .set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: self.title.clone(),
+                    mode: self.window_mode,
+                    ..default()
+                }),
+                ..default()
+            })
+    }
+
+    /// A new app running the game, with [`BrkrsConfig::default_plugins`] unless disabled.
+    pub fn app(self) -> App {
+        let mut app = App::new();
+        if self.add_default_plugins {
+            // Read by the log capture layer as `LogPlugin` is built
+            app.insert_resource(self.logging.clone());
+            app.add_plugins(self.default_plugins());
+        }
+        app.add_plugins(BrkrsPlugins::new(self));
+        app
+    }
+
+    /// Build the app and run it until it exits, writing a crash report should it panic (see
+    /// `systems::crash_report`), then let the save sync on exit finish (see
+    /// `systems::cloud_sync`).
+    pub fn run(self) -> AppExit {
+        let mut app = self.app();
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(log) = app
+            .world()
+            .get_resource::<systems::crash_report::CrashLog>()
+        {
+            systems::crash_report::install_crash_hook(log.clone());
+        }
+        // `App::run` hands the world to the runner, so keep a handle on the sync beforehand
+        let pending_sync = app
+            .world()
+            .get_resource::<systems::cloud_sync::PendingSync>()
+            .cloned();
+        let exit = app.run();
+        if let Some(pending_sync) = pending_sync {
+            pending_sync.wait();
+        }
+        exit
+    }
+}
+
+/// Every plugin of the game, in registration order, without `DefaultPlugins`.
+#[derive(Debug, Clone, Default)]
+pub struct BrkrsPlugins {
+    config: BrkrsConfig,
+}
+
+impl BrkrsPlugins {
+    pub fn new(config: BrkrsConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl PluginGroup for BrkrsPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let group = PluginGroupBuilder::start::<Self>().add(BrkrsCorePlugin {
+            config: self.config,
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        let group = group.add(WireframePlugin::default());
+        let group = group
+            .add(RapierPhysicsPlugin::<NoUserData>::default())
+            // TAS playback picks the starting level and seed, so it is built first
+            .add(systems::TasPlugin)
+            .add(systems::LevelSwitchPlugin)
+            // Level files over HTTP in the browser build
+            .add(systems::LevelFetchPlugin)
+            .add(crate::level_loader::LevelLoaderPlugin)
+            // .add(RapierDebugRenderPlugin::default())
+            .add(systems::RespawnPlugin)
+            .add(systems::InputBufferPlugin)
+            .add(crate::pause::PausePlugin)
+            .add(systems::AudioPlugin)
+            .add(systems::MerkabaPlugin)
+            .add(systems::PaddleSizePlugin)
+            .add(systems::PowerupPlugin)
+            .add(systems::StickyPaddlePlugin)
+            .add(systems::BallSizePlugin)
+            .add(systems::WreckingBallPlugin)
+            .add(systems::ExplosionPlugin)
+            .add(systems::FloorZonesPlugin)
+            .add(systems::ForceFieldsPlugin)
+            .add(systems::InvisibleBricksPlugin)
+            .add(systems::AngleGuardPlugin)
+            .add(systems::LevelClockPlugin)
+            .add(systems::NearMissPlugin)
+            .add(systems::PostProcessingPlugin)
+            .add(systems::LightingPlugin)
+            .add(systems::QualityGuardPlugin)
+            .add(systems::DisplayPlugin)
+            .add(systems::HeatmapPlugin)
+            .add(systems::GhostPathPlugin)
+            .add(systems::CompletionPlugin)
+            .add(systems::GameCompletePlugin)
+            .add(systems::CampaignPlugin)
+            .add(systems::TutorialPlugin)
+            .add(systems::CheckpointsPlugin)
+            .add(systems::BrickDebrisPlugin)
+            .add(systems::BrickGroupsPlugin)
+            .add(systems::DurabilityPlugin)
+            .add(systems::TeamBricksPlugin)
+            .add(systems::BrickSurfacesPlugin)
+            .add(systems::BrickShapesPlugin)
+            .add(systems::ArmoredBricksPlugin)
+            .add(systems::BumpersPlugin)
+            .add(systems::OneWayGatesPlugin)
+            .add(systems::PortalEdgesPlugin)
+            .add(systems::GravityWellsPlugin)
+            .add(systems::WavesPlugin)
+            .add(systems::SurvivalPlugin)
+            .add(systems::EnergyPlugin)
+            .add(systems::AssistPlugin)
+            .add(systems::HintsPlugin)
+            .add(systems::FovKickPlugin)
+            .add(systems::LossSlowMotionPlugin)
+            .add(systems::RemixPlugin)
+            .add(systems::PlaylistPlugin)
+            .add(systems::ProfilesPlugin)
+            .add(systems::AutosavePlugin)
+            .add(systems::CloudSyncPlugin)
+            // State kept for crash reports
+            .add(systems::CrashReportPlugin)
+            // Startup check of the referenced level, texture and sound files
+            .add(systems::PreflightPlugin)
+            // Cheat mode plugin (feature: toggle, indicator, gated level controls)
+            .add(systems::CheatModePlugin)
+            // Cheat console (`spawnbrick`, Ctrl + click) on top of cheat mode
+            .add(systems::CheatConsolePlugin)
+            // Slow motion (1-4) and frame stepping while paused (.) on top of cheat mode
+            .add(systems::SimSpeedPlugin)
+            // Entity inspector (I): live editing of gravity, tuning, lives and the ball/paddle
+            .add(systems::InspectorPlugin)
+            // Log console (F12) on top of cheat mode
+            .add(systems::LoggingPlugin);
+        #[cfg(feature = "texture_manifest")]
+        let group = group
+            .add(systems::TextureManifestPlugin)
+            .add(systems::ThemesPlugin);
+        group
+            // FontsPlugin wires platform-appropriate font loading systems
+            .add(crate::ui::fonts::FontsPlugin)
+            // UI plugin (Constitution VIII: Plugin-Based Architecture)
+            .add(crate::ui::UiPlugin)
+    }
+}
+
+/// Run condition: brkrs may change the primary window and cursor (see
+/// [`BrkrsConfig::manage_window`]). Holds when no config is present, as in tests.
+pub fn manages_window(config: Option<Res<BrkrsConfig>>) -> bool {
+    config.is_none_or(|config| config.manage_window)
+}
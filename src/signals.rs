@@ -65,6 +65,23 @@ pub struct BallWallHit {
     pub wall_entity: Entity,
 }
 
+/// Ball bounced off a brick that survived the hit (indestructible, multi-hit, durable, ...).
+///
+/// **Producers**: Ball-brick bounce detection (`detect_ball_brick_bounces`, emits via
+/// `commands.trigger`)
+/// **Consumers**: Audio system (observer: `On<BallBrickBounce>`, plays the bounce sound)
+/// **Contract**: Fired once per ball-brick contact start when the brick is not destroyed by it;
+/// destroyed bricks produce [`BrickDestroyed`] instead
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BallBrickBounce {
+    /// Entity of the ball that hit the brick
+    pub ball_entity: Entity,
+    /// Entity of the brick that was hit
+    pub brick_entity: Entity,
+    /// Brick type after the hit
+    pub brick_type: u8,
+}
+
 /// Brick destruction signal unified for scoring and audio.
 ///
 /// **Producers**: Brick collision system, entity despawn system
@@ -80,6 +97,19 @@ pub struct BrickDestroyed {
     pub destroyed_by: Option<Entity>,
 }
 
+/// Ball bounced off a pinball-style bumper.
+///
+/// **Producers**: Bumper kick system (`systems::bumpers`)
+/// **Consumers**: Audio system (`BumperHit` sound)
+/// **Contract**: Fired once per ball-bumper contact start
+#[derive(Message, Debug, Clone, Copy)]
+pub struct BumperHit {
+    /// Entity of the bumper that was hit
+    pub bumper_entity: Entity,
+    /// Entity of the ball that hit it
+    pub ball_entity: Entity,
+}
+
 /// Life award signal for granting extra lives to the player.
 ///
 /// **Producers**: Brick collision systems (e.g., brick 41 extra life brick)
@@ -0,0 +1,140 @@
+//! Minimum bounce angle enforcement after wall bounces.
+//!
+//! A ball that leaves a wall at a very shallow angle can travel almost parallel to it for a long
+//! time (classically along the top wall). After every ball-wall contact this module checks the
+//! angle between the ball's path and the wall and, if it is below the configured minimum, rotates
+//! the velocity just enough to reach it. Speed is preserved, and so are the signs of both
+//! components, so the correction never reverses the ball.
+//!
+//! The minimum comes from [`GameplayConfig::min_bounce_angle_deg`], i.e. it depends on the
+//! configured [`Difficulty`](crate::gameplay_config::Difficulty).
+//!
+//! # Wall normals
+//! Borders are axis-aligned slabs centred on the X or Z axis, so a wall's normal is the axis its
+//! centre is offset along, pointing back towards the play field.
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{CollisionEvent, Sensor, Velocity};
+
+use crate::gameplay_config::GameplayConfig;
+use crate::{Ball, Border};
+
+/// Unit normal (XZ) of an axis-aligned border at `wall_center`, pointing into the play field.
+pub fn wall_normal(wall_center: Vec3) -> Vec3 {
+    if wall_center.x.abs() >= wall_center.z.abs() {
+        Vec3::new(-wall_center.x.signum(), 0.0, 0.0)
+    } else {
+        Vec3::new(0.0, 0.0, -wall_center.z.signum())
+    }
+}
+
+/// Rotate `velocity` (in the XZ plane) so it leaves a wall with inward `normal` at no less than
+/// `min_angle` radians. Returns `None` when no correction is needed.
+pub fn enforce_min_angle(velocity: Vec3, normal: Vec3, min_angle: f32) -> Option<Vec3> {
+    let planar = Vec3::new(velocity.x, 0.0, velocity.z);
+    let speed = planar.length();
+    if min_angle <= 0.0 || speed <= f32::EPSILON {
+        return None;
+    }
+    let away = planar.dot(normal);
+    let along = planar - normal * away;
+    if away > 0.0 && away / speed >= min_angle.sin() {
+        return None;
+    }
+    // Keep travelling along the wall in the same direction, but leave it at `min_angle`
+    let tangent = along.normalize_or_zero();
+    let corrected =
+        (normal * min_angle.sin() + tangent * min_angle.cos()).normalize_or(normal) * speed;
+    Some(Vec3::new(corrected.x, velocity.y, corrected.z))
+}
+
+/// Correct shallow ball-wall bounces to the configured minimum angle.
+pub fn guard_bounce_angles(
+    mut collisions: MessageReader<CollisionEvent>,
+    config: Res<GameplayConfig>,
+    walls: Query<&Transform, (With<Border>, Without<Sensor>)>,
+    mut balls: Query<&mut Velocity, With<Ball>>,
+) {
+    let min_angle = config.min_bounce_angle_deg().to_radians();
+    for event in collisions.read() {
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        let (ball, wall) = if balls.contains(*e1) {
+            (*e1, *e2)
+        } else {
+            (*e2, *e1)
+        };
+        let (Ok(mut velocity), Ok(wall_transform)) = (balls.get_mut(ball), walls.get(wall)) else {
+            continue;
+        };
+        let normal = wall_normal(wall_transform.translation);
+        if let Some(corrected) = enforce_min_angle(velocity.linvel, normal, min_angle) {
+            debug!(
+                "Angle guard: ball {:?} {:?} -> {:?}",
+                ball, velocity.linvel, corrected
+            );
+            velocity.linvel = corrected;
+        }
+    }
+}
+
+/// Plugin registering the minimum bounce angle guard.
+pub struct AngleGuardPlugin;
+
+impl Plugin for AngleGuardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameplayConfig>();
+        app.add_message::<CollisionEvent>();
+        app.add_systems(Update, guard_bounce_angles);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn angle_to_wall(velocity: Vec3, normal: Vec3) -> f32 {
+        (velocity.dot(normal) / velocity.length()).asin()
+    }
+
+    #[test]
+    fn normals_point_into_the_field() {
+        assert_eq!(wall_normal(Vec3::new(-17.5, 0.0, 0.0)), Vec3::X);
+        assert_eq!(wall_normal(Vec3::new(0.0, 0.0, 22.5)), Vec3::NEG_Z);
+        assert_eq!(wall_normal(Vec3::new(0.0, 0.0, -22.5)), Vec3::Z);
+    }
+
+    #[test]
+    fn shallow_bounce_is_rotated_to_min_angle() {
+        let min = 15f32.to_radians();
+        let velocity = Vec3::new(0.2, 0.0, -12.0);
+        let corrected = enforce_min_angle(velocity, Vec3::X, min).unwrap();
+        assert!((corrected.length() - velocity.length()).abs() < 1e-4);
+        assert!((angle_to_wall(corrected, Vec3::X) - min).abs() < 1e-4);
+        // Still heading the same way along the wall
+        assert!(corrected.z < 0.0);
+    }
+
+    #[test]
+    fn steep_bounce_is_untouched() {
+        let velocity = Vec3::new(6.0, 0.0, 8.0);
+        assert_eq!(
+            enforce_min_angle(velocity, Vec3::X, 15f32.to_radians()),
+            None
+        );
+        assert_eq!(
+            enforce_min_angle(Vec3::new(0.0, 0.0, 5.0), Vec3::X, 0.0),
+            None
+        );
+    }
+
+    #[test]
+    fn ball_sliding_into_the_wall_is_pushed_away() {
+        let min = 10f32.to_radians();
+        let corrected = enforce_min_angle(Vec3::new(-0.1, 0.0, 9.0), Vec3::X, min).unwrap();
+        assert!(corrected.x > 0.0);
+        assert!((angle_to_wall(corrected, Vec3::X) - min).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,210 @@
+//! Armored bricks (indices 43-46): destroyed only by hits on one weak side.
+//!
+//! | Index | Weak side |
+//! |-------|-----------|
+//! | 43 | bottom, facing row 19 (hit from below) |
+//! | 44 | top, facing row 0 (hit from above) |
+//! | 45 | left, facing column 0 |
+//! | 46 | right, facing column 19 |
+//!
+//! When a ball hits a brick, `mark_brick_on_ball_collision` asks [`breaks_armor`] whether the
+//! ball came in through the weak side. Collision events carry no contact data, so the side is
+//! derived from the contact normal of the ball on the brick's box: the direction from the closest
+//! point of the box to the ball's centre ([`hit_side`]). Hits on any other side bounce off
+//! without effect. Explosions still destroy armored bricks.
+//!
+//! An arrow on top of each armored brick points towards its weak side.
+
+use bevy::prelude::*;
+
+use crate::level_format::{
+    ARMORED_BRICK_BOTTOM, ARMORED_BRICK_LEFT, ARMORED_BRICK_RIGHT, ARMORED_BRICK_TOP,
+};
+use crate::systems::one_way_gates::GateDirection;
+use crate::{Brick, BrickTypeId, GridResolution};
+
+/// Colour of the weak side arrows.
+pub const ARMOR_ARROW_COLOR: Color = Color::srgb(1.0, 0.55, 0.15);
+
+/// Side of an armored brick that can be broken, as the direction the side faces in grid terms.
+pub fn weak_side(brick_type: u8) -> Option<GateDirection> {
+    match brick_type {
+        ARMORED_BRICK_BOTTOM => Some(GateDirection::Down),
+        ARMORED_BRICK_TOP => Some(GateDirection::Up),
+        ARMORED_BRICK_LEFT => Some(GateDirection::Left),
+        ARMORED_BRICK_RIGHT => Some(GateDirection::Right),
+        _ => None,
+    }
+}
+
+/// Side of the brick at `brick`, on a level laid out on `grid`, that a ball at `ball` touches.
+///
+/// Uses the contact normal of a ball on the brick's box (in the XZ plane): the direction from the
+/// box's closest point to the ball's centre. A ball whose centre is already inside the box is
+/// assigned the side it is closest to.
+pub fn hit_side(brick: Vec3, ball: Vec3, grid: &GridResolution) -> GateDirection {
+    // Brick boxes are 90% of the cell (see `level_loader::spawn_brick`)
+    let half_extents = Vec2::new(grid.cell_height() * 0.45, grid.cell_width() * 0.45);
+    let offset = Vec2::new(ball.x - brick.x, ball.z - brick.z);
+    let outside = offset - offset.clamp(-half_extents, half_extents);
+    let normal = if outside.length_squared() > f32::EPSILON {
+        outside
+    } else {
+        offset / half_extents
+    };
+    [
+        GateDirection::Up,
+        GateDirection::Down,
+        GateDirection::Left,
+        GateDirection::Right,
+    ]
+    .into_iter()
+    .max_by(|a, b| normal.dot(a.normal()).total_cmp(&normal.dot(b.normal())))
+    .unwrap_or(GateDirection::Down)
+}
+
+/// Whether a ball at `ball` hitting a brick of `brick_type` at `brick` may damage it: always for
+/// unarmored bricks, only through the weak side for armored ones.
+pub fn breaks_armor(brick_type: u8, brick: Vec3, ball: Vec3, grid: &GridResolution) -> bool {
+    weak_side(brick_type).is_none_or(|weak| hit_side(brick, ball, grid) == weak)
+}
+
+/// Marker for the weak side arrow drawn on an armored brick.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ArmorArrow;
+
+/// Shared meshes and material of the weak side arrows, sized for the standard 20x20 grid.
+#[derive(Resource, Debug, Clone)]
+pub struct ArmorArrowAssets {
+    pub shaft: Handle<Mesh>,
+    pub head: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+fn setup_armor_arrow_assets(
+    mut commands: Commands,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    let length = GridResolution::default().cell_height() * 0.6;
+    commands.insert_resource(ArmorArrowAssets {
+        shaft: meshes.add(Cuboid::new(length * 0.6, 0.05, 0.12)),
+        head: meshes.add(Cone::new(0.22, length * 0.4)),
+        material: materials.add(StandardMaterial {
+            base_color: ARMOR_ARROW_COLOR,
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Draw the weak side arrow on newly spawned armored bricks.
+pub fn spawn_armor_arrows(
+    mut commands: Commands,
+    assets: Option<Res<ArmorArrowAssets>>,
+    grid: Option<Res<GridResolution>>,
+    bricks: Query<(Entity, &BrickTypeId), (With<Brick>, Added<BrickTypeId>)>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    // The shared meshes fit standard cells; shrink or grow the arrows with the level's cells
+    let scale = grid.cell_height() / GridResolution::default().cell_height();
+    let length = GridResolution::default().cell_height() * 0.6;
+    for (entity, brick_type) in bricks.iter() {
+        let Some(weak) = weak_side(brick_type.0) else {
+            continue;
+        };
+        let normal = weak.normal();
+        let heading = Quat::from_rotation_arc(Vec3::X, Vec3::new(normal.x, 0.0, normal.y));
+        commands.entity(entity).with_children(|brick| {
+            // Local +X points at the weak side; the arrow lies on the brick's top face
+            brick
+                .spawn((
+                    Transform::from_xyz(0.0, 0.28, 0.0)
+                        .with_rotation(heading)
+                        .with_scale(Vec3::splat(scale)),
+                    Visibility::default(),
+                    ArmorArrow,
+                ))
+                .with_children(|arrow| {
+                    arrow.spawn((
+                        Mesh3d(assets.shaft.clone()),
+                        MeshMaterial3d(assets.material.clone()),
+                        Transform::from_xyz(-length * 0.2, 0.0, 0.0),
+                    ));
+                    arrow.spawn((
+                        Mesh3d(assets.head.clone()),
+                        MeshMaterial3d(assets.material.clone()),
+                        // Cone tip points along +Y; lay it down along +X
+                        Transform::from_xyz(length * 0.3, 0.0, 0.0)
+                            .with_rotation(Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2)),
+                    ));
+                });
+        });
+    }
+}
+
+/// Plugin drawing the weak side arrows of armored bricks (the hits themselves are filtered in
+/// `mark_brick_on_ball_collision`).
+pub struct ArmoredBricksPlugin;
+
+impl Plugin for ArmoredBricksPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_armor_arrow_assets);
+        app.add_systems(Update, spawn_armor_arrows);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_side_follows_the_contact_normal() {
+        let grid = GridResolution::default();
+        let brick = Vec3::new(0.0, 2.0, 0.0);
+        // Rows grow along +X, so a ball at larger X is below the brick
+        assert_eq!(
+            hit_side(brick, Vec3::new(1.0, 2.0, 0.3), &grid),
+            GateDirection::Down
+        );
+        assert_eq!(
+            hit_side(brick, Vec3::new(-1.0, 2.0, 0.0), &grid),
+            GateDirection::Up
+        );
+        // Columns grow along -Z
+        assert_eq!(
+            hit_side(brick, Vec3::new(0.2, 2.0, 1.2), &grid),
+            GateDirection::Left
+        );
+        assert_eq!(
+            hit_side(brick, Vec3::new(0.0, 2.0, -1.2), &grid),
+            GateDirection::Right
+        );
+        // Beside the bottom edge but past the corner: the side face wins
+        assert_eq!(
+            hit_side(brick, Vec3::new(0.6, 2.0, -1.1), &grid),
+            GateDirection::Right
+        );
+    }
+
+    #[test]
+    fn only_the_weak_side_breaks_armor() {
+        let grid = GridResolution::default();
+        let brick = Vec3::ZERO;
+        let below = Vec3::new(1.0, 0.0, 0.0);
+        let above = Vec3::new(-1.0, 0.0, 0.0);
+        assert!(breaks_armor(ARMORED_BRICK_BOTTOM, brick, below, &grid));
+        assert!(!breaks_armor(ARMORED_BRICK_BOTTOM, brick, above, &grid));
+        assert!(breaks_armor(ARMORED_BRICK_TOP, brick, above, &grid));
+        assert!(!breaks_armor(ARMORED_BRICK_TOP, brick, below, &grid));
+        // Plain bricks break from every side
+        assert!(breaks_armor(20, brick, above, &grid));
+        assert!(breaks_armor(20, brick, below, &grid));
+    }
+}
@@ -0,0 +1,51 @@
+//! Assist mode.
+//!
+//! An accessibility option, turned on with `assist: true` in `config/gameplay.ron`
+//! (`GameplayConfig::assist`). Every time a level is loaded or restarted, an emergency barrier is
+//! put up across the lower goal: a ball-save shield (`systems::ball_save`) marked
+//! [`AssistShield`]. The first ball lost on the level bounces back off it instead of costing a
+//! life; after that the level plays as usual. A barrier left unused is replaced by the next
+//! level's, so the forgiveness does not pile up.
+//!
+//! `ui::assist_notice` tells the player while the barrier is up and when it saves a ball.
+
+use bevy::prelude::*;
+
+use crate::gameplay_config::GameplayConfig;
+use crate::level_loader::CurrentLevel;
+use crate::systems::ball_save::spawn_ball_save_shield;
+
+/// Marker for the barrier put up by assist mode.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct AssistShield;
+
+/// Put up a fresh barrier for the level just loaded.
+pub fn grant_assist_shield(
+    mut commands: Commands,
+    gameplay: Option<Res<GameplayConfig>>,
+    existing: Query<Entity, With<AssistShield>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+    if !gameplay.is_some_and(|config| config.assist) {
+        return;
+    }
+    let shield = spawn_ball_save_shield(&mut commands, &mut meshes, &mut materials);
+    commands.entity(shield).insert(AssistShield);
+    info!("Assist barrier up");
+}
+
+/// Plugin registering assist mode.
+pub struct AssistPlugin;
+
+impl Plugin for AssistPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            grant_assist_shield.run_if(resource_exists_and_changed::<CurrentLevel>),
+        );
+    }
+}
@@ -10,7 +10,9 @@
 //! - [`AudioPlugin`] registers all audio resources and message consumer systems
 //! - [`consume_ui_beep_messages`] reads [`UiBeep`](crate::signals::UiBeep) messages
 //! - [`consume_brick_destroyed_messages`] reads [`BrickDestroyed`](crate::signals::BrickDestroyed) messages
-//! - [`AudioConfig`] stores user-adjustable volume and mute settings
+//! - [`AudioConfig`] stores user-adjustable volume and mute settings, per mixer bus ([`AudioBus`])
+//! - [`AudioMixer`] ducks buses under other sounds ([`DUCKING_RULES`]); with [`limiter_gain`] it
+//!   sets the volume of playing one-shots in [`mix_one_shots`]
 //! - [`AudioAssets`] holds loaded audio asset handles keyed by [`SoundType`]
 //! - [`ActiveSounds`] tracks concurrent playback to limit simultaneous sounds
 //!
@@ -26,15 +28,19 @@
 //! - **BrickDestroyed**: Consumed by [`consume_brick_destroyed_messages`] system
 //!   - Single message path (no dual producer/consumer)
 //!   - Fired by collision/despawn systems with destruction context
-//!   - Produces: Brick break sound effect based on brick type
+//!   - Produces: Brick break sound effect based on brick type: the brick type's own sound from
+//!     the manifest's `brick_sounds` table, brick 41's unique sound, or `BrickDestroy`
 //!
 //! # Sound Types
 //!
 //! The system supports 8 distinct sound effects:
 //!
 //! - `BrickDestroy` - Standard brick destruction
-//! - `MultiHitImpact` - Multi-hit brick damage (indices 10-13)
+//! - `MultiHitImpact` - Multi-hit brick damage (indices 10-13) and hits on durable bricks
+//!   (`systems::durability`), pitched lower the fewer hits the brick has left ([`impact_speed`])
 //! - `WallBounce` - Ball bounces off wall
+//! - `BallBrickBounce` - Ball bounces off a brick that survives the hit (indestructible,
+//!   multi-hit, ...), on top of any impact sound of the brick
 //! - `PaddleHit` - Ball bounces off paddle
 //! - `PaddleWallHit` - Paddle collides with wall
 //! - `PaddleBrickHit` - Paddle collides with brick
@@ -48,6 +54,26 @@
 //! - [`AudioSystems::Update`]: Message consumer systems run here
 //! - [`AudioSystems::Cleanup`]: Remove stale active sound tracking
 //!
+//! # Mixing
+//!
+//! Every sound plays on a bus: UI beeps on [`AudioBus::Ui`], theme music on
+//! [`AudioBus::Music`], everything else (level jingles included) on [`AudioBus::Sfx`]. A sound's
+//! volume is master x bus volume, times the bus's ducking gain: the music drops to 30% while the
+//! level complete jingle plays. When more than [`LIMITER_VOICES`] one-shots play at once, the
+//! limiter turns each down so their sum stays at that level instead of clipping.
+//!
+//! # Pause and Respawn Safety
+//!
+//! Every one-shot carries a [`QueuedSound`] marker. In `PostUpdate`, before Bevy starts queued
+//! playback, [`sync_playback_with_pause`] pauses every playing sink when the game pauses and
+//! resumes exactly those sinks on resume, and [`gate_queued_sounds`] drops:
+//! - gameplay one-shots requested while paused or while the respawn fade is on screen (playing
+//!   gameplay one-shots are stopped when the fade starts), and
+//! - any one-shot still waiting for its asset after [`MAX_QUEUED_SOUND_SECS`], so late sounds do
+//!   not pile up and play out of context.
+//!
+//! UI beeps and level start/complete jingles are not gameplay sounds and are never gated.
+//!
 //! # Graceful Degradation
 //!
 //! The system handles missing audio assets gracefully by logging warnings
@@ -67,12 +93,15 @@
 //! ```
 
 use crate::signals::{
-    BallWallHit, BrickDestroyed as BrickDestroyedMsg, MerkabaBrickCollision,
-    MerkabaPaddleCollision, MerkabaWallCollision, UiBeep,
+    BallBrickBounce, BallWallHit, BrickDestroyed as BrickDestroyedMsg, BumperHit,
+    MerkabaBrickCollision, MerkabaPaddleCollision, MerkabaWallCollision, UiBeep,
+};
+use crate::systems::profiles::{profile_path, profile_switched, CurrentProfile};
+use crate::systems::sound_packs::{
+    load_sound_packs, sound_pack_files, switch_sound_pack, SoundFiles, SoundPacks,
 };
 use bevy::ecs::message::MessageReader;
 use bevy::prelude::*;
-use ron::de::from_str;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 #[cfg(target_arch = "wasm32")]
@@ -91,6 +120,12 @@ pub enum AudioSystems {
 /// Maximum number of concurrent sounds of the same type.
 const MAX_CONCURRENT_SOUNDS: u8 = 4;
 
+/// One-shots that have not started this long (real time) after being requested are dropped.
+pub const MAX_QUEUED_SOUND_SECS: f32 = 0.25;
+
+/// Pitch drop of the multi-hit impact per hit the brick has already taken.
+pub const MULTI_HIT_PITCH_STEP: f32 = 0.08;
+
 /// Identifies the category of sound effect for mapping and concurrent tracking.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SoundType {
@@ -122,6 +157,59 @@ pub enum SoundType {
     MerkabaLoop,
     /// Brick 41 (Extra Life) unique destruction sound.
     Brick41ExtraLife,
+    /// Ball kicked by a bumper (index 16).
+    BumperHit,
+    /// Ball bounces off a brick that survives the hit.
+    BallBrickBounce,
+}
+
+impl SoundType {
+    /// Every sound type, in declaration order.
+    pub const ALL: [SoundType; 16] = [
+        SoundType::BrickDestroy,
+        SoundType::MultiHitImpact,
+        SoundType::WallBounce,
+        SoundType::PaddleHit,
+        SoundType::PaddleWallHit,
+        SoundType::PaddleBrickHit,
+        SoundType::LevelStart,
+        SoundType::LevelComplete,
+        SoundType::UiBeep,
+        SoundType::MerkabaWall,
+        SoundType::MerkabaBrick,
+        SoundType::MerkabaPaddle,
+        SoundType::MerkabaLoop,
+        SoundType::Brick41ExtraLife,
+        SoundType::BumperHit,
+        SoundType::BallBrickBounce,
+    ];
+
+    /// Mixer bus the sound plays on.
+    pub fn bus(self) -> AudioBus {
+        match self {
+            SoundType::UiBeep => AudioBus::Ui,
+            _ => AudioBus::Sfx,
+        }
+    }
+
+    /// Whether this sound belongs to gameplay (silenced while paused or during the respawn fade).
+    pub fn is_gameplay(self) -> bool {
+        !matches!(
+            self,
+            SoundType::UiBeep | SoundType::LevelStart | SoundType::LevelComplete
+        )
+    }
+}
+
+/// Mixer bus, with its own volume in [`AudioConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AudioBus {
+    /// Sound effects, level jingles included.
+    Sfx,
+    /// Looping music (theme packs).
+    Music,
+    /// Menu feedback beeps.
+    Ui,
 }
 
 /// User-adjustable audio settings, persisted across sessions.
@@ -129,15 +217,35 @@ pub enum SoundType {
 /// # Fields
 ///
 /// - `master_volume` - Global volume multiplier (0.0 to 1.0)
+/// - `sfx_volume`, `music_volume`, `ui_volume` - Volumes of the mixer buses (0.0 to 1.0), under
+///   the master volume
 /// - `muted` - Whether audio is muted
+/// - `visual_cues` - Whether important sounds also show on-screen pips
+/// - `sound_pack` - Sound pack to play (see `systems::sound_packs`)
 #[derive(Resource, Debug, Clone, Serialize, Deserialize)]
 pub struct AudioConfig {
     /// Global volume multiplier (0.0 to 1.0).
     #[serde(default = "default_volume")]
     pub master_volume: f32,
+    /// Volume of the sound effects bus (0.0 to 1.0).
+    #[serde(default = "default_volume")]
+    pub sfx_volume: f32,
+    /// Volume of the music bus (0.0 to 1.0).
+    #[serde(default = "default_volume")]
+    pub music_volume: f32,
+    /// Volume of the UI bus (0.0 to 1.0).
+    #[serde(default = "default_volume")]
+    pub ui_volume: f32,
     /// Whether audio is muted.
     #[serde(default)]
     pub muted: bool,
+    /// Show directional visual pips for important sounds (see `ui::sound_cues`).
+    #[serde(default)]
+    pub visual_cues: bool,
+    /// Id of the sound pack to play; the default pack if `None`. A theme pack's sound pack
+    /// takes precedence.
+    #[serde(default)]
+    pub sound_pack: Option<String>,
 }
 
 fn default_volume() -> f32 {
@@ -148,7 +256,12 @@ impl Default for AudioConfig {
     fn default() -> Self {
         Self {
             master_volume: 1.0,
+            sfx_volume: 1.0,
+            music_volume: 1.0,
+            ui_volume: 1.0,
             muted: false,
+            visual_cues: false,
+            sound_pack: None,
         }
     }
 }
@@ -158,7 +271,12 @@ impl AudioConfig {
     pub fn new(master_volume: f32, muted: bool) -> Self {
         Self {
             master_volume: master_volume.clamp(0.0, 1.0),
+            sfx_volume: 1.0,
+            music_volume: 1.0,
+            ui_volume: 1.0,
             muted,
+            visual_cues: false,
+            sound_pack: None,
         }
     }
 
@@ -167,15 +285,57 @@ impl AudioConfig {
         self.master_volume = volume.clamp(0.0, 1.0);
     }
 
+    /// Set the volume of `bus` (clamped to 0.0-1.0).
+    pub fn set_bus_volume(&mut self, bus: AudioBus, volume: f32) {
+        let volume = volume.clamp(0.0, 1.0);
+        match bus {
+            AudioBus::Sfx => self.sfx_volume = volume,
+            AudioBus::Music => self.music_volume = volume,
+            AudioBus::Ui => self.ui_volume = volume,
+        }
+    }
+
+    /// Volume sounds on `bus` play at: the bus volume under the master volume, 0.0 while muted.
+    pub fn bus_volume(&self, bus: AudioBus) -> f32 {
+        if self.muted {
+            return 0.0;
+        }
+        let bus_volume = match bus {
+            AudioBus::Sfx => self.sfx_volume,
+            AudioBus::Music => self.music_volume,
+            AudioBus::Ui => self.ui_volume,
+        };
+        self.master_volume * bus_volume
+    }
+
+    /// Clamp every volume to 0.0-1.0.
+    pub fn clamp_volumes(&mut self) {
+        for volume in [
+            &mut self.master_volume,
+            &mut self.sfx_volume,
+            &mut self.music_volume,
+            &mut self.ui_volume,
+        ] {
+            *volume = volume.clamp(0.0, 1.0);
+        }
+    }
+
     /// Toggle mute state and return the new state.
     pub fn toggle_mute(&mut self) -> bool {
         self.muted = !self.muted;
         self.muted
     }
 
-    /// Check if the audio config is valid (volume in range).
+    /// Check if the audio config is valid (volumes in range).
     pub fn is_valid(&self) -> bool {
-        (0.0..=1.0).contains(&self.master_volume)
+        [
+            self.master_volume,
+            self.sfx_volume,
+            self.music_volume,
+            self.ui_volume,
+        ]
+        .iter()
+        .all(|volume| (0.0..=1.0).contains(volume))
     }
 }
 
@@ -184,13 +344,42 @@ impl AudioConfig {
 pub struct AudioAssets {
     /// Map of sound types to their loaded audio handles.
     pub sounds: HashMap<SoundType, Handle<AudioSource>>,
+    /// Destruction sounds of particular brick types, keyed by `BrickTypeId`.
+    pub brick_sounds: HashMap<u8, Handle<AudioSource>>,
+    /// Sound pack the handles belong to; the default pack if `None`.
+    pub pack: Option<String>,
 }
 
 impl AudioAssets {
+    /// Replace the handles with those of the sound files `files` (asset paths).
+    pub fn load(&mut self, asset_server: &AssetServer, files: &SoundFiles) {
+        self.sounds = files
+            .sounds
+            .iter()
+            .map(|(sound_type, path)| {
+                debug!(target: "audio", ?sound_type, %path, "Loaded audio asset");
+                (*sound_type, asset_server.load(path.clone()))
+            })
+            .collect();
+        self.brick_sounds = files
+            .bricks
+            .iter()
+            .map(|(brick_type, path)| {
+                debug!(target: "audio", brick_type, %path, "Loaded brick audio asset");
+                (*brick_type, asset_server.load(path.clone()))
+            })
+            .collect();
+    }
+
     /// Get the audio handle for a specific sound type.
     pub fn get(&self, sound_type: SoundType) -> Option<&Handle<AudioSource>> {
         self.sounds.get(&sound_type)
     }
+
+    /// Get the destruction sound of bricks of `brick_type`, if the brick type has its own.
+    pub fn brick_sound(&self, brick_type: u8) -> Option<&Handle<AudioSource>> {
+        self.brick_sounds.get(&brick_type)
+    }
 }
 
 /// Tracks concurrent playback count per sound type.
@@ -234,10 +423,111 @@ pub struct ActiveAudioInstances {
     pub instances: HashMap<Entity, SoundType>,
 }
 
+/// Marker on a one-shot audio entity, tracking how long it has waited to start.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct QueuedSound {
+    pub sound_type: SoundType,
+    /// Real seconds spent waiting for playback to start.
+    pub waited: f32,
+}
+
+/// Marker on sinks paused by [`sync_playback_with_pause`], so only those are resumed.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct PausedByGame;
+
+/// Audio manifest file, relative to the working directory; sound files resolve in `assets/audio/`.
+pub const AUDIO_MANIFEST_PATH: &str = "assets/audio/manifest.ron";
+
+/// Sounds loaded when the manifest has no entry for them (asset paths).
+pub const PLACEHOLDER_SOUNDS: [(SoundType, &str); 4] = [
+    (SoundType::MerkabaWall, "audio/merkaba_wall.ogg"),
+    (SoundType::MerkabaBrick, "audio/merkaba_brick.ogg"),
+    (SoundType::MerkabaPaddle, "audio/merkaba_paddle.ogg"),
+    (SoundType::MerkabaLoop, "audio/merkaba_loop_helicopter.ogg"),
+];
+
 /// Audio manifest for deserializing the audio configuration file.
 #[derive(Debug, Deserialize)]
-struct AudioManifest {
-    sounds: HashMap<SoundType, String>,
+pub struct AudioManifest {
+    pub sounds: HashMap<SoundType, String>,
+    /// Destruction sounds of particular brick types, keyed by `BrickTypeId`; other bricks play
+    /// `BrickDestroy`.
+    #[serde(default)]
+    pub brick_sounds: HashMap<u8, String>,
+}
+
+/// Lower `bus` to `gain` for `secs` (real time) whenever a `trigger` sound starts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuckingRule {
+    pub trigger: SoundType,
+    pub bus: AudioBus,
+    pub gain: f32,
+    pub secs: f32,
+}
+
+/// Ducking rules of the mixer: the music steps back for the level complete jingle.
+pub const DUCKING_RULES: [DuckingRule; 1] = [DuckingRule {
+    trigger: SoundType::LevelComplete,
+    bus: AudioBus::Music,
+    gain: 0.3,
+    secs: 3.0,
+}];
+
+/// Seconds at the end of a duck over which the bus returns to full volume.
+pub const DUCK_RELEASE_SECS: f32 = 0.5;
+
+/// One-shots that play together at full volume; above that the limiter turns them down.
+pub const LIMITER_VOICES: usize = 6;
+
+/// Gain the limiter applies to each of `voices` simultaneous one-shots, keeping their summed
+/// level at that of [`LIMITER_VOICES`] full-volume sounds so bursts of sounds do not clip.
+pub fn limiter_gain(voices: usize) -> f32 {
+    if voices <= LIMITER_VOICES {
+        1.0
+    } else {
+        LIMITER_VOICES as f32 / voices as f32
+    }
+}
+
+/// Runtime state of the mixer buses: which buses are ducked, how far and for how long.
+#[derive(Resource, Debug, Default)]
+pub struct AudioMixer {
+    /// Ducked buses -> (gain, real seconds left)
+    ducks: HashMap<AudioBus, (f32, f32)>,
+}
+
+impl AudioMixer {
+    /// Apply `rule`; overlapping ducks keep the deeper gain and the longer time.
+    pub fn duck(&mut self, rule: &DuckingRule) {
+        let duck = self.ducks.entry(rule.bus).or_insert((1.0, 0.0));
+        duck.0 = duck.0.min(rule.gain.clamp(0.0, 1.0));
+        duck.1 = duck.1.max(rule.secs);
+    }
+
+    /// Current ducking gain of `bus`, easing back to 1.0 over the last [`DUCK_RELEASE_SECS`].
+    pub fn gain(&self, bus: AudioBus) -> f32 {
+        let Some(&(gain, remaining)) = self.ducks.get(&bus) else {
+            return 1.0;
+        };
+        if remaining >= DUCK_RELEASE_SECS {
+            gain
+        } else {
+            1.0 - (1.0 - gain) * (remaining / DUCK_RELEASE_SECS).max(0.0)
+        }
+    }
+
+    /// Advance the ducks by `secs`, releasing the finished ones.
+    pub fn tick(&mut self, secs: f32) {
+        for duck in self.ducks.values_mut() {
+            duck.1 -= secs;
+        }
+        self.ducks.retain(|_, duck| duck.1 > 0.0);
+    }
+
+    /// Whether any bus is ducked.
+    pub fn is_ducking(&self) -> bool {
+        !self.ducks.is_empty()
+    }
 }
 
 /// Audio plugin that registers all audio resources and systems.
@@ -246,28 +536,145 @@ pub struct AudioPlugin;
 impl Plugin for AudioPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AudioAssets>()
+            .init_resource::<SoundPacks>()
             .init_resource::<ActiveSounds>()
             .init_resource::<ActiveAudioInstances>()
             .init_resource::<AudioLoopState>()
+            .init_resource::<AudioMixer>()
             .add_message::<UiBeep>()
             .add_message::<MerkabaWallCollision>()
             .add_message::<MerkabaBrickCollision>()
+            .add_message::<BumperHit>()
             .add_systems(Startup, load_audio_config)
-            .add_systems(Startup, load_audio_assets)
+            .add_systems(Update, load_audio_config.run_if(profile_switched))
+            .add_systems(Startup, (load_sound_packs, load_audio_assets))
+            .add_systems(Update, switch_sound_pack)
             .add_systems(Update, save_audio_config_on_change)
             .add_systems(Update, cleanup_finished_sounds)
             .add_observer(on_multi_hit_brick_sound)
+            .add_observer(on_durable_brick_sound)
             .add_observer(on_paddle_ball_hit_sound)
             .add_observer(on_paddle_wall_hit_sound)
             .add_observer(on_paddle_brick_hit_sound)
             .add_observer(on_ball_wall_hit_sound)
+            .add_observer(on_ball_brick_bounce_sound)
             .add_observer(on_level_started_sound)
             .add_observer(on_level_complete_sound)
             .add_observer(on_merkaba_paddle_collision_sound)
             .add_systems(Update, consume_brick_destroyed_messages)
             .add_systems(Update, consume_ui_beep_messages)
             .add_systems(Update, consume_merkaba_wall_collision_messages)
-            .add_systems(Update, consume_merkaba_brick_collision_messages);
+            .add_systems(Update, consume_merkaba_brick_collision_messages)
+            .add_systems(Update, consume_bumper_hit_messages)
+            .add_systems(Update, (duck_under_new_sounds, mix_one_shots).chain())
+            // Before `TransformSystems::Propagate`, i.e. before Bevy starts queued playback
+            .add_systems(
+                PostUpdate,
+                (sync_playback_with_pause, gate_queued_sounds)
+                    .chain()
+                    .in_set(AudioSystems::Cleanup)
+                    .before(bevy::transform::TransformSystems::Propagate),
+            );
+    }
+}
+
+/// Pause every playing sink when the game pauses; resume them when it resumes.
+pub fn sync_playback_with_pause(
+    pause_state: Option<Res<crate::pause::PauseState>>,
+    mut commands: Commands,
+    sinks: Query<(Entity, &AudioSink), Without<PausedByGame>>,
+    paused_sinks: Query<(Entity, &AudioSink), With<PausedByGame>>,
+) {
+    let Some(pause_state) = pause_state else {
+        return;
+    };
+    if !pause_state.is_changed() {
+        return;
+    }
+    if matches!(*pause_state, crate::pause::PauseState::Paused { .. }) {
+        for (entity, sink) in sinks.iter() {
+            if !sink.is_paused() {
+                sink.pause();
+                commands.entity(entity).insert(PausedByGame);
+            }
+        }
+        debug!(target: "audio", "Paused in-flight audio");
+    } else {
+        for (entity, sink) in paused_sinks.iter() {
+            sink.play();
+            commands.entity(entity).remove::<PausedByGame>();
+        }
+        debug!(target: "audio", "Resumed paused audio");
+    }
+}
+
+/// Drop stale or gated one-shots before they start, and stop gameplay sounds during the respawn
+/// fade.
+pub fn gate_queued_sounds(
+    pause_state: Option<Res<crate::pause::PauseState>>,
+    fade: Query<(), With<crate::systems::respawn::RespawnFadeOverlay>>,
+    time: Res<Time<Real>>,
+    mut commands: Commands,
+    mut sounds: Query<(Entity, &mut QueuedSound, Has<AudioSink>)>,
+) {
+    let paused =
+        pause_state.is_some_and(|state| matches!(*state, crate::pause::PauseState::Paused { .. }));
+    let fading = !fade.is_empty();
+    for (entity, mut queued, started) in sounds.iter_mut() {
+        let gated = queued.sound_type.is_gameplay() && (fading || (paused && !started));
+        let stale = !started && queued.waited > MAX_QUEUED_SOUND_SECS;
+        if gated || stale {
+            debug!(
+                target: "audio",
+                sound_type = ?queued.sound_type,
+                gated,
+                stale,
+                "Dropped queued sound"
+            );
+            // Despawning fires `RemovedComponents<AudioPlayer>`, which releases the count
+            commands.entity(entity).despawn();
+        } else if !started {
+            queued.waited += time.delta_secs();
+        }
+    }
+}
+
+/// Duck the buses named by the [`DUCKING_RULES`] of newly requested one-shots.
+pub fn duck_under_new_sounds(
+    sounds: Query<&QueuedSound, Added<QueuedSound>>,
+    mut mixer: ResMut<AudioMixer>,
+) {
+    for queued in sounds.iter() {
+        for rule in DUCKING_RULES
+            .iter()
+            .filter(|rule| rule.trigger == queued.sound_type)
+        {
+            mixer.duck(rule);
+            debug!(target: "audio", bus = ?rule.bus, gain = rule.gain, "Ducking bus");
+        }
+    }
+}
+
+/// Keep playing one-shots at their bus volume, under the ducking gain and the limiter.
+pub fn mix_one_shots(
+    time: Res<Time<Real>>,
+    config: Option<Res<AudioConfig>>,
+    mut mixer: ResMut<AudioMixer>,
+    mut sinks: Query<(&QueuedSound, &mut AudioSink)>,
+) {
+    if mixer.is_ducking() {
+        mixer.tick(time.delta_secs());
+    }
+    let Some(config) = config else {
+        return;
+    };
+    let limiter = limiter_gain(sinks.iter().count());
+    for (queued, mut sink) in sinks.iter_mut() {
+        let bus = queued.sound_type.bus();
+        let volume = config.bus_volume(bus) * mixer.gain(bus) * limiter;
+        if sink.volume().to_linear() != volume {
+            sink.set_volume(bevy::audio::Volume::Linear(volume));
+        }
     }
 }
 
@@ -288,17 +695,20 @@ fn cleanup_finished_sounds(
 // UiBeep buffered message consumed via MessageReader
 
 /// Path to the audio config file.
-const AUDIO_CONFIG_PATH: &str = "config/audio.ron";
+pub const AUDIO_CONFIG_PATH: &str = "config/audio.ron";
 
-/// Load audio configuration from disk or use defaults.
-fn load_audio_config(mut commands: Commands) {
+/// Load audio configuration from disk (the active profile's, see `systems::profiles`) or use
+/// defaults.
+#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+fn load_audio_config(mut commands: Commands, profile: Option<Res<CurrentProfile>>) {
     #[cfg(not(target_arch = "wasm32"))]
     let config = {
-        match std::fs::read_to_string(AUDIO_CONFIG_PATH) {
+        let path = profile_path(profile.as_deref(), "audio.ron", AUDIO_CONFIG_PATH);
+        match std::fs::read_to_string(&path) {
             Ok(content) => match ron::de::from_str::<AudioConfig>(&content) {
                 Ok(mut loaded) => {
-                    // Ensure volume is in valid range
-                    loaded.master_volume = loaded.master_volume.clamp(0.0, 1.0);
+                    // Ensure volumes are in valid range
+                    loaded.clamp_volumes();
                     info!(
                         target: "audio",
                         volume = loaded.master_volume,
@@ -337,7 +747,7 @@ fn load_audio_config(mut commands: Commands) {
                 if let Ok(Some(item)) = storage.get_item(storage_key) {
                     match ron::de::from_str::<AudioConfig>(&item) {
                         Ok(mut loaded) => {
-                            loaded.master_volume = loaded.master_volume.clamp(0.0, 1.0);
+                            loaded.clamp_volumes();
                             info!(
                                 target: "audio",
                                 volume = loaded.master_volume,
@@ -373,15 +783,17 @@ fn load_audio_config(mut commands: Commands) {
 }
 
 /// Save audio configuration when it changes.
-fn save_audio_config_on_change(config: Res<AudioConfig>) {
+#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+fn save_audio_config_on_change(config: Res<AudioConfig>, profile: Option<Res<CurrentProfile>>) {
     if !config.is_changed() {
         return;
     }
 
     #[cfg(not(target_arch = "wasm32"))]
     {
+        let path = profile_path(profile.as_deref(), "audio.ron", AUDIO_CONFIG_PATH);
         // Ensure config directory exists
-        if let Some(parent) = std::path::Path::new(AUDIO_CONFIG_PATH).parent() {
+        if let Some(parent) = path.parent() {
             if let Err(e) = std::fs::create_dir_all(parent) {
                 warn!(
                     target: "audio",
@@ -406,7 +818,7 @@ fn save_audio_config_on_change(config: Res<AudioConfig>) {
             }
         };
 
-        if let Err(e) = std::fs::write(AUDIO_CONFIG_PATH, content) {
+        if let Err(e) = std::fs::write(&path, content) {
             warn!(
                 target: "audio",
                 error = %e,
@@ -462,7 +874,7 @@ fn save_audio_config_on_change(config: Res<AudioConfig>) {
     }
 }
 
-/// Load audio assets from the manifest file.
+/// Load the default pack's audio assets from the manifest file.
 /// If an `AssetServer` resource is not available (e.g., in minimal test setups),
 /// gracefully skip loading and leave `AudioAssets` empty.
 fn load_audio_assets(
@@ -485,89 +897,15 @@ fn load_audio_assets(
         return;
     }
 
-    // Try to read the manifest file
-    #[cfg(not(target_arch = "wasm32"))]
-    let manifest_content = std::fs::read_to_string("assets/audio/manifest.ron");
-    #[cfg(target_arch = "wasm32")]
-    // Use an absolute path based on the crate root so moving this file doesn't
-    // break the include. `env!("CARGO_MANIFEST_DIR")` is evaluated at compile
-    // time and `concat!` produces a literal suitable for `include_str!`.
-    let manifest_content: Result<&str, &str> = Ok(include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/assets/audio/manifest.ron"
-    )));
-
-    match manifest_content {
-        Ok(content) => {
-            #[cfg(not(target_arch = "wasm32"))]
-            let content_ref = content.as_str();
-            #[cfg(target_arch = "wasm32")]
-            let content_ref = content;
-
-            match from_str::<AudioManifest>(content_ref) {
-                Ok(manifest) => {
-                    for (sound_type, file_name) in manifest.sounds {
-                        let path = format!("audio/{}", file_name);
-                        let handle: Handle<AudioSource> = asset_server.load(&path);
-                        audio_assets.sounds.insert(sound_type, handle);
-                        debug!(
-                            target: "audio",
-                            ?sound_type,
-                            %path,
-                            "Loaded audio asset"
-                        );
-                    }
-                    info!(
-                        target: "audio",
-                        count = audio_assets.sounds.len(),
-                        "Audio assets loaded from manifest"
-                    );
-                }
-                Err(e) => {
-                    warn!(
-                        target: "audio",
-                        error = %e,
-                        "Failed to parse audio manifest, audio will be disabled"
-                    );
-                }
-            }
-        }
-        Err(_e) => {
-            warn!(
-                target: "audio",
-                "Audio manifest not found, audio will be disabled"
-            );
-        }
-    }
-
-    // Ensure merkaba audio handles exist even if the manifest is missing entries.
-    // This reuses the placeholder assets added in Phase 1.
-    let insert_placeholder = |assets: &mut AudioAssets, ty: SoundType, path: &'static str| {
-        assets.sounds.entry(ty).or_insert_with(|| {
-            let handle: Handle<AudioSource> = asset_server.load(path);
-            handle
-        });
-    };
-
-    insert_placeholder(
-        &mut audio_assets,
-        SoundType::MerkabaWall,
-        "audio/merkaba_wall.ogg",
-    );
-    insert_placeholder(
-        &mut audio_assets,
-        SoundType::MerkabaBrick,
-        "audio/merkaba_brick.ogg",
-    );
-    insert_placeholder(
-        &mut audio_assets,
-        SoundType::MerkabaPaddle,
-        "audio/merkaba_paddle.ogg",
+    // The default pack; `switch_sound_pack` follows the configured one once it is loaded
+    audio_assets.load(
+        &asset_server,
+        &sound_pack_files(&SoundPacks::default(), None),
     );
-    insert_placeholder(
-        &mut audio_assets,
-        SoundType::MerkabaLoop,
-        "audio/merkaba_loop_helicopter.ogg",
+    info!(
+        target: "audio",
+        count = audio_assets.sounds.len(),
+        "Audio assets loaded from manifest"
     );
 }
 
@@ -592,13 +930,58 @@ fn play_sound(
     active_instances: &mut ActiveAudioInstances,
     commands: &mut Commands,
 ) {
-    // Check if muted
-    if config.muted {
-        return;
-    }
+    play_sound_at_speed(
+        sound_type,
+        1.0,
+        config,
+        assets,
+        audio_sources,
+        active_sounds,
+        active_instances,
+        commands,
+    );
+}
+
+/// Like [`play_sound`], at playback `speed` (which shifts the pitch as well: 0.5 is an octave
+/// lower).
+#[allow(clippy::too_many_arguments)]
+fn play_sound_at_speed(
+    sound_type: SoundType,
+    speed: f32,
+    config: &AudioConfig,
+    assets: &AudioAssets,
+    audio_sources: Option<&Assets<AudioSource>>,
+    active_sounds: &mut ActiveSounds,
+    active_instances: &mut ActiveAudioInstances,
+    commands: &mut Commands,
+) {
+    play_handle_at_speed(
+        sound_type,
+        assets.get(sound_type),
+        speed,
+        config,
+        audio_sources,
+        active_sounds,
+        active_instances,
+        commands,
+    );
+}
 
-    // Check if volume is effectively zero
-    if config.master_volume <= 0.0 {
+/// Like [`play_sound_at_speed`], playing `handle` and counting it as `sound_type`.
+#[allow(clippy::too_many_arguments)]
+fn play_handle_at_speed(
+    sound_type: SoundType,
+    handle: Option<&Handle<AudioSource>>,
+    speed: f32,
+    config: &AudioConfig,
+    audio_sources: Option<&Assets<AudioSource>>,
+    active_sounds: &mut ActiveSounds,
+    active_instances: &mut ActiveAudioInstances,
+    commands: &mut Commands,
+) {
+    // Check if muted or the bus is silent
+    let bus_volume = config.bus_volume(sound_type.bus());
+    if bus_volume <= 0.0 {
         return;
     }
 
@@ -613,7 +996,7 @@ fn play_sound(
     }
 
     // Get the audio handle
-    let Some(handle) = assets.get(sound_type) else {
+    let Some(handle) = handle else {
         warn!(
             target: "audio",
             ?sound_type,
@@ -636,6 +1019,9 @@ fn play_sound(
         }
     }
 
+    // Start under the limiter; `mix_one_shots` keeps the volume up to date while it plays
+    let volume = bus_volume * limiter_gain(active_instances.instances.len() + 1);
+
     // Spawn the audio player and record the spawned entity so we can
     // decrement the concurrent-count when playback finishes (entity despawn).
     let entity = commands
@@ -643,9 +1029,14 @@ fn play_sound(
             AudioPlayer::new(handle.clone()),
             PlaybackSettings {
                 mode: bevy::audio::PlaybackMode::Despawn,
-                volume: bevy::audio::Volume::Linear(config.master_volume),
+                volume: bevy::audio::Volume::Linear(volume),
+                speed,
                 ..default()
             },
+            QueuedSound {
+                sound_type,
+                waited: 0.0,
+            },
         ))
         .id();
 
@@ -656,11 +1047,27 @@ fn play_sound(
     debug!(
         target: "audio",
         ?sound_type,
-        volume = config.master_volume,
+        volume,
+        speed,
         "Playing sound"
     );
 }
 
+/// Playback speed of the impact on a brick with `hits_remaining` hits left after it: full pitch
+/// while it has four or more left (a fresh index 13 brick), a deeper thunk with every hit below
+/// that, the deepest when the next hit destroys it.
+pub fn impact_speed(hits_remaining: u8) -> f32 {
+    let durability =
+        crate::level_format::MULTI_HIT_BRICK_4 - crate::level_format::MULTI_HIT_BRICK_1 + 1;
+    let taken = durability.saturating_sub(hits_remaining.max(1));
+    1.0 - MULTI_HIT_PITCH_STEP * f32::from(taken)
+}
+
+/// Playback speed of the multi-hit impact for `hit` (see [`impact_speed`]).
+pub fn multi_hit_impact_speed(hit: &crate::systems::multi_hit::MultiHitBrickHit) -> f32 {
+    impact_speed(hit.hits_remaining())
+}
+
 // =============================================================================
 // Event definitions
 // =============================================================================
@@ -706,8 +1113,37 @@ fn on_multi_hit_brick_sound(
         new_type = event.new_type,
         "Multi-hit brick impact"
     );
-    play_sound(
+    play_sound_at_speed(
         SoundType::MultiHitImpact,
+        multi_hit_impact_speed(event),
+        &config,
+        &assets,
+        None,
+        &mut active_sounds,
+        &mut active_instances,
+        &mut commands,
+    );
+}
+
+/// Observer for the impact sound of durable bricks, shared with the multi-hit bricks.
+fn on_durable_brick_sound(
+    trigger: On<crate::systems::durability::DurableBrickHit>,
+    config: Res<AudioConfig>,
+    assets: Res<AudioAssets>,
+    mut active_sounds: ResMut<ActiveSounds>,
+    mut active_instances: ResMut<ActiveAudioInstances>,
+    mut commands: Commands,
+) {
+    let event = trigger.event();
+    debug!(
+        target: "audio",
+        entity = ?event.entity,
+        hits_left = event.hits_left,
+        "Durable brick impact"
+    );
+    play_sound_at_speed(
+        SoundType::MultiHitImpact,
+        impact_speed(event.hits_left),
         &config,
         &assets,
         None,
@@ -769,18 +1205,26 @@ fn consume_brick_destroyed_messages(
 
         let brick41_sound_available = brick41_available.unwrap_or(false);
 
-        let sound_type = if event.brick_type == crate::level_format::EXTRA_LIFE_BRICK
-            && brick41_sound_available
-        {
-            SoundType::Brick41ExtraLife
-        } else {
-            SoundType::BrickDestroy
+        // A brick type with its own sound plays it in place of the generic one
+        let (sound_type, handle) = match assets.brick_sound(event.brick_type) {
+            Some(handle) => (SoundType::BrickDestroy, Some(handle)),
+            None => {
+                let sound_type = if event.brick_type == crate::level_format::EXTRA_LIFE_BRICK
+                    && brick41_sound_available
+                {
+                    SoundType::Brick41ExtraLife
+                } else {
+                    SoundType::BrickDestroy
+                };
+                (sound_type, assets.get(sound_type))
+            }
         };
 
-        play_sound(
+        play_handle_at_speed(
             sound_type,
+            handle,
+            1.0,
             &config,
-            &assets,
             None,
             &mut active_sounds,
             &mut active_instances,
@@ -817,6 +1261,35 @@ pub(crate) fn on_ball_wall_hit_sound(
     );
 }
 
+/// Observer for the bounce sound of a ball off a brick that survives the hit.
+pub(crate) fn on_ball_brick_bounce_sound(
+    trigger: On<BallBrickBounce>,
+    config: Res<AudioConfig>,
+    assets: Res<AudioAssets>,
+    audio_sources: Option<Res<Assets<AudioSource>>>,
+    mut active_sounds: ResMut<ActiveSounds>,
+    mut active_instances: ResMut<ActiveAudioInstances>,
+    mut commands: Commands,
+) {
+    let event = trigger.event();
+    debug!(
+        target: "audio",
+        ball_entity = ?event.ball_entity,
+        brick_entity = ?event.brick_entity,
+        brick_type = event.brick_type,
+        "Ball brick bounce"
+    );
+    play_sound(
+        SoundType::BallBrickBounce,
+        &config,
+        &assets,
+        audio_sources.as_deref(),
+        &mut active_sounds,
+        &mut active_instances,
+        &mut commands,
+    );
+}
+
 /// Observer for paddle-ball hit sound (ball bounces off paddle).
 fn on_paddle_ball_hit_sound(
     trigger: On<crate::BallHit>,
@@ -953,6 +1426,94 @@ mod brick41_audio_tests {
             "Brick 41 should fall back to generic destroy sound when unique handle missing"
         );
     }
+
+    #[test]
+    fn brick_sound_table_overrides_generic_sound() {
+        let mut app = setup_app(false);
+        {
+            let mut assets = app.world_mut().resource_mut::<AudioAssets>();
+            assets.sounds.remove(&SoundType::BrickDestroy);
+            assets.brick_sounds.insert(90, make_handle());
+        }
+
+        for brick_type in [20, 90] {
+            let brick = app.world_mut().spawn_empty().id();
+            app.world_mut().write_message(BrickDestroyedMsg {
+                brick_entity: brick,
+                brick_type,
+                destroyed_by: None,
+            });
+            app.update();
+        }
+
+        // Only brick 90 has a sound without the generic one; it counts as a destruction sound
+        let counts = app.world().resource::<ActiveSounds>();
+        assert_eq!(1, counts.count(SoundType::BrickDestroy));
+    }
+}
+
+#[cfg(test)]
+mod multi_hit_audio_tests {
+    use super::*;
+    use crate::systems::multi_hit::MultiHitBrickHit;
+    use bevy::MinimalPlugins;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(AudioConfig::default());
+        let mut audio_assets = AudioAssets::default();
+        audio_assets
+            .sounds
+            .insert(SoundType::MultiHitImpact, Handle::default());
+        app.insert_resource(audio_assets);
+        app.insert_resource(ActiveSounds::default());
+        app.insert_resource(ActiveAudioInstances::default());
+        app.add_observer(on_multi_hit_brick_sound);
+        app
+    }
+
+    /// Playback speed of the impact played for a hit turning `previous_type` into `new_type`.
+    fn played_speed(previous_type: u8, new_type: u8) -> f32 {
+        let mut app = setup_app();
+        let brick = app.world_mut().spawn_empty().id();
+        app.world_mut().trigger(MultiHitBrickHit {
+            entity: brick,
+            previous_type,
+            new_type,
+        });
+        app.update();
+        let mut players = app
+            .world_mut()
+            .query_filtered::<&PlaybackSettings, With<AudioPlayer>>();
+        let speeds: Vec<f32> = players
+            .iter(app.world())
+            .map(|settings| settings.speed)
+            .collect();
+        assert_eq!(speeds.len(), 1, "one impact per hit");
+        speeds[0]
+    }
+
+    #[test]
+    fn impact_deepens_as_the_brick_wears_down() {
+        let speeds = [
+            played_speed(13, 12),
+            played_speed(12, 11),
+            played_speed(11, 10),
+            played_speed(10, 20),
+        ];
+        assert_eq!(speeds[0], 1.0, "a fresh brick keeps the sample's pitch");
+        assert!(speeds.windows(2).all(|pair| pair[1] < pair[0]));
+        assert!((speeds[3] - (1.0 - 3.0 * MULTI_HIT_PITCH_STEP)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn durable_bricks_deepen_only_near_the_end() {
+        assert_eq!(impact_speed(12), 1.0);
+        assert_eq!(impact_speed(4), 1.0);
+        assert!(impact_speed(2) < impact_speed(3));
+        assert_eq!(impact_speed(1), played_speed(10, 20));
+    }
 }
 
 /// Observer for paddle-brick hit sound.
@@ -1108,6 +1669,44 @@ fn consume_merkaba_wall_collision_messages(
     }
 }
 
+/// Message consumer for bumper hit sounds.
+fn consume_bumper_hit_messages(
+    reader: Option<MessageReader<BumperHit>>,
+    config: Option<Res<AudioConfig>>,
+    assets: Option<Res<AudioAssets>>,
+    active_sounds: Option<ResMut<ActiveSounds>>,
+    active_instances: Option<ResMut<ActiveAudioInstances>>,
+    mut commands: Commands,
+) {
+    let Some(mut reader) = reader else { return };
+    let Some(config) = config else { return };
+    let Some(assets) = assets else { return };
+    let Some(mut active_sounds) = active_sounds else {
+        return;
+    };
+    let Some(mut active_instances) = active_instances else {
+        return;
+    };
+
+    for event in reader.read() {
+        debug!(
+            target: "audio",
+            bumper_entity = ?event.bumper_entity,
+            ball_entity = ?event.ball_entity,
+            "Bumper hit"
+        );
+        play_sound(
+            SoundType::BumperHit,
+            &config,
+            &assets,
+            None,
+            &mut active_sounds,
+            &mut active_instances,
+            &mut commands,
+        );
+    }
+}
+
 /// Message consumer for merkaba-brick collision sounds.
 fn consume_merkaba_brick_collision_messages(
     reader: Option<MessageReader<MerkabaBrickCollision>>,
@@ -1206,6 +1805,49 @@ mod tests {
         assert_eq!(config.master_volume, 0.5);
     }
 
+    #[test]
+    fn bus_volume_is_under_master_volume() {
+        let mut config = AudioConfig::new(0.5, false);
+        config.set_bus_volume(AudioBus::Music, 0.4);
+        config.set_bus_volume(AudioBus::Ui, 3.0);
+        assert_eq!(config.bus_volume(AudioBus::Sfx), 0.5);
+        assert_eq!(config.bus_volume(AudioBus::Music), 0.2);
+        assert_eq!(config.bus_volume(AudioBus::Ui), 0.5);
+        assert_eq!(SoundType::UiBeep.bus(), AudioBus::Ui);
+        assert_eq!(SoundType::LevelComplete.bus(), AudioBus::Sfx);
+
+        config.muted = true;
+        assert_eq!(config.bus_volume(AudioBus::Sfx), 0.0);
+    }
+
+    #[test]
+    fn limiter_caps_the_summed_level() {
+        assert_eq!(limiter_gain(0), 1.0);
+        assert_eq!(limiter_gain(LIMITER_VOICES), 1.0);
+        assert_eq!(limiter_gain(LIMITER_VOICES * 2), 0.5);
+        for voices in LIMITER_VOICES..40 {
+            assert!(voices as f32 * limiter_gain(voices) <= LIMITER_VOICES as f32 + 1e-4);
+        }
+    }
+
+    #[test]
+    fn mixer_ducks_then_releases() {
+        let mut mixer = AudioMixer::default();
+        let rule = DUCKING_RULES[0];
+        mixer.duck(&rule);
+        assert!(mixer.is_ducking());
+        assert_eq!(mixer.gain(AudioBus::Music), rule.gain);
+        assert_eq!(mixer.gain(AudioBus::Sfx), 1.0);
+
+        mixer.tick(rule.secs - DUCK_RELEASE_SECS / 2.0);
+        let releasing = mixer.gain(AudioBus::Music);
+        assert!(releasing > rule.gain && releasing < 1.0);
+
+        mixer.tick(DUCK_RELEASE_SECS);
+        assert!(!mixer.is_ducking());
+        assert_eq!(mixer.gain(AudioBus::Music), 1.0);
+    }
+
     #[test]
     fn audio_config_toggle_mute() {
         let mut config = AudioConfig::default();
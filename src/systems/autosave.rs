@@ -0,0 +1,379 @@
+//! Crash-safe autosave of campaign progress.
+//!
+//! Progress is saved as soon as the level following a completed one has been loaded: the level to
+//! resume at, with the score and lives the player starts it with, and the highest level completed.
+//! A crash therefore never loses more than the level being played. The file is
+//! `config/progress.ron`, or `progress.ron` in the active profile's directory
+//! (`systems::profiles`).
+//!
+//! Writes are atomic ([`write_atomic`]): the content goes to a temporary file next to the target,
+//! which is flushed to disk and renamed over it, so the save file always holds either the old or
+//! the new progress. [`read_recovering`] cleans up after a crash in the middle of a write: a
+//! leftover temporary file is used if the save file itself is missing or unreadable, and discarded
+//! otherwise.
+//!
+//! In browsers the progress is kept in localStorage instead (`systems::web_storage`).
+//!
+//! At startup, and whenever the player switches profile, saved progress is resumed: the score and
+//! lives are restored and the saved level is loaded (unless a level was chosen at launch with
+//! `--level`, `BK_LEVEL` or `BK_LEVEL_PATH`). Completing the last level clears the resume point.
+//!
+//! Timed powerup effects are cleared on every level change, so a level starts without any. When
+//! the game is quit while playing the saved level, the effects running at that moment are added
+//! to the progress file ([`save_effects_on_exit`]) with their time left, and they are caught again
+//! once the resumed level is running ([`restore_saved_effects`]).
+
+use std::path::{Path, PathBuf};
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::launch::LaunchOptions;
+use crate::level_loader::CurrentLevel;
+use crate::systems::audio::LevelCompleted;
+use crate::systems::ball_size::BallSizeEffect;
+use crate::systems::level_switch::{LevelSwitchDirection, LevelSwitchRequested, LevelSwitchSource};
+use crate::systems::paddle_size::PaddleSizeEffect;
+use crate::systems::powerups::{
+    FastBall, PowerupCollected, PowerupSystems, ReversedControls, RubberPaddle,
+};
+use crate::systems::profiles::{profile_path, profile_switched, CurrentProfile};
+use crate::systems::respawn::LivesState;
+use crate::systems::scoring::ScoreState;
+use crate::systems::sticky_paddle::StickyPaddle;
+use crate::systems::timed_effects::{
+    clear_restored_effects, restore_effect_timers, ActiveEffects, EffectSnapshot, RestoredEffects,
+};
+use crate::systems::web_storage::{read_save_file, write_save_file};
+use crate::systems::wrecking_ball::WreckingBall;
+use crate::{GameProgress, Paddle};
+
+/// Path of the progress file while playing as a guest.
+pub const PROGRESS_PATH: &str = "config/progress.ron";
+/// File name of the progress file in a profile directory.
+pub const PROGRESS_FILE: &str = "progress.ron";
+/// Appended to a path while it is being written.
+pub const TEMP_SUFFIX: &str = ".tmp";
+
+/// Campaign progress as saved on disk.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SavedProgress {
+    /// Level to resume at; `None` once the game is complete
+    #[serde(default)]
+    pub level: Option<u32>,
+    /// Score when `level` was started
+    #[serde(default)]
+    pub score: u32,
+    /// Lives when `level` was started
+    #[serde(default)]
+    pub lives: u8,
+    /// Highest level number completed
+    #[serde(default)]
+    pub highest_level: u32,
+    /// Timed effects running when the game was quit during `level`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub effects: Vec<EffectSnapshot>,
+}
+
+/// Autosave bookkeeping.
+#[derive(Resource, Debug, Default)]
+pub struct AutosaveState {
+    /// A level was completed and the progress is saved once the next one is loaded
+    pub pending: bool,
+    /// Progress to resume once a level is loaded
+    pub resume: Option<SavedProgress>,
+    /// The last saved progress
+    pub saved: SavedProgress,
+    /// Level and effects to restore once that level is running
+    pub restore_effects: Option<(u32, Vec<EffectSnapshot>)>,
+}
+
+/// Path of the temporary file used while writing `path`.
+pub fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(TEMP_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Write `content` to `path` atomically: through a temporary file that is synced and renamed over
+/// `path`, creating the parent directory.
+pub fn write_atomic(path: &Path, content: &str) -> Result<(), String> {
+    use std::io::Write;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+    }
+    let temp = temp_path(path);
+    std::fs::File::create(&temp)
+        .and_then(|mut file| {
+            file.write_all(content.as_bytes())?;
+            file.sync_all()
+        })
+        .map_err(|e| format!("failed to write {}: {e}", temp.display()))?;
+    std::fs::rename(&temp, path).map_err(|e| format!("failed to replace {}: {e}", path.display()))
+}
+
+/// Read `path`, recovering from an interrupted [`write_atomic`]: if `path` is missing or `parse`
+/// rejects it, a leftover temporary file that parses is moved into place and used instead. Any
+/// other leftover is removed.
+pub fn read_recovering<T>(path: &Path, parse: impl Fn(&str) -> Option<T>) -> Option<T> {
+    let temp = temp_path(path);
+    let saved = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| parse(&content));
+    if !temp.exists() {
+        return saved;
+    }
+    if saved.is_some() {
+        let _ = std::fs::remove_file(&temp);
+        return saved;
+    }
+    let recovered = std::fs::read_to_string(&temp)
+        .ok()
+        .and_then(|content| parse(&content));
+    if recovered.is_some() {
+        warn!("Recovered {} from an interrupted save", path.display());
+        if let Err(e) = std::fs::rename(&temp, path) {
+            warn!("Failed to restore {}: {}", path.display(), e);
+        }
+    } else {
+        let _ = std::fs::remove_file(&temp);
+    }
+    recovered
+}
+
+/// Path of the progress file of `profile` (or the shared one for guests).
+pub fn progress_path(profile: Option<&CurrentProfile>) -> PathBuf {
+    profile_path(profile, PROGRESS_FILE, PROGRESS_PATH)
+}
+
+/// Read saved progress from `path`.
+pub fn load_progress(path: &Path) -> Option<SavedProgress> {
+    read_save_file(path, |content| {
+        ron::de::from_str(content)
+            .map_err(|e| warn!("Failed to parse {}: {}", path.display(), e))
+            .ok()
+    })
+}
+
+/// Write `progress` to `path` atomically.
+pub fn save_progress(path: &Path, progress: &SavedProgress) -> Result<(), String> {
+    let content = ron::ser::to_string_pretty(progress, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("failed to serialize progress: {e}"))?;
+    write_save_file(path, &content)
+}
+
+/// Load the progress of the active profile, to be resumed once a level is loaded.
+pub fn load_saved_progress(
+    mut state: ResMut<AutosaveState>,
+    profile: Option<Res<CurrentProfile>>,
+    launch: Option<Res<LaunchOptions>>,
+) {
+    let progress = load_progress(&progress_path(profile.as_deref()));
+
+    state.pending = false;
+    state.saved = progress.clone().unwrap_or_default();
+    state.resume = progress
+        .filter(|progress| progress.level.is_some())
+        .filter(|_| {
+            // A level chosen at launch (or through the environment) takes precedence
+            !launch.as_deref().map_or_else(
+                || LaunchOptions::from_env().level_chosen(),
+                LaunchOptions::level_chosen,
+            )
+        });
+}
+
+/// Resume loaded progress: restore score and lives and switch to the saved level.
+pub fn resume_saved_progress(
+    mut state: ResMut<AutosaveState>,
+    current_level: Option<Res<CurrentLevel>>,
+    mut switches: MessageWriter<LevelSwitchRequested>,
+    score: Option<ResMut<ScoreState>>,
+    lives: Option<ResMut<LivesState>>,
+) {
+    let Some(current_level) = current_level else {
+        return;
+    };
+    let Some(progress) = state.resume.take() else {
+        return;
+    };
+    let Some(level) = progress.level else {
+        return;
+    };
+    info!(
+        "Resuming at level {} with {} points and {} lives",
+        level, progress.score, progress.lives
+    );
+    if let Some(mut score) = score {
+        score.restore(progress.score);
+    }
+    if let Some(mut lives) = lives {
+        lives.lives_remaining = progress.lives.max(1);
+        lives.on_last_life = lives.lives_remaining == 1;
+    }
+    if !progress.effects.is_empty() {
+        state.restore_effects = Some((level, progress.effects));
+    }
+    if current_level.0.number != level {
+        switches.write(LevelSwitchRequested {
+            source: LevelSwitchSource::Resume,
+            direction: LevelSwitchDirection::Jump(level),
+        });
+    }
+}
+
+/// Catch the saved effects again once the resumed level is running, with their saved time left.
+///
+/// Waits for a frame after the level was loaded, when the effects of the level change have been
+/// cleared and the paddle is in place. The effects go through the usual capsule handling
+/// (`PowerupCollected`), and [`RestoredEffects`] sets their timers as their components are added.
+pub fn restore_saved_effects(
+    mut state: ResMut<AutosaveState>,
+    current_level: Option<Res<CurrentLevel>>,
+    paddles: Query<Entity, With<Paddle>>,
+    mut collected: MessageWriter<PowerupCollected>,
+    mut restored: ResMut<RestoredEffects>,
+) {
+    let Some((level, _)) = state.restore_effects else {
+        return;
+    };
+    let Some(current_level) = current_level else {
+        return;
+    };
+    if current_level.is_changed() || current_level.0.number != level {
+        return;
+    }
+    let Some(paddle) = paddles.iter().next() else {
+        return;
+    };
+    let Some((_, effects)) = state.restore_effects.take() else {
+        return;
+    };
+    for effect in &effects {
+        collected.write(PowerupCollected {
+            kind: effect.kind,
+            paddle,
+        });
+    }
+    info!("Restored {} timed effects", effects.len());
+    restored.0 = effects;
+}
+
+/// Note a completed level; the progress is saved once the next one is loaded.
+fn on_level_completed(trigger: On<LevelCompleted>, mut state: ResMut<AutosaveState>) {
+    let state = state.as_mut();
+    state.saved.highest_level = state.saved.highest_level.max(trigger.event().level_index);
+    state.pending = true;
+}
+
+/// Save progress after a completed level, once the next level is loaded or the game is complete.
+pub fn autosave_progress(
+    mut state: ResMut<AutosaveState>,
+    current_level: Option<Res<CurrentLevel>>,
+    game_progress: Option<Res<GameProgress>>,
+    score: Option<Res<ScoreState>>,
+    lives: Option<Res<LivesState>>,
+    profile: Option<Res<CurrentProfile>>,
+) {
+    if !state.pending {
+        return;
+    }
+    let level = if game_progress.is_some_and(|p| p.finished) {
+        None
+    } else {
+        match current_level {
+            Some(level) if level.is_changed() => Some(level.0.number),
+            _ => return,
+        }
+    };
+    state.pending = false;
+    state.saved = SavedProgress {
+        level,
+        score: score.map_or(0, |s| s.current_score),
+        lives: lives.map_or(LivesState::default().lives_remaining, |l| l.lives_remaining),
+        highest_level: state.saved.highest_level,
+        // A new level starts without effects
+        effects: Vec::new(),
+    };
+    let path = progress_path(profile.as_deref());
+    match save_progress(&path, &state.saved) {
+        Ok(()) => debug!("Saved progress to {}", path.display()),
+        Err(e) => warn!("Failed to save progress: {}", e),
+    }
+}
+
+/// Add the running timed effects to the saved progress when the game is quit during the saved
+/// level.
+pub fn save_effects_on_exit(
+    mut exits: MessageReader<AppExit>,
+    mut state: ResMut<AutosaveState>,
+    current_level: Option<Res<CurrentLevel>>,
+    effects: ActiveEffects,
+    profile: Option<Res<CurrentProfile>>,
+) {
+    if exits.read().count() == 0 {
+        return;
+    }
+    let Some(level) = state.saved.level else {
+        return;
+    };
+    if current_level.is_none_or(|current| current.0.number != level) {
+        return;
+    }
+    let running = effects.snapshots();
+    if running == state.saved.effects {
+        return;
+    }
+    state.saved.effects = running;
+    let path = progress_path(profile.as_deref());
+    match save_progress(&path, &state.saved) {
+        Ok(()) => debug!("Saved running effects to {}", path.display()),
+        Err(e) => warn!("Failed to save running effects: {}", e),
+    }
+}
+
+/// Plugin registering the progress autosave.
+pub struct AutosavePlugin;
+
+impl Plugin for AutosavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AutosaveState>();
+        app.init_resource::<RestoredEffects>();
+        app.add_message::<LevelSwitchRequested>();
+        app.add_message::<PowerupCollected>();
+        app.add_systems(Startup, load_saved_progress);
+        app.add_systems(
+            Update,
+            (
+                load_saved_progress.run_if(profile_switched),
+                resume_saved_progress,
+                autosave_progress,
+            )
+                .chain(),
+        );
+        app.add_systems(Update, restore_saved_effects.before(PowerupSystems::Apply));
+        // The collected effects are inserted by the end of Update
+        app.add_systems(
+            PostUpdate,
+            (
+                (
+                    restore_effect_timers::<PaddleSizeEffect>,
+                    restore_effect_timers::<ReversedControls>,
+                    restore_effect_timers::<RubberPaddle>,
+                    restore_effect_timers::<StickyPaddle>,
+                    restore_effect_timers::<BallSizeEffect>,
+                    restore_effect_timers::<FastBall>,
+                    restore_effect_timers::<WreckingBall>,
+                ),
+                clear_restored_effects,
+            )
+                .chain(),
+        );
+        // Last, so exit requests written anywhere this frame are seen before the app quits
+        app.add_systems(Last, save_effects_on_exit);
+        app.add_observer(on_level_completed);
+    }
+}
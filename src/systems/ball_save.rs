@@ -0,0 +1,49 @@
+//! Ball-save shields.
+//!
+//! A shield is a glowing bar across the lower goal. The next ball that reaches the goal while a
+//! shield is up is bounced back into play instead of costing a life, and the shield is used up
+//! (`respawn::detect_ball_loss`), which sends [`BallSaved`]. Several shields stack: each one saves
+//! a single ball.
+//!
+//! Shields are bought with paddle energy (`systems::energy`) and stay up until used, across
+//! levels. Assist mode (`systems::assist`) puts one up on every level.
+
+use bevy::ecs::message::Message;
+use bevy::prelude::*;
+
+use crate::{PLANE_H, PLANE_W};
+
+/// Height of the shield bar above the playfield.
+const SHIELD_Y: f32 = 2.0;
+
+/// Marker for a ball-save shield.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct BallSaveShield;
+
+/// Sent when a shield saves a ball.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BallSaved {
+    pub ball: Entity,
+}
+
+/// Spawn a shield in front of the lower goal.
+pub fn spawn_ball_save_shield(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) -> Entity {
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.2, 0.2, PLANE_W))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgba(0.3, 0.9, 1.0, 0.6),
+                emissive: LinearRgba::rgb(0.5, 2.0, 2.5),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_xyz(PLANE_H / 2.0, SHIELD_Y, 0.0),
+            BallSaveShield,
+        ))
+        .id()
+}
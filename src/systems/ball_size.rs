@@ -0,0 +1,290 @@
+//! Temporary ball size effects granted by powerup capsules.
+//!
+//! - Grow: ball radius x1.6 for 10 seconds (easier to hit bricks and catch on the paddle)
+//! - Shrink: ball radius x0.6 for 10 seconds (debuff)
+//!
+//! Resizing replaces the ball's `Collider` at runtime instead of scaling its `Transform`:
+//! Rapier derives collider scale from the transform, so scaling both would double-apply. The
+//! visual is swapped to a pre-built sphere mesh of the matching radius, and the collider density
+//! is adjusted so mass grows linearly with radius rather than with volume (a 1.6x ball is 1.6x
+//! heavier, not 4x), keeping paddle and brick impulses in a sensible range.
+//!
+//! Effects replace each other, and are cleared (restoring the base collider, mass, and mesh) on
+//! level switch or life loss. Respawned balls always start at base size.
+//!
+//! # System Organization
+//!
+//! [`BallSizeSystems::Apply`] -> [`BallSizeSystems::UpdateTimers`] -> [`BallSizeSystems::Cleanup`]
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{Collider, ColliderMassProperties};
+
+use crate::systems::level_switch::LevelSwitchRequested;
+use crate::systems::powerups::PowerupKind;
+use crate::systems::respawn::LifeLostEvent;
+use crate::systems::timed_effects::{tick_timed_effects, TimedEffect};
+use crate::systems::wrecking_ball::{WreckingBall, WRECKING_MASS_MULTIPLIER};
+use crate::{Ball, BALL_RADIUS};
+
+/// Radius multiplier while the ball is grown.
+pub const BALL_GROW_MULTIPLIER: f32 = 1.6;
+/// Radius multiplier while the ball is shrunk.
+pub const BALL_SHRINK_MULTIPLIER: f32 = 0.6;
+/// Ball size effect duration in seconds.
+pub const BALL_SIZE_EFFECT_DURATION: f32 = 10.0;
+
+/// System set organization for the ball size feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum BallSizeSystems {
+    /// Swap collider/mesh for newly applied effects
+    Apply,
+    /// Update effect timers
+    UpdateTimers,
+    /// Remove expired effects and restore base size
+    Cleanup,
+}
+
+/// Type of ball size effect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BallSizeEffectType {
+    Grow,
+    Shrink,
+}
+
+impl BallSizeEffectType {
+    /// Radius multiplier for this effect.
+    pub fn multiplier(self) -> f32 {
+        match self {
+            BallSizeEffectType::Grow => BALL_GROW_MULTIPLIER,
+            BallSizeEffectType::Shrink => BALL_SHRINK_MULTIPLIER,
+        }
+    }
+}
+
+/// Component tracking an active ball size effect.
+#[derive(Component, Clone, Debug)]
+pub struct BallSizeEffect {
+    pub effect_type: BallSizeEffectType,
+    pub timer: Timer,
+}
+
+impl BallSizeEffect {
+    pub fn new(effect_type: BallSizeEffectType) -> Self {
+        Self {
+            effect_type,
+            timer: Timer::from_seconds(BALL_SIZE_EFFECT_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+impl TimedEffect for BallSizeEffect {
+    fn kind(&self) -> PowerupKind {
+        match self.effect_type {
+            BallSizeEffectType::Grow => PowerupKind::GrowBall,
+            BallSizeEffectType::Shrink => PowerupKind::ShrinkBall,
+        }
+    }
+
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// Start (or restart) a ball size effect on `ball`, replacing any effect already running.
+///
+/// The component is removed before re-inserting so [`apply_ball_size_effects`] sees it as newly
+/// added; timer ticks alone must not trigger collider replacement.
+pub fn apply_ball_size_effect(
+    commands: &mut Commands,
+    ball: Entity,
+    effect_type: BallSizeEffectType,
+) {
+    commands
+        .entity(ball)
+        .remove::<BallSizeEffect>()
+        .insert(BallSizeEffect::new(effect_type));
+}
+
+/// Pre-built sphere meshes for each ball size.
+/// Constitution VIII: Asset Handle Reuse — no per-effect mesh allocation.
+#[derive(Resource, Debug, Clone)]
+pub struct BallSizeMeshes {
+    pub base: Handle<Mesh>,
+    pub grown: Handle<Mesh>,
+    pub shrunk: Handle<Mesh>,
+}
+
+impl BallSizeMeshes {
+    fn for_effect(&self, effect: Option<BallSizeEffectType>) -> Handle<Mesh> {
+        match effect {
+            None => self.base.clone(),
+            Some(BallSizeEffectType::Grow) => self.grown.clone(),
+            Some(BallSizeEffectType::Shrink) => self.shrunk.clone(),
+        }
+    }
+}
+
+/// Collider radius and density for a ball with the given effect (or none).
+///
+/// Density scales with `1 / multiplier²` so the resulting mass is linear in the multiplier.
+pub fn ball_collider_for(effect: Option<BallSizeEffectType>) -> (f32, f32) {
+    let multiplier = effect.map_or(1.0, BallSizeEffectType::multiplier);
+    (BALL_RADIUS * multiplier, 1.0 / (multiplier * multiplier))
+}
+
+fn resize_ball(
+    commands: &mut Commands,
+    entity: Entity,
+    effect: Option<BallSizeEffectType>,
+    wrecking: bool,
+    meshes: Option<&BallSizeMeshes>,
+) {
+    let (radius, mut density) = ball_collider_for(effect);
+    // Keep a wrecking ball heavy across size changes
+    if wrecking {
+        density *= WRECKING_MASS_MULTIPLIER;
+    }
+    let mut ball = commands.entity(entity);
+    ball.insert((
+        Collider::ball(radius),
+        ColliderMassProperties::Density(density),
+    ));
+    if let Some(meshes) = meshes {
+        ball.insert(Mesh3d(meshes.for_effect(effect)));
+    }
+}
+
+fn setup_ball_size_meshes(mut commands: Commands, meshes: Option<ResMut<Assets<Mesh>>>) {
+    let Some(mut meshes) = meshes else {
+        return;
+    };
+    commands.insert_resource(BallSizeMeshes {
+        base: meshes.add(Sphere::new(BALL_RADIUS).mesh()),
+        grown: meshes.add(Sphere::new(BALL_RADIUS * BALL_GROW_MULTIPLIER).mesh()),
+        shrunk: meshes.add(Sphere::new(BALL_RADIUS * BALL_SHRINK_MULTIPLIER).mesh()),
+    });
+}
+
+/// Replace collider, mass, and mesh for balls whose effect was just applied or replaced.
+pub fn apply_ball_size_effects(
+    mut commands: Commands,
+    balls: Query<(Entity, &BallSizeEffect, Has<WreckingBall>), (With<Ball>, Added<BallSizeEffect>)>,
+    meshes: Option<Res<BallSizeMeshes>>,
+) {
+    for (entity, effect, wrecking) in balls.iter() {
+        resize_ball(
+            &mut commands,
+            entity,
+            Some(effect.effect_type),
+            wrecking,
+            meshes.as_deref(),
+        );
+        debug!("Applied {:?} ball size effect", effect.effect_type);
+    }
+}
+
+/// Remove expired effects; restoration happens in [`restore_ball_size`].
+pub fn remove_expired_ball_size_effects(
+    mut commands: Commands,
+    balls: Query<(Entity, &BallSizeEffect)>,
+) {
+    for (entity, effect) in balls.iter() {
+        if effect.timer.is_finished() {
+            commands.entity(entity).remove::<BallSizeEffect>();
+        }
+    }
+}
+
+/// Restore the base collider, mass, and mesh on balls that lost their size effect.
+/// Uses RemovedComponents so it only runs work when an effect ends.
+pub fn restore_ball_size(
+    mut commands: Commands,
+    mut removed: RemovedComponents<BallSizeEffect>,
+    balls: Query<Has<WreckingBall>, (With<Ball>, Without<BallSizeEffect>)>,
+    meshes: Option<Res<BallSizeMeshes>>,
+) {
+    for entity in removed.read() {
+        // Despawned balls (life loss) show up here too; only restore the survivors
+        if let Ok(wrecking) = balls.get(entity) {
+            resize_ball(&mut commands, entity, None, wrecking, meshes.as_deref());
+            debug!("Restored ball {:?} to base size", entity);
+        }
+    }
+}
+
+/// Clear ball size effects on level switch or life loss.
+/// Note: Uses Option<MessageReader> for test compatibility where messages may not be registered
+pub fn clear_ball_size_on_reset(
+    mut commands: Commands,
+    level_switch_events: Option<MessageReader<LevelSwitchRequested>>,
+    life_lost_events: Option<MessageReader<LifeLostEvent>>,
+    balls: Query<Entity, With<BallSizeEffect>>,
+) {
+    let switched = level_switch_events.is_some_and(|mut r| r.read().count() > 0);
+    let lost = life_lost_events.is_some_and(|mut r| r.read().count() > 0);
+    if switched || lost {
+        for entity in balls.iter() {
+            commands.entity(entity).remove::<BallSizeEffect>();
+        }
+    }
+}
+
+/// Plugin to register ball size systems.
+pub struct BallSizePlugin;
+
+impl Plugin for BallSizePlugin {
+    fn build(&self, app: &mut App) {
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_systems(Startup, setup_ball_size_meshes);
+        app.configure_sets(
+            Update,
+            (
+                BallSizeSystems::Apply,
+                BallSizeSystems::UpdateTimers,
+                BallSizeSystems::Cleanup,
+            )
+                .chain()
+                .after(crate::systems::powerups::PowerupSystems::Apply),
+        );
+        app.add_systems(
+            Update,
+            (
+                (clear_ball_size_on_reset, apply_ball_size_effects)
+                    .chain()
+                    .in_set(BallSizeSystems::Apply),
+                tick_timed_effects::<BallSizeEffect>
+                    .in_set(BallSizeSystems::UpdateTimers)
+                    .run_if(crate::pause::not_paused),
+                (remove_expired_ball_size_effects, restore_ball_size)
+                    .chain()
+                    .in_set(BallSizeSystems::Cleanup),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_ball_keeps_default_collider() {
+        assert_eq!(ball_collider_for(None), (BALL_RADIUS, 1.0));
+    }
+
+    #[test]
+    fn mass_scales_linearly_with_radius() {
+        for effect in [BallSizeEffectType::Grow, BallSizeEffectType::Shrink] {
+            let (radius, density) = ball_collider_for(Some(effect));
+            let base_mass = BALL_RADIUS.powi(3);
+            let mass = radius.powi(3) * density;
+            assert!((mass / base_mass - effect.multiplier()).abs() < 1e-4);
+        }
+    }
+}
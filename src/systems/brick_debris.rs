@@ -0,0 +1,211 @@
+//! Brick debris: a handful of small cuboids bursting out of a destroyed brick.
+//!
+//! When a brick marked for despawn (`MarkedForDespawn`) goes away, [`spawn_brick_debris`] throws
+//! between [`BrickDebrisConfig::min_pieces`] and [`BrickDebrisConfig::max_pieces`] cuboids in the
+//! brick's material, with random velocities and spins. The pieces have no collider and no rigid
+//! body: [`update_brick_debris`] moves them under their own gravity, so they tumble through the
+//! floor, and retires them after [`BrickDebrisConfig::lifetime`]. A [`BrickDebrisConfig::color`]
+//! (set by theme packs) paints all pieces in one colour instead.
+//!
+//! Bricks removed by a level switch or restart are despawned without `MarkedForDespawn` and leave
+//! no debris; wrecking ball debris (`systems::wrecking_ball`) shatters when it finally expires.
+//!
+//! # Performance bounds
+//! Retired pieces are hidden and kept in the [`DebrisPool`] for the next brick instead of being
+//! despawned, and all pieces share one cuboid mesh. At most [`BrickDebrisConfig::max_alive`]
+//! pieces fly at once; bricks destroyed beyond that get fewer pieces or none. Debris is skipped
+//! entirely once the frame-time guard (`systems::quality_guard`) has lowered the quality.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::systems::quality_guard::{QualityGuard, QualityLevel};
+use crate::{Brick, MarkedForDespawn};
+
+/// Edge length of a debris cuboid.
+pub const DEBRIS_PIECE_SIZE: f32 = 0.25;
+/// Downward acceleration of the pieces.
+const DEBRIS_GRAVITY: f32 = 30.0;
+/// Spread of the pieces around the brick's centre.
+const DEBRIS_SPREAD: f32 = 0.3;
+
+/// Tuning for brick debris.
+#[derive(Resource, Debug, Clone)]
+pub struct BrickDebrisConfig {
+    pub enabled: bool,
+    /// Fewest pieces per brick.
+    pub min_pieces: usize,
+    /// Most pieces per brick.
+    pub max_pieces: usize,
+    /// How long a piece flies before it is retired, in seconds.
+    pub lifetime: f32,
+    /// Upper bound on simultaneously flying pieces.
+    pub max_alive: usize,
+    /// Colour of every piece instead of the brick's material, e.g. from a theme pack.
+    pub color: Option<Color>,
+}
+
+impl Default for BrickDebrisConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_pieces: 3,
+            max_pieces: 6,
+            lifetime: 1.0,
+            max_alive: 60,
+            color: None,
+        }
+    }
+}
+
+/// A flying piece of debris.
+#[derive(Component, Debug, Clone)]
+pub struct DebrisPiece {
+    pub velocity: Vec3,
+    /// Angular velocity, in radians per second around each axis.
+    pub spin: Vec3,
+    pub timer: Timer,
+}
+
+/// Shared mesh, retired pieces waiting for reuse and the number of pieces in flight.
+#[derive(Resource, Debug, Default)]
+pub struct DebrisPool {
+    mesh: Option<Handle<Mesh>>,
+    /// Material of [`BrickDebrisConfig::color`], rebuilt when the colour changes
+    tinted: Option<(Color, Handle<StandardMaterial>)>,
+    idle: Vec<Entity>,
+    active: usize,
+}
+
+impl DebrisPool {
+    /// Number of pieces in flight.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Number of hidden pieces ready for reuse.
+    pub fn idle(&self) -> usize {
+        self.idle.len()
+    }
+}
+
+/// Burst a brick into debris as it is despawned.
+pub fn spawn_brick_debris(
+    remove: On<Remove, Brick>,
+    bricks: Query<
+        (&GlobalTransform, Option<&MeshMaterial3d<StandardMaterial>>),
+        With<MarkedForDespawn>,
+    >,
+    config: Res<BrickDebrisConfig>,
+    guard: Option<Res<QualityGuard>>,
+    mut pool: ResMut<DebrisPool>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+    mut commands: Commands,
+) {
+    if !config.enabled || guard.is_some_and(|g| g.level >= QualityLevel::Reduced) {
+        return;
+    }
+    let Ok((transform, material)) = bricks.get(remove.event().entity) else {
+        return;
+    };
+    let mut rng = rand::rng();
+    let wanted = rng.random_range(config.min_pieces..=config.max_pieces.max(config.min_pieces));
+    let count = wanted.min(config.max_alive.saturating_sub(pool.active));
+    if count == 0 {
+        return;
+    }
+    let mesh = match (&pool.mesh, meshes) {
+        (Some(mesh), _) => mesh.clone(),
+        (None, Some(mut meshes)) => {
+            let mesh = meshes.add(Cuboid::from_length(DEBRIS_PIECE_SIZE));
+            pool.mesh = Some(mesh.clone());
+            mesh
+        }
+        (None, None) => return,
+    };
+    let material = match (config.color, materials) {
+        (Some(color), Some(mut materials)) => {
+            let handle = match pool.tinted.take() {
+                Some((tint, handle)) if tint == color => handle,
+                _ => materials.add(color),
+            };
+            pool.tinted = Some((color, handle.clone()));
+            MeshMaterial3d(handle)
+        }
+        _ => material.cloned().unwrap_or_default(),
+    };
+    let centre = transform.translation();
+
+    for _ in 0..count {
+        let offset = Vec3::new(
+            rng.random_range(-DEBRIS_SPREAD..=DEBRIS_SPREAD),
+            0.0,
+            rng.random_range(-DEBRIS_SPREAD..=DEBRIS_SPREAD),
+        );
+        let piece = (
+            DebrisPiece {
+                velocity: Vec3::new(
+                    offset.x * 10.0 + rng.random_range(-2.0..=2.0),
+                    rng.random_range(3.0..=8.0),
+                    offset.z * 10.0 + rng.random_range(-2.0..=2.0),
+                ),
+                spin: Vec3::new(
+                    rng.random_range(-10.0..=10.0),
+                    rng.random_range(-10.0..=10.0),
+                    rng.random_range(-10.0..=10.0),
+                ),
+                timer: Timer::from_seconds(config.lifetime, TimerMode::Once),
+            },
+            Transform::from_translation(centre + offset),
+            material.clone(),
+            Visibility::Inherited,
+        );
+        match pool.idle.pop() {
+            Some(entity) => {
+                commands.entity(entity).insert(piece);
+            }
+            None => {
+                commands.spawn((Mesh3d(mesh.clone()), piece));
+            }
+        }
+        pool.active += 1;
+    }
+}
+
+/// Move the pieces under gravity and retire them into the pool when their time is up.
+pub fn update_brick_debris(
+    mut commands: Commands,
+    mut pieces: Query<(Entity, &mut DebrisPiece, &mut Transform, &mut Visibility)>,
+    mut pool: ResMut<DebrisPool>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut piece, mut transform, mut visibility) in pieces.iter_mut() {
+        piece.timer.tick(time.delta());
+        if piece.timer.is_finished() {
+            *visibility = Visibility::Hidden;
+            commands.entity(entity).remove::<DebrisPiece>();
+            pool.idle.push(entity);
+            pool.active = pool.active.saturating_sub(1);
+            continue;
+        }
+        piece.velocity.y -= DEBRIS_GRAVITY * dt;
+        transform.translation += piece.velocity * dt;
+        transform.rotate(Quat::from_scaled_axis(piece.spin * dt));
+    }
+}
+
+/// Plugin registering brick debris.
+pub struct BrickDebrisPlugin;
+
+impl Plugin for BrickDebrisPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BrickDebrisConfig>();
+        app.init_resource::<DebrisPool>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_observer(spawn_brick_debris);
+        app.add_systems(Update, update_brick_debris.run_if(crate::pause::not_paused));
+    }
+}
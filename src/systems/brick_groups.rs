@@ -0,0 +1,312 @@
+//! Brick groups and group locks (puzzle layouts).
+//!
+//! A level can name groups of brick cells and lock a group until another one is cleared:
+//!
+//! ```ron
+//! groups: {
+//!     1: [(2, 8), (2, 9), (2, 10)],
+//!     2: [(5, 0), (5, 19)],
+//! },
+//! group_locks: [
+//!     (group: 2, until_cleared: 1), // group 2 cannot be destroyed while any of group 1 is left
+//! ],
+//! ```
+//!
+//! Bricks get a [`BrickGroup`] component when spawned. While a lock is active its bricks carry
+//! [`GroupLocked`]: they lose `CountsTowardsCompletion` (which every destruction path requires, so
+//! they behave like indestructible bricks and do not block completion) and are drawn with a dark
+//! "locked" material. Once the unlocking group is cleared, the marker and material are removed.
+//!
+//! Groups whose cells do not all hold destructible bricks, and locks referring to unknown groups or
+//! forming a cycle, are skipped with a warning.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::{
+    matrix_resolution, validate_cell_range_in, BUMPER, HAZARD_BRICK_91, INDESTRUCTIBLE_BRICK,
+};
+use crate::level_loader::{CurrentLevel, LevelAdvanceSystems, LevelDefinition};
+use crate::{Brick, BrickGridPos, CountsTowardsCompletion, MarkedForDespawn};
+
+/// Base colour of locked bricks.
+pub const LOCKED_BRICK_COLOR: Color = Color::srgb(0.18, 0.18, 0.22);
+
+/// A group lock as authored in a level file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupLock {
+    /// Group that stays indestructible...
+    pub group: u32,
+    /// ...until every brick of this group has been destroyed
+    pub until_cleared: u32,
+}
+
+/// Group a brick belongs to.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrickGroup(pub u32);
+
+/// A brick whose group is currently locked.
+#[derive(Component, Debug, Clone)]
+pub struct GroupLocked {
+    /// Whether the brick counted towards completion before it was locked
+    counted: bool,
+    /// Material to put back when unlocked
+    material: Option<Handle<StandardMaterial>>,
+}
+
+/// Check a group's cells against the level `matrix`: every cell must hold a destructible brick.
+pub fn validate_group(cells: &[(usize, usize)], matrix: &[Vec<u8>]) -> Result<(), String> {
+    if cells.is_empty() {
+        return Err("group has no bricks".to_string());
+    }
+    cells.iter().try_for_each(|&(row, col)| {
+        validate_cell_range_in((row, row), (col, col), matrix_resolution(matrix))?;
+        match matrix.get(row).and_then(|r| r.get(col)) {
+            Some(&(INDESTRUCTIBLE_BRICK | HAZARD_BRICK_91 | BUMPER)) => Err(format!(
+                "group cell ({}, {}) holds an indestructible brick",
+                row, col
+            )),
+            Some(3..=255) => Ok(()),
+            _ => Err(format!("group cell ({}, {}) holds no brick", row, col)),
+        }
+    })
+}
+
+/// Whether following the locks from `start` leads back to it.
+fn forms_cycle(start: u32, locks: &[GroupLock]) -> bool {
+    let mut seen = HashSet::new();
+    let mut frontier = vec![start];
+    while let Some(group) = frontier.pop() {
+        for lock in locks.iter().filter(|l| l.group == group) {
+            if lock.until_cleared == start {
+                return true;
+            }
+            if seen.insert(lock.until_cleared) {
+                frontier.push(lock.until_cleared);
+            }
+        }
+    }
+    false
+}
+
+/// Valid groups and locks of the current level.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LevelBrickGroups {
+    /// Group of each grouped cell
+    pub cells: HashMap<(usize, usize), u32>,
+    pub locks: Vec<GroupLock>,
+}
+
+impl LevelBrickGroups {
+    /// Load the groups and locks of a level, skipping (and logging) invalid ones.
+    pub fn for_level(def: &LevelDefinition) -> Self {
+        let groups: BTreeMap<u32, &Vec<(usize, usize)>> = def
+            .groups
+            .iter()
+            .filter(|(id, cells)| match validate_group(cells, &def.matrix) {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Ignoring group {} of level {}: {}", id, def.number, e);
+                    false
+                }
+            })
+            .map(|(id, cells)| (*id, cells))
+            .collect();
+        let known: Vec<GroupLock> = def
+            .group_locks
+            .iter()
+            .copied()
+            .filter(|lock| {
+                let valid =
+                    groups.contains_key(&lock.group) && groups.contains_key(&lock.until_cleared);
+                if !valid {
+                    warn!(
+                        "Ignoring lock of group {} on group {} in level {}: unknown group",
+                        lock.group, lock.until_cleared, def.number
+                    );
+                }
+                valid
+            })
+            .collect();
+        let locks = known
+            .iter()
+            .copied()
+            .filter(|lock| {
+                let cyclic = forms_cycle(lock.group, &known);
+                if cyclic {
+                    warn!(
+                        "Ignoring lock of group {} on group {} in level {}: locks form a cycle",
+                        lock.group, lock.until_cleared, def.number
+                    );
+                }
+                !cyclic
+            })
+            .collect();
+        let mut cells = HashMap::new();
+        for (id, group_cells) in groups {
+            for &cell in group_cells {
+                cells.insert(cell, id);
+            }
+        }
+        Self { cells, locks }
+    }
+}
+
+/// Reload the groups when a level is loaded.
+pub fn load_brick_groups(current_level: Res<CurrentLevel>, mut groups: ResMut<LevelBrickGroups>) {
+    *groups = LevelBrickGroups::for_level(&current_level.0);
+}
+
+/// Tag newly spawned bricks with their group.
+pub fn assign_brick_groups(
+    mut commands: Commands,
+    groups: Res<LevelBrickGroups>,
+    bricks: Query<(Entity, &BrickGridPos), (With<Brick>, Added<BrickGridPos>)>,
+) {
+    for (entity, pos) in bricks.iter() {
+        if let Some(&group) = groups.cells.get(&(pos.row, pos.col)) {
+            commands.entity(entity).insert(BrickGroup(group));
+        }
+    }
+}
+
+/// Lock and unlock grouped bricks as their unlocking groups are cleared.
+#[allow(clippy::type_complexity)]
+pub fn evaluate_group_locks(
+    mut commands: Commands,
+    groups: Res<LevelBrickGroups>,
+    bricks: Query<
+        (
+            Entity,
+            &BrickGroup,
+            Option<&GroupLocked>,
+            Has<CountsTowardsCompletion>,
+            Option<&MeshMaterial3d<StandardMaterial>>,
+        ),
+        Without<MarkedForDespawn>,
+    >,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+    mut locked_material: Local<Option<Handle<StandardMaterial>>>,
+) {
+    let remaining: HashSet<u32> = bricks.iter().map(|(_, group, ..)| group.0).collect();
+    for (entity, group, locked, counted, material) in bricks.iter() {
+        let should_lock = groups
+            .locks
+            .iter()
+            .any(|lock| lock.group == group.0 && remaining.contains(&lock.until_cleared));
+        match (should_lock, locked) {
+            (true, None) => {
+                let mut brick = commands.entity(entity);
+                brick
+                    .remove::<CountsTowardsCompletion>()
+                    .insert(GroupLocked {
+                        counted,
+                        material: material.map(|m| m.0.clone()),
+                    });
+                if let Some(materials) = materials.as_deref_mut() {
+                    let handle = locked_material
+                        .get_or_insert_with(|| {
+                            materials.add(StandardMaterial {
+                                base_color: LOCKED_BRICK_COLOR,
+                                perceptual_roughness: 0.3,
+                                metallic: 0.8,
+                                ..default()
+                            })
+                        })
+                        .clone();
+                    brick.insert(MeshMaterial3d(handle));
+                }
+            }
+            (false, Some(locked)) => {
+                let mut brick = commands.entity(entity);
+                brick.remove::<GroupLocked>();
+                if locked.counted {
+                    brick.insert(CountsTowardsCompletion);
+                }
+                if let Some(material) = locked.material.clone() {
+                    brick.insert(MeshMaterial3d(material));
+                }
+                debug!("Brick {:?} of group {} unlocked", entity, group.0);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Plugin registering brick groups and group locks.
+pub struct BrickGroupsPlugin;
+
+impl Plugin for BrickGroupsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelBrickGroups>();
+        app.add_systems(
+            Update,
+            (
+                load_brick_groups.run_if(resource_exists_and_changed::<CurrentLevel>),
+                assign_brick_groups,
+                evaluate_group_locks,
+            )
+                .chain()
+                // Unlock in the frame the last unlocking brick goes, before completion is checked
+                .after(crate::despawn_marked_entities)
+                .before(LevelAdvanceSystems),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(matrix: Vec<Vec<u8>>) -> LevelDefinition {
+        let mut def: LevelDefinition =
+            ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+        def.matrix = matrix;
+        def
+    }
+
+    #[test]
+    fn invalid_groups_and_locks_are_skipped() {
+        let mut def = level(vec![vec![20, 20, 90], vec![20, 20, 0]]);
+        def.groups = BTreeMap::from([
+            (1, vec![(0, 0)]),
+            (2, vec![(0, 1)]),
+            (3, vec![(0, 2)]),
+            (4, vec![(1, 0)]),
+            (5, vec![(1, 1)]),
+        ]);
+        def.group_locks = vec![
+            GroupLock {
+                group: 2,
+                until_cleared: 1,
+            },
+            GroupLock {
+                group: 1,
+                until_cleared: 3,
+            },
+            GroupLock {
+                group: 4,
+                until_cleared: 5,
+            },
+            GroupLock {
+                group: 5,
+                until_cleared: 4,
+            },
+        ];
+        let groups = LevelBrickGroups::for_level(&def);
+        assert_eq!(
+            groups.cells.len(),
+            4,
+            "group 3 holds an indestructible brick"
+        );
+        assert_eq!(
+            groups.locks,
+            vec![GroupLock {
+                group: 2,
+                until_cleared: 1
+            }]
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Brick mesh shape variants.
+//!
+//! Bricks are cuboids unless their type variant in the texture manifest names another shape:
+//!
+//! ```ron
+//! type_variants: [
+//!     (object_class: Brick, type_id: 30, profile_id: "brick/type20", shape: Wedge),
+//!     (object_class: Brick, type_id: 31, profile_id: "brick/type20", shape: Dome),
+//! ],
+//! ```
+//!
+//! - **Wedge**: a ramp filling half the cell, its slanted face looking towards higher rows and
+//!   lower columns, so balls glance off it diagonally.
+//! - **Cylinder**: an upright (elliptical) cylinder touching the cell sides.
+//! - **Dome**: a rounded cap over the cell, deflecting balls by where they hit it.
+//!
+//! Every shape fits the same footprint as the cuboid (90% of the cell) and gets a convex collider
+//! built from its mesh, so what the ball bounces off matches what is drawn. Shapes are applied to
+//! bricks as they spawn, whatever spawned them, and again when a brick changes type (multi-hit
+//! bricks) or the manifest is reloaded.
+
+use std::collections::HashMap;
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Collider;
+use serde::{Deserialize, Serialize};
+
+use crate::systems::bumpers::Bumper;
+use crate::systems::textures::TypeVariantRegistry;
+use crate::{Brick, BrickTypeId, GridResolution};
+
+/// Half the height of every brick shape.
+const HALF_HEIGHT: f32 = 0.25;
+/// Brick footprint relative to its cell.
+const FOOTPRINT: f32 = 0.9;
+/// Segments around the cylinder and the dome.
+const SEGMENTS: u32 = 24;
+/// Rings from the dome's rim to its top.
+const DOME_RINGS: u32 = 6;
+
+/// Mesh and collider shape of a brick type; also kept on shaped bricks as a component.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BrickShape {
+    #[default]
+    Cuboid,
+    Wedge,
+    Cylinder,
+    Dome,
+}
+
+impl BrickShape {
+    /// Mesh of the shape for a footprint with half extents (`half_x`, `half_z`), centred on the
+    /// origin.
+    pub fn mesh(self, half_x: f32, half_z: f32) -> Mesh {
+        let mut mesh = match self {
+            BrickShape::Cuboid => Cuboid::new(half_x * 2.0, HALF_HEIGHT * 2.0, half_z * 2.0)
+                .mesh()
+                .build(),
+            BrickShape::Wedge => {
+                // Right angle at (-x, -z); extruded along Z, then stood up along Y
+                let triangle = Triangle2d::new(
+                    Vec2::new(-half_x, half_z),
+                    Vec2::new(half_x, half_z),
+                    Vec2::new(-half_x, -half_z),
+                );
+                Extrusion::new(triangle, HALF_HEIGHT * 2.0)
+                    .mesh()
+                    .build()
+                    .rotated_by(Quat::from_rotation_x(-FRAC_PI_2))
+            }
+            BrickShape::Cylinder => Cylinder::new(1.0, HALF_HEIGHT * 2.0)
+                .mesh()
+                .resolution(SEGMENTS)
+                .build()
+                .scaled_by(Vec3::new(half_x, 1.0, half_z)),
+            BrickShape::Dome => dome_mesh(half_x, half_z),
+        };
+        // Tangents for normal-mapped brick materials
+        if let Err(e) = mesh.generate_tangents() {
+            debug!("No tangents for {:?} brick mesh: {}", self, e);
+        }
+        mesh
+    }
+
+    /// Collider of the shape, matching [`BrickShape::mesh`]: a convex hull of the mesh for all but
+    /// the cuboid.
+    pub fn collider(self, half_x: f32, half_z: f32) -> Collider {
+        let cuboid = || Collider::cuboid(half_x, HALF_HEIGHT, half_z);
+        if self == BrickShape::Cuboid {
+            return cuboid();
+        }
+        let mesh = self.mesh(half_x, half_z);
+        let points: Vec<Vec3> = mesh
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .and_then(|positions| positions.as_float3())
+            .map(|positions| positions.iter().copied().map(Vec3::from).collect())
+            .unwrap_or_default();
+        Collider::convex_hull(&points).unwrap_or_else(cuboid)
+    }
+}
+
+/// Half of an ellipsoid over a footprint with half extents (`half_x`, `half_z`), rim at the bottom
+/// of the brick and top at its top. Open at the bottom, which faces the ground.
+fn dome_mesh(half_x: f32, half_z: f32) -> Mesh {
+    let mut positions = Vec::new();
+    let mut uvs = Vec::new();
+    for ring in 0..=DOME_RINGS {
+        let lat = ring as f32 / DOME_RINGS as f32 * FRAC_PI_2;
+        let (y, radius) = (lat.sin(), lat.cos());
+        for segment in 0..=SEGMENTS {
+            let lon = segment as f32 / SEGMENTS as f32 * TAU;
+            positions.push([
+                half_x * radius * lon.cos(),
+                -HALF_HEIGHT + 2.0 * HALF_HEIGHT * y,
+                half_z * radius * lon.sin(),
+            ]);
+            uvs.push([
+                segment as f32 / SEGMENTS as f32,
+                ring as f32 / DOME_RINGS as f32,
+            ]);
+        }
+    }
+    let mut indices = Vec::new();
+    let row = SEGMENTS + 1;
+    for ring in 0..DOME_RINGS {
+        for segment in 0..SEGMENTS {
+            let (a, b) = (ring * row + segment, ring * row + segment + 1);
+            let (c, d) = (a + row, b + row);
+            // Counter-clockwise seen from outside
+            indices.extend([a, c, d, a, d, b]);
+        }
+    }
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_indices(Indices::U32(indices));
+    mesh.compute_smooth_normals();
+    mesh
+}
+
+/// Give bricks the mesh and collider of their type's shape.
+///
+/// Checks bricks whose type changed (including newly spawned ones), and all bricks once the
+/// type variants are rebuilt. Meshes are shared per shape and grid.
+pub fn apply_brick_shapes(
+    mut commands: Commands,
+    registry: Option<Res<TypeVariantRegistry>>,
+    grid: Option<Res<GridResolution>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    bricks: Query<(Entity, Ref<BrickTypeId>, Option<&BrickShape>), (With<Brick>, Without<Bumper>)>,
+    mut shared: Local<HashMap<(BrickShape, GridResolution), Handle<Mesh>>>,
+) {
+    let Some(registry) = registry else {
+        return;
+    };
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    let (half_x, half_z) = (
+        grid.cell_height() * FOOTPRINT / 2.0,
+        grid.cell_width() * FOOTPRINT / 2.0,
+    );
+    for (entity, type_id, current) in bricks.iter() {
+        if !registry.is_changed() && !type_id.is_changed() {
+            continue;
+        }
+        let shape = registry.shape(type_id.0);
+        if current.copied().unwrap_or_default() == shape {
+            continue;
+        }
+        let mesh = shared
+            .entry((shape, grid))
+            .or_insert_with(|| meshes.add(shape.mesh(half_x, half_z)))
+            .clone();
+        commands
+            .entity(entity)
+            .insert((Mesh3d(mesh), shape.collider(half_x, half_z), shape));
+    }
+}
+
+/// Plugin registering brick shape variants.
+pub struct BrickShapesPlugin;
+
+impl Plugin for BrickShapesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_brick_shapes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shapes_stay_within_the_brick_footprint() {
+        let (half_x, half_z) = (0.675, 0.9);
+        for shape in [
+            BrickShape::Cuboid,
+            BrickShape::Wedge,
+            BrickShape::Cylinder,
+            BrickShape::Dome,
+        ] {
+            let mesh = shape.mesh(half_x, half_z);
+            let positions = mesh
+                .attribute(Mesh::ATTRIBUTE_POSITION)
+                .and_then(|positions| positions.as_float3())
+                .unwrap();
+            let (mut min, mut max) = (Vec3::MAX, Vec3::MIN);
+            for &position in positions {
+                min = min.min(Vec3::from(position));
+                max = max.max(Vec3::from(position));
+            }
+            let extent = Vec3::new(half_x, HALF_HEIGHT, half_z);
+            assert!(
+                (max - extent).abs().max_element() < 1e-4
+                    && (min + extent).abs().max_element() < 1e-4,
+                "{shape:?} spans {min} to {max}"
+            );
+            assert!(
+                mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_some(),
+                "{shape:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn shaped_bricks_get_convex_colliders() {
+        assert!(BrickShape::Cuboid.collider(0.5, 0.5).as_cuboid().is_some());
+        for shape in [BrickShape::Wedge, BrickShape::Cylinder, BrickShape::Dome] {
+            let collider = shape.collider(0.5, 0.5);
+            assert!(collider.as_convex_polyhedron().is_some(), "{shape:?}");
+        }
+        // The wedge's slanted face leaves the far corner free
+        let wedge = BrickShape::Wedge.collider(0.5, 0.5);
+        assert!(wedge.contains_local_point(Vec3::new(-0.4, 0.0, -0.4)));
+        assert!(!wedge.contains_local_point(Vec3::new(0.4, 0.0, 0.4)));
+    }
+}
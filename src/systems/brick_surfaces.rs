@@ -0,0 +1,167 @@
+//! Per-type brick surfaces.
+//!
+//! Every brick takes its restitution and friction from `BrickPhysicsConfig` unless its type has a
+//! surface of its own. Surfaces are set by brick type index, in `config/gameplay.ron` for the whole
+//! game (`GameplayConfig::brick_surfaces`) and in a level file for that level:
+//!
+//! ```ron
+//! brick_surfaces: {
+//!     90: (restitution: 1.6), // metal: bounces harder
+//!     20: (restitution: 0.4, friction: 1.8), // sponge: soaks the ball up
+//! },
+//! ```
+//!
+//! Either value may be left out to keep the one it would otherwise get. A level's surface for a
+//! type replaces the game-wide one value by value, and both are applied to bricks as they spawn
+//! (level load, level advance, brick waves and the cheat console alike). Values follow the
+//! `BrickPhysicsConfig` bounds, 0 to 2; level entries outside them are skipped with a warning.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{Friction, Restitution};
+use serde::{Deserialize, Serialize};
+
+use crate::gameplay_config::GameplayConfig;
+use crate::level_loader::{CurrentLevel, LevelDefinition};
+use crate::{Brick, BrickTypeId};
+
+/// Restitution and friction of one brick type; `None` keeps the value it would otherwise get.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct BrickSurface {
+    #[serde(default)]
+    pub restitution: Option<f32>,
+    #[serde(default)]
+    pub friction: Option<f32>,
+}
+
+impl BrickSurface {
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("restitution", self.restitution),
+            ("friction", self.friction),
+        ] {
+            if let Some(value) = value {
+                if !value.is_finite() || !(0.0..=2.0).contains(&value) {
+                    return Err(format!("Brick {} out of bounds: {}", name, value));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// This surface with the values of `other` taking precedence where set.
+    pub fn overridden_by(self, other: BrickSurface) -> Self {
+        Self {
+            restitution: other.restitution.or(self.restitution),
+            friction: other.friction.or(self.friction),
+        }
+    }
+}
+
+/// Surfaces of the brick types on the current level.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct BrickSurfaces {
+    pub by_type: BTreeMap<u8, BrickSurface>,
+}
+
+impl BrickSurfaces {
+    /// Combine the game-wide surfaces with a level's, skipping (and logging) invalid level
+    /// entries.
+    pub fn for_level(game: &BTreeMap<u8, BrickSurface>, def: &LevelDefinition) -> Self {
+        let mut by_type = game.clone();
+        for (&type_id, &surface) in &def.brick_surfaces {
+            match surface.validate() {
+                Ok(()) => {
+                    let base = by_type.get(&type_id).copied().unwrap_or_default();
+                    by_type.insert(type_id, base.overridden_by(surface));
+                }
+                Err(e) => warn!(
+                    "Ignoring surface of brick type {} on level {}: {}",
+                    type_id, def.number, e
+                ),
+            }
+        }
+        Self { by_type }
+    }
+}
+
+/// Recombine the surfaces when a level is loaded.
+pub fn load_brick_surfaces(
+    current_level: Res<CurrentLevel>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut surfaces: ResMut<BrickSurfaces>,
+) {
+    let game = gameplay.map(|config| config.brick_surfaces.clone());
+    *surfaces = BrickSurfaces::for_level(&game.unwrap_or_default(), &current_level.0);
+}
+
+/// Give newly spawned bricks the surface of their type.
+pub fn apply_brick_surfaces(
+    surfaces: Res<BrickSurfaces>,
+    mut bricks: Query<
+        (&BrickTypeId, &mut Restitution, &mut Friction),
+        (With<Brick>, Added<BrickTypeId>),
+    >,
+) {
+    if surfaces.by_type.is_empty() {
+        return;
+    }
+    for (type_id, mut restitution, mut friction) in bricks.iter_mut() {
+        let Some(surface) = surfaces.by_type.get(&type_id.0) else {
+            continue;
+        };
+        if let Some(value) = surface.restitution {
+            restitution.coefficient = value;
+        }
+        if let Some(value) = surface.friction {
+            friction.coefficient = value;
+        }
+    }
+}
+
+/// Plugin registering per-type brick surfaces.
+pub struct BrickSurfacesPlugin;
+
+impl Plugin for BrickSurfacesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BrickSurfaces>();
+        app.add_systems(
+            Update,
+            (
+                load_brick_surfaces.run_if(resource_exists_and_changed::<CurrentLevel>),
+                apply_brick_surfaces,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn surface(restitution: Option<f32>, friction: Option<f32>) -> BrickSurface {
+        BrickSurface {
+            restitution,
+            friction,
+        }
+    }
+
+    #[test]
+    fn level_surfaces_override_the_game_ones_value_by_value() {
+        let game = BTreeMap::from([(90, surface(Some(1.6), Some(0.2)))]);
+        let def: LevelDefinition = ron::de::from_str(
+            "LevelDefinition(number: 1, matrix: [], brick_surfaces: {
+                90: (friction: 0.5),
+                20: (restitution: 0.4),
+                3: (restitution: 5.0),
+            })",
+        )
+        .unwrap();
+        let surfaces = BrickSurfaces::for_level(&game, &def);
+        assert_eq!(surfaces.by_type[&90], surface(Some(1.6), Some(0.5)));
+        assert_eq!(surfaces.by_type[&20], surface(Some(0.4), None));
+        assert!(!surfaces.by_type.contains_key(&3), "out of bounds");
+    }
+}
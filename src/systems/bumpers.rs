@@ -0,0 +1,169 @@
+//! Pinball-style bumpers (index 16).
+//!
+//! A bumper is a round, indestructible obstacle occupying one grid cell. When a ball touches it,
+//! the ball is kicked away from the bumper's centre: [`BUMPER_KICK_SPEED`] is added to its
+//! velocity along the outward direction, on top of the regular bounce. Every hit scores
+//! [`BUMPER_POINTS`], plays the `BumperHit` sound and squashes the bumper, which then wobbles back
+//! to its rest shape over [`BUMPER_SQUASH_SECONDS`].
+//!
+//! Bumpers are spawned by the level loader with the `Brick` marker (so they are despawned with the
+//! level, kept in checkpoint snapshots and respawned on restart) but without
+//! `CountsTowardsCompletion`, so they are never destroyed and never block completion.
+
+use std::f32::consts::PI;
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::level_format::BUMPER;
+use crate::signals::BumperHit;
+use crate::systems::scoring::ScoreState;
+use crate::{Ball, Brick, BrickGridPos, BrickTypeId, GridResolution};
+
+/// Bumper radius, relative to the smaller cell side.
+const BUMPER_RADIUS_SCALE: f32 = 0.45;
+/// Speed added to a ball along the outward direction on contact.
+pub const BUMPER_KICK_SPEED: f32 = 12.0;
+/// Points scored per bumper hit.
+pub const BUMPER_POINTS: u32 = 50;
+/// Duration of the squash-and-stretch animation after a hit.
+pub const BUMPER_SQUASH_SECONDS: f32 = 0.35;
+/// Peak horizontal stretch (and vertical squash) of the animation.
+const BUMPER_SQUASH_AMOUNT: f32 = 0.3;
+
+/// Marker for bumper entities.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Bumper;
+
+/// Squash-and-stretch animation in progress on a bumper.
+#[derive(Component, Debug, Clone)]
+pub struct BumperSquash {
+    pub timer: Timer,
+}
+
+/// Spawn the bumper for cell (`row`, `col`) of `grid`, sized to fit the cell.
+pub fn spawn_bumper(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    grid: &GridResolution,
+    row: usize,
+    col: usize,
+) {
+    let radius = grid.cell_height().min(grid.cell_width()) * BUMPER_RADIUS_SCALE;
+    commands.spawn((
+        Mesh3d(meshes.add(Cylinder::new(radius, 0.5))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.95, 0.55, 0.1),
+            emissive: LinearRgba::rgb(0.6, 0.25, 0.0),
+            metallic: 0.6,
+            perceptual_roughness: 0.3,
+            ..default()
+        })),
+        Transform::from_translation(grid.cell_center(row, col)),
+        Brick,
+        Bumper,
+        BrickTypeId(BUMPER),
+        BrickGridPos { row, col },
+        RigidBody::Fixed,
+        Collider::cylinder(0.25, radius),
+        Restitution {
+            coefficient: 1.0,
+            combine_rule: CoefficientCombineRule::Max,
+        },
+        ActiveEvents::COLLISION_EVENTS,
+    ));
+}
+
+/// Kick balls away from the bumpers they touch, score the hit and start the squash animation.
+pub fn kick_balls_off_bumpers(
+    mut collisions: MessageReader<CollisionEvent>,
+    bumpers: Query<&Transform, (With<Bumper>, Without<Ball>)>,
+    mut balls: Query<(&Transform, &mut Velocity), With<Ball>>,
+    mut score: Option<ResMut<ScoreState>>,
+    mut hits: MessageWriter<BumperHit>,
+    mut commands: Commands,
+) {
+    for event in collisions.read() {
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        let (ball, bumper) = if balls.contains(*e1) {
+            (*e1, *e2)
+        } else {
+            (*e2, *e1)
+        };
+        let (Ok((ball_transform, mut velocity)), Ok(bumper_transform)) =
+            (balls.get_mut(ball), bumpers.get(bumper))
+        else {
+            continue;
+        };
+        let outward = (ball_transform.translation - bumper_transform.translation)
+            .with_y(0.0)
+            .normalize_or(Vec3::X);
+        // Drop any speed into the bumper, then kick outwards
+        let into = velocity.linvel.dot(outward).min(0.0);
+        velocity.linvel += outward * (BUMPER_KICK_SPEED - into);
+        if let Some(score) = score.as_mut() {
+            score.current_score = score.current_score.saturating_add(BUMPER_POINTS);
+        }
+        commands.entity(bumper).insert(BumperSquash {
+            timer: Timer::from_seconds(BUMPER_SQUASH_SECONDS, TimerMode::Once),
+        });
+        hits.write(BumperHit {
+            bumper_entity: bumper,
+            ball_entity: ball,
+        });
+    }
+}
+
+/// Scale of a bumper `t` (0..=1) through its squash animation: flattened and widened on impact,
+/// then wobbling back to rest.
+pub fn squash_scale(t: f32) -> Vec3 {
+    let t = t.clamp(0.0, 1.0);
+    let amount = BUMPER_SQUASH_AMOUNT * (1.0 - t) * (3.0 * PI * t).cos();
+    Vec3::new(1.0 + amount, 1.0 - amount, 1.0 + amount)
+}
+
+/// Advance the squash animations.
+pub fn animate_bumper_squash(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut bumpers: Query<(Entity, &mut Transform, &mut BumperSquash)>,
+) {
+    for (entity, mut transform, mut squash) in bumpers.iter_mut() {
+        squash.timer.tick(time.delta());
+        transform.scale = squash_scale(squash.timer.fraction());
+        if squash.timer.is_finished() {
+            transform.scale = Vec3::ONE;
+            commands.entity(entity).remove::<BumperSquash>();
+        }
+    }
+}
+
+/// Plugin registering bumper kicks and animation.
+pub struct BumpersPlugin;
+
+impl Plugin for BumpersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<CollisionEvent>();
+        app.add_message::<BumperHit>();
+        app.add_systems(
+            Update,
+            (kick_balls_off_bumpers, animate_bumper_squash).chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squash_starts_flattened_and_ends_at_rest() {
+        let start = squash_scale(0.0);
+        assert!(start.x > 1.0 && start.y < 1.0);
+        assert!(squash_scale(1.0).abs_diff_eq(Vec3::ONE, 1e-6));
+    }
+}
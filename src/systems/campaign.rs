@@ -0,0 +1,181 @@
+//! Campaign routing and secret exits.
+//!
+//! By default completing level `n` advances to level `n + 1`. The campaign manifest
+//! (`assets/levels/campaign.ron`) can reroute that per level:
+//!
+//! ```ron
+//! (
+//!     levels: {
+//!         3: (secret_exit: Some(101)), // destroy a secret exit brick on level 3 to go to 101
+//!         101: (next: Some(4)),        // and rejoin the main path afterwards
+//!     },
+//! )
+//! ```
+//!
+//! A secret exit brick (index 17, `SECRET_EXIT_BRICK`) is an ordinary destructible brick; once one
+//! has been destroyed, completing the level takes the `secret_exit` route instead of `next`. The
+//! flag is cleared whenever the level is (re)loaded, so a restart has to find the exit again.
+//! Levels without a manifest entry, and a missing manifest, keep the linear progression.
+
+use std::collections::BTreeMap;
+
+use bevy::ecs::message::MessageReader;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::SECRET_EXIT_BRICK;
+use crate::level_loader::{CurrentLevel, LevelAdvanceSystems};
+use crate::signals::BrickDestroyed;
+use crate::systems::playlist::Playlist;
+
+/// Path of the campaign manifest.
+pub const CAMPAIGN_MANIFEST_PATH: &str = "assets/levels/campaign.ron";
+
+/// Where a level leads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CampaignLink {
+    /// Next level on the regular path (defaults to the following number)
+    #[serde(default)]
+    pub next: Option<u32>,
+    /// Level reached by completing this one after destroying a secret exit brick
+    #[serde(default)]
+    pub secret_exit: Option<u32>,
+}
+
+/// Level progression overrides, keyed by level number.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CampaignManifest {
+    #[serde(default)]
+    pub levels: BTreeMap<u32, CampaignLink>,
+}
+
+impl CampaignManifest {
+    /// Level that follows `current`, taking the secret route if `secret_found` and one exists.
+    pub fn next_level(&self, current: u32, secret_found: bool) -> u32 {
+        let link = self.levels.get(&current).copied().unwrap_or_default();
+        match link.secret_exit {
+            Some(secret) if secret_found => secret,
+            _ => link.next.unwrap_or(current + 1),
+        }
+    }
+}
+
+/// Whether a secret exit brick has been destroyed on the current attempt.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SecretExitState {
+    pub found: bool,
+}
+
+/// Read the campaign manifest into [`CampaignManifest`].
+pub fn load_campaign_manifest(mut commands: Commands) {
+    #[cfg(not(target_arch = "wasm32"))]
+    let content = std::fs::read_to_string(CAMPAIGN_MANIFEST_PATH).ok();
+    #[cfg(target_arch = "wasm32")]
+    let content = Some(include_str!("../../assets/levels/campaign.ron").to_string());
+
+    let manifest = match content.map(|c| ron::de::from_str::<CampaignManifest>(&c)) {
+        Some(Ok(manifest)) => {
+            info!("Loaded campaign manifest from {}", CAMPAIGN_MANIFEST_PATH);
+            manifest
+        }
+        Some(Err(e)) => {
+            warn!(
+                "Failed to parse campaign manifest: {}; using linear progression",
+                e
+            );
+            CampaignManifest::default()
+        }
+        None => CampaignManifest::default(),
+    };
+    commands.insert_resource(manifest);
+}
+
+/// Clear the secret exit flag when a level is loaded or restarted.
+pub fn reset_secret_exit(mut secret: ResMut<SecretExitState>) {
+    secret.found = false;
+}
+
+/// Note destroyed secret exit bricks.
+pub fn detect_secret_exit(
+    mut destroyed: MessageReader<BrickDestroyed>,
+    mut secret: ResMut<SecretExitState>,
+) {
+    if destroyed
+        .read()
+        .any(|msg| msg.brick_type == SECRET_EXIT_BRICK)
+    {
+        if !secret.found {
+            info!("Secret exit found");
+        }
+        secret.found = true;
+    }
+}
+
+/// Resolves the level that follows the current one.
+#[derive(SystemParam)]
+pub struct CampaignRoute<'w> {
+    manifest: Option<Res<'w, CampaignManifest>>,
+    secret: Option<Res<'w, SecretExitState>>,
+    playlist: Option<Res<'w, Playlist>>,
+}
+
+impl CampaignRoute<'_> {
+    /// Next level after completing `current`, or `None` when the game ends there.
+    ///
+    /// A running playlist takes precedence while its current entry is being played (see
+    /// `systems::playlist`); its last entry ends the game.
+    pub fn next_level(&self, current: u32) -> Option<u32> {
+        if let Some(playlist) = self.playlist.as_deref() {
+            if playlist.current_entry() == Some(current) {
+                return playlist.next_entry();
+            }
+        }
+        let secret_found = self.secret.as_deref().is_some_and(|s| s.found);
+        Some(
+            self.manifest
+                .as_deref()
+                .map_or(current + 1, |m| m.next_level(current, secret_found)),
+        )
+    }
+}
+
+/// Plugin registering campaign routing and secret exits.
+pub struct CampaignPlugin;
+
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CampaignManifest>();
+        app.init_resource::<SecretExitState>();
+        app.add_message::<BrickDestroyed>();
+        app.add_systems(Startup, load_campaign_manifest);
+        app.add_systems(
+            Update,
+            (
+                reset_secret_exit.run_if(resource_exists_and_changed::<CurrentLevel>),
+                detect_secret_exit,
+            )
+                .chain()
+                // After bricks are despawned (and their messages written), before the advance
+                .after(crate::systems::merkaba::MerkabaSpawnFlowSystems::Queue)
+                .before(LevelAdvanceSystems),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_exit_routes_only_when_found() {
+        let manifest: CampaignManifest =
+            ron::de::from_str("(levels: { 3: (secret_exit: Some(101)), 101: (next: Some(4)) })")
+                .unwrap();
+        assert_eq!(manifest.next_level(3, false), 4);
+        assert_eq!(manifest.next_level(3, true), 101);
+        assert_eq!(manifest.next_level(101, false), 4);
+        assert_eq!(manifest.next_level(101, true), 4);
+        assert_eq!(manifest.next_level(7, true), 8);
+    }
+}
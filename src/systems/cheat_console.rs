@@ -0,0 +1,327 @@
+//! Cheat console: runtime commands for reproducing gameplay bugs.
+//!
+//! With cheat mode active (`G`), the backquote key (`` ` ``) opens a one-line console at the bottom
+//! of the screen (`ui::cheat_console`). While it is open, keystrokes go to the console instead of
+//! the game. Enter runs the line, Escape or `` ` `` closes the console. Commands:
+//!
+//! - `spawnbrick <type> <row> <col>`: spawn a brick of type index `<type>` (see `docs/bricks.md`)
+//!   into grid cell (`<row>`, `<col>`) of the live level
+//! - `help`: list the commands
+//!
+//! Ctrl + left click spawns a brick of the last `spawnbrick` type (simple brick by default) in the
+//! cell under the cursor.
+//!
+//! Both send [`SpawnBrickRequested`]; [`apply_spawn_brick_requests`] spawns the brick through the
+//! level loader's `spawn_brick`, like bricks loaded from a level file, so specific brick types can
+//! be placed next to the ball to reproduce collision bugs. Occupied cells, and cells outside the
+//! grid of the live level, are left alone.
+
+use std::collections::HashSet;
+
+use bevy::ecs::message::{Message, MessageReader, MessageWriter};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::level_format::{validate_cell_range_in, BUMPER, MAX_RESOLUTION, SIMPLE_BRICK};
+use crate::level_loader::spawn_brick;
+use crate::physics_config::BrickPhysicsConfig;
+use crate::systems::cheat_mode::CheatModeState;
+#[cfg(feature = "texture_manifest")]
+use crate::systems::textures::{ObjectClass, TypeVariantRegistry};
+use crate::{Brick, BrickGridPos, GridResolution, MainCamera};
+
+/// Text listing the console commands.
+pub const CHEAT_CONSOLE_HELP: &str = "spawnbrick <type> <row> <col> | help";
+
+/// A parsed console command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheatCommand {
+    SpawnBrick {
+        brick_type: u8,
+        row: usize,
+        col: usize,
+    },
+    Help,
+}
+
+/// Request to spawn a brick into the live level.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpawnBrickRequested {
+    pub brick_type: u8,
+    pub row: usize,
+    pub col: usize,
+}
+
+/// State of the cheat console.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct CheatConsole {
+    pub open: bool,
+    /// Line being typed
+    pub input: String,
+    /// Result of the last command
+    pub output: String,
+    /// Brick type spawned by Ctrl + click
+    pub click_brick_type: u8,
+}
+
+impl Default for CheatConsole {
+    fn default() -> Self {
+        Self {
+            open: false,
+            input: String::new(),
+            output: String::new(),
+            click_brick_type: SIMPLE_BRICK,
+        }
+    }
+}
+
+/// Parse a console line. Cells are checked against the largest level grid; whether they lie on
+/// the grid of the live level is checked when the brick is spawned.
+pub fn parse_cheat_command(line: &str) -> Result<CheatCommand, String> {
+    let mut words = line.split_whitespace();
+    match words.next() {
+        Some("spawnbrick") => {
+            let args: Vec<&str> = words.collect();
+            let [brick_type, row, col] = args[..] else {
+                return Err("usage: spawnbrick <type> <row> <col>".to_string());
+            };
+            let brick_type = match brick_type.parse::<u8>() {
+                Ok(brick_type @ 3..=255) => brick_type,
+                _ => return Err(format!("invalid brick type '{brick_type}' (3-255)")),
+            };
+            let row = row
+                .parse::<usize>()
+                .map_err(|_| format!("invalid row '{row}'"))?;
+            let col = col
+                .parse::<usize>()
+                .map_err(|_| format!("invalid column '{col}'"))?;
+            validate_cell_range_in((row, row), (col, col), (MAX_RESOLUTION, MAX_RESOLUTION))?;
+            Ok(CheatCommand::SpawnBrick {
+                brick_type,
+                row,
+                col,
+            })
+        }
+        Some("help") => Ok(CheatCommand::Help),
+        Some(other) => Err(format!("unknown command '{other}'; {CHEAT_CONSOLE_HELP}")),
+        None => Err(CHEAT_CONSOLE_HELP.to_string()),
+    }
+}
+
+/// Run a console line: spawn commands are forwarded as [`SpawnBrickRequested`].
+pub fn run_cheat_command(
+    line: &str,
+    console: &mut CheatConsole,
+    spawns: &mut MessageWriter<SpawnBrickRequested>,
+) {
+    console.output = match parse_cheat_command(line) {
+        Ok(CheatCommand::SpawnBrick {
+            brick_type,
+            row,
+            col,
+        }) => {
+            spawns.write(SpawnBrickRequested {
+                brick_type,
+                row,
+                col,
+            });
+            console.click_brick_type = brick_type;
+            format!("spawned brick {brick_type} at ({row}, {col})")
+        }
+        Ok(CheatCommand::Help) => CHEAT_CONSOLE_HELP.to_string(),
+        Err(e) => e,
+    };
+}
+
+/// Open the console with `` ` `` in cheat mode and feed it keystrokes while it is open.
+///
+/// Runs in `PreUpdate` before the cheat mode toggle and clears the keyboard state while the console
+/// is open, so typing does not trigger gameplay keys.
+pub fn cheat_console_input(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut key_events: MessageReader<KeyboardInput>,
+    cheat: Res<CheatModeState>,
+    mut console: ResMut<CheatConsole>,
+    mut spawns: MessageWriter<SpawnBrickRequested>,
+) {
+    if !console.open {
+        key_events.clear();
+        if cheat.is_active() && keyboard.just_pressed(KeyCode::Backquote) {
+            console.open = true;
+            console.input.clear();
+            keyboard.reset_all();
+        }
+        return;
+    }
+    if !cheat.is_active() {
+        console.open = false;
+        return;
+    }
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed || !console.open {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                run_cheat_command(&line, &mut console, &mut spawns);
+            }
+            Key::Escape => console.open = false,
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Space => console.input.push(' '),
+            Key::Character(text) if text.as_str() == "`" => console.open = false,
+            Key::Character(text) => console.input.push_str(text),
+            _ => {}
+        }
+    }
+    keyboard.reset_all();
+}
+
+/// Spawn a brick of the console's click type in the cell under the cursor on Ctrl + left click.
+pub fn spawn_brick_on_click(
+    cheat: Res<CheatModeState>,
+    console: Res<CheatConsole>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    grid: Option<Res<GridResolution>>,
+    mut spawns: MessageWriter<SpawnBrickRequested>,
+) {
+    if !cheat.is_active()
+        || !mouse.just_pressed(MouseButton::Left)
+        || !keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+    {
+        return;
+    }
+    let Some(cursor) = window.single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera.single() else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+        return;
+    };
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    // Bricks sit on the plane through the cell centres
+    let plane_origin = grid.cell_center(0, 0);
+    let Some(distance) = ray.intersect_plane(plane_origin, InfinitePlane3d::new(Vec3::Y)) else {
+        return;
+    };
+    if let Some((row, col)) = grid.cell_at(ray.get_point(distance)) {
+        spawns.write(SpawnBrickRequested {
+            brick_type: console.click_brick_type,
+            row,
+            col,
+        });
+    }
+}
+
+/// Spawn requested bricks into free cells while cheat mode is active.
+pub fn apply_spawn_brick_requests(
+    mut requests: MessageReader<SpawnBrickRequested>,
+    cheat: Res<CheatModeState>,
+    bricks: Query<&BrickGridPos, With<Brick>>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    brick_config: Res<BrickPhysicsConfig>,
+    grid: Option<Res<GridResolution>>,
+    #[cfg(feature = "texture_manifest")] type_registry: Option<Res<TypeVariantRegistry>>,
+    mut console: Option<ResMut<CheatConsole>>,
+    mut fallback_material: Local<Option<Handle<StandardMaterial>>>,
+) {
+    if !cheat.is_active() {
+        requests.clear();
+        return;
+    }
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    let mut occupied: HashSet<(usize, usize)> =
+        bricks.iter().map(|pos| (pos.row, pos.col)).collect();
+    for request in requests.read() {
+        let cell = (request.row, request.col);
+        let outside = request.row >= grid.rows || request.col >= grid.cols;
+        if outside || !occupied.insert(cell) {
+            let message = if outside {
+                format!(
+                    "cell ({}, {}) lies outside the grid",
+                    request.row, request.col
+                )
+            } else {
+                format!("cell ({}, {}) is occupied", request.row, request.col)
+            };
+            info!("Cheat spawn skipped: {}", message);
+            if let Some(console) = console.as_mut() {
+                console.output = message;
+            }
+            continue;
+        }
+        if request.brick_type == BUMPER {
+            crate::systems::bumpers::spawn_bumper(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &grid,
+                request.row,
+                request.col,
+            );
+        } else {
+            #[cfg(feature = "texture_manifest")]
+            let registered = type_registry
+                .as_ref()
+                .and_then(|registry| registry.get(ObjectClass::Brick, request.brick_type));
+            #[cfg(not(feature = "texture_manifest"))]
+            let registered: Option<Handle<StandardMaterial>> = None;
+            let material = registered.unwrap_or_else(|| {
+                fallback_material
+                    .get_or_insert_with(|| {
+                        materials.add(StandardMaterial {
+                            base_color: Color::srgb(0.9, 0.1, 0.1),
+                            unlit: false,
+                            ..default()
+                        })
+                    })
+                    .clone()
+            });
+            spawn_brick(
+                &mut commands,
+                &mut meshes,
+                material,
+                request.brick_type,
+                &grid,
+                request.row,
+                request.col,
+                &brick_config,
+            );
+        }
+        info!(
+            "Cheat spawned brick {} at ({}, {})",
+            request.brick_type, request.row, request.col
+        );
+    }
+}
+
+/// Plugin registering the cheat console and click-to-spawn.
+pub struct CheatConsolePlugin;
+
+impl Plugin for CheatConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CheatConsole>();
+        app.add_message::<SpawnBrickRequested>();
+        app.add_systems(
+            PreUpdate,
+            cheat_console_input
+                .after(bevy::input::InputSystems)
+                .before(crate::systems::cheat_mode::toggle_cheat_mode_input),
+        );
+        app.add_systems(
+            Update,
+            (spawn_brick_on_click, apply_spawn_brick_requests).chain(),
+        );
+    }
+}
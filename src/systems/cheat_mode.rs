@@ -52,7 +52,7 @@ impl Plugin for CheatModePlugin {
 }
 
 /// Toggle cheat mode when G is pressed during gameplay
-fn toggle_cheat_mode_input(
+pub(crate) fn toggle_cheat_mode_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
     mut cheat: ResMut<CheatModeState>,
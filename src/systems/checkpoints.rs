@@ -0,0 +1,284 @@
+//! Level checkpoints.
+//!
+//! Long levels can define checkpoints as groups of brick cells (`LevelDefinition::checkpoints`):
+//!
+//! ```ron
+//! checkpoints: [
+//!     (bricks: [(2, 8), (2, 9), (2, 10), (2, 11)]),
+//!     (bricks: [(6, 0), (6, 19)]),
+//! ],
+//! ```
+//!
+//! Checkpoints are reached in order, once every brick in the group has been destroyed. Reaching one
+//! stores a [`Checkpoint`] snapshot in [`LevelCheckpoints`]: the remaining bricks (cell and current
+//! type, so damaged multi-hit bricks stay damaged) and the score at that moment.
+//!
+//! Losing the last life with a checkpoint reached does not end the game: instead of the game-over
+//! overlay, [`restore_checkpoint_on_game_over`] requests a restart from the checkpoint, which
+//! rebuilds the level with only the snapshot's bricks, resets lives and puts the score back to the
+//! checkpoint's. A full restart (R) or a different level discards the checkpoints.
+//! `ui::checkpoint_display` shows the progress.
+
+use std::collections::HashSet;
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::{
+    matrix_resolution, validate_cell_range_in, BUMPER, HAZARD_BRICK_91, INDESTRUCTIBLE_BRICK,
+};
+use crate::level_loader::{CurrentLevel, LevelDefinition, RestartRequested};
+use crate::systems::respawn::GameOverRequested;
+use crate::systems::scoring::ScoreState;
+use crate::{Brick, BrickGridPos, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn};
+
+/// A checkpoint as authored in a level file: the `(row, col)` cells of its brick group.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointDef {
+    pub bricks: Vec<(usize, usize)>,
+}
+
+impl CheckpointDef {
+    /// Check the group against the level `matrix`: every cell must hold a destructible brick.
+    pub fn validate(&self, matrix: &[Vec<u8>]) -> Result<(), String> {
+        if self.bricks.is_empty() {
+            return Err("checkpoint has no bricks".to_string());
+        }
+        self.bricks.iter().try_for_each(|&(row, col)| {
+            validate_cell_range_in((row, row), (col, col), matrix_resolution(matrix))?;
+            match matrix.get(row).and_then(|r| r.get(col)) {
+                Some(&(INDESTRUCTIBLE_BRICK | HAZARD_BRICK_91 | BUMPER)) => Err(format!(
+                    "checkpoint cell ({}, {}) holds an indestructible brick",
+                    row, col
+                )),
+                Some(3..=255) => Ok(()),
+                _ => Err(format!("checkpoint cell ({}, {}) holds no brick", row, col)),
+            }
+        })
+    }
+}
+
+/// A brick left on the field when a checkpoint was reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointBrick {
+    pub row: usize,
+    pub col: usize,
+    pub type_id: u8,
+}
+
+/// Snapshot taken when a checkpoint is reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Index into the level's checkpoint list
+    pub index: usize,
+    pub bricks: Vec<CheckpointBrick>,
+    pub score: u32,
+}
+
+impl Checkpoint {
+    /// `def` with its bricks replaced by the snapshot's; everything else (paddle and ball cells,
+    /// gravity, zones, ...) is kept.
+    pub fn apply_to(&self, mut def: LevelDefinition) -> LevelDefinition {
+        for row in def.matrix.iter_mut() {
+            for cell in row.iter_mut().filter(|cell| **cell >= 3) {
+                *cell = 0;
+            }
+        }
+        for brick in &self.bricks {
+            if let Some(cell) = def
+                .matrix
+                .get_mut(brick.row)
+                .and_then(|r| r.get_mut(brick.col))
+            {
+                *cell = brick.type_id;
+            }
+        }
+        def
+    }
+}
+
+/// Checkpoints of the current level and the latest one reached.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LevelCheckpoints {
+    /// Level the checkpoints belong to
+    pub level_number: Option<u32>,
+    /// Valid brick groups, in order
+    pub groups: Vec<HashSet<(usize, usize)>>,
+    /// Latest checkpoint reached on this level
+    pub reached: Option<Checkpoint>,
+}
+
+impl LevelCheckpoints {
+    /// Load the groups of a newly started level, skipping (and logging) invalid ones.
+    pub fn for_level(def: &LevelDefinition) -> Self {
+        let groups = def
+            .checkpoints
+            .iter()
+            .filter(|cp| match cp.validate(&def.matrix) {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Ignoring checkpoint of level {}: {}", def.number, e);
+                    false
+                }
+            })
+            .map(|cp| cp.bricks.iter().copied().collect())
+            .collect();
+        Self {
+            level_number: Some(def.number),
+            groups,
+            reached: None,
+        }
+    }
+
+    /// Number of checkpoints reached so far.
+    pub fn reached_count(&self) -> usize {
+        self.reached.as_ref().map_or(0, |cp| cp.index + 1)
+    }
+
+    /// Forget the checkpoints reached (full restart).
+    pub fn clear(&mut self) {
+        self.reached = None;
+    }
+}
+
+/// Load the checkpoints of a new level and record a snapshot whenever the next one is reached.
+pub fn track_checkpoints(
+    current_level: Option<Res<CurrentLevel>>,
+    bricks: Query<(&BrickGridPos, &BrickTypeId), (With<Brick>, Without<MarkedForDespawn>)>,
+    counted: Query<(), (With<CountsTowardsCompletion>, Without<MarkedForDespawn>)>,
+    score: Option<Res<ScoreState>>,
+    mut checkpoints: ResMut<LevelCheckpoints>,
+) {
+    let Some(level) = current_level else {
+        return;
+    };
+    // Restoring a checkpoint reloads the same level number and must keep what was reached
+    if checkpoints.level_number != Some(level.0.number) {
+        *checkpoints = LevelCheckpoints::for_level(&level.0);
+    }
+    // Nothing on the field yet (level still loading) or already cleared
+    if counted.is_empty() {
+        return;
+    }
+    let mut next = checkpoints.reached_count();
+    while let Some(group) = checkpoints.groups.get(next) {
+        if bricks
+            .iter()
+            .any(|(pos, _)| group.contains(&(pos.row, pos.col)))
+        {
+            break;
+        }
+        next += 1;
+    }
+    if next == checkpoints.reached_count() {
+        return;
+    }
+    let snapshot = Checkpoint {
+        index: next - 1,
+        bricks: bricks
+            .iter()
+            .map(|(pos, type_id)| CheckpointBrick {
+                row: pos.row,
+                col: pos.col,
+                type_id: type_id.0,
+            })
+            .collect(),
+        score: score.map_or(0, |s| s.current_score),
+    };
+    info!(
+        "Checkpoint {}/{} reached ({} bricks left)",
+        snapshot.index + 1,
+        checkpoints.groups.len(),
+        snapshot.bricks.len()
+    );
+    checkpoints.reached = Some(snapshot);
+}
+
+/// Restart from the latest checkpoint instead of ending the game.
+pub fn restore_checkpoint_on_game_over(
+    mut game_over: MessageReader<GameOverRequested>,
+    checkpoints: Res<LevelCheckpoints>,
+    mut restart: MessageWriter<RestartRequested>,
+) {
+    if game_over.read().count() == 0 || checkpoints.reached.is_none() {
+        return;
+    }
+    info!(
+        "Out of lives; restarting from checkpoint {}",
+        checkpoints.reached_count()
+    );
+    restart.write(RestartRequested {
+        from_checkpoint: true,
+    });
+}
+
+/// Plugin registering level checkpoints.
+pub struct CheckpointsPlugin;
+
+impl Plugin for CheckpointsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelCheckpoints>();
+        app.add_message::<GameOverRequested>();
+        app.add_message::<RestartRequested>();
+        app.add_systems(
+            Update,
+            (track_checkpoints, restore_checkpoint_on_game_over).chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(matrix: Vec<Vec<u8>>) -> LevelDefinition {
+        let mut def: LevelDefinition =
+            ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+        def.matrix = matrix;
+        def
+    }
+
+    #[test]
+    fn invalid_groups_are_skipped() {
+        let mut def = level(vec![vec![2, 20, 90], vec![0, 10, 1]]);
+        def.checkpoints = vec![
+            CheckpointDef {
+                bricks: vec![(0, 1), (1, 1)],
+            },
+            CheckpointDef {
+                bricks: vec![(0, 2)],
+            },
+            CheckpointDef {
+                bricks: vec![(1, 0)],
+            },
+            CheckpointDef { bricks: vec![] },
+        ];
+        let checkpoints = LevelCheckpoints::for_level(&def);
+        assert_eq!(checkpoints.groups.len(), 1);
+        assert!(checkpoints.groups[0].contains(&(1, 1)));
+    }
+
+    #[test]
+    fn snapshot_replaces_bricks_but_keeps_spawn_cells() {
+        let def = level(vec![vec![2, 20, 90], vec![0, 13, 1]]);
+        let checkpoint = Checkpoint {
+            index: 0,
+            bricks: vec![
+                CheckpointBrick {
+                    row: 1,
+                    col: 1,
+                    type_id: 11,
+                },
+                CheckpointBrick {
+                    row: 0,
+                    col: 2,
+                    type_id: 90,
+                },
+            ],
+            score: 0,
+        };
+        let restored = checkpoint.apply_to(def);
+        assert_eq!(restored.matrix, vec![vec![2, 0, 90], vec![0, 11, 1]]);
+    }
+}
@@ -0,0 +1,557 @@
+//! Save sync: keep progress and settings in step across machines.
+//!
+//! Saves are read and written through the [`SaveBackend`] trait. [`LocalFileBackend`] works on the
+//! files the game already writes; with the `cloud_sync` feature, [`HttpBackend`] stores them on a
+//! remote HTTP/WebDAV server (`GET`/`PUT`, collections created with `MKCOL`).
+//!
+//! Sync is opt-in through `config/sync.ron` (native only):
+//!
+//! ```ron
+//! (
+//!     enabled: true,
+//!     url: "https://dav.example.com/brkrs",
+//!     username: Some("player"),
+//!     password: Some("secret"),
+//! )
+//! ```
+//!
+//! When enabled, every save file (the shared settings, playlist and progress, and the files of each
+//! profile, see `systems::profiles`) is synced once at startup and again on exit, after the last
+//! saves. Conflicts are resolved latest-wins ([`sync_file`]): the newer copy replaces the older
+//! one, and the replaced copy is kept next to it with a [`BACKUP_SUFFIX`].
+//! Profiles created on other machines are found through a profile index stored on the remote.
+//!
+//! Syncs run on the IO task pool ([`PendingSync`]), so a slow server never stalls a frame. Files
+//! the startup sync downloads are loaded once it finishes, as on a profile switch. The sync on exit
+//! is awaited by [`PendingSync::wait`] after the app has stopped; `BrkrsConfig::run` does so, and
+//! apps embedding the plugins should keep a clone of the resource and call it after `App::run` too.
+//!
+//! Credentials are only sent over `https`; a `url` with another scheme and a username or password
+//! is refused.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy::tasks::futures_lite::future;
+use bevy::tasks::{block_on, IoTaskPool, Task};
+use serde::{Deserialize, Serialize};
+
+use crate::systems::audio::AUDIO_CONFIG_PATH;
+use crate::systems::autosave::{write_atomic, PROGRESS_FILE, PROGRESS_PATH};
+use crate::systems::hints::{HINTS_FILE, HINTS_PATH};
+use crate::systems::playlist::PLAYLIST_PATH;
+use crate::systems::post_processing::GRAPHICS_CONFIG_PATH;
+use crate::systems::profiles::{
+    list_profiles, load_profile_data, profile_dir, CurrentProfile, ProfileData, ProfileDirty,
+    PROFILE_DATA_FILE,
+};
+
+/// Path of the sync settings file.
+pub const SYNC_CONFIG_PATH: &str = "config/sync.ron";
+/// Key of the list of profile names kept on the remote.
+pub const PROFILE_INDEX_KEY: &str = "config/profiles/index.ron";
+/// Appended to a key to store the copy replaced by a sync.
+pub const BACKUP_SUFFIX: &str = ".bak";
+/// Files every profile keeps in its directory.
+const PROFILE_FILES: [&str; 6] = [
+    PROFILE_DATA_FILE,
+    "audio.ron",
+    "graphics.ron",
+    "playlist.ron",
+    PROGRESS_FILE,
+    HINTS_FILE,
+];
+
+/// Sync settings (`config/sync.ron`); sync stays off unless `enabled` is set.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Base URL of the remote HTTP/WebDAV collection
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+/// Contents of a save file with its modification time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedFile {
+    /// Seconds since the Unix epoch
+    pub modified: u64,
+    pub content: String,
+}
+
+/// Storage for save files, addressed by their path relative to the game directory
+/// (e.g. `config/audio.ron`).
+pub trait SaveBackend: Send + Sync {
+    /// Read `key`; `Ok(None)` if it does not exist.
+    fn read(&self, key: &str) -> Result<Option<SavedFile>, String>;
+    /// Write `key`, keeping `file.modified` as its modification time.
+    fn write(&self, key: &str, file: &SavedFile) -> Result<(), String>;
+}
+
+/// Save files on the local disk, under `root`.
+#[derive(Debug, Clone)]
+pub struct LocalFileBackend {
+    root: PathBuf,
+}
+
+impl LocalFileBackend {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl SaveBackend for LocalFileBackend {
+    fn read(&self, key: &str) -> Result<Option<SavedFile>, String> {
+        let path = self.root.join(key);
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("failed to read {}: {e}", path.display())),
+        };
+        let modified = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| format!("failed to read the time of {}: {e}", path.display()))?;
+        Ok(Some(SavedFile {
+            modified: unix_secs(modified),
+            content,
+        }))
+    }
+
+    fn write(&self, key: &str, file: &SavedFile) -> Result<(), String> {
+        let path = self.root.join(key);
+        // Atomic like the game's own saves, so an interrupted sync never leaves a torn file
+        write_atomic(&path, &file.content)?;
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|f| f.set_modified(UNIX_EPOCH + Duration::from_secs(file.modified)))
+            .map_err(|e| format!("failed to set the time of {}: {e}", path.display()))
+    }
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Save files on a remote HTTP/WebDAV server.
+///
+/// Each file is stored as a RON [`SavedFile`], so its modification time survives the round trip.
+#[cfg(feature = "cloud_sync")]
+pub struct HttpBackend {
+    agent: ureq::Agent,
+    base_url: String,
+    authorization: Option<String>,
+}
+
+#[cfg(feature = "cloud_sync")]
+impl HttpBackend {
+    /// Backend for the collection at `config.url`, with basic authentication if a username is set.
+    pub fn new(config: &SyncConfig) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(10)))
+            .http_status_as_error(false)
+            .build()
+            .new_agent();
+        let authorization = config.username.as_deref().map(|user| {
+            let credentials = format!("{user}:{}", config.password.as_deref().unwrap_or(""));
+            format!("Basic {}", base64_encode(credentials.as_bytes()))
+        });
+        Self {
+            agent,
+            base_url: config.url.trim_end_matches('/').to_string(),
+            authorization,
+        }
+    }
+
+    fn url(&self, key: &str) -> String {
+        let path: Vec<String> = key.split('/').map(encode_segment).collect();
+        format!("{}/{}", self.base_url, path.join("/"))
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::http::request::Builder {
+        let builder = ureq::http::Request::builder().method(method).uri(url);
+        match &self.authorization {
+            Some(authorization) => builder.header("Authorization", authorization),
+            None => builder,
+        }
+    }
+
+    fn send(&self, method: &str, url: &str, body: String) -> Result<u16, String> {
+        let request = self
+            .request(method, url)
+            .body(body)
+            .map_err(|e| format!("invalid request to {url}: {e}"))?;
+        self.agent
+            .run(request)
+            .map(|response| response.status().as_u16())
+            .map_err(|e| format!("{method} {url} failed: {e}"))
+    }
+}
+
+#[cfg(feature = "cloud_sync")]
+impl SaveBackend for HttpBackend {
+    fn read(&self, key: &str) -> Result<Option<SavedFile>, String> {
+        let url = self.url(key);
+        let request = self
+            .request("GET", &url)
+            .body(())
+            .map_err(|e| format!("invalid request to {url}: {e}"))?;
+        let mut response = self
+            .agent
+            .run(request)
+            .map_err(|e| format!("GET {url} failed: {e}"))?;
+        match response.status().as_u16() {
+            404 => Ok(None),
+            200..=299 => {
+                let body = response
+                    .body_mut()
+                    .read_to_string()
+                    .map_err(|e| format!("failed to read {url}: {e}"))?;
+                ron::de::from_str(&body)
+                    .map(Some)
+                    .map_err(|e| format!("invalid save file at {url}: {e}"))
+            }
+            status => Err(format!("GET {url} returned {status}")),
+        }
+    }
+
+    fn write(&self, key: &str, file: &SavedFile) -> Result<(), String> {
+        // WebDAV needs the parent collections; creating existing ones just fails harmlessly
+        let segments: Vec<&str> = key.split('/').collect();
+        for depth in 1..segments.len() {
+            let collection = self.url(&segments[..depth].join("/"));
+            self.send("MKCOL", &format!("{collection}/"), String::new())?;
+        }
+        let url = self.url(key);
+        let content = ron::ser::to_string_pretty(file, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("failed to serialize {key}: {e}"))?;
+        match self.send("PUT", &url, content)? {
+            200..=299 => Ok(()),
+            status => Err(format!("PUT {url} returned {status}")),
+        }
+    }
+}
+
+/// Percent-encode a URL path segment.
+#[cfg(feature = "cloud_sync")]
+fn encode_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(feature = "cloud_sync")]
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let block = chunk.iter().enumerate().fold(0u32, |block, (i, byte)| {
+            block | (u32::from(*byte) << (16 - 8 * i))
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[((block >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// The remote backend described by `config`.
+pub fn remote_backend(config: &SyncConfig) -> Result<Box<dyn SaveBackend>, String> {
+    if config.url.is_empty() {
+        return Err("no sync url configured".to_string());
+    }
+    let has_credentials = config.username.is_some() || config.password.is_some();
+    if has_credentials && !config.url.to_ascii_lowercase().starts_with("https://") {
+        return Err(format!(
+            "refusing to send credentials to {} without https",
+            config.url
+        ));
+    }
+    #[cfg(feature = "cloud_sync")]
+    {
+        Ok(Box::new(HttpBackend::new(config)))
+    }
+    #[cfg(not(feature = "cloud_sync"))]
+    Err("built without the `cloud_sync` feature".to_string())
+}
+
+/// What [`sync_file`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncOutcome {
+    /// Neither side has the file
+    Missing,
+    /// Both sides have the same content
+    UpToDate,
+    /// The local copy was written to the remote
+    Uploaded,
+    /// The remote copy was written locally
+    Downloaded,
+}
+
+/// Key of the backup kept when a sync replaces `key`.
+pub fn backup_key(key: &str) -> String {
+    format!("{key}{BACKUP_SUFFIX}")
+}
+
+/// Sync one file, latest-wins: the newer copy replaces the older one, which is backed up next to
+/// it on the same side. On a tie the local copy wins.
+pub fn sync_file(
+    local: &dyn SaveBackend,
+    remote: &dyn SaveBackend,
+    key: &str,
+) -> Result<SyncOutcome, String> {
+    match (local.read(key)?, remote.read(key)?) {
+        (None, None) => Ok(SyncOutcome::Missing),
+        (Some(local_file), None) => {
+            remote.write(key, &local_file)?;
+            Ok(SyncOutcome::Uploaded)
+        }
+        (None, Some(remote_file)) => {
+            local.write(key, &remote_file)?;
+            Ok(SyncOutcome::Downloaded)
+        }
+        (Some(local_file), Some(remote_file)) if local_file.content == remote_file.content => {
+            Ok(SyncOutcome::UpToDate)
+        }
+        (Some(local_file), Some(remote_file)) if remote_file.modified > local_file.modified => {
+            local.write(&backup_key(key), &local_file)?;
+            local.write(key, &remote_file)?;
+            Ok(SyncOutcome::Downloaded)
+        }
+        (Some(local_file), Some(remote_file)) => {
+            remote.write(&backup_key(key), &remote_file)?;
+            remote.write(key, &local_file)?;
+            Ok(SyncOutcome::Uploaded)
+        }
+    }
+}
+
+/// Keys of every save file: the shared ones and those of each profile in `profiles`.
+pub fn save_keys(profiles: &[String]) -> Vec<String> {
+    let mut keys: Vec<String> = [
+        AUDIO_CONFIG_PATH,
+        GRAPHICS_CONFIG_PATH,
+        PLAYLIST_PATH,
+        PROGRESS_PATH,
+        HINTS_PATH,
+    ]
+    .map(str::to_string)
+    .to_vec();
+    for name in profiles {
+        let dir = profile_dir(name);
+        keys.extend(
+            PROFILE_FILES
+                .iter()
+                .map(|file| dir.join(file).to_string_lossy().replace('\\', "/")),
+        );
+    }
+    keys
+}
+
+/// Merge the remote profile index with `local_profiles`, update the remote copy and return the
+/// merged names.
+pub fn merge_profile_index(
+    remote: &dyn SaveBackend,
+    local_profiles: &[String],
+) -> Result<Vec<String>, String> {
+    let remote_names: Vec<String> = match remote.read(PROFILE_INDEX_KEY)? {
+        Some(file) => ron::de::from_str(&file.content).unwrap_or_else(|e| {
+            warn!("Ignoring invalid remote profile index: {}", e);
+            Vec::new()
+        }),
+        None => Vec::new(),
+    };
+    let mut names = remote_names.clone();
+    names.extend(local_profiles.iter().cloned());
+    names.sort();
+    names.dedup();
+    if names != remote_names {
+        let content = ron::ser::to_string(&names)
+            .map_err(|e| format!("failed to serialize the profile index: {e}"))?;
+        remote.write(
+            PROFILE_INDEX_KEY,
+            &SavedFile {
+                modified: unix_secs(SystemTime::now()),
+                content,
+            },
+        )?;
+    }
+    Ok(names)
+}
+
+/// Sync every save file with `remote`. Returns the number of files transferred.
+pub fn sync_all(local: &dyn SaveBackend, remote: &dyn SaveBackend) -> Result<usize, String> {
+    let profiles = merge_profile_index(remote, &list_profiles())?;
+    let mut transferred = 0;
+    for key in save_keys(&profiles) {
+        match sync_file(local, remote, &key) {
+            Ok(SyncOutcome::Uploaded | SyncOutcome::Downloaded) => transferred += 1,
+            Ok(SyncOutcome::Missing | SyncOutcome::UpToDate) => {}
+            Err(e) => warn!("Failed to sync {}: {}", key, e),
+        }
+    }
+    Ok(transferred)
+}
+
+fn run_sync(config: &SyncConfig) -> Result<usize, String> {
+    let result =
+        remote_backend(config).and_then(|remote| sync_all(&LocalFileBackend::new("."), &*remote));
+    match &result {
+        Ok(transferred) => info!("Synced saves with {} ({} files)", config.url, transferred),
+        Err(e) => warn!("Save sync failed: {}", e),
+    }
+    result
+}
+
+/// Sync running on the IO task pool, if any.
+///
+/// Clones share the running sync, so a clone taken before `App::run` can wait for the sync on exit
+/// once the app is gone.
+#[derive(Resource, Clone, Default)]
+pub struct PendingSync {
+    task: Arc<Mutex<Option<Task<Result<usize, String>>>>>,
+}
+
+impl PendingSync {
+    pub fn is_running(&self) -> bool {
+        self.slot().is_some()
+    }
+
+    fn slot(&self) -> std::sync::MutexGuard<'_, Option<Task<Result<usize, String>>>> {
+        self.task
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Start a sync, after the running one so two never touch the same files at once.
+    fn start(&self, config: &SyncConfig) {
+        let config = config.clone();
+        let mut slot = self.slot();
+        let previous = slot.take();
+        *slot = Some(IoTaskPool::get().spawn(async move {
+            if let Some(previous) = previous {
+                let _ = previous.await;
+            }
+            run_sync(&config)
+        }));
+    }
+
+    /// The result of the running sync, if it has finished.
+    fn poll(&self) -> Option<Result<usize, String>> {
+        let mut slot = self.slot();
+        let result = block_on(future::poll_once(slot.as_mut()?))?;
+        *slot = None;
+        Some(result)
+    }
+
+    /// Block until the running sync, if any, has finished. Call after `App::run` returns so the
+    /// sync on exit completes before the process ends.
+    pub fn wait(&self) {
+        let task = self.slot().take();
+        if let Some(task) = task {
+            let _ = block_on(task);
+        }
+    }
+}
+
+/// Load `config/sync.ron` into [`SyncConfig`]; sync stays off without it.
+pub fn load_sync_config(mut commands: Commands) {
+    let config = match std::fs::read_to_string(SYNC_CONFIG_PATH) {
+        Ok(content) => ron::de::from_str::<SyncConfig>(&content).unwrap_or_else(|e| {
+            warn!("Failed to parse {}: {}; sync disabled", SYNC_CONFIG_PATH, e);
+            SyncConfig::default()
+        }),
+        Err(_) => SyncConfig::default(),
+    };
+    commands.insert_resource(config);
+}
+
+/// Start syncing in the background at startup.
+pub fn sync_saves_at_startup(config: Res<SyncConfig>, pending: Res<PendingSync>) {
+    if config.enabled {
+        pending.start(&config);
+    }
+}
+
+/// Once the startup sync finishes, load what it downloaded: every persistence module reloads its
+/// files as on a profile switch, and the profile's data unless it already has unsaved changes.
+pub fn finish_startup_sync(
+    pending: Res<PendingSync>,
+    profile: Option<ResMut<CurrentProfile>>,
+    data: Option<ResMut<ProfileData>>,
+    dirty: Option<Res<ProfileDirty>>,
+) {
+    let Some(result) = pending.poll() else {
+        return;
+    };
+    let (Ok(transferred), Some(mut profile)) = (result, profile) else {
+        return;
+    };
+    if transferred == 0 {
+        return;
+    }
+    profile.set_changed();
+    let dirty = dirty.is_some_and(|dirty| dirty.0);
+    if let (Some(name), Some(mut data)) = (profile.name.as_deref(), data) {
+        if !dirty {
+            *data = load_profile_data(name);
+        }
+    }
+}
+
+/// Start syncing on exit, after the last saves; [`PendingSync::wait`] lets it finish.
+pub fn sync_saves_on_exit(
+    config: Res<SyncConfig>,
+    pending: Res<PendingSync>,
+    mut exits: MessageReader<AppExit>,
+) {
+    if exits.read().count() > 0 && config.enabled {
+        pending.start(&config);
+    }
+}
+
+/// Plugin registering save sync (native only).
+pub struct CloudSyncPlugin;
+
+impl Plugin for CloudSyncPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SyncConfig>();
+        app.init_resource::<PendingSync>();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            app.add_systems(
+                PreStartup,
+                (load_sync_config, sync_saves_at_startup).chain(),
+            );
+            app.add_systems(
+                Update,
+                finish_startup_sync
+                    .run_if(|pending: Res<PendingSync>| pending.is_running())
+                    .before(crate::systems::profiles::apply_profile_selection),
+            );
+            app.add_systems(
+                Last,
+                sync_saves_on_exit.after(crate::systems::profiles::save_profile),
+            );
+        }
+    }
+}
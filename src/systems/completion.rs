@@ -0,0 +1,286 @@
+//! Per-level completion rules.
+//!
+//! By default a level is complete once every brick that counts towards completion is gone. Levels
+//! can pick a different goal with `LevelDefinition::completion`:
+//!
+//! ```ron
+//! completion: BrickPercent(75.0),       // destroy 75% of the bricks
+//! completion: TargetScore(2000),        // earn 2000 points on this level
+//! completion: SurviveSeconds(90.0),     // last 90 seconds of play time
+//! completion: KeyBricks([(2, 9), (2, 10)]), // destroy the bricks in these (row, col) cells
+//! ```
+//!
+//! Clearing every counted brick completes a level whatever its rule, so a level can never get stuck.
+//! Levels with brick waves (`systems::waves`) are not complete until the last wave has arrived,
+//! and tutorial levels (`systems::tutorial`) not until the last prompt has been followed.
+//!
+//! # Evaluation
+//! [`LevelCompletion`] holds the current level's validated rule (invalid rules are logged and fall
+//! back to [`CompletionRule::AllBricks`]) and the most bricks seen on the field, the baseline for
+//! percentages. `level_loader::advance_level_when_cleared` gathers a [`CompletionProgress`] through
+//! [`CompletionInputs`] every frame and starts the level advance once the rule is met.
+//! Score targets count points earned on the level (`ScoreState::level_points`), and survival time
+//! is the level clock, which stands still while paused.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::{
+    matrix_resolution, validate_cell_range_in, BUMPER, HAZARD_BRICK_91, INDESTRUCTIBLE_BRICK,
+};
+use crate::level_loader::{CurrentLevel, LevelAdvanceSystems};
+use crate::systems::level_clock::LevelClock;
+use crate::systems::scoring::ScoreState;
+use crate::systems::tutorial::TutorialState;
+use crate::systems::waves::WaveSchedule;
+use crate::{Brick, BrickGridPos, CountsTowardsCompletion, MarkedForDespawn};
+
+/// What the player has to achieve to complete a level.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum CompletionRule {
+    /// Destroy every brick that counts towards completion.
+    #[default]
+    AllBricks,
+    /// Destroy this percentage (`0 < p <= 100`) of the bricks that count towards completion.
+    BrickPercent(f32),
+    /// Earn this many points on the level.
+    TargetScore(u32),
+    /// Keep playing for this many seconds of level time.
+    SurviveSeconds(f32),
+    /// Destroy the bricks in these `(row, col)` cells of the level matrix.
+    KeyBricks(Vec<(usize, usize)>),
+}
+
+impl CompletionRule {
+    /// Check the rule against the level `matrix` it belongs to.
+    pub fn validate(&self, matrix: &[Vec<u8>]) -> Result<(), String> {
+        match self {
+            CompletionRule::AllBricks | CompletionRule::TargetScore(_) => Ok(()),
+            CompletionRule::BrickPercent(p) if !(*p > 0.0 && *p <= 100.0) => {
+                Err(format!("brick percentage must be in (0, 100], got {}", p))
+            }
+            CompletionRule::BrickPercent(_) => Ok(()),
+            CompletionRule::SurviveSeconds(s) if !(s.is_finite() && *s > 0.0) => {
+                Err(format!("survival time must be positive, got {}", s))
+            }
+            CompletionRule::SurviveSeconds(_) => Ok(()),
+            CompletionRule::KeyBricks(cells) if cells.is_empty() => {
+                Err("key brick list is empty".to_string())
+            }
+            CompletionRule::KeyBricks(cells) => cells.iter().try_for_each(|&(row, col)| {
+                validate_cell_range_in((row, row), (col, col), matrix_resolution(matrix))?;
+                match matrix.get(row).and_then(|r| r.get(col)) {
+                    Some(&(INDESTRUCTIBLE_BRICK | HAZARD_BRICK_91 | BUMPER)) => Err(format!(
+                        "key brick cell ({}, {}) holds an indestructible brick",
+                        row, col
+                    )),
+                    Some(3..=255) => Ok(()),
+                    _ => Err(format!("key brick cell ({}, {}) holds no brick", row, col)),
+                }
+            }),
+        }
+    }
+
+    /// Whether the rule is met given the current `progress`.
+    pub fn is_met(&self, progress: &CompletionProgress) -> bool {
+        if progress.bricks_remaining == 0 {
+            return true;
+        }
+        match self {
+            CompletionRule::AllBricks => false,
+            CompletionRule::BrickPercent(percent) => {
+                let total = progress.bricks_total.max(progress.bricks_remaining);
+                let destroyed = total - progress.bricks_remaining;
+                destroyed as f32 * 100.0 >= percent * total as f32
+            }
+            CompletionRule::TargetScore(target) => progress.level_points >= *target,
+            CompletionRule::SurviveSeconds(secs) => progress.elapsed.as_secs_f32() >= *secs,
+            CompletionRule::KeyBricks(_) => progress.key_bricks_remaining == 0,
+        }
+    }
+}
+
+/// Snapshot of everything a [`CompletionRule`] can depend on.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CompletionProgress {
+    /// Bricks that count towards completion still on the field
+    pub bricks_remaining: usize,
+    /// Most counted bricks seen on the field this level
+    pub bricks_total: usize,
+    /// Key bricks still on the field (`KeyBricks` only)
+    pub key_bricks_remaining: usize,
+    /// Points earned on this level
+    pub level_points: u32,
+    /// Level clock reading
+    pub elapsed: Duration,
+}
+
+/// The current level's completion rule and brick baseline.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LevelCompletion {
+    pub rule: CompletionRule,
+    /// Level the baseline belongs to
+    pub level_number: Option<u32>,
+    /// Most counted bricks seen on the field since the level loaded
+    pub peak_bricks: usize,
+}
+
+/// Pick up the rule of a newly loaded level and track the brick baseline.
+pub fn update_level_completion(
+    current_level: Option<Res<CurrentLevel>>,
+    counted: Query<(), (With<Brick>, With<CountsTowardsCompletion>)>,
+    mut completion: ResMut<LevelCompletion>,
+) {
+    let Some(level) = current_level else {
+        return;
+    };
+    if level.is_changed() {
+        let rule = match level.0.completion.validate(&level.0.matrix) {
+            Ok(()) => level.0.completion.clone(),
+            Err(e) => {
+                warn!(
+                    "Ignoring completion rule of level {}: {}",
+                    level.0.number, e
+                );
+                CompletionRule::AllBricks
+            }
+        };
+        completion.rule = rule;
+        // Restarts keep the baseline; a different level starts a new one
+        if completion.level_number != Some(level.0.number) {
+            completion.level_number = Some(level.0.number);
+            completion.peak_bricks = 0;
+        }
+    }
+    let count = counted.iter().count();
+    if count > completion.peak_bricks {
+        completion.peak_bricks = count;
+    }
+}
+
+/// Everything `advance_level_when_cleared` needs beyond the brick count.
+#[derive(SystemParam)]
+pub struct CompletionInputs<'w, 's> {
+    completion: Option<Res<'w, LevelCompletion>>,
+    score: Option<Res<'w, ScoreState>>,
+    clock: Option<Res<'w, LevelClock>>,
+    waves: Option<Res<'w, WaveSchedule>>,
+    tutorial: Option<Res<'w, TutorialState>>,
+    key_bricks: Query<'w, 's, &'static BrickGridPos, (With<Brick>, Without<MarkedForDespawn>)>,
+}
+
+impl CompletionInputs<'_, '_> {
+    /// Whether the current level's rule is met with `bricks_remaining` counted bricks left.
+    ///
+    /// A level with brick waves still to come, or tutorial steps still to do, is never complete.
+    pub fn is_complete(&self, bricks_remaining: usize) -> bool {
+        if self.waves.as_deref().is_some_and(|w| w.pending() > 0) {
+            return false;
+        }
+        if self.tutorial.as_deref().is_some_and(|t| t.pending() > 0) {
+            return false;
+        }
+        let default_rule = CompletionRule::AllBricks;
+        let (rule, bricks_total) = self
+            .completion
+            .as_deref()
+            .map_or((&default_rule, 0), |c| (&c.rule, c.peak_bricks));
+        let key_bricks_remaining = match rule {
+            CompletionRule::KeyBricks(cells) => {
+                let keys: HashSet<(usize, usize)> = cells.iter().copied().collect();
+                self.key_bricks
+                    .iter()
+                    .filter(|pos| keys.contains(&(pos.row, pos.col)))
+                    .count()
+            }
+            _ => 0,
+        };
+        rule.is_met(&CompletionProgress {
+            bricks_remaining,
+            bricks_total,
+            key_bricks_remaining,
+            level_points: self.score.as_deref().map_or(0, |s| s.level_points()),
+            elapsed: self.clock.as_deref().map_or(Duration::ZERO, |c| c.elapsed),
+        })
+    }
+}
+
+/// Plugin registering per-level completion rules.
+pub struct CompletionPlugin;
+
+impl Plugin for CompletionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelCompletion>();
+        app.add_systems(Update, update_level_completion.before(LevelAdvanceSystems));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(remaining: usize, total: usize) -> CompletionProgress {
+        CompletionProgress {
+            bricks_remaining: remaining,
+            bricks_total: total,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rules_are_met_by_their_own_goal_or_a_cleared_field() {
+        assert!(!CompletionRule::AllBricks.is_met(&progress(1, 10)));
+        assert!(CompletionRule::AllBricks.is_met(&progress(0, 10)));
+
+        let percent = CompletionRule::BrickPercent(75.0);
+        assert!(!percent.is_met(&progress(3, 10)));
+        assert!(percent.is_met(&progress(2, 10)));
+
+        let score = CompletionRule::TargetScore(500);
+        let mut p = progress(5, 10);
+        assert!(!score.is_met(&p));
+        p.level_points = 500;
+        assert!(score.is_met(&p));
+
+        let survive = CompletionRule::SurviveSeconds(30.0);
+        p.elapsed = Duration::from_secs(29);
+        assert!(!survive.is_met(&p));
+        p.elapsed = Duration::from_secs(30);
+        assert!(survive.is_met(&p));
+
+        let keys = CompletionRule::KeyBricks(vec![(0, 0)]);
+        p.key_bricks_remaining = 1;
+        assert!(!keys.is_met(&p));
+        p.key_bricks_remaining = 0;
+        assert!(keys.is_met(&p));
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let matrix = vec![vec![0, 20], vec![1, 90]];
+        assert!(CompletionRule::BrickPercent(0.0).validate(&matrix).is_err());
+        assert!(CompletionRule::BrickPercent(101.0)
+            .validate(&matrix)
+            .is_err());
+        assert!(CompletionRule::SurviveSeconds(f32::NAN)
+            .validate(&matrix)
+            .is_err());
+        assert!(CompletionRule::KeyBricks(vec![]).validate(&matrix).is_err());
+        assert!(CompletionRule::KeyBricks(vec![(0, 0)])
+            .validate(&matrix)
+            .is_err());
+        assert!(CompletionRule::KeyBricks(vec![(1, 1)])
+            .validate(&matrix)
+            .is_err());
+        assert!(CompletionRule::KeyBricks(vec![(0, 1)])
+            .validate(&matrix)
+            .is_ok());
+        assert!(CompletionRule::KeyBricks(vec![(25, 1)])
+            .validate(&matrix)
+            .is_err());
+    }
+}
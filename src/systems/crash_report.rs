@@ -0,0 +1,291 @@
+//! Crash reports.
+//!
+//! While the game runs, [`CrashLog`] keeps what a bug report needs: the current level, the
+//! gameplay seed, the score, the running effects and the last [`TELEMETRY_CAPACITY`] gameplay
+//! events (bricks destroyed, lives lost, powerups caught, level switches...). On a panic the hook
+//! installed by [`install_crash_hook`] (done by `BrkrsConfig::run`) writes them with the panic
+//! message to a timestamped file in [`CRASH_REPORTS_DIR`], e.g.
+//! `crash_reports/crash-2026-10-17_14-03-59.txt`, and prints its path.
+//!
+//! The next start picks the report up ([`PreviousCrash`]) and `ui::crash_notice` tells the player
+//! where it is, so it can be attached to a bug report. Crash reports are written on native builds
+//! only.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+
+use crate::level_loader::{CurrentLevel, RestartRequested};
+use crate::signals::BrickDestroyed;
+use crate::systems::ball_save::BallSaved;
+use crate::systems::level_switch::LevelSwitchRequested;
+use crate::systems::powerups::PowerupCollected;
+use crate::systems::respawn::LifeLostEvent;
+use crate::systems::rng::GameRng;
+use crate::systems::scoring::{MilestoneReached, ScoreState};
+use crate::systems::themes::civil_from_days;
+use crate::systems::timed_effects::{ActiveEffects, EffectSnapshot};
+
+/// Directory the crash reports are written to.
+pub const CRASH_REPORTS_DIR: &str = "crash_reports";
+/// Gameplay events kept for the report.
+pub const TELEMETRY_CAPACITY: usize = 500;
+/// File in [`CRASH_REPORTS_DIR`] naming the report the next start should point out.
+const LAST_CRASH_FILE: &str = "last_crash.txt";
+
+/// A gameplay event, with the game time it happened at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelemetryEvent {
+    pub secs: f32,
+    pub text: String,
+}
+
+/// State of the game as the crash report shows it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrashContext {
+    pub level: Option<u32>,
+    pub seed: Option<u64>,
+    pub score: Option<u32>,
+    pub effects: Vec<EffectSnapshot>,
+    /// The last [`TELEMETRY_CAPACITY`] events, oldest first
+    pub events: VecDeque<TelemetryEvent>,
+}
+
+impl CrashContext {
+    /// Keep an event, dropping the oldest one once [`TELEMETRY_CAPACITY`] are kept.
+    pub fn record(&mut self, secs: f32, text: String) {
+        if self.events.len() >= TELEMETRY_CAPACITY {
+            self.events.pop_front();
+        }
+        self.events.push_back(TelemetryEvent { secs, text });
+    }
+
+    /// Text of the crash report for a panic with `message`.
+    pub fn report(&self, message: &str, timestamp: &str) -> String {
+        let shown = |value: Option<String>| value.unwrap_or_else(|| "unknown".to_string());
+        let mut report = format!(
+            "brkrs {} crash report, {timestamp} UTC\n\npanic: {message}\n\n",
+            env!("CARGO_PKG_VERSION")
+        );
+        report.push_str(&format!(
+            "level: {}\nseed: {}\nscore: {}\n",
+            shown(self.level.map(|level| level.to_string())),
+            shown(self.seed.map(|seed| seed.to_string())),
+            shown(self.score.map(|score| score.to_string())),
+        ));
+        report.push_str("\nactive effects:\n");
+        if self.effects.is_empty() {
+            report.push_str("  none\n");
+        }
+        for effect in &self.effects {
+            report.push_str(&format!(
+                "  {:?}, {:.1} s left\n",
+                effect.kind, effect.remaining_secs
+            ));
+        }
+        report.push_str(&format!(
+            "\nlast {} gameplay events, oldest first:\n",
+            self.events.len()
+        ));
+        for event in &self.events {
+            report.push_str(&format!("  [{:>9.3} s] {}\n", event.secs, event.text));
+        }
+        report
+    }
+}
+
+/// The crash context, shared with the panic hook.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CrashLog(pub Arc<Mutex<CrashContext>>);
+
+impl CrashLog {
+    /// Current context; a panic while it was being updated leaves what was there.
+    pub fn snapshot(&self) -> CrashContext {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    fn update(&self, change: impl FnOnce(&mut CrashContext)) {
+        change(&mut self.0.lock().unwrap_or_else(|p| p.into_inner()));
+    }
+}
+
+/// Crash report left by the previous session.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct PreviousCrash(pub PathBuf);
+
+/// `YYYY-MM-DD_HH-MM-SS` (UTC) of a time in seconds since 1970-01-01, as used in report names.
+pub fn crash_timestamp(unix_secs: u64) -> String {
+    let (year, month, day) = civil_from_days((unix_secs / 86_400) as i64);
+    let secs = unix_secs % 86_400;
+    format!(
+        "{year:04}-{month:02}-{day:02}_{:02}-{:02}-{:02}",
+        secs / 3_600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// Write `report` to `dir` and remember it for the next start; returns the report's path.
+pub fn write_crash_report(dir: &Path, timestamp: &str, report: &str) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("crash-{timestamp}.txt"));
+    std::fs::write(&path, report)?;
+    std::fs::write(dir.join(LAST_CRASH_FILE), path.display().to_string())?;
+    Ok(path)
+}
+
+/// The report of the last crash not pointed out yet, forgetting it.
+pub fn take_previous_crash(dir: &Path) -> Option<PathBuf> {
+    let marker = dir.join(LAST_CRASH_FILE);
+    let path = std::fs::read_to_string(&marker).ok()?;
+    if let Err(e) = std::fs::remove_file(&marker) {
+        warn!("Failed to remove {}: {}", marker.display(), e);
+    }
+    Some(PathBuf::from(path.trim()))
+}
+
+/// Write a crash report of `log` when the game panics, after the panic is printed as usual. Only
+/// the first panic is reported: Bevy panics again once a system's panic reaches the main thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn install_crash_hook(log: CrashLog) {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static REPORTED: AtomicBool = AtomicBool::new(false);
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        previous(info);
+        if REPORTED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("(no message)");
+        let message = match info.location() {
+            Some(location) => format!("{message} at {location}"),
+            None => message.to_string(),
+        };
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let timestamp = crash_timestamp(now);
+        let report = log.snapshot().report(&message, &timestamp);
+        match write_crash_report(Path::new(CRASH_REPORTS_DIR), &timestamp, &report) {
+            Ok(path) => eprintln!(
+                "brkrs crashed. A crash report was saved to {}; please attach it to your bug \
+                 report.",
+                path.display()
+            ),
+            Err(e) => eprintln!("brkrs crashed, and the crash report could not be written: {e}"),
+        }
+    }));
+}
+
+/// Keep the level, seed, score and running effects up to date.
+pub fn update_crash_context(
+    log: Res<CrashLog>,
+    current_level: Option<Res<CurrentLevel>>,
+    rng: Option<Res<GameRng>>,
+    score: Option<Res<ScoreState>>,
+    effects: ActiveEffects,
+) {
+    let effects = effects.snapshots();
+    log.update(|context| {
+        context.level = current_level.map(|level| level.0.number);
+        context.seed = rng.map(|rng| rng.seed());
+        context.score = score.map(|score| score.current_score);
+        context.effects = effects;
+    });
+}
+
+/// Keep this frame's gameplay events.
+#[allow(clippy::too_many_arguments)]
+pub fn record_telemetry(
+    log: Res<CrashLog>,
+    time: Res<Time>,
+    current_level: Option<Res<CurrentLevel>>,
+    mut destroyed: MessageReader<BrickDestroyed>,
+    mut lost: MessageReader<LifeLostEvent>,
+    mut collected: MessageReader<PowerupCollected>,
+    mut saved: MessageReader<BallSaved>,
+    mut milestones: MessageReader<MilestoneReached>,
+    mut switches: MessageReader<LevelSwitchRequested>,
+    mut restarts: MessageReader<RestartRequested>,
+) {
+    let mut events: Vec<String> = Vec::new();
+    if let Some(level) = current_level.filter(|level| level.is_changed()) {
+        events.push(format!("Level {} loaded", level.0.number));
+    }
+    events.extend(destroyed.read().map(|event| format!("{event:?}")));
+    events.extend(lost.read().map(|event| format!("{event:?}")));
+    events.extend(collected.read().map(|event| format!("{event:?}")));
+    events.extend(saved.read().map(|event| format!("{event:?}")));
+    events.extend(milestones.read().map(|event| format!("{event:?}")));
+    events.extend(switches.read().map(|event| format!("{event:?}")));
+    events.extend(restarts.read().map(|event| format!("{event:?}")));
+    if events.is_empty() {
+        return;
+    }
+    let secs = time.elapsed_secs();
+    log.update(|context| {
+        for text in events {
+            context.record(secs, text);
+        }
+    });
+}
+
+/// Point out the report of a crash in the previous session.
+pub fn detect_previous_crash(mut commands: Commands) {
+    if let Some(path) = take_previous_crash(Path::new(CRASH_REPORTS_DIR)) {
+        info!("The previous session crashed; see {}", path.display());
+        commands.insert_resource(PreviousCrash(path));
+    }
+}
+
+/// Plugin keeping the state crash reports are written from.
+pub struct CrashReportPlugin;
+
+impl Plugin for CrashReportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CrashLog>();
+        app.add_message::<BrickDestroyed>();
+        app.add_message::<LifeLostEvent>();
+        app.add_message::<PowerupCollected>();
+        app.add_message::<BallSaved>();
+        app.add_message::<MilestoneReached>();
+        app.add_message::<LevelSwitchRequested>();
+        app.add_message::<RestartRequested>();
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Startup, detect_previous_crash);
+        app.add_systems(Last, (update_crash_context, record_telemetry));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps_name_reports_in_utc() {
+        assert_eq!(crash_timestamp(0), "1970-01-01_00-00-00");
+        assert_eq!(crash_timestamp(1_792_245_839), "2026-10-17_14-03-59");
+    }
+
+    #[test]
+    fn only_the_latest_events_are_kept() {
+        let mut context = CrashContext::default();
+        for i in 0..TELEMETRY_CAPACITY + 5 {
+            context.record(i as f32, format!("event {i}"));
+        }
+        assert_eq!(context.events.len(), TELEMETRY_CAPACITY);
+        assert_eq!(context.events[0].text, "event 5");
+    }
+}
@@ -0,0 +1,404 @@
+//! Display settings: window mode, monitor, vsync and frame cap, switchable while playing.
+//!
+//! [`DisplaySettings`] picks windowed, borderless fullscreen or exclusive fullscreen, the
+//! monitor to use, whether to wait for vsync and an optional frame cap (e.g. to save battery on
+//! laptops). On native builds they are kept in `config/display.ron`, e.g.
+//!
+//! ```ron
+//! (mode: Exclusive, monitor: Some(1), vsync: false, frame_cap: Some(60))
+//! ```
+//!
+//! The file also sets the mode the window opens in ([`crate::BrkrsConfig`]); `--windowed` wins
+//! for that session. The settings belong to the machine rather than the player, so they are
+//! neither per profile nor synced.
+//!
+//! # Controls
+//! - `F11` toggles between windowed and fullscreen (borderless, or the exclusive mode it was in)
+//! - The pause menu cycles the mode, the monitor, vsync and the frame cap
+//!
+//! The game leaves fullscreen while paused (`crate::pause`), so a fullscreen mode chosen from the
+//! pause menu takes effect on resume. Browsers control fullscreen and frame pacing themselves: on
+//! wasm the settings stay at their defaults and nothing is switched.
+//!
+//! # Frame cap
+//! [`limit_frame_rate`] sleeps at the end of each frame until the frame took at least
+//! `1 / cap` seconds. Physics runs on Rapier's variable timestep, which drops time beyond its
+//! maximum step, so `systems::sim_speed` raises the maximum step (split into substeps) to cover a
+//! capped frame: the game keeps its speed at any cap.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy::window::{MonitorSelection, PresentMode, VideoModeSelection, WindowMode};
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::window::{PrimaryWindow, WindowPosition};
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::launch::LaunchOptions;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::pause::PauseState;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::systems::autosave::{read_recovering, write_atomic};
+
+/// Native path of the display settings file.
+pub const DISPLAY_CONFIG_PATH: &str = "config/display.ron";
+/// Frame caps offered by the pause menu, in frames per second; `None` is uncapped.
+pub const FRAME_CAPS: [Option<u32>; 5] = [None, Some(30), Some(60), Some(120), Some(144)];
+
+/// How the window fills the screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisplayMode {
+    Windowed,
+    /// A borderless window covering the monitor
+    #[default]
+    Borderless,
+    /// Exclusive fullscreen at the monitor's current video mode
+    Exclusive,
+}
+
+impl DisplayMode {
+    /// The mode after this one in the pause menu.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Windowed => Self::Borderless,
+            Self::Borderless => Self::Exclusive,
+            Self::Exclusive => Self::Windowed,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Windowed => "Windowed",
+            Self::Borderless => "Borderless",
+            Self::Exclusive => "Exclusive",
+        }
+    }
+}
+
+/// Display options chosen by the player, persisted across sessions.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplaySettings {
+    #[serde(default)]
+    pub mode: DisplayMode,
+    /// Index of the monitor in the order the platform lists them; `None` stays on the current one
+    #[serde(default)]
+    pub monitor: Option<usize>,
+    /// Fullscreen mode `F11` returns to from windowed
+    #[serde(default)]
+    pub fullscreen: DisplayMode,
+    /// Wait for the display's refresh before presenting a frame
+    #[serde(default = "default_vsync")]
+    pub vsync: bool,
+    /// Maximum frames per second; `None` leaves the frame rate to vsync or the hardware
+    #[serde(default)]
+    pub frame_cap: Option<u32>,
+}
+
+fn default_vsync() -> bool {
+    true
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            mode: DisplayMode::default(),
+            monitor: None,
+            fullscreen: DisplayMode::default(),
+            vsync: true,
+            frame_cap: None,
+        }
+    }
+}
+
+impl DisplaySettings {
+    pub fn monitor_selection(&self) -> MonitorSelection {
+        self.monitor
+            .map_or(MonitorSelection::Current, MonitorSelection::Index)
+    }
+
+    /// The window mode these settings ask for.
+    pub fn window_mode(&self) -> WindowMode {
+        match self.mode {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::Borderless => WindowMode::BorderlessFullscreen(self.monitor_selection()),
+            DisplayMode::Exclusive => {
+                WindowMode::Fullscreen(self.monitor_selection(), VideoModeSelection::Current)
+            }
+        }
+    }
+
+    /// Switch to `mode`, remembering the last fullscreen mode for [`Self::toggle_fullscreen`].
+    pub fn set_mode(&mut self, mode: DisplayMode) {
+        if mode != DisplayMode::Windowed {
+            self.fullscreen = mode;
+        }
+        self.mode = mode;
+    }
+
+    /// Toggle between windowed and the last fullscreen mode (`F11`).
+    pub fn toggle_fullscreen(&mut self) {
+        if self.mode == DisplayMode::Windowed {
+            self.mode = match self.fullscreen {
+                DisplayMode::Windowed => DisplayMode::Borderless,
+                fullscreen => fullscreen,
+            };
+        } else {
+            self.set_mode(DisplayMode::Windowed);
+        }
+    }
+
+    /// Move to the next of `monitor_count` monitors, then back to the current one.
+    pub fn next_monitor(&mut self, monitor_count: usize) {
+        self.monitor = match self.monitor {
+            None if monitor_count > 0 => Some(0),
+            Some(index) if index + 1 < monitor_count => Some(index + 1),
+            _ => None,
+        };
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        if self.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        }
+    }
+
+    /// Move to the next of [`FRAME_CAPS`], e.g. from a cap edited into the file back to the first.
+    pub fn next_frame_cap(&mut self) {
+        let index = FRAME_CAPS.iter().position(|cap| *cap == self.frame_cap);
+        self.frame_cap = FRAME_CAPS[index.map_or(0, |index| (index + 1) % FRAME_CAPS.len())];
+    }
+
+    /// Shortest frame the cap allows; a cap of 0 counts as uncapped.
+    pub fn min_frame_time(&self) -> Option<f32> {
+        self.frame_cap
+            .filter(|cap| *cap > 0)
+            .map(|cap| 1.0 / cap as f32)
+    }
+
+    pub fn frame_cap_label(&self) -> String {
+        match self.frame_cap {
+            Some(cap) => format!("{cap} fps"),
+            None => "Off".to_string(),
+        }
+    }
+
+    pub fn monitor_label(&self) -> String {
+        match self.monitor {
+            Some(index) => format!("{}", index + 1),
+            None => "Current".to_string(),
+        }
+    }
+
+    /// Read the settings from `path`, or the defaults when it is missing or invalid.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &Path) -> Self {
+        read_recovering(path, |content| match ron::de::from_str(content) {
+            Ok(settings) => Some(settings),
+            Err(e) => {
+                warn!("Failed to parse display settings: {}; using defaults", e);
+                None
+            }
+        })
+        .unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("failed to serialize display settings: {e}"))?;
+        write_atomic(path, &content)
+    }
+}
+
+/// Load `config/display.ron` into the [`DisplaySettings`] resource (native only).
+///
+/// A `--windowed` launch starts windowed without changing the saved mode.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_display_settings(mut commands: Commands, launch: Option<Res<LaunchOptions>>) {
+    let mut settings = DisplaySettings::load(Path::new(DISPLAY_CONFIG_PATH));
+    if launch.is_some_and(|launch| launch.windowed) {
+        settings.mode = DisplayMode::Windowed;
+    }
+    commands.insert_resource(settings);
+}
+
+/// `F11` toggles fullscreen.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn toggle_fullscreen(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<DisplaySettings>,
+) {
+    if keyboard.just_pressed(KeyCode::F11) {
+        settings.toggle_fullscreen();
+    }
+}
+
+/// Apply changed settings to the primary window.
+///
+/// While paused the window stays windowed and the mode is restored on resume instead. The window
+/// already opened in the loaded mode, so the initial insert only sets the present mode.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn apply_display_settings(
+    settings: Res<DisplaySettings>,
+    mut pause_state: ResMut<PauseState>,
+    window: Option<Single<&mut Window, With<PrimaryWindow>>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let Some(mut window) = window else {
+        return;
+    };
+    let present_mode = settings.present_mode();
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+    if settings.is_added() {
+        return;
+    }
+    let mode = settings.window_mode();
+    if settings.mode == DisplayMode::Windowed {
+        window.position = WindowPosition::Centered(settings.monitor_selection());
+    }
+    match *pause_state {
+        PauseState::Paused { .. } => {
+            *pause_state = PauseState::Paused {
+                window_mode_before_pause: mode,
+            };
+        }
+        PauseState::Active => window.mode = mode,
+    }
+}
+
+/// Sleep until the frame took at least as long as the frame cap allows.
+///
+/// Runs last, so the sleep ends right before the next frame starts.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn limit_frame_rate(settings: Res<DisplaySettings>, mut frame_start: Local<Option<Instant>>) {
+    if let (Some(min_frame_time), Some(start)) = (settings.min_frame_time(), *frame_start) {
+        let remaining = Duration::from_secs_f32(min_frame_time).saturating_sub(start.elapsed());
+        if !remaining.is_zero() {
+            std::thread::sleep(remaining);
+        }
+    }
+    *frame_start = Some(Instant::now());
+}
+
+/// Save the settings whenever the player changes them.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_display_settings_on_change(settings: Res<DisplaySettings>) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+    if let Err(e) = settings.save(Path::new(DISPLAY_CONFIG_PATH)) {
+        warn!("Failed to save display settings: {}", e);
+    }
+}
+
+/// Plugin registering the display settings, `F11`, the frame cap and their persistence.
+pub struct DisplayPlugin;
+
+impl Plugin for DisplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DisplaySettings>();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            app.add_systems(Startup, load_display_settings);
+            app.add_systems(
+                Update,
+                (
+                    toggle_fullscreen,
+                    // Left alone when an embedding app owns the window
+                    apply_display_settings.run_if(crate::plugins::manages_window),
+                    save_display_settings_on_change,
+                )
+                    .chain(),
+            );
+            app.add_systems(Last, limit_frame_rate);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f11_returns_to_the_last_fullscreen_mode() {
+        let mut settings = DisplaySettings::default();
+        settings.set_mode(DisplayMode::Exclusive);
+        settings.toggle_fullscreen();
+        assert_eq!(settings.mode, DisplayMode::Windowed);
+        settings.toggle_fullscreen();
+        assert_eq!(settings.mode, DisplayMode::Exclusive);
+
+        let mut windowed = DisplaySettings {
+            mode: DisplayMode::Windowed,
+            fullscreen: DisplayMode::Windowed,
+            ..default()
+        };
+        windowed.toggle_fullscreen();
+        assert_eq!(windowed.mode, DisplayMode::Borderless);
+    }
+
+    #[test]
+    fn monitors_cycle_back_to_the_current_one() {
+        let mut settings = DisplaySettings::default();
+        settings.next_monitor(2);
+        assert_eq!(settings.monitor, Some(0));
+        assert_eq!(
+            settings.window_mode(),
+            WindowMode::BorderlessFullscreen(MonitorSelection::Index(0))
+        );
+        settings.next_monitor(2);
+        assert_eq!(settings.monitor_label(), "2");
+        settings.next_monitor(2);
+        assert_eq!(settings.monitor, None);
+        settings.next_monitor(0);
+        assert_eq!(settings.monitor, None);
+    }
+
+    #[test]
+    fn frame_caps_cycle_back_to_uncapped() {
+        let mut settings = DisplaySettings::default();
+        assert_eq!(settings.min_frame_time(), None);
+        settings.next_frame_cap();
+        assert_eq!(settings.frame_cap, Some(30));
+        assert_eq!(settings.min_frame_time(), Some(1.0 / 30.0));
+        for _ in 1..FRAME_CAPS.len() {
+            settings.next_frame_cap();
+        }
+        assert_eq!(settings.frame_cap_label(), "Off");
+
+        // Caps edited into the file start over
+        settings.frame_cap = Some(75);
+        settings.next_frame_cap();
+        assert_eq!(settings.frame_cap, None);
+        assert_eq!(
+            DisplaySettings {
+                frame_cap: Some(0),
+                ..default()
+            }
+            .min_frame_time(),
+            None
+        );
+    }
+
+    #[test]
+    fn settings_parse_with_defaults() {
+        let settings: DisplaySettings = ron::de::from_str("(mode: Exclusive)").unwrap();
+        assert_eq!(settings.monitor, None);
+        assert!(settings.vsync);
+        assert_eq!(settings.present_mode(), PresentMode::AutoVsync);
+        assert_eq!(
+            settings.window_mode(),
+            WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+        );
+    }
+}
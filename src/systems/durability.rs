@@ -0,0 +1,345 @@
+//! Bricks with authored hit counts (durability).
+//!
+//! The multi-hit indices (10-13, `systems::multi_hit`) cover two to five hits. A level can give
+//! any destructible brick a hit count of its own:
+//!
+//! ```ron
+//! durability: [
+//!     (hits: 8, cells: [(2, 9), (2, 10)]),
+//!     (hits: 3, cells: [(4, 0)]),
+//! ],
+//! ```
+//!
+//! Bricks get a [`Durability`] component with their hits left when spawned. A ball hit on a brick
+//! with more than one hit left takes one off and triggers [`DurableBrickHit`] instead of
+//! destroying it; the last hit destroys it like any brick of its type. Only ball hits wear bricks
+//! down: explosions and the wrecking ball destroy them outright.
+//!
+//! The hits left are drawn as health pips on top of the brick: rows of up to [`PIPS_PER_ROW`],
+//! sized to fit the brick for the authored count, so they get smaller the more hits a brick
+//! takes. A pip disappears with every hit.
+//!
+//! Entries whose hit count is 0 or whose cells do not hold a brick the ball can destroy (including
+//! the multi-hit indices, which count hits with their index instead) are skipped with a warning.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::{
+    is_multi_hit_brick, matrix_resolution, validate_cell_range_in, BUMPER, HAZARD_BRICK_91,
+    INDESTRUCTIBLE_BRICK, PADDLE_DESTROYABLE_BRICK, TIMED_BOMB_BRICK,
+};
+use crate::level_loader::{CurrentLevel, LevelDefinition};
+use crate::{Brick, BrickGridPos, GridResolution};
+
+/// Most health pips in a row on top of a brick.
+pub const PIPS_PER_ROW: u8 = 10;
+/// Colour of the health pips.
+pub const PIP_COLOR: Color = Color::srgb(0.95, 0.85, 0.3);
+
+/// Hit count of some bricks as authored in a level file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurabilityDef {
+    /// Hits the bricks take before they are destroyed
+    pub hits: u8,
+    /// Grid cells `(row, col)` of the bricks
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// Hits a brick takes before it is destroyed (1: the next hit destroys it).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Durability(pub u8);
+
+/// Event triggered when a ball hit wears a durable brick down without destroying it.
+#[derive(Event, Debug, Clone)]
+pub struct DurableBrickHit {
+    /// The brick entity that was hit.
+    pub entity: Entity,
+    /// Hits the brick takes after this one before it is destroyed (at least 1).
+    pub hits_left: u8,
+}
+
+/// A health pip drawn on a durable brick; `0` is the pip of the last hit.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthPip(pub u8);
+
+/// Check a durability entry against the level `matrix`.
+pub fn validate_durability(def: &DurabilityDef, matrix: &[Vec<u8>]) -> Result<(), String> {
+    if def.hits == 0 {
+        return Err("hit count must be at least 1".to_string());
+    }
+    if def.cells.is_empty() {
+        return Err("no cells given".to_string());
+    }
+    def.cells.iter().try_for_each(|&(row, col)| {
+        validate_cell_range_in((row, row), (col, col), matrix_resolution(matrix))?;
+        match matrix.get(row).and_then(|r| r.get(col)) {
+            Some(&(INDESTRUCTIBLE_BRICK | HAZARD_BRICK_91 | BUMPER)) => Err(format!(
+                "cell ({}, {}) holds an indestructible brick",
+                row, col
+            )),
+            Some(&(PADDLE_DESTROYABLE_BRICK | TIMED_BOMB_BRICK)) => Err(format!(
+                "cell ({}, {}) holds a brick balls do not destroy",
+                row, col
+            )),
+            Some(&index) if is_multi_hit_brick(index) => Err(format!(
+                "cell ({}, {}) holds a multi-hit brick, whose index sets its hits",
+                row, col
+            )),
+            Some(3..=255) => Ok(()),
+            _ => Err(format!("cell ({}, {}) holds no brick", row, col)),
+        }
+    })
+}
+
+/// Hit counts of the current level's cells.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LevelDurability {
+    pub cells: HashMap<(usize, usize), u8>,
+}
+
+impl LevelDurability {
+    /// Load the hit counts of a level, skipping (and logging) invalid entries. A later entry
+    /// overrides an earlier one for the same cell.
+    pub fn for_level(def: &LevelDefinition) -> Self {
+        let mut cells = HashMap::new();
+        for entry in &def.durability {
+            match validate_durability(entry, &def.matrix) {
+                Ok(()) => cells.extend(entry.cells.iter().map(|&cell| (cell, entry.hits))),
+                Err(e) => warn!(
+                    "Ignoring durability {} of level {}: {}",
+                    entry.hits, def.number, e
+                ),
+            }
+        }
+        Self { cells }
+    }
+}
+
+/// Shared mesh and material of the health pips.
+#[derive(Resource, Debug, Clone)]
+pub struct HealthPipAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Reload the hit counts when a level is loaded.
+pub fn load_level_durability(
+    current_level: Res<CurrentLevel>,
+    mut durability: ResMut<LevelDurability>,
+) {
+    *durability = LevelDurability::for_level(&current_level.0);
+}
+
+/// Give newly spawned bricks their hit count.
+pub fn assign_brick_durability(
+    mut commands: Commands,
+    durability: Res<LevelDurability>,
+    bricks: Query<(Entity, &BrickGridPos), (With<Brick>, Added<BrickGridPos>)>,
+) {
+    for (entity, pos) in bricks.iter() {
+        if let Some(&hits) = durability.cells.get(&(pos.row, pos.col)) {
+            commands.entity(entity).insert(Durability(hits));
+        }
+    }
+}
+
+fn setup_health_pip_assets(
+    mut commands: Commands,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    commands.insert_resource(HealthPipAssets {
+        mesh: meshes.add(Cuboid::new(1.0, 1.0, 1.0)),
+        material: materials.add(StandardMaterial {
+            base_color: PIP_COLOR,
+            emissive: LinearRgba::rgb(0.3, 0.25, 0.05),
+            ..default()
+        }),
+    });
+}
+
+/// Side length and local positions of the pips for a brick taking `hits` hits on a level laid
+/// out on `grid`.
+///
+/// Pips sit on the brick's top face in rows of up to [`PIPS_PER_ROW`] along the brick's width,
+/// sized so all rows fit on the face.
+pub fn health_pip_layout(hits: u8, grid: &GridResolution) -> (f32, Vec<Vec3>) {
+    let count = usize::from(hits);
+    let per_row = count.min(usize::from(PIPS_PER_ROW)).max(1);
+    let rows = count.div_ceil(per_row).max(1);
+    // Brick faces are 90% of the cell (see `level_loader::spawn_brick`); keep a margin
+    let slot = (grid.cell_width() * 0.8 / per_row as f32)
+        .min(grid.cell_height() * 0.8 / rows as f32)
+        .min(0.3);
+    let size = slot * 0.7;
+    let positions = (0..count)
+        .map(|index| {
+            let (row, col) = (index / per_row, index % per_row);
+            Vec3::new(
+                (row as f32 - (rows - 1) as f32 / 2.0) * slot,
+                0.25 + size / 2.0,
+                (col as f32 - (per_row - 1) as f32 / 2.0) * slot,
+            )
+        })
+        .collect();
+    (size, positions)
+}
+
+/// Draw the pips of newly durable bricks.
+pub fn spawn_health_pips(
+    mut commands: Commands,
+    assets: Option<Res<HealthPipAssets>>,
+    grid: Option<Res<GridResolution>>,
+    bricks: Query<(Entity, &Durability), Added<Durability>>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    for (entity, durability) in bricks.iter() {
+        let (size, positions) = health_pip_layout(durability.0, &grid);
+        commands.entity(entity).with_children(|brick| {
+            for (index, position) in positions.into_iter().enumerate() {
+                brick.spawn((
+                    Mesh3d(assets.mesh.clone()),
+                    MeshMaterial3d(assets.material.clone()),
+                    Transform::from_translation(position).with_scale(Vec3::splat(size)),
+                    HealthPip(index as u8),
+                ));
+            }
+        });
+    }
+}
+
+/// Remove the pips of the hits a brick has taken.
+pub fn update_health_pips(
+    mut commands: Commands,
+    bricks: Query<(&Durability, &Children), Changed<Durability>>,
+    pips: Query<&HealthPip>,
+) {
+    for (durability, children) in bricks.iter() {
+        for child in children.iter() {
+            if pips.get(child).is_ok_and(|pip| pip.0 >= durability.0) {
+                commands.entity(child).despawn();
+            }
+        }
+    }
+}
+
+/// Plugin registering authored brick durability and its health pips.
+pub struct DurabilityPlugin;
+
+impl Plugin for DurabilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelDurability>();
+        app.add_systems(Startup, setup_health_pip_assets);
+        app.add_systems(
+            Update,
+            (
+                load_level_durability.run_if(resource_exists_and_changed::<CurrentLevel>),
+                assign_brick_durability,
+                spawn_health_pips,
+                update_health_pips,
+            )
+                .chain()
+                // Count hits in the frame they happen
+                .after(crate::mark_brick_on_ball_collision),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(matrix: Vec<Vec<u8>>, durability: Vec<DurabilityDef>) -> LevelDefinition {
+        let mut def: LevelDefinition =
+            ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+        def.matrix = matrix;
+        def.durability = durability;
+        def
+    }
+
+    #[test]
+    fn invalid_entries_are_skipped() {
+        let def = level(
+            vec![vec![20, 90, 12, 57], vec![0, 20, 20, 20]],
+            vec![
+                DurabilityDef {
+                    hits: 8,
+                    cells: vec![(0, 0), (1, 1)],
+                },
+                DurabilityDef {
+                    hits: 0,
+                    cells: vec![(1, 2)],
+                },
+                DurabilityDef {
+                    hits: 6,
+                    cells: vec![(0, 1)],
+                },
+                DurabilityDef {
+                    hits: 6,
+                    cells: vec![(0, 2)],
+                },
+                DurabilityDef {
+                    hits: 6,
+                    cells: vec![(0, 3)],
+                },
+                DurabilityDef {
+                    hits: 6,
+                    cells: vec![(1, 0)],
+                },
+                // Overrides the first entry's hits for (1, 1)
+                DurabilityDef {
+                    hits: 3,
+                    cells: vec![(1, 1), (1, 3)],
+                },
+            ],
+        );
+        let durability = LevelDurability::for_level(&def);
+        assert_eq!(
+            durability.cells,
+            HashMap::from([((0, 0), 8), ((1, 1), 3), ((1, 3), 3)])
+        );
+    }
+
+    #[test]
+    fn durability_parses_from_ron() {
+        let def: LevelDefinition = ron::de::from_str(
+            "LevelDefinition(number: 1, matrix: [], durability: [(hits: 12, cells: [(3, 4)])])",
+        )
+        .unwrap();
+        assert_eq!(
+            def.durability,
+            vec![DurabilityDef {
+                hits: 12,
+                cells: vec![(3, 4)]
+            }]
+        );
+    }
+
+    #[test]
+    fn pips_shrink_to_fit_high_hit_counts() {
+        let grid = GridResolution::default();
+        let (few, positions) = health_pip_layout(3, &grid);
+        assert_eq!(positions.len(), 3);
+        let (many, positions) = health_pip_layout(40, &grid);
+        assert_eq!(positions.len(), 40);
+        assert!(many < few);
+        // Every pip stays on the brick's top face, also on the smaller cells of a 40x40 level
+        let fine = GridResolution::new(40, 40);
+        let (tiny, fine_positions) = health_pip_layout(40, &fine);
+        for (grid, size, positions) in [(grid, many, positions), (fine, tiny, fine_positions)] {
+            for position in positions {
+                assert!(position.x.abs() + size / 2.0 <= grid.cell_height() * 0.45);
+                assert!(position.z.abs() + size / 2.0 <= grid.cell_width() * 0.45);
+            }
+        }
+    }
+}
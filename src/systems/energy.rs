@@ -0,0 +1,227 @@
+//! Paddle energy meter.
+//!
+//! Every brick hit charges the meter: destroying a brick, knocking a multi-hit brick down a stage
+//! or wearing a durable brick down. The charge is spent with a key press on one of:
+//! - **S** — a ball-save shield across the lower goal (`systems::ball_save`), which sends the next
+//!   lost ball back into play
+//! - **D** — a paddle dash, a short burst of speed in the direction the paddle was last moved
+//!
+//! Presses that cannot be paid for do nothing, and so does a second shield while one is up. The
+//! meter is emptied when the game is over.
+//!
+//! Charge and costs are tuned in `config/gameplay.ron` ([`EnergyConfig`]); the meter is drawn by
+//! `ui::energy_meter`.
+
+use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::prelude::*;
+
+use crate::gameplay_config::{EnergyConfig, GameplayConfig};
+use crate::signals::BrickDestroyed;
+use crate::systems::ball_save::{spawn_ball_save_shield, BallSaveShield};
+use crate::systems::durability::DurableBrickHit;
+use crate::systems::multi_hit::MultiHitBrickHit;
+use crate::systems::powerups::ReversedControls;
+use crate::systems::respawn::GameOverRequested;
+use crate::Paddle;
+
+/// Key that buys a ball-save shield.
+pub const SHIELD_KEY: KeyCode = KeyCode::KeyS;
+/// Key that makes the paddle dash.
+pub const DASH_KEY: KeyCode = KeyCode::KeyD;
+
+/// Current paddle energy.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct EnergyMeter {
+    pub energy: f32,
+}
+
+impl EnergyMeter {
+    /// Add `amount`, up to `max`.
+    pub fn charge(&mut self, amount: f32, max: f32) {
+        self.energy = (self.energy + amount).min(max);
+    }
+
+    /// Take `cost` off the meter if it holds that much.
+    pub fn try_spend(&mut self, cost: f32) -> bool {
+        if self.energy < cost {
+            return false;
+        }
+        self.energy -= cost;
+        true
+    }
+
+    /// How full the meter is, from 0 to 1.
+    pub fn fraction(&self, max: f32) -> f32 {
+        (self.energy / max).clamp(0.0, 1.0)
+    }
+}
+
+/// A paddle dash in progress; `move_paddle` adds the velocity to the mouse movement.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PaddleDash {
+    /// Paddle velocity added by the dash, in units per second.
+    pub velocity: Vec3,
+    /// Seconds left.
+    pub remaining: f32,
+}
+
+/// Direction the paddle was last moved in, on the playfield plane.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct DashDirection(pub Vec3);
+
+impl Default for DashDirection {
+    fn default() -> Self {
+        Self(Vec3::NEG_Z)
+    }
+}
+
+pub(crate) fn energy_config(gameplay: Option<&GameplayConfig>) -> EnergyConfig {
+    gameplay.map_or_else(EnergyConfig::default, |config| config.energy)
+}
+
+/// Charge the meter for destroyed bricks.
+pub fn charge_on_brick_destroyed(
+    mut destroyed: MessageReader<BrickDestroyed>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut meter: ResMut<EnergyMeter>,
+) {
+    let hits = destroyed.read().count();
+    if hits == 0 {
+        return;
+    }
+    let config = energy_config(gameplay.as_deref());
+    meter.charge(config.per_hit * hits as f32, config.max);
+}
+
+/// Charge the meter when a multi-hit brick loses a stage.
+pub fn charge_on_multi_hit(
+    _trigger: On<MultiHitBrickHit>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut meter: ResMut<EnergyMeter>,
+) {
+    let config = energy_config(gameplay.as_deref());
+    meter.charge(config.per_hit, config.max);
+}
+
+/// Charge the meter when a durable brick is worn down.
+pub fn charge_on_durable_hit(
+    _trigger: On<DurableBrickHit>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut meter: ResMut<EnergyMeter>,
+) {
+    let config = energy_config(gameplay.as_deref());
+    meter.charge(config.per_hit, config.max);
+}
+
+/// Remember which way the player last moved the paddle, mirroring `move_paddle`'s mapping.
+pub fn track_dash_direction(
+    mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+    paddles: Query<Has<ReversedControls>, With<Paddle>>,
+    mut direction: ResMut<DashDirection>,
+) {
+    let Some(motion) = mouse_motion else {
+        return;
+    };
+    let moved = Vec3::new(motion.delta.y, 0.0, -motion.delta.x);
+    if moved.length_squared() < f32::EPSILON {
+        return;
+    }
+    let reversed = paddles.iter().any(|reversed| reversed);
+    direction.0 = moved.normalize() * if reversed { -1.0 } else { 1.0 };
+}
+
+/// Spend energy on a shield or a dash.
+pub fn spend_energy(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gameplay: Option<Res<GameplayConfig>>,
+    direction: Res<DashDirection>,
+    mut meter: ResMut<EnergyMeter>,
+    shields: Query<(), With<BallSaveShield>>,
+    paddles: Query<Entity, (With<Paddle>, Without<PaddleDash>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let config = energy_config(gameplay.as_deref());
+    if keyboard.just_pressed(SHIELD_KEY)
+        && shields.is_empty()
+        && meter.try_spend(config.shield_cost)
+    {
+        spawn_ball_save_shield(&mut commands, &mut meshes, &mut materials);
+        info!("Ball-save shield up");
+    }
+    if keyboard.just_pressed(DASH_KEY) && !paddles.is_empty() && meter.try_spend(config.dash_cost) {
+        for paddle in paddles.iter() {
+            commands.entity(paddle).insert(PaddleDash {
+                velocity: direction.0 * config.dash_speed,
+                remaining: config.dash_secs,
+            });
+        }
+    }
+}
+
+/// End dashes that have run their time.
+pub fn tick_paddle_dash(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut dashes: Query<(Entity, &mut PaddleDash)>,
+) {
+    for (entity, mut dash) in dashes.iter_mut() {
+        dash.remaining -= time.delta_secs();
+        if dash.remaining <= 0.0 {
+            commands.entity(entity).remove::<PaddleDash>();
+        }
+    }
+}
+
+/// Empty the meter when the game is over.
+pub fn reset_energy_on_game_over(
+    mut game_over: MessageReader<GameOverRequested>,
+    mut meter: ResMut<EnergyMeter>,
+) {
+    if game_over.read().count() > 0 {
+        *meter = EnergyMeter::default();
+    }
+}
+
+/// Plugin registering the paddle energy meter.
+pub struct EnergyPlugin;
+
+impl Plugin for EnergyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnergyMeter>();
+        app.init_resource::<DashDirection>();
+        app.add_message::<BrickDestroyed>();
+        app.add_message::<GameOverRequested>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_observer(charge_on_multi_hit);
+        app.add_observer(charge_on_durable_hit);
+        app.add_systems(
+            Update,
+            (
+                (charge_on_brick_destroyed, reset_energy_on_game_over).chain(),
+                (tick_paddle_dash, track_dash_direction, spend_energy)
+                    .chain()
+                    .run_if(crate::pause::not_paused),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meter_fills_up_to_max_and_pays_only_what_it_holds() {
+        let mut meter = EnergyMeter::default();
+        meter.charge(70.0, 100.0);
+        meter.charge(70.0, 100.0);
+        assert_eq!(meter.energy, 100.0);
+        assert!(meter.try_spend(60.0));
+        assert!(!meter.try_spend(60.0));
+        assert_eq!(meter.energy, 40.0);
+        assert_eq!(meter.fraction(100.0), 0.4);
+    }
+}
@@ -0,0 +1,566 @@
+//! Explosive bricks: the bomb (index 40), the timed bomb (index 19) and the blast brick (index 50).
+//!
+//! - A bomb explodes as soon as it is destroyed.
+//! - A timed bomb survives the ball hit. The first hit lights a [`FuseTimer`]; the brick blinks
+//!   faster and glows brighter while the fuse burns down, then explodes. Level designers can use
+//!   this for timing puzzles (clear a path before the blast, or use it to open one).
+//! - A blast brick explodes as soon as it is destroyed, like a bomb, but with a wider, round
+//!   blast.
+//!
+//! Bombs and timed bombs destroy every destructible brick within [`BLAST_RADIUS`] cells, found
+//! through the [`BrickGridPos`] each brick is spawned with. A blast brick destroys every
+//! destructible brick whose centre lies within `GameplayConfig::blast.radius` world units of its
+//! own, found through the brick transforms ([`BlastReach`]). Explosive bricks caught in a blast
+//! detonate in the same frame (lit fuses are cut short), so a whole chain, bombs and blast bricks
+//! mixed, resolves in one pass of [`resolve_blast`].
+//!
+//! The blast runs while the exploding brick is on its way out (marked, before
+//! `despawn_marked_entities` sends its `BrickDestroyed`). Destroyed bricks are only marked with
+//! `MarkedForDespawn`, so each sends its own `BrickDestroyed` and scoring, drops, and audio follow
+//! the normal despawn path.
+//!
+//! # Visuals
+//! Bricks share materials, so a lit brick gets its own copy of its material ([`FuseGlow`]) before
+//! the emissive channel is animated. The copy is dropped with the brick.
+//!
+//! Every explosion flashes ([`BlastFlash`]): a glowing sphere grows to the blast's reach while a
+//! point light fades out, both gone after [`FLASH_SECONDS`]. The flying pieces come from
+//! `systems::brick_debris`, as for any destroyed brick.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::CollisionEvent;
+
+use crate::gameplay_config::GameplayConfig;
+use crate::level_format::{is_explosive_brick, BLAST_BRICK, TIMED_BOMB_BRICK};
+use crate::{Ball, Brick, BrickGridPos, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn};
+
+/// Seconds between lighting a timed bomb and its explosion.
+pub const FUSE_SECONDS: f32 = 3.0;
+/// Blast reach of bombs and timed bombs in grid cells (1 = the eight surrounding cells).
+pub const BLAST_RADIUS: usize = 1;
+/// How long an explosion flash lasts, in seconds.
+pub const FLASH_SECONDS: f32 = 0.35;
+/// Peak intensity of the flash's point light, in lumens.
+const FLASH_INTENSITY: f32 = 2_000_000.0;
+/// Larger side of a grid cell in world units, to size the flash of a grid blast.
+const CELL_SIZE: f32 = 2.0;
+
+/// Blink frequency (Hz) right after the fuse is lit and just before it runs out.
+const BLINK_HZ_START: f32 = 2.0;
+const BLINK_HZ_END: f32 = 10.0;
+
+/// Burning fuse on a timed bomb brick.
+#[derive(Component, Debug, Clone)]
+pub struct FuseTimer {
+    pub timer: Timer,
+}
+
+impl FuseTimer {
+    pub fn new(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
+impl Default for FuseTimer {
+    fn default() -> Self {
+        Self::new(FUSE_SECONDS)
+    }
+}
+
+/// Per-brick material copy animated while the fuse burns.
+#[derive(Component, Debug, Clone)]
+pub struct FuseGlow(pub Handle<StandardMaterial>);
+
+/// Emitted once for every explosive brick that goes off (including chained ones).
+#[derive(Message, Debug, Clone, Copy)]
+pub struct BrickExploded {
+    pub brick: Entity,
+    pub position: Vec3,
+    pub reach: BlastReach,
+}
+
+/// How far the blast of an explosive brick reaches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlastReach {
+    /// Grid cells around the brick (1 = the eight surrounding cells)
+    Cells(usize),
+    /// Distance from the brick's centre, in world units
+    Radius(f32),
+}
+
+impl BlastReach {
+    /// Reach of the explosive brick `type_id`, with blast bricks reaching `radius`.
+    pub fn of(type_id: u8, radius: f32) -> Self {
+        if type_id == BLAST_BRICK {
+            Self::Radius(radius)
+        } else {
+            Self::Cells(BLAST_RADIUS)
+        }
+    }
+
+    /// Whether a blast from `origin` reaches `target`.
+    pub fn covers(self, origin: &BlastTarget, target: &BlastTarget) -> bool {
+        match self {
+            Self::Cells(cells) => {
+                origin.cell.row.abs_diff(target.cell.row) <= cells
+                    && origin.cell.col.abs_diff(target.cell.col) <= cells
+            }
+            Self::Radius(radius) => origin.position.distance(target.position) <= radius,
+        }
+    }
+
+    /// Approximate reach in world units.
+    pub fn world_radius(self) -> f32 {
+        match self {
+            Self::Cells(cells) => (cells as f32 + 0.5) * CELL_SIZE,
+            Self::Radius(radius) => radius,
+        }
+    }
+}
+
+/// A destructible brick as seen by [`resolve_blast`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlastTarget {
+    pub entity: Entity,
+    pub cell: BrickGridPos,
+    pub position: Vec3,
+    /// Reach of the brick's own blast, for explosive bricks
+    pub reach: Option<BlastReach>,
+}
+
+/// Expanding, fading light and glow of an explosion.
+#[derive(Component, Debug, Clone)]
+pub struct BlastFlash {
+    pub timer: Timer,
+    /// World radius the glow grows to
+    pub radius: f32,
+}
+
+/// Outcome of [`resolve_blast`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Blast {
+    /// Every explosive brick that went off, origins first.
+    pub detonated: Vec<Entity>,
+    /// Bricks destroyed by the blast (chained explosives included, origins excluded).
+    pub destroyed: Vec<Entity>,
+}
+
+/// Grid cells within `radius` of `center`, `center` included: bricks spawned by waves or the cheat
+/// console can share a cell with the exploding brick.
+fn cells_within(center: BrickGridPos, radius: usize) -> impl Iterator<Item = BrickGridPos> {
+    let rows = center.row.saturating_sub(radius)..=center.row + radius;
+    rows.flat_map(move |row| {
+        let cols = center.col.saturating_sub(radius)..=center.col + radius;
+        cols.map(move |col| BrickGridPos { row, col })
+    })
+}
+
+/// Resolve a (possibly chained) explosion.
+///
+/// `origins` are the bricks exploding this frame; `intact` maps each cell to the remaining
+/// destructible bricks in it (usually one, but spawned bricks may share a cell).
+pub fn resolve_blast(
+    origins: impl IntoIterator<Item = BlastTarget>,
+    intact: &HashMap<BrickGridPos, Vec<BlastTarget>>,
+) -> Blast {
+    let mut queue: VecDeque<BlastTarget> = origins.into_iter().collect();
+    let mut seen: HashSet<Entity> = queue.iter().map(|origin| origin.entity).collect();
+    let mut blast = Blast::default();
+    while let Some(origin) = queue.pop_front() {
+        blast.detonated.push(origin.entity);
+        let reach = origin.reach.unwrap_or(BlastReach::Cells(BLAST_RADIUS));
+        let reached: Vec<BlastTarget> = match reach {
+            BlastReach::Cells(cells) => cells_within(origin.cell, cells)
+                .filter_map(|pos| intact.get(&pos))
+                .flatten()
+                .copied()
+                .collect(),
+            BlastReach::Radius(_) => intact
+                .values()
+                .flatten()
+                .filter(|target| reach.covers(&origin, target))
+                .copied()
+                .collect(),
+        };
+        for target in reached {
+            if !seen.insert(target.entity) {
+                continue;
+            }
+            blast.destroyed.push(target.entity);
+            if target.reach.is_some() {
+                queue.push_back(target);
+            }
+        }
+    }
+    blast
+}
+
+/// Light the fuse of timed bombs hit by a ball. Later hits on a lit bomb do nothing.
+pub fn light_fuses(
+    mut commands: Commands,
+    mut collisions: MessageReader<CollisionEvent>,
+    balls: Query<(), With<Ball>>,
+    bombs: Query<
+        (&BrickTypeId, Option<&MeshMaterial3d<StandardMaterial>>),
+        (With<Brick>, Without<FuseTimer>, Without<MarkedForDespawn>),
+    >,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let mut lit = Vec::new();
+    for event in collisions.read() {
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        let brick = if balls.contains(*e1) {
+            *e2
+        } else if balls.contains(*e2) {
+            *e1
+        } else {
+            continue;
+        };
+        let Ok((type_id, material)) = bombs.get(brick) else {
+            continue;
+        };
+        if type_id.0 != TIMED_BOMB_BRICK || lit.contains(&brick) {
+            continue;
+        }
+        lit.push(brick);
+
+        let mut entity = commands.entity(brick);
+        entity.insert(FuseTimer::default());
+        let own_material = material
+            .zip(materials.as_deref_mut())
+            .and_then(|(m, assets)| {
+                let copy = assets.get(&m.0)?.clone();
+                Some(assets.add(copy))
+            });
+        if let Some(handle) = own_material {
+            entity.insert((MeshMaterial3d(handle.clone()), FuseGlow(handle)));
+        }
+        debug!("Timed bomb {:?} fuse lit", brick);
+    }
+}
+
+/// Burn down lit fuses.
+pub fn tick_fuses(mut fuses: Query<&mut FuseTimer>, time: Res<Time>) {
+    for mut fuse in fuses.iter_mut() {
+        fuse.timer.tick(time.delta());
+    }
+}
+
+/// Blink lit bombs, faster and brighter as the fuse runs out.
+pub fn blink_fuses(
+    fuses: Query<(&FuseTimer, &FuseGlow)>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let Some(mut materials) = materials else {
+        return;
+    };
+    for (fuse, glow) in fuses.iter() {
+        let Some(material) = materials.get_mut(&glow.0) else {
+            continue;
+        };
+        let progress = fuse.timer.fraction();
+        let hz = BLINK_HZ_START + (BLINK_HZ_END - BLINK_HZ_START) * progress;
+        let phase = fuse.timer.elapsed_secs() * hz * std::f32::consts::TAU;
+        material.emissive = if phase.sin() > 0.0 {
+            LinearRgba::rgb(1.0, 0.35, 0.05) * (1.0 + 6.0 * progress)
+        } else {
+            LinearRgba::BLACK
+        };
+    }
+}
+
+/// Explode burnt-out fuses and destroyed bombs, marking everything in the blast for despawn.
+///
+/// Runs after `mark_brick_on_ball_collision` (to see bombs destroyed this frame) and before
+/// `despawn_marked_entities`.
+#[allow(clippy::type_complexity)]
+pub fn detonate_explosives(
+    mut commands: Commands,
+    fuses: Query<
+        (Entity, &FuseTimer, &BrickTypeId, &BrickGridPos, &Transform),
+        Without<MarkedForDespawn>,
+    >,
+    destroyed_bombs: Query<
+        (Entity, &BrickTypeId, &BrickGridPos, &Transform),
+        Added<MarkedForDespawn>,
+    >,
+    intact: Query<
+        (Entity, &BrickTypeId, &BrickGridPos, &Transform),
+        (
+            With<Brick>,
+            With<CountsTowardsCompletion>,
+            Without<MarkedForDespawn>,
+        ),
+    >,
+    config: Option<Res<GameplayConfig>>,
+    mut exploded: MessageWriter<BrickExploded>,
+) {
+    let radius = config.map(|c| c.blast).unwrap_or_default().radius;
+    let target =
+        |entity: Entity, type_id: &BrickTypeId, cell: &BrickGridPos, t: &Transform| BlastTarget {
+            entity,
+            cell: *cell,
+            position: t.translation,
+            reach: is_explosive_brick(type_id.0).then(|| BlastReach::of(type_id.0, radius)),
+        };
+    let mut origins: Vec<BlastTarget> = fuses
+        .iter()
+        .filter(|(_, fuse, ..)| fuse.timer.is_finished())
+        .map(|(entity, _, type_id, cell, t)| target(entity, type_id, cell, t))
+        .collect();
+    origins.extend(
+        destroyed_bombs
+            .iter()
+            .filter(|(_, type_id, ..)| is_explosive_brick(type_id.0))
+            .map(|(entity, type_id, cell, t)| target(entity, type_id, cell, t)),
+    );
+    if origins.is_empty() {
+        return;
+    }
+
+    let mut grid: HashMap<BrickGridPos, Vec<BlastTarget>> = HashMap::new();
+    for (entity, type_id, cell, t) in intact.iter() {
+        if origins.iter().any(|origin| origin.entity == entity) {
+            continue;
+        }
+        grid.entry(*cell)
+            .or_default()
+            .push(target(entity, type_id, cell, t));
+    }
+    let explosives: HashMap<Entity, BlastTarget> = origins
+        .iter()
+        .chain(grid.values().flatten())
+        .filter(|target| target.reach.is_some())
+        .map(|target| (target.entity, *target))
+        .collect();
+    let blast = resolve_blast(origins, &grid);
+
+    for &brick in blast.detonated.iter().chain(&blast.destroyed) {
+        commands.entity(brick).try_insert(MarkedForDespawn);
+    }
+    for brick in &blast.detonated {
+        let Some(origin) = explosives.get(brick) else {
+            continue;
+        };
+        exploded.write(BrickExploded {
+            brick: *brick,
+            position: origin.position,
+            reach: origin.reach.unwrap_or(BlastReach::Cells(BLAST_RADIUS)),
+        });
+    }
+    debug!(
+        "Explosion: {} detonated, {} destroyed",
+        blast.detonated.len(),
+        blast.destroyed.len()
+    );
+}
+
+/// Flash every explosion.
+pub fn spawn_blast_flashes(
+    mut commands: Commands,
+    mut exploded: MessageReader<BrickExploded>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    if exploded.is_empty() {
+        return;
+    }
+    // One sphere and glow per frame, shared by the flashes of a chain
+    let glow = meshes.zip(materials).map(|(mut meshes, mut materials)| {
+        (
+            meshes.add(Sphere::new(1.0)),
+            materials.add(StandardMaterial {
+                base_color: Color::srgba(1.0, 0.6, 0.2, 0.6),
+                emissive: LinearRgba::rgb(8.0, 3.0, 0.6),
+                alpha_mode: AlphaMode::Add,
+                unlit: true,
+                ..default()
+            }),
+        )
+    });
+    for event in exploded.read() {
+        let radius = event.reach.world_radius();
+        let mut flash = commands.spawn((
+            BlastFlash {
+                timer: Timer::from_seconds(FLASH_SECONDS, TimerMode::Once),
+                radius,
+            },
+            PointLight {
+                color: Color::srgb(1.0, 0.7, 0.3),
+                intensity: FLASH_INTENSITY,
+                range: radius * 3.0,
+                ..default()
+            },
+            Transform::from_translation(event.position).with_scale(Vec3::splat(0.01)),
+        ));
+        if let Some((mesh, material)) = glow.as_ref() {
+            flash.insert((Mesh3d(mesh.clone()), MeshMaterial3d(material.clone())));
+        }
+    }
+}
+
+/// Grow and fade explosion flashes, despawning finished ones.
+pub fn update_blast_flashes(
+    mut commands: Commands,
+    mut flashes: Query<(Entity, &mut BlastFlash, &mut Transform, &mut PointLight)>,
+    time: Res<Time>,
+) {
+    for (entity, mut flash, mut transform, mut light) in flashes.iter_mut() {
+        flash.timer.tick(time.delta());
+        if flash.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let progress = flash.timer.fraction();
+        // Quick swell to full reach, light fading as it goes
+        transform.scale = Vec3::splat(flash.radius * progress.sqrt().max(0.01));
+        light.intensity = FLASH_INTENSITY * (1.0 - progress).powi(2);
+    }
+}
+
+/// Plugin registering explosive and timed bomb bricks.
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_message::<BrickExploded>();
+        app.add_message::<CollisionEvent>();
+        app.add_systems(
+            Update,
+            (
+                (tick_fuses, blink_fuses)
+                    .chain()
+                    .run_if(crate::pause::not_paused),
+                light_fuses,
+            )
+                .before(detonate_explosives),
+        );
+        app.add_systems(
+            Update,
+            detonate_explosives
+                .after(crate::mark_brick_on_ball_collision)
+                .before(crate::systems::powerups::PowerupSystems::Drop)
+                .before(crate::despawn_marked_entities),
+        );
+        app.add_systems(
+            Update,
+            (
+                spawn_blast_flashes.after(detonate_explosives),
+                update_blast_flashes.run_if(crate::pause::not_paused),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pos(row: usize, col: usize) -> BrickGridPos {
+        BrickGridPos { row, col }
+    }
+
+    #[test]
+    fn blast_cells_clip_at_grid_edge() {
+        assert_eq!(cells_within(pos(0, 0), 1).count(), 4);
+        assert_eq!(cells_within(pos(5, 5), 1).count(), 9);
+    }
+
+    /// A brick at `cell`, placed on a grid of 1.5 x 2.0 cells.
+    fn brick(world: &mut World, row: usize, col: usize, reach: Option<BlastReach>) -> BlastTarget {
+        BlastTarget {
+            entity: world.spawn_empty().id(),
+            cell: pos(row, col),
+            position: Vec3::new(row as f32 * 1.5, 2.0, col as f32 * 2.0),
+            reach,
+        }
+    }
+
+    fn grid(bricks: &[BlastTarget]) -> HashMap<BrickGridPos, Vec<BlastTarget>> {
+        let mut grid: HashMap<BrickGridPos, Vec<BlastTarget>> = HashMap::new();
+        for brick in bricks {
+            grid.entry(brick.cell).or_default().push(*brick);
+        }
+        grid
+    }
+
+    #[test]
+    fn blast_chains_through_explosive_neighbours() {
+        let mut world = World::new();
+        let bomb_reach = Some(BlastReach::Cells(BLAST_RADIUS));
+        let origin = brick(&mut world, 3, 3, bomb_reach);
+        let plain = brick(&mut world, 2, 3, None);
+        let bomb = brick(&mut world, 3, 4, bomb_reach);
+        let far = brick(&mut world, 3, 5, None);
+        let out_of_reach = brick(&mut world, 3, 7, None);
+
+        let grid = grid(&[plain, bomb, far, out_of_reach]);
+        let blast = resolve_blast([origin], &grid);
+        let (origin, bomb, far, out_of_reach) =
+            (origin.entity, bomb.entity, far.entity, out_of_reach.entity);
+
+        assert_eq!(blast.detonated, vec![origin, bomb]);
+        assert_eq!(blast.destroyed.len(), 3);
+        assert!(blast.destroyed.contains(&far));
+        assert!(!blast.destroyed.contains(&out_of_reach));
+    }
+
+    #[test]
+    fn blast_brick_reaches_a_world_radius_and_chains() {
+        let mut world = World::new();
+        let reach = Some(BlastReach::Radius(4.0));
+        let origin = brick(&mut world, 5, 5, reach);
+        // 3.0 away along X (two rows), 4.0 along Z (two columns), 5.0 diagonally
+        let two_rows = brick(&mut world, 7, 5, None);
+        let two_cols = brick(&mut world, 5, 7, None);
+        let diagonal = brick(&mut world, 7, 7, None);
+        // A bomb within reach chains into its own eight neighbours
+        let bomb = brick(&mut world, 5, 3, Some(BlastReach::Cells(BLAST_RADIUS)));
+        let behind_bomb = brick(&mut world, 5, 2, None);
+
+        let grid = grid(&[two_rows, two_cols, diagonal, bomb, behind_bomb]);
+        let blast = resolve_blast([origin], &grid);
+
+        assert_eq!(blast.detonated, vec![origin.entity, bomb.entity]);
+        assert!(blast.destroyed.contains(&two_rows.entity));
+        assert!(blast.destroyed.contains(&two_cols.entity));
+        assert!(!blast.destroyed.contains(&diagonal.entity));
+        assert!(blast.destroyed.contains(&behind_bomb.entity));
+        assert_eq!(BlastReach::of(BLAST_BRICK, 4.0), BlastReach::Radius(4.0));
+        assert_eq!(
+            BlastReach::of(crate::level_format::BOMB_BRICK, 4.0),
+            BlastReach::Cells(BLAST_RADIUS)
+        );
+    }
+
+    #[test]
+    fn bricks_sharing_a_cell_are_all_caught() {
+        let mut world = World::new();
+        let bomb_reach = Some(BlastReach::Cells(BLAST_RADIUS));
+        let origin = brick(&mut world, 3, 3, bomb_reach);
+        // A spawned brick on the bomb's own cell, and a brick and a bomb stacked next to it
+        let same_cell = brick(&mut world, 3, 3, None);
+        let lower = brick(&mut world, 3, 4, None);
+        let stacked_bomb = brick(&mut world, 3, 4, bomb_reach);
+        let chained = brick(&mut world, 3, 5, None);
+        let chained_twin = brick(&mut world, 3, 5, None);
+
+        let grid = grid(&[same_cell, lower, stacked_bomb, chained, chained_twin]);
+        let blast = resolve_blast([origin], &grid);
+
+        assert_eq!(blast.detonated, vec![origin.entity, stacked_bomb.entity]);
+        assert_eq!(blast.destroyed.len(), 5);
+        for brick in [same_cell, lower, stacked_bomb, chained, chained_twin] {
+            assert!(blast.destroyed.contains(&brick.entity));
+        }
+    }
+}
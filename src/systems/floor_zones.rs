@@ -0,0 +1,285 @@
+//! Level-defined floor zones that change how the ball slides while it is over them.
+//!
+//! - **Ice**: scales the ball's linear damping down, so it keeps its speed.
+//! - **Sticky**: adds linear damping, so the ball slows noticeably while crossing.
+//!
+//! Zones are declared per level (`LevelDefinition::floor_zones`) as inclusive row/column ranges of
+//! the level grid (20x20 unless the level declares another `resolution`). When the level changes
+//! the [`FloorZones`] resource is rebuilt in world space and a tinted decal is laid on the ground
+//! for each zone.
+//!
+//! `limit_ball_velocity` rewrites `Damping::linear_damping` every frame, so the zone modifier is
+//! applied right after it on top of that value instead of accumulating.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Damping;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::{matrix_resolution, validate_cell_range_in};
+use crate::level_loader::CurrentLevel;
+use crate::{Ball, GridResolution, PLANE_H, PLANE_W};
+
+/// Linear damping multiplier over ice.
+pub const ICE_DAMPING_SCALE: f32 = 0.1;
+/// Linear damping added over sticky floor.
+pub const STICKY_DAMPING_BONUS: f32 = 1.5;
+
+/// Height of the zone decals above the ground plane (avoids z-fighting).
+const DECAL_Y: f32 = 0.01;
+
+/// Surface type of a floor zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FloorZoneKind {
+    Ice,
+    Sticky,
+}
+
+impl FloorZoneKind {
+    /// Linear damping for a ball over this surface, given the damping it would otherwise have.
+    pub fn apply(self, linear_damping: f32) -> f32 {
+        match self {
+            FloorZoneKind::Ice => linear_damping * ICE_DAMPING_SCALE,
+            FloorZoneKind::Sticky => linear_damping + STICKY_DAMPING_BONUS,
+        }
+    }
+
+    /// Decal tint.
+    pub fn tint(self) -> Color {
+        match self {
+            FloorZoneKind::Ice => Color::srgba(0.6, 0.85, 1.0, 0.45),
+            FloorZoneKind::Sticky => Color::srgba(0.45, 0.3, 0.1, 0.55),
+        }
+    }
+}
+
+/// A floor zone as authored in a level file: inclusive `(first, last)` row and column ranges.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FloorZoneDef {
+    pub kind: FloorZoneKind,
+    pub rows: (usize, usize),
+    pub cols: (usize, usize),
+}
+
+impl FloorZoneDef {
+    /// Check the zone's cell range against the grid of the level `matrix`.
+    pub fn validate(&self, matrix: &[Vec<u8>]) -> Result<(), String> {
+        validate_cell_range_in(self.rows, self.cols, matrix_resolution(matrix))
+    }
+}
+
+/// World-space XZ bounds `(min, max)` of an inclusive cell range of `grid`, as `(x, z)` pairs.
+pub fn cell_range_bounds(
+    rows: (usize, usize),
+    cols: (usize, usize),
+    grid: &GridResolution,
+) -> (Vec2, Vec2) {
+    // Rows run along +X, columns along -Z (see level_loader brick placement)
+    let x0 = -PLANE_H / 2.0 + rows.0 as f32 * grid.cell_height();
+    let x1 = -PLANE_H / 2.0 + (rows.1 + 1) as f32 * grid.cell_height();
+    let z0 = PLANE_W / 2.0 - (cols.1 + 1) as f32 * grid.cell_width();
+    let z1 = PLANE_W / 2.0 - cols.0 as f32 * grid.cell_width();
+    (Vec2::new(x0, z0), Vec2::new(x1, z1))
+}
+
+/// Whether `translation` lies inside the XZ rectangle `min..=max`.
+pub fn xz_contains(min: Vec2, max: Vec2, translation: Vec3) -> bool {
+    let p = Vec2::new(translation.x, translation.z);
+    p.cmpge(min).all() && p.cmple(max).all()
+}
+
+/// A floor zone in world space (XZ rectangle).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveFloorZone {
+    pub kind: FloorZoneKind,
+    /// Minimum corner as (x, z).
+    pub min: Vec2,
+    /// Maximum corner as (x, z).
+    pub max: Vec2,
+}
+
+impl ActiveFloorZone {
+    fn from_def(def: &FloorZoneDef, grid: &GridResolution) -> Self {
+        let (min, max) = cell_range_bounds(def.rows, def.cols, grid);
+        Self {
+            kind: def.kind,
+            min,
+            max,
+        }
+    }
+
+    pub fn contains(&self, translation: Vec3) -> bool {
+        xz_contains(self.min, self.max, translation)
+    }
+}
+
+/// Floor zones of the current level, rebuilt whenever the level changes.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct FloorZones {
+    pub zones: Vec<ActiveFloorZone>,
+}
+
+impl FloorZones {
+    /// Build world-space zones on the level `grid`, skipping (and logging) invalid definitions.
+    pub fn from_defs(defs: &[FloorZoneDef], matrix: &[Vec<u8>], grid: &GridResolution) -> Self {
+        let zones = defs
+            .iter()
+            .filter(|def| match def.validate(matrix) {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Ignoring floor zone: {}", e);
+                    false
+                }
+            })
+            .map(|def| ActiveFloorZone::from_def(def, grid))
+            .collect();
+        Self { zones }
+    }
+
+    /// Surface under `translation`. Later zones win where zones overlap.
+    pub fn kind_at(&self, translation: Vec3) -> Option<FloorZoneKind> {
+        self.zones
+            .iter()
+            .rev()
+            .find(|zone| zone.contains(translation))
+            .map(|zone| zone.kind)
+    }
+}
+
+/// Marker for floor zone decals (despawned on level change).
+#[derive(Component)]
+pub struct FloorZoneDecal;
+
+/// Rebuild [`FloorZones`] and the decals for the newly loaded level.
+pub fn rebuild_floor_zones(
+    mut commands: Commands,
+    current_level: Res<CurrentLevel>,
+    decals: Query<Entity, With<FloorZoneDecal>>,
+    mut zones: ResMut<FloorZones>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    for entity in decals.iter() {
+        commands.entity(entity).despawn();
+    }
+    *zones = FloorZones::from_defs(
+        current_level.0.floor_zones.as_deref().unwrap_or_default(),
+        &current_level.0.matrix,
+        &current_level.0.grid(),
+    );
+
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    // One material per surface kind, shared by all its decals
+    let mut kind_materials: Vec<(FloorZoneKind, Handle<StandardMaterial>)> = Vec::new();
+    for zone in &zones.zones {
+        let material = match kind_materials.iter().find(|(k, _)| *k == zone.kind) {
+            Some((_, handle)) => handle.clone(),
+            None => {
+                let handle = materials.add(StandardMaterial {
+                    base_color: zone.kind.tint(),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                });
+                kind_materials.push((zone.kind, handle.clone()));
+                handle
+            }
+        };
+        let size = zone.max - zone.min;
+        let center = (zone.min + zone.max) / 2.0;
+        commands.spawn((
+            Mesh3d(meshes.add(Plane3d::default().mesh().size(size.x, size.y))),
+            MeshMaterial3d(material),
+            Transform::from_xyz(center.x, DECAL_Y, center.y),
+            FloorZoneDecal,
+        ));
+    }
+}
+
+/// Adjust each ball's linear damping for the floor zone it is over.
+pub fn apply_floor_zone_damping(
+    zones: Res<FloorZones>,
+    mut balls: Query<(&Transform, &mut Damping), With<Ball>>,
+) {
+    if zones.zones.is_empty() {
+        return;
+    }
+    for (transform, mut damping) in balls.iter_mut() {
+        if let Some(kind) = zones.kind_at(transform.translation) {
+            damping.linear_damping = kind.apply(damping.linear_damping);
+        }
+    }
+}
+
+/// Plugin registering level floor zones.
+pub struct FloorZonesPlugin;
+
+impl Plugin for FloorZonesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FloorZones>();
+        app.add_systems(
+            Update,
+            (
+                rebuild_floor_zones.run_if(resource_exists_and_changed::<CurrentLevel>),
+                apply_floor_zone_damping.after(crate::limit_ball_velocity),
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CELL_HEIGHT, CELL_WIDTH};
+
+    fn def(kind: FloorZoneKind, rows: (usize, usize), cols: (usize, usize)) -> FloorZoneDef {
+        FloorZoneDef { kind, rows, cols }
+    }
+
+    fn standard_zones(defs: &[FloorZoneDef]) -> FloorZones {
+        FloorZones::from_defs(defs, &[], &GridResolution::default())
+    }
+
+    #[test]
+    fn zone_covers_exactly_its_cells() {
+        let zones = standard_zones(&[def(FloorZoneKind::Ice, (0, 0), (0, 0))]);
+        // Centre of cell (0, 0), same formula as brick placement
+        let cell = Vec3::new(
+            -PLANE_H / 2.0 + 0.5 * CELL_HEIGHT,
+            2.0,
+            PLANE_W / 2.0 - 0.5 * CELL_WIDTH,
+        );
+        assert_eq!(zones.kind_at(cell), Some(FloorZoneKind::Ice));
+        assert_eq!(zones.kind_at(cell + Vec3::X * CELL_HEIGHT), None);
+        assert_eq!(zones.kind_at(cell - Vec3::Z * CELL_WIDTH), None);
+    }
+
+    #[test]
+    fn later_zone_wins_and_invalid_zones_are_dropped() {
+        let zones = standard_zones(&[
+            def(FloorZoneKind::Ice, (0, 19), (0, 19)),
+            def(FloorZoneKind::Sticky, (5, 5), (5, 5)),
+            def(FloorZoneKind::Sticky, (3, 2), (0, 0)),
+            def(FloorZoneKind::Sticky, (0, 20), (0, 0)),
+        ]);
+        assert_eq!(zones.zones.len(), 2);
+        assert_eq!(
+            zones.kind_at(Vec3::new(-14.0, 2.0, 0.0)),
+            Some(FloorZoneKind::Ice)
+        );
+        let sticky_cell = Vec3::new(
+            -PLANE_H / 2.0 + 5.5 * CELL_HEIGHT,
+            2.0,
+            PLANE_W / 2.0 - 5.5 * CELL_WIDTH,
+        );
+        assert_eq!(zones.kind_at(sticky_cell), Some(FloorZoneKind::Sticky));
+    }
+
+    #[test]
+    fn surfaces_scale_damping() {
+        assert!(FloorZoneKind::Ice.apply(0.5) < 0.5);
+        assert!(FloorZoneKind::Sticky.apply(0.5) > 0.5);
+    }
+}
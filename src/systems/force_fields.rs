@@ -0,0 +1,346 @@
+//! Level-defined wind / force field zones.
+//!
+//! Each field covers an inclusive row/column range of the level grid (20x20 unless the level
+//! declares another `resolution`) and pushes every ball inside it
+//! with a continuous force along `direction` (an `(x, z)` pair in world axes). An optional
+//! [`ForceOscillation`] makes the strength swell and fade sinusoidally, e.g. for gusts.
+//! Overlapping fields add up.
+//!
+//! Fields are rebuilt into the [`ForceFields`] resource whenever the level changes. Each ball's
+//! Rapier `ExternalForce` is owned by this module: it is set to the sum of the fields the ball is
+//! in and reset to zero once it leaves them.
+//!
+//! # Debugging
+//! A direction arrow is spawned at the centre of every field as part of the grid overlay, so it is
+//! shown together with the grid in wireframe mode. Its length follows the current strength.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::ExternalForce;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::{matrix_resolution, validate_cell_range_in};
+use crate::level_loader::CurrentLevel;
+use crate::systems::floor_zones::{cell_range_bounds, xz_contains};
+use crate::{Ball, GridOverlay, GridResolution};
+
+/// Height of the debug arrows (just above the gameplay plane).
+const ARROW_Y: f32 = 2.1;
+/// Arrow length at full strength, as a fraction of the field's shorter side.
+const ARROW_FILL: f32 = 0.8;
+
+/// Sinusoidal strength modulation: the force scales between `1 - amplitude` and `1 + amplitude`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ForceOscillation {
+    /// Seconds per full cycle.
+    pub period: f32,
+    /// Relative swing in `[0, 1]`.
+    #[serde(default = "default_amplitude")]
+    pub amplitude: f32,
+}
+
+fn default_amplitude() -> f32 {
+    1.0
+}
+
+impl ForceOscillation {
+    /// Strength factor at `elapsed` seconds.
+    pub fn factor(&self, elapsed: f32) -> f32 {
+        1.0 + self.amplitude * (TAU * elapsed / self.period).sin()
+    }
+}
+
+/// A force field as authored in a level file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForceFieldDef {
+    pub rows: (usize, usize),
+    pub cols: (usize, usize),
+    /// Push direction as `(x, z)`; normalized on load.
+    pub direction: (f32, f32),
+    /// Force magnitude in newtons.
+    pub strength: f32,
+    #[serde(default)]
+    pub oscillation: Option<ForceOscillation>,
+}
+
+impl ForceFieldDef {
+    /// Check the field against the grid of the level `matrix`.
+    pub fn validate(&self, matrix: &[Vec<u8>]) -> Result<(), String> {
+        validate_cell_range_in(self.rows, self.cols, matrix_resolution(matrix))?;
+        let direction = Vec2::new(self.direction.0, self.direction.1);
+        if !direction.is_finite() || direction.length_squared() == 0.0 {
+            return Err(format!(
+                "force field direction is invalid: {:?}",
+                self.direction
+            ));
+        }
+        if !self.strength.is_finite() {
+            return Err(format!(
+                "force field strength is invalid: {}",
+                self.strength
+            ));
+        }
+        if let Some(osc) = self.oscillation {
+            if !osc.period.is_finite() || osc.period <= 0.0 {
+                return Err(format!("oscillation period must be > 0: {}", osc.period));
+            }
+            if !(0.0..=1.0).contains(&osc.amplitude) {
+                return Err(format!(
+                    "oscillation amplitude out of [0, 1]: {}",
+                    osc.amplitude
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A force field in world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveForceField {
+    /// Minimum corner as (x, z).
+    pub min: Vec2,
+    /// Maximum corner as (x, z).
+    pub max: Vec2,
+    /// Force at full strength (Y is always 0).
+    pub force: Vec3,
+    pub oscillation: Option<ForceOscillation>,
+}
+
+impl ActiveForceField {
+    fn from_def(def: &ForceFieldDef, grid: &GridResolution) -> Self {
+        let (min, max) = cell_range_bounds(def.rows, def.cols, grid);
+        let direction = Vec2::new(def.direction.0, def.direction.1).normalize();
+        Self {
+            min,
+            max,
+            force: Vec3::new(direction.x, 0.0, direction.y) * def.strength,
+            oscillation: def.oscillation,
+        }
+    }
+
+    /// Strength factor at `elapsed` seconds (1.0 without oscillation).
+    pub fn factor(&self, elapsed: f32) -> f32 {
+        self.oscillation.map_or(1.0, |osc| osc.factor(elapsed))
+    }
+}
+
+/// Force fields of the current level, rebuilt whenever the level changes.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ForceFields {
+    pub fields: Vec<ActiveForceField>,
+}
+
+impl ForceFields {
+    /// Build world-space fields on the level `grid`, skipping (and logging) invalid definitions.
+    pub fn from_defs(defs: &[ForceFieldDef], matrix: &[Vec<u8>], grid: &GridResolution) -> Self {
+        let fields = defs
+            .iter()
+            .filter(|def| match def.validate(matrix) {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Ignoring force field: {}", e);
+                    false
+                }
+            })
+            .map(|def| ActiveForceField::from_def(def, grid))
+            .collect();
+        Self { fields }
+    }
+
+    /// Total force on a ball at `translation`, `elapsed` seconds into the level.
+    pub fn force_at(&self, translation: Vec3, elapsed: f32) -> Vec3 {
+        self.fields
+            .iter()
+            .filter(|field| xz_contains(field.min, field.max, translation))
+            .map(|field| field.force * field.factor(elapsed))
+            .sum()
+    }
+}
+
+/// Debug arrow for the force field at `index` in [`ForceFields::fields`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ForceFieldArrow {
+    pub index: usize,
+}
+
+fn spawn_arrow(
+    commands: &mut Commands,
+    index: usize,
+    field: &ActiveForceField,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<StandardMaterial>,
+) {
+    let center = (field.min + field.max) / 2.0;
+    let size = field.max - field.min;
+    let length = size.min_element() * ARROW_FILL;
+    let heading = Quat::from_rotation_arc(Vec3::X, field.force.normalize_or(Vec3::X));
+    // The arrow points along local +X; its X scale carries the current strength
+    commands
+        .spawn((
+            Transform::from_xyz(center.x, ARROW_Y, center.y).with_rotation(heading),
+            GridOverlay,
+            Visibility::Hidden,
+            ForceFieldArrow { index },
+        ))
+        .with_children(|arrow| {
+            arrow.spawn((
+                Mesh3d(meshes.add(Cuboid::new(length * 0.75, 0.05, 0.05))),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(length * 0.375, 0.0, 0.0),
+            ));
+            arrow.spawn((
+                Mesh3d(meshes.add(Cone::new(0.2, length * 0.25))),
+                MeshMaterial3d(material),
+                // Cone tip points along +Y; turn it to +X
+                Transform::from_xyz(length * 0.875, 0.0, 0.0)
+                    .with_rotation(Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2)),
+            ));
+        });
+}
+
+/// Rebuild [`ForceFields`] and the debug arrows for the newly loaded level.
+pub fn rebuild_force_fields(
+    mut commands: Commands,
+    current_level: Res<CurrentLevel>,
+    arrows: Query<Entity, With<ForceFieldArrow>>,
+    mut fields: ResMut<ForceFields>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    for entity in arrows.iter() {
+        commands.entity(entity).despawn();
+    }
+    *fields = ForceFields::from_defs(
+        current_level.0.force_fields.as_deref().unwrap_or_default(),
+        &current_level.0.matrix,
+        &current_level.0.grid(),
+    );
+
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    if fields.fields.is_empty() {
+        return;
+    }
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.3, 0.9, 1.0, 0.8),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    for (index, field) in fields.fields.iter().enumerate() {
+        spawn_arrow(&mut commands, index, field, &mut meshes, material.clone());
+    }
+}
+
+/// Set each ball's `ExternalForce` to the sum of the fields it is in.
+pub fn apply_force_fields(
+    mut commands: Commands,
+    fields: Res<ForceFields>,
+    time: Res<Time>,
+    mut balls: Query<(Entity, &Transform, Option<&mut ExternalForce>), With<Ball>>,
+) {
+    let elapsed = time.elapsed_secs();
+    for (entity, transform, external) in balls.iter_mut() {
+        let force = fields.force_at(transform.translation, elapsed);
+        match external {
+            Some(mut external) if external.force != force => {
+                external.force = force;
+            }
+            None if force != Vec3::ZERO => {
+                commands.entity(entity).insert(ExternalForce {
+                    force,
+                    torque: Vec3::ZERO,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Scale the debug arrows with the current field strength.
+pub fn animate_force_field_arrows(
+    fields: Res<ForceFields>,
+    time: Res<Time>,
+    mut arrows: Query<(&ForceFieldArrow, &mut Transform)>,
+) {
+    let elapsed = time.elapsed_secs();
+    for (arrow, mut transform) in arrows.iter_mut() {
+        if let Some(field) = fields.fields.get(arrow.index) {
+            if field.oscillation.is_some() {
+                transform.scale.x = field.factor(elapsed).max(0.05);
+            }
+        }
+    }
+}
+
+/// Plugin registering level force fields.
+pub struct ForceFieldsPlugin;
+
+impl Plugin for ForceFieldsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ForceFields>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_systems(
+            Update,
+            (
+                rebuild_force_fields.run_if(resource_exists_and_changed::<CurrentLevel>),
+                (apply_force_fields, animate_force_field_arrows).run_if(crate::pause::not_paused),
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn def(direction: (f32, f32), strength: f32) -> ForceFieldDef {
+        ForceFieldDef {
+            rows: (0, 19),
+            cols: (0, 19),
+            direction,
+            strength,
+            oscillation: None,
+        }
+    }
+
+    #[test]
+    fn overlapping_fields_add_up() {
+        let fields = ForceFields::from_defs(
+            &[def((1.0, 0.0), 2.0), def((0.0, 3.0), 1.0)],
+            &[],
+            &GridResolution::default(),
+        );
+        let force = fields.force_at(Vec3::new(0.0, 2.0, 0.0), 0.0);
+        assert!((force - Vec3::new(2.0, 0.0, 1.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn oscillation_swings_around_base_strength() {
+        let osc = ForceOscillation {
+            period: 4.0,
+            amplitude: 0.5,
+        };
+        assert!((osc.factor(0.0) - 1.0).abs() < 1e-5);
+        assert!((osc.factor(1.0) - 1.5).abs() < 1e-5);
+        assert!((osc.factor(3.0) - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn invalid_fields_are_rejected() {
+        assert!(def((0.0, 0.0), 1.0).validate(&[]).is_err());
+        assert!(def((1.0, 0.0), f32::NAN).validate(&[]).is_err());
+        let mut bad_period = def((1.0, 0.0), 1.0);
+        bad_period.oscillation = Some(ForceOscillation {
+            period: 0.0,
+            amplitude: 1.0,
+        });
+        assert!(bad_period.validate(&[]).is_err());
+        assert!(def((1.0, 1.0), 4.0).validate(&[]).is_ok());
+    }
+}
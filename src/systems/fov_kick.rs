@@ -0,0 +1,171 @@
+//! Impact FOV kick.
+//!
+//! When the paddle slams into a wall or a brick hard enough, the camera's field of view widens by
+//! one to two degrees and eases back over [`FOV_KICK_SECS`], on top of the camera shake. The kick
+//! grows with the impact's impulse; soft contacts below [`FOV_KICK_MIN_IMPULSE`] do not kick at
+//! all. The shake moves the camera and the kick only touches its projection, so the two simply
+//! add up. A kick landing while another is still fading restarts the fade from the larger of the
+//! two.
+//!
+//! Both the kick and the shake are turned off by `reduced_motion: true` in `config/gameplay.ron`
+//! (`GameplayConfig::reduced_motion`).
+
+use bevy::prelude::*;
+
+use crate::gameplay_config::GameplayConfig;
+use crate::{BrickHit, MainCamera, WallHit};
+
+/// Impulse below which an impact does not kick the FOV.
+pub const FOV_KICK_MIN_IMPULSE: f32 = 20.0;
+/// Impulse at which the kick reaches [`FOV_KICK_MAX_DEG`].
+pub const FOV_KICK_FULL_IMPULSE: f32 = 200.0;
+/// Kick of the softest impact that still counts, in degrees.
+pub const FOV_KICK_MIN_DEG: f32 = 1.0;
+/// Kick of the hardest impacts, in degrees.
+pub const FOV_KICK_MAX_DEG: f32 = 2.0;
+/// How long the FOV takes to settle back, in seconds.
+pub const FOV_KICK_SECS: f32 = 0.2;
+
+/// A fading FOV kick on a camera.
+#[derive(Component, Debug, Clone)]
+pub struct FovKick {
+    /// FOV of the camera before the kick, in radians.
+    pub base_fov: f32,
+    /// Extra FOV at the start of the fade, in radians.
+    pub peak: f32,
+    pub timer: Timer,
+}
+
+impl FovKick {
+    /// Extra FOV right now, in radians.
+    pub fn current(&self) -> f32 {
+        self.peak * (1.0 - self.timer.fraction())
+    }
+}
+
+/// Kick in degrees for an impact with the given impulse magnitude, or `None` if it is too soft.
+pub fn fov_kick_degrees(impulse: f32) -> Option<f32> {
+    if !impulse.is_finite() || impulse < FOV_KICK_MIN_IMPULSE {
+        return None;
+    }
+    let t = ((impulse - FOV_KICK_MIN_IMPULSE) / (FOV_KICK_FULL_IMPULSE - FOV_KICK_MIN_IMPULSE))
+        .clamp(0.0, 1.0);
+    Some(FOV_KICK_MIN_DEG + (FOV_KICK_MAX_DEG - FOV_KICK_MIN_DEG) * t)
+}
+
+fn reduced_motion(gameplay: Option<&GameplayConfig>) -> bool {
+    gameplay.is_some_and(|config| config.reduced_motion)
+}
+
+fn kick_cameras(
+    impulse: Vec3,
+    gameplay: Option<&GameplayConfig>,
+    cameras: &mut Query<(Entity, &Projection, Option<&mut FovKick>), With<MainCamera>>,
+    commands: &mut Commands,
+) {
+    if reduced_motion(gameplay) {
+        return;
+    }
+    let Some(degrees) = fov_kick_degrees(impulse.length()) else {
+        return;
+    };
+    let peak = degrees.to_radians();
+    for (entity, projection, kick) in cameras.iter_mut() {
+        match kick {
+            Some(mut kick) => {
+                kick.peak = kick.current().max(peak);
+                kick.timer.reset();
+            }
+            None => {
+                let Projection::Perspective(perspective) = projection else {
+                    continue;
+                };
+                commands.entity(entity).insert(FovKick {
+                    base_fov: perspective.fov,
+                    peak,
+                    timer: Timer::from_seconds(FOV_KICK_SECS, TimerMode::Once),
+                });
+            }
+        }
+    }
+}
+
+/// Kick the FOV when the paddle hits a wall.
+pub fn kick_on_wall_hit(
+    trigger: On<WallHit>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut cameras: Query<(Entity, &Projection, Option<&mut FovKick>), With<MainCamera>>,
+    mut commands: Commands,
+) {
+    kick_cameras(
+        trigger.event().impulse,
+        gameplay.as_deref(),
+        &mut cameras,
+        &mut commands,
+    );
+}
+
+/// Kick the FOV when the paddle hits a brick.
+pub fn kick_on_brick_hit(
+    trigger: On<BrickHit>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut cameras: Query<(Entity, &Projection, Option<&mut FovKick>), With<MainCamera>>,
+    mut commands: Commands,
+) {
+    kick_cameras(
+        trigger.event().impulse,
+        gameplay.as_deref(),
+        &mut cameras,
+        &mut commands,
+    );
+}
+
+/// Fade kicks out and restore the FOV when they end.
+pub fn update_fov_kick(
+    time: Res<Time>,
+    mut cameras: Query<(Entity, &mut Projection, &mut FovKick)>,
+    mut commands: Commands,
+) {
+    for (entity, mut projection, mut kick) in cameras.iter_mut() {
+        kick.timer.tick(time.delta());
+        let Projection::Perspective(perspective) = projection.as_mut() else {
+            commands.entity(entity).remove::<FovKick>();
+            continue;
+        };
+        if kick.timer.is_finished() {
+            perspective.fov = kick.base_fov;
+            commands.entity(entity).remove::<FovKick>();
+        } else {
+            perspective.fov = kick.base_fov + kick.current();
+        }
+    }
+}
+
+/// Plugin registering the impact FOV kick.
+pub struct FovKickPlugin;
+
+impl Plugin for FovKickPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(kick_on_wall_hit);
+        app.add_observer(kick_on_brick_hit);
+        app.add_systems(Update, update_fov_kick);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kick_grows_with_impulse_within_bounds() {
+        assert_eq!(fov_kick_degrees(FOV_KICK_MIN_IMPULSE - 1.0), None);
+        assert_eq!(fov_kick_degrees(f32::NAN), None);
+        assert_eq!(
+            fov_kick_degrees(FOV_KICK_MIN_IMPULSE),
+            Some(FOV_KICK_MIN_DEG)
+        );
+        let middle = fov_kick_degrees(100.0).unwrap();
+        assert!(middle > FOV_KICK_MIN_DEG && middle < FOV_KICK_MAX_DEG);
+        assert_eq!(fov_kick_degrees(10_000.0), Some(FOV_KICK_MAX_DEG));
+    }
+}
@@ -0,0 +1,115 @@
+//! Game completion.
+//!
+//! When the last level is cleared and there is no next one, `advance_level_when_cleared` triggers
+//! [`GameCompleted`] once. This plugin then ends the run: the paddle, balls and merkabas are
+//! removed so nothing more happens on the field, and [`GameCompleteState`] records the final score
+//! for the score screen (`ui::game_complete_screen`).
+//!
+//! From that screen the player can quit or play again. [`RestartGameRequested`] starts a fresh
+//! game: score and lives are reset and the first level is loaded through the usual level switch.
+
+use bevy::ecs::message::{Message, MessageReader, MessageWriter};
+use bevy::prelude::*;
+
+use crate::systems::level_switch::{
+    LevelSwitchDirection, LevelSwitchRequested, LevelSwitchSource, LevelSwitchState,
+};
+use crate::systems::merkaba::{Merkaba, PendingMerkabaSpawns};
+use crate::systems::respawn::LivesState;
+use crate::systems::scoring::ScoreState;
+use crate::{Ball, Paddle};
+
+/// Triggered once when the last level of the game has been cleared.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameCompleted {
+    /// Number of the level that finished the game.
+    pub last_level: u32,
+}
+
+/// Ask for a new game after the game was completed.
+#[derive(Message, Debug, Clone, Copy, Default)]
+pub struct RestartGameRequested;
+
+/// Result of a completed game, shown on the score screen.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameCompleteState {
+    /// Whether the game is complete and waiting on the player.
+    pub active: bool,
+    pub final_score: u32,
+    pub last_level: u32,
+}
+
+/// End the run and record its result.
+pub fn on_game_completed(
+    trigger: On<GameCompleted>,
+    mut commands: Commands,
+    mut state: ResMut<GameCompleteState>,
+    score: Option<Res<ScoreState>>,
+    paddles: Query<Entity, With<Paddle>>,
+    balls: Query<Entity, With<Ball>>,
+    merkabas: Query<Entity, With<Merkaba>>,
+    pending_merkaba_spawns: Option<ResMut<PendingMerkabaSpawns>>,
+) {
+    let event = trigger.event();
+    info!("Game complete after level {}", event.last_level);
+    // Freeze gameplay: nothing left to hit or to be hit by
+    for entity in paddles.iter().chain(balls.iter()).chain(merkabas.iter()) {
+        commands.entity(entity).despawn();
+    }
+    if let Some(mut spawns) = pending_merkaba_spawns {
+        spawns.entries.clear();
+    }
+    *state = GameCompleteState {
+        active: true,
+        final_score: score.map_or(0, |s| s.current_score),
+        last_level: event.last_level,
+    };
+}
+
+/// Start a new game from the first level.
+pub fn restart_game(
+    mut requests: MessageReader<RestartGameRequested>,
+    mut state: ResMut<GameCompleteState>,
+    switch_state: Option<Res<LevelSwitchState>>,
+    score: Option<ResMut<ScoreState>>,
+    lives: Option<ResMut<LivesState>>,
+    mut switches: MessageWriter<LevelSwitchRequested>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    let Some(first) = switch_state
+        .as_deref()
+        .and_then(|s| s.ordered_levels().first())
+        .map(|slot| slot.number)
+    else {
+        warn!("No levels found; cannot start a new game");
+        return;
+    };
+    info!("Starting a new game at level {}", first);
+    *state = GameCompleteState::default();
+    if let Some(mut score) = score {
+        score.reset();
+    }
+    if let Some(mut lives) = lives {
+        lives.lives_remaining = LivesState::default().lives_remaining;
+        lives.on_last_life = false;
+    }
+    switches.write(LevelSwitchRequested {
+        source: LevelSwitchSource::NewGame,
+        direction: LevelSwitchDirection::Jump(first),
+    });
+}
+
+/// Plugin registering game completion and restart.
+pub struct GameCompletePlugin;
+
+impl Plugin for GameCompletePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameCompleteState>();
+        app.add_message::<RestartGameRequested>();
+        app.add_message::<LevelSwitchRequested>();
+        app.add_observer(on_game_completed);
+        app.add_systems(Update, restart_game);
+    }
+}
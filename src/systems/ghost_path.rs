@@ -0,0 +1,346 @@
+//! Ghost best path: the ball's route through the fastest completed run of a level (level design
+//! dev tool).
+//!
+//! While a level is played, [`GhostRecorder`] samples the ball's position every
+//! [`GHOST_SAMPLE_INTERVAL`] seconds of play (paused frames and frozen balls are skipped; with
+//! several balls in play, the oldest one is followed). A restart or level change starts a new
+//! recording. When the level is completed, the recording is saved as the level's ghost if no ghost
+//! exists yet or the run was faster than the saved one, to `config/ghosts/level_NNN.ron` (to
+//! localStorage on wasm, see `systems::web_storage`). Runs at another game speed than 100%
+//! (`systems::sim_speed`) are never saved. Ghosts are shared by all profiles, since
+//! they describe the level rather than the player.
+//!
+//! The ghost of the current level is shown as a line over the playing field while the designer
+//! palette (`ui::palette`) is open, or toggled with `B` while cheat mode is active. It shows how
+//! players actually route the ball through the layout, next to the time spent per area of
+//! `systems::heatmap`.
+
+use std::path::PathBuf;
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+use serde::{Deserialize, Serialize};
+
+use crate::level_loader::CurrentLevel;
+use crate::systems::audio::LevelCompleted;
+use crate::systems::cheat_mode::CheatModeState;
+use crate::systems::sim_speed::SimSpeed;
+use crate::systems::web_storage::{read_save_file, write_save_file};
+use crate::ui::palette::PaletteState;
+use crate::{Ball, BallFrozen};
+
+/// Directory of the ghost files.
+pub const GHOSTS_DIR: &str = "config/ghosts";
+/// Seconds of play between two samples of a recording.
+pub const GHOST_SAMPLE_INTERVAL: f32 = 0.1;
+/// Most samples kept per recording (ten minutes of play); later positions are dropped.
+pub const MAX_GHOST_SAMPLES: usize = 6000;
+
+/// Height of the overlay line (just above the gameplay plane).
+const OVERLAY_Y: f32 = 2.05;
+
+/// Ball route of a completed run, as stored in a ghost file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GhostPath {
+    /// Level the run completed
+    pub level: u32,
+    /// Seconds the run had a moving ball
+    pub duration: f32,
+    /// Ball positions `(x, z)`, one per [`GHOST_SAMPLE_INTERVAL`]
+    pub points: Vec<(f32, f32)>,
+}
+
+impl GhostPath {
+    /// Whether this run should replace `saved` as the level's ghost.
+    pub fn beats(&self, saved: Option<&GhostPath>) -> bool {
+        saved.is_none_or(|saved| self.duration < saved.duration)
+    }
+}
+
+/// Where ghost files are kept ([`GHOSTS_DIR`] unless overridden, e.g. by tests).
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct GhostStore {
+    pub dir: PathBuf,
+}
+
+impl Default for GhostStore {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from(GHOSTS_DIR),
+        }
+    }
+}
+
+impl GhostStore {
+    /// Ghost file of level `number`.
+    pub fn path(&self, number: u32) -> PathBuf {
+        self.dir.join(format!("level_{number:03}.ron"))
+    }
+
+    /// Read the ghost of level `number`; a missing or unreadable file means no ghost.
+    pub fn load(&self, number: u32) -> Option<GhostPath> {
+        read_save_file(&self.path(number), |content| {
+            ron::de::from_str::<GhostPath>(content)
+                .inspect_err(|e| warn!("Failed to parse ghost of level {}: {}", number, e))
+                .ok()
+        })
+    }
+
+    /// Write `ghost` to its level's ghost file.
+    pub fn save(&self, ghost: &GhostPath) -> Result<(), String> {
+        let path = self.path(ghost.level);
+        let content = ron::ser::to_string(ghost)
+            .map_err(|e| format!("failed to serialize ghost path: {e}"))?;
+        write_save_file(&path, &content)?;
+        info!("Saved ghost path to {}", path.display());
+        Ok(())
+    }
+}
+
+/// Ball route of the current attempt.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct GhostRecorder {
+    /// Level being recorded (`None` until the first level loads)
+    pub level_number: Option<u32>,
+    /// Seconds the attempt has had a moving ball
+    pub elapsed: f32,
+    pub points: Vec<(f32, f32)>,
+    /// Play time since the last sample
+    since_sample: f32,
+    /// The level was completed; nothing more is recorded until the next attempt
+    pub finished: bool,
+}
+
+impl GhostRecorder {
+    /// Start recording an attempt at level `number`.
+    pub fn start(&mut self, number: u32) {
+        *self = Self {
+            level_number: Some(number),
+            ..Default::default()
+        };
+    }
+
+    /// Advance the recording by `seconds`, with the ball at `ball` (if one is in play).
+    pub fn advance(&mut self, seconds: f32, ball: Option<Vec3>) {
+        if self.finished || self.level_number.is_none() {
+            return;
+        }
+        // Only time with a ball in play counts, like the samples
+        let Some(ball) = ball else {
+            return;
+        };
+        self.elapsed += seconds;
+        self.since_sample += seconds;
+        // The first position is taken at once, so the line starts at the launch point
+        if self.since_sample >= GHOST_SAMPLE_INTERVAL || self.points.is_empty() {
+            self.since_sample = 0.0;
+            if self.points.len() < MAX_GHOST_SAMPLES {
+                self.points.push((ball.x, ball.z));
+            }
+        }
+    }
+
+    /// Finish the attempt at level `number`, returning its route.
+    pub fn finish(&mut self, number: u32) -> Option<GhostPath> {
+        if self.finished || self.level_number != Some(number) || self.points.len() < 2 {
+            return None;
+        }
+        self.finished = true;
+        Some(GhostPath {
+            level: number,
+            duration: self.elapsed,
+            points: self.points.clone(),
+        })
+    }
+}
+
+/// Ghost of the current level, if one was saved.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LevelGhost(pub Option<GhostPath>);
+
+/// Whether the ghost overlay was toggled on with `B` (it also shows while the palette is open).
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GhostOverlayToggle(pub bool);
+
+/// Line showing the ghost of the current level.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GhostPathOverlay;
+
+/// Start a new recording and load the saved ghost when a level is loaded or restarted.
+pub fn load_level_ghost(
+    current_level: Res<CurrentLevel>,
+    store: Res<GhostStore>,
+    mut recorder: ResMut<GhostRecorder>,
+    mut ghost: ResMut<LevelGhost>,
+) {
+    let number = current_level.0.number;
+    recorder.start(number);
+    if ghost.0.as_ref().is_none_or(|g| g.level != number) {
+        ghost.0 = store.load(number);
+    }
+}
+
+/// Sample the position of the oldest moving ball.
+pub fn record_ghost_path(
+    time: Res<Time>,
+    balls: Query<(Entity, &Transform), (With<Ball>, Without<BallFrozen>)>,
+    mut recorder: ResMut<GhostRecorder>,
+) {
+    let ball = balls
+        .iter()
+        .min_by_key(|(entity, _)| *entity)
+        .map(|(_, transform)| transform.translation);
+    recorder.advance(time.delta_secs(), ball);
+}
+
+/// Save the finished run as the level's ghost if it beats the saved one.
+fn on_level_completed(
+    trigger: On<LevelCompleted>,
+    store: Res<GhostStore>,
+    sim: Option<Res<SimSpeed>>,
+    mut recorder: ResMut<GhostRecorder>,
+    mut ghost: ResMut<LevelGhost>,
+) {
+    let Some(run) = recorder.finish(trigger.event().level_index) else {
+        return;
+    };
+    // A slowed-down or sped-up run's duration is not comparable
+    if sim.is_some_and(|sim| !sim.counts_for_records()) || !run.beats(ghost.0.as_ref()) {
+        return;
+    }
+    if let Err(e) = store.save(&run) {
+        warn!("Failed to save ghost path: {}", e);
+    }
+    ghost.0 = Some(run);
+}
+
+/// Toggle the overlay with `B` while cheat mode is active.
+pub fn toggle_ghost_overlay(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    cheat_mode: Option<Res<CheatModeState>>,
+    mut toggle: ResMut<GhostOverlayToggle>,
+) {
+    let cheat_active = cheat_mode.is_some_and(|c| c.is_active());
+    if cheat_active && keyboard.is_some_and(|k| k.just_pressed(KeyCode::KeyB)) {
+        toggle.0 = !toggle.0;
+    }
+}
+
+/// Line strip mesh through the points of `ghost`.
+pub fn ghost_path_mesh(ghost: &GhostPath) -> Mesh {
+    let positions: Vec<[f32; 3]> = ghost
+        .points
+        .iter()
+        .map(|&(x, z)| [x, OVERLAY_Y, z])
+        .collect();
+    Mesh::new(PrimitiveTopology::LineStrip, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+}
+
+/// Show the ghost of the current level while the overlay is wanted, rebuilding the line when the
+/// ghost changes.
+pub fn sync_ghost_overlay(
+    mut commands: Commands,
+    ghost: Res<LevelGhost>,
+    toggle: Res<GhostOverlayToggle>,
+    palette: Option<Res<PaletteState>>,
+    overlays: Query<Entity, With<GhostPathOverlay>>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let wanted = toggle.0 || palette.is_some_and(|p| p.open);
+    let shown = !overlays.is_empty();
+    let path = ghost.0.as_ref().filter(|_| wanted);
+    if shown == path.is_some() && !ghost.is_changed() {
+        return;
+    }
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn();
+    }
+    let (Some(path), Some(mut meshes), Some(mut materials)) = (path, meshes, materials) else {
+        return;
+    };
+    commands.spawn((
+        Mesh3d(meshes.add(ghost_path_mesh(path))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgba(0.8, 0.9, 1.0, 0.6),
+            unlit: true,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        })),
+        Transform::default(),
+        GhostPathOverlay,
+    ));
+}
+
+/// Plugin registering the ghost best path recorder and overlay.
+pub struct GhostPathPlugin;
+
+impl Plugin for GhostPathPlugin {
+    fn build(&self, app: &mut App) {
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.init_resource::<GhostStore>();
+        app.init_resource::<GhostRecorder>();
+        app.init_resource::<LevelGhost>();
+        app.init_resource::<GhostOverlayToggle>();
+        app.add_systems(
+            Update,
+            (
+                load_level_ghost.run_if(resource_exists_and_changed::<CurrentLevel>),
+                record_ghost_path.run_if(crate::pause::not_paused),
+                toggle_ghost_overlay,
+                sync_ghost_overlay,
+            )
+                .chain(),
+        );
+        app.add_observer(on_level_completed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_samples_at_the_interval() {
+        let mut recorder = GhostRecorder::default();
+        recorder.start(4);
+        for step in 0..10 {
+            recorder.advance(0.06, Some(Vec3::new(step as f32, 2.0, 1.0)));
+        }
+        // Positions 0, 2, 4, 6, 8: the first at once, then one every two frames
+        assert_eq!(recorder.points.len(), 5);
+        assert_eq!(recorder.points[1], (2.0, 1.0));
+        recorder.advance(0.5, None);
+        assert!((recorder.elapsed - 0.6).abs() < 1e-5, "no ball, no time");
+        assert_eq!(recorder.points.len(), 5);
+    }
+
+    #[test]
+    fn only_the_recorded_level_finishes_once() {
+        let mut recorder = GhostRecorder::default();
+        recorder.start(4);
+        recorder.advance(0.1, Some(Vec3::ZERO));
+        recorder.advance(0.1, Some(Vec3::X));
+        assert!(recorder.finish(5).is_none());
+        let run = recorder.finish(4).unwrap();
+        assert_eq!(run.points, vec![(0.0, 0.0), (1.0, 0.0)]);
+        assert!(recorder.finish(4).is_none());
+        recorder.advance(0.1, Some(Vec3::Z));
+        assert_eq!(recorder.points.len(), 2, "nothing recorded after finishing");
+    }
+
+    #[test]
+    fn faster_runs_replace_the_ghost() {
+        let run = |duration| GhostPath {
+            level: 1,
+            duration,
+            points: Vec::new(),
+        };
+        assert!(run(30.0).beats(None));
+        assert!(run(30.0).beats(Some(&run(40.0))));
+        assert!(!run(50.0).beats(Some(&run(40.0))));
+    }
+}
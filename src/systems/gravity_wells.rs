@@ -0,0 +1,376 @@
+//! Level-placed gravity wells (black-hole style attractors).
+//!
+//! A well sits at the centre of an empty grid cell and pulls every ball towards it:
+//!
+//! ```ron
+//! gravity_wells: [
+//!     (row: 8, col: 10, strength: 40.0),
+//!     (row: 4, col: 3, strength: 25.0, consumes: true),
+//! ],
+//! ```
+//!
+//! The pull follows an inverse-square law: a ball `d` units away is accelerated by
+//! `strength / d²` units/s² towards the well. Below [`MIN_PULL_DISTANCE`] the distance is clamped,
+//! so the pull stays finite at the centre and a ball passing straight through is not flung off at
+//! an absurd speed. The pull changes ball velocities directly rather than through
+//! `ExternalForce`, which belongs to `systems::force_fields`; wells and force fields add up.
+//!
+//! A well with `consumes: true` is also a hazard: a ball reaching its centre (within
+//! [`CONSUME_RADIUS`]) is swallowed and the player loses a life, as if the ball had dropped into
+//! the goal.
+//!
+//! Wells are drawn as a dark core with spiral arms swirling around it; consuming wells glow red.
+//! Entries whose cell is out of range or not empty, or whose strength is not a positive number,
+//! are skipped with a warning.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::{matrix_resolution, validate_cell_range_in};
+use crate::level_loader::CurrentLevel;
+use crate::systems::respawn::{LifeLossCause, LifeLostEvent, RespawnHandle, SpawnPoints};
+use crate::{Ball, GridResolution, CELL_WIDTH};
+
+/// Distance below which the pull no longer grows.
+pub const MIN_PULL_DISTANCE: f32 = CELL_WIDTH * 0.5;
+/// Distance from a consuming well's centre at which balls are swallowed.
+pub const CONSUME_RADIUS: f32 = 0.4;
+/// Radians per second the swirl turns.
+const SWIRL_SPEED: f32 = 2.5;
+/// Spiral arms drawn around a well.
+const SWIRL_ARMS: usize = 3;
+/// Segments per spiral arm.
+const ARM_SEGMENTS: usize = 5;
+
+/// A gravity well as authored in a level file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GravityWellDef {
+    pub row: usize,
+    pub col: usize,
+    /// Pull in units/s² on a ball one unit away.
+    pub strength: f32,
+    /// Whether a ball reaching the centre is lost.
+    #[serde(default)]
+    pub consumes: bool,
+}
+
+impl GravityWellDef {
+    /// Check the well against the level `matrix`: it needs an empty cell.
+    pub fn validate(&self, matrix: &[Vec<u8>]) -> Result<(), String> {
+        validate_cell_range_in(
+            (self.row, self.row),
+            (self.col, self.col),
+            matrix_resolution(matrix),
+        )?;
+        if !self.strength.is_finite() || self.strength <= 0.0 {
+            return Err(format!(
+                "gravity well strength must be > 0: {}",
+                self.strength
+            ));
+        }
+        match matrix.get(self.row).and_then(|r| r.get(self.col)) {
+            None | Some(0) => Ok(()),
+            Some(_) => Err(format!("cell ({}, {}) is not empty", self.row, self.col)),
+        }
+    }
+}
+
+/// A gravity well in world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveGravityWell {
+    /// Centre as (x, z).
+    pub center: Vec2,
+    pub strength: f32,
+    pub consumes: bool,
+}
+
+impl ActiveGravityWell {
+    fn from_def(def: &GravityWellDef, grid: &GridResolution) -> Self {
+        let center = grid.cell_center(def.row, def.col);
+        Self {
+            center: center.xz(),
+            strength: def.strength,
+            consumes: def.consumes,
+        }
+    }
+
+    /// Acceleration towards the well of a ball at `translation` (Y is always 0).
+    pub fn pull_at(&self, translation: Vec3) -> Vec3 {
+        let offset = self.center - translation.xz();
+        let distance = offset.length().max(MIN_PULL_DISTANCE);
+        let pull = offset.normalize_or_zero() * self.strength / (distance * distance);
+        Vec3::new(pull.x, 0.0, pull.y)
+    }
+
+    /// Whether the well swallows a ball at `translation`.
+    pub fn swallows(&self, translation: Vec3) -> bool {
+        self.consumes && self.center.distance(translation.xz()) <= CONSUME_RADIUS
+    }
+}
+
+/// Gravity wells of the current level, rebuilt whenever the level changes.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct GravityWells {
+    pub wells: Vec<ActiveGravityWell>,
+}
+
+impl GravityWells {
+    /// Build world-space wells on the level `grid`, skipping (and logging) invalid definitions.
+    pub fn from_defs(defs: &[GravityWellDef], matrix: &[Vec<u8>], grid: &GridResolution) -> Self {
+        let wells = defs
+            .iter()
+            .filter(|def| match def.validate(matrix) {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Ignoring gravity well: {}", e);
+                    false
+                }
+            })
+            .map(|def| ActiveGravityWell::from_def(def, grid))
+            .collect();
+        Self { wells }
+    }
+
+    /// Total acceleration of a ball at `translation`.
+    pub fn pull_at(&self, translation: Vec3) -> Vec3 {
+        self.wells
+            .iter()
+            .map(|well| well.pull_at(translation))
+            .sum()
+    }
+
+    /// Whether any consuming well swallows a ball at `translation`.
+    pub fn swallows(&self, translation: Vec3) -> bool {
+        self.wells.iter().any(|well| well.swallows(translation))
+    }
+}
+
+/// Swirling visual of a gravity well; turns around its Y axis.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GravityWellSwirl;
+
+fn spawn_well_visual(
+    commands: &mut Commands,
+    well: &ActiveGravityWell,
+    meshes: &mut Assets<Mesh>,
+    core_material: Handle<StandardMaterial>,
+    arm_material: Handle<StandardMaterial>,
+) {
+    let core = meshes.add(Sphere::new(0.35));
+    let segment = meshes.add(Cuboid::new(0.12, 0.04, 0.3));
+    commands
+        .spawn((
+            Transform::from_xyz(well.center.x, 2.0, well.center.y),
+            Visibility::default(),
+            GravityWellSwirl,
+        ))
+        .with_children(|swirl| {
+            swirl.spawn((Mesh3d(core), MeshMaterial3d(core_material)));
+            // Arms spiral inwards: each segment sits further round and closer to the core
+            for arm in 0..SWIRL_ARMS {
+                for step in 0..ARM_SEGMENTS {
+                    let t = step as f32 / ARM_SEGMENTS as f32;
+                    let angle = arm as f32 * TAU / SWIRL_ARMS as f32 + t * TAU * 0.4;
+                    let radius = CELL_WIDTH * (0.75 - 0.45 * t);
+                    swirl.spawn((
+                        Mesh3d(segment.clone()),
+                        MeshMaterial3d(arm_material.clone()),
+                        Transform::from_xyz(radius * angle.cos(), 0.0, radius * angle.sin())
+                            .with_rotation(Quat::from_rotation_y(-angle))
+                            .with_scale(Vec3::splat(1.0 - 0.5 * t)),
+                    ));
+                }
+            }
+        });
+}
+
+/// Rebuild [`GravityWells`] and their visuals for the newly loaded level.
+pub fn rebuild_gravity_wells(
+    mut commands: Commands,
+    current_level: Res<CurrentLevel>,
+    swirls: Query<Entity, With<GravityWellSwirl>>,
+    mut wells: ResMut<GravityWells>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    for entity in swirls.iter() {
+        commands.entity(entity).despawn();
+    }
+    *wells = GravityWells::from_defs(
+        &current_level.0.gravity_wells,
+        &current_level.0.matrix,
+        &current_level.0.grid(),
+    );
+
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    if wells.wells.is_empty() {
+        return;
+    }
+    let core_material = materials.add(StandardMaterial {
+        base_color: Color::BLACK,
+        unlit: true,
+        ..default()
+    });
+    let arm_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.6, 0.4, 1.0, 0.7),
+        emissive: LinearRgba::rgb(0.3, 0.15, 0.6),
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    let hazard_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.3, 0.2, 0.7),
+        emissive: LinearRgba::rgb(0.7, 0.1, 0.05),
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    for well in &wells.wells {
+        let arms = if well.consumes {
+            hazard_material.clone()
+        } else {
+            arm_material.clone()
+        };
+        spawn_well_visual(
+            &mut commands,
+            well,
+            &mut meshes,
+            core_material.clone(),
+            arms,
+        );
+    }
+}
+
+/// Pull balls towards the wells.
+pub fn apply_gravity_wells(
+    wells: Res<GravityWells>,
+    time: Res<Time>,
+    mut balls: Query<(&Transform, &mut Velocity), With<Ball>>,
+) {
+    if wells.wells.is_empty() {
+        return;
+    }
+    let dt = time.delta_secs();
+    for (transform, mut velocity) in balls.iter_mut() {
+        velocity.linvel += wells.pull_at(transform.translation) * dt;
+    }
+}
+
+/// Swallow balls that reached the centre of a consuming well.
+pub fn consume_balls_in_wells(
+    mut commands: Commands,
+    wells: Res<GravityWells>,
+    spawn_points: Res<SpawnPoints>,
+    balls: Query<(Entity, &Transform, Option<&RespawnHandle>), With<Ball>>,
+    mut life_lost_events: MessageWriter<LifeLostEvent>,
+) {
+    for (ball, transform, handle) in balls.iter() {
+        if !wells.swallows(transform.translation) {
+            continue;
+        }
+        info!("Ball {:?} swallowed by a gravity well", ball);
+        life_lost_events.write(LifeLostEvent {
+            ball,
+            cause: LifeLossCause::GravityWell,
+            ball_spawn: handle.map_or_else(|| spawn_points.ball_spawn(), |handle| handle.spawn),
+        });
+        commands.entity(ball).despawn();
+    }
+}
+
+/// Turn the well visuals.
+pub fn animate_gravity_wells(
+    time: Res<Time>,
+    mut swirls: Query<&mut Transform, With<GravityWellSwirl>>,
+) {
+    for mut transform in swirls.iter_mut() {
+        transform.rotate_y(-SWIRL_SPEED * time.delta_secs());
+    }
+}
+
+/// Plugin registering level gravity wells.
+pub struct GravityWellsPlugin;
+
+impl Plugin for GravityWellsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GravityWells>();
+        app.init_resource::<SpawnPoints>();
+        app.add_message::<LifeLostEvent>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_systems(
+            Update,
+            (
+                rebuild_gravity_wells.run_if(resource_exists_and_changed::<CurrentLevel>),
+                (
+                    apply_gravity_wells,
+                    consume_balls_in_wells,
+                    animate_gravity_wells,
+                )
+                    .run_if(crate::pause::not_paused),
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn well(consumes: bool) -> ActiveGravityWell {
+        ActiveGravityWell {
+            center: Vec2::ZERO,
+            strength: 16.0,
+            consumes,
+        }
+    }
+
+    #[test]
+    fn pull_follows_the_inverse_square_law() {
+        let well = well(false);
+        let near = well.pull_at(Vec3::new(2.0, 2.0, 0.0));
+        let far = well.pull_at(Vec3::new(4.0, 2.0, 0.0));
+        assert!((near - Vec3::new(-4.0, 0.0, 0.0)).length() < 1e-5);
+        assert!((far - Vec3::new(-1.0, 0.0, 0.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn pull_is_clamped_near_the_centre() {
+        let well = well(false);
+        let max = well.strength / (MIN_PULL_DISTANCE * MIN_PULL_DISTANCE);
+        let close = well.pull_at(Vec3::new(0.01, 2.0, 0.0));
+        assert!((close.length() - max).abs() < 1e-3);
+        assert_eq!(well.pull_at(Vec3::new(0.0, 2.0, 0.0)), Vec3::ZERO);
+    }
+
+    #[test]
+    fn only_consuming_wells_swallow_balls() {
+        let centre = Vec3::new(0.1, 2.0, 0.1);
+        assert!(well(true).swallows(centre));
+        assert!(!well(false).swallows(centre));
+        assert!(!well(true).swallows(Vec3::new(1.0, 2.0, 0.0)));
+    }
+
+    #[test]
+    fn invalid_wells_are_rejected() {
+        let matrix = vec![vec![0, 20]];
+        let def = |col, strength| GravityWellDef {
+            row: 0,
+            col,
+            strength,
+            consumes: false,
+        };
+        assert!(def(0, 10.0).validate(&matrix).is_ok());
+        assert!(
+            def(1, 10.0).validate(&matrix).is_err(),
+            "cell holds a brick"
+        );
+        assert!(def(0, 0.0).validate(&matrix).is_err());
+        assert!(def(0, f32::NAN).validate(&matrix).is_err());
+        assert!(def(25, 10.0).validate(&matrix).is_err());
+    }
+}
@@ -1,22 +1,39 @@
-//! Grid debug overlay system for visualizing the 20x20 game grid
+//! Grid debug overlay system for visualizing the game grid
 //!
 //! This module provides a wireframe grid overlay that is visible only when
-//! wireframe mode is enabled, helping with alignment and debugging.
+//! wireframe mode is enabled, helping with alignment and debugging. The overlay follows the
+//! resolution of the current level (20x20 unless the level declares another one).
 
 use bevy::prelude::*;
 
 #[cfg(not(target_arch = "wasm32"))]
 use bevy::pbr::wireframe::WireframeConfig;
 
-use crate::{GridOverlay, CELL_HEIGHT, CELL_WIDTH, GRID_HEIGHT, GRID_WIDTH, PLANE_H, PLANE_W};
+use crate::{GridOverlay, GridResolution, PLANE_H, PLANE_W};
 
-/// Spawns the 20x20 grid wireframe overlay
+/// Marker for the lines of the grid overlay (other debug visuals also carry [`GridOverlay`]).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GridLine;
+
+/// Spawns the grid wireframe overlay for the current [`GridResolution`], and rebuilds it when a
+/// level with another resolution is loaded.
 /// The grid is initially hidden and only becomes visible when wireframe mode is enabled
 pub fn spawn_grid_overlay(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    grid: Res<GridResolution>,
+    lines: Query<Entity, With<GridLine>>,
+    mut built: Local<Option<GridResolution>>,
 ) {
+    if *built == Some(*grid) {
+        return;
+    }
+    *built = Some(*grid);
+    for entity in lines.iter() {
+        commands.entity(entity).despawn();
+    }
+
     // Create a thin wireframe material for the grid lines
     let grid_material = materials.add(StandardMaterial {
         base_color: Color::srgba(0.5, 0.5, 0.5, 0.3),
@@ -31,31 +48,31 @@ pub fn spawn_grid_overlay(
     let start_z = -PLANE_W / 2.0; // Z-axis (horizontal on screen)
 
     // Create vertical lines (along X axis) - these span the height
-    for i in 0..=GRID_WIDTH {
-        let z_pos = start_z + (i as f32 * CELL_WIDTH);
-
-        let line_mesh = meshes.add(Cuboid::new(PLANE_H, 0.02, 0.02));
+    let line_mesh = meshes.add(Cuboid::new(PLANE_H, 0.02, 0.02));
+    for i in 0..=grid.cols {
+        let z_pos = start_z + (i as f32 * grid.cell_width());
 
         commands.spawn((
-            Mesh3d(line_mesh),
+            Mesh3d(line_mesh.clone()),
             MeshMaterial3d(grid_material.clone()),
             Transform::from_xyz(0.0, 2.0, z_pos),
             GridOverlay,
+            GridLine,
             Visibility::Hidden, // Initially hidden
         ));
     }
 
     // Create horizontal lines (along Z axis) - these span the width
-    for i in 0..=GRID_HEIGHT {
-        let x_pos = start_x + (i as f32 * CELL_HEIGHT);
-
-        let line_mesh = meshes.add(Cuboid::new(0.02, 0.02, PLANE_W));
+    let line_mesh = meshes.add(Cuboid::new(0.02, 0.02, PLANE_W));
+    for i in 0..=grid.rows {
+        let x_pos = start_x + (i as f32 * grid.cell_height());
 
         commands.spawn((
-            Mesh3d(line_mesh),
+            Mesh3d(line_mesh.clone()),
             MeshMaterial3d(grid_material.clone()),
             Transform::from_xyz(x_pos, 2.0, 0.0),
             GridOverlay,
+            GridLine,
             Visibility::Hidden, // Initially hidden
         ));
     }
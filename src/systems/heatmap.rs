@@ -0,0 +1,287 @@
+//! Ball position heatmap (level design dev tool).
+//!
+//! [`BallHeatmap`] accumulates how long balls spend over each part of the playing field, on a grid
+//! [`HEATMAP_SUBDIVISIONS`] times finer than the current level's grid. It keeps accumulating across
+//! restarts and lives lost, so a designer can play a level repeatedly and see which areas the ball
+//! rarely reaches; switching to a different level number starts a fresh map.
+//!
+//! With cheat mode active, `H` toggles an overlay that draws the map as a texture over the ground
+//! plane: dark blue for dead zones, through green and yellow, to red for the hottest cells.
+//! Frozen balls (level transitions) and paused frames are not recorded.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::level_loader::CurrentLevel;
+use crate::systems::cheat_mode::CheatModeState;
+use crate::{Ball, BallFrozen, GridResolution, PLANE_H, PLANE_W};
+
+/// Heatmap cells per level grid cell, along each axis.
+pub const HEATMAP_SUBDIVISIONS: usize = 2;
+
+/// Height of the overlay above the ground plane (above the floor zone decals).
+const OVERLAY_Y: f32 = 0.02;
+/// Opacity of the overlay.
+const OVERLAY_ALPHA: u8 = 170;
+
+/// Time balls have spent over each heatmap cell, in seconds.
+///
+/// Cells are stored row-major with `x` (the level grid's rows) varying fastest, matching the UV
+/// layout of the ground plane so the data can be used as a texture directly.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct BallHeatmap {
+    /// Level the data belongs to (`None` until a level loads)
+    pub level_number: Option<u32>,
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<f32>,
+}
+
+impl Default for BallHeatmap {
+    fn default() -> Self {
+        Self::for_grid(&GridResolution::default())
+    }
+}
+
+impl BallHeatmap {
+    /// Empty map [`HEATMAP_SUBDIVISIONS`] times finer than `grid`.
+    pub fn for_grid(grid: &GridResolution) -> Self {
+        let width = grid.rows * HEATMAP_SUBDIVISIONS;
+        let height = grid.cols * HEATMAP_SUBDIVISIONS;
+        Self {
+            level_number: None,
+            width,
+            height,
+            cells: vec![0.0; width * height],
+        }
+    }
+
+    /// Cell `(x, z)` under a world position, if it lies on the playing field.
+    pub fn cell_at(&self, translation: Vec3) -> Option<(usize, usize)> {
+        let u = translation.x / PLANE_H + 0.5;
+        let v = translation.z / PLANE_W + 0.5;
+        if !(0.0..1.0).contains(&u) || !(0.0..1.0).contains(&v) {
+            return None;
+        }
+        Some((
+            (u * self.width as f32) as usize,
+            (v * self.height as f32) as usize,
+        ))
+    }
+
+    /// Accumulated time over cell `(x, z)`.
+    pub fn value(&self, x: usize, z: usize) -> f32 {
+        self.cells[z * self.width + x]
+    }
+
+    /// Add `seconds` to the cell under `translation`. Positions off the field are ignored.
+    pub fn record(&mut self, translation: Vec3, seconds: f32) {
+        if let Some((x, z)) = self.cell_at(translation) {
+            self.cells[z * self.width + x] += seconds;
+        }
+    }
+
+    /// Forget all samples.
+    pub fn clear(&mut self) {
+        self.cells.fill(0.0);
+    }
+
+    /// Render the map as RGBA8 pixels, normalised to the hottest cell.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        let max = self.cells.iter().copied().fold(0.0, f32::max);
+        self.cells
+            .iter()
+            .flat_map(|&value| {
+                // sqrt spreads out the low end, where dead zones live
+                let t = if max > 0.0 { (value / max).sqrt() } else { 0.0 };
+                heat_color(t)
+            })
+            .collect()
+    }
+}
+
+/// Colour ramp for `t` in `0..=1`: blue, green, yellow, red.
+pub fn heat_color(t: f32) -> [u8; 4] {
+    let t = t.clamp(0.0, 1.0);
+    let (r, g, b) = if t < 0.5 {
+        let k = t / 0.5;
+        (0.0, k, 0.5 * (1.0 - k))
+    } else if t < 0.75 {
+        ((t - 0.5) / 0.25, 1.0, 0.0)
+    } else {
+        (1.0, 1.0 - (t - 0.75) / 0.25, 0.0)
+    };
+    [
+        (r * 255.0) as u8,
+        (g * 255.0) as u8,
+        (b * 255.0) as u8,
+        OVERLAY_ALPHA,
+    ]
+}
+
+/// Overlay plane showing the heatmap texture.
+#[derive(Component, Debug, Clone)]
+pub struct HeatmapOverlay {
+    pub image: Handle<Image>,
+}
+
+/// Accumulate the time each moving ball spends over the field.
+pub fn record_ball_positions(
+    time: Res<Time>,
+    current_level: Option<Res<CurrentLevel>>,
+    balls: Query<&Transform, (With<Ball>, Without<BallFrozen>)>,
+    mut heatmap: ResMut<BallHeatmap>,
+) {
+    let level_number = current_level.as_ref().map(|l| l.0.number);
+    if heatmap.level_number != level_number {
+        let grid = current_level.map(|l| l.0.grid()).unwrap_or_default();
+        *heatmap = BallHeatmap::for_grid(&grid);
+        heatmap.level_number = level_number;
+    }
+    let dt = time.delta_secs();
+    if dt <= 0.0 || balls.is_empty() {
+        return;
+    }
+    for transform in balls.iter() {
+        heatmap.record(transform.translation, dt);
+    }
+}
+
+/// Toggle the overlay with `H` while cheat mode is active, spawning it on first use.
+pub fn toggle_heatmap_overlay(
+    mut commands: Commands,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    cheat_mode: Option<Res<CheatModeState>>,
+    heatmap: Res<BallHeatmap>,
+    mut overlays: Query<&mut Visibility, With<HeatmapOverlay>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let cheat_active = cheat_mode.is_some_and(|c| c.is_active());
+    if !cheat_active || !keyboard.is_some_and(|k| k.just_pressed(KeyCode::KeyH)) {
+        return;
+    }
+    if let Ok(mut visibility) = overlays.single_mut() {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+        return;
+    }
+
+    let mut image = Image::new(
+        Extent3d {
+            width: heatmap.width as u32,
+            height: heatmap.height as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        heatmap.to_rgba(),
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    // Keep cells crisp rather than smearing them together
+    image.sampler = ImageSampler::nearest();
+    let image = images.add(image);
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(PLANE_H, PLANE_W))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color_texture: Some(image.clone()),
+            unlit: true,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        })),
+        Transform::from_xyz(0.0, OVERLAY_Y, 0.0),
+        Visibility::Visible,
+        HeatmapOverlay { image },
+    ));
+}
+
+/// Copy the latest data into the overlay texture while it is visible.
+pub fn refresh_heatmap_texture(
+    heatmap: Res<BallHeatmap>,
+    overlays: Query<(&HeatmapOverlay, Ref<Visibility>)>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for (overlay, visibility) in overlays.iter() {
+        // Also refresh right after the overlay is shown, even if no ball moved since
+        if *visibility == Visibility::Hidden || !(heatmap.is_changed() || visibility.is_changed()) {
+            continue;
+        }
+        if let Some(image) = images.get_mut(&overlay.image) {
+            // A level with a different grid size brings a differently sized map
+            let size = Extent3d {
+                width: heatmap.width as u32,
+                height: heatmap.height as u32,
+                depth_or_array_layers: 1,
+            };
+            if image.texture_descriptor.size != size {
+                image.resize(size);
+            }
+            image.data = Some(heatmap.to_rgba());
+        }
+    }
+}
+
+/// Plugin registering the ball position heatmap.
+pub struct HeatmapPlugin;
+
+impl Plugin for HeatmapPlugin {
+    fn build(&self, app: &mut App) {
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.init_resource::<BallHeatmap>();
+        app.add_systems(
+            Update,
+            (
+                record_ball_positions.run_if(crate::pause::not_paused),
+                toggle_heatmap_overlay,
+                refresh_heatmap_texture,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_map_to_cells_across_the_field() {
+        let heatmap = BallHeatmap::default();
+        assert_eq!(heatmap.width, 40);
+        assert_eq!(heatmap.height, 40);
+        assert_eq!(heatmap.cell_at(Vec3::ZERO), Some((20, 20)));
+        assert_eq!(
+            heatmap.cell_at(Vec3::new(-PLANE_H / 2.0, 0.0, -PLANE_W / 2.0)),
+            Some((0, 0))
+        );
+        assert_eq!(
+            heatmap.cell_at(Vec3::new(PLANE_H / 2.0 - 0.01, 0.0, PLANE_W / 2.0 - 0.01)),
+            Some((39, 39))
+        );
+        assert_eq!(heatmap.cell_at(Vec3::new(PLANE_H, 0.0, 0.0)), None);
+
+        // Finer levels get a finer map over the same field
+        let fine = BallHeatmap::for_grid(&GridResolution::new(40, 30));
+        assert_eq!((fine.width, fine.height), (80, 60));
+        assert_eq!(fine.cell_at(Vec3::ZERO), Some((40, 30)));
+    }
+
+    #[test]
+    fn texture_is_normalised_to_the_hottest_cell() {
+        let mut heatmap = BallHeatmap::default();
+        heatmap.record(Vec3::ZERO, 2.0);
+        let pixels = heatmap.to_rgba();
+        assert_eq!(pixels.len(), 40 * 40 * 4);
+        let hot = (20 * 40 + 20) * 4;
+        assert_eq!(&pixels[hot..hot + 4], &heat_color(1.0));
+        assert_eq!(&pixels[0..4], &heat_color(0.0));
+        assert_eq!(heat_color(0.0)[2], 127, "dead zones are dark blue");
+        assert_eq!(heat_color(1.0)[..3], [255, 0, 0]);
+    }
+}
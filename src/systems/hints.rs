@@ -0,0 +1,252 @@
+//! Adaptive hints.
+//!
+//! Gameplay statistics are watched for patterns of repeated failure, and a matching tip is shown
+//! as a toast (`ui::toasts`) the first time a pattern shows up on a level:
+//! - [`Hint::RepeatedLosses`]: [`LOSSES_BEFORE_HINT`] balls lost on the same level.
+//! - [`Hint::ShallowBounces`]: [`SHALLOW_BOUNCES_BEFORE_HINT`] wall bounces of a ball crossing the
+//!   field almost sideways (less than [`SHALLOW_BOUNCE_DEG`] towards the bricks or the goal).
+//!
+//! Counters start over when another level is loaded; restarting the same level keeps them. Each
+//! toast offers "Don't show again", which is remembered in [`HintSettings`]: `config/hints.ron`, or
+//! `hints.ron` in the active profile's directory (`systems::profiles`).
+
+use std::path::PathBuf;
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+use serde::{Deserialize, Serialize};
+
+use crate::level_loader::CurrentLevel;
+use crate::signals::BallWallHit;
+use crate::systems::profiles::{profile_path, profile_switched, CurrentProfile};
+use crate::systems::respawn::LifeLostEvent;
+use crate::systems::web_storage::{read_save_file, write_save_file};
+use crate::ui::toasts::ShowToast;
+use crate::Ball;
+
+/// Path of the hint settings while playing as a guest.
+pub const HINTS_PATH: &str = "config/hints.ron";
+/// File name of the hint settings in a profile directory.
+pub const HINTS_FILE: &str = "hints.ron";
+/// Balls lost on one level before [`Hint::RepeatedLosses`] is shown.
+pub const LOSSES_BEFORE_HINT: u32 = 3;
+/// Shallow bounces on one level before [`Hint::ShallowBounces`] is shown.
+pub const SHALLOW_BOUNCES_BEFORE_HINT: u32 = 8;
+/// Paths closer than this to the paddle's line (degrees) count as shallow.
+pub const SHALLOW_BOUNCE_DEG: f32 = 20.0;
+
+/// A tip shown when the player keeps running into the same trouble.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Hint {
+    RepeatedLosses,
+    ShallowBounces,
+}
+
+impl Hint {
+    pub fn text(self) -> &'static str {
+        match self {
+            Hint::RepeatedLosses => {
+                "Tip: keep the paddle under the ball and move it as the ball arrives to steer the \
+                 bounce. Press F1 for all controls."
+            }
+            Hint::ShallowBounces => {
+                "Tip: a ball crawling sideways wastes time. Hit it with a still paddle for a \
+                 steeper return."
+            }
+        }
+    }
+}
+
+/// Hints the player asked not to see again.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HintSettings {
+    #[serde(default)]
+    pub dismissed: Vec<Hint>,
+}
+
+impl HintSettings {
+    pub fn is_dismissed(&self, hint: Hint) -> bool {
+        self.dismissed.contains(&hint)
+    }
+
+    pub fn dismiss(&mut self, hint: Hint) {
+        if !self.is_dismissed(hint) {
+            self.dismissed.push(hint);
+        }
+    }
+}
+
+/// Failure counters of the level being played.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct HintStats {
+    /// Number of the level the counters belong to
+    pub level: Option<u32>,
+    pub losses: u32,
+    pub shallow_bounces: u32,
+    /// Hints already shown on this level
+    pub shown: Vec<Hint>,
+}
+
+impl HintStats {
+    /// Hints whose pattern was reached and that were not shown on this level yet.
+    pub fn due(&self) -> impl Iterator<Item = Hint> + '_ {
+        [
+            (Hint::RepeatedLosses, self.losses >= LOSSES_BEFORE_HINT),
+            (
+                Hint::ShallowBounces,
+                self.shallow_bounces >= SHALLOW_BOUNCES_BEFORE_HINT,
+            ),
+        ]
+        .into_iter()
+        .filter(|(hint, reached)| *reached && !self.shown.contains(hint))
+        .map(|(hint, _)| hint)
+    }
+}
+
+/// Whether a ball moving at `velocity` crosses the field almost sideways, i.e. nearly parallel to
+/// the paddle's line (the Z axis).
+pub fn is_shallow(velocity: Vec3) -> bool {
+    let planar = Vec2::new(velocity.x, velocity.z);
+    let speed = planar.length();
+    speed > f32::EPSILON && planar.x.abs() / speed < SHALLOW_BOUNCE_DEG.to_radians().sin()
+}
+
+/// Path of the hint settings of `profile` (or the shared one for guests).
+pub fn hints_path(profile: Option<&CurrentProfile>) -> PathBuf {
+    profile_path(profile, HINTS_FILE, HINTS_PATH)
+}
+
+/// Load the hint settings of the active profile.
+pub fn load_hint_settings(
+    mut settings: ResMut<HintSettings>,
+    profile: Option<Res<CurrentProfile>>,
+) {
+    let path = hints_path(profile.as_deref());
+    let loaded = read_save_file(&path, |content| {
+        ron::de::from_str(content)
+            .map_err(|e| warn!("Failed to parse {}: {}", path.display(), e))
+            .ok()
+    })
+    .unwrap_or_default();
+    // Loading is not a change to save back
+    *settings.bypass_change_detection() = loaded;
+}
+
+/// Save the hint settings when a hint is dismissed.
+pub fn save_hint_settings_on_change(
+    settings: Res<HintSettings>,
+    profile: Option<Res<CurrentProfile>>,
+) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+    let path = hints_path(profile.as_deref());
+    let result = ron::ser::to_string_pretty(&*settings, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("failed to serialize hint settings: {e}"))
+        .and_then(|content| write_save_file(&path, &content));
+    if let Err(e) = result {
+        warn!("Failed to save hint settings: {}", e);
+    }
+}
+
+/// Start counting again when another level is loaded.
+pub fn reset_hint_stats(current_level: Option<Res<CurrentLevel>>, mut stats: ResMut<HintStats>) {
+    let Some(current_level) = current_level.filter(|level| level.is_changed()) else {
+        return;
+    };
+    let number = current_level.0.number;
+    if stats.level != Some(number) {
+        *stats = HintStats {
+            level: Some(number),
+            ..default()
+        };
+    }
+}
+
+/// Count lost balls.
+pub fn count_lost_balls(mut losses: MessageReader<LifeLostEvent>, mut stats: ResMut<HintStats>) {
+    let lost = losses.read().count() as u32;
+    if lost > 0 {
+        stats.losses += lost;
+    }
+}
+
+/// Count wall bounces of balls crossing the field almost sideways.
+pub fn count_shallow_bounces(
+    trigger: On<BallWallHit>,
+    balls: Query<&Velocity, With<Ball>>,
+    stats: Option<ResMut<HintStats>>,
+) {
+    let (Some(mut stats), Ok(velocity)) = (stats, balls.get(trigger.event().ball_entity)) else {
+        return;
+    };
+    if is_shallow(velocity.linvel) {
+        stats.shallow_bounces += 1;
+    }
+}
+
+/// Show the hints that became due, unless the player dismissed them for good.
+pub fn show_due_hints(
+    mut commands: Commands,
+    mut stats: ResMut<HintStats>,
+    settings: Res<HintSettings>,
+) {
+    let due: Vec<Hint> = stats.due().collect();
+    for hint in due {
+        stats.shown.push(hint);
+        if !settings.is_dismissed(hint) {
+            commands.trigger(ShowToast::hint(hint));
+        }
+    }
+}
+
+/// Plugin registering adaptive hints.
+pub struct HintsPlugin;
+
+impl Plugin for HintsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HintSettings>();
+        app.init_resource::<HintStats>();
+        app.add_message::<LifeLostEvent>();
+        app.add_systems(Startup, load_hint_settings);
+        app.add_systems(
+            Update,
+            (
+                load_hint_settings.run_if(profile_switched),
+                reset_hint_stats,
+                count_lost_balls,
+                show_due_hints,
+                save_hint_settings_on_change,
+            )
+                .chain(),
+        );
+        app.add_observer(count_shallow_bounces);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sideways_paths_are_shallow() {
+        assert!(is_shallow(Vec3::new(0.1, 0.0, 5.0)));
+        assert!(is_shallow(Vec3::new(-0.5, 2.0, -5.0)));
+        assert!(!is_shallow(Vec3::new(3.0, 0.0, 5.0)));
+        assert!(!is_shallow(Vec3::new(-5.0, 0.0, 0.0)));
+        assert!(!is_shallow(Vec3::ZERO));
+    }
+
+    #[test]
+    fn hints_are_due_once_per_level() {
+        let mut stats = HintStats {
+            losses: LOSSES_BEFORE_HINT,
+            ..default()
+        };
+        assert_eq!(stats.due().collect::<Vec<_>>(), vec![Hint::RepeatedLosses]);
+        stats.shown.push(Hint::RepeatedLosses);
+        stats.losses += 1;
+        assert_eq!(stats.due().count(), 0);
+    }
+}
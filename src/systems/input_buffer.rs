@@ -0,0 +1,199 @@
+//! Paddle input buffering during the respawn lockout.
+//!
+//! While the paddle carries `InputLocked` (after a lost ball, until the respawn settles)
+//! `move_paddle` ignores the mouse, so a player who starts steering a moment early used to have
+//! that movement thrown away. [`buffer_locked_input`] keeps the movement the mouse asked for over
+//! the last [`INPUT_BUFFER_SECS`]. When the lock is lifted, [`start_catch_up`] hands it to the
+//! paddle as a [`PaddleCatchUp`], which `move_paddle` plays out over about [`CATCH_UP_SECS`],
+//! fast at first and easing in at the end.
+//!
+//! Only recent movement counts: steering that stopped more than [`INPUT_BUFFER_SECS`] before the
+//! lock ends is dropped, as before.
+
+use std::collections::VecDeque;
+
+use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::prelude::*;
+
+use crate::systems::powerups::ReversedControls;
+use crate::systems::respawn::{InputLocked, RespawnSystems};
+use crate::{paddle_mouse_translation, Paddle};
+
+/// How much locked input is kept, in seconds.
+pub const INPUT_BUFFER_SECS: f32 = 0.2;
+/// How long the catch-up takes, in seconds.
+pub const CATCH_UP_SECS: f32 = 0.15;
+/// Share of the remaining catch-up covered per second (exponential ease-out).
+const CATCH_UP_RATE: f32 = 30.0;
+
+/// Paddle movement asked for while input was locked, with the time it was asked for.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PaddleInputBuffer {
+    samples: VecDeque<(f32, Vec3)>,
+}
+
+impl PaddleInputBuffer {
+    /// Record `translation` at `now` and forget anything older than the buffer window.
+    pub fn push(&mut self, now: f32, translation: Vec3) {
+        if translation != Vec3::ZERO {
+            self.samples.push_back((now, translation));
+        }
+        while self
+            .samples
+            .front()
+            .is_some_and(|(time, _)| now - time > INPUT_BUFFER_SECS)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Total buffered translation; empties the buffer.
+    pub fn take(&mut self) -> Vec3 {
+        self.samples
+            .drain(..)
+            .map(|(_, translation)| translation)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+/// Buffered movement the paddle still has to make after its input was unlocked.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PaddleCatchUp {
+    pub remaining: Vec3,
+    /// Seconds left; whatever remains then is applied at once.
+    pub time_left: f32,
+}
+
+impl PaddleCatchUp {
+    pub fn new(translation: Vec3) -> Self {
+        Self {
+            remaining: translation,
+            time_left: CATCH_UP_SECS,
+        }
+    }
+
+    /// Translation to apply this frame.
+    pub fn step(&mut self, delta_secs: f32) -> Vec3 {
+        self.time_left -= delta_secs;
+        let step = if self.time_left <= 0.0 {
+            self.remaining
+        } else {
+            self.remaining * (1.0 - (-CATCH_UP_RATE * delta_secs).exp())
+        };
+        self.remaining -= step;
+        step
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.time_left <= 0.0
+    }
+}
+
+/// Record the paddle movement the mouse asks for while the paddle is locked.
+pub fn buffer_locked_input(
+    mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+    paddles: Query<Has<ReversedControls>, (With<Paddle>, With<InputLocked>)>,
+    time: Res<Time>,
+    mut buffer: ResMut<PaddleInputBuffer>,
+) {
+    let Some(motion) = mouse_motion else {
+        return;
+    };
+    let Some(reversed) = paddles.iter().next() else {
+        return;
+    };
+    let delta_secs = time.delta_secs();
+    if delta_secs <= 0.0 {
+        return;
+    }
+    let translation = paddle_mouse_translation(motion.delta, delta_secs, reversed);
+    buffer.push(time.elapsed_secs(), translation);
+}
+
+/// Start the catch-up once the paddle's input is unlocked.
+pub fn start_catch_up(
+    unlocked: On<Remove, InputLocked>,
+    paddles: Query<(), With<Paddle>>,
+    time: Res<Time>,
+    mut buffer: ResMut<PaddleInputBuffer>,
+    mut commands: Commands,
+) {
+    let paddle = unlocked.event().entity;
+    if paddles.get(paddle).is_err() {
+        return;
+    }
+    // Drop movement that went stale while the lock lasted
+    buffer.push(time.elapsed_secs(), Vec3::ZERO);
+    let translation = buffer.take();
+    if translation != Vec3::ZERO {
+        commands
+            .entity(paddle)
+            .try_insert(PaddleCatchUp::new(translation));
+    }
+}
+
+/// Remove finished catch-ups.
+pub fn finish_catch_up(mut commands: Commands, catch_ups: Query<(Entity, &PaddleCatchUp)>) {
+    for (entity, catch_up) in catch_ups.iter() {
+        if catch_up.is_finished() {
+            commands.entity(entity).remove::<PaddleCatchUp>();
+        }
+    }
+}
+
+/// Plugin registering paddle input buffering.
+pub struct InputBufferPlugin;
+
+impl Plugin for InputBufferPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PaddleInputBuffer>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_observer(start_catch_up);
+        app.add_systems(
+            Update,
+            (
+                buffer_locked_input
+                    .before(RespawnSystems::Control)
+                    .run_if(crate::pause::not_paused),
+                finish_catch_up,
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffer_keeps_only_the_last_window() {
+        let mut buffer = PaddleInputBuffer::default();
+        buffer.push(0.0, Vec3::X);
+        buffer.push(0.1, Vec3::X);
+        buffer.push(0.25, Vec3::Z);
+        assert_eq!(buffer.take(), Vec3::X + Vec3::Z);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn catch_up_eases_out_and_lands_exactly() {
+        let target = Vec3::new(0.0, 0.0, -3.0);
+        let mut catch_up = PaddleCatchUp::new(target);
+        let mut moved = Vec3::ZERO;
+        let mut steps = Vec::new();
+        while !catch_up.is_finished() {
+            let step = catch_up.step(1.0 / 60.0);
+            steps.push(step.length());
+            moved += step;
+        }
+        assert!((moved - target).length() < 1e-5);
+        assert_eq!(catch_up.remaining, Vec3::ZERO);
+        // Largest steps first
+        assert!(steps[0] > steps[steps.len() / 2]);
+    }
+}
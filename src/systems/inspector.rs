@@ -0,0 +1,472 @@
+//! Entity inspector: live editing of key resources and of the ball or paddle, to shorten the
+//! tuning loop.
+//!
+//! With cheat mode active (`G`), `I` opens the inspector panel (`ui::inspector`):
+//! - Up/Down pick a field, Left/Right lower or raise it (hold Shift for ten times the step)
+//! - `[`/`]` cycle the selected entity through the balls and the paddle
+//!
+//! Editable fields are listed in [`INSPECTOR_FIELDS`]: the live and level gravity
+//! (`GravityConfiguration`, `GravityConfig`), ball and paddle physics tuning (applied to the config
+//! and to the live bodies), the remaining lives and the selected entity's velocity. The panel also
+//! shows the selected entity's position and the active size effects. Tuning values are validated
+//! like the physics configs; invalid edits are rejected.
+//!
+//! Clicking a brick while the panel is open inspects it: the panel shows its type, grid cell and
+//! position. The brick is found by a camera ray against the brick colliders (`systems::picking`).
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::physics_config::{BallPhysicsConfig, PaddlePhysicsConfig};
+use crate::systems::ball_size::BallSizeEffect;
+use crate::systems::cheat_mode::CheatModeState;
+use crate::systems::paddle_size::PaddleSizeEffect;
+use crate::systems::picking::BrickPicker;
+use crate::systems::respawn::LivesState;
+use crate::ui::focus::{step_focus, FocusMove};
+use crate::{Ball, Brick, BrickGridPos, BrickTypeId, GravityConfig, GravityConfiguration, Paddle};
+
+/// A value the inspector can edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InspectorField {
+    GravityX,
+    GravityZ,
+    LevelGravityX,
+    LevelGravityZ,
+    BallRestitution,
+    BallFriction,
+    BallLinearDamping,
+    PaddleRestitution,
+    PaddleFriction,
+    Lives,
+    VelocityX,
+    VelocityZ,
+}
+
+/// Fields in panel order.
+pub const INSPECTOR_FIELDS: [InspectorField; 12] = [
+    InspectorField::GravityX,
+    InspectorField::GravityZ,
+    InspectorField::LevelGravityX,
+    InspectorField::LevelGravityZ,
+    InspectorField::BallRestitution,
+    InspectorField::BallFriction,
+    InspectorField::BallLinearDamping,
+    InspectorField::PaddleRestitution,
+    InspectorField::PaddleFriction,
+    InspectorField::Lives,
+    InspectorField::VelocityX,
+    InspectorField::VelocityZ,
+];
+
+impl InspectorField {
+    pub fn label(self) -> &'static str {
+        match self {
+            InspectorField::GravityX => "Gravity X",
+            InspectorField::GravityZ => "Gravity Z",
+            InspectorField::LevelGravityX => "Level gravity X",
+            InspectorField::LevelGravityZ => "Level gravity Z",
+            InspectorField::BallRestitution => "Ball restitution",
+            InspectorField::BallFriction => "Ball friction",
+            InspectorField::BallLinearDamping => "Ball linear damping",
+            InspectorField::PaddleRestitution => "Paddle restitution",
+            InspectorField::PaddleFriction => "Paddle friction",
+            InspectorField::Lives => "Lives",
+            InspectorField::VelocityX => "Selected velocity X",
+            InspectorField::VelocityZ => "Selected velocity Z",
+        }
+    }
+
+    /// Change per Left/Right press.
+    pub fn step(self) -> f32 {
+        match self {
+            InspectorField::GravityX
+            | InspectorField::GravityZ
+            | InspectorField::LevelGravityX
+            | InspectorField::LevelGravityZ
+            | InspectorField::VelocityX
+            | InspectorField::VelocityZ
+            | InspectorField::Lives => 1.0,
+            InspectorField::BallRestitution
+            | InspectorField::BallFriction
+            | InspectorField::BallLinearDamping
+            | InspectorField::PaddleRestitution
+            | InspectorField::PaddleFriction => 0.05,
+        }
+    }
+}
+
+/// State of the inspector panel.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct InspectorState {
+    pub open: bool,
+    /// Index into [`INSPECTOR_FIELDS`]
+    pub field: usize,
+    /// Ball or paddle whose components are shown and edited
+    pub selected: Option<Entity>,
+    /// Brick last clicked while the panel was open
+    pub brick: Option<Entity>,
+    /// Panel text, rebuilt while open
+    pub text: String,
+}
+
+/// Resources and components the inspector reads and edits.
+#[derive(SystemParam)]
+pub(crate) struct InspectorTargets<'w, 's> {
+    gravity: Option<ResMut<'w, GravityConfiguration>>,
+    level_gravity: Option<ResMut<'w, GravityConfig>>,
+    ball_config: Option<ResMut<'w, BallPhysicsConfig>>,
+    paddle_config: Option<ResMut<'w, PaddlePhysicsConfig>>,
+    lives: Option<ResMut<'w, LivesState>>,
+    balls: Query<
+        'w,
+        's,
+        (
+            Option<&'static mut Restitution>,
+            Option<&'static mut Friction>,
+            Option<&'static mut Damping>,
+        ),
+        (With<Ball>, Without<Paddle>),
+    >,
+    paddles: Query<
+        'w,
+        's,
+        (
+            Option<&'static mut Restitution>,
+            Option<&'static mut Friction>,
+        ),
+        (With<Paddle>, Without<Ball>),
+    >,
+    bodies: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Transform,
+            Option<&'static mut Velocity>,
+            Has<Ball>,
+            Option<&'static BallSizeEffect>,
+            Option<&'static PaddleSizeEffect>,
+        ),
+        Or<(With<Ball>, With<Paddle>)>,
+    >,
+    bricks: Query<
+        'w,
+        's,
+        (
+            &'static BrickTypeId,
+            Option<&'static BrickGridPos>,
+            &'static GlobalTransform,
+        ),
+        With<Brick>,
+    >,
+}
+
+impl InspectorTargets<'_, '_> {
+    /// Current value of `field`, if its resource or entity exists.
+    pub fn get(&self, field: InspectorField, selected: Option<Entity>) -> Option<f32> {
+        let velocity = || {
+            selected
+                .and_then(|entity| self.bodies.get(entity).ok())
+                .and_then(|(_, _, velocity, ..)| velocity.map(|v| v.linvel))
+        };
+        match field {
+            InspectorField::GravityX => self.gravity.as_ref().map(|g| g.current.x),
+            InspectorField::GravityZ => self.gravity.as_ref().map(|g| g.current.z),
+            InspectorField::LevelGravityX => self.level_gravity.as_ref().map(|g| g.normal.x),
+            InspectorField::LevelGravityZ => self.level_gravity.as_ref().map(|g| g.normal.z),
+            InspectorField::BallRestitution => self.ball_config.as_ref().map(|c| c.restitution),
+            InspectorField::BallFriction => self.ball_config.as_ref().map(|c| c.friction),
+            InspectorField::BallLinearDamping => {
+                self.ball_config.as_ref().map(|c| c.linear_damping)
+            }
+            InspectorField::PaddleRestitution => self.paddle_config.as_ref().map(|c| c.restitution),
+            InspectorField::PaddleFriction => self.paddle_config.as_ref().map(|c| c.friction),
+            InspectorField::Lives => self.lives.as_ref().map(|l| f32::from(l.lives_remaining)),
+            InspectorField::VelocityX => velocity().map(|v| v.x),
+            InspectorField::VelocityZ => velocity().map(|v| v.z),
+        }
+    }
+
+    /// Set `field` to `value`. Tuning values that fail validation are rejected.
+    pub fn set(
+        &mut self,
+        field: InspectorField,
+        selected: Option<Entity>,
+        value: f32,
+    ) -> Result<(), String> {
+        match field {
+            InspectorField::GravityX | InspectorField::GravityZ => {
+                let gravity = self.gravity.as_mut().ok_or("no gravity configuration")?;
+                if field == InspectorField::GravityX {
+                    gravity.current.x = value;
+                } else {
+                    gravity.current.z = value;
+                }
+            }
+            InspectorField::LevelGravityX | InspectorField::LevelGravityZ => {
+                let gravity = self.level_gravity.as_mut().ok_or("no level gravity")?;
+                if field == InspectorField::LevelGravityX {
+                    gravity.normal.x = value;
+                } else {
+                    gravity.normal.z = value;
+                }
+            }
+            InspectorField::BallRestitution
+            | InspectorField::BallFriction
+            | InspectorField::BallLinearDamping => {
+                let config = self.ball_config.as_mut().ok_or("no ball physics config")?;
+                let mut edited = (**config).clone();
+                match field {
+                    InspectorField::BallRestitution => edited.restitution = value,
+                    InspectorField::BallFriction => edited.friction = value,
+                    _ => edited.linear_damping = value,
+                }
+                edited.validate()?;
+                for (restitution, friction, damping) in self.balls.iter_mut() {
+                    if let Some(mut restitution) = restitution {
+                        restitution.coefficient = edited.restitution;
+                    }
+                    if let Some(mut friction) = friction {
+                        friction.coefficient = edited.friction;
+                    }
+                    if let Some(mut damping) = damping {
+                        damping.linear_damping = edited.linear_damping;
+                    }
+                }
+                **config = edited;
+            }
+            InspectorField::PaddleRestitution | InspectorField::PaddleFriction => {
+                let config = self
+                    .paddle_config
+                    .as_mut()
+                    .ok_or("no paddle physics config")?;
+                let mut edited = (**config).clone();
+                if field == InspectorField::PaddleRestitution {
+                    edited.restitution = value;
+                } else {
+                    edited.friction = value;
+                }
+                edited.validate()?;
+                for (restitution, friction) in self.paddles.iter_mut() {
+                    if let Some(mut restitution) = restitution {
+                        restitution.coefficient = edited.restitution;
+                    }
+                    if let Some(mut friction) = friction {
+                        friction.coefficient = edited.friction;
+                    }
+                }
+                **config = edited;
+            }
+            InspectorField::Lives => {
+                let lives = self.lives.as_mut().ok_or("no lives state")?;
+                lives.lives_remaining = value.round().clamp(0.0, f32::from(u8::MAX)) as u8;
+            }
+            InspectorField::VelocityX | InspectorField::VelocityZ => {
+                let (_, _, velocity, ..) = selected
+                    .and_then(|entity| self.bodies.get_mut(entity).ok())
+                    .ok_or("no entity selected")?;
+                let mut velocity = velocity.ok_or("selected entity has no velocity")?;
+                if field == InspectorField::VelocityX {
+                    velocity.linvel.x = value;
+                } else {
+                    velocity.linvel.z = value;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Balls and paddles that can be selected, in a stable order.
+    pub fn selectable(&self) -> Vec<Entity> {
+        let mut entities: Vec<Entity> = self.bodies.iter().map(|(entity, ..)| entity).collect();
+        entities.sort();
+        entities
+    }
+
+    /// Panel text: every field with its value, the selected field marked, then the selected
+    /// entity and the active size effects.
+    pub fn describe(&self, state: &InspectorState) -> String {
+        let mut text =
+            String::from("INSPECTOR  [Up/Down] field  [Left/Right] edit  [ [ ] ] select\n");
+        for (index, field) in INSPECTOR_FIELDS.iter().enumerate() {
+            let marker = if index == state.field { '>' } else { ' ' };
+            let value = self
+                .get(*field, state.selected)
+                .map_or_else(|| "-".to_string(), |value| format!("{value:.2}"));
+            text.push_str(&format!("{marker} {}: {value}\n", field.label()));
+        }
+        match state
+            .selected
+            .and_then(|entity| self.bodies.get(entity).ok())
+        {
+            Some((entity, transform, velocity, is_ball, ..)) => {
+                let kind = if is_ball { "Ball" } else { "Paddle" };
+                let position = transform.translation;
+                text.push_str(&format!(
+                    "{kind} {entity}: position ({:.2}, {:.2}, {:.2})",
+                    position.x, position.y, position.z
+                ));
+                if let Some(velocity) = velocity {
+                    text.push_str(&format!(", speed {:.2}", velocity.linvel.length()));
+                }
+                text.push('\n');
+            }
+            None => text.push_str("No entity selected\n"),
+        }
+        if let Some((brick, (type_id, cell, transform))) = state
+            .brick
+            .and_then(|brick| self.bricks.get(brick).ok().map(|data| (brick, data)))
+        {
+            let position = transform.translation();
+            let cell = cell.map_or_else(
+                || "-".to_string(),
+                |cell| format!("({}, {})", cell.row, cell.col),
+            );
+            text.push_str(&format!(
+                "Brick {brick}: type {}, cell {cell}, position ({:.2}, {:.2}, {:.2})\n",
+                type_id.0, position.x, position.y, position.z
+            ));
+        }
+        let effects: Vec<String> = self
+            .bodies
+            .iter()
+            .flat_map(|(entity, _, _, _, ball_effect, paddle_effect)| {
+                let ball = ball_effect.map(|effect| {
+                    format!(
+                        "ball {entity} {:?} {:.1}s",
+                        effect.effect_type,
+                        effect.timer.remaining_secs()
+                    )
+                });
+                let paddle = paddle_effect.map(|effect| {
+                    format!(
+                        "paddle {entity} {:?} {:.1}s",
+                        effect.effect_type,
+                        effect.timer.remaining_secs()
+                    )
+                });
+                ball.into_iter().chain(paddle)
+            })
+            .collect();
+        if effects.is_empty() {
+            text.push_str("Effects: none");
+        } else {
+            text.push_str(&format!("Effects: {}", effects.join(", ")));
+        }
+        text
+    }
+}
+
+/// Open and close the inspector with `I` while cheat mode is active.
+pub fn toggle_inspector(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    cheat: Res<CheatModeState>,
+    mut inspector: ResMut<InspectorState>,
+) {
+    if !cheat.is_active() {
+        if inspector.open {
+            inspector.open = false;
+        }
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::KeyI) {
+        inspector.open = !inspector.open;
+    }
+}
+
+/// Navigate and edit the fields while the inspector is open, and refresh the panel text.
+pub(crate) fn edit_inspector_fields(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut inspector: ResMut<InspectorState>,
+    mut targets: InspectorTargets,
+) {
+    if !inspector.open {
+        return;
+    }
+    let count = INSPECTOR_FIELDS.len();
+    if keyboard.just_pressed(KeyCode::ArrowDown) {
+        inspector.field = (inspector.field + 1) % count;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowUp) {
+        inspector.field = (inspector.field + count - 1) % count;
+    }
+
+    let selectable = targets.selectable();
+    let step = if keyboard.just_pressed(KeyCode::BracketRight) {
+        Some(FocusMove::Next)
+    } else if keyboard.just_pressed(KeyCode::BracketLeft) {
+        Some(FocusMove::Previous)
+    } else {
+        None
+    };
+    let selected = step_focus(&selectable, inspector.selected, step);
+    if inspector.selected != selected {
+        inspector.selected = selected;
+    }
+
+    let direction = if keyboard.just_pressed(KeyCode::ArrowRight) {
+        1.0
+    } else if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        -1.0
+    } else {
+        0.0
+    };
+    if direction != 0.0 {
+        let field = INSPECTOR_FIELDS[inspector.field];
+        let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        let step = field.step() * if shift { 10.0 } else { 1.0 };
+        if let Some(value) = targets.get(field, inspector.selected) {
+            match targets.set(field, inspector.selected, value + direction * step) {
+                Ok(()) => info!(
+                    "Inspector: {} = {:.2}",
+                    field.label(),
+                    value + direction * step
+                ),
+                Err(e) => warn!("Inspector: {} not changed: {}", field.label(), e),
+            }
+        }
+    }
+
+    let text = targets.describe(&inspector);
+    if inspector.text != text {
+        inspector.text = text;
+    }
+}
+
+/// Inspect the brick clicked while the inspector is open; clicking elsewhere clears it.
+pub fn inspect_clicked_brick(
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    mut inspector: ResMut<InspectorState>,
+    picker: BrickPicker,
+) {
+    if !inspector.open || !mouse.is_some_and(|mouse| mouse.just_pressed(MouseButton::Left)) {
+        return;
+    }
+    let brick = picker.brick_under_cursor();
+    if inspector.brick != brick {
+        inspector.brick = brick;
+        if let Some(brick) = brick {
+            info!("Inspector: inspecting brick {:?}", brick);
+        }
+    }
+}
+
+/// Plugin registering the entity inspector.
+pub struct InspectorPlugin;
+
+impl Plugin for InspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InspectorState>();
+        app.add_systems(
+            Update,
+            (
+                toggle_inspector,
+                inspect_clicked_brick,
+                edit_inspector_fields,
+            )
+                .chain(),
+        );
+    }
+}
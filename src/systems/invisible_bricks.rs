@@ -0,0 +1,220 @@
+//! Invisible bricks (index 18): present and solid from the start, but not drawn until revealed.
+//!
+//! A concealed brick is revealed when a ball passes within [`reveal_distance`] of it, or when a
+//! ball hits it. The revealing hit does not destroy the brick; once revealed it fades in over
+//! [`REVEAL_FADE_SECONDS`] and behaves like a simple brick.
+//!
+//! # Level completion
+//! Invisible bricks count towards completion like any destructible brick. So that a level can never
+//! be stuck on bricks the player cannot see, every remaining concealed brick is revealed as soon as
+//! only concealed bricks are left.
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::CollisionEvent;
+
+use crate::{Ball, Brick, CountsTowardsCompletion, GridResolution, MarkedForDespawn};
+
+/// Distance (XZ, centre to centre) at which a passing ball reveals a concealed brick on the
+/// standard 20x20 grid.
+pub const REVEAL_DISTANCE: f32 = 2.0;
+/// Duration of the fade-in after a brick is revealed, in seconds.
+pub const REVEAL_FADE_SECONDS: f32 = 0.6;
+
+/// Marker for an invisible brick that has not been revealed yet.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Concealed;
+
+/// Fade-in in progress on a revealed brick.
+#[derive(Component, Debug, Clone)]
+pub struct Revealing {
+    pub timer: Timer,
+    /// Per-brick material copy whose alpha is animated (absent when no material was available).
+    material: Option<Handle<StandardMaterial>>,
+}
+
+/// Reveal a concealed brick and start its fade-in.
+///
+/// Bricks share materials, so the fade runs on a private copy of the brick's material.
+pub fn reveal_brick(
+    commands: &mut Commands,
+    brick: Entity,
+    material: Option<&MeshMaterial3d<StandardMaterial>>,
+    materials: Option<&mut Assets<StandardMaterial>>,
+) {
+    let faded = material.zip(materials).and_then(|(m, assets)| {
+        let mut copy = assets.get(&m.0)?.clone();
+        copy.base_color.set_alpha(0.0);
+        copy.alpha_mode = AlphaMode::Blend;
+        Some(assets.add(copy))
+    });
+    let mut entity = commands.entity(brick);
+    entity.remove::<Concealed>().insert((
+        Visibility::Inherited,
+        Revealing {
+            timer: Timer::from_seconds(REVEAL_FADE_SECONDS, TimerMode::Once),
+            material: faded.clone(),
+        },
+    ));
+    if let Some(handle) = faded {
+        entity.insert(MeshMaterial3d(handle));
+    }
+    debug!("Invisible brick {:?} revealed", brick);
+}
+
+/// Reveal distance on a level laid out on `grid`: [`REVEAL_DISTANCE`] scaled with the cell size.
+pub fn reveal_distance(grid: &GridResolution) -> f32 {
+    let diagonal =
+        |grid: &GridResolution| Vec2::new(grid.cell_height(), grid.cell_width()).length();
+    REVEAL_DISTANCE * diagonal(grid) / diagonal(&GridResolution::default())
+}
+
+/// Reveal concealed bricks a ball passes close to.
+pub fn reveal_on_proximity(
+    mut commands: Commands,
+    grid: Option<Res<GridResolution>>,
+    balls: Query<&Transform, With<Ball>>,
+    concealed: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&MeshMaterial3d<StandardMaterial>>,
+        ),
+        (With<Concealed>, Without<Ball>),
+    >,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let reveal_distance = reveal_distance(&grid.as_deref().copied().unwrap_or_default());
+    for (brick, brick_transform, material) in concealed.iter() {
+        let brick_xz = brick_transform.translation.xz();
+        let near = balls
+            .iter()
+            .any(|ball| ball.translation.xz().distance(brick_xz) <= reveal_distance);
+        if near {
+            reveal_brick(&mut commands, brick, material, materials.as_deref_mut());
+        }
+    }
+}
+
+/// Reveal concealed bricks that were hit by a ball.
+///
+/// Runs after `mark_brick_on_ball_collision`, which ignores hits on concealed bricks, so the
+/// revealing hit never destroys the brick.
+pub fn reveal_on_hit(
+    mut commands: Commands,
+    mut collisions: MessageReader<CollisionEvent>,
+    balls: Query<(), With<Ball>>,
+    concealed: Query<Option<&MeshMaterial3d<StandardMaterial>>, With<Concealed>>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let mut revealed = Vec::new();
+    for event in collisions.read() {
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        let brick = if balls.contains(*e1) {
+            *e2
+        } else if balls.contains(*e2) {
+            *e1
+        } else {
+            continue;
+        };
+        if revealed.contains(&brick) {
+            continue;
+        }
+        if let Ok(material) = concealed.get(brick) {
+            reveal_brick(&mut commands, brick, material, materials.as_deref_mut());
+            revealed.push(brick);
+        }
+    }
+}
+
+/// Reveal every concealed brick once nothing else is left to clear.
+pub fn reveal_last_hidden_bricks(
+    mut commands: Commands,
+    remaining: Query<
+        (
+            Entity,
+            Has<Concealed>,
+            Option<&MeshMaterial3d<StandardMaterial>>,
+        ),
+        (
+            With<Brick>,
+            With<CountsTowardsCompletion>,
+            Without<MarkedForDespawn>,
+        ),
+    >,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    if remaining.is_empty() || !remaining.iter().all(|(_, concealed, _)| concealed) {
+        return;
+    }
+    for (brick, _, material) in remaining.iter() {
+        reveal_brick(&mut commands, brick, material, materials.as_deref_mut());
+    }
+}
+
+/// Fade revealed bricks in, then switch them back to opaque rendering.
+pub fn fade_in_revealed_bricks(
+    mut commands: Commands,
+    mut revealing: Query<(Entity, &mut Revealing)>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+    time: Res<Time>,
+) {
+    for (entity, mut reveal) in revealing.iter_mut() {
+        reveal.timer.tick(time.delta());
+        let done = reveal.timer.is_finished();
+        if let (Some(handle), Some(assets)) = (&reveal.material, materials.as_deref_mut()) {
+            if let Some(material) = assets.get_mut(handle) {
+                material.base_color.set_alpha(reveal.timer.fraction());
+                if done {
+                    material.alpha_mode = AlphaMode::Opaque;
+                }
+            }
+        }
+        if done {
+            commands.entity(entity).remove::<Revealing>();
+        }
+    }
+}
+
+/// Plugin registering invisible brick reveal and fade-in.
+pub struct InvisibleBricksPlugin;
+
+impl Plugin for InvisibleBricksPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<CollisionEvent>();
+        app.add_systems(
+            Update,
+            (
+                (
+                    reveal_on_proximity,
+                    reveal_on_hit,
+                    reveal_last_hidden_bricks,
+                )
+                    .chain()
+                    .after(crate::despawn_marked_entities),
+                fade_in_revealed_bricks,
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_distance_exceeds_contact_distance() {
+        // A ball touching a brick corner is closer than this; proximity normally reveals first
+        for grid in [
+            GridResolution::default(),
+            GridResolution::new(40, 40),
+            GridResolution::new(10, 20),
+        ] {
+            let contact =
+                Vec2::new(grid.cell_height() * 0.45, grid.cell_width() * 0.45).length() + 0.3;
+            assert!(reveal_distance(&grid) > contact, "{grid:?}");
+        }
+    }
+}
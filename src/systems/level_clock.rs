@@ -0,0 +1,137 @@
+//! Per-level game clock.
+//!
+//! [`LevelClock`] measures how long the player has been playing the current level. It only runs
+//! while gameplay does: it stands still while the game is paused, during level transitions
+//! (fade-out and paddle growth), while a level intro is showing, and once the level is completed.
+//!
+//! # Lifecycle
+//! - A new or restarted level (`CurrentLevel` inserted or changed) starts a fresh clock.
+//! - `LevelCompleted` stops the clock and records the time: [`LevelClock::last_completed`] feeds the
+//!   end-of-level summary, [`LevelClock::best_times`] feeds time-attack and statistics. Best times
+//!   are only kept at the normal game speed (`systems::sim_speed`).
+//!
+//! The optional HUD readout lives in `ui::level_clock_display`.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::level_loader::{CurrentLevel, LevelAdvanceState};
+use crate::pause::PauseState;
+use crate::systems::sim_speed::SimSpeed;
+use crate::systems::LevelCompleted;
+use crate::ui::level_intro::LevelIntro;
+
+/// Elapsed play time on the current level, plus completion times.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LevelClock {
+    /// Level being timed (`None` until the first level loads)
+    pub level_number: Option<u32>,
+    /// Play time on the current attempt
+    pub elapsed: Duration,
+    /// The level was completed; the clock waits for the next level
+    pub stopped: bool,
+    /// Level number and time of the most recent completion
+    pub last_completed: Option<(u32, Duration)>,
+    /// Fastest completion per level number
+    pub best_times: BTreeMap<u32, Duration>,
+}
+
+impl LevelClock {
+    /// Start timing an attempt at level `number` from zero.
+    pub fn start(&mut self, number: u32) {
+        self.level_number = Some(number);
+        self.elapsed = Duration::ZERO;
+        self.stopped = false;
+    }
+
+    /// Stop the clock for a completed level and record the time, as a best time too if `ranked`.
+    pub fn complete(&mut self, number: u32, ranked: bool) {
+        if self.stopped {
+            return;
+        }
+        self.stopped = true;
+        self.last_completed = Some((number, self.elapsed));
+        if !ranked {
+            return;
+        }
+        let best = self.best_times.entry(number).or_insert(self.elapsed);
+        *best = (*best).min(self.elapsed);
+    }
+}
+
+/// Format a clock reading as `m:ss.t`.
+pub fn format_clock(elapsed: Duration) -> String {
+    let tenths = elapsed.as_millis() / 100;
+    format!("{}:{:02}.{}", tenths / 600, (tenths / 10) % 60, tenths % 10)
+}
+
+/// Restart the clock on level changes and advance it while gameplay is running.
+pub fn tick_level_clock(
+    time: Res<Time>,
+    current_level: Option<Res<CurrentLevel>>,
+    level_advance: Option<Res<LevelAdvanceState>>,
+    pause_state: Option<Res<PauseState>>,
+    intro: Option<Res<LevelIntro>>,
+    mut clock: ResMut<LevelClock>,
+) {
+    let Some(level) = current_level else {
+        return;
+    };
+    if level.is_changed() {
+        clock.start(level.0.number);
+    }
+    let paused = pause_state.is_some_and(|p| !matches!(*p, PauseState::Active));
+    let advancing = level_advance.is_some_and(|a| a.active);
+    if clock.stopped || paused || advancing || intro.is_some() {
+        return;
+    }
+    clock.elapsed += time.delta();
+}
+
+/// Stop the clock when the level is completed.
+fn on_level_completed(
+    trigger: On<LevelCompleted>,
+    sim: Option<Res<SimSpeed>>,
+    mut clock: ResMut<LevelClock>,
+) {
+    let ranked = sim.is_none_or(|sim| sim.counts_for_records());
+    clock.complete(trigger.event().level_index, ranked);
+}
+
+/// Plugin registering the per-level clock.
+pub struct LevelClockPlugin;
+
+impl Plugin for LevelClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelClock>();
+        app.add_systems(Update, tick_level_clock);
+        app.add_observer(on_level_completed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_formats_minutes_seconds_and_tenths() {
+        assert_eq!(format_clock(Duration::ZERO), "0:00.0");
+        assert_eq!(format_clock(Duration::from_millis(65_340)), "1:05.3");
+        assert_eq!(format_clock(Duration::from_secs(600)), "10:00.0");
+    }
+
+    #[test]
+    fn completion_keeps_the_fastest_time() {
+        let mut clock = LevelClock::default();
+        clock.start(3);
+        clock.elapsed = Duration::from_secs(40);
+        clock.complete(3, true);
+        clock.start(3);
+        clock.elapsed = Duration::from_secs(55);
+        clock.complete(3, true);
+        assert_eq!(clock.last_completed, Some((3, Duration::from_secs(55))));
+        assert_eq!(clock.best_times[&3], Duration::from_secs(40));
+    }
+}
@@ -0,0 +1,391 @@
+//! Level files over HTTP for the browser build.
+//!
+//! Native builds read level files from disk whenever they need one. The browser build fetches
+//! them from the web server through the asset server instead of embedding every level in the
+//! binary, so the binary stays small and levels can be added or changed by deploying their files.
+//!
+//! A web server cannot list a directory, so [`LEVEL_MANIFEST_PATH`] lists the numbered level
+//! files; a new `level_NNN.ron` has to be added there to be found by browsers:
+//!
+//! ```ron
+//! (levels: [1, 2, 3, 997])
+//! ```
+//!
+//! Fetching is asynchronous, while the level loader reads files within a frame, so:
+//! - the first level is loaded once its file has arrived, a few frames after startup
+//! - the levels that can follow the current one (next in the campaign, next and previous for
+//!   level switching) are fetched ahead while it is played
+//! - a level switch to a level that has not arrived yet waits for it
+//!
+//! [`read_level_file`] and [`level_file_listed`] hide the difference from the level loader. The
+//! campaign manifest (`systems::campaign`) is a few hundred bytes and stays embedded.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+#[cfg(target_arch = "wasm32")]
+use bevy::asset::io::Reader;
+#[cfg(target_arch = "wasm32")]
+use bevy::asset::{AssetLoader, LoadContext, LoadState};
+use bevy::prelude::*;
+#[cfg(target_arch = "wasm32")]
+use bevy::tasks::ConditionalSendFuture;
+use serde::Deserialize;
+
+use crate::launch::{level_file_path, DEFAULT_LEVELS_DIR};
+#[cfg(target_arch = "wasm32")]
+use crate::level_loader::CurrentLevel;
+#[cfg(target_arch = "wasm32")]
+use crate::systems::campaign::CampaignRoute;
+#[cfg(target_arch = "wasm32")]
+use crate::systems::level_switch::LevelSwitchState;
+
+/// Path of the level manifest.
+pub const LEVEL_MANIFEST_PATH: &str = "assets/levels/manifest.ron";
+
+/// Numbered level files available to the browser build.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct LevelManifest {
+    #[serde(default)]
+    pub levels: Vec<u32>,
+}
+
+impl LevelManifest {
+    pub fn parse(content: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::de::from_str(content)
+    }
+
+    /// Whether the manifest lists the level file `path`.
+    pub fn lists(&self, path: &str) -> bool {
+        self.levels
+            .iter()
+            .any(|&number| level_file_path(Path::new(DEFAULT_LEVELS_DIR), number) == path)
+    }
+}
+
+/// Asset path of the file `path`, relative to the `assets` directory.
+pub fn asset_path(path: &str) -> &str {
+    path.strip_prefix("assets/").unwrap_or(path)
+}
+
+/// State of a level file in [`LevelFiles`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelFileStatus<'a> {
+    Fetched(&'a str),
+    /// Being fetched, or not requested yet
+    Pending,
+    Failed,
+}
+
+/// Level files fetched by the browser build, and those still wanted.
+#[derive(Resource, Debug, Default)]
+pub struct LevelFiles {
+    manifest: Option<LevelManifest>,
+    /// Contents by path; `None` when the fetch failed
+    fetched: HashMap<String, Option<String>>,
+    wanted: HashSet<String>,
+}
+
+impl LevelFiles {
+    /// Files with only the manifest requested.
+    pub fn new() -> Self {
+        let mut files = Self::default();
+        files.request(LEVEL_MANIFEST_PATH);
+        files
+    }
+
+    /// The level manifest, once fetched; empty when it could not be fetched.
+    pub fn manifest(&self) -> Option<&LevelManifest> {
+        self.manifest.as_ref()
+    }
+
+    pub fn set_manifest(&mut self, manifest: LevelManifest) {
+        self.manifest = Some(manifest);
+    }
+
+    /// Whether the manifest lists the level file `path`; `None` before it was fetched.
+    pub fn listed(&self, path: &str) -> Option<bool> {
+        self.manifest.as_ref().map(|manifest| manifest.lists(path))
+    }
+
+    pub fn status(&self, path: &str) -> LevelFileStatus<'_> {
+        match self.fetched.get(path) {
+            Some(Some(content)) => LevelFileStatus::Fetched(content),
+            Some(None) => LevelFileStatus::Failed,
+            None => LevelFileStatus::Pending,
+        }
+    }
+
+    /// Whether `path` was fetched or requested already.
+    pub fn is_known(&self, path: &str) -> bool {
+        self.fetched.contains_key(path) || self.wanted.contains(path)
+    }
+
+    /// Fetch `path` unless that was done already.
+    pub fn request(&mut self, path: &str) {
+        if !self.fetched.contains_key(path) {
+            self.wanted.insert(path.to_string());
+        }
+    }
+
+    /// Store a fetched file; `None` when the fetch failed.
+    pub fn insert(&mut self, path: String, content: Option<String>) {
+        self.wanted.remove(&path);
+        self.fetched.insert(path, content);
+    }
+}
+
+/// Contents of the level file `path`, or `Ok(None)` while the browser build is still fetching it.
+///
+/// Native builds read the file from disk. The browser build takes it from `files`, and requests it
+/// there when it has not been fetched yet.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub fn read_level_file(
+    path: &str,
+    files: Option<&mut LevelFiles>,
+) -> Result<Option<String>, String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::fs::read_to_string(path)
+            .map(Some)
+            .map_err(|err| format!("failed to read level file '{path}': {err}"))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(files) = files else {
+            return Err(format!(
+                "failed to read level file '{path}': no level files"
+            ));
+        };
+        match files.status(path) {
+            LevelFileStatus::Fetched(content) => Ok(Some(content.to_string())),
+            LevelFileStatus::Failed => Err(format!("failed to fetch level file '{path}'")),
+            LevelFileStatus::Pending => {
+                files.request(path);
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Whether the level file `path` exists, or `None` while the browser build is still fetching the
+/// level manifest.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub fn level_file_listed(path: &str, files: Option<&LevelFiles>) -> Option<bool> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Some(Path::new(path).exists())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        files.map_or(Some(false), |files| files.listed(path))
+    }
+}
+
+/// Raw content of a level file, as fetched by the asset server.
+#[cfg(target_arch = "wasm32")]
+#[derive(Asset, TypePath, Debug)]
+pub struct LevelFile(pub String);
+
+#[cfg(target_arch = "wasm32")]
+#[derive(Default)]
+struct LevelFileLoader;
+
+#[cfg(target_arch = "wasm32")]
+impl AssetLoader for LevelFileLoader {
+    type Asset = LevelFile;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext,
+    ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            String::from_utf8(bytes)
+                .map(LevelFile)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+/// Start fetching wanted files, and move arrived ones into [`LevelFiles`].
+#[cfg(target_arch = "wasm32")]
+fn fetch_level_files(
+    asset_server: Res<AssetServer>,
+    mut assets: ResMut<Assets<LevelFile>>,
+    mut files: ResMut<LevelFiles>,
+    mut loading: Local<HashMap<String, Handle<LevelFile>>>,
+) {
+    let new: Vec<String> = files
+        .wanted
+        .iter()
+        .filter(|path| !loading.contains_key(*path))
+        .cloned()
+        .collect();
+    for path in new {
+        debug!("Fetching {}", path);
+        let handle = asset_server.load(asset_path(&path).to_string());
+        loading.insert(path, handle);
+    }
+
+    let mut arrived = Vec::new();
+    for (path, handle) in loading.iter() {
+        match asset_server.get_load_state(handle) {
+            Some(LoadState::Loaded) => {
+                arrived.push((path.clone(), assets.remove(handle).map(|file| file.0)));
+            }
+            Some(LoadState::Failed(e)) => {
+                warn!("Failed to fetch '{}': {}", path, e);
+                arrived.push((path.clone(), None));
+            }
+            _ => {}
+        }
+    }
+    for (path, content) in arrived {
+        loading.remove(&path);
+        if path == LEVEL_MANIFEST_PATH {
+            let manifest = match content.as_deref().map(LevelManifest::parse) {
+                Some(Ok(manifest)) => manifest,
+                Some(Err(e)) => {
+                    warn!("Failed to parse level manifest: {}", e);
+                    LevelManifest::default()
+                }
+                None => LevelManifest::default(),
+            };
+            info!("Level manifest lists {} levels", manifest.levels.len());
+            files.set_manifest(manifest);
+        }
+        files.insert(path, content);
+    }
+}
+
+/// Use the manifest's levels for level switching once it has arrived.
+#[cfg(target_arch = "wasm32")]
+fn apply_level_manifest(files: Res<LevelFiles>, mut switch_state: ResMut<LevelSwitchState>) {
+    if let Some(manifest) = files.manifest() {
+        switch_state.set_levels(Path::new(DEFAULT_LEVELS_DIR), &manifest.levels);
+    }
+}
+
+/// Fetch the levels that can follow the current one ahead of time.
+#[cfg(target_arch = "wasm32")]
+fn prefetch_following_levels(
+    current_level: Option<Res<CurrentLevel>>,
+    campaign: CampaignRoute,
+    switch_state: Option<Res<LevelSwitchState>>,
+    mut files: ResMut<LevelFiles>,
+) {
+    let Some(current_level) = current_level else {
+        return;
+    };
+    let number = current_level.0.number;
+    let mut paths: Vec<String> = campaign
+        .next_level(number)
+        .map(|next| level_file_path(Path::new(DEFAULT_LEVELS_DIR), next))
+        .into_iter()
+        .collect();
+    if let Some(switch_state) = switch_state {
+        paths.extend(
+            [
+                switch_state.next_level_after(number),
+                switch_state.previous_level_before(number),
+            ]
+            .into_iter()
+            .flatten()
+            .map(|slot| slot.path.clone()),
+        );
+    }
+    for path in paths {
+        // Only touch the resource for new requests, so it is not marked changed every frame
+        if files.listed(&path) == Some(true) && !files.is_known(&path) {
+            files.request(&path);
+        }
+    }
+}
+
+/// Plugin fetching level files in the browser build; does nothing on native platforms.
+pub struct LevelFetchPlugin;
+
+impl Plugin for LevelFetchPlugin {
+    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+    fn build(&self, app: &mut App) {
+        #[cfg(target_arch = "wasm32")]
+        {
+            app.init_asset::<LevelFile>();
+            app.register_asset_loader(LevelFileLoader);
+            app.insert_resource(LevelFiles::new());
+            app.add_systems(
+                PreUpdate,
+                (
+                    fetch_level_files,
+                    apply_level_manifest.run_if(resource_changed::<LevelFiles>),
+                    prefetch_following_levels,
+                )
+                    .chain(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_lists_every_bundled_level() {
+        let content = std::fs::read_to_string(LEVEL_MANIFEST_PATH).unwrap();
+        let manifest = LevelManifest::parse(&content).unwrap();
+        let mut bundled: Vec<u32> = std::fs::read_dir(DEFAULT_LEVELS_DIR)
+            .unwrap()
+            .filter_map(|entry| {
+                let name = entry.unwrap().file_name().into_string().unwrap();
+                name.strip_prefix("level_")?
+                    .strip_suffix(".ron")?
+                    .parse()
+                    .ok()
+            })
+            .collect();
+        bundled.sort_unstable();
+        assert_eq!(manifest.levels, bundled);
+        assert!(manifest.lists("assets/levels/level_001.ron"));
+        assert!(!manifest.lists("assets/levels/level_000.ron"));
+    }
+
+    #[test]
+    fn files_are_requested_until_fetched() {
+        let mut files = LevelFiles::new();
+        let path = "assets/levels/level_002.ron";
+        assert!(files.is_known(LEVEL_MANIFEST_PATH));
+        assert_eq!(files.listed(path), None);
+        assert_eq!(files.status(path), LevelFileStatus::Pending);
+        files.request(path);
+        assert!(files.is_known(path));
+
+        files.insert(
+            path.to_string(),
+            Some("LevelDefinition(number: 2)".to_string()),
+        );
+        assert_eq!(
+            files.status(path),
+            LevelFileStatus::Fetched("LevelDefinition(number: 2)")
+        );
+        files.request(path);
+        assert!(!files.wanted.contains(path));
+
+        files.insert("assets/levels/level_003.ron".to_string(), None);
+        assert_eq!(
+            files.status("assets/levels/level_003.ron"),
+            LevelFileStatus::Failed
+        );
+        assert_eq!(asset_path(path), "levels/level_002.ron");
+    }
+}
@@ -5,6 +5,8 @@ use tracing::info;
 #[cfg(not(target_arch = "wasm32"))]
 use tracing::warn;
 
+use crate::launch::{level_file_path, levels_dir, LaunchOptions, DEFAULT_LEVELS_DIR};
+
 /// Message emitted when any source requests a level switch.
 #[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LevelSwitchRequested {
@@ -16,11 +18,20 @@ pub struct LevelSwitchRequested {
 pub enum LevelSwitchDirection {
     Next,
     Previous,
+    /// Straight to the level with this number
+    Jump(u32),
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LevelSwitchSource {
     Keyboard,
     Automation,
+    Playlist,
+    /// Resuming autosaved progress
+    Resume,
+    /// Playing again after the game was completed
+    NewGame,
+    /// Starting the first-run tutorial
+    Tutorial,
 }
 
 /// Ordered list of playable level files plus bookkeeping for pending transitions.
@@ -29,14 +40,23 @@ pub struct LevelSwitchState {
     ordered_levels: Vec<LevelSlot>,
     trigger_file: PathBuf,
     pending_transition: bool,
+    wrap: bool,
 }
 
 impl Default for LevelSwitchState {
     fn default() -> Self {
+        Self::with_levels_dir(Path::new(DEFAULT_LEVELS_DIR))
+    }
+}
+
+impl LevelSwitchState {
+    /// State for the numbered level files found in `dir` (`--levels-dir`).
+    pub fn with_levels_dir(dir: &Path) -> Self {
         Self {
-            ordered_levels: discover_level_slots(),
+            ordered_levels: discover_level_slots(dir),
             trigger_file: PathBuf::from(".level-switch-next"),
             pending_transition: false,
+            wrap: true,
         }
     }
 }
@@ -46,6 +66,35 @@ impl LevelSwitchState {
         &self.ordered_levels
     }
 
+    /// Replace the levels with the numbered level files `numbers` in `dir`, e.g. those of the
+    /// browser build's level manifest (`systems::level_fetch`).
+    pub fn set_levels(&mut self, dir: &Path, numbers: &[u32]) {
+        let mut slots: Vec<LevelSlot> = numbers
+            .iter()
+            .map(|&number| LevelSlot {
+                number,
+                path: level_file_path(dir, number),
+            })
+            .collect();
+        if slots.is_empty() {
+            slots.push(LevelSlot {
+                number: 1,
+                path: level_file_path(dir, 1),
+            });
+        }
+        slots.sort_by_key(|slot| slot.number);
+        self.ordered_levels = slots;
+    }
+
+    /// Whether switching past either end wraps around to the other (`LevelSwitchConfig::wrap`).
+    pub fn wraps(&self) -> bool {
+        self.wrap
+    }
+
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
     pub fn next_level_after(&self, current: u32) -> Option<&LevelSlot> {
         if self.ordered_levels.is_empty() {
             return None;
@@ -53,18 +102,32 @@ impl LevelSwitchState {
         self.ordered_levels
             .iter()
             .find(|slot| slot.number > current)
-            .or_else(|| self.ordered_levels.first())
+            .or_else(|| self.ordered_levels.first().filter(|_| self.wrap))
+    }
+
+    /// One-based position of level `number` among the levels, and the number of levels.
+    pub fn slot_position(&self, number: u32) -> Option<(usize, usize)> {
+        self.ordered_levels
+            .iter()
+            .position(|slot| slot.number == number)
+            .map(|index| (index + 1, self.ordered_levels.len()))
+    }
+
+    pub fn level(&self, number: u32) -> Option<&LevelSlot> {
+        self.ordered_levels
+            .iter()
+            .find(|slot| slot.number == number)
     }
 
     pub fn previous_level_before(&self, current: u32) -> Option<&LevelSlot> {
         if self.ordered_levels.is_empty() {
             return None;
         }
-        // find the last level with number < current, otherwise return last
+        // find the last level with number < current, otherwise wrap to the last
         self.ordered_levels
             .iter()
             .rfind(|slot| slot.number < current)
-            .or_else(|| self.ordered_levels.last())
+            .or_else(|| self.ordered_levels.last().filter(|_| self.wrap))
     }
 
     pub fn mark_transition_start(&mut self) {
@@ -94,50 +157,30 @@ pub struct LevelSlot {
     pub path: String,
 }
 
-fn discover_level_slots() -> Vec<LevelSlot> {
+#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+fn discover_level_slots(dir: &Path) -> Vec<LevelSlot> {
     let mut slots: Vec<LevelSlot> = Vec::new();
     #[cfg(not(target_arch = "wasm32"))]
     {
-        if let Ok(entries) = std::fs::read_dir("assets/levels") {
+        if let Ok(entries) = std::fs::read_dir(dir) {
             for entry in entries.flatten() {
                 if let Some(name) = entry.file_name().to_str() {
                     if let Some(num) = parse_level_number(name) {
                         slots.push(LevelSlot {
                             number: num,
-                            path: format!("assets/levels/{name}"),
+                            path: format!("{}/{name}", dir.display()),
                         });
                     }
                 }
             }
         }
     }
-    #[cfg(target_arch = "wasm32")]
-    {
-        // On WASM, hardcode the level list since there's no filesystem access
-        for i in 1..=74 {
-            slots.push(LevelSlot {
-                number: i,
-                path: format!("assets/levels/level_{:03}.ron", i),
-            });
-        }
-        // Add special debug levels
-        slots.push(LevelSlot {
-            number: 997,
-            path: "assets/levels/level_997.ron".to_string(),
-        });
-        slots.push(LevelSlot {
-            number: 998,
-            path: "assets/levels/level_998.ron".to_string(),
-        });
-        slots.push(LevelSlot {
-            number: 999,
-            path: "assets/levels/level_999.ron".to_string(),
-        });
-    }
+    // On WASM there is no directory to scan; the levels of the level manifest replace level_001
+    // once it has been fetched (`systems::level_fetch`)
     if slots.is_empty() {
         slots.push(LevelSlot {
             number: 1,
-            path: "assets/levels/level_001.ron".to_string(),
+            path: level_file_path(dir, 1),
         });
         info!(target: "level_switch", "No level files discovered; defaulting to level_001 only");
     }
@@ -160,15 +203,44 @@ pub struct LevelSwitchPlugin;
 
 impl Plugin for LevelSwitchPlugin {
     fn build(&self, app: &mut App) {
+        // Levels come from `--levels-dir` when launch options are present
+        if !app.world().contains_resource::<LevelSwitchState>() {
+            let state = LevelSwitchState::with_levels_dir(levels_dir(
+                app.world().get_resource::<LaunchOptions>(),
+            ));
+            app.insert_resource(state);
+        }
         app.add_message::<LevelSwitchRequested>()
-            .init_resource::<LevelSwitchState>()
             // Run the keyboard producer in PreUpdate so it observes `just_pressed` reliably
             .add_systems(PreUpdate, queue_keyboard_requests)
             // Contract/polling can remain in Update
-            .add_systems(Update, poll_contract_trigger);
+            .add_systems(
+                Update,
+                (
+                    poll_contract_trigger,
+                    apply_level_switch_config.run_if(
+                        resource_exists_and_changed::<crate::gameplay_config::GameplayConfig>,
+                    ),
+                ),
+            );
     }
 }
 
+/// Take the wrap-around setting from `GameplayConfig::level_switch`.
+fn apply_level_switch_config(
+    gameplay: Res<crate::gameplay_config::GameplayConfig>,
+    mut state: ResMut<LevelSwitchState>,
+) {
+    if state.wraps() != gameplay.level_switch.wrap {
+        state.set_wrap(gameplay.level_switch.wrap);
+    }
+}
+
+/// Whether either Shift key is held.
+pub(crate) fn shift_held(keyboard: &ButtonInput<KeyCode>) -> bool {
+    keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+}
+
 fn queue_keyboard_requests(
     keyboard: Res<ButtonInput<KeyCode>>,
     cheat: Option<Res<crate::systems::cheat_mode::CheatModeState>>,
@@ -192,7 +264,10 @@ fn queue_keyboard_requests(
         }
     }
 
-    if keyboard.just_pressed(KeyCode::KeyP) {
+    // P, or Shift + L (plain L opens the playlist editor)
+    if keyboard.just_pressed(KeyCode::KeyP)
+        || (keyboard.just_pressed(KeyCode::KeyL) && shift_held(&keyboard))
+    {
         if let Some(cheat) = cheat.as_ref() {
             if cheat.is_active() {
                 events.write(LevelSwitchRequested {
@@ -321,4 +396,34 @@ mod tests {
         assert_eq!(beep.0, 0, "Allowed N should not emit a beep");
         assert_eq!(sw.0, 1, "Allowed N should create a single switch request");
     }
+
+    #[test]
+    fn shift_l_switches_to_previous_level_in_cheat_mode() {
+        let mut app = app_with_plugins();
+        app.add_plugins(crate::systems::cheat_mode::CheatModePlugin);
+        app.world_mut()
+            .resource_mut::<crate::systems::cheat_mode::CheatModeState>()
+            .active = true;
+        {
+            let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            input.press(KeyCode::ShiftLeft);
+            input.press(KeyCode::KeyL);
+        }
+        app.update();
+        assert_eq!(app.world().resource::<SwitchCount>().0, 1);
+    }
+
+    #[test]
+    fn wrap_around_at_the_edges_is_optional() {
+        let mut state = LevelSwitchState::with_levels_dir(Path::new("assets/levels"));
+        state.set_levels(Path::new("assets/levels"), &[1, 2, 5]);
+        assert_eq!(state.next_level_after(5).map(|s| s.number), Some(1));
+        assert_eq!(state.previous_level_before(1).map(|s| s.number), Some(5));
+        assert_eq!(state.slot_position(2), Some((2, 3)));
+
+        state.set_wrap(false);
+        assert_eq!(state.next_level_after(2).map(|s| s.number), Some(5));
+        assert!(state.next_level_after(5).is_none());
+        assert!(state.previous_level_before(1).is_none());
+    }
 }
@@ -0,0 +1,305 @@
+//! Shadow and lighting settings.
+//!
+//! [`LightingSettings`] picks a [`ShadowQuality`] and a [`LightBrightness`] for every point light
+//! in the scene: the main light and any light a level spawns later. They are read at startup from
+//! `config/lighting.ron` when that file exists (the active profile's `lighting.ron`, from
+//! localStorage on wasm, see `systems::web_storage`), e.g.
+//!
+//! ```ron
+//! (shadows: High, brightness: Bright, shadow_map_size: Some(4096))
+//! ```
+//!
+//! The pause menu cycles both presets; changes are saved back to the file.
+//!
+//! | Shadows | Point light shadows | Shadow map |
+//! |---------|---------------------|------------|
+//! | `Off` | - | - |
+//! | `Low` (wasm default) | yes | 512 px |
+//! | `Medium` (native default) | yes | 1024 px |
+//! | `High` | yes | 2048 px |
+//!
+//! `shadow_map_size` overrides the preset's resolution. When the frame-time guard
+//! (`systems::quality_guard`) has lowered the quality, its limits win over these settings.
+
+use bevy::light::PointLightShadowMap;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::systems::profiles::{profile_path, profile_switched, CurrentProfile};
+use crate::systems::quality_guard::{QualityGuard, QualityLevel, REDUCED_SHADOW_MAP_SIZE};
+use crate::systems::web_storage::{read_save_file, write_save_file};
+
+/// Native path of the optional lighting config file.
+pub const LIGHTING_CONFIG_PATH: &str = "config/lighting.ron";
+
+/// Shadow presets, from cheapest to most expensive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShadowQuality {
+    Off,
+    Low,
+    Medium,
+    High,
+}
+
+impl Default for ShadowQuality {
+    fn default() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            Self::Low
+        } else {
+            Self::Medium
+        }
+    }
+}
+
+impl ShadowQuality {
+    /// The preset after this one in the pause menu.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Low,
+            Self::Low => Self::Medium,
+            Self::Medium => Self::High,
+            Self::High => Self::Off,
+        }
+    }
+
+    /// Point light shadow map size, in pixels; `None` when shadows are off.
+    pub fn shadow_map_size(self) -> Option<usize> {
+        match self {
+            Self::Off => None,
+            Self::Low => Some(512),
+            Self::Medium => Some(1024),
+            Self::High => Some(2048),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        }
+    }
+}
+
+/// Light intensity presets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LightBrightness {
+    Dim,
+    #[default]
+    Normal,
+    Bright,
+}
+
+impl LightBrightness {
+    /// The preset after this one in the pause menu.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Dim => Self::Normal,
+            Self::Normal => Self::Bright,
+            Self::Bright => Self::Dim,
+        }
+    }
+
+    /// Factor applied to each light's spawned intensity.
+    pub fn scale(self) -> f32 {
+        match self {
+            Self::Dim => 0.6,
+            Self::Normal => 1.0,
+            Self::Bright => 1.5,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Dim => "Dim",
+            Self::Normal => "Normal",
+            Self::Bright => "Bright",
+        }
+    }
+}
+
+/// Lighting settings: shadow and brightness presets plus an optional shadow map override.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightingSettings {
+    #[serde(default)]
+    pub shadows: ShadowQuality,
+    #[serde(default)]
+    pub brightness: LightBrightness,
+    /// Shadow map size in pixels, instead of the preset's
+    #[serde(default)]
+    pub shadow_map_size: Option<usize>,
+}
+
+/// What the lights end up with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightingEffects {
+    pub shadows: bool,
+    pub shadow_map_size: usize,
+    pub intensity_scale: f32,
+}
+
+impl LightingSettings {
+    /// Resolve the presets and the override, capped by the frame-time guard's `quality`.
+    pub fn effects(&self, quality: QualityLevel) -> LightingEffects {
+        let preset_size = self.shadows.shadow_map_size();
+        let mut shadow_map_size = self
+            .shadow_map_size
+            .or(preset_size)
+            .unwrap_or(PointLightShadowMap::default().size)
+            .max(1);
+        if quality >= QualityLevel::Reduced {
+            shadow_map_size = shadow_map_size.min(REDUCED_SHADOW_MAP_SIZE);
+        }
+        LightingEffects {
+            shadows: preset_size.is_some() && quality < QualityLevel::Minimal,
+            shadow_map_size,
+            intensity_scale: self.brightness.scale(),
+        }
+    }
+}
+
+/// Intensity a light was spawned with, before the brightness preset.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct BaseLightIntensity(pub f32);
+
+/// Load `config/lighting.ron` (the active profile's `lighting.ron`, see `systems::profiles`) into
+/// the [`LightingSettings`] resource.
+pub fn load_lighting_settings(mut commands: Commands, profile: Option<Res<CurrentProfile>>) {
+    let path = profile_path(profile.as_deref(), "lighting.ron", LIGHTING_CONFIG_PATH);
+    let settings = read_save_file(&path, |content| {
+        match ron::de::from_str::<LightingSettings>(content) {
+            Ok(loaded) => {
+                info!("Loaded lighting settings from {}", path.display());
+                Some(loaded)
+            }
+            Err(e) => {
+                warn!("Failed to parse lighting settings: {}; using defaults", e);
+                None
+            }
+        }
+    })
+    .unwrap_or_default();
+
+    commands.insert_resource(settings);
+}
+
+/// Apply the settings to every point light and the shadow map resolution.
+///
+/// Runs when the settings or the quality level change, or a point light is spawned.
+pub fn apply_lighting(
+    mut commands: Commands,
+    settings: Res<LightingSettings>,
+    guard: Option<Res<QualityGuard>>,
+    shadow_map: Option<ResMut<PointLightShadowMap>>,
+    mut lights: Query<(Entity, &mut PointLight, Option<&BaseLightIntensity>)>,
+    new_lights: Query<(), Added<PointLight>>,
+) {
+    let guard_changed = guard.as_ref().is_some_and(|g| g.is_changed());
+    if !settings.is_changed() && !guard_changed && new_lights.is_empty() {
+        return;
+    }
+    let quality = guard.map_or(QualityLevel::Full, |g| g.level);
+    let effects = settings.effects(quality);
+
+    if let Some(mut shadow_map) = shadow_map {
+        if shadow_map.size != effects.shadow_map_size {
+            shadow_map.size = effects.shadow_map_size;
+        }
+    }
+    for (entity, mut light, base) in lights.iter_mut() {
+        let base = match base {
+            Some(base) => base.0,
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(BaseLightIntensity(light.intensity));
+                light.intensity
+            }
+        };
+        light.intensity = base * effects.intensity_scale;
+        light.shadows_enabled = effects.shadows;
+    }
+}
+
+/// Save the settings whenever the player changes them.
+pub fn save_lighting_settings_on_change(
+    settings: Res<LightingSettings>,
+    profile: Option<Res<CurrentProfile>>,
+) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+    let path = profile_path(profile.as_deref(), "lighting.ron", LIGHTING_CONFIG_PATH);
+    let result = ron::ser::to_string_pretty(&*settings, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("failed to serialize lighting settings: {e}"))
+        .and_then(|content| write_save_file(&path, &content));
+    if let Err(e) = result {
+        warn!("Failed to save lighting settings: {}", e);
+    }
+}
+
+/// Plugin registering the lighting settings and their persistence.
+pub struct LightingPlugin;
+
+impl Plugin for LightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LightingSettings>();
+        app.add_systems(Startup, load_lighting_settings);
+        app.add_systems(
+            Update,
+            (
+                load_lighting_settings.run_if(profile_switched),
+                apply_lighting,
+                save_lighting_settings_on_change,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_resolve_and_the_quality_guard_caps_them() {
+        let settings = LightingSettings {
+            shadows: ShadowQuality::High,
+            brightness: LightBrightness::Dim,
+            shadow_map_size: None,
+        };
+        assert_eq!(
+            settings.effects(QualityLevel::Full),
+            LightingEffects {
+                shadows: true,
+                shadow_map_size: 2048,
+                intensity_scale: 0.6,
+            }
+        );
+        let reduced = settings.effects(QualityLevel::Reduced);
+        assert!(reduced.shadows);
+        assert_eq!(reduced.shadow_map_size, REDUCED_SHADOW_MAP_SIZE);
+        assert!(!settings.effects(QualityLevel::Minimal).shadows);
+
+        let overridden = LightingSettings {
+            shadow_map_size: Some(4096),
+            ..settings
+        };
+        assert_eq!(overridden.effects(QualityLevel::Full).shadow_map_size, 4096);
+        let off = LightingSettings {
+            shadows: ShadowQuality::Off,
+            ..settings
+        };
+        assert!(!off.effects(QualityLevel::Full).shadows);
+    }
+
+    #[test]
+    fn settings_parse_with_defaults() {
+        let settings: LightingSettings = ron::de::from_str("(brightness: Bright)").unwrap();
+        assert_eq!(settings.shadows, ShadowQuality::default());
+        assert_eq!(settings.shadow_map_size, None);
+        assert_eq!(ShadowQuality::High.next(), ShadowQuality::Off);
+        assert_eq!(LightBrightness::Bright.next(), LightBrightness::Dim);
+    }
+}
@@ -0,0 +1,367 @@
+//! Logging configuration and the in-game log console.
+//!
+//! `config/logging.ron` sets what is logged and where, on native builds:
+//!
+//! ```ron
+//! (
+//!     level: "info",
+//!     targets: {"brkrs::systems::respawn": "debug", "bevy_render": "warn"},
+//!     file: Some("logs/brkrs.log"),
+//!     console_targets: ["brkrs::systems::respawn", "brkrs::systems::level_switch"],
+//! )
+//! ```
+//!
+//! `level` is the default level, `targets` sets levels per target (module path prefix), `file`
+//! also writes the log to a file (started afresh each run), and `console_targets` picks the
+//! targets whose lines the log console keeps (all of the game's by default). `RUST_LOG` still
+//! replaces `level` and `targets` when set.
+//!
+//! [`BrkrsConfig::default_plugins`](crate::BrkrsConfig::default_plugins) sets Bevy's `LogPlugin`
+//! up from [`LoggingConfig`]; its extra layer ([`logging_layers`]) keeps the last
+//! [`LOG_CONSOLE_CAPACITY`] lines in [`LogBuffer`]. In cheat mode [`LOG_CONSOLE_KEY`] shows them in
+//! the log console (`ui::log_console`), scrolled with `PageUp` / `PageDown` and back to the newest
+//! line with `End`, so testers can follow diagnostics without a terminal.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use bevy::log::tracing::field::{Field, Visit};
+use bevy::log::tracing::{Event, Level, Subscriber};
+use bevy::log::tracing_subscriber::layer::Context;
+use bevy::log::tracing_subscriber::Layer;
+use bevy::log::{BoxedLayer, LogPlugin, DEFAULT_FILTER};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::systems::cheat_mode::CheatModeState;
+
+/// Native path of the optional logging config file.
+pub const LOGGING_CONFIG_PATH: &str = "config/logging.ron";
+/// Lines kept for the log console.
+pub const LOG_CONSOLE_CAPACITY: usize = 500;
+/// Lines the log console shows at once.
+pub const LOG_CONSOLE_ROWS: usize = 20;
+/// Key that shows or hides the log console (cheat mode only).
+pub const LOG_CONSOLE_KEY: KeyCode = KeyCode::F12;
+
+/// What is logged and where.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoggingConfig {
+    /// Default level: `error`, `warn`, `info`, `debug` or `trace`
+    #[serde(default = "default_level")]
+    pub level: String,
+    /// Levels per target, overriding `level`
+    #[serde(default)]
+    pub targets: BTreeMap<String, String>,
+    /// File the log is also written to
+    #[serde(default)]
+    pub file: Option<PathBuf>,
+    /// Targets whose lines the log console keeps
+    #[serde(default = "default_console_targets")]
+    pub console_targets: Vec<String>,
+}
+
+fn default_level() -> String {
+    "info".to_string()
+}
+
+fn default_console_targets() -> Vec<String> {
+    vec!["brkrs".to_string()]
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: default_level(),
+            targets: BTreeMap::new(),
+            file: None,
+            console_targets: default_console_targets(),
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// Read the config from `path`, or the defaults when it is missing or invalid. The logger is
+    /// not running yet, so problems are printed.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &std::path::Path) -> Self {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        ron::de::from_str(&content).unwrap_or_else(|e| {
+            eprintln!(
+                "Failed to parse {}: {}; using the default logging",
+                path.display(),
+                e
+            );
+            Self::default()
+        })
+    }
+
+    /// The default level, `info` when it is not a level name.
+    pub fn level(&self) -> Level {
+        self.level.parse().unwrap_or(Level::INFO)
+    }
+
+    /// `EnvFilter` directives of the per-target levels, after Bevy's own defaults.
+    pub fn filter(&self) -> String {
+        std::iter::once(DEFAULT_FILTER.to_string())
+            .chain(
+                self.targets
+                    .iter()
+                    .map(|(target, level)| format!("{target}={level}")),
+            )
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Whether the log console keeps lines of `target`.
+    pub fn in_console(&self, target: &str) -> bool {
+        self.console_targets
+            .iter()
+            .any(|prefix| target.starts_with(prefix.as_str()))
+    }
+
+    /// Bevy's `LogPlugin` set up from this config.
+    pub fn log_plugin(&self) -> LogPlugin {
+        LogPlugin {
+            level: self.level(),
+            filter: self.filter(),
+            custom_layer: logging_layers,
+            ..default()
+        }
+    }
+}
+
+/// One logged line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// The last lines logged.
+#[derive(Debug, Clone, Default)]
+pub struct LogLines {
+    /// Oldest first
+    pub lines: VecDeque<LogLine>,
+    /// Lines logged since startup, including those dropped since
+    pub written: u64,
+}
+
+impl LogLines {
+    /// Keep `line`, dropping the oldest one once [`LOG_CONSOLE_CAPACITY`] are kept.
+    pub fn push(&mut self, line: LogLine) {
+        if self.lines.len() >= LOG_CONSOLE_CAPACITY {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+        self.written += 1;
+    }
+}
+
+/// Lines for the log console, shared with the logger.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LogBuffer(pub Arc<Mutex<LogLines>>);
+
+impl LogBuffer {
+    pub fn push(&self, line: LogLine) {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(line);
+    }
+
+    /// Lines kept.
+    pub fn len(&self) -> usize {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .lines
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn snapshot(&self) -> LogLines {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+/// Collects the `message` field of an event.
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.insert_str(0, &format!("{value:?}"));
+        } else {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.0.insert_str(0, value);
+        } else {
+            self.0.push_str(&format!(" {}={}", field.name(), value));
+        }
+    }
+}
+
+/// Logger layer keeping the lines of the console targets in a [`LogBuffer`].
+pub struct LogCaptureLayer {
+    pub buffer: LogBuffer,
+    pub config: LoggingConfig,
+}
+
+impl<S: Subscriber> Layer<S> for LogCaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let target = metadata.target();
+        if !self.config.in_console(target) {
+            return;
+        }
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.buffer.push(LogLine {
+            level: *metadata.level(),
+            target: target.to_string(),
+            message: visitor.0,
+        });
+    }
+}
+
+/// `LogPlugin::custom_layer`: capture lines for the console into a new [`LogBuffer`] resource and,
+/// when configured, write the log to a file.
+pub fn logging_layers(app: &mut App) -> Option<BoxedLayer> {
+    let config = app
+        .world()
+        .get_resource::<LoggingConfig>()
+        .cloned()
+        .unwrap_or_default();
+    let buffer = LogBuffer::default();
+    app.insert_resource(buffer.clone());
+    let file = config.file.clone();
+    let mut layers: Vec<BoxedLayer> = vec![Box::new(LogCaptureLayer { buffer, config })];
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(path) = &file {
+        match open_log_file(path) {
+            Ok(file) => layers.push(Box::new(
+                bevy::log::tracing_subscriber::fmt::layer()
+                    .with_ansi(false)
+                    .with_writer(Mutex::new(file)),
+            )),
+            Err(e) => eprintln!("Failed to open log file {}: {}", path.display(), e),
+        }
+    }
+    Some(Box::new(layers))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn open_log_file(path: &std::path::Path) -> std::io::Result<std::fs::File> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::File::create(path)
+}
+
+/// Whether the log console is showing, and how far it is scrolled back.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogConsoleState {
+    pub open: bool,
+    /// Lines between the newest one and the last line shown
+    pub scroll: usize,
+}
+
+/// Show or hide the log console with [`LOG_CONSOLE_KEY`] in cheat mode, and scroll it.
+pub fn handle_log_console_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    cheat: Res<CheatModeState>,
+    buffer: Option<Res<LogBuffer>>,
+    mut console: ResMut<LogConsoleState>,
+) {
+    if !cheat.is_active() {
+        if console.open {
+            console.open = false;
+        }
+        return;
+    }
+    if keyboard.just_pressed(LOG_CONSOLE_KEY) {
+        console.open = !console.open;
+        console.scroll = 0;
+    }
+    if !console.open {
+        return;
+    }
+    let len = buffer.map_or(0, |buffer| buffer.len());
+    let max_scroll = len.saturating_sub(LOG_CONSOLE_ROWS);
+    let page = LOG_CONSOLE_ROWS / 2;
+    let mut scroll = console.scroll;
+    if keyboard.just_pressed(KeyCode::PageUp) {
+        scroll = (scroll + page).min(max_scroll);
+    }
+    if keyboard.just_pressed(KeyCode::PageDown) {
+        scroll = scroll.saturating_sub(page);
+    }
+    if keyboard.just_pressed(KeyCode::End) {
+        scroll = 0;
+    }
+    if scroll != console.scroll {
+        console.scroll = scroll;
+    }
+}
+
+/// Plugin registering the log console state and input; logging itself is set up with
+/// `LogPlugin` (see [`LoggingConfig::log_plugin`]).
+pub struct LoggingPlugin;
+
+impl Plugin for LoggingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LogConsoleState>();
+        app.add_systems(Update, handle_log_console_input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_levels_follow_bevys_filter() {
+        let config: LoggingConfig = ron::de::from_str(
+            r#"(level: "debug", targets: {"brkrs::systems::respawn": "trace", "wgpu": "off"})"#,
+        )
+        .unwrap();
+        assert_eq!(config.level(), Level::DEBUG);
+        assert_eq!(
+            config.filter(),
+            format!("{DEFAULT_FILTER},brkrs::systems::respawn=trace,wgpu=off")
+        );
+        assert_eq!(config.console_targets, vec!["brkrs".to_string()]);
+
+        let typo: LoggingConfig = ron::de::from_str(r#"(level: "loud")"#).unwrap();
+        assert_eq!(typo.level(), Level::INFO);
+    }
+
+    #[test]
+    fn console_targets_are_prefixes() {
+        let config = LoggingConfig {
+            console_targets: vec!["brkrs::systems::respawn".to_string()],
+            ..default()
+        };
+        assert!(config.in_console("brkrs::systems::respawn"));
+        assert!(!config.in_console("brkrs::systems::level_switch"));
+    }
+}
@@ -0,0 +1,202 @@
+//! Life-loss slow motion: the last ball slows down and the camera leans in just before it is lost.
+//!
+//! When the only ball left heads into the lower goal and is within [`SLOW_MOTION_MARGIN`] of it,
+//! [`start_loss_slow_motion`] drops the virtual clock to [`SLOW_MOTION_FACTOR`] of the current
+//! simulation speed (`systems::sim_speed`, game speed included), so physics and gameplay timers
+//! slow down together.
+//! While it lasts, [`update_loss_slow_motion`] moves the camera part of the way towards the ball.
+//!
+//! The effect ends, restoring the clock and the camera, when the loss resolves (the ball is
+//! despawned or respawned), when the ball turns away from the goal, after [`SLOW_MOTION_SECS`] of
+//! real time, or when the player skips it with one of [`SKIP_KEYS`] or a click. It is held while the
+//! game is paused, and balls that cannot be lost (a ball-save shield is up, or the ball is in its
+//! respawn grace period) do not trigger it. With `GameplayConfig::reduced_motion` the clock still
+//! slows down but the camera stays put.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+
+use crate::gameplay_config::GameplayConfig;
+use crate::pause::PauseState;
+use crate::systems::ball_save::BallSaveShield;
+use crate::systems::respawn::RespawnGrace;
+use crate::systems::sim_speed::SimSpeed;
+use crate::systems::spawning::MainCamera;
+use crate::{Ball, BallFrozen, LowerGoal};
+
+/// Distance to the lower goal at which the slow motion starts.
+pub const SLOW_MOTION_MARGIN: f32 = 3.0;
+/// Share of the simulation speed the clock runs at during the slow motion.
+pub const SLOW_MOTION_FACTOR: f32 = 0.25;
+/// Longest the slow motion lasts, in real seconds.
+pub const SLOW_MOTION_SECS: f32 = 1.0;
+/// Share of the way from the camera to the ball covered by the zoom.
+pub const ZOOM_FRACTION: f32 = 0.35;
+/// Keys skipping the slow motion (as does a click).
+pub const SKIP_KEYS: [KeyCode; 3] = [KeyCode::Space, KeyCode::Enter, KeyCode::NumpadEnter];
+/// Time the zoom takes to reach [`ZOOM_FRACTION`], in real seconds.
+const ZOOM_IN_SECS: f32 = 0.2;
+
+/// Running slow motion, if any.
+#[derive(Resource, Debug, Default)]
+pub struct LossSlowMotion {
+    /// Ball about to be lost
+    pub ball: Option<Entity>,
+    /// Real seconds since the slow motion started (not counting pauses)
+    pub elapsed: f32,
+    /// Camera position before the zoom
+    camera_origin: Option<Vec3>,
+}
+
+impl LossSlowMotion {
+    pub fn is_active(&self) -> bool {
+        self.ball.is_some()
+    }
+}
+
+/// Whether a ball at `position` moving with `velocity` is about to cross the goal at `goal_x`.
+pub fn approaching_goal(position: Vec3, velocity: Vec3, goal_x: f32) -> bool {
+    velocity.x > 0.0 && position.x < goal_x && goal_x - position.x <= SLOW_MOTION_MARGIN
+}
+
+/// Start the slow motion when the last ball is about to be lost.
+pub fn start_loss_slow_motion(
+    mut slow_motion: ResMut<LossSlowMotion>,
+    pause: Option<Res<PauseState>>,
+    balls: Query<
+        (Entity, &Transform, Option<&Velocity>, Has<RespawnGrace>),
+        (With<Ball>, Without<BallFrozen>),
+    >,
+    all_balls: Query<(), With<Ball>>,
+    goals: Query<&Transform, With<LowerGoal>>,
+    shields: Query<(), With<BallSaveShield>>,
+    sim: Option<Res<SimSpeed>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if slow_motion.is_active()
+        || pause.is_some_and(|pause| !matches!(*pause, PauseState::Active))
+        || all_balls.iter().count() != 1
+        || !shields.is_empty()
+    {
+        return;
+    }
+    let Some(goal) = goals.iter().next() else {
+        return;
+    };
+    let Some((ball, transform, velocity, graced)) = balls.iter().next() else {
+        return;
+    };
+    let velocity = velocity.map_or(Vec3::ZERO, |v| v.linvel);
+    if graced || !approaching_goal(transform.translation, velocity, goal.translation.x) {
+        return;
+    }
+    let speed = sim.map_or(1.0, |sim| sim.effective());
+    time.set_relative_speed(speed * SLOW_MOTION_FACTOR);
+    *slow_motion = LossSlowMotion {
+        ball: Some(ball),
+        ..Default::default()
+    };
+}
+
+/// Advance the slow motion and the camera zoom; restore both once it ends.
+pub fn update_loss_slow_motion(
+    mut slow_motion: ResMut<LossSlowMotion>,
+    pause: Option<Res<PauseState>>,
+    balls: Query<(&Transform, Option<&Velocity>), (With<Ball>, Without<MainCamera>)>,
+    goals: Query<&Transform, (With<LowerGoal>, Without<MainCamera>)>,
+    mut cameras: Query<&mut Transform, With<MainCamera>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    config: Option<Res<GameplayConfig>>,
+    sim: Option<Res<SimSpeed>>,
+    real: Res<Time<Real>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    let Some(ball) = slow_motion.ball else {
+        return;
+    };
+    if pause.is_some_and(|pause| !matches!(*pause, PauseState::Active)) {
+        return;
+    }
+    slow_motion.elapsed += real.delta_secs();
+
+    let skipped = keys.is_some_and(|keys| keys.any_just_pressed(SKIP_KEYS))
+        || mouse.is_some_and(|mouse| mouse.just_pressed(MouseButton::Left));
+    let goal_x = goals.iter().next().map(|goal| goal.translation.x);
+    let ball_state = balls.get(ball).ok().and_then(|(transform, velocity)| {
+        let velocity = velocity.map_or(Vec3::ZERO, |v| v.linvel);
+        goal_x
+            .is_some_and(|goal_x| velocity.x > 0.0 && transform.translation.x < goal_x + 1.0)
+            .then_some(transform.translation)
+    });
+
+    let Some(ball_position) =
+        ball_state.filter(|_| !skipped && slow_motion.elapsed < SLOW_MOTION_SECS)
+    else {
+        time.set_relative_speed(sim.map_or(1.0, |sim| sim.effective()));
+        if let Some(origin) = slow_motion.camera_origin {
+            for mut transform in cameras.iter_mut() {
+                transform.translation = origin;
+            }
+        }
+        *slow_motion = LossSlowMotion::default();
+        return;
+    };
+
+    if config.is_some_and(|config| config.reduced_motion) {
+        return;
+    }
+    let zoom = ZOOM_FRACTION * (slow_motion.elapsed / ZOOM_IN_SECS).min(1.0);
+    for mut transform in cameras.iter_mut() {
+        let origin = *slow_motion
+            .camera_origin
+            .get_or_insert(transform.translation);
+        transform.translation = origin.lerp(ball_position, zoom);
+    }
+}
+
+/// Plugin registering the life-loss slow motion.
+pub struct LossSlowMotionPlugin;
+
+impl Plugin for LossSlowMotionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LossSlowMotion>();
+        app.add_systems(
+            Update,
+            (start_loss_slow_motion, update_loss_slow_motion).chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_balls_heading_into_the_goal_nearby_count() {
+        let goal_x = 15.5;
+        assert!(approaching_goal(
+            Vec3::new(13.0, 2.0, 0.0),
+            Vec3::X * 10.0,
+            goal_x
+        ));
+        // Too far away
+        assert!(!approaching_goal(
+            Vec3::new(10.0, 2.0, 0.0),
+            Vec3::X * 10.0,
+            goal_x
+        ));
+        // Moving away from the goal
+        assert!(!approaching_goal(
+            Vec3::new(14.0, 2.0, 0.0),
+            Vec3::NEG_X,
+            goal_x
+        ));
+        // Already across
+        assert!(!approaching_goal(
+            Vec3::new(16.0, 2.0, 0.0),
+            Vec3::X,
+            goal_x
+        ));
+    }
+}
@@ -9,7 +9,7 @@ use bevy::prelude::*;
 use bevy::render::render_resource::PrimitiveTopology;
 use bevy_rapier3d::prelude::{
     ActiveEvents, Ccd, Collider, CollisionEvent, CollisionGroups, GravityScale, Group, LockedAxes,
-    Restitution, RigidBody, SolverGroups, Velocity,
+    Restitution, RigidBody, Sensor, SolverGroups, Velocity,
 };
 
 use crate::signals::{
@@ -430,7 +430,7 @@ fn detect_goal_collision(
 fn detect_merkaba_wall_collision(
     collision_events: Option<MessageReader<CollisionEvent>>,
     merkabas: Query<Entity, With<Merkaba>>,
-    walls: Query<Entity, With<Border>>,
+    walls: Query<Entity, (With<Border>, Without<Sensor>)>,
     mut writer: Option<bevy::ecs::message::MessageWriter<MerkabaWallCollision>>,
 ) {
     if let Some(mut collision_events) = collision_events {
@@ -1,31 +1,152 @@
 /// Game systems module
 ///
 /// Contains all game system implementations organized by functionality
+pub mod angle_guard;
+pub mod armored_bricks;
+pub mod assist;
 pub mod audio;
+pub mod autosave;
+pub mod ball_save;
+pub mod ball_size;
+pub mod brick_debris;
+pub mod brick_groups;
+pub mod brick_shapes;
+pub mod brick_surfaces;
+pub mod bumpers;
+pub mod campaign;
+pub mod cheat_console;
 pub mod cheat_mode;
+pub mod checkpoints;
+pub mod cloud_sync;
+pub mod completion;
+pub mod crash_report;
+pub mod display;
+pub mod durability;
+pub mod energy;
+pub mod explosion;
+pub mod floor_zones;
+pub mod force_fields;
+pub mod fov_kick;
+pub mod game_complete;
+pub mod ghost_path;
 pub mod gravity;
+pub mod gravity_wells;
 pub mod grid_debug;
+pub mod heatmap;
+pub mod hints;
+pub mod input_buffer;
+pub mod inspector;
+pub mod invisible_bricks;
+pub mod level_clock;
+pub mod level_fetch;
 pub mod level_switch;
+pub mod lighting;
+pub mod logging;
+pub mod loss_slowmo;
 pub mod merkaba;
 pub mod multi_hit;
+pub mod near_miss;
+pub mod one_way_gates;
 pub mod paddle_size;
+pub mod picking;
+pub mod playlist;
+pub mod portal_edges;
+pub mod post_processing;
+pub mod powerups;
+pub mod preflight;
+pub mod profiles;
+pub mod quality_guard;
+pub mod remix;
 pub mod respawn;
+pub mod rng;
 pub mod scoring;
 pub mod sets;
+pub mod sim_speed;
+pub mod sound_packs;
 pub mod spawning;
+pub mod sticky_paddle;
+pub mod survival;
+pub mod tas;
+pub mod team_bricks;
 pub mod textures;
+pub mod themes;
+pub mod timed_effects;
+pub mod tutorial;
+pub mod waves;
+pub mod web_storage;
+pub mod wrecking_ball;
 
-pub use crate::signals::BallWallHit;
-pub use audio::{AudioConfig, AudioPlugin, LevelCompleted, LevelStarted, SoundType};
+pub use crate::signals::{BallBrickBounce, BallWallHit};
+pub use angle_guard::AngleGuardPlugin;
+pub use armored_bricks::ArmoredBricksPlugin;
+pub use assist::{AssistPlugin, AssistShield};
+pub use audio::{AudioBus, AudioConfig, AudioPlugin, LevelCompleted, LevelStarted, SoundType};
+pub use autosave::{AutosavePlugin, SavedProgress};
+pub use ball_save::BallSaveShield;
+pub use ball_size::{BallSizeEffect, BallSizePlugin};
+pub use brick_debris::{BrickDebrisConfig, BrickDebrisPlugin};
+pub use brick_groups::{BrickGroup, BrickGroupsPlugin, GroupLocked};
+pub use brick_shapes::{BrickShape, BrickShapesPlugin};
+pub use brick_surfaces::{BrickSurface, BrickSurfaces, BrickSurfacesPlugin};
+pub use bumpers::{Bumper, BumpersPlugin};
+pub use campaign::{CampaignManifest, CampaignPlugin, SecretExitState};
+pub use cheat_console::{CheatConsole, CheatConsolePlugin, SpawnBrickRequested};
 pub use cheat_mode::{CheatModePlugin, CheatModeState, CheatModeToggled};
+pub use checkpoints::{CheckpointsPlugin, LevelCheckpoints};
+pub use cloud_sync::{CloudSyncPlugin, SaveBackend, SyncConfig};
+pub use completion::{CompletionPlugin, CompletionRule, LevelCompletion};
+pub use crash_report::{CrashLog, CrashReportPlugin};
+pub use display::{DisplayMode, DisplayPlugin, DisplaySettings};
+pub use durability::{Durability, DurabilityPlugin, DurableBrickHit};
+pub use energy::{EnergyMeter, EnergyPlugin};
+pub use explosion::{BrickExploded, ExplosionPlugin, FuseTimer};
+pub use floor_zones::{FloorZones, FloorZonesPlugin};
+pub use force_fields::{ForceFields, ForceFieldsPlugin};
+pub use fov_kick::{FovKick, FovKickPlugin};
+pub use game_complete::{GameCompletePlugin, GameCompleted};
+pub use ghost_path::{GhostPathPlugin, LevelGhost};
 pub use gravity::GravityChanged;
+pub use gravity_wells::{GravityWells, GravityWellsPlugin};
+pub use heatmap::{BallHeatmap, HeatmapPlugin};
+pub use hints::{HintSettings, HintsPlugin};
+pub use input_buffer::{InputBufferPlugin, PaddleCatchUp};
+pub use inspector::{InspectorPlugin, InspectorState};
+pub use invisible_bricks::{Concealed, InvisibleBricksPlugin};
+pub use level_clock::{LevelClock, LevelClockPlugin};
+pub use level_fetch::{LevelFetchPlugin, LevelFiles};
 pub use level_switch::{
     LevelSwitchPlugin, LevelSwitchRequested, LevelSwitchSource, LevelSwitchState,
 };
+pub use lighting::{LightingPlugin, LightingSettings};
+pub use logging::{LogBuffer, LoggingConfig, LoggingPlugin};
+pub use loss_slowmo::{LossSlowMotion, LossSlowMotionPlugin};
 pub use merkaba::{MerkabaPlugin, PendingMerkabaSpawn, PendingMerkabaSpawns};
 pub use multi_hit::MultiHitBrickHit;
+pub use near_miss::{BrickNearMiss, NearMissPlugin};
+pub use one_way_gates::{OneWayGates, OneWayGatesPlugin};
 pub use paddle_size::{
     PaddleSizeEffect, PaddleSizeEffectApplied, PaddleSizePlugin, SizeEffectType,
 };
+pub use picking::BrickPicker;
+pub use playlist::{Playlist, PlaylistPlugin};
+pub use portal_edges::{PortalEdges, PortalEdgesPlugin};
+pub use post_processing::{PostProcessingConfig, PostProcessingPlugin};
+pub use powerups::{PowerupCapsule, PowerupCollected, PowerupKind, PowerupPlugin};
+pub use preflight::{PreflightPlugin, PreflightReport};
+pub use profiles::{CurrentProfile, ProfileData, ProfilesPlugin};
+pub use quality_guard::{QualityGuard, QualityGuardPlugin, QualityLevel};
+pub use remix::RemixPlugin;
 pub use respawn::{InputLocked, RespawnPlugin, RespawnSystems};
+pub use rng::GameRng;
+pub use sim_speed::{SimSpeed, SimSpeedPlugin};
+pub use sound_packs::SoundPacks;
+pub use sticky_paddle::{BallStuck, StickyPaddle, StickyPaddlePlugin};
+pub use survival::{SurvivalMode, SurvivalPlugin};
+pub use tas::{TasPlayback, TasPlugin, TasRecorder};
+pub use team_bricks::{BallTeam, Team, TeamBrick, TeamBricksPlugin, TeamScores};
 pub use textures::TextureManifestPlugin;
+pub use themes::{ActiveTheme, ThemePacks, ThemesPlugin};
+pub use timed_effects::{ActiveEffects, EffectSnapshot, TimedEffect};
+pub use tutorial::{TutorialPlugin, TutorialState};
+pub use waves::{WaveSchedule, WavesPlugin};
+pub use wrecking_ball::{WreckingBallConfig, WreckingBallPlugin};
@@ -21,6 +21,7 @@
 
 use bevy::prelude::*;
 
+use crate::level_format::{is_multi_hit_brick, MULTI_HIT_BRICK_1};
 use crate::BrickTypeId;
 
 /// Event emitted when a multi-hit brick (indices 10-13) is hit by the ball.
@@ -55,6 +56,18 @@ pub struct MultiHitBrickHit {
     pub new_type: u8,
 }
 
+impl MultiHitBrickHit {
+    /// Hits the brick takes after this one before it breaks: 4 after the first hit on index 13,
+    /// 1 once it turned into a simple stone.
+    pub fn hits_remaining(&self) -> u8 {
+        if is_multi_hit_brick(self.new_type) {
+            self.new_type - MULTI_HIT_BRICK_1 + 2
+        } else {
+            1
+        }
+    }
+}
+
 /// System that watches for brick type changes and swaps materials accordingly.
 ///
 /// This system detects changes to `BrickTypeId` components and updates the
@@ -127,4 +140,17 @@ mod tests {
         assert_eq!(cloned.previous_type, event.previous_type);
         assert_eq!(cloned.new_type, event.new_type);
     }
+
+    #[test]
+    fn hits_remaining_counts_down_to_the_stone() {
+        let hit = |previous_type, new_type| MultiHitBrickHit {
+            entity: Entity::PLACEHOLDER,
+            previous_type,
+            new_type,
+        };
+        assert_eq!(hit(13, 12).hits_remaining(), 4);
+        assert_eq!(hit(12, 11).hits_remaining(), 3);
+        assert_eq!(hit(11, 10).hits_remaining(), 2);
+        assert_eq!(hit(10, 20).hits_remaining(), 1);
+    }
 }
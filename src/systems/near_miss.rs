@@ -0,0 +1,265 @@
+//! Near-miss brick highlight.
+//!
+//! When a ball passes within [`NEAR_MISS_MARGIN`] of a brick without hitting it, the brick flashes
+//! a short emissive rim highlight. Pure juice: it has no gameplay effect.
+//!
+//! # Detection
+//! Each frame every ball runs a Rapier shape intersection test with a ball shape slightly larger
+//! than its own collider. Bricks found this way are "near" that ball; a brick that stops being near
+//! without the ball having touched it in between is a near miss ([`BrickNearMiss`]).
+//! Hidden (`Concealed`) bricks and lit timed bombs are left alone.
+//!
+//! # Visuals
+//! Bricks share materials, so a flashing brick gets its own copy of its material
+//! ([`NearMissFlash`]). When the flash ends the original material is put back (unless something
+//! else replaced the material meanwhile) and the copy is dropped.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::prelude::*;
+use bevy_rapier3d::parry::shape::Ball as BallShape;
+use bevy_rapier3d::prelude::{CollisionEvent, QueryFilter, ReadRapierContext};
+
+use crate::systems::explosion::FuseTimer;
+use crate::systems::invisible_bricks::Concealed;
+use crate::{Ball, Brick, MarkedForDespawn, BALL_RADIUS};
+
+/// Extra reach around the ball, in world units, that still counts as "near".
+pub const NEAR_MISS_MARGIN: f32 = 0.5;
+/// Duration of the highlight, in seconds.
+pub const NEAR_MISS_FLASH_SECS: f32 = 0.35;
+
+/// Peak emissive boost of the highlight.
+const FLASH_EMISSIVE: LinearRgba = LinearRgba::rgb(0.9, 0.9, 1.2);
+
+/// A ball passed close to a brick without touching it.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrickNearMiss {
+    pub brick: Entity,
+    pub ball: Entity,
+}
+
+/// Which bricks are currently near which ball, and whether the ball touched them meanwhile.
+#[derive(Debug, Default, Clone)]
+pub struct NearMissTracker {
+    near: HashMap<(Entity, Entity), bool>,
+}
+
+impl NearMissTracker {
+    /// The ball touched the brick; its current pass no longer counts as a miss.
+    pub fn touch(&mut self, ball: Entity, brick: Entity) {
+        self.near.insert((ball, brick), true);
+    }
+
+    /// Record the bricks now near `ball`; returns the bricks that just left its proximity
+    /// untouched.
+    pub fn update(&mut self, ball: Entity, nearby: &HashSet<Entity>) -> Vec<Entity> {
+        let mut missed = Vec::new();
+        self.near.retain(|&(b, brick), touched| {
+            if b != ball || nearby.contains(&brick) {
+                return true;
+            }
+            if !*touched {
+                missed.push(brick);
+            }
+            false
+        });
+        for &brick in nearby {
+            self.near.entry((ball, brick)).or_insert(false);
+        }
+        missed
+    }
+
+    /// Forget balls that no longer exist.
+    pub fn retain_balls(&mut self, alive: impl Fn(Entity) -> bool) {
+        self.near.retain(|&(ball, _), _| alive(ball));
+    }
+}
+
+/// Temporary highlight on a brick, with the material to restore afterwards.
+#[derive(Component, Debug, Clone)]
+pub struct NearMissFlash {
+    pub timer: Timer,
+    pub original: Handle<StandardMaterial>,
+    pub glow: Handle<StandardMaterial>,
+}
+
+/// Find bricks near each ball and report the ones passed without a hit.
+pub fn detect_near_misses(
+    rapier_context: ReadRapierContext,
+    mut collisions: MessageReader<CollisionEvent>,
+    balls: Query<(Entity, &Transform), With<Ball>>,
+    bricks: Query<(), (With<Brick>, Without<MarkedForDespawn>)>,
+    mut tracker: Local<NearMissTracker>,
+    mut near_misses: MessageWriter<BrickNearMiss>,
+) {
+    for event in collisions.read() {
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        if balls.contains(*e1) {
+            tracker.touch(*e1, *e2);
+        } else if balls.contains(*e2) {
+            tracker.touch(*e2, *e1);
+        }
+    }
+    tracker.retain_balls(|ball| balls.contains(ball));
+
+    let Ok(context) = rapier_context.single() else {
+        return;
+    };
+    let probe = BallShape::new(BALL_RADIUS + NEAR_MISS_MARGIN);
+    for (ball, transform) in balls.iter() {
+        let mut nearby = HashSet::new();
+        context.intersect_shape(
+            transform.translation,
+            Quat::IDENTITY,
+            &probe,
+            QueryFilter::default().exclude_collider(ball),
+            |entity| {
+                if bricks.contains(entity) {
+                    nearby.insert(entity);
+                }
+                true
+            },
+        );
+        for brick in tracker.update(ball, &nearby) {
+            if bricks.contains(brick) {
+                near_misses.write(BrickNearMiss { brick, ball });
+            }
+        }
+    }
+}
+
+/// Start (or restart) the highlight on bricks that were just missed.
+pub fn start_near_miss_flash(
+    mut commands: Commands,
+    mut near_misses: MessageReader<BrickNearMiss>,
+    mut flashing: Query<&mut NearMissFlash>,
+    bricks: Query<
+        &MeshMaterial3d<StandardMaterial>,
+        (
+            With<Brick>,
+            Without<NearMissFlash>,
+            Without<Concealed>,
+            Without<FuseTimer>,
+        ),
+    >,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let mut started = HashSet::new();
+    for miss in near_misses.read() {
+        if let Ok(mut flash) = flashing.get_mut(miss.brick) {
+            flash.timer.reset();
+            continue;
+        }
+        if !started.insert(miss.brick) {
+            continue;
+        }
+        let Ok(material) = bricks.get(miss.brick) else {
+            continue;
+        };
+        let Some(assets) = materials.as_deref_mut() else {
+            return;
+        };
+        let Some(copy) = assets.get(&material.0).cloned() else {
+            continue;
+        };
+        let glow = assets.add(copy);
+        commands.entity(miss.brick).insert((
+            MeshMaterial3d(glow.clone()),
+            NearMissFlash {
+                timer: Timer::from_seconds(NEAR_MISS_FLASH_SECS, TimerMode::Once),
+                original: material.0.clone(),
+                glow,
+            },
+        ));
+    }
+}
+
+/// Fade the highlight out and restore the shared material when it is done.
+pub fn fade_near_miss_flash(
+    mut commands: Commands,
+    mut flashes: Query<(
+        Entity,
+        &mut NearMissFlash,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+    time: Res<Time>,
+) {
+    let Some(mut materials) = materials else {
+        return;
+    };
+    for (entity, mut flash, current) in flashes.iter_mut() {
+        flash.timer.tick(time.delta());
+        if flash.timer.is_finished() {
+            let mut brick = commands.entity(entity);
+            brick.remove::<NearMissFlash>();
+            if current.0 == flash.glow {
+                brick.insert(MeshMaterial3d(flash.original.clone()));
+            }
+            materials.remove(&flash.glow);
+            continue;
+        }
+        let Some(base) = materials.get(&flash.original).map(|m| m.emissive) else {
+            continue;
+        };
+        if let Some(glow) = materials.get_mut(&flash.glow) {
+            glow.emissive = base + FLASH_EMISSIVE * (1.0 - flash.timer.fraction());
+        }
+    }
+}
+
+/// Plugin registering the near-miss highlight.
+pub struct NearMissPlugin;
+
+impl Plugin for NearMissPlugin {
+    fn build(&self, app: &mut App) {
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_message::<BrickNearMiss>();
+        app.add_message::<CollisionEvent>();
+        app.add_systems(
+            Update,
+            (
+                detect_near_misses.run_if(crate::pause::not_paused),
+                start_near_miss_flash,
+                fade_near_miss_flash,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(n: u32) -> Entity {
+        Entity::from_raw_u32(n).unwrap()
+    }
+
+    #[test]
+    fn leaving_proximity_untouched_is_a_miss() {
+        let mut tracker = NearMissTracker::default();
+        let (ball, brick) = (entity(1), entity(2));
+        assert!(tracker.update(ball, &HashSet::from([brick])).is_empty());
+        assert!(tracker.update(ball, &HashSet::from([brick])).is_empty());
+        assert_eq!(tracker.update(ball, &HashSet::new()), vec![brick]);
+        // Forgotten once reported
+        assert!(tracker.update(ball, &HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn touched_bricks_and_other_balls_are_not_misses() {
+        let mut tracker = NearMissTracker::default();
+        let (ball, other, brick) = (entity(1), entity(2), entity(3));
+        tracker.update(ball, &HashSet::from([brick]));
+        tracker.update(other, &HashSet::from([brick]));
+        tracker.touch(ball, brick);
+        assert!(tracker.update(ball, &HashSet::new()).is_empty());
+        assert_eq!(tracker.update(other, &HashSet::new()), vec![brick]);
+    }
+}
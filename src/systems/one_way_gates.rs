@@ -0,0 +1,370 @@
+//! Level-defined one-way gates.
+//!
+//! A gate is a thin barrier across a one-cell-thick row or column range that balls can cross in
+//! its `direction` only. Ranges refer to the level grid (20x20 unless the level declares another
+//! `resolution`):
+//!
+//! ```ron
+//! gates: Some([
+//!     (rows: (12, 12), cols: (6, 13), direction: Up),   // balls may pass towards row 0 only
+//!     (rows: (3, 8), cols: (15, 15), direction: Right), // balls may pass towards column 19 only
+//! ]),
+//! ```
+//!
+//! Gates have no collider. [`enforce_one_way_gates`] compares each ball's position with the one it
+//! had last frame (so fast balls cannot tunnel through): a ball reaching a gate from its exit side
+//! while moving against the gate's direction is put back on the exit side, just touching the gate,
+//! and its velocity is mirrored across the gate as if it had bounced off a wall. Balls travelling
+//! in the gate's direction are never touched.
+//!
+//! Every gate is drawn as a translucent bar; a direction arrow is spawned as part of the grid
+//! overlay, so it is shown together with the grid in wireframe mode.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+use serde::{Deserialize, Serialize};
+
+use crate::level_format::{matrix_resolution, validate_cell_range_in};
+use crate::level_loader::CurrentLevel;
+use crate::systems::floor_zones::cell_range_bounds;
+use crate::{Ball, GridOverlay, GridResolution, BALL_RADIUS, CELL_HEIGHT};
+
+/// Thickness of the gate bars.
+const GATE_THICKNESS: f32 = 0.1;
+/// Height of the gate bars and debug arrows (the gameplay plane).
+const GATE_Y: f32 = 2.0;
+/// Movement between two frames beyond which a ball is considered teleported (respawn, level
+/// change) rather than moving, so it is not caught by gates along the way.
+const MAX_FRAME_TRAVEL: f32 = CELL_HEIGHT * 2.0;
+
+/// Direction in which balls may cross a gate, in grid terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GateDirection {
+    /// Towards row 0
+    Up,
+    /// Towards row 19
+    Down,
+    /// Towards column 0
+    Left,
+    /// Towards column 19
+    Right,
+}
+
+impl GateDirection {
+    /// World-space (x, z) unit vector of the direction. Rows run along +X, columns along -Z.
+    pub fn normal(self) -> Vec2 {
+        match self {
+            GateDirection::Up => Vec2::NEG_X,
+            GateDirection::Down => Vec2::X,
+            GateDirection::Left => Vec2::Y,
+            GateDirection::Right => Vec2::NEG_Y,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, GateDirection::Up | GateDirection::Down)
+    }
+}
+
+/// A gate as authored in a level file: inclusive `(first, last)` row and column ranges.
+///
+/// Up/Down gates span a single row, Left/Right gates a single column; the gate runs through the
+/// middle of those cells.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateDef {
+    pub rows: (usize, usize),
+    pub cols: (usize, usize),
+    pub direction: GateDirection,
+}
+
+impl GateDef {
+    /// Check the gate against the grid of the level `matrix`: it must be one cell thick.
+    pub fn validate(&self, matrix: &[Vec<u8>]) -> Result<(), String> {
+        validate_cell_range_in(self.rows, self.cols, matrix_resolution(matrix))?;
+        match self.direction.is_vertical() {
+            true if self.rows.0 != self.rows.1 => Err(format!(
+                "{:?} gate must span a single row, got {:?}",
+                self.direction, self.rows
+            )),
+            false if self.cols.0 != self.cols.1 => Err(format!(
+                "{:?} gate must span a single column, got {:?}",
+                self.direction, self.cols
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A gate in world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveGate {
+    /// Centre of the gate as (x, z)
+    pub center: Vec2,
+    /// Direction balls may cross in, as (x, z)
+    pub normal: Vec2,
+    /// Half the gate's length along the line it spans
+    pub half_length: f32,
+}
+
+impl ActiveGate {
+    fn from_def(def: &GateDef, grid: &GridResolution) -> Self {
+        let (min, max) = cell_range_bounds(def.rows, def.cols, grid);
+        let size = max - min;
+        let normal = def.direction.normal();
+        Self {
+            center: (min + max) / 2.0,
+            normal,
+            // The gate runs perpendicular to its normal
+            half_length: size.dot(normal.perp().abs()) / 2.0,
+        }
+    }
+
+    /// Where a ball of `radius` that moved from `previous` to `current` (both (x, z)) has to be
+    /// stopped, or `None` if the gate lets it through (or it is not near the gate).
+    pub fn block(
+        &self,
+        previous: Vec2,
+        current: Vec2,
+        velocity: Vec2,
+        radius: f32,
+    ) -> Option<Vec2> {
+        let tangent = self.normal.perp();
+        let lateral = (current - self.center).dot(tangent);
+        if lateral.abs() > self.half_length + radius {
+            return None;
+        }
+        let reach = radius + GATE_THICKNESS / 2.0;
+        let was_on_exit_side = (previous - self.center).dot(self.normal) >= reach - f32::EPSILON;
+        let touching = (current - self.center).dot(self.normal) < reach;
+        let moving_back = velocity.dot(self.normal) < 0.0;
+        (was_on_exit_side && touching && moving_back)
+            .then(|| self.center + self.normal * reach + tangent * lateral)
+    }
+}
+
+/// One-way gates of the current level, rebuilt whenever the level changes.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct OneWayGates {
+    pub gates: Vec<ActiveGate>,
+}
+
+impl OneWayGates {
+    /// Build world-space gates on the level `grid`, skipping (and logging) invalid definitions.
+    pub fn from_defs(defs: &[GateDef], matrix: &[Vec<u8>], grid: &GridResolution) -> Self {
+        let gates = defs
+            .iter()
+            .filter(|def| match def.validate(matrix) {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Ignoring one-way gate: {}", e);
+                    false
+                }
+            })
+            .map(|def| ActiveGate::from_def(def, grid))
+            .collect();
+        Self { gates }
+    }
+}
+
+/// Bar or debug arrow drawn for a gate.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GateVisual;
+
+fn spawn_gate_visuals(
+    commands: &mut Commands,
+    gate: &ActiveGate,
+    grid: &GridResolution,
+    meshes: &mut Assets<Mesh>,
+    bar_material: Handle<StandardMaterial>,
+    arrow_material: Handle<StandardMaterial>,
+) {
+    let heading = Quat::from_rotation_arc(Vec3::X, Vec3::new(gate.normal.x, 0.0, gate.normal.y));
+    let center = Vec3::new(gate.center.x, GATE_Y, gate.center.y);
+    // Local +X is the crossing direction, local Z runs along the gate
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(GATE_THICKNESS, 0.5, gate.half_length * 2.0))),
+        MeshMaterial3d(bar_material),
+        Transform::from_translation(center).with_rotation(heading),
+        GateVisual,
+    ));
+    let length = grid.cell_height() * 0.8;
+    commands
+        .spawn((
+            Transform::from_translation(center).with_rotation(heading),
+            GridOverlay,
+            Visibility::Hidden,
+            GateVisual,
+        ))
+        .with_children(|arrow| {
+            arrow.spawn((
+                Mesh3d(meshes.add(Cuboid::new(length * 0.75, 0.05, 0.05))),
+                MeshMaterial3d(arrow_material.clone()),
+                Transform::from_xyz(-length * 0.125, 0.0, 0.0),
+            ));
+            arrow.spawn((
+                Mesh3d(meshes.add(Cone::new(0.2, length * 0.25))),
+                MeshMaterial3d(arrow_material),
+                // Cone tip points along +Y; turn it to +X
+                Transform::from_xyz(length * 0.375, 0.0, 0.0)
+                    .with_rotation(Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2)),
+            ));
+        });
+}
+
+/// Rebuild [`OneWayGates`] and the gate visuals for the newly loaded level.
+pub fn rebuild_one_way_gates(
+    mut commands: Commands,
+    current_level: Res<CurrentLevel>,
+    visuals: Query<Entity, With<GateVisual>>,
+    mut gates: ResMut<OneWayGates>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    for entity in visuals.iter() {
+        commands.entity(entity).despawn();
+    }
+    let grid = current_level.0.grid();
+    *gates = OneWayGates::from_defs(
+        current_level.0.gates.as_deref().unwrap_or_default(),
+        &current_level.0.matrix,
+        &grid,
+    );
+
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    if gates.gates.is_empty() {
+        return;
+    }
+    let bar_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.7, 0.4, 1.0, 0.5),
+        emissive: LinearRgba::rgb(0.3, 0.1, 0.6),
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    let arrow_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.85, 0.6, 1.0, 0.8),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    for gate in &gates.gates {
+        spawn_gate_visuals(
+            &mut commands,
+            gate,
+            &grid,
+            &mut meshes,
+            bar_material.clone(),
+            arrow_material.clone(),
+        );
+    }
+}
+
+/// Stop balls crossing gates the wrong way.
+pub fn enforce_one_way_gates(
+    gates: Res<OneWayGates>,
+    mut balls: Query<(Entity, &mut Transform, &mut Velocity), With<Ball>>,
+    mut previous: Local<HashMap<Entity, Vec2>>,
+) {
+    let mut seen = HashMap::with_capacity(previous.len());
+    for (entity, mut transform, mut velocity) in balls.iter_mut() {
+        let mut current = transform.translation.xz();
+        let before = previous
+            .get(&entity)
+            .copied()
+            .filter(|before| before.distance(current) <= MAX_FRAME_TRAVEL)
+            .unwrap_or(current);
+        for gate in &gates.gates {
+            let Some(stop) = gate.block(before, current, velocity.linvel.xz(), BALL_RADIUS) else {
+                continue;
+            };
+            let into = velocity.linvel.xz().dot(gate.normal);
+            let bounced = velocity.linvel.xz() - 2.0 * into * gate.normal;
+            velocity.linvel = Vec3::new(bounced.x, velocity.linvel.y, bounced.y);
+            transform.translation.x = stop.x;
+            transform.translation.z = stop.y;
+            current = stop;
+            debug!("One-way gate turned back ball {:?}", entity);
+        }
+        seen.insert(entity, current);
+    }
+    *previous = seen;
+}
+
+/// Plugin registering one-way gates.
+pub struct OneWayGatesPlugin;
+
+impl Plugin for OneWayGatesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OneWayGates>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_systems(
+            Update,
+            (
+                rebuild_one_way_gates.run_if(resource_exists_and_changed::<CurrentLevel>),
+                enforce_one_way_gates.run_if(crate::pause::not_paused),
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn up_gate() -> ActiveGate {
+        ActiveGate::from_def(
+            &GateDef {
+                rows: (10, 10),
+                cols: (4, 9),
+                direction: GateDirection::Up,
+            },
+            &GridResolution::default(),
+        )
+    }
+
+    #[test]
+    fn gate_spans_its_cells() {
+        let gate = up_gate();
+        assert_eq!(gate.normal, Vec2::NEG_X);
+        assert!((gate.half_length - 6.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn balls_pass_in_the_gate_direction_only() {
+        let gate = up_gate();
+        let exit = gate.center + gate.normal * 1.0;
+        let entry = gate.center - gate.normal * 1.0;
+        // Crossing with the gate direction
+        assert_eq!(gate.block(entry, exit, gate.normal, 0.3), None);
+        // Crossing against it, even in a single frame
+        let stop = gate.block(exit, entry, -gate.normal, 0.3).unwrap();
+        assert!((stop - (gate.center + gate.normal * 0.35)).length() < 1e-5);
+        // Beside the gate
+        let beside = gate.normal.perp() * 10.0;
+        assert_eq!(
+            gate.block(exit + beside, entry + beside, -gate.normal, 0.3),
+            None
+        );
+    }
+
+    #[test]
+    fn thick_gates_are_rejected() {
+        let def = GateDef {
+            rows: (3, 4),
+            cols: (0, 5),
+            direction: GateDirection::Down,
+        };
+        assert!(def.validate(&[]).is_err());
+        let side = GateDef {
+            rows: (3, 4),
+            cols: (5, 5),
+            direction: GateDirection::Left,
+        };
+        assert!(side.validate(&[]).is_ok());
+    }
+}
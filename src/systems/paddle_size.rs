@@ -1,15 +1,38 @@
-//! Paddle size modification system triggered by special brick collisions.
+//! Paddle size modification system triggered by caught powerup capsules.
 //!
 //! This module implements paddle size powerups:
-//! - Brick Type 30: Shrinks paddle to 70% (14 units) for 10 seconds
-//! - Brick Type 32: Enlarges paddle to 150% (30 units) for 10 seconds
+//! - Shrink: paddle at 70% (14 units) for 10 seconds
+//! - Enlarge: paddle at 150% (30 units) for 10 seconds
 //!
-//! Effects are temporary, replace each other, and clear on level changes or life loss.
+//! Destroyed powerup bricks drop the capsule of their effect (brick type 30 shrink, 32 enlarge;
+//! see `systems::powerups`), and the effect applies when the paddle catches it. Capsules from the
+//! drop table carry the same effects.
+//!
+//! Effects are temporary, their timers stop while the game is paused (`systems::timed_effects`),
+//! and they clear on level changes or life loss. An opposite effect replaces the running one;
+//! catching the same effect again follows the stacking policy of the gameplay config
+//! (`GameplayConfig::paddle_size`):
+//! - `Refresh` (default): the timer restarts at its full duration
+//! - `Extend`: a full duration is added to the time left, up to [`MAX_EFFECT_DURATION`]
+//! - `Stack`: the size change applies once more (a second shrink leaves 49% of the base width),
+//!   up to `max_stacks` times and within [`MIN_PADDLE_WIDTH`]..[`MAX_PADDLE_WIDTH`], and the timer
+//!   restarts
+//!
+//! Size changes from an effect, and back when it expires, are animated over
+//! [`PADDLE_RESIZE_DURATION`] with the ease-out curve of the paddle growth (`PaddleGrowing`).
+//! Clearing on level changes or life loss stays instant, so the life loss animation starts from
+//! the base size.
+//!
+//! The paddle's collider is rebuilt whenever its scale changes ([`sync_paddle_collider`]), for
+//! these length changes as well as the growth on respawn. Letting Rapier scale the capsule from the
+//! transform would not match the mesh: a capsule scaled along its length only is approximated by a
+//! polyhedron with flattened ends. The rebuilt capsule has the same length as the scaled mesh and
+//! round ends, and Rapier's own scaling is switched off (`ColliderScale::Absolute`).
 //!
 //! # System Organization
 //!
 //! Systems are organized using the [`PaddleSizeSystems`] SystemSet enum:
-//! - [`PaddleSizeSystems::Detect`]: Detect collisions / inputs
+//! - [`PaddleSizeSystems::Detect`]: Detect inputs
 //! - [`PaddleSizeSystems::UpdateTimers`]: Update effect timers
 //! - [`PaddleSizeSystems::Cleanup`]: Cleanup expired effects
 //! - [`PaddleSizeSystems::Visual`]: Visual updates (change-driven)
@@ -19,11 +42,14 @@
 
 use bevy::ecs::message::{Message, MessageReader, MessageWriter};
 use bevy::prelude::*;
-use bevy_rapier3d::prelude::CollisionEvent;
+use bevy_rapier3d::prelude::{Collider, ColliderScale, PhysicsSet};
 
+use crate::gameplay_config::{PaddleSizeConfig, SizeStackPolicy};
 use crate::systems::level_switch::LevelSwitchRequested;
+use crate::systems::powerups::PowerupKind;
 use crate::systems::respawn::LifeLostEvent;
-use crate::{Ball, Brick, BrickTypeId, Paddle};
+use crate::systems::timed_effects::{tick_timed_effects, TimedEffect};
+use crate::{Paddle, PaddleGrowing, PADDLE_HEIGHT, PADDLE_RADIUS};
 
 /// Base paddle width in units
 pub const PADDLE_BASE_WIDTH: f32 = 20.0;
@@ -33,15 +59,19 @@ pub const SHRINK_MULTIPLIER: f32 = 0.7;
 pub const ENLARGE_MULTIPLIER: f32 = 1.5;
 /// Effect duration in seconds
 pub const EFFECT_DURATION: f32 = 10.0;
-/// Minimum paddle width
-pub const MIN_PADDLE_WIDTH: f32 = 10.0;
-/// Maximum paddle width
-pub const MAX_PADDLE_WIDTH: f32 = 30.0;
+/// Longest time an extended effect can have left, in seconds
+pub const MAX_EFFECT_DURATION: f32 = 30.0;
+/// Minimum paddle width, reached by stacked shrink effects
+pub const MIN_PADDLE_WIDTH: f32 = 8.0;
+/// Maximum paddle width, reached by stacked enlarge effects
+pub const MAX_PADDLE_WIDTH: f32 = 40.0;
+/// Duration of the paddle length animation in seconds
+pub const PADDLE_RESIZE_DURATION: f32 = 0.3;
 
 /// System set organization for paddle size feature.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
 pub enum PaddleSizeSystems {
-    /// Detect collisions / inputs
+    /// Detect inputs
     Detect,
     /// Update effect timers
     UpdateTimers,
@@ -53,9 +83,9 @@ pub enum PaddleSizeSystems {
     Audio,
 }
 
-/// Brick type ID for shrink powerup
+/// Brick type ID of the shrink powerup brick, dropping a shrink capsule
 pub const BRICK_TYPE_30: u8 = 30;
-/// Brick type ID for enlarge powerup
+/// Brick type ID of the enlarge powerup brick, dropping an enlarge capsule
 pub const BRICK_TYPE_32: u8 = 32;
 
 /// Component tracking an active paddle size effect
@@ -67,8 +97,79 @@ pub struct PaddleSizeEffect {
     pub timer: Timer,
     /// Original paddle width before effect (always 20.0)
     pub base_width: f32,
+    /// Times the effect has been applied on top of itself (1 unless stacked)
+    pub stacks: u32,
 }
 
+impl PaddleSizeEffect {
+    /// A fresh effect of `effect_type` with the full duration.
+    pub fn new(effect_type: SizeEffectType) -> Self {
+        Self {
+            effect_type,
+            timer: Timer::from_seconds(EFFECT_DURATION, TimerMode::Once),
+            base_width: PADDLE_BASE_WIDTH,
+            stacks: 1,
+        }
+    }
+
+    /// Paddle width while the effect runs.
+    pub fn width(&self) -> f32 {
+        stacked_paddle_width(self.base_width, self.effect_type, self.stacks)
+    }
+
+    /// The effect after catching `effect_type` while this one runs, following `config`.
+    pub fn combine(&self, effect_type: SizeEffectType, config: &PaddleSizeConfig) -> Self {
+        let fresh = Self::new(effect_type);
+        if effect_type != self.effect_type || self.timer.is_finished() {
+            return fresh;
+        }
+        match config.policy {
+            SizeStackPolicy::Refresh => fresh,
+            SizeStackPolicy::Extend => {
+                let duration =
+                    (self.timer.remaining_secs() + EFFECT_DURATION).min(MAX_EFFECT_DURATION);
+                Self {
+                    timer: Timer::from_seconds(duration, TimerMode::Once),
+                    ..fresh
+                }
+            }
+            SizeStackPolicy::Stack => Self {
+                stacks: (self.stacks + 1).min(config.max_stacks.max(1)),
+                ..fresh
+            },
+        }
+    }
+}
+
+impl TimedEffect for PaddleSizeEffect {
+    fn kind(&self) -> PowerupKind {
+        match self.effect_type {
+            SizeEffectType::Shrink => PowerupKind::ShrinkPaddle,
+            SizeEffectType::Enlarge => PowerupKind::EnlargePaddle,
+        }
+    }
+
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// Component animating the paddle length (its Y scale) towards `target_scale`
+#[derive(Component, Clone, Debug)]
+pub struct PaddleResizing {
+    pub timer: Timer,
+    pub start_scale: f32,
+    pub target_scale: f32,
+}
+
+/// Component recording the transform scale the paddle's collider was built for
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct PaddleColliderScale(pub Vec3);
+
 /// Type of size effect
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SizeEffectType {
@@ -91,11 +192,27 @@ pub struct PaddleSizeEffectApplied {
 
 /// Calculate clamped paddle width based on effect type
 pub fn calculate_paddle_width(base_width: f32, effect_type: SizeEffectType) -> f32 {
+    stacked_paddle_width(base_width, effect_type, 1)
+}
+
+/// Calculate clamped paddle width for an effect applied `stacks` times
+pub fn stacked_paddle_width(base_width: f32, effect_type: SizeEffectType, stacks: u32) -> f32 {
     let multiplier = match effect_type {
         SizeEffectType::Shrink => SHRINK_MULTIPLIER,
         SizeEffectType::Enlarge => ENLARGE_MULTIPLIER,
     };
-    (base_width * multiplier).clamp(MIN_PADDLE_WIDTH, MAX_PADDLE_WIDTH)
+    let exponent = i32::try_from(stacks).unwrap_or(i32::MAX);
+    (base_width * multiplier.powi(exponent)).clamp(MIN_PADDLE_WIDTH, MAX_PADDLE_WIDTH)
+}
+
+/// Capsule collider matching the paddle mesh at transform `scale`.
+///
+/// The radius follows the smaller cross-section scale (X/Z); the length end to end follows the Y
+/// scale, so the round ends reach as far as the mesh does.
+pub fn paddle_collider_for(scale: Vec3) -> Collider {
+    let radius = PADDLE_RADIUS * scale.x.min(scale.z);
+    let half_length = (PADDLE_HEIGHT / 2.0 + PADDLE_RADIUS) * scale.y;
+    Collider::capsule_y((half_length - radius).max(0.0), radius)
 }
 
 /// Get color for visual feedback based on effect type
@@ -118,88 +235,73 @@ pub fn effect_to_glow(effect_type: SizeEffectType) -> LinearRgba {
     }
 }
 
-/// System to detect ball-brick collisions and apply paddle size effects
-pub fn detect_powerup_brick_collisions(
-    mut collision_events: MessageReader<CollisionEvent>,
-    balls: Query<Entity, With<Ball>>,
-    bricks: Query<&BrickTypeId, With<Brick>>,
-    mut paddles: Query<(Entity, &mut Transform), With<Paddle>>,
-    mut commands: Commands,
-    mut effect_applied_events: MessageWriter<PaddleSizeEffectApplied>,
+/// Apply a size effect to one paddle, combined with the `existing` effect under `config`.
+///
+/// Used for caught powerup capsules (`systems::powerups`).
+pub fn apply_size_effect(
+    commands: &mut Commands,
+    paddle_entity: Entity,
+    transform: &Transform,
+    existing: Option<&PaddleSizeEffect>,
+    effect_type: SizeEffectType,
+    config: &PaddleSizeConfig,
+    effect_applied_events: &mut MessageWriter<PaddleSizeEffectApplied>,
 ) {
-    for event in collision_events.read() {
-        if let CollisionEvent::Started(e1, e2, _) = event {
-            // Determine which entity is the ball and which is the brick
-            let (_ball_entity, brick_entity) = if balls.contains(*e1) {
-                (*e1, *e2)
-            } else if balls.contains(*e2) {
-                (*e2, *e1)
-            } else {
-                continue; // Neither entity is a ball
-            };
-
-            // Check if the brick entity is a powerup brick
-            let brick_type = bricks.get(brick_entity).ok();
-
-            if let Some(brick_type_id) = brick_type {
-                let effect_type = match brick_type_id.0 {
-                    BRICK_TYPE_30 => Some(SizeEffectType::Shrink),
-                    BRICK_TYPE_32 => Some(SizeEffectType::Enlarge),
-                    _ => None,
-                };
-
-                if let Some(effect_type) = effect_type {
-                    // Apply effect to all paddles
-                    // Note: Game design assumes single paddle, but implementation supports multiple
-                    for (paddle_entity, mut transform) in paddles.iter_mut() {
-                        let new_width = calculate_paddle_width(PADDLE_BASE_WIDTH, effect_type);
-
-                        // Remove any existing effect and insert new one
-                        commands.entity(paddle_entity).remove::<PaddleSizeEffect>();
-                        commands.entity(paddle_entity).insert(PaddleSizeEffect {
-                            effect_type,
-                            timer: Timer::from_seconds(EFFECT_DURATION, TimerMode::Once),
-                            base_width: PADDLE_BASE_WIDTH,
-                        });
-
-                        // Update paddle length: the capsule's height axis maps to world Z after rotation, so scale Y
-                        let scale_factor = new_width / PADDLE_BASE_WIDTH;
-                        transform.scale.y = scale_factor;
-
-                        // Emit event for audio/visual feedback
-                        effect_applied_events.write(PaddleSizeEffectApplied {
-                            paddle_entity,
-                            effect_type,
-                            new_width,
-                        });
-
-                        debug!(
-                            "Applied {:?} effect to paddle: width = {}",
-                            effect_type, new_width
-                        );
-                    }
-                }
-            }
-        }
-    }
+    let effect = match existing {
+        Some(existing) => existing.combine(effect_type, config),
+        None => PaddleSizeEffect::new(effect_type),
+    };
+    let new_width = effect.width();
+
+    // Remove any existing effect and insert the combined one
+    commands.entity(paddle_entity).remove::<PaddleSizeEffect>();
+    commands.entity(paddle_entity).insert(effect);
+
+    // Animate the paddle length: the capsule's height axis maps to world Z after rotation, so
+    // scale Y
+    resize_paddle(
+        commands,
+        paddle_entity,
+        transform,
+        new_width / PADDLE_BASE_WIDTH,
+    );
+
+    // Emit event for audio/visual feedback
+    effect_applied_events.write(PaddleSizeEffectApplied {
+        paddle_entity,
+        effect_type,
+        new_width,
+    });
+
+    debug!(
+        "Applied {:?} effect to paddle: width = {}",
+        effect_type, new_width
+    );
 }
 
-/// System to countdown effect timers
-pub fn update_effect_timers(mut paddles: Query<&mut PaddleSizeEffect>, time: Res<Time>) {
-    for mut effect in paddles.iter_mut() {
-        effect.timer.tick(time.delta());
-    }
+/// Start animating the paddle length from its current Y scale to `target_scale`.
+fn resize_paddle(
+    commands: &mut Commands,
+    paddle_entity: Entity,
+    transform: &Transform,
+    target_scale: f32,
+) {
+    commands.entity(paddle_entity).insert(PaddleResizing {
+        timer: Timer::from_seconds(PADDLE_RESIZE_DURATION, TimerMode::Once),
+        start_scale: transform.scale.y,
+        target_scale,
+    });
 }
 
 /// System to remove expired effects and restore paddle size
 pub fn remove_expired_effects(
-    mut paddles: Query<(Entity, &PaddleSizeEffect, &mut Transform), With<Paddle>>,
+    paddles: Query<(Entity, &PaddleSizeEffect, &Transform), With<Paddle>>,
     mut commands: Commands,
 ) {
-    for (entity, effect, mut transform) in paddles.iter_mut() {
+    for (entity, effect, transform) in paddles.iter() {
         if effect.timer.is_finished() {
-            // Restore paddle to base length
-            transform.scale.y = 1.0;
+            // Animate paddle back to base length
+            resize_paddle(&mut commands, entity, transform, 1.0);
 
             // Remove effect component
             commands.entity(entity).remove::<PaddleSizeEffect>();
@@ -209,6 +311,54 @@ pub fn remove_expired_effects(
     }
 }
 
+/// System to animate paddle length changes over [`PADDLE_RESIZE_DURATION`]
+///
+/// Waits while the paddle grows or shrinks as a whole (`PaddleGrowing`), which owns the scale.
+pub fn animate_paddle_resize(
+    time: Res<Time>,
+    mut paddles: Query<(Entity, &mut Transform, &mut PaddleResizing), Without<PaddleGrowing>>,
+    mut commands: Commands,
+) {
+    for (entity, mut transform, mut resizing) in paddles.iter_mut() {
+        resizing.timer.tick(time.delta());
+        if resizing.timer.is_finished() {
+            transform.scale.y = resizing.target_scale;
+            commands.entity(entity).remove::<PaddleResizing>();
+        } else {
+            // Same ease-out cubic as the paddle growth
+            let progress = resizing.timer.fraction();
+            let eased_progress = 1.0 - (1.0 - progress).powi(3);
+            transform.scale.y = resizing
+                .start_scale
+                .lerp(resizing.target_scale, eased_progress);
+        }
+    }
+}
+
+/// System to rebuild the paddle collider when the paddle's scale changed
+///
+/// Covers the size effect animation, the instant clears, and the growth on respawn
+/// (`PaddleGrowing`). Moving the paddle changes its transform too, so the scale the collider was
+/// built for is kept in [`PaddleColliderScale`] and compared.
+pub fn sync_paddle_collider(
+    paddles: Query<
+        (Entity, &Transform, Option<&PaddleColliderScale>),
+        (With<Paddle>, With<Collider>, Changed<Transform>),
+    >,
+    mut commands: Commands,
+) {
+    for (entity, transform, built_for) in paddles.iter() {
+        if built_for.is_some_and(|built_for| built_for.0 == transform.scale) {
+            continue;
+        }
+        commands.entity(entity).insert((
+            paddle_collider_for(transform.scale),
+            ColliderScale::Absolute(Vec3::ONE),
+            PaddleColliderScale(transform.scale),
+        ));
+    }
+}
+
 /// System to update paddle visual feedback based on active effect
 /// Only runs when PaddleSizeEffect changes (reactive updates)
 pub fn update_paddle_visual_feedback(
@@ -265,10 +415,16 @@ pub fn play_effect_audio(
     }
 }
 
-/// System to clear paddle size effects on level change
+/// System to clear paddle size effects on level change (instantly)
 pub fn clear_effects_on_level_change(
     level_switch_events: Option<MessageReader<LevelSwitchRequested>>,
-    mut paddles: Query<(Entity, &mut Transform), (With<Paddle>, With<PaddleSizeEffect>)>,
+    mut paddles: Query<
+        (Entity, &mut Transform),
+        (
+            With<Paddle>,
+            Or<(With<PaddleSizeEffect>, With<PaddleResizing>)>,
+        ),
+    >,
     mut commands: Commands,
 ) {
     if let Some(mut events) = level_switch_events {
@@ -277,8 +433,10 @@ pub fn clear_effects_on_level_change(
                 // Restore paddle to base length
                 transform.scale.y = 1.0;
 
-                // Remove effect component
-                commands.entity(entity).remove::<PaddleSizeEffect>();
+                // Remove effect component and any running animation
+                commands
+                    .entity(entity)
+                    .remove::<(PaddleSizeEffect, PaddleResizing)>();
 
                 debug!("Cleared paddle size effect on level change");
             }
@@ -286,11 +444,17 @@ pub fn clear_effects_on_level_change(
     }
 }
 
-/// System to clear paddle size effects on life loss
+/// System to clear paddle size effects on life loss (instantly)
 /// Note: Uses Option<MessageReader> for test compatibility where messages may not be registered
 pub fn clear_effects_on_life_loss(
     life_lost_events: Option<MessageReader<LifeLostEvent>>,
-    mut paddles: Query<(Entity, &mut Transform), (With<Paddle>, With<PaddleSizeEffect>)>,
+    mut paddles: Query<
+        (Entity, &mut Transform),
+        (
+            With<Paddle>,
+            Or<(With<PaddleSizeEffect>, With<PaddleResizing>)>,
+        ),
+    >,
     mut commands: Commands,
 ) {
     if let Some(mut events) = life_lost_events {
@@ -299,8 +463,10 @@ pub fn clear_effects_on_life_loss(
                 // Restore paddle to base length
                 transform.scale.y = 1.0;
 
-                // Remove effect component
-                commands.entity(entity).remove::<PaddleSizeEffect>();
+                // Remove effect component and any running animation
+                commands
+                    .entity(entity)
+                    .remove::<(PaddleSizeEffect, PaddleResizing)>();
 
                 debug!("Cleared paddle size effect on life loss");
             }
@@ -314,15 +480,30 @@ pub struct PaddleSizePlugin;
 impl Plugin for PaddleSizePlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<PaddleSizeEffectApplied>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
 
         // Register systems with explicit ordering to ensure deterministic execution
-        // Ordering: collision detection → timer updates → effect removal → visual feedback
-        app.add_systems(Update, detect_powerup_brick_collisions);
+        // Ordering: caught capsules → timer updates → effect removal → visual feedback
+        // Effects keep their time left while the game is paused
+        app.add_systems(
+            Update,
+            tick_timed_effects::<PaddleSizeEffect>
+                .after(crate::systems::powerups::PowerupSystems::Apply)
+                .run_if(crate::pause::not_paused),
+        );
+        app.add_systems(
+            Update,
+            remove_expired_effects.after(tick_timed_effects::<PaddleSizeEffect>),
+        );
+        // The clearing systems set the scale directly, so the animation must not overwrite it
         app.add_systems(
             Update,
-            update_effect_timers.after(detect_powerup_brick_collisions),
+            animate_paddle_resize
+                .after(remove_expired_effects)
+                .before(clear_effects_on_level_change)
+                .before(clear_effects_on_life_loss),
         );
-        app.add_systems(Update, remove_expired_effects.after(update_effect_timers));
         app.add_systems(
             Update,
             update_paddle_visual_feedback.after(remove_expired_effects),
@@ -340,5 +521,11 @@ impl Plugin for PaddleSizePlugin {
         );
 
         app.add_systems(Update, play_effect_audio);
+
+        // After every scale change of the frame, before Rapier picks up the collider
+        app.add_systems(
+            PostUpdate,
+            sync_paddle_collider.before(PhysicsSet::SyncBackend),
+        );
     }
 }
@@ -0,0 +1,165 @@
+//! Cursor picking: from a cursor position to the grid cell or the brick under it.
+//!
+//! [`cursor_ray`] turns the cursor into a ray from the camera. Two things can be picked with it:
+//! - a grid cell: [`ground_point`] intersects the ray with the ground plane (y = 0) and
+//!   [`world_to_cell`] maps the point to a cell, as [`cursor_to_grid`] does in one go. Used for
+//!   empty cells, e.g. to place a brick.
+//! - a brick: [`pick_brick`] casts the ray against the brick colliders (Rapier scene queries), so
+//!   the brick actually under the cursor is found by its shape and height, also where the ground
+//!   point would fall into a neighbouring cell at the edge of the view.
+//!
+//! Systems use the [`BrickPicker`] system parameter, which brings the window, the camera and the
+//! Rapier context together. The designer palette (`ui::palette`) selects and erases existing bricks
+//! with it, and the inspector (`systems::inspector`) inspects a clicked brick.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_rapier3d::prelude::{QueryFilter, RapierContext, ReadRapierContext};
+
+use crate::{Brick, GridResolution, MarkedForDespawn, PLANE_H, PLANE_W};
+
+/// Longest distance from the camera at which bricks are picked.
+pub const PICK_DISTANCE: f32 = 200.0;
+
+/// Ray from the camera through `cursor` (viewport coordinates).
+pub fn cursor_ray(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    cursor: Vec2,
+) -> Option<Ray3d> {
+    camera.viewport_to_world(camera_transform, cursor).ok()
+}
+
+/// Where `ray` meets the ground plane (y = 0), if it does in front of the camera.
+pub fn ground_point(ray: Ray3d) -> Option<Vec3> {
+    let direction = *ray.direction;
+    if direction.y.abs() < 0.001 {
+        return None; // Ray is parallel to ground
+    }
+    let t = -ray.origin.y / direction.y;
+    if t < 0.0 {
+        return None; // Intersection behind camera
+    }
+    Some(ray.origin + direction * t)
+}
+
+/// Cell `(grid_x, grid_z)` of `grid` containing the world position `point`, or `None` outside the
+/// play area.
+///
+/// World space: X ∈ [-PLANE_H/2, PLANE_H/2], Z ∈ [-PLANE_W/2, PLANE_W/2]
+/// Grid space: X ∈ [0, grid.rows), Z ∈ [0, grid.cols)
+pub fn world_to_cell(point: Vec3, grid: &GridResolution) -> Option<(usize, usize)> {
+    let x_normalized = (point.x + PLANE_H / 2.0) / PLANE_H;
+    let z_normalized = (point.z + PLANE_W / 2.0) / PLANE_W;
+    if !(0.0..1.0).contains(&x_normalized) || !(0.0..1.0).contains(&z_normalized) {
+        return None; // Outside play area
+    }
+    let grid_x = (x_normalized * grid.rows as f32).floor() as usize;
+    let grid_z = (z_normalized * grid.cols as f32).floor() as usize;
+    Some((grid_x, grid_z))
+}
+
+/// Cell of `grid` under `cursor` on the ground plane, or `None` outside the play area.
+pub fn cursor_to_grid(
+    cursor: Vec2,
+    camera_transform: &GlobalTransform,
+    camera: &Camera,
+    grid: &GridResolution,
+) -> Option<(usize, usize)> {
+    cursor_ray(camera, camera_transform, cursor)
+        .and_then(ground_point)
+        .and_then(|point| world_to_cell(point, grid))
+}
+
+/// First collider along `ray` for which `is_brick` holds, with the point where the ray hits it.
+pub fn pick_brick(
+    context: &RapierContext,
+    ray: Ray3d,
+    is_brick: &dyn Fn(Entity) -> bool,
+) -> Option<(Entity, Vec3)> {
+    let filter = QueryFilter::default().predicate(is_brick);
+    context
+        .cast_ray(ray.origin, *ray.direction, PICK_DISTANCE, true, filter)
+        .map(|(entity, toi)| (entity, ray.get_point(toi)))
+}
+
+/// Window, camera and physics access for picking under the cursor.
+#[derive(SystemParam)]
+pub struct BrickPicker<'w, 's> {
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    cameras: Query<'w, 's, (&'static GlobalTransform, &'static Camera), With<Camera3d>>,
+    rapier: ReadRapierContext<'w, 's>,
+    bricks: Query<'w, 's, (), (With<Brick>, Without<MarkedForDespawn>)>,
+    grid: Option<Res<'w, GridResolution>>,
+}
+
+impl BrickPicker<'_, '_> {
+    /// Ray from the camera through the cursor, while the cursor is over the window.
+    pub fn cursor_ray(&self) -> Option<Ray3d> {
+        let cursor = self.windows.single().ok()?.cursor_position()?;
+        let (camera_transform, camera) = self.cameras.single().ok()?;
+        cursor_ray(camera, camera_transform, cursor)
+    }
+
+    /// Cell of the level grid under the cursor on the ground plane.
+    pub fn cell_under_cursor(&self) -> Option<(usize, usize)> {
+        let grid = self.grid();
+        self.cursor_ray()
+            .and_then(ground_point)
+            .and_then(|point| world_to_cell(point, &grid))
+    }
+
+    /// Grid of the current level.
+    pub fn grid(&self) -> GridResolution {
+        self.grid.as_deref().copied().unwrap_or_default()
+    }
+
+    /// The brick under the cursor, skipping bricks already on their way out.
+    pub fn brick_under_cursor(&self) -> Option<Entity> {
+        let ray = self.cursor_ray()?;
+        let context = self.rapier.single().ok()?;
+        let is_brick = |entity: Entity| self.bricks.contains(entity);
+        pick_brick(&context, ray, &is_brick).map(|(entity, _)| entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_from_above_lands_in_the_cell_below() {
+        let grid = GridResolution::default();
+        let ray = Ray3d::new(Vec3::new(0.1, 37.0, 0.1), Dir3::NEG_Y);
+        let point = ground_point(ray).unwrap();
+        assert!((point - Vec3::new(0.1, 0.0, 0.1)).length() < 1e-4);
+        assert_eq!(
+            world_to_cell(point, &grid),
+            Some((grid.rows / 2, grid.cols / 2))
+        );
+
+        let corner = Vec3::new(-PLANE_H / 2.0, 0.0, -PLANE_W / 2.0);
+        assert_eq!(world_to_cell(corner, &grid), Some((0, 0)));
+        assert_eq!(world_to_cell(corner - Vec3::X, &grid), None);
+    }
+
+    #[test]
+    fn finer_grids_pick_finer_cells() {
+        let point = Vec3::new(0.1, 0.0, 0.1);
+        assert_eq!(
+            world_to_cell(point, &GridResolution::new(40, 40)),
+            Some((20, 20))
+        );
+        assert_eq!(
+            world_to_cell(point, &GridResolution::new(40, 10)),
+            Some((20, 5))
+        );
+    }
+
+    #[test]
+    fn rays_missing_the_ground_pick_nothing() {
+        assert_eq!(ground_point(Ray3d::new(Vec3::Y * 10.0, Dir3::X)), None);
+        assert_eq!(ground_point(Ray3d::new(Vec3::Y * 10.0, Dir3::Y)), None);
+    }
+}
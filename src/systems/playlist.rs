@@ -0,0 +1,292 @@
+//! Tournament / playlist mode: an ordered list of levels played back to back.
+//!
+//! A playlist (`assets/levels/playlist.ron`) names levels, from the campaign or custom level files,
+//! in the order they are played; the same level may appear more than once:
+//!
+//! ```ron
+//! (
+//!     name: "Friday cup",
+//!     levels: [3, 7, 12, 101],
+//! )
+//! ```
+//!
+//! Starting a run ([`PlaylistStartRequested`], sent by the playlist editor) resets score and lives
+//! and switches to the first entry. While the entry being played is the current level, completing
+//! it records the points earned on it and advances to the next entry instead of following the
+//! campaign route; the score carries over between entries as usual. Completing the last entry
+//! finishes the game, and the summary overlay (`ui::playlist_summary`) lists each entry's points
+//! and the total.
+//!
+//! The playlist is edited in game (`ui::playlist_editor`, toggled with `L`) and saved back to the
+//! file (to localStorage on wasm, see `systems::web_storage`). Each player profile has its own playlist (`systems::profiles`).
+
+use bevy::ecs::message::{Message, MessageReader, MessageWriter};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::systems::audio::LevelCompleted;
+use crate::systems::level_switch::{LevelSwitchDirection, LevelSwitchRequested, LevelSwitchSource};
+use crate::systems::profiles::{profile_path, profile_switched, CurrentProfile};
+use crate::systems::respawn::LivesState;
+use crate::systems::scoring::ScoreState;
+use crate::systems::web_storage::{read_save_file, write_save_file};
+
+/// Path of the playlist file.
+pub const PLAYLIST_PATH: &str = "assets/levels/playlist.ron";
+
+/// Playlist as stored on disk.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistDefinition {
+    #[serde(default)]
+    pub name: String,
+    /// Level numbers in play order
+    #[serde(default)]
+    pub levels: Vec<u32>,
+}
+
+/// Points earned on one completed playlist entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaylistEntryResult {
+    pub level: u32,
+    pub points: u32,
+}
+
+/// The playlist and the progress of its current (or last) run.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct Playlist {
+    pub definition: PlaylistDefinition,
+    /// Index of the entry being played; `None` when no run is active
+    pub position: Option<usize>,
+    /// Entries completed in the current or last run
+    pub results: Vec<PlaylistEntryResult>,
+    /// Whether the last run completed every entry
+    pub finished: bool,
+}
+
+impl Playlist {
+    /// Playlist with the given levels and no run.
+    pub fn new(name: impl Into<String>, levels: Vec<u32>) -> Self {
+        Self {
+            definition: PlaylistDefinition {
+                name: name.into(),
+                levels,
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.position.is_some()
+    }
+
+    /// Level of the entry being played.
+    pub fn current_entry(&self) -> Option<u32> {
+        self.position
+            .and_then(|index| self.definition.levels.get(index).copied())
+    }
+
+    /// Level of the entry after the one being played (`None` on the last entry).
+    pub fn next_entry(&self) -> Option<u32> {
+        self.position
+            .and_then(|index| self.definition.levels.get(index + 1).copied())
+    }
+
+    /// Start a run from the first entry and return its level, or `None` if the playlist is empty.
+    pub fn start(&mut self) -> Option<u32> {
+        self.results.clear();
+        self.finished = false;
+        let first = self.definition.levels.first().copied();
+        self.position = first.map(|_| 0);
+        first
+    }
+
+    /// Record `points` for the entry being played if it is `level`, and move to the next entry.
+    ///
+    /// Completing the last entry ends the run as finished. Returns whether a result was recorded.
+    pub fn complete_entry(&mut self, level: u32, points: u32) -> bool {
+        let Some(index) = self.position else {
+            return false;
+        };
+        if self.current_entry() != Some(level) {
+            return false;
+        }
+        self.results.push(PlaylistEntryResult { level, points });
+        self.position = Some(index + 1).filter(|next| *next < self.definition.levels.len());
+        self.finished = self.position.is_none();
+        true
+    }
+
+    /// Total points of the completed entries.
+    pub fn total_points(&self) -> u32 {
+        self.results.iter().map(|result| result.points).sum()
+    }
+
+    /// Append `level` to the playlist. Editing ends any run.
+    pub fn push_level(&mut self, level: u32) {
+        self.stop();
+        self.definition.levels.push(level);
+    }
+
+    /// Remove the last entry. Editing ends any run.
+    pub fn pop_level(&mut self) -> Option<u32> {
+        self.stop();
+        self.definition.levels.pop()
+    }
+
+    /// Remove every entry. Editing ends any run.
+    pub fn clear(&mut self) {
+        self.stop();
+        self.definition.levels.clear();
+    }
+
+    /// End the current run without finishing it.
+    pub fn stop(&mut self) {
+        self.position = None;
+    }
+}
+
+/// Request to start a playlist run from its first entry.
+#[derive(Message, Debug, Clone, Copy, Default)]
+pub struct PlaylistStartRequested;
+
+/// Read the playlist file (the active profile's, see `systems::profiles`) into [`Playlist`]; a
+/// missing file leaves the playlist empty.
+pub fn load_playlist(mut playlist: ResMut<Playlist>, profile: Option<Res<CurrentProfile>>) {
+    let content = read_save_file(
+        &profile_path(profile.as_deref(), "playlist.ron", PLAYLIST_PATH),
+        |content| Some(content.to_string()),
+    );
+
+    let definition = match content.map(|c| ron::de::from_str::<PlaylistDefinition>(&c)) {
+        Some(Ok(definition)) => {
+            info!(
+                "Loaded playlist '{}' ({} levels)",
+                definition.name,
+                definition.levels.len()
+            );
+            definition
+        }
+        Some(Err(e)) => {
+            warn!("Failed to parse playlist: {}", e);
+            PlaylistDefinition::default()
+        }
+        None => PlaylistDefinition::default(),
+    };
+    *playlist = Playlist {
+        definition,
+        ..Default::default()
+    };
+}
+
+/// Write `definition` to the playlist file of `profile` (or the shared one for guests).
+pub fn save_playlist(
+    definition: &PlaylistDefinition,
+    profile: Option<&CurrentProfile>,
+) -> Result<(), String> {
+    let path = profile_path(profile, "playlist.ron", PLAYLIST_PATH);
+    let content = ron::ser::to_string_pretty(definition, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("failed to serialize playlist: {e}"))?;
+    write_save_file(&path, &content)?;
+    info!("Saved playlist to {}", path.display());
+    Ok(())
+}
+
+/// Start requested runs: reset score and lives and switch to the first entry.
+pub fn start_playlist_runs(
+    mut requests: MessageReader<PlaylistStartRequested>,
+    mut playlist: ResMut<Playlist>,
+    mut switches: MessageWriter<LevelSwitchRequested>,
+    score: Option<ResMut<ScoreState>>,
+    lives: Option<ResMut<LivesState>>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    let Some(first) = playlist.start() else {
+        warn!("Playlist is empty; nothing to play");
+        return;
+    };
+    info!(
+        "Starting playlist '{}' at level {}",
+        playlist.definition.name, first
+    );
+    if let Some(mut score) = score {
+        score.reset();
+    }
+    if let Some(mut lives) = lives {
+        lives.lives_remaining = LivesState::default().lives_remaining;
+        lives.on_last_life = false;
+    }
+    switches.write(LevelSwitchRequested {
+        source: LevelSwitchSource::Playlist,
+        direction: LevelSwitchDirection::Jump(first),
+    });
+}
+
+/// Record the points of a completed playlist entry.
+fn on_level_completed(
+    trigger: On<LevelCompleted>,
+    mut playlist: ResMut<Playlist>,
+    score: Option<Res<ScoreState>>,
+) {
+    let level = trigger.event().level_index;
+    let points = score.map_or(0, |s| s.level_points());
+    if playlist.complete_entry(level, points) {
+        info!(
+            "Playlist entry level {} completed with {} points",
+            level, points
+        );
+        if playlist.finished {
+            info!(
+                "Playlist '{}' finished with {} points",
+                playlist.definition.name,
+                playlist.total_points()
+            );
+        }
+    }
+}
+
+/// Plugin registering playlist mode.
+pub struct PlaylistPlugin;
+
+impl Plugin for PlaylistPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Playlist>();
+        app.add_message::<PlaylistStartRequested>();
+        app.add_message::<LevelSwitchRequested>();
+        app.add_systems(Startup, load_playlist);
+        app.add_systems(Update, load_playlist.run_if(profile_switched));
+        app.add_systems(Update, start_playlist_runs);
+        app.add_observer(on_level_completed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_walks_the_entries_and_finishes_after_the_last() {
+        let mut playlist = Playlist::new("cup", vec![3, 7, 3]);
+        assert_eq!(playlist.start(), Some(3));
+        assert_eq!(playlist.next_entry(), Some(7));
+        assert!(
+            !playlist.complete_entry(7, 100),
+            "not the entry being played"
+        );
+        assert!(playlist.complete_entry(3, 100));
+        assert!(playlist.complete_entry(7, 250));
+        assert_eq!(playlist.current_entry(), Some(3));
+        assert_eq!(playlist.next_entry(), None);
+        assert!(playlist.complete_entry(3, 50));
+        assert!(playlist.finished);
+        assert!(!playlist.is_running());
+        assert_eq!(playlist.total_points(), 400);
+
+        playlist.start();
+        assert!(playlist.results.is_empty() && !playlist.finished);
+        playlist.push_level(9);
+        assert!(!playlist.is_running(), "editing ends the run");
+        assert_eq!(Playlist::default().start(), None);
+    }
+}
@@ -0,0 +1,211 @@
+//! Wrap-around side walls (portal edges).
+//!
+//! A level can let balls leave through the left or right wall and come back in through the
+//! opposite one:
+//!
+//! ```ron
+//! wrap_sides: true,
+//! ```
+//!
+//! The two side walls ([`SideWall`]) then become sensors: balls (and merkabas) pass into them
+//! instead of bouncing, and [`wrap_through_portal_edges`] moves everything that enters one to the
+//! opposite edge of the field, keeping its velocity. Levels without the option get solid walls
+//! back. The upper wall and the goal are never affected.
+//!
+//! Wrapping edges are drawn with a shimmering translucent band along the inside of the wall.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{CollisionEvent, Sensor, Velocity};
+
+use crate::level_loader::CurrentLevel;
+use crate::systems::merkaba::Merkaba;
+use crate::{Ball, SideWall, BALL_RADIUS, PLANE_H, PLANE_W};
+
+/// Gap left between a wrapped ball and the opposite wall, so it does not re-enter it at once.
+const WRAP_CLEARANCE: f32 = 0.05;
+/// Height of the shimmer bands (the gameplay plane).
+const SHIMMER_Y: f32 = 2.0;
+/// Pulses per second of the shimmer bands.
+const SHIMMER_SPEED: f32 = 1.5;
+
+/// Whether the current level wraps balls around its side walls.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PortalEdges {
+    pub active: bool,
+}
+
+/// Translucent band drawn along a wrapping side wall.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PortalShimmer;
+
+/// Where something at `z` that entered a side wall comes back in: just inside the opposite edge.
+pub fn wrapped_z(z: f32) -> f32 {
+    -z.signum() * (PLANE_W / 2.0 - BALL_RADIUS - WRAP_CLEARANCE)
+}
+
+/// Opacity of the shimmer bands `elapsed` seconds into the level.
+pub fn shimmer_alpha(elapsed: f32) -> f32 {
+    0.35 + 0.2 * (elapsed * SHIMMER_SPEED * std::f32::consts::TAU).sin()
+}
+
+/// Read the wrap option of the newly loaded level.
+pub fn load_portal_edges(current_level: Res<CurrentLevel>, mut portals: ResMut<PortalEdges>) {
+    portals.set_if_neq(PortalEdges {
+        active: current_level.0.wrap_sides,
+    });
+}
+
+/// Turn the side walls into sensors (and draw the shimmer) while the level wraps, and back into
+/// solid walls otherwise.
+///
+/// Runs every frame rather than on level change, since the walls are spawned independently of
+/// level loading.
+pub fn sync_portal_walls(
+    mut commands: Commands,
+    portals: Res<PortalEdges>,
+    walls: Query<(Entity, Has<Sensor>), With<SideWall>>,
+    shimmers: Query<Entity, With<PortalShimmer>>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    for (wall, sensor) in walls.iter() {
+        match (portals.active, sensor) {
+            (true, false) => {
+                commands.entity(wall).insert(Sensor);
+            }
+            (false, true) => {
+                commands.entity(wall).remove::<Sensor>();
+            }
+            _ => {}
+        }
+    }
+
+    if !portals.active {
+        for shimmer in shimmers.iter() {
+            commands.entity(shimmer).despawn();
+        }
+        return;
+    }
+    if !shimmers.is_empty() {
+        return;
+    }
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    let mesh = meshes.add(Cuboid::new(PLANE_H, 0.6, 0.05));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.3, 0.9, 1.0, shimmer_alpha(0.0)),
+        emissive: LinearRgba::rgb(0.1, 0.5, 0.7),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    for side in [-1.0, 1.0] {
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(0.0, SHIMMER_Y, side * (PLANE_W / 2.0 - 0.05)),
+            PortalShimmer,
+        ));
+    }
+}
+
+/// Pulse the opacity of the shimmer bands.
+pub fn animate_portal_shimmer(
+    time: Res<Time>,
+    shimmers: Query<&MeshMaterial3d<StandardMaterial>, With<PortalShimmer>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let Some(mut materials) = materials else {
+        return;
+    };
+    // Both bands share one material
+    let Some(handle) = shimmers.iter().next() else {
+        return;
+    };
+    if let Some(material) = materials.get_mut(&handle.0) {
+        material
+            .base_color
+            .set_alpha(shimmer_alpha(time.elapsed_secs()));
+    }
+}
+
+/// Move balls and merkabas entering a wrapping side wall to the opposite edge.
+pub fn wrap_through_portal_edges(
+    mut collision_events: MessageReader<CollisionEvent>,
+    portals: Res<PortalEdges>,
+    walls: Query<(), (With<SideWall>, With<Sensor>)>,
+    mut movers: Query<(&mut Transform, &Velocity), Or<(With<Ball>, With<Merkaba>)>>,
+) {
+    for event in collision_events.read() {
+        if !portals.active {
+            continue;
+        }
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        let mover = if walls.contains(*e2) {
+            *e1
+        } else if walls.contains(*e1) {
+            *e2
+        } else {
+            continue;
+        };
+        let Ok((mut transform, velocity)) = movers.get_mut(mover) else {
+            continue;
+        };
+        // Only wrap what is heading out; a wrapped ball touching the other wall is heading in
+        if transform.translation.z * velocity.linvel.z <= 0.0 {
+            continue;
+        }
+        transform.translation.z = wrapped_z(transform.translation.z);
+        debug!(
+            "Portal edge wrapped {:?} to z = {}",
+            mover, transform.translation.z
+        );
+    }
+}
+
+/// Plugin registering wrap-around side walls.
+pub struct PortalEdgesPlugin;
+
+impl Plugin for PortalEdgesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PortalEdges>();
+        app.add_message::<CollisionEvent>();
+        app.add_systems(
+            Update,
+            (
+                load_portal_edges.run_if(resource_exists_and_changed::<CurrentLevel>),
+                sync_portal_walls,
+                animate_portal_shimmer,
+                wrap_through_portal_edges,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_lands_just_inside_the_opposite_edge() {
+        let right = wrapped_z(PLANE_W / 2.0 - 0.1);
+        assert!(right < 0.0);
+        assert!(
+            right - BALL_RADIUS > -PLANE_W / 2.0,
+            "clear of the left wall"
+        );
+        assert_eq!(wrapped_z(-PLANE_W / 2.0 + 0.1), -right);
+    }
+
+    #[test]
+    fn shimmer_stays_visible() {
+        for step in 0..100 {
+            let alpha = shimmer_alpha(step as f32 * 0.05);
+            assert!((0.1..=0.6).contains(&alpha));
+        }
+    }
+}
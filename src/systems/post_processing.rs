@@ -0,0 +1,248 @@
+//! Optional post-processing: bloom, vignette and chromatic aberration.
+//!
+//! # Configuration
+//! [`PostProcessingConfig`] picks a performance [`PostProcessingPreset`] and can switch single
+//! effects on or off on top of it. It is read at startup from `config/graphics.ron` when that file
+//! exists (from localStorage on wasm, see `systems::web_storage`), e.g.
+//!
+//! ```ron
+//! (preset: High, chromatic_aberration: Some(false))
+//! ```
+//!
+//! Changing the resource at runtime re-applies the effects.
+//!
+//! | Preset | Bloom | Vignette | Chromatic aberration |
+//! |--------|-------|----------|----------------------|
+//! | `Off` | - | - | - |
+//! | `Low` | - | yes | - |
+//! | `Medium` (native default) | yes | yes | - |
+//! | `High` | yes | yes | yes |
+//!
+//! # Effects
+//! - Bloom makes emissive bricks glow; it switches the main camera to HDR.
+//! - The vignette is a UI radial gradient over the whole screen, so it costs no extra render pass
+//!   and works everywhere.
+//! - Chromatic aberration uses Bevy's post-processing stack and is kept subtle.
+//!
+//! # wasm fallback
+//! Browsers run the WebGL2 backend, where the HDR passes are comparatively expensive: wasm builds
+//! default to `Low` and never enable chromatic aberration.
+
+use bevy::post_process::bloom::Bloom;
+use bevy::post_process::effect_stack::ChromaticAberration;
+use bevy::prelude::*;
+use bevy::render::view::Hdr;
+use serde::{Deserialize, Serialize};
+
+use crate::systems::profiles::{profile_path, profile_switched, CurrentProfile};
+use crate::systems::web_storage::read_save_file;
+use crate::MainCamera;
+
+/// Native path of the optional graphics config file.
+pub const GRAPHICS_CONFIG_PATH: &str = "config/graphics.ron";
+
+/// Bloom strength; low, so only emissive surfaces really glow.
+const BLOOM_INTENSITY: f32 = 0.15;
+/// Fringe size as a fraction of the window.
+const CHROMATIC_ABERRATION_INTENSITY: f32 = 0.004;
+/// Darkness of the vignette at the screen corners.
+const VIGNETTE_ALPHA: f32 = 0.45;
+
+/// Performance presets, from cheapest to most expensive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PostProcessingPreset {
+    Off,
+    Low,
+    Medium,
+    High,
+}
+
+impl Default for PostProcessingPreset {
+    fn default() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            Self::Low
+        } else {
+            Self::Medium
+        }
+    }
+}
+
+/// Post-processing settings: a preset plus optional per-effect overrides.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PostProcessingConfig {
+    #[serde(default)]
+    pub preset: PostProcessingPreset,
+    #[serde(default)]
+    pub bloom: Option<bool>,
+    #[serde(default)]
+    pub vignette: Option<bool>,
+    #[serde(default)]
+    pub chromatic_aberration: Option<bool>,
+}
+
+/// Which effects end up enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostProcessingEffects {
+    pub bloom: bool,
+    pub vignette: bool,
+    pub chromatic_aberration: bool,
+}
+
+impl PostProcessingConfig {
+    /// Resolve the preset and overrides into the effects to enable on this platform.
+    pub fn effects(&self) -> PostProcessingEffects {
+        use PostProcessingPreset::*;
+        let preset = self.preset;
+        PostProcessingEffects {
+            bloom: self.bloom.unwrap_or(matches!(preset, Medium | High)),
+            vignette: self.vignette.unwrap_or(preset != Off),
+            chromatic_aberration: !cfg!(target_arch = "wasm32")
+                && self.chromatic_aberration.unwrap_or(preset == High),
+        }
+    }
+}
+
+/// Full-screen vignette overlay.
+#[derive(Component, Debug)]
+pub struct Vignette;
+
+/// Load `config/graphics.ron` (the active profile's `graphics.ron`, see `systems::profiles`) into
+/// the [`PostProcessingConfig`] resource.
+pub fn load_post_processing_config(mut commands: Commands, profile: Option<Res<CurrentProfile>>) {
+    let path = profile_path(profile.as_deref(), "graphics.ron", GRAPHICS_CONFIG_PATH);
+    let config = read_save_file(&path, |content| {
+        match ron::de::from_str::<PostProcessingConfig>(content) {
+            Ok(loaded) => {
+                info!("Loaded graphics config from {}", path.display());
+                Some(loaded)
+            }
+            Err(e) => {
+                warn!("Failed to parse graphics config: {}; using defaults", e);
+                None
+            }
+        }
+    })
+    .unwrap_or_default();
+
+    commands.insert_resource(config);
+}
+
+/// Apply the configured effects to the main camera and the vignette overlay.
+///
+/// Runs when the config changes or a main camera is spawned.
+pub fn apply_post_processing(
+    mut commands: Commands,
+    config: Res<PostProcessingConfig>,
+    cameras: Query<Entity, With<MainCamera>>,
+    new_cameras: Query<(), Added<MainCamera>>,
+    vignettes: Query<Entity, With<Vignette>>,
+) {
+    if !config.is_changed() && new_cameras.is_empty() {
+        return;
+    }
+    let effects = config.effects();
+    for camera in cameras.iter() {
+        let mut camera = commands.entity(camera);
+        if effects.bloom {
+            camera.insert(Bloom {
+                intensity: BLOOM_INTENSITY,
+                ..Bloom::NATURAL
+            });
+        } else {
+            camera.remove::<(Bloom, Hdr)>();
+        }
+        if effects.chromatic_aberration {
+            camera.insert(ChromaticAberration {
+                intensity: CHROMATIC_ABERRATION_INTENSITY,
+                ..default()
+            });
+        } else {
+            camera.remove::<ChromaticAberration>();
+        }
+    }
+
+    match (effects.vignette, vignettes.is_empty()) {
+        (true, true) => {
+            commands.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundGradient::from(RadialGradient::new(
+                    UiPosition::CENTER,
+                    RadialGradientShape::FarthestCorner,
+                    vec![
+                        ColorStop::new(Color::NONE, Val::Percent(55.0)),
+                        ColorStop::new(
+                            Color::srgba(0.0, 0.0, 0.0, VIGNETTE_ALPHA),
+                            Val::Percent(100.0),
+                        ),
+                    ],
+                )),
+                // Behind every other UI element, and never in the way of clicks
+                GlobalZIndex(i32::MIN),
+                Pickable::IGNORE,
+                Vignette,
+            ));
+        }
+        (false, false) => {
+            for entity in vignettes.iter() {
+                commands.entity(entity).despawn();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Plugin registering configurable post-processing.
+pub struct PostProcessingPlugin;
+
+impl Plugin for PostProcessingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PostProcessingConfig>();
+        app.add_systems(Startup, load_post_processing_config);
+        app.add_systems(
+            Update,
+            load_post_processing_config
+                .run_if(profile_switched)
+                .before(apply_post_processing),
+        );
+        app.add_systems(Update, apply_post_processing);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_scale_up_and_overrides_win() {
+        let effects = |preset, bloom| {
+            PostProcessingConfig {
+                preset,
+                bloom,
+                ..default()
+            }
+            .effects()
+        };
+        let off = effects(PostProcessingPreset::Off, None);
+        assert!(!off.bloom && !off.vignette && !off.chromatic_aberration);
+        let low = effects(PostProcessingPreset::Low, None);
+        assert!(!low.bloom && low.vignette);
+        let medium = effects(PostProcessingPreset::Medium, None);
+        assert!(medium.bloom && !medium.chromatic_aberration);
+        assert!(!effects(PostProcessingPreset::High, Some(false)).bloom);
+        assert!(effects(PostProcessingPreset::Low, Some(true)).bloom);
+    }
+
+    #[test]
+    fn config_parses_with_partial_overrides() {
+        let config: PostProcessingConfig =
+            ron::de::from_str("(preset: High, chromatic_aberration: Some(false))").unwrap();
+        assert_eq!(config.preset, PostProcessingPreset::High);
+        assert_eq!(config.bloom, None);
+        assert!(!config.effects().chromatic_aberration);
+    }
+}
@@ -0,0 +1,815 @@
+//! Falling powerup capsules released by destroyed bricks.
+//!
+//! Any destructible brick may drop a capsule when it is destroyed. Whether it drops, and which
+//! capsule it drops, is decided by the active [`DropTable`]: the current level's `drops` override
+//! when present, otherwise the global table in [`GameplayConfig`]. Rolls use the seeded
+//! [`GameRng`] so a fixed `BK_SEED` reproduces the same drops.
+//!
+//! Powerup bricks always drop their own capsule instead of rolling ([`powerup_brick_capsule`]):
+//! brick 30 a shrink capsule, brick 32 an enlarge capsule, brick 58 a catch capsule. Their effect
+//! only applies once the paddle catches it.
+//!
+//! Some capsules are debuffs (shrink paddle, reversed controls, faster ball). They are tinted in
+//! warm/purple tones and listed with a `-` prefix in the effects HUD, so catching a capsule is a
+//! risk/reward decision rather than a free bonus.
+//!
+//! The faster ball speeds up every ball in play for a while ([`FastBall`]); when it runs out, the
+//! balls slow down by the same factor again.
+//!
+//! The rubber paddle changes the paddle's surface for a while ([`PaddleSurface`]): the ball
+//! bounces off it harder and takes more of the paddle's movement with it. The surface starts from
+//! the `PaddlePhysicsConfig` values, and `sync_paddle_surface` keeps the paddle's contact
+//! restitution and friction in line with it.
+//!
+//! The catch capsule makes the paddle sticky for a while: balls that touch it stick to it until
+//! launched (see `sticky_paddle`).
+//!
+//! Capsules fall towards the lower goal (+X) at a constant speed. They are kinematic-free: the
+//! paddle catches a capsule when the capsule centre comes within reach of the paddle's capsule
+//! segment, which keeps them out of the Rapier collision pipeline entirely. Capsules that pass the
+//! lower goal line are despawned, and all capsules are cleared on level change or life loss.
+//!
+//! # System Organization
+//!
+//! - [`PowerupSystems::Drop`]: roll drops for bricks marked for despawn this frame
+//! - [`PowerupSystems::Move`]: move capsules and despawn missed ones
+//! - [`PowerupSystems::Collect`]: detect paddle catches and emit [`PowerupCollected`]
+//! - [`PowerupSystems::Apply`]: apply the collected effect
+//!
+//! Ordering: Move -> Collect -> Apply. `Drop` is pinned between brick marking and despawn.
+
+use bevy::ecs::message::{Message, MessageReader, MessageWriter};
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::gameplay_config::{load_gameplay_config, DropTable, GameplayConfig};
+use crate::level_format::GLUE_BRICK;
+use crate::level_loader::CurrentLevel;
+use crate::physics_config::{PaddlePhysicsConfig, MAX_SPIN_TRANSFER};
+use crate::signals::LifeAwardMessage;
+use crate::systems::ball_size::{apply_ball_size_effect, BallSizeEffect, BallSizeEffectType};
+use crate::systems::paddle_size::{
+    apply_size_effect, PaddleSizeEffect, PaddleSizeEffectApplied, SizeEffectType, BRICK_TYPE_30,
+    BRICK_TYPE_32,
+};
+use crate::systems::respawn::LifeLostEvent;
+use crate::systems::rng::GameRng;
+use crate::systems::sticky_paddle::StickyPaddle;
+use crate::systems::timed_effects::{remove_finished_effects, tick_timed_effects, TimedEffect};
+use crate::systems::wrecking_ball::{start_wrecking_ball, WreckingBallConfig};
+use bevy_rapier3d::prelude::{Friction, Restitution, Velocity};
+
+use crate::{
+    Ball, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn, Paddle, PADDLE_HEIGHT,
+    PADDLE_RADIUS, PLANE_H,
+};
+
+/// Capsule collision radius used for catching.
+pub const CAPSULE_RADIUS: f32 = 0.35;
+/// Length of the capsule's cylindrical section (visual only).
+pub const CAPSULE_LENGTH: f32 = 0.8;
+/// Fall speed towards the lower goal, in world units per second.
+pub const CAPSULE_FALL_SPEED: f32 = 6.0;
+/// Height at which capsules travel (matches the ball/paddle plane).
+pub const CAPSULE_Y: f32 = 2.0;
+/// How long the reversed-controls debuff lasts, in seconds.
+pub const REVERSE_CONTROLS_DURATION: f32 = 5.0;
+/// Velocity multiplier applied to every ball by the speed-up debuff.
+pub const BALL_SPEED_UP_FACTOR: f32 = 1.35;
+/// How long the speed-up debuff lasts, in seconds.
+pub const BALL_SPEED_UP_DURATION: f32 = 8.0;
+/// How long the rubber paddle lasts, in seconds.
+pub const RUBBER_PADDLE_DURATION: f32 = 8.0;
+/// Restitution added to the paddle's by the rubber paddle (capped at 2).
+pub const RUBBER_RESTITUTION_BONUS: f32 = 0.5;
+/// Factor applied to the paddle's spin transfer by the rubber paddle: rubber grips the ball.
+pub const RUBBER_SPIN_FACTOR: f32 = 2.0;
+
+/// System set organization for the powerup capsule feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum PowerupSystems {
+    /// Roll drop tables for bricks about to be despawned
+    Drop,
+    /// Move capsules and despawn missed ones
+    Move,
+    /// Detect capsules caught by the paddle
+    Collect,
+    /// Apply collected effects
+    Apply,
+}
+
+/// Effect carried by a capsule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PowerupKind {
+    /// Enlarge the paddle (same effect as brick 32)
+    EnlargePaddle,
+    /// Award one extra life (same effect as brick 41)
+    ExtraLife,
+    /// Debuff: shrink the paddle (same effect as brick 30)
+    ShrinkPaddle,
+    /// Debuff: invert paddle movement for [`REVERSE_CONTROLS_DURATION`] seconds
+    ReverseControls,
+    /// Debuff: multiply every ball's velocity by [`BALL_SPEED_UP_FACTOR`] for
+    /// [`BALL_SPEED_UP_DURATION`] seconds
+    SpeedUpBall,
+    /// Grow every ball for a limited time (see `ball_size`)
+    GrowBall,
+    /// Debuff: shrink every ball for a limited time (see `ball_size`)
+    ShrinkBall,
+    /// Heavy ball that knocks bricks loose as debris (see `wrecking_ball`)
+    WreckingBall,
+    /// Bouncier, grippier paddle surface for [`RUBBER_PADDLE_DURATION`] seconds
+    RubberPaddle,
+    /// Sticky paddle that catches the ball until launched (see `sticky_paddle`)
+    Catch,
+}
+
+impl PowerupKind {
+    /// All kinds, in a stable order (used for asset caching).
+    pub const ALL: [PowerupKind; 10] = [
+        PowerupKind::EnlargePaddle,
+        PowerupKind::ExtraLife,
+        PowerupKind::ShrinkPaddle,
+        PowerupKind::ReverseControls,
+        PowerupKind::SpeedUpBall,
+        PowerupKind::GrowBall,
+        PowerupKind::ShrinkBall,
+        PowerupKind::WreckingBall,
+        PowerupKind::RubberPaddle,
+        PowerupKind::Catch,
+    ];
+
+    /// Whether catching this capsule hurts the player.
+    pub fn is_debuff(self) -> bool {
+        matches!(
+            self,
+            PowerupKind::ShrinkPaddle
+                | PowerupKind::ReverseControls
+                | PowerupKind::SpeedUpBall
+                | PowerupKind::ShrinkBall
+        )
+    }
+
+    /// Capsule tint so players can tell capsules apart while they fall.
+    /// Buffs are green/pink, debuffs red/purple/orange.
+    pub fn color(self) -> Color {
+        match self {
+            PowerupKind::EnlargePaddle => Color::srgb(0.3, 0.85, 0.35),
+            PowerupKind::ExtraLife => Color::srgb(0.95, 0.3, 0.6),
+            PowerupKind::ShrinkPaddle => Color::srgb(0.9, 0.15, 0.1),
+            PowerupKind::ReverseControls => Color::srgb(0.55, 0.2, 0.9),
+            PowerupKind::SpeedUpBall => Color::srgb(1.0, 0.55, 0.05),
+            PowerupKind::GrowBall => Color::srgb(0.2, 0.8, 0.95),
+            PowerupKind::ShrinkBall => Color::srgb(0.8, 0.1, 0.45),
+            PowerupKind::WreckingBall => Color::srgb(0.55, 0.55, 0.6),
+            PowerupKind::RubberPaddle => Color::srgb(0.95, 0.85, 0.2),
+            PowerupKind::Catch => Color::srgb(0.6, 0.9, 0.75),
+        }
+    }
+
+    /// Short player-facing name, used by the effects HUD.
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerupKind::EnlargePaddle => "Enlarge",
+            PowerupKind::ExtraLife => "Extra life",
+            PowerupKind::ShrinkPaddle => "Shrink",
+            PowerupKind::ReverseControls => "Reversed",
+            PowerupKind::SpeedUpBall => "Fast ball",
+            PowerupKind::GrowBall => "Big ball",
+            PowerupKind::ShrinkBall => "Small ball",
+            PowerupKind::WreckingBall => "Wrecking ball",
+            PowerupKind::RubberPaddle => "Rubber paddle",
+            PowerupKind::Catch => "Catch",
+        }
+    }
+}
+
+/// Paddle debuff: horizontal input is inverted until the timer finishes.
+#[derive(Component, Debug, Clone)]
+pub struct ReversedControls {
+    pub timer: Timer,
+}
+
+impl Default for ReversedControls {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(REVERSE_CONTROLS_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+impl TimedEffect for ReversedControls {
+    fn kind(&self) -> PowerupKind {
+        PowerupKind::ReverseControls
+    }
+
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// Paddle buff: a rubber surface that bounces the ball harder and passes on more of the paddle's
+/// movement, until the timer finishes.
+#[derive(Component, Debug, Clone)]
+pub struct RubberPaddle {
+    pub timer: Timer,
+}
+
+impl Default for RubberPaddle {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(RUBBER_PADDLE_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+impl TimedEffect for RubberPaddle {
+    fn kind(&self) -> PowerupKind {
+        PowerupKind::RubberPaddle
+    }
+
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// Ball debuff: the ball moves [`BALL_SPEED_UP_FACTOR`] times faster until the timer finishes.
+#[derive(Component, Debug, Clone)]
+pub struct FastBall {
+    pub timer: Timer,
+}
+
+impl Default for FastBall {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(BALL_SPEED_UP_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+impl TimedEffect for FastBall {
+    fn kind(&self) -> PowerupKind {
+        PowerupKind::SpeedUpBall
+    }
+
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// Bounce characteristics of a paddle: its `PaddlePhysicsConfig` values, modified by surface
+/// powerups.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaddleSurface {
+    pub restitution: f32,
+    pub friction: f32,
+    pub spin_transfer: f32,
+}
+
+impl PaddleSurface {
+    /// Surface of a paddle with the given config, with or without the rubber paddle.
+    pub fn of(config: &PaddlePhysicsConfig, rubber: bool) -> Self {
+        let base = Self {
+            restitution: config.restitution,
+            friction: config.friction,
+            spin_transfer: config.spin_transfer,
+        };
+        if !rubber {
+            return base;
+        }
+        Self {
+            restitution: (base.restitution + RUBBER_RESTITUTION_BONUS).min(2.0),
+            spin_transfer: (base.spin_transfer * RUBBER_SPIN_FACTOR).min(MAX_SPIN_TRANSFER),
+            ..base
+        }
+    }
+}
+
+/// A falling capsule waiting to be caught.
+/// Constitution VIII: Required Components — all 3D entities require Transform + Visibility.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Transform, Visibility)]
+pub struct PowerupCapsule {
+    pub kind: PowerupKind,
+}
+
+/// Message emitted when the paddle catches a capsule.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct PowerupCollected {
+    pub kind: PowerupKind,
+    pub paddle: Entity,
+}
+
+/// Cached capsule mesh and per-kind materials.
+/// Constitution VIII: Asset Handle Reuse — created once, shared by every capsule.
+#[derive(Resource, Debug, Clone)]
+pub struct PowerupAssets {
+    pub mesh: Handle<Mesh>,
+    pub materials: Vec<(PowerupKind, Handle<StandardMaterial>)>,
+}
+
+impl PowerupAssets {
+    fn material(&self, kind: PowerupKind) -> Option<Handle<StandardMaterial>> {
+        self.materials
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, h)| h.clone())
+    }
+}
+
+/// Resolve the drop table in effect for the current level.
+pub fn active_drop_table<'a>(
+    level: Option<&'a CurrentLevel>,
+    config: &'a GameplayConfig,
+) -> &'a DropTable {
+    level
+        .and_then(|l| l.0.drops.as_ref())
+        .unwrap_or(&config.drops)
+}
+
+/// Capsule always dropped by the powerup brick `brick_type`, if it is one.
+pub fn powerup_brick_capsule(brick_type: u8) -> Option<PowerupKind> {
+    match brick_type {
+        BRICK_TYPE_30 => Some(PowerupKind::ShrinkPaddle),
+        BRICK_TYPE_32 => Some(PowerupKind::EnlargePaddle),
+        GLUE_BRICK => Some(PowerupKind::Catch),
+        _ => None,
+    }
+}
+
+/// Roll a drop table once. Returns the capsule kind to spawn, if any.
+pub fn roll_drop(table: &DropTable, rng: &mut impl Rng) -> Option<PowerupKind> {
+    let total = table.total_weight();
+    if table.chance <= 0.0 || total == 0 {
+        return None;
+    }
+    if rng.random::<f32>() >= table.chance {
+        return None;
+    }
+    table.pick(rng.random_range(0..total))
+}
+
+/// Point of the paddle's capsule segment closest to `point`, in the play plane (XZ).
+///
+/// The paddle capsule's axis is its local Y, so the segment endpoints follow the paddle's
+/// rotation and its `scale.y` (used by size effects).
+pub fn closest_point_on_paddle(point: Vec3, paddle: &Transform) -> Vec2 {
+    let half = Vec3::Y * (PADDLE_HEIGHT / 2.0);
+    let a = paddle.transform_point(half).xz();
+    let b = paddle.transform_point(-half).xz();
+    let ab = b - a;
+    let t = if ab.length_squared() > f32::EPSILON {
+        ((point.xz() - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    a + ab * t
+}
+
+/// Shortest distance in the play plane (XZ) from `point` to the paddle's capsule segment.
+pub fn distance_to_paddle(point: Vec3, paddle: &Transform) -> f32 {
+    point.xz().distance(closest_point_on_paddle(point, paddle))
+}
+
+fn setup_powerup_assets(
+    mut commands: Commands,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    let mesh = meshes.add(Capsule3d::new(CAPSULE_RADIUS, CAPSULE_LENGTH));
+    let materials = PowerupKind::ALL
+        .iter()
+        .map(|kind| {
+            let color = kind.color();
+            (
+                *kind,
+                materials.add(StandardMaterial {
+                    base_color: color,
+                    emissive: color.to_linear() * 0.4,
+                    ..default()
+                }),
+            )
+        })
+        .collect();
+    commands.insert_resource(PowerupAssets { mesh, materials });
+}
+
+/// Drop capsules for the destructible bricks marked for despawn this frame: the powerup bricks'
+/// own capsule, otherwise a roll of the active drop table.
+pub fn roll_powerup_drops(
+    mut commands: Commands,
+    bricks: Query<
+        (&Transform, &BrickTypeId),
+        (With<CountsTowardsCompletion>, Added<MarkedForDespawn>),
+    >,
+    config: Res<GameplayConfig>,
+    current_level: Option<Res<CurrentLevel>>,
+    mut rng: ResMut<GameRng>,
+    assets: Option<Res<PowerupAssets>>,
+    wrecking: Option<Res<WreckingBallConfig>>,
+) {
+    if bricks.is_empty() {
+        return;
+    }
+    let table = active_drop_table(current_level.as_deref(), &config);
+    for (transform, brick_type) in bricks.iter() {
+        let kind = powerup_brick_capsule(brick_type.0).or_else(|| roll_drop(table, &mut **rng));
+        let Some(kind) = kind else {
+            continue;
+        };
+        // Don't offer a capsule whose mode is disabled on this platform
+        if kind == PowerupKind::WreckingBall && !wrecking.as_ref().is_some_and(|w| w.enabled) {
+            continue;
+        }
+        let pos = transform.translation;
+        let mut capsule = commands.spawn((
+            PowerupCapsule { kind },
+            // Lay the capsule along Z so it reads as a pill falling towards the paddle.
+            Transform::from_xyz(pos.x, CAPSULE_Y, pos.z)
+                .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        ));
+        if let Some(assets) = assets.as_ref() {
+            capsule.insert(Mesh3d(assets.mesh.clone()));
+            if let Some(material) = assets.material(kind) {
+                capsule.insert(MeshMaterial3d(material));
+            }
+        }
+        debug!("Brick dropped {:?} capsule at {:?}", kind, pos);
+    }
+}
+
+/// Move capsules towards the lower goal and despawn those that slipped past the paddle.
+pub fn move_powerup_capsules(
+    mut commands: Commands,
+    mut capsules: Query<(Entity, &mut Transform), With<PowerupCapsule>>,
+    time: Res<Time>,
+) {
+    let step = CAPSULE_FALL_SPEED * time.delta_secs();
+    for (entity, mut transform) in capsules.iter_mut() {
+        transform.translation.x += step;
+        if transform.translation.x > PLANE_H / 2.0 + CAPSULE_RADIUS {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Catch capsules that touch the paddle.
+pub fn collect_powerup_capsules(
+    mut commands: Commands,
+    capsules: Query<(Entity, &Transform, &PowerupCapsule)>,
+    paddles: Query<(Entity, &Transform), With<Paddle>>,
+    mut collected: MessageWriter<PowerupCollected>,
+) {
+    let reach = PADDLE_RADIUS + CAPSULE_RADIUS;
+    for (capsule_entity, capsule_transform, capsule) in capsules.iter() {
+        let caught_by = paddles.iter().find(|(_, paddle_transform)| {
+            distance_to_paddle(capsule_transform.translation, paddle_transform) <= reach
+        });
+        if let Some((paddle, _)) = caught_by {
+            commands.entity(capsule_entity).despawn();
+            collected.write(PowerupCollected {
+                kind: capsule.kind,
+                paddle,
+            });
+            debug!("Paddle caught {:?} capsule", capsule.kind);
+        }
+    }
+}
+
+/// Apply the effect of each caught capsule.
+pub fn apply_collected_powerups(
+    mut commands: Commands,
+    mut collected: MessageReader<PowerupCollected>,
+    paddles: Query<(&Transform, Option<&PaddleSizeEffect>), With<Paddle>>,
+    mut balls: Query<
+        (
+            Entity,
+            &mut Velocity,
+            Option<&BallSizeEffect>,
+            Option<&mut FastBall>,
+        ),
+        With<Ball>,
+    >,
+    mut size_events: MessageWriter<PaddleSizeEffectApplied>,
+    mut life_awards: MessageWriter<LifeAwardMessage>,
+    wrecking: Option<Res<WreckingBallConfig>>,
+    config: Option<Res<GameplayConfig>>,
+) {
+    let size_config = config.map(|c| c.paddle_size).unwrap_or_default();
+    // Balls sped up by this call, whose `FastBall` is not inserted yet
+    let mut sped_up = Vec::new();
+    for event in collected.read() {
+        match event.kind {
+            PowerupKind::EnlargePaddle | PowerupKind::ShrinkPaddle => {
+                let effect_type = if event.kind == PowerupKind::EnlargePaddle {
+                    SizeEffectType::Enlarge
+                } else {
+                    SizeEffectType::Shrink
+                };
+                if let Ok((transform, existing)) = paddles.get(event.paddle) {
+                    apply_size_effect(
+                        &mut commands,
+                        event.paddle,
+                        transform,
+                        existing,
+                        effect_type,
+                        &size_config,
+                        &mut size_events,
+                    );
+                }
+            }
+            PowerupKind::ExtraLife => {
+                life_awards.write(LifeAwardMessage { delta: 1 });
+            }
+            PowerupKind::ReverseControls => {
+                // Re-inserting restarts the timer when the debuff is caught twice
+                commands
+                    .entity(event.paddle)
+                    .insert(ReversedControls::default());
+            }
+            PowerupKind::SpeedUpBall => {
+                for (ball, mut velocity, _, fast) in balls.iter_mut() {
+                    // Catching it again restarts the timer without speeding the ball up twice
+                    if let Some(mut fast) = fast {
+                        fast.timer.reset();
+                    } else if !sped_up.contains(&ball) {
+                        // `limit_ball_velocity` still caps the result at the global maximum
+                        velocity.linvel *= BALL_SPEED_UP_FACTOR;
+                        commands.entity(ball).insert(FastBall::default());
+                        sped_up.push(ball);
+                    }
+                }
+            }
+            PowerupKind::GrowBall | PowerupKind::ShrinkBall => {
+                let effect_type = if event.kind == PowerupKind::GrowBall {
+                    BallSizeEffectType::Grow
+                } else {
+                    BallSizeEffectType::Shrink
+                };
+                for (ball, _, _, _) in balls.iter() {
+                    apply_ball_size_effect(&mut commands, ball, effect_type);
+                }
+            }
+            PowerupKind::WreckingBall => {
+                let Some(config) = wrecking.as_deref().filter(|c| c.enabled) else {
+                    continue;
+                };
+                for (ball, _, size_effect, _) in balls.iter() {
+                    start_wrecking_ball(&mut commands, ball, size_effect, config);
+                }
+            }
+            PowerupKind::RubberPaddle => {
+                // Re-inserting restarts the timer when the capsule is caught twice
+                commands
+                    .entity(event.paddle)
+                    .insert(RubberPaddle::default());
+            }
+            PowerupKind::Catch => {
+                // Re-inserting restarts the timer when the capsule is caught twice
+                commands
+                    .entity(event.paddle)
+                    .insert(StickyPaddle::default());
+            }
+        }
+    }
+}
+
+/// Slow balls whose speed-up has run out back down.
+pub fn expire_fast_balls(
+    mut commands: Commands,
+    mut balls: Query<(Entity, &FastBall, &mut Velocity)>,
+) {
+    for (entity, fast, mut velocity) in balls.iter_mut() {
+        if fast.timer.is_finished() {
+            velocity.linvel /= BALL_SPEED_UP_FACTOR;
+            commands.entity(entity).remove::<FastBall>();
+        }
+    }
+}
+
+/// Keep the paddle's contact restitution and friction in line with its surface.
+pub fn sync_paddle_surface(
+    config: Option<Res<PaddlePhysicsConfig>>,
+    mut paddles: Query<(&mut Restitution, &mut Friction, Has<RubberPaddle>), With<Paddle>>,
+) {
+    let Some(config) = config else {
+        return;
+    };
+    for (mut restitution, mut friction, rubber) in paddles.iter_mut() {
+        let surface = PaddleSurface::of(&config, rubber);
+        if restitution.coefficient != surface.restitution {
+            restitution.coefficient = surface.restitution;
+        }
+        if friction.coefficient != surface.friction {
+            friction.coefficient = surface.friction;
+        }
+    }
+}
+
+/// Remove falling capsules, timed paddle effects and the speed-up when the level changes or a life
+/// is lost.
+pub fn clear_powerups_on_reset(
+    mut commands: Commands,
+    capsules: Query<Entity, With<PowerupCapsule>>,
+    reversed: Query<Entity, With<ReversedControls>>,
+    rubber: Query<Entity, With<RubberPaddle>>,
+    sticky: Query<Entity, With<StickyPaddle>>,
+    mut fast: Query<(Entity, &mut Velocity), With<FastBall>>,
+    current_level: Option<Res<CurrentLevel>>,
+    life_lost: Option<MessageReader<LifeLostEvent>>,
+) {
+    let level_changed = current_level.is_some_and(|l| l.is_changed());
+    let life_lost = life_lost.is_some_and(|mut r| r.read().count() > 0);
+    if level_changed || life_lost {
+        for entity in capsules.iter() {
+            commands.entity(entity).despawn();
+        }
+        for entity in reversed.iter() {
+            commands.entity(entity).remove::<ReversedControls>();
+        }
+        for entity in rubber.iter() {
+            commands.entity(entity).remove::<RubberPaddle>();
+        }
+        for entity in sticky.iter() {
+            commands.entity(entity).remove::<StickyPaddle>();
+        }
+        for (entity, mut velocity) in fast.iter_mut() {
+            velocity.linvel /= BALL_SPEED_UP_FACTOR;
+            commands.entity(entity).remove::<FastBall>();
+        }
+    }
+}
+
+/// Plugin registering capsule drops, movement, catching, and effects.
+pub struct PowerupPlugin;
+
+impl Plugin for PowerupPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameRng>();
+        app.init_resource::<GameplayConfig>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_message::<PowerupCollected>();
+        app.add_message::<PaddleSizeEffectApplied>();
+        app.add_message::<LifeAwardMessage>();
+
+        app.add_systems(Startup, (load_gameplay_config, setup_powerup_assets));
+
+        app.configure_sets(
+            Update,
+            (
+                PowerupSystems::Move,
+                PowerupSystems::Collect,
+                PowerupSystems::Apply,
+            )
+                .chain(),
+        );
+        app.configure_sets(
+            Update,
+            PowerupSystems::Drop
+                .after(crate::mark_brick_on_ball_collision)
+                .before(crate::despawn_marked_entities),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                roll_powerup_drops.in_set(PowerupSystems::Drop),
+                move_powerup_capsules
+                    .in_set(PowerupSystems::Move)
+                    .run_if(crate::pause::not_paused),
+                collect_powerup_capsules
+                    .in_set(PowerupSystems::Collect)
+                    .run_if(crate::pause::not_paused),
+                apply_collected_powerups.in_set(PowerupSystems::Apply),
+                // Expiry restores normal input, the normal surface (`sync_paddle_surface`) and the
+                // ball speed
+                (
+                    tick_timed_effects::<ReversedControls>,
+                    tick_timed_effects::<RubberPaddle>,
+                    tick_timed_effects::<FastBall>,
+                )
+                    .in_set(PowerupSystems::Move)
+                    .run_if(crate::pause::not_paused),
+                (
+                    remove_finished_effects::<ReversedControls>,
+                    remove_finished_effects::<RubberPaddle>,
+                    expire_fast_balls,
+                )
+                    .in_set(PowerupSystems::Move)
+                    .after(tick_timed_effects::<ReversedControls>)
+                    .after(tick_timed_effects::<RubberPaddle>)
+                    .after(tick_timed_effects::<FastBall>),
+                sync_paddle_surface.after(PowerupSystems::Apply),
+                clear_powerups_on_reset.before(PowerupSystems::Move),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paddle_at(x: f32, z: f32) -> Transform {
+        Transform::from_xyz(x, 2.0, z)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+    }
+
+    #[test]
+    fn distance_follows_paddle_axis_along_z() {
+        let paddle = paddle_at(10.0, 0.0);
+        // Point beside the paddle tip along Z is within reach
+        let near_tip = Vec3::new(10.0, 2.0, PADDLE_HEIGHT / 2.0 + 0.2);
+        assert!(distance_to_paddle(near_tip, &paddle) < 0.3);
+        // Point offset along X is measured perpendicular to the segment
+        let above = Vec3::new(9.0, 2.0, 0.5);
+        assert!((distance_to_paddle(above, &paddle) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn distance_accounts_for_paddle_scale() {
+        let mut paddle = paddle_at(0.0, 0.0);
+        let point = Vec3::new(0.0, 2.0, PADDLE_HEIGHT);
+        let before = distance_to_paddle(point, &paddle);
+        paddle.scale.y = 2.0;
+        assert!(distance_to_paddle(point, &paddle) < before);
+    }
+
+    #[test]
+    fn debuffs_are_flagged() {
+        let debuffs: Vec<_> = PowerupKind::ALL.iter().filter(|k| k.is_debuff()).collect();
+        assert_eq!(debuffs.len(), 4);
+        assert!(!PowerupKind::EnlargePaddle.is_debuff());
+        assert!(!PowerupKind::ExtraLife.is_debuff());
+    }
+
+    #[test]
+    fn rubber_paddle_bounces_harder_and_grips_more() {
+        let config = PaddlePhysicsConfig::default();
+        let plain = PaddleSurface::of(&config, false);
+        let rubber = PaddleSurface::of(&config, true);
+        assert_eq!(plain.restitution, config.restitution);
+        assert_eq!(plain.spin_transfer, config.spin_transfer);
+        assert!(rubber.restitution > plain.restitution);
+        assert!(rubber.spin_transfer > plain.spin_transfer);
+        assert_eq!(rubber.friction, plain.friction);
+
+        let bouncy = PaddlePhysicsConfig {
+            restitution: 1.8,
+            ..PaddlePhysicsConfig::default()
+        };
+        assert_eq!(PaddleSurface::of(&bouncy, true).restitution, 2.0);
+    }
+
+    #[test]
+    fn powerup_bricks_drop_their_capsule() {
+        assert_eq!(powerup_brick_capsule(30), Some(PowerupKind::ShrinkPaddle));
+        assert_eq!(powerup_brick_capsule(32), Some(PowerupKind::EnlargePaddle));
+        assert_eq!(powerup_brick_capsule(58), Some(PowerupKind::Catch));
+        assert_eq!(powerup_brick_capsule(20), None);
+    }
+
+    #[test]
+    fn roll_drop_honours_chance_bounds() {
+        let mut rng = GameRng::from_seed(1);
+        assert_eq!(roll_drop(&DropTable::disabled(), &mut *rng), None);
+
+        let always = DropTable {
+            chance: 1.0,
+            ..DropTable::default()
+        };
+        for _ in 0..32 {
+            assert!(roll_drop(&always, &mut *rng).is_some());
+        }
+    }
+
+    #[test]
+    fn roll_drop_is_reproducible_for_a_seed() {
+        let table = DropTable {
+            chance: 0.5,
+            ..DropTable::default()
+        };
+        let mut a = GameRng::from_seed(99);
+        let mut b = GameRng::from_seed(99);
+        let xs: Vec<_> = (0..64).map(|_| roll_drop(&table, &mut *a)).collect();
+        let ys: Vec<_> = (0..64).map(|_| roll_drop(&table, &mut *b)).collect();
+        assert_eq!(xs, ys);
+    }
+}
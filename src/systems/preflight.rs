@@ -0,0 +1,377 @@
+//! Asset preflight check.
+//!
+//! Native builds check at startup that the assets the game refers to exist, before anything asks
+//! for them:
+//! - the numbered level files, which must parse; those listed in the level manifest or routed to
+//!   by the campaign must exist, and those missing from the level manifest are reported because
+//!   browser builds cannot find them
+//! - the textures and model scenes named in the texture manifest
+//! - the files of the audio manifest and of the sound packs, brick destruction sounds included,
+//!   and sound types without any sound
+//!
+//! The outcome is logged as one report and kept in [`PreflightReport`]; debug builds also list
+//! the problems in a panel (`ui::preflight_panel`) until it is dismissed.
+
+use bevy::prelude::*;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::launch::{level_file_path, levels_dir, LaunchOptions};
+use crate::level_loader::LevelDefinition;
+use crate::systems::audio::{AudioManifest, SoundType, AUDIO_MANIFEST_PATH, PLACEHOLDER_SOUNDS};
+use crate::systems::campaign::{CampaignManifest, CAMPAIGN_MANIFEST_PATH};
+use crate::systems::level_fetch::{asset_path, LevelManifest};
+use crate::systems::sound_packs::{SoundPacks, DEFAULT_SOUND_PACK, SOUND_PACKS_PATH};
+use crate::systems::textures::loader::{RawTextureManifest, TEXTURE_MANIFEST_PATH};
+use crate::systems::textures::materials::manifest_asset_path;
+
+/// What a preflight problem concerns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AssetKind {
+    Level,
+    Texture,
+    Sound,
+}
+
+impl fmt::Display for AssetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AssetKind::Level => "level",
+            AssetKind::Texture => "texture",
+            AssetKind::Sound => "sound",
+        })
+    }
+}
+
+/// One missing or broken asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreflightProblem {
+    pub kind: AssetKind,
+    pub message: String,
+}
+
+/// Outcome of the asset preflight check.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreflightReport {
+    /// Number of files checked
+    pub checked: usize,
+    pub problems: Vec<PreflightProblem>,
+}
+
+impl PreflightReport {
+    pub fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    fn problem(&mut self, kind: AssetKind, message: String) {
+        self.problems.push(PreflightProblem { kind, message });
+    }
+
+    /// Count the file `path` and report it if it does not exist; `what` names its use.
+    fn check_file(&mut self, kind: AssetKind, path: &Path, what: &str) {
+        self.checked += 1;
+        if !path.is_file() {
+            self.problem(kind, format!("{what}: {} is missing", path.display()));
+        }
+    }
+
+    /// The consolidated report, one problem per line.
+    pub fn summary(&self) -> String {
+        let mut text = format!(
+            "Asset preflight: {} files checked, {} problem(s)",
+            self.checked,
+            self.problems.len()
+        );
+        for problem in &self.problems {
+            text.push_str(&format!("\n  [{}] {}", problem.kind, problem.message));
+        }
+        text
+    }
+}
+
+/// Read and parse a RON file; a missing file is `Ok(None)`.
+fn read_ron<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Option<T>, String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(None);
+    };
+    ron::de::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("{} does not parse: {}", path.display(), e))
+}
+
+/// Number of a `level_NNN.ron` file name.
+fn level_number(file_name: &str) -> Option<u32> {
+    file_name
+        .strip_prefix("level_")?
+        .strip_suffix(".ron")?
+        .parse()
+        .ok()
+}
+
+/// Check the level files in `levels_dir` against its level manifest and the campaign manifest.
+pub fn check_levels(report: &mut PreflightReport, assets_dir: &Path, levels_dir: &Path) {
+    let mut present = BTreeSet::new();
+    if let Ok(entries) = fs::read_dir(levels_dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            if let Some(number) = name.to_str().and_then(level_number) {
+                present.insert(number);
+            }
+        }
+    }
+    for &number in &present {
+        report.checked += 1;
+        let path = level_file_path(levels_dir, number);
+        if let Err(e) = read_ron::<LevelDefinition>(Path::new(&path)) {
+            report.problem(AssetKind::Level, e);
+        }
+    }
+
+    let manifest_path = levels_dir.join("manifest.ron");
+    match read_ron::<LevelManifest>(&manifest_path) {
+        Ok(Some(manifest)) => {
+            for &number in &manifest.levels {
+                if !present.contains(&number) {
+                    report.checked += 1;
+                    let path = level_file_path(levels_dir, number);
+                    report.problem(
+                        AssetKind::Level,
+                        format!("listed in the level manifest: {path} is missing"),
+                    );
+                }
+            }
+            for &number in present.difference(&manifest.levels.iter().copied().collect()) {
+                report.problem(
+                    AssetKind::Level,
+                    format!(
+                        "{} is not in {}; browser builds cannot load it",
+                        level_file_path(levels_dir, number),
+                        manifest_path.display()
+                    ),
+                );
+            }
+        }
+        Ok(None) => report.problem(
+            AssetKind::Level,
+            format!("level manifest {} is missing", manifest_path.display()),
+        ),
+        Err(e) => report.problem(AssetKind::Level, e),
+    }
+
+    let campaign_path = assets_dir.join(asset_path(CAMPAIGN_MANIFEST_PATH));
+    match read_ron::<CampaignManifest>(&campaign_path) {
+        Ok(Some(campaign)) => {
+            for (&from, link) in &campaign.levels {
+                for target in [link.next, link.secret_exit].into_iter().flatten() {
+                    if !present.contains(&target) {
+                        report.checked += 1;
+                        report.problem(
+                            AssetKind::Level,
+                            format!(
+                                "campaign routes level {from} to {}, which is missing",
+                                level_file_path(levels_dir, target)
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+        // No campaign manifest means linear progression
+        Ok(None) => {}
+        Err(e) => report.problem(AssetKind::Level, e),
+    }
+}
+
+/// Check the textures and model scenes of the texture manifest.
+pub fn check_textures(report: &mut PreflightReport, assets_dir: &Path) {
+    let manifest_path = assets_dir.join(TEXTURE_MANIFEST_PATH);
+    let manifest = match read_ron::<RawTextureManifest>(&manifest_path) {
+        Ok(Some(manifest)) => manifest,
+        Ok(None) => {
+            report.problem(
+                AssetKind::Texture,
+                format!("texture manifest {} is missing", manifest_path.display()),
+            );
+            return;
+        }
+        Err(e) => {
+            report.problem(AssetKind::Texture, e);
+            return;
+        }
+    };
+    for profile in &manifest.profiles {
+        let maps = [
+            ("albedo", Some(&profile.albedo_path)),
+            ("normal", profile.normal_path.as_ref()),
+            ("orm", profile.orm_path.as_ref()),
+            ("emissive", profile.emissive_path.as_ref()),
+            ("depth", profile.depth_path.as_ref()),
+        ];
+        for (map, path) in maps {
+            if let Some(path) = path {
+                report.check_file(
+                    AssetKind::Texture,
+                    &assets_dir.join(manifest_asset_path(path)),
+                    &format!("{map} map of profile `{}`", profile.id),
+                );
+            }
+        }
+    }
+    let models = [
+        ("paddle", manifest.models.paddle.as_ref()),
+        ("ball", manifest.models.ball.as_ref()),
+    ];
+    for (object, model) in models {
+        if let Some(model) = model {
+            // Drop the `#Scene<n>` label
+            let file = model.scene_path.split('#').next().unwrap_or_default();
+            report.check_file(
+                AssetKind::Texture,
+                &assets_dir.join(file),
+                &format!("{object} model"),
+            );
+        }
+    }
+}
+
+/// Check the files of the audio manifest, and that every [`SoundType`] has a sound.
+pub fn check_sounds(report: &mut PreflightReport, assets_dir: &Path) {
+    let manifest_path = assets_dir.join(asset_path(AUDIO_MANIFEST_PATH));
+    let manifest = match read_ron::<AudioManifest>(&manifest_path) {
+        Ok(Some(manifest)) => manifest,
+        Ok(None) => {
+            report.problem(
+                AssetKind::Sound,
+                format!("audio manifest {} is missing", manifest_path.display()),
+            );
+            return;
+        }
+        Err(e) => {
+            report.problem(AssetKind::Sound, e);
+            return;
+        }
+    };
+    let audio_dir = manifest_path.parent().unwrap_or(assets_dir);
+    for sound_type in SoundType::ALL {
+        if let Some(file) = manifest.sounds.get(&sound_type) {
+            report.check_file(
+                AssetKind::Sound,
+                &audio_dir.join(file),
+                &format!("{sound_type:?}"),
+            );
+        } else if let Some((_, path)) = PLACEHOLDER_SOUNDS.iter().find(|(ty, _)| *ty == sound_type)
+        {
+            report.check_file(
+                AssetKind::Sound,
+                &assets_dir.join(path),
+                &format!("{sound_type:?} (placeholder)"),
+            );
+        } else {
+            report.problem(
+                AssetKind::Sound,
+                format!("{sound_type:?} has no sound in the audio manifest; it plays silently"),
+            );
+        }
+    }
+    check_brick_sounds(report, &manifest, audio_dir, "");
+}
+
+/// Check the files of the per-brick destruction sounds of `manifest`, found in `dir`; `of_pack`
+/// names the sound pack in the problems.
+fn check_brick_sounds(
+    report: &mut PreflightReport,
+    manifest: &AudioManifest,
+    dir: &Path,
+    of_pack: &str,
+) {
+    let mut sounds: Vec<_> = manifest.brick_sounds.iter().collect();
+    sounds.sort();
+    for (brick_type, file) in sounds {
+        report.check_file(
+            AssetKind::Sound,
+            &dir.join(file),
+            &format!("destruction sound of brick {brick_type}{of_pack}"),
+        );
+    }
+}
+
+/// Check the files of the sound packs other than the default one.
+pub fn check_sound_packs(report: &mut PreflightReport, assets_dir: &Path) {
+    let packs = match read_ron::<SoundPacks>(&assets_dir.join(asset_path(SOUND_PACKS_PATH))) {
+        Ok(packs) => packs.unwrap_or_default(),
+        Err(e) => {
+            report.problem(AssetKind::Sound, e);
+            return;
+        }
+    };
+    for pack in packs.packs.iter().filter(|p| p.id != DEFAULT_SOUND_PACK) {
+        let folder = assets_dir.join(pack.folder.trim_end_matches('/'));
+        let manifest = match read_ron::<AudioManifest>(&folder.join("manifest.ron")) {
+            Ok(Some(manifest)) => manifest,
+            Ok(None) => {
+                report.problem(
+                    AssetKind::Sound,
+                    format!(
+                        "sound pack `{}`: {} is missing",
+                        pack.id,
+                        folder.join("manifest.ron").display()
+                    ),
+                );
+                continue;
+            }
+            Err(e) => {
+                report.problem(AssetKind::Sound, e);
+                continue;
+            }
+        };
+        let mut sounds: Vec<_> = manifest.sounds.iter().collect();
+        sounds.sort_by_key(|(sound_type, _)| format!("{sound_type:?}"));
+        for (sound_type, file) in sounds {
+            report.check_file(
+                AssetKind::Sound,
+                &folder.join(file),
+                &format!("{sound_type:?} of sound pack `{}`", pack.id),
+            );
+        }
+        let of_pack = format!(" of sound pack `{}`", pack.id);
+        check_brick_sounds(report, &manifest, &folder, &of_pack);
+    }
+}
+
+/// Run every check against the `assets_dir` and the level files in `levels_dir`.
+pub fn preflight(assets_dir: &Path, levels_dir: &Path) -> PreflightReport {
+    let mut report = PreflightReport::default();
+    check_levels(&mut report, assets_dir, levels_dir);
+    check_textures(&mut report, assets_dir);
+    check_sounds(&mut report, assets_dir);
+    check_sound_packs(&mut report, assets_dir);
+    report
+}
+
+/// Run the preflight check and log its report.
+pub fn run_preflight(mut commands: Commands, launch: Option<Res<LaunchOptions>>) {
+    let report = preflight(Path::new("assets"), levels_dir(launch.as_deref()));
+    if report.is_clean() {
+        info!(
+            "Asset preflight: {} files checked, nothing missing",
+            report.checked
+        );
+    } else {
+        warn!("{}", report.summary());
+    }
+    commands.insert_resource(report);
+}
+
+/// Checks the referenced assets at startup on native builds.
+pub struct PreflightPlugin;
+
+impl Plugin for PreflightPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PreflightReport>();
+        // Browser builds fetch their assets and cannot look at the files up front
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Startup, run_preflight);
+    }
+}
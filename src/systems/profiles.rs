@@ -0,0 +1,378 @@
+//! Player profiles: several named players sharing one machine.
+//!
+//! Each profile keeps its own files under `config/profiles/<name>/` (in localStorage on wasm, see
+//! `systems::web_storage`):
+//! - `profile.ron`: [`ProfileData`], i.e. progress, high scores (campaign and survival) and
+//!   statistics
+//! - `audio.ron`, `graphics.ron`: the profile's settings (`systems::audio`,
+//!   `systems::post_processing`)
+//! - `playlist.ron`: the profile's playlist (`systems::playlist`)
+//! - `progress.ron`: the autosaved campaign progress (`systems::autosave`)
+//! - `hints.ron`: the hints the player dismissed (`systems::hints`)
+//!
+//! [`CurrentProfile`] names the active profile. Persistence modules resolve their files with
+//! [`profile_path`], which falls back to the shared default path while no profile is selected, so
+//! playing as a guest behaves as before. They reload when the player switches profile
+//! ([`profile_switched`]).
+//!
+//! At startup the profile selection screen (`ui::profile_select`) lists the existing profiles;
+//! choosing or creating one sends [`ProfileSelected`]. Progress and statistics are recorded while
+//! playing and saved after each completed level, on game over, at the end of the game and on exit.
+
+#[cfg(target_arch = "wasm32")]
+use std::path::Path;
+use std::path::PathBuf;
+
+use bevy::ecs::message::{Message, MessageReader};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::signals::BrickDestroyed;
+use crate::systems::audio::LevelCompleted;
+#[cfg(target_arch = "wasm32")]
+use crate::systems::cloud_sync::PROFILE_INDEX_KEY;
+use crate::systems::respawn::GameOverRequested;
+use crate::systems::scoring::ScoreState;
+use crate::systems::survival::SurvivalMode;
+use crate::systems::web_storage::{read_save_file, write_save_file};
+use crate::GameProgress;
+
+/// Directory holding one sub-directory per profile.
+pub const PROFILES_DIR: &str = "config/profiles";
+/// File name of a profile's [`ProfileData`].
+pub const PROFILE_DATA_FILE: &str = "profile.ron";
+/// Number of high scores kept per profile.
+pub const MAX_HIGH_SCORES: usize = 5;
+
+/// The active profile; `None` plays as a guest with the shared default files.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CurrentProfile {
+    pub name: Option<String>,
+}
+
+impl CurrentProfile {
+    /// Directory of the active profile, if any.
+    pub fn dir(&self) -> Option<PathBuf> {
+        self.name.as_ref().map(|name| profile_dir(name))
+    }
+}
+
+/// Per-profile counters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfileStats {
+    #[serde(default)]
+    pub levels_completed: u32,
+    #[serde(default)]
+    pub bricks_destroyed: u64,
+    #[serde(default)]
+    pub games_over: u32,
+    #[serde(default)]
+    pub play_time_secs: f64,
+}
+
+/// Progress, high scores and statistics of a profile.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfileData {
+    /// Highest level number completed
+    #[serde(default)]
+    pub highest_level: u32,
+    /// Best final scores, highest first
+    #[serde(default)]
+    pub high_scores: Vec<u32>,
+    /// Best final scores of survival runs (`systems::survival`), highest first
+    #[serde(default)]
+    pub survival_scores: Vec<u32>,
+    #[serde(default)]
+    pub stats: ProfileStats,
+}
+
+impl ProfileData {
+    /// Record a final score, keeping the [`MAX_HIGH_SCORES`] best. Returns whether it made the list.
+    pub fn record_score(&mut self, score: u32) -> bool {
+        insert_high_score(&mut self.high_scores, score)
+    }
+
+    /// Record the final score of a survival run on its own list, like [`Self::record_score`].
+    pub fn record_survival_score(&mut self, score: u32) -> bool {
+        insert_high_score(&mut self.survival_scores, score)
+    }
+}
+
+fn insert_high_score(scores: &mut Vec<u32>, score: u32) -> bool {
+    if score == 0 {
+        return false;
+    }
+    let index = scores.partition_point(|best| *best >= score);
+    if index >= MAX_HIGH_SCORES {
+        return false;
+    }
+    scores.insert(index, score);
+    scores.truncate(MAX_HIGH_SCORES);
+    true
+}
+
+/// Request to switch to the named profile, creating it if needed.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ProfileSelected {
+    pub name: String,
+}
+
+/// Whether profile data changed since it was last saved.
+#[derive(Resource, Debug, Default)]
+pub struct ProfileDirty(pub bool);
+
+/// Directory of profile `name`.
+pub fn profile_dir(name: &str) -> PathBuf {
+    PathBuf::from(PROFILES_DIR).join(name)
+}
+
+/// Path of `file_name` for the active profile, or `default` while playing as a guest.
+pub fn profile_path(profile: Option<&CurrentProfile>, file_name: &str, default: &str) -> PathBuf {
+    profile
+        .and_then(CurrentProfile::dir)
+        .map_or_else(|| PathBuf::from(default), |dir| dir.join(file_name))
+}
+
+/// Run condition: the player switched profile after startup.
+pub fn profile_switched(profile: Option<Res<CurrentProfile>>) -> bool {
+    profile.is_some_and(|p| p.is_changed() && !p.is_added())
+}
+
+/// Profile names may only use letters, digits, spaces, `-` and `_`, so they are safe directory
+/// names.
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.trim().is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+}
+
+/// First free "Player N" name.
+pub fn new_profile_name(existing: &[String]) -> String {
+    (1..)
+        .map(|n| format!("Player {n}"))
+        .find(|name| !existing.contains(name))
+        .unwrap_or_default()
+}
+
+/// Names of the existing profiles, sorted.
+pub fn list_profiles() -> Vec<String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let Ok(entries) = std::fs::read_dir(PROFILES_DIR) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+            .filter(|name| is_valid_profile_name(name))
+            .collect();
+        names.sort();
+        names
+    }
+    // Browsers have no directories to scan; saving a profile adds it to an index
+    #[cfg(target_arch = "wasm32")]
+    {
+        read_save_file(Path::new(PROFILE_INDEX_KEY), |content| {
+            ron::de::from_str::<Vec<String>>(content).ok()
+        })
+        .unwrap_or_default()
+    }
+}
+
+/// Read the data of profile `name`, recovering an interrupted save; a new or unreadable profile
+/// starts empty.
+pub fn load_profile_data(name: &str) -> ProfileData {
+    read_save_file(&profile_dir(name).join(PROFILE_DATA_FILE), |content| {
+        ron::de::from_str(content)
+            .map_err(|e| warn!("Failed to parse profile '{}': {}", name, e))
+            .ok()
+    })
+    .unwrap_or_default()
+}
+
+/// Write the data of profile `name` atomically, creating its directory; in browsers the profile
+/// is also added to the profile index.
+pub fn save_profile_data(name: &str, data: &ProfileData) -> Result<(), String> {
+    let content = ron::ser::to_string_pretty(data, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("failed to serialize profile: {e}"))?;
+    write_save_file(&profile_dir(name).join(PROFILE_DATA_FILE), &content)?;
+    #[cfg(target_arch = "wasm32")]
+    {
+        let mut names = list_profiles();
+        if !names.iter().any(|existing| existing == name) {
+            names.push(name.to_string());
+            names.sort();
+            let index = ron::ser::to_string(&names)
+                .map_err(|e| format!("failed to serialize the profile index: {e}"))?;
+            write_save_file(Path::new(PROFILE_INDEX_KEY), &index)?;
+        }
+    }
+    Ok(())
+}
+
+/// Switch to selected profiles, saving the one being left.
+pub fn apply_profile_selection(
+    mut selections: MessageReader<ProfileSelected>,
+    mut profile: ResMut<CurrentProfile>,
+    mut data: ResMut<ProfileData>,
+    mut dirty: ResMut<ProfileDirty>,
+) {
+    let Some(selected) = selections.read().last() else {
+        return;
+    };
+    if !is_valid_profile_name(&selected.name) {
+        warn!("Ignoring invalid profile name '{}'", selected.name);
+        return;
+    }
+    if profile.name.as_deref() == Some(selected.name.as_str()) {
+        return;
+    }
+    save_if_dirty(&profile, &data, &mut dirty);
+    *data = load_profile_data(&selected.name);
+    profile.name = Some(selected.name.clone());
+    // Create the profile directory right away so it is listed next time
+    dirty.0 = true;
+    info!("Playing as profile '{}'", selected.name);
+}
+
+/// Record progress and statistics from gameplay.
+pub fn track_profile_progress(
+    time: Res<Time>,
+    mut destroyed: MessageReader<BrickDestroyed>,
+    mut game_overs: MessageReader<GameOverRequested>,
+    mut data: ResMut<ProfileData>,
+    mut dirty: ResMut<ProfileDirty>,
+    score: Option<Res<ScoreState>>,
+    progress: Option<Res<GameProgress>>,
+    survival: Option<Res<SurvivalMode>>,
+    mut was_finished: Local<bool>,
+) {
+    let data = data.bypass_change_detection();
+    data.stats.play_time_secs += time.delta_secs_f64();
+    data.stats.bricks_destroyed += destroyed.read().count() as u64;
+
+    let final_score = score.as_deref().map_or(0, |s| s.current_score);
+    if game_overs.read().any(|msg| msg.remaining_lives == 0) {
+        data.stats.games_over += 1;
+        // Survival runs are ranked separately from the campaign
+        if survival.is_some_and(|mode| mode.active) {
+            data.record_survival_score(final_score);
+        } else {
+            data.record_score(final_score);
+        }
+        dirty.0 = true;
+    }
+    let finished = progress.is_some_and(|p| p.finished);
+    if finished && !*was_finished {
+        data.record_score(final_score);
+        dirty.0 = true;
+    }
+    *was_finished = finished;
+}
+
+/// Record a completed level.
+fn on_level_completed(
+    trigger: On<LevelCompleted>,
+    mut data: ResMut<ProfileData>,
+    mut dirty: ResMut<ProfileDirty>,
+) {
+    data.highest_level = data.highest_level.max(trigger.event().level_index);
+    data.stats.levels_completed += 1;
+    dirty.0 = true;
+}
+
+fn save_if_dirty(profile: &CurrentProfile, data: &ProfileData, dirty: &mut ProfileDirty) {
+    if !dirty.0 {
+        return;
+    }
+    dirty.0 = false;
+    if let Some(name) = profile.name.as_deref() {
+        if let Err(e) = save_profile_data(name, data) {
+            warn!("Failed to save profile '{}': {}", name, e);
+        }
+    }
+}
+
+/// Save the active profile after milestones, and on exit.
+pub fn save_profile(
+    profile: Res<CurrentProfile>,
+    data: Res<ProfileData>,
+    mut dirty: ResMut<ProfileDirty>,
+    mut exits: MessageReader<AppExit>,
+) {
+    if exits.read().count() > 0 {
+        dirty.0 = true;
+    }
+    save_if_dirty(&profile, &data, &mut dirty);
+}
+
+/// Plugin registering player profiles.
+pub struct ProfilesPlugin;
+
+impl Plugin for ProfilesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CurrentProfile>();
+        app.init_resource::<ProfileData>();
+        app.init_resource::<ProfileDirty>();
+        app.add_message::<ProfileSelected>();
+        app.add_message::<BrickDestroyed>();
+        app.add_message::<GameOverRequested>();
+        app.add_systems(
+            Update,
+            (apply_profile_selection, track_profile_progress).chain(),
+        );
+        // Last, so exit requests written anywhere this frame are seen before the app quits
+        app.add_systems(Last, save_profile);
+        app.add_observer(on_level_completed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn high_scores_keep_the_best_in_order() {
+        let mut data = ProfileData::default();
+        for score in [300, 100, 500, 200, 400] {
+            assert!(data.record_score(score));
+        }
+        assert!(!data.record_score(50), "below the five best");
+        assert!(!data.record_score(0));
+        assert!(data.record_score(350));
+        assert_eq!(data.high_scores, vec![500, 400, 350, 300, 200]);
+    }
+
+    #[test]
+    fn survival_scores_have_their_own_list() {
+        let mut data = ProfileData::default();
+        assert!(data.record_survival_score(900));
+        assert!(data.high_scores.is_empty());
+        assert!(data.record_score(100));
+        assert_eq!(data.survival_scores, vec![900]);
+    }
+
+    #[test]
+    fn paths_fall_back_to_the_shared_default_for_guests() {
+        let guest = CurrentProfile::default();
+        assert_eq!(
+            profile_path(Some(&guest), "audio.ron", "config/audio.ron"),
+            PathBuf::from("config/audio.ron")
+        );
+        let named = CurrentProfile {
+            name: Some("Player 2".into()),
+        };
+        assert_eq!(
+            profile_path(Some(&named), "audio.ron", "config/audio.ron"),
+            PathBuf::from("config/profiles/Player 2/audio.ron")
+        );
+        assert_eq!(
+            new_profile_name(&["Player 1".into(), "Player 3".into()]),
+            "Player 2"
+        );
+        assert!(!is_valid_profile_name("../escape"));
+        assert!(!is_valid_profile_name("  "));
+    }
+}
@@ -0,0 +1,188 @@
+//! Frame-time budget guard.
+//!
+//! Watches the real frame time and, when it stays over [`FRAME_BUDGET_SECS`] for
+//! [`OVER_BUDGET_SECS`] in a row, lowers the visual quality by one [`QualityLevel`]. It mostly
+//! rescues the browser build, where the WebGL2 backend can fall far behind on weak machines.
+//!
+//! | Level | Changes |
+//! |-------|---------|
+//! | `Full` | none, everything as configured |
+//! | `Reduced` | post-processing off (`PostProcessingPreset::Off`), smaller point light shadow maps, no brick debris |
+//! | `Minimal` | point light shadows off as well |
+//!
+//! The shadow limits are applied together with the lighting settings (`systems::lighting`), and
+//! brick debris (`systems::brick_debris`) checks the level before it spawns.
+//!
+//! The frame time is smoothed first, so a single slow frame (a level load, say) does not count.
+//! Quality is only ever lowered, never raised again, so the game does not flip back and forth
+//! around the budget.
+
+use bevy::prelude::*;
+
+use crate::systems::post_processing::{PostProcessingConfig, PostProcessingPreset};
+
+/// Longest acceptable frame, in seconds (50 fps).
+pub const FRAME_BUDGET_SECS: f32 = 1.0 / 50.0;
+/// How long the smoothed frame time must stay over budget before quality drops, in seconds.
+pub const OVER_BUDGET_SECS: f32 = 3.0;
+/// Weight of the newest frame in the smoothed frame time.
+const SMOOTHING: f32 = 0.1;
+/// Point light shadow map size from `QualityLevel::Reduced` on, in pixels.
+pub const REDUCED_SHADOW_MAP_SIZE: usize = 512;
+
+/// Visual quality steps, from best to cheapest.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QualityLevel {
+    #[default]
+    Full,
+    Reduced,
+    Minimal,
+}
+
+impl QualityLevel {
+    /// The next cheaper level, if any.
+    pub fn lower(self) -> Option<Self> {
+        match self {
+            Self::Full => Some(Self::Reduced),
+            Self::Reduced => Some(Self::Minimal),
+            Self::Minimal => None,
+        }
+    }
+}
+
+/// Current quality level and the frame time bookkeeping behind it.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct QualityGuard {
+    pub level: QualityLevel,
+    /// Smoothed frame time, in seconds
+    pub smoothed_frame_secs: f32,
+    /// How long the smoothed frame time has been over budget, in seconds
+    pub over_budget_secs: f32,
+}
+
+impl Default for QualityGuard {
+    fn default() -> Self {
+        Self {
+            level: QualityLevel::Full,
+            smoothed_frame_secs: 0.0,
+            over_budget_secs: 0.0,
+        }
+    }
+}
+
+impl QualityGuard {
+    /// Account for a frame that took `frame_secs`; returns whether the quality was lowered.
+    pub fn record(&mut self, frame_secs: f32) -> bool {
+        if !frame_secs.is_finite() || frame_secs <= 0.0 {
+            return false;
+        }
+        self.smoothed_frame_secs = if self.smoothed_frame_secs == 0.0 {
+            frame_secs
+        } else {
+            self.smoothed_frame_secs + (frame_secs - self.smoothed_frame_secs) * SMOOTHING
+        };
+        if self.smoothed_frame_secs <= FRAME_BUDGET_SECS {
+            self.over_budget_secs = 0.0;
+            return false;
+        }
+        self.over_budget_secs += frame_secs;
+        if self.over_budget_secs < OVER_BUDGET_SECS {
+            return false;
+        }
+        self.over_budget_secs = 0.0;
+        match self.level.lower() {
+            Some(level) => {
+                self.level = level;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Track the real frame time and lower the quality when the budget is blown.
+pub fn monitor_frame_time(time: Res<Time<Real>>, mut guard: ResMut<QualityGuard>) {
+    // Only a quality drop counts as a change, so `apply_quality_level` runs just then
+    if guard.bypass_change_detection().record(time.delta_secs()) {
+        warn!(
+            "Frame time {:.1} ms over budget; lowering quality to {:?}",
+            guard.smoothed_frame_secs * 1000.0,
+            guard.level
+        );
+        guard.set_changed();
+    }
+}
+
+/// Apply the current quality level to the post-processing.
+///
+/// The lights read the quality level themselves (`systems::lighting::apply_lighting`), so lights
+/// spawned later respect it too.
+pub fn apply_quality_level(
+    guard: Res<QualityGuard>,
+    post_processing: Option<ResMut<PostProcessingConfig>>,
+) {
+    if guard.level < QualityLevel::Reduced {
+        return;
+    }
+    if let Some(mut config) = post_processing {
+        let off = PostProcessingConfig {
+            preset: PostProcessingPreset::Off,
+            ..default()
+        };
+        config.set_if_neq(off);
+    }
+}
+
+/// Plugin registering the frame-time budget guard.
+pub struct QualityGuardPlugin;
+
+impl Plugin for QualityGuardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<QualityGuard>();
+        app.add_systems(
+            Update,
+            (
+                monitor_frame_time,
+                apply_quality_level.run_if(resource_changed::<QualityGuard>),
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SLOW_FRAME: f32 = 1.0 / 20.0;
+    const FAST_FRAME: f32 = 1.0 / 60.0;
+
+    fn run(guard: &mut QualityGuard, frame_secs: f32, secs: f32) -> usize {
+        let frames = (secs / frame_secs).ceil() as usize;
+        (0..frames).filter(|_| guard.record(frame_secs)).count()
+    }
+
+    #[test]
+    fn sustained_slow_frames_lower_quality_step_by_step() {
+        let mut guard = QualityGuard::default();
+        assert_eq!(run(&mut guard, SLOW_FRAME, OVER_BUDGET_SECS - 0.5), 0);
+        assert_eq!(guard.level, QualityLevel::Full);
+
+        assert_eq!(run(&mut guard, SLOW_FRAME, 1.0), 1);
+        assert_eq!(guard.level, QualityLevel::Reduced);
+
+        run(&mut guard, SLOW_FRAME, 10.0);
+        assert_eq!(guard.level, QualityLevel::Minimal);
+    }
+
+    #[test]
+    fn short_hitches_are_ignored() {
+        let mut guard = QualityGuard::default();
+        for _ in 0..20 {
+            run(&mut guard, FAST_FRAME, 2.0);
+            // A level load
+            guard.record(0.5);
+        }
+        assert_eq!(guard.level, QualityLevel::Full);
+    }
+}
@@ -0,0 +1,122 @@
+//! Remix mode: replay existing levels with some simple bricks swapped for special ones.
+//!
+//! When `remix.enabled` is set in the gameplay config (`GameplayConfig::remix`), each simple brick
+//! (index 20, or legacy 3) that spawns is replaced, with probability `remix.fraction`, by one of the
+//! configured `remix.bricks` (multi-hit or powerup bricks). Every other brick type is left alone, so
+//! indestructible walls, hazards, bumpers and the rest of a level's structure are preserved.
+//!
+//! The draw for a cell depends only on the seed, the level number and the cell, so restarting a
+//! level reproduces the same remix and every level gets its own. The seed is `remix.seed`, or the
+//! gameplay seed of [`GameRng`] (which `BK_SEED` pins) when omitted.
+//!
+//! Substitution happens on the freshly spawned bricks (before their first collision), by changing
+//! their `BrickTypeId`; with the `texture_manifest` feature the brick material follows the new type.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::gameplay_config::{GameplayConfig, RemixConfig};
+use crate::level_format::{SIMPLE_BRICK, TARGET_COLS};
+use crate::level_loader::CurrentLevel;
+use crate::systems::rng::GameRng;
+use crate::{Brick, BrickGridPos, BrickTypeId};
+
+/// Index of the legacy simple brick.
+const LEGACY_SIMPLE_BRICK: u8 = 3;
+
+/// Brick type that cell (`row`, `col`) of level `level` gets in a remix seeded with `seed`, or
+/// `None` if the cell keeps its brick.
+pub fn remix_cell(
+    config: &RemixConfig,
+    seed: u64,
+    level: u32,
+    row: usize,
+    col: usize,
+) -> Option<u8> {
+    if config.bricks.is_empty() {
+        return None;
+    }
+    let cell = (row * TARGET_COLS + col) as u64;
+    let mut rng = StdRng::seed_from_u64(seed ^ ((level as u64) << 32) ^ cell);
+    (rng.random::<f32>() < config.fraction)
+        .then(|| config.bricks[rng.random_range(0..config.bricks.len())])
+}
+
+/// Swap newly spawned simple bricks according to the remix config.
+pub fn remix_spawned_bricks(
+    config: Option<Res<GameplayConfig>>,
+    game_rng: Option<Res<GameRng>>,
+    current_level: Option<Res<CurrentLevel>>,
+    mut bricks: Query<(&BrickGridPos, &mut BrickTypeId), (With<Brick>, Added<BrickTypeId>)>,
+) {
+    let (Some(config), Some(level)) = (config, current_level) else {
+        return;
+    };
+    let remix = &config.remix;
+    if !remix.enabled || bricks.is_empty() {
+        return;
+    }
+    let seed = remix
+        .seed
+        .or_else(|| game_rng.map(|rng| rng.seed()))
+        .unwrap_or_default();
+    let mut swapped = 0;
+    for (pos, mut type_id) in bricks.iter_mut() {
+        if !matches!(type_id.0, SIMPLE_BRICK | LEGACY_SIMPLE_BRICK) {
+            continue;
+        }
+        if let Some(new_type) = remix_cell(remix, seed, level.0.number, pos.row, pos.col) {
+            type_id.0 = new_type;
+            swapped += 1;
+        }
+    }
+    if swapped > 0 {
+        debug!(
+            "Remix swapped {} bricks on level {}",
+            swapped, level.0.number
+        );
+    }
+}
+
+/// Plugin registering remix mode.
+pub struct RemixPlugin;
+
+impl Plugin for RemixPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameplayConfig>();
+        app.add_systems(Update, remix_spawned_bricks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remix_is_reproducible_and_respects_the_fraction() {
+        let config = RemixConfig {
+            enabled: true,
+            fraction: 0.3,
+            ..RemixConfig::default()
+        };
+        let draw = |seed, level| -> Vec<Option<u8>> {
+            (0..400)
+                .map(|i| remix_cell(&config, seed, level, i / 20, i % 20))
+                .collect()
+        };
+        assert_eq!(draw(7, 1), draw(7, 1));
+        assert_ne!(draw(7, 1), draw(7, 2), "levels are remixed differently");
+
+        let cells = draw(7, 1);
+        let swapped = cells.iter().flatten().count();
+        assert!((80..160).contains(&swapped), "about 30% of 400: {swapped}");
+        assert!(cells.iter().flatten().all(|id| config.bricks.contains(id)));
+
+        let none = RemixConfig {
+            fraction: 0.0,
+            ..config.clone()
+        };
+        assert!((0..20).all(|c| remix_cell(&none, 7, 1, 0, c).is_none()));
+    }
+}
@@ -243,6 +243,7 @@ pub enum LifeLossCause {
     LowerGoal,
     MerkabaCollision,
     PaddleHazard,
+    GravityWell,
 }
 
 #[allow(dead_code)]
@@ -268,6 +269,28 @@ pub struct GameOverRequested {
     pub remaining_lives: u8,
 }
 
+/// Seconds a respawned ball is protected from the lower goal once it is released.
+pub const RESPAWN_GRACE_SECS: f32 = 1.5;
+
+/// Grace period of a freshly respawned ball.
+///
+/// Until the timer runs out (it only counts while the ball is not `BallFrozen`), a lower goal
+/// contact puts the ball back on its spawn point, frozen like after the respawn, instead of
+/// costing another life. The timer is not reset, so a ball that keeps drifting into the goal
+/// still costs a life eventually.
+#[derive(Component, Debug, Clone)]
+pub struct RespawnGrace {
+    pub timer: Timer,
+}
+
+impl Default for RespawnGrace {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(RESPAWN_GRACE_SECS, TimerMode::Once),
+        }
+    }
+}
+
 /// Marker component used to disable paddle input while respawn settles.
 #[derive(Component, Debug, Default, Clone, Copy)]
 pub struct InputLocked;
@@ -295,6 +318,7 @@ impl Plugin for RespawnPlugin {
             .add_message::<RespawnScheduled>()
             .add_message::<RespawnCompleted>()
             .add_message::<GameOverRequested>()
+            .add_message::<crate::systems::ball_save::BallSaved>()
             .configure_sets(
                 Update,
                 (
@@ -308,7 +332,12 @@ impl Plugin for RespawnPlugin {
             );
 
         // Detect phase systems
-        app.add_systems(Update, detect_ball_loss.in_set(RespawnSystems::Detect));
+        app.add_systems(
+            Update,
+            (tick_respawn_grace, detect_ball_loss)
+                .chain()
+                .in_set(RespawnSystems::Detect),
+        );
         app.add_systems(
             Update,
             life_loss_logging
@@ -383,7 +412,15 @@ fn detect_ball_loss(
     spawn_points: Res<SpawnPoints>,
     mut commands: Commands,
     mut life_lost_events: MessageWriter<LifeLostEvent>,
+    shields: Query<Entity, With<crate::systems::ball_save::BallSaveShield>>,
+    mut velocities: Query<&mut Velocity, With<Ball>>,
+    mut ball_saves: MessageWriter<crate::systems::ball_save::BallSaved>,
+    mut graced: Query<&mut Transform, (With<Ball>, With<RespawnGrace>)>,
 ) {
+    let mut shields = shields.iter();
+    // With several balls in play only the last one lost costs a life
+    let mut balls_left = balls.iter().count();
+    let mut lost = Vec::new();
     for event in collision_events.read() {
         if let CollisionEvent::Started(e1, e2, _) = event {
             let e1_is_ball = balls.get(*e1).is_ok();
@@ -393,6 +430,39 @@ fn detect_ball_loss(
 
             if (e1_is_ball && e2_is_lower) || (e2_is_ball && e1_is_lower) {
                 let ball_entity = if e1_is_ball { *e1 } else { *e2 };
+                if lost.contains(&ball_entity) {
+                    continue;
+                }
+                // A freshly respawned ball goes back to its spawn point, frozen
+                if let Ok(mut transform) = graced.get_mut(ball_entity) {
+                    let spawn = ball_handles
+                        .get(ball_entity)
+                        .map_or_else(|_| spawn_points.ball_spawn(), |handle| handle.spawn);
+                    *transform = spawn.to_transform();
+                    if let Ok(mut velocity) = velocities.get_mut(ball_entity) {
+                        *velocity = Velocity::zero();
+                    }
+                    commands.entity(ball_entity).insert(BallFrozen);
+                    info!(target: "respawn", ?ball_entity, "Ball reached the goal during respawn grace; re-frozen");
+                    continue;
+                }
+                if balls_left > 1 {
+                    balls_left -= 1;
+                    lost.push(ball_entity);
+                    commands.entity(ball_entity).despawn();
+                    info!(target: "respawn", ?ball_entity, balls_left, "Ball lost; others still in play");
+                    continue;
+                }
+                // A ball-save shield sends the ball back up instead
+                if let Some(shield) = shields.next() {
+                    if let Ok(mut velocity) = velocities.get_mut(ball_entity) {
+                        velocity.linvel.x = -velocity.linvel.x.abs();
+                    }
+                    commands.entity(shield).despawn();
+                    ball_saves.write(crate::systems::ball_save::BallSaved { ball: ball_entity });
+                    info!(target: "respawn", ?ball_entity, "Ball saved by shield");
+                    continue;
+                }
                 let ball_spawn = match ball_handles.get(ball_entity) {
                     Ok(handle) => handle.spawn,
                     Err(_) => {
@@ -410,12 +480,28 @@ fn detect_ball_loss(
                     cause: LifeLossCause::LowerGoal,
                     ball_spawn,
                 });
+                balls_left = balls_left.saturating_sub(1);
+                lost.push(ball_entity);
                 commands.entity(ball_entity).despawn();
             }
         }
     }
 }
 
+/// Run down the grace period of released respawned balls.
+fn tick_respawn_grace(
+    time: Res<Time>,
+    mut balls: Query<(Entity, &mut RespawnGrace), (With<Ball>, Without<BallFrozen>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut grace) in balls.iter_mut() {
+        grace.timer.tick(time.delta());
+        if grace.timer.is_finished() {
+            commands.entity(entity).remove::<RespawnGrace>();
+        }
+    }
+}
+
 fn life_loss_logging(mut life_lost_events: MessageReader<LifeLostEvent>) {
     for event in life_lost_events.read() {
         let spawn = event.ball_spawn.translation;
@@ -894,6 +980,7 @@ fn respawn_executor(
             Ccd::enabled(),
             ExternalImpulse::default(),
             GravityScale(1.0),
+            RespawnGrace::default(),
         ))
         .id();
 
@@ -1093,6 +1180,110 @@ mod tests {
         assert!(world.entity(paddle).contains::<InputLocked>());
     }
 
+    fn spawn_balls(app: &mut App, count: usize) -> Vec<Entity> {
+        (0..count)
+            .map(|i| {
+                app.world_mut()
+                    .spawn((Ball, ball_handle_at(Vec3::new(i as f32, 2.0, 0.0))))
+                    .id()
+            })
+            .collect()
+    }
+
+    fn send_to_goal(app: &mut App, balls: &[Entity], lower_goal: Entity) {
+        let mut messages = app.world_mut().resource_mut::<Messages<CollisionEvent>>();
+        for ball in balls {
+            messages.write(CollisionEvent::Started(
+                *ball,
+                lower_goal,
+                CollisionEventFlags::SENSOR,
+            ));
+        }
+    }
+
+    fn lives_lost(app: &mut App) -> usize {
+        app.world_mut()
+            .resource_mut::<Messages<LifeLostEvent>>()
+            .drain()
+            .count()
+    }
+
+    #[test]
+    fn only_the_last_of_two_balls_costs_a_life() {
+        let mut app = test_app();
+        let balls = spawn_balls(&mut app, 2);
+        let lower_goal = app.world_mut().spawn(LowerGoal).id();
+
+        send_to_goal(&mut app, &balls[..1], lower_goal);
+        app.update();
+        assert_eq!(lives_lost(&mut app), 0);
+        assert!(app.world().resource::<RespawnSchedule>().pending.is_none());
+        assert!(!app.world().entities().contains(balls[0]));
+        assert!(app.world().entities().contains(balls[1]));
+
+        send_to_goal(&mut app, &balls[1..], lower_goal);
+        app.update();
+        assert_eq!(lives_lost(&mut app), 1);
+        assert!(app.world().resource::<RespawnSchedule>().pending.is_some());
+    }
+
+    #[test]
+    fn three_balls_cost_one_life_even_when_lost_together() {
+        let mut app = test_app();
+        let balls = spawn_balls(&mut app, 3);
+        let lower_goal = app.world_mut().spawn(LowerGoal).id();
+
+        send_to_goal(&mut app, &balls[..1], lower_goal);
+        app.update();
+        assert_eq!(lives_lost(&mut app), 0);
+
+        // The other two in the same frame, one of them reported twice
+        send_to_goal(&mut app, &[balls[1], balls[2], balls[2]], lower_goal);
+        app.update();
+        assert_eq!(lives_lost(&mut app), 1);
+        let world = app.world();
+        assert!(balls.iter().all(|ball| !world.entities().contains(*ball)));
+    }
+
+    #[test]
+    fn goal_contact_during_grace_returns_the_ball_to_its_spawn() {
+        let mut app = test_app();
+        let spawn = Vec3::new(1.0, 2.0, 3.0);
+        let ball = app
+            .world_mut()
+            .spawn((
+                Ball,
+                ball_handle_at(spawn),
+                RespawnGrace::default(),
+                Transform::from_xyz(-9.0, 2.0, 3.0),
+                Velocity::linear(Vec3::new(-4.0, 0.0, 1.0)),
+            ))
+            .id();
+        let lower_goal = app.world_mut().spawn(LowerGoal).id();
+        let hit_goal = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Messages<CollisionEvent>>()
+                .write(CollisionEvent::Started(
+                    ball,
+                    lower_goal,
+                    CollisionEventFlags::SENSOR,
+                ));
+            app.update();
+        };
+
+        hit_goal(&mut app);
+        let world = app.world();
+        assert!(world.resource::<RespawnSchedule>().pending.is_none());
+        assert_eq!(world.get::<Transform>(ball).unwrap().translation, spawn);
+        assert_eq!(world.get::<Velocity>(ball).unwrap().linvel, Vec3::ZERO);
+
+        // Once the grace period is over the goal costs a life again
+        app.world_mut().entity_mut(ball).remove::<RespawnGrace>();
+        hit_goal(&mut app);
+        assert!(app.world().resource::<RespawnSchedule>().pending.is_some());
+        assert!(!app.world().entities().contains(ball));
+    }
+
     #[test]
     fn executor_respawns_paddle_and_ball() {
         let mut app = test_app();
@@ -1269,6 +1460,57 @@ mod tests {
         assert!(!events.is_empty());
     }
 
+    #[test]
+    fn shield_saves_one_ball() {
+        let mut app = test_app();
+        let shield = app
+            .world_mut()
+            .spawn(crate::systems::ball_save::BallSaveShield)
+            .id();
+        let ball = app
+            .world_mut()
+            .spawn((
+                Ball,
+                Velocity::linear(Vec3::new(5.0, 0.0, 1.0)),
+                ball_handle_at(Vec3::new(0.0, 2.0, 0.0)),
+            ))
+            .id();
+        let lower_goal = app.world_mut().spawn(LowerGoal).id();
+
+        app.world_mut()
+            .resource_mut::<Messages<CollisionEvent>>()
+            .write(CollisionEvent::Started(
+                ball,
+                lower_goal,
+                CollisionEventFlags::SENSOR,
+            ));
+        advance_time(&mut app, 0.016);
+        app.update();
+
+        let world = app.world();
+        assert!(!world.entities().contains(shield));
+        assert_eq!(
+            world.get::<Velocity>(ball).unwrap().linvel,
+            Vec3::new(-5.0, 0.0, 1.0)
+        );
+        assert!(world.resource::<RespawnSchedule>().pending.is_none());
+        assert!(!world
+            .resource::<Messages<crate::systems::ball_save::BallSaved>>()
+            .is_empty());
+
+        // Used up: the next drop costs a life
+        app.world_mut()
+            .resource_mut::<Messages<CollisionEvent>>()
+            .write(CollisionEvent::Started(
+                ball,
+                lower_goal,
+                CollisionEventFlags::SENSOR,
+            ));
+        advance_time(&mut app, 0.016);
+        app.update();
+        assert!(!app.world().entities().contains(ball));
+    }
+
     #[test]
     fn queued_life_losses_run_after_pending_respawn() {
         let mut app = test_app();
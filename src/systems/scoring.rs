@@ -4,26 +4,92 @@
 //! - Score state tracking across game sessions
 //! - Point awards on brick destruction
 //! - Milestone detection at 5000-point intervals
+//! - Chain bonuses for destroying several bricks of the same type in quick succession
+//! - Per-team points for score races on levels with team bricks (`systems::team_bricks`)
+//! - Per-level score bookkeeping (see [`ScoreState`] for the score semantics)
 //! - Event communication with other game systems
 
+use std::collections::{BTreeMap, HashMap};
+
+use crate::level_loader::CurrentLevel;
 use crate::signals::BrickDestroyed;
+use crate::systems::team_bricks::{Team, TeamLedger, TeamScores};
 use bevy::ecs::message::{Message, MessageReader, MessageWriter};
 use bevy::prelude::*;
 use rand::{rng, Rng};
 
 const MILESTONE_STEP: u32 = 5_000;
 
+/// Maximum gap in seconds between two same-type bricks for the chain to continue.
+pub const CHAIN_WINDOW_SECS: f32 = 2.0;
+/// Chain length at which bonuses start.
+pub const CHAIN_MIN_LENGTH: u32 = 3;
+/// Bonus for the first qualifying brick; each further brick in the chain adds another step.
+pub const CHAIN_BONUS_STEP: u32 = 50;
+
 /// Global game state tracking cumulative player score and milestone progress.
 ///
-/// The score persists throughout a game session (across level transitions) and only
-/// resets when the player starts a new game.
-#[derive(Resource, Debug, Clone, Copy, Default)]
+/// Score semantics:
+/// - The score persists across level advance (and level switches); `track_level_scores` starts a
+///   new level attempt whenever the current level number changes.
+/// - Restarting a single level (R) subtracts the points earned in that attempt
+///   ([`ScoreState::restart_level`]). Milestones already reached stay reached, so re-earning the
+///   points does not award the same extra life twice.
+/// - Restarting the campaign resets everything ([`ScoreState::reset`]).
+#[derive(Resource, Debug, Clone, Default)]
 pub struct ScoreState {
     /// Total points accumulated in current game session (range: 0 to u32::MAX)
     pub current_score: u32,
 
     /// Highest milestone tier achieved (e.g., 0, 1, 2 for 0, 5000, 10000 points)
     pub last_milestone_reached: u32,
+
+    /// Level of the current attempt (`None` until the first level is tracked)
+    pub level_number: Option<u32>,
+
+    /// Score when the current level attempt began
+    pub level_start_score: u32,
+
+    /// Points earned on each level left so far, keyed by level number (latest attempt wins)
+    pub level_scores: BTreeMap<u32, u32>,
+}
+
+impl ScoreState {
+    /// Points earned since the current level attempt began.
+    pub fn level_points(&self) -> u32 {
+        self.current_score.saturating_sub(self.level_start_score)
+    }
+
+    /// Start an attempt at level `number`, recording the points earned on the level being left.
+    pub fn begin_level(&mut self, number: u32) {
+        if let Some(previous) = self.level_number {
+            self.level_scores.insert(previous, self.level_points());
+        }
+        self.level_number = Some(number);
+        self.level_start_score = self.current_score;
+    }
+
+    /// Restart the current level: drop the points earned in this attempt and return them.
+    pub fn restart_level(&mut self) -> u32 {
+        let earned = self.level_points();
+        self.current_score = self.level_start_score;
+        earned
+    }
+
+    /// Restart the campaign: clear the score and all per-level bookkeeping.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Resume a saved game at `score`: a fresh session whose milestones up to `score` count as
+    /// already reached.
+    pub fn restore(&mut self, score: u32) {
+        *self = Self {
+            current_score: score,
+            last_milestone_reached: score / MILESTONE_STEP,
+            ..Self::default()
+        };
+    }
 }
 
 // BrickDestroyed message is defined in `crate::signals`.
@@ -38,6 +104,58 @@ pub struct MilestoneReached {
     pub total_score: u32,
 }
 
+/// Domain signal that a same-type chain earned bonus points (drives the chain popup).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainBonusAwarded {
+    pub brick_type: u8,
+    /// Bricks in the chain so far (>= `CHAIN_MIN_LENGTH`)
+    pub chain_length: u32,
+    /// Bonus points awarded for this brick
+    pub points: u32,
+}
+
+/// Running chain for one brick type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrickChain {
+    pub length: u32,
+    /// `Time::elapsed_secs` of the latest brick in the chain
+    pub last_at: f32,
+}
+
+/// Per-brick-type chains, keyed by `BrickDestroyed::brick_type`.
+///
+/// Chains of different types run side by side; a chain breaks once more than
+/// `CHAIN_WINDOW_SECS` pass without another brick of its type.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ChainTracker {
+    pub chains: HashMap<u8, BrickChain>,
+}
+
+impl ChainTracker {
+    /// Record a destroyed brick at time `now`; returns the new chain length for its type.
+    pub fn record(&mut self, brick_type: u8, now: f32) -> u32 {
+        let chain = self.chains.entry(brick_type).or_insert(BrickChain {
+            length: 0,
+            last_at: now,
+        });
+        if now - chain.last_at > CHAIN_WINDOW_SECS {
+            chain.length = 0;
+        }
+        chain.length += 1;
+        chain.last_at = now;
+        chain.length
+    }
+}
+
+/// Bonus for the brick that brings a chain to `chain_length` (0 below `CHAIN_MIN_LENGTH`).
+pub fn chain_bonus_points(chain_length: u32) -> u32 {
+    if chain_length < CHAIN_MIN_LENGTH {
+        0
+    } else {
+        CHAIN_BONUS_STEP * (chain_length - CHAIN_MIN_LENGTH + 1)
+    }
+}
+
 /// Map brick type/index to point value per docs/bricks.md.
 ///
 /// # Purpose
@@ -66,6 +184,15 @@ pub fn brick_points(brick_type: u8, rng: &mut impl Rng) -> u32 {
         // Multi-hit bricks
         10..=13 => 50,
 
+        // Secret exit brick
+        17 => 100,
+
+        // Invisible brick
+        18 => 50,
+
+        // Timed bomb
+        19 => 100,
+
         // Simple stone
         20 => 25,
 
@@ -99,6 +226,7 @@ pub fn brick_points(brick_type: u8, rng: &mut impl Rng) -> u32 {
 
         // Hazard bricks
         40 => 100, // Bomb
+        50 => 100, // Blast
         42 => 90,  // Killer (Type 42: Destructible hazard, awards 90 points)
 
         // Extra ball brick (no points)
@@ -110,19 +238,25 @@ pub fn brick_points(brick_type: u8, rng: &mut impl Rng) -> u32 {
 
         // Special bricks
         49 => 150,                        // Teleport
-        50 => 300,                        // Level Up
         51 => 30,                         // Slow / Hourglass
         53 => rng.random_range(25..=300), // Question brick random score
         54 => 0,                          // Level Down (no score)
         55 => 0,                          // Magnet (enabled)
         56 => 0,                          // Magnet (disabled)
         57 => 250,                        // Bat (paddle destroyable)
+        58 => 150,                        // Glue (catch capsule)
 
         // Indestructible / unknown bricks
         _ => 0,
     }
 }
 
+/// Team credited with a destroyed brick's points, or `None` if it awards none. Without team
+/// bricks every brick scores for the player's team.
+fn credited_team(ledger: Option<&TeamLedger>, brick: Entity) -> Option<Team> {
+    ledger.map_or(Some(Team::default()), |ledger| ledger.credited_team(brick))
+}
+
 /// Awards points for destroyed bricks and updates ScoreState.
 ///
 /// # Purpose
@@ -139,8 +273,10 @@ pub fn brick_points(brick_type: u8, rng: &mut impl Rng) -> u32 {
 /// # Behavior
 ///
 /// - Reads all `BrickDestroyed` messages from this frame
+/// - Skips team bricks the `TeamLedger` credits to no one (destroyed by the other team)
 /// - Calls `brick_points()` to determine point value
-/// - Updates `ScoreState.current_score` using saturating addition
+/// - Updates `ScoreState.current_score` using saturating addition, and the credited team's
+///   `TeamScores`
 /// - Score updates are synchronous (immediate)
 ///
 /// # Performance
@@ -150,12 +286,62 @@ pub fn brick_points(brick_type: u8, rng: &mut impl Rng) -> u32 {
 pub fn award_points_system(
     mut brick_destroyed_events: MessageReader<BrickDestroyed>,
     mut score_state: ResMut<ScoreState>,
+    ledger: Option<Res<TeamLedger>>,
+    mut team_scores: Option<ResMut<TeamScores>>,
 ) {
     let mut rng = rng();
 
     for event in brick_destroyed_events.read() {
+        let Some(team) = credited_team(ledger.as_deref(), event.brick_entity) else {
+            continue;
+        };
         let points = brick_points(event.brick_type, &mut rng);
         score_state.current_score = score_state.current_score.saturating_add(points);
+        if let Some(team_scores) = team_scores.as_mut() {
+            team_scores.add(team, points);
+        }
+    }
+}
+
+/// Tracks same-type brick chains and adds chain bonuses to the score.
+///
+/// # When it runs
+///
+/// Runs after `award_points_system` and before `detect_milestone_system`, so chain
+/// bonuses count towards milestones. Chains are cleared when the level changes. Team bricks that
+/// award no points (see `award_points_system`) do not extend chains either.
+#[allow(clippy::too_many_arguments)]
+pub fn track_brick_chains(
+    mut brick_destroyed_events: MessageReader<BrickDestroyed>,
+    mut tracker: ResMut<ChainTracker>,
+    mut score_state: ResMut<ScoreState>,
+    mut bonus_events: MessageWriter<ChainBonusAwarded>,
+    current_level: Option<Res<CurrentLevel>>,
+    time: Res<Time>,
+    ledger: Option<Res<TeamLedger>>,
+    mut team_scores: Option<ResMut<TeamScores>>,
+) {
+    if current_level.is_some_and(|level| level.is_changed()) {
+        tracker.chains.clear();
+    }
+    let now = time.elapsed_secs();
+    for event in brick_destroyed_events.read() {
+        let Some(team) = credited_team(ledger.as_deref(), event.brick_entity) else {
+            continue;
+        };
+        let chain_length = tracker.record(event.brick_type, now);
+        let points = chain_bonus_points(chain_length);
+        if points > 0 {
+            score_state.current_score = score_state.current_score.saturating_add(points);
+            if let Some(team_scores) = team_scores.as_mut() {
+                team_scores.add(team, points);
+            }
+            bonus_events.write(ChainBonusAwarded {
+                brick_type: event.brick_type,
+                chain_length,
+                points,
+            });
+        }
     }
 }
 
@@ -206,8 +392,27 @@ pub fn detect_milestone_system(
     }
 }
 
-/// Helper to reset score state to initial values
+/// Starts a new level attempt in `ScoreState` whenever the current level number changes.
+///
+/// # When it runs
+///
+/// Runs before `award_points_system`, so points scored in the first frame of a level count
+/// towards that level. Restarting a level keeps its number and is handled by
+/// [`ScoreState::restart_level`] instead.
+pub fn track_level_scores(
+    current_level: Option<Res<CurrentLevel>>,
+    mut score_state: ResMut<ScoreState>,
+) {
+    let Some(level) = current_level else {
+        return;
+    };
+    let number = level.0.number;
+    if score_state.level_number != Some(number) {
+        score_state.begin_level(number);
+    }
+}
+
+/// Helper to reset score state to initial values (campaign restart)
 pub fn reset_score(score_state: &mut ResMut<ScoreState>) {
-    score_state.current_score = 0;
-    score_state.last_milestone_reached = 0;
+    score_state.reset();
 }
@@ -0,0 +1,227 @@
+//! Simulation speed: the casual game speed option, and speeds and frame stepping for debugging
+//! fast collision sequences.
+//!
+//! `game_speed` in `config/gameplay.ron` (`GameplayConfig::game_speed`, 80 to 120 percent) lets
+//! casual players run the whole game a little slower or faster. It is not applied to survival runs
+//! (`systems::survival`): their scores are ranked, so they always play at 100%. Runs at another
+//! game speed set no records: level best times (`systems::level_clock`) and ghost paths
+//! (`systems::ghost_path`) are only kept at 100% ([`SimSpeed::counts_for_records`]).
+//!
+//! With cheat mode active (`G`):
+//! - `1`/`2`/`3`/`4` run the simulation at 0.25x, 0.5x, 1x or 2x speed
+//! - `.` advances the physics by a single frame while the game is paused (ESC)
+//!
+//! The speed scales Bevy's virtual clock, so gameplay timers slow down with the physics, and
+//! Rapier's variable timestep: at speeds above 1x the step may grow accordingly and is split into
+//! substeps, so fast balls do not tunnel. Leaving cheat mode restores normal speed.
+//!
+//! The same applies to frames longer than a 60 Hz frame under a frame cap
+//! (`systems::display`): the maximum step covers a whole capped frame, so the game keeps its
+//! speed instead of slowing down with the frame rate.
+//!
+//! The game speed and the cheat speed multiply.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::gameplay_config::GameplayConfig;
+use crate::pause::PauseState;
+use crate::systems::cheat_mode::CheatModeState;
+use crate::systems::display::DisplaySettings;
+use crate::systems::survival::SurvivalMode;
+
+/// Selectable speeds, bound to `1`..`4`.
+pub const SIM_SPEEDS: [f32; 4] = [0.25, 0.5, 1.0, 2.0];
+/// Frame rate of Rapier's default maximum step.
+pub const BASE_FRAME_RATE: f32 = 60.0;
+/// Rapier's default maximum step at 1x.
+pub const BASE_MAX_DT: f32 = 1.0 / BASE_FRAME_RATE;
+
+const SPEED_KEYS: [KeyCode; 4] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+];
+
+/// Current simulation speed and pending frame step.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SimSpeed {
+    /// Cheat-mode multiplier of the virtual clock and physics step
+    pub speed: f32,
+    /// Casual game speed multiplier, from `GameplayConfig::game_speed`
+    pub game_speed: f32,
+    /// A single physics frame was requested while paused
+    pub step_requested: bool,
+}
+
+impl Default for SimSpeed {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            game_speed: 1.0,
+            step_requested: false,
+        }
+    }
+}
+
+impl SimSpeed {
+    /// Speed the game actually runs at: the game speed times the cheat speed.
+    pub fn effective(&self) -> f32 {
+        self.speed * self.game_speed
+    }
+
+    /// Whether a run at this speed may set records: only at the normal game speed.
+    pub fn counts_for_records(&self) -> bool {
+        self.game_speed == 1.0
+    }
+}
+
+/// Game speed multiplier for `config`: 1.0 in survival runs, which are ranked.
+pub fn game_speed_factor(config: Option<&GameplayConfig>, survival: Option<&SurvivalMode>) -> f32 {
+    if survival.is_some_and(|mode| mode.active) {
+        return 1.0;
+    }
+    config.map_or(1.0, |config| config.game_speed.factor())
+}
+
+/// Rapier timestep for `speed`: the maximum step scales with the speed and is split into
+/// substeps of at most [`BASE_MAX_DT`].
+pub fn timestep_for_speed(speed: f32) -> TimestepMode {
+    timestep_for(speed, None)
+}
+
+/// Rapier timestep for `speed` with frames capped at `frame_cap` frames per second: the maximum
+/// step also covers a whole capped frame.
+pub fn timestep_for(speed: f32, frame_cap: Option<u32>) -> TimestepMode {
+    let frames = frame_cap
+        .filter(|cap| *cap > 0)
+        .map_or(1.0, |cap| (BASE_FRAME_RATE / cap as f32).max(1.0));
+    let scale = speed.max(1.0) * frames;
+    TimestepMode::Variable {
+        max_dt: BASE_MAX_DT * scale,
+        time_scale: 1.0,
+        substeps: scale.ceil() as usize,
+    }
+}
+
+/// Pick a speed with `1`..`4` and request frame steps with `.` while cheat mode is active.
+pub fn handle_sim_speed_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    cheat: Res<CheatModeState>,
+    pause: Res<PauseState>,
+    mut sim: ResMut<SimSpeed>,
+) {
+    if !cheat.is_active() {
+        if sim.speed != 1.0 || sim.step_requested {
+            sim.speed = 1.0;
+            sim.step_requested = false;
+            info!("Simulation speed reset to 1x");
+        }
+        return;
+    }
+    if let Some(index) = SPEED_KEYS
+        .iter()
+        .position(|key| keyboard.just_pressed(*key))
+    {
+        let speed = SIM_SPEEDS[index];
+        if sim.speed != speed {
+            sim.speed = speed;
+            info!("Simulation speed: {}x", speed);
+        }
+    }
+    if keyboard.just_pressed(KeyCode::Period) && matches!(*pause, PauseState::Paused { .. }) {
+        sim.step_requested = true;
+    }
+}
+
+/// Take the game speed from the gameplay config, unless the session is a survival run.
+pub fn update_game_speed(
+    config: Option<Res<GameplayConfig>>,
+    survival: Option<Res<SurvivalMode>>,
+    mut sim: ResMut<SimSpeed>,
+) {
+    let config_changed = config.as_ref().is_some_and(|config| config.is_changed());
+    let survival_changed = survival.as_ref().is_some_and(|mode| mode.is_changed());
+    if !config_changed && !survival_changed {
+        return;
+    }
+    let game_speed = game_speed_factor(config.as_deref(), survival.as_deref());
+    if sim.game_speed == game_speed {
+        return;
+    }
+    sim.game_speed = game_speed;
+    if game_speed == 1.0 && config.is_some_and(|config| config.game_speed.factor() != 1.0) {
+        info!("Game speed is not applied to survival runs");
+    } else {
+        info!("Game speed: {}%", (game_speed * 100.0).round());
+    }
+}
+
+/// Apply the speed to the virtual clock and Rapier's timestep when it or the frame cap changes.
+pub fn apply_sim_speed(
+    sim: Res<SimSpeed>,
+    display: Option<Res<DisplaySettings>>,
+    mut time: ResMut<Time<Virtual>>,
+    mut timestep: ResMut<TimestepMode>,
+) {
+    if !sim.is_changed() && !display.as_ref().is_some_and(|display| display.is_changed()) {
+        return;
+    }
+    let speed = sim.effective();
+    if time.relative_speed() != speed {
+        time.set_relative_speed(speed);
+    }
+    // Leave custom (fixed or interpolated) timesteps alone
+    if matches!(*timestep, TimestepMode::Variable { .. }) {
+        let frame_cap = display.and_then(|display| display.frame_cap);
+        let wanted = timestep_for(speed, frame_cap);
+        if *timestep != wanted {
+            *timestep = wanted;
+        }
+    }
+}
+
+/// Run the physics pipeline for one frame when a step was requested while paused.
+///
+/// Runs after the pause system has frozen the physics for this frame; the next frame freezes it
+/// again.
+pub fn apply_frame_step(
+    mut sim: ResMut<SimSpeed>,
+    pause: Res<PauseState>,
+    mut rapier_config: Query<&mut RapierConfiguration>,
+) {
+    if !sim.step_requested {
+        return;
+    }
+    sim.step_requested = false;
+    if !matches!(*pause, PauseState::Paused { .. }) {
+        return;
+    }
+    let Ok(mut config) = rapier_config.single_mut() else {
+        return;
+    };
+    config.physics_pipeline_active = true;
+    debug!("Stepped physics by one frame");
+}
+
+/// Plugin registering the game speed, and the simulation speed and frame step controls.
+pub struct SimSpeedPlugin;
+
+impl Plugin for SimSpeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SimSpeed>();
+        app.init_resource::<PauseState>();
+        app.init_resource::<TimestepMode>();
+        app.add_systems(
+            Update,
+            (
+                handle_sim_speed_input,
+                update_game_speed,
+                apply_sim_speed,
+                apply_frame_step.after(crate::pause::apply_pause_to_physics),
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,320 @@
+//! Sound packs: alternative sets of sound effects.
+//!
+//! Packs are listed in `assets/audio/packs.ron`. Each is a folder (relative to `assets/`) holding
+//! a `manifest.ron` in the audio manifest format, its file names relative to the folder:
+//!
+//! ```ron
+//! (
+//!     packs: [
+//!         (id: "default", name: "Standard", folder: "audio"),
+//!         (id: "retro", name: "Retro", folder: "audio/packs/retro"),
+//!     ],
+//! )
+//! ```
+//!
+//! The default pack is `assets/audio/manifest.ron`. Other packs are layered over it, so a pack
+//! only needs the sounds it replaces, brick destruction sounds (`brick_sounds`) included. The pack played is the active theme pack's `sound_pack`,
+//! otherwise [`AudioConfig::sound_pack`] (cycled from the pause menu); [`switch_sound_pack`]
+//! reloads [`AudioAssets`] whenever that changes. Browser builds read no pack manifests and
+//! always play the default pack.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::systems::audio::{
+    AudioAssets, AudioConfig, AudioManifest, SoundType, AUDIO_MANIFEST_PATH, PLACEHOLDER_SOUNDS,
+};
+use crate::systems::themes::ActiveTheme;
+
+/// Path of the sound pack list.
+pub const SOUND_PACKS_PATH: &str = "assets/audio/packs.ron";
+/// Id of the pack of `assets/audio/manifest.ron`.
+pub const DEFAULT_SOUND_PACK: &str = "default";
+
+/// A sound pack.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SoundPack {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// Folder of the pack's `manifest.ron` and sound files, relative to `assets/`
+    pub folder: String,
+}
+
+/// Asset paths of the sounds of a pack.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SoundFiles {
+    pub sounds: HashMap<SoundType, String>,
+    /// Destruction sounds of particular brick types, keyed by `BrickTypeId`
+    pub bricks: HashMap<u8, String>,
+}
+
+/// Sound packs available to the game.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct SoundPacks {
+    #[serde(default)]
+    pub packs: Vec<SoundPack>,
+}
+
+impl SoundPacks {
+    pub fn get(&self, id: &str) -> Option<&SoundPack> {
+        self.packs.iter().find(|pack| pack.id == id)
+    }
+
+    /// Display name of the pack `id`, the default pack for `None`.
+    pub fn name(&self, id: Option<&str>) -> String {
+        let id = id.unwrap_or(DEFAULT_SOUND_PACK);
+        match self.get(id) {
+            Some(pack) if !pack.name.is_empty() => pack.name.clone(),
+            _ => id.to_string(),
+        }
+    }
+
+    /// Pack listed after `current` (`None`: the default pack), wrapping around; `None` for the
+    /// default pack.
+    pub fn next(&self, current: Option<&str>) -> Option<String> {
+        let current = current.unwrap_or(DEFAULT_SOUND_PACK);
+        let next = match self.packs.iter().position(|pack| pack.id == current) {
+            Some(index) => &self.packs[(index + 1) % self.packs.len()],
+            None => self.packs.first()?,
+        };
+        (next.id != DEFAULT_SOUND_PACK).then(|| next.id.clone())
+    }
+}
+
+/// Pack to play: the active theme's, otherwise the configured one; `None` for the default pack.
+pub fn selected_sound_pack<'a>(
+    config: Option<&'a AudioConfig>,
+    theme: Option<&'a ActiveTheme>,
+) -> Option<&'a str> {
+    theme
+        .and_then(|theme| theme.0.as_ref())
+        .and_then(|pack| pack.sound_pack.as_deref())
+        .or_else(|| config.and_then(|config| config.sound_pack.as_deref()))
+        .filter(|id| *id != DEFAULT_SOUND_PACK)
+}
+
+/// Asset paths of the default pack's sounds, with placeholders for the Merkaba sounds it lacks.
+pub fn default_sound_files(manifest: Option<&AudioManifest>) -> SoundFiles {
+    let mut files = SoundFiles::default();
+    if let Some(manifest) = manifest {
+        layer_sound_pack(&mut files, "audio", manifest);
+    }
+    for (sound_type, path) in PLACEHOLDER_SOUNDS {
+        files
+            .sounds
+            .entry(sound_type)
+            .or_insert_with(|| path.to_string());
+    }
+    files
+}
+
+/// Put the sounds of the pack in `folder` over `files`.
+pub fn layer_sound_pack(files: &mut SoundFiles, folder: &str, manifest: &AudioManifest) {
+    let folder = folder.trim_end_matches('/');
+    for (sound_type, file) in &manifest.sounds {
+        files.sounds.insert(*sound_type, format!("{folder}/{file}"));
+    }
+    for (brick_type, file) in &manifest.brick_sounds {
+        files.bricks.insert(*brick_type, format!("{folder}/{file}"));
+    }
+}
+
+/// Read the default audio manifest, warning if it is missing or broken.
+pub fn default_audio_manifest() -> Option<AudioManifest> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let content = std::fs::read_to_string(AUDIO_MANIFEST_PATH).ok();
+    #[cfg(target_arch = "wasm32")]
+    let content = Some(include_str!("../../assets/audio/manifest.ron").to_string());
+
+    let Some(content) = content else {
+        warn!(
+            target: "audio",
+            path = AUDIO_MANIFEST_PATH,
+            "Audio manifest not found, audio will be disabled"
+        );
+        return None;
+    };
+    match ron::de::from_str::<AudioManifest>(&content) {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            warn!(
+                target: "audio",
+                error = %e,
+                "Failed to parse audio manifest, audio will be disabled"
+            );
+            None
+        }
+    }
+}
+
+/// Read the manifest of `pack`.
+#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+fn pack_manifest(pack: &SoundPack) -> Option<AudioManifest> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let path = format!("assets/{}/manifest.ron", pack.folder.trim_end_matches('/'));
+        let result = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| ron::de::from_str(&content).map_err(|e| e.to_string()));
+        match result {
+            Ok(manifest) => Some(manifest),
+            Err(e) => {
+                warn!(
+                    target: "audio",
+                    "Failed to load sound pack manifest {}: {}; playing the default sounds",
+                    path,
+                    e
+                );
+                None
+            }
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        warn!(target: "audio", "Sound packs need a native build; playing the default sounds");
+        None
+    }
+}
+
+/// Asset paths of the sounds of `pack` (the default pack for `None`).
+pub fn sound_pack_files(packs: &SoundPacks, pack: Option<&str>) -> SoundFiles {
+    let mut files = default_sound_files(default_audio_manifest().as_ref());
+    let Some(id) = pack.filter(|id| *id != DEFAULT_SOUND_PACK) else {
+        return files;
+    };
+    match packs.get(id) {
+        Some(pack) => {
+            if let Some(manifest) = pack_manifest(pack) {
+                layer_sound_pack(&mut files, &pack.folder, &manifest);
+            }
+        }
+        None => warn!(
+            target: "audio",
+            "Unknown sound pack '{}'; playing the default sounds", id
+        ),
+    }
+    files
+}
+
+/// Read the sound pack list into [`SoundPacks`].
+pub fn load_sound_packs(mut commands: Commands) {
+    #[cfg(not(target_arch = "wasm32"))]
+    let content = std::fs::read_to_string(SOUND_PACKS_PATH).ok();
+    #[cfg(target_arch = "wasm32")]
+    let content = Some(include_str!("../../assets/audio/packs.ron").to_string());
+
+    let packs = match content.map(|c| ron::de::from_str::<SoundPacks>(&c)) {
+        Some(Ok(packs)) => {
+            info!(
+                target: "audio",
+                "Loaded {} sound packs from {}",
+                packs.packs.len(),
+                SOUND_PACKS_PATH
+            );
+            packs
+        }
+        Some(Err(e)) => {
+            warn!(
+                target: "audio",
+                "Failed to parse sound packs: {}; playing the default sounds", e
+            );
+            SoundPacks::default()
+        }
+        None => SoundPacks::default(),
+    };
+    commands.insert_resource(packs);
+}
+
+/// Reload [`AudioAssets`] from the selected pack when the selection changes.
+pub fn switch_sound_pack(
+    asset_server: Option<Res<AssetServer>>,
+    audio_sources: Option<Res<Assets<AudioSource>>>,
+    packs: Res<SoundPacks>,
+    config: Option<Res<AudioConfig>>,
+    theme: Option<Res<ActiveTheme>>,
+    mut audio_assets: ResMut<AudioAssets>,
+) {
+    let selected = selected_sound_pack(config.as_deref(), theme.as_deref());
+    if selected == audio_assets.pack.as_deref() {
+        return;
+    }
+    let (Some(asset_server), Some(_)) = (asset_server, audio_sources) else {
+        return;
+    };
+    audio_assets.load(&asset_server, &sound_pack_files(&packs, selected));
+    audio_assets.pack = selected.map(str::to_string);
+    info!(
+        target: "audio",
+        pack = selected.unwrap_or(DEFAULT_SOUND_PACK),
+        "Switched sound pack"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packs() -> SoundPacks {
+        ron::de::from_str(
+            r#"(packs: [
+                (id: "default", name: "Standard", folder: "audio"),
+                (id: "retro", name: "Retro", folder: "audio/packs/retro/"),
+                (id: "soft", folder: "audio/packs/soft"),
+            ])"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn packs_cycle_through_the_list() {
+        let packs = packs();
+        assert_eq!(packs.next(None).as_deref(), Some("retro"));
+        assert_eq!(packs.next(Some("retro")).as_deref(), Some("soft"));
+        assert_eq!(packs.next(Some("soft")), None);
+        assert_eq!(packs.next(Some("unknown")), None);
+        assert_eq!(SoundPacks::default().next(None), None);
+
+        assert_eq!(packs.name(None), "Standard");
+        assert_eq!(packs.name(Some("soft")), "soft");
+    }
+
+    #[test]
+    fn packs_replace_only_their_own_sounds() {
+        let base: AudioManifest = ron::de::from_str(
+            r#"(
+                sounds: { BrickDestroy: "brick.ogg", WallBounce: "wall.ogg" },
+                brick_sounds: { 90: "clang.ogg", 20: "shatter.ogg" },
+            )"#,
+        )
+        .unwrap();
+        let mut files = default_sound_files(Some(&base));
+        assert_eq!(files.sounds[&SoundType::BrickDestroy], "audio/brick.ogg");
+        assert_eq!(
+            files.sounds[&SoundType::MerkabaLoop],
+            "audio/merkaba_loop_helicopter.ogg"
+        );
+        assert_eq!(files.bricks[&20], "audio/shatter.ogg");
+
+        let retro: AudioManifest = ron::de::from_str(
+            r#"(
+                sounds: { WallBounce: "bonk.ogg", MerkabaLoop: "whirr.ogg" },
+                brick_sounds: { 20: "crunch.ogg" },
+            )"#,
+        )
+        .unwrap();
+        layer_sound_pack(&mut files, "audio/packs/retro/", &retro);
+        assert_eq!(files.sounds[&SoundType::BrickDestroy], "audio/brick.ogg");
+        assert_eq!(
+            files.sounds[&SoundType::WallBounce],
+            "audio/packs/retro/bonk.ogg"
+        );
+        assert_eq!(
+            files.sounds[&SoundType::MerkabaLoop],
+            "audio/packs/retro/whirr.ogg"
+        );
+        assert_eq!(files.bricks[&20], "audio/packs/retro/crunch.ogg");
+        assert_eq!(files.bricks[&90], "audio/clang.ogg");
+    }
+}
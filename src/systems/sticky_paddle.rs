@@ -0,0 +1,215 @@
+//! Sticky paddle: the catch capsule (dropped by glue brick 58) makes balls stick to the paddle.
+//!
+//! While the paddle carries [`StickyPaddle`], a ball that touches it is caught instead of
+//! bounced: `on_paddle_ball_hit` gives it [`BallStuck`], which holds the ball where it touched
+//! the paddle, in the paddle's own frame, so it rides along as the paddle moves and turns. Its
+//! collider is disabled meanwhile so the paddle does not keep shoving it.
+//!
+//! A click launches every stuck ball; a ball that is not launched within
+//! [`STICKY_LAUNCH_SECS`] launches by itself. Balls leave away from the paddle surface, towards
+//! the bricks, at the speed they arrived with (at least [`STICKY_MIN_LAUNCH_SPEED`]). Balls
+//! caught near a paddle tip leave at an angle.
+//!
+//! The effect ends after [`STICKY_PADDLE_DURATION`] seconds; balls still stuck then are launched
+//! as usual.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{ColliderDisabled, Velocity};
+
+use crate::systems::powerups::{closest_point_on_paddle, PowerupKind, PowerupSystems};
+use crate::systems::timed_effects::{remove_finished_effects, tick_timed_effects, TimedEffect};
+use crate::{Ball, Paddle};
+
+/// How long the paddle stays sticky, in seconds.
+pub const STICKY_PADDLE_DURATION: f32 = 15.0;
+/// Seconds a ball stays stuck before it launches by itself.
+pub const STICKY_LAUNCH_SECS: f32 = 3.0;
+/// Slowest launch, in world units per second.
+pub const STICKY_MIN_LAUNCH_SPEED: f32 = 10.0;
+/// Smallest share of the launch direction pointing towards the bricks (-X).
+const MIN_LAUNCH_FORWARD: f32 = 0.5;
+
+/// Paddle buff: balls that touch the paddle stick to it until the timer finishes.
+#[derive(Component, Debug, Clone)]
+pub struct StickyPaddle {
+    pub timer: Timer,
+}
+
+impl Default for StickyPaddle {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(STICKY_PADDLE_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+impl TimedEffect for StickyPaddle {
+    fn kind(&self) -> PowerupKind {
+        PowerupKind::Catch
+    }
+
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// A ball held by a sticky paddle until it is launched.
+#[derive(Component, Debug, Clone)]
+pub struct BallStuck {
+    pub paddle: Entity,
+    /// Ball position relative to the paddle, in the paddle's local frame
+    pub offset: Vec3,
+    /// Launch speed
+    pub speed: f32,
+    /// Time left until the ball launches by itself
+    pub timer: Timer,
+}
+
+impl BallStuck {
+    /// Catch a ball at `ball` moving at `speed` on `paddle`.
+    pub fn new(paddle: Entity, paddle_transform: &Transform, ball: Vec3, speed: f32) -> Self {
+        Self {
+            paddle,
+            offset: paddle_transform.rotation.inverse() * (ball - paddle_transform.translation),
+            speed: speed.max(STICKY_MIN_LAUNCH_SPEED),
+            timer: Timer::from_seconds(STICKY_LAUNCH_SECS, TimerMode::Once),
+        }
+    }
+
+    /// Where the ball sits on a paddle at `paddle_transform`.
+    pub fn position(&self, paddle_transform: &Transform) -> Vec3 {
+        paddle_transform.translation + paddle_transform.rotation * self.offset
+    }
+}
+
+/// Catch `ball` on `paddle`.
+pub fn stick_ball(
+    commands: &mut Commands,
+    ball: Entity,
+    ball_transform: &Transform,
+    speed: f32,
+    paddle: Entity,
+    paddle_transform: &Transform,
+) {
+    commands.entity(ball).insert((
+        BallStuck::new(paddle, paddle_transform, ball_transform.translation, speed),
+        ColliderDisabled,
+    ));
+    debug!("Paddle caught ball {:?}", ball);
+}
+
+/// Launch velocity of a ball at `ball` leaving a paddle at `paddle_transform`: away from the
+/// paddle surface, never less than [`MIN_LAUNCH_FORWARD`] towards the bricks.
+pub fn launch_velocity(ball: Vec3, paddle_transform: &Transform, speed: f32) -> Vec3 {
+    let away = ball.xz() - closest_point_on_paddle(ball, paddle_transform);
+    let away = away.try_normalize().unwrap_or(Vec2::NEG_X);
+    let forward = (-away.x).max(MIN_LAUNCH_FORWARD);
+    Vec3::new(-forward, 0.0, away.y).normalize() * speed
+}
+
+/// Keep stuck balls on their paddle, at rest.
+pub fn hold_stuck_balls(
+    mut balls: Query<(&BallStuck, &mut Transform, &mut Velocity), With<Ball>>,
+    paddles: Query<&Transform, (With<Paddle>, Without<Ball>)>,
+) {
+    for (stuck, mut transform, mut velocity) in balls.iter_mut() {
+        if let Ok(paddle_transform) = paddles.get(stuck.paddle) {
+            transform.translation = stuck.position(paddle_transform);
+        }
+        velocity.linvel = Vec3::ZERO;
+        velocity.angvel = Vec3::ZERO;
+    }
+}
+
+/// Launch stuck balls on a click, when their timer runs out, or when their paddle is gone.
+pub fn launch_stuck_balls(
+    mut commands: Commands,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    time: Res<Time>,
+    mut balls: Query<(Entity, &mut BallStuck, &Transform, &mut Velocity), With<Ball>>,
+    paddles: Query<&Transform, (With<Paddle>, Without<Ball>)>,
+) {
+    let clicked = mouse.is_some_and(|mouse| mouse.just_pressed(MouseButton::Left));
+    for (ball, mut stuck, transform, mut velocity) in balls.iter_mut() {
+        stuck.timer.tick(time.delta());
+        let paddle_transform = paddles.get(stuck.paddle).ok();
+        if !clicked && !stuck.timer.is_finished() && paddle_transform.is_some() {
+            continue;
+        }
+        if let Some(paddle_transform) = paddle_transform {
+            velocity.linvel = launch_velocity(transform.translation, paddle_transform, stuck.speed);
+        }
+        commands
+            .entity(ball)
+            .remove::<(BallStuck, ColliderDisabled)>();
+        debug!("Launched ball {:?} at {:?}", ball, velocity.linvel);
+    }
+}
+
+/// Plugin registering the sticky paddle.
+pub struct StickyPaddlePlugin;
+
+impl Plugin for StickyPaddlePlugin {
+    fn build(&self, app: &mut App) {
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_systems(
+            Update,
+            (
+                (
+                    tick_timed_effects::<StickyPaddle>,
+                    remove_finished_effects::<StickyPaddle>,
+                )
+                    .chain()
+                    .in_set(PowerupSystems::Move)
+                    .run_if(crate::pause::not_paused),
+                (
+                    launch_stuck_balls.run_if(crate::pause::not_paused),
+                    hold_stuck_balls,
+                )
+                    .chain(),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paddle_at(x: f32, z: f32) -> Transform {
+        Transform::from_xyz(x, 2.0, z)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+    }
+
+    #[test]
+    fn stuck_ball_keeps_its_place_on_the_paddle() {
+        let paddle = paddle_at(10.0, 0.0);
+        let stuck = BallStuck::new(Entity::PLACEHOLDER, &paddle, Vec3::new(9.0, 2.0, 1.5), 4.0);
+        assert_eq!(stuck.speed, STICKY_MIN_LAUNCH_SPEED);
+
+        let moved = paddle_at(10.0, -3.0);
+        assert!(stuck.position(&moved).distance(Vec3::new(9.0, 2.0, -1.5)) < 1e-4);
+    }
+
+    #[test]
+    fn balls_launch_towards_the_bricks() {
+        let paddle = paddle_at(10.0, 0.0);
+        let centre = launch_velocity(Vec3::new(9.0, 2.0, 0.0), &paddle, 12.0);
+        assert!(centre.distance(Vec3::new(-12.0, 0.0, 0.0)) < 1e-4);
+
+        // Beyond the tip the ball leaves at an angle, still towards the bricks
+        let tip = Vec3::new(9.5, 2.0, crate::PADDLE_HEIGHT / 2.0 + 0.5);
+        let angled = launch_velocity(tip, &paddle, 12.0);
+        assert!(angled.x < 0.0 && angled.z > 0.0);
+        assert!((angled.length() - 12.0).abs() < 1e-4);
+
+        // Caught behind the paddle, it still goes up the field
+        let behind = launch_velocity(Vec3::new(11.0, 2.0, 0.0), &paddle, 12.0);
+        assert!(behind.x < 0.0);
+    }
+}
@@ -0,0 +1,234 @@
+//! Score-attack survival mode.
+//!
+//! Started with `brkrs --survival` (`?survival` in the browser build), the game plays a single
+//! arena, [`SURVIVAL_ARENA_PATH`](crate::launch::SURVIVAL_ARENA_PATH) unless another level was
+//! chosen, for as long as the player can keep it up:
+//! - every `survival.stage_secs` (30 s by default) of level clock time the run reaches the next
+//!   stage
+//! - moving balls are kept at or above a minimum speed, which grows with every stage
+//! - new bricks keep arriving as brick waves (`systems::waves`), and the waves come more often with
+//!   every stage
+//!
+//! The pace is tuned in `config/gameplay.ron` ([`SurvivalConfig`]).
+//!
+//! The arena's `waves` are not played as authored: they are the patterns of the survival waves,
+//! used in rotation, each one arriving a wave interval after the previous one. Arenas without
+//! waves get a full row of simple bricks. As there is always a wave to come, the arena is never
+//! completed; the run ends with the last life, and its final score goes on the profile's survival
+//! high score list (`ProfileData::survival_scores`) instead of the campaign one.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+
+use crate::gameplay_config::{GameplayConfig, SurvivalConfig};
+use crate::launch::LaunchOptions;
+use crate::level_format::{SIMPLE_BRICK, TARGET_COLS};
+use crate::level_loader::CurrentLevel;
+use crate::systems::level_clock::LevelClock;
+use crate::systems::waves::{load_wave_schedule, spawn_due_waves, Wave, WaveSchedule, WaveTrigger};
+use crate::{Ball, BallFrozen};
+
+/// Balls slower than this are waiting to be launched and are left alone.
+const MIN_MOVING_SPEED: f32 = 0.5;
+
+/// Whether this session plays the survival mode.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SurvivalMode {
+    pub active: bool,
+}
+
+/// Progress of the current survival run.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct SurvivalRun {
+    /// Stage reached, starting at 0
+    pub stage: u32,
+    /// Level clock time in seconds at which the next queued wave arrives
+    pub next_wave_at: f32,
+    /// Wave patterns used in rotation
+    pub patterns: Vec<Wave>,
+    /// Index of the next pattern
+    pub next_pattern: usize,
+}
+
+impl SurvivalRun {
+    /// A fresh run over the valid waves of the arena (or the default row of bricks).
+    pub fn new(arena_waves: &[Wave], config: &SurvivalConfig) -> Self {
+        let mut patterns = WaveSchedule::from_waves(arena_waves).waves;
+        if patterns.is_empty() {
+            patterns.push(Wave {
+                trigger: WaveTrigger::AfterSeconds(0.0),
+                matrix: vec![vec![SIMPLE_BRICK; TARGET_COLS]],
+            });
+        }
+        Self {
+            stage: 0,
+            next_wave_at: config.wave_interval_at(0),
+            patterns,
+            next_pattern: 0,
+        }
+    }
+
+    /// The next wave: the next pattern, arriving at `next_wave_at`. Moves on to the following
+    /// pattern and time.
+    pub fn next_wave(&mut self, config: &SurvivalConfig) -> Wave {
+        let pattern = &self.patterns[self.next_pattern % self.patterns.len()];
+        let wave = Wave {
+            trigger: WaveTrigger::AfterSeconds(self.next_wave_at),
+            matrix: pattern.matrix.clone(),
+        };
+        self.next_pattern = (self.next_pattern + 1) % self.patterns.len();
+        self.next_wave_at += config.wave_interval_at(self.stage);
+        wave
+    }
+}
+
+fn survival_config(gameplay: Option<&GameplayConfig>) -> SurvivalConfig {
+    gameplay.map_or_else(SurvivalConfig::default, |config| config.survival)
+}
+
+/// Run condition: this session plays the survival mode.
+pub fn survival_active(mode: Option<Res<SurvivalMode>>) -> bool {
+    mode.is_some_and(|mode| mode.active)
+}
+
+/// Pick up the survival option chosen at launch.
+pub fn init_survival_mode(launch: Option<Res<LaunchOptions>>, mut mode: ResMut<SurvivalMode>) {
+    mode.active = launch.as_deref().map_or_else(
+        || LaunchOptions::from_env().survival,
+        |launch| launch.survival,
+    );
+    if mode.active {
+        info!("Playing the survival mode");
+    }
+}
+
+/// Start a new run when the arena is loaded or restarted, replacing its authored waves.
+pub fn start_survival_run(
+    current_level: Res<CurrentLevel>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut run: ResMut<SurvivalRun>,
+    mut schedule: ResMut<WaveSchedule>,
+) {
+    let config = survival_config(gameplay.as_deref());
+    *run = SurvivalRun::new(&current_level.0.waves, &config);
+    *schedule = WaveSchedule::default();
+}
+
+/// Move on to the next stage as the level clock runs.
+pub fn advance_survival_stage(
+    clock: Option<Res<LevelClock>>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut run: ResMut<SurvivalRun>,
+) {
+    let elapsed = clock.map_or(Duration::ZERO, |clock| clock.elapsed);
+    let config = survival_config(gameplay.as_deref());
+    let stage = config.stage_at(elapsed.as_secs_f32());
+    if stage > run.stage {
+        run.stage = stage;
+        info!(
+            "Survival stage {}: ball speed {:.1}, a wave every {:.1}s",
+            stage + 1,
+            config.ball_speed_at(stage),
+            config.wave_interval_at(stage)
+        );
+    }
+}
+
+/// Keep one wave queued, so bricks keep coming and the arena is never completed.
+pub fn queue_survival_waves(
+    gameplay: Option<Res<GameplayConfig>>,
+    mut run: ResMut<SurvivalRun>,
+    mut schedule: ResMut<WaveSchedule>,
+) {
+    if schedule.pending() > 0 {
+        return;
+    }
+    let wave = run.next_wave(&survival_config(gameplay.as_deref()));
+    schedule.waves.push(wave);
+}
+
+/// Speed up moving balls that are slower than the stage's minimum speed.
+pub fn enforce_survival_ball_speed(
+    gameplay: Option<Res<GameplayConfig>>,
+    run: Res<SurvivalRun>,
+    mut balls: Query<&mut Velocity, (With<Ball>, Without<BallFrozen>)>,
+) {
+    let min_speed = survival_config(gameplay.as_deref()).ball_speed_at(run.stage);
+    for mut velocity in balls.iter_mut() {
+        let speed = velocity.linvel.length();
+        if speed > MIN_MOVING_SPEED && speed < min_speed {
+            velocity.linvel *= min_speed / speed;
+        }
+    }
+}
+
+/// Plugin registering the survival mode.
+pub struct SurvivalPlugin;
+
+impl Plugin for SurvivalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SurvivalMode>();
+        app.init_resource::<SurvivalRun>();
+        app.init_resource::<WaveSchedule>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_systems(Startup, init_survival_mode);
+        app.add_systems(
+            Update,
+            (
+                start_survival_run
+                    .run_if(resource_exists_and_changed::<CurrentLevel>)
+                    .after(load_wave_schedule)
+                    .before(spawn_due_waves),
+                (advance_survival_stage, enforce_survival_ball_speed)
+                    .chain()
+                    .after(start_survival_run)
+                    .run_if(crate::pause::not_paused),
+                // Right after a wave arrives, so completion is never checked without one to come
+                queue_survival_waves
+                    .after(spawn_due_waves)
+                    .after(start_survival_run)
+                    .before(crate::level_loader::LevelAdvanceSystems),
+            )
+                .run_if(survival_active),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(brick: u8) -> Wave {
+        Wave {
+            trigger: WaveTrigger::BricksBelow(5),
+            matrix: vec![vec![brick]],
+        }
+    }
+
+    #[test]
+    fn patterns_rotate_and_come_faster_in_later_stages() {
+        let config = SurvivalConfig::default();
+        let mut run = SurvivalRun::new(&[pattern(20), pattern(3)], &config);
+
+        let first = run.next_wave(&config);
+        assert_eq!(first.trigger, WaveTrigger::AfterSeconds(15.0));
+        assert_eq!(first.matrix, vec![vec![20]]);
+        assert_eq!(run.next_wave(&config).matrix, vec![vec![3]]);
+        assert_eq!(run.next_wave(&config).matrix, vec![vec![20]]);
+        assert_eq!(run.next_wave_at, 60.0);
+
+        run.stage = 1;
+        run.next_wave(&config);
+        assert_eq!(run.next_wave_at, 60.0 + config.wave_interval_at(1));
+    }
+
+    #[test]
+    fn arenas_without_waves_get_a_row_of_bricks() {
+        let run = SurvivalRun::new(&[], &SurvivalConfig::default());
+        assert_eq!(run.patterns.len(), 1);
+        assert_eq!(run.patterns[0].matrix[0], vec![SIMPLE_BRICK; TARGET_COLS]);
+    }
+}
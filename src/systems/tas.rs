@@ -0,0 +1,426 @@
+//! Tool-assisted (TAS) input files.
+//!
+//! A TAS file holds the input of every frame of a session: the mouse motion that drives the
+//! paddle, the mouse wheel that turns it, and the keys and mouse buttons held down. `--record-tas
+//! FILE` records one; `--tas FILE` (or `--replay FILE`) plays one back, e.g. to reproduce a bug
+//! attached to an issue.
+//! The files are plain text so they can be edited by hand:
+//!
+//! ```text
+//! # brkrs TAS input: frames mouse_x mouse_y scroll buttons
+//! seed 42
+//! level 3
+//! 30 0 0 0
+//! 1 0 0 0 MouseLeft
+//! 120 4.5 0 0
+//! 2 0 0 0.5 KeyR
+//! ```
+//!
+//! Each input line repeats the same input for its number of frames. Buttons are the keys of the
+//! game's bindings (`input_map`) by their `KeyCode` name, plus `MouseLeft` and `MouseRight`; lines
+//! starting with `#` are comments. The `seed` and `level` lines are used unless others were chosen
+//! on the command line.
+//!
+//! Recording and playback advance the game by a fixed [`TAS_FRAME_SECS`] per frame, so a file
+//! plays the same game again on the same seed, level and configuration. During playback the file
+//! replaces the player's input; once it runs out the player takes over, and a headless game exits.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::input_map::InputMap;
+use crate::launch::LaunchOptions;
+use crate::level_loader::CurrentLevel;
+use crate::systems::rng::GameRng;
+
+/// Game time a frame advances by while recording or playing back.
+pub const TAS_FRAME_SECS: f64 = 1.0 / 60.0;
+
+const TAS_HEADER: &str = "# brkrs TAS input: frames mouse_x mouse_y scroll buttons";
+
+/// A key or mouse button a TAS file can hold down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TasButton {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl TasButton {
+    /// Every button a TAS file can hold down: the keys of the default bindings and the left and
+    /// right mouse buttons.
+    pub fn all() -> Vec<TasButton> {
+        let mut buttons: Vec<TasButton> = Vec::new();
+        let chords = InputMap::default()
+            .bindings
+            .into_iter()
+            .flat_map(|b| b.keys);
+        for chord in chords {
+            for key in chord.modifier.into_iter().chain([chord.key]) {
+                if !buttons.contains(&TasButton::Key(key)) {
+                    buttons.push(TasButton::Key(key));
+                }
+            }
+        }
+        buttons.extend([
+            TasButton::Mouse(MouseButton::Left),
+            TasButton::Mouse(MouseButton::Right),
+        ]);
+        buttons
+    }
+
+    /// Name of the button in TAS files, e.g. `KeyR` or `MouseLeft`.
+    pub fn name(self) -> String {
+        match self {
+            TasButton::Key(key) => format!("{key:?}"),
+            TasButton::Mouse(button) => format!("Mouse{button:?}"),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<TasButton> {
+        TasButton::all()
+            .into_iter()
+            .find(|button| button.name() == name)
+    }
+}
+
+/// Input of one frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TasFrame {
+    /// Mouse motion, as `AccumulatedMouseMotion` reports it
+    pub mouse: Vec2,
+    /// Vertical mouse wheel motion
+    pub scroll: f32,
+    /// Buttons held down
+    pub buttons: Vec<TasButton>,
+}
+
+/// A TAS file: the seed and level it starts from and the input of every frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TasMovie {
+    pub seed: Option<u64>,
+    pub level: Option<u32>,
+    pub frames: Vec<TasFrame>,
+}
+
+impl TasMovie {
+    /// Parse the text of a TAS file.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut movie = TasMovie::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("line {}: {message}: '{line}'", index + 1);
+            let mut fields = line.split_whitespace();
+            match fields.next() {
+                Some("seed") => {
+                    let seed = fields.next().and_then(|seed| seed.parse().ok());
+                    movie.seed = Some(seed.ok_or_else(|| error("seed is not a number"))?);
+                }
+                Some("level") => {
+                    let level = fields.next().and_then(|level| level.parse().ok());
+                    movie.level = Some(level.ok_or_else(|| error("level is not a number"))?);
+                }
+                Some(count) => {
+                    let count: usize = count
+                        .parse()
+                        .map_err(|_| error("frame count is not a number"))?;
+                    let mut number = |what: &str| {
+                        fields
+                            .next()
+                            .and_then(|value| value.parse::<f32>().ok())
+                            .ok_or_else(|| error(&format!("{what} missing or not a number")))
+                    };
+                    let mouse = Vec2::new(number("mouse_x")?, number("mouse_y")?);
+                    let scroll = number("scroll")?;
+                    let buttons = fields
+                        .map(|name| {
+                            TasButton::from_name(name)
+                                .ok_or_else(|| error(&format!("unknown button '{name}'")))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let frame = TasFrame {
+                        mouse,
+                        scroll,
+                        buttons,
+                    };
+                    movie.frames.extend(std::iter::repeat_n(frame, count));
+                }
+                None => {}
+            }
+        }
+        Ok(movie)
+    }
+
+    /// Text of the TAS file, repeated frames written as one line.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{TAS_HEADER}\n");
+        if let Some(seed) = self.seed {
+            text.push_str(&format!("seed {seed}\n"));
+        }
+        if let Some(level) = self.level {
+            text.push_str(&format!("level {level}\n"));
+        }
+        for run in self.frames.chunk_by(|a, b| a == b) {
+            let frame = &run[0];
+            text.push_str(&format!(
+                "{} {} {} {}",
+                run.len(),
+                frame.mouse.x,
+                frame.mouse.y,
+                frame.scroll
+            ));
+            for button in &frame.buttons {
+                text.push(' ');
+                text.push_str(&button.name());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::parse(&text)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_text()).map_err(|e| e.to_string())
+    }
+}
+
+/// A TAS file being played back.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TasPlayback {
+    pub movie: TasMovie,
+    /// Next frame to play
+    pub frame: usize,
+    /// Buttons held down by the last frame played
+    pub held: Vec<TasButton>,
+}
+
+impl TasPlayback {
+    pub fn new(movie: TasMovie) -> Self {
+        Self { movie, ..default() }
+    }
+}
+
+/// A session being recorded into a TAS file.
+#[derive(Resource, Debug, Clone)]
+pub struct TasRecorder {
+    pub path: PathBuf,
+    pub movie: TasMovie,
+}
+
+impl TasRecorder {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            movie: TasMovie::default(),
+        }
+    }
+}
+
+/// Make `button` held down or not this frame, whatever the real device did.
+fn set_button<T>(input: &mut ButtonInput<T>, button: T, down: bool, was_down: bool)
+where
+    T: Copy + Eq + std::hash::Hash + Send + Sync + 'static,
+{
+    input.reset(button);
+    if down || was_down {
+        input.press(button);
+    }
+    if was_down {
+        input.clear_just_pressed(button);
+    }
+    if was_down && !down {
+        input.release(button);
+    }
+}
+
+fn set_buttons(
+    keys: &mut ButtonInput<KeyCode>,
+    mouse: &mut ButtonInput<MouseButton>,
+    down: &[TasButton],
+    was_down: &[TasButton],
+) {
+    for button in TasButton::all() {
+        let (down, was_down) = (down.contains(&button), was_down.contains(&button));
+        match button {
+            TasButton::Key(key) => set_button(keys, key, down, was_down),
+            TasButton::Mouse(mouse_button) => set_button(mouse, mouse_button, down, was_down),
+        }
+    }
+}
+
+/// Replace this frame's input with the next frame of the TAS file.
+pub fn play_tas_frame(
+    mut commands: Commands,
+    mut playback: ResMut<TasPlayback>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut motion: ResMut<AccumulatedMouseMotion>,
+    mut scroll: ResMut<AccumulatedMouseScroll>,
+    launch: Option<Res<LaunchOptions>>,
+    mut exits: MessageWriter<AppExit>,
+) {
+    let Some(frame) = playback.movie.frames.get(playback.frame).cloned() else {
+        info!("TAS playback finished after {} frames", playback.frame);
+        set_buttons(&mut keys, &mut mouse, &[], &playback.held);
+        commands.remove_resource::<TasPlayback>();
+        if launch.is_some_and(|launch| launch.headless) {
+            exits.write(AppExit::Success);
+        }
+        return;
+    };
+    motion.delta = frame.mouse;
+    scroll.delta = Vec2::new(0.0, frame.scroll);
+    set_buttons(&mut keys, &mut mouse, &frame.buttons, &playback.held);
+    playback.held = frame.buttons;
+    playback.frame += 1;
+}
+
+/// Add this frame's input to the recording.
+pub fn record_tas_frame(
+    mut recorder: ResMut<TasRecorder>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    motion: Res<AccumulatedMouseMotion>,
+    scroll: Res<AccumulatedMouseScroll>,
+    rng: Option<Res<GameRng>>,
+    current_level: Option<Res<CurrentLevel>>,
+) {
+    let movie = &mut recorder.movie;
+    if movie.seed.is_none() {
+        movie.seed = rng.map(|rng| rng.seed());
+    }
+    if movie.level.is_none() {
+        movie.level = current_level.map(|level| level.0.number);
+    }
+    let buttons = TasButton::all()
+        .into_iter()
+        .filter(|button| match *button {
+            TasButton::Key(key) => keys.pressed(key),
+            TasButton::Mouse(mouse_button) => mouse.pressed(mouse_button),
+        })
+        .collect();
+    movie.frames.push(TasFrame {
+        mouse: motion.delta,
+        scroll: scroll.delta.y,
+        buttons,
+    });
+}
+
+/// Write the recording when the game exits.
+pub fn save_tas_recording(recorder: Res<TasRecorder>, mut exits: MessageReader<AppExit>) {
+    if exits.read().count() == 0 {
+        return;
+    }
+    match recorder.movie.save(&recorder.path) {
+        Ok(()) => info!(
+            "Recorded {} frames to {}",
+            recorder.movie.frames.len(),
+            recorder.path.display()
+        ),
+        Err(e) => warn!(
+            "Failed to write TAS file {}: {}",
+            recorder.path.display(),
+            e
+        ),
+    }
+}
+
+/// Plugin registering TAS recording and playback, as chosen by the launch options.
+pub struct TasPlugin;
+
+impl Plugin for TasPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(mut launch) = app.world().get_resource::<LaunchOptions>().cloned() else {
+            return;
+        };
+        let mut fixed_step = false;
+        if let Some(path) = launch.playback_path().map(Path::to_path_buf) {
+            match TasMovie::load(&path) {
+                Ok(movie) => {
+                    info!(
+                        "Playing back {} frames from {}",
+                        movie.frames.len(),
+                        path.display()
+                    );
+                    if !launch.level_chosen() {
+                        launch.level = movie.level;
+                    }
+                    launch.seed = launch.seed.or(movie.seed);
+                    app.insert_resource(TasPlayback::new(movie));
+                    fixed_step = true;
+                }
+                Err(e) => warn!("Failed to load TAS file {}: {}", path.display(), e),
+            }
+        }
+        if let Some(path) = launch.record_tas.clone() {
+            app.insert_resource(TasRecorder::new(path));
+            fixed_step = true;
+        }
+        if fixed_step {
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                TAS_FRAME_SECS,
+            )));
+        }
+        app.insert_resource(launch);
+        app.add_systems(
+            PreUpdate,
+            (
+                play_tas_frame.run_if(resource_exists::<TasPlayback>),
+                // Recording during playback keeps the played input
+                record_tas_frame.run_if(resource_exists::<TasRecorder>),
+            )
+                .chain()
+                .after(InputSystems),
+        );
+        app.add_systems(
+            Last,
+            save_tas_recording.run_if(resource_exists::<TasRecorder>),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_frames_are_written_as_one_line() {
+        let still = TasFrame::default();
+        let restart = TasFrame {
+            scroll: 0.5,
+            buttons: vec![TasButton::Key(KeyCode::KeyR)],
+            ..default()
+        };
+        let movie = TasMovie {
+            seed: Some(42),
+            level: Some(3),
+            frames: vec![still.clone(), still, restart.clone(), restart],
+        };
+        let text = movie.to_text();
+        assert!(text.contains("\n2 0 0 0\n2 0 0 0.5 KeyR\n"), "{text}");
+        assert_eq!(TasMovie::parse(&text).unwrap(), movie);
+    }
+
+    #[test]
+    fn every_bound_key_has_a_name() {
+        for button in TasButton::all() {
+            assert_eq!(TasButton::from_name(&button.name()), Some(button));
+        }
+        assert_eq!(
+            TasButton::from_name("MouseLeft"),
+            Some(TasButton::Mouse(MouseButton::Left))
+        );
+    }
+}
@@ -0,0 +1,530 @@
+//! Team-colored bricks for score races.
+//!
+//! A level can tag destructible bricks with a team color:
+//!
+//! ```ron
+//! teams: [
+//!     (team: Red, cells: [(2, 0), (2, 1)]),
+//!     (team: Blue, cells: [(2, 18), (2, 19)]),
+//! ],
+//! ```
+//!
+//! Tagged bricks get a [`TeamBrick`] component and a stripe of their color on top. Balls belong to
+//! the team of their [`BallTeam`]; balls without one belong to [`Team::Red`], the single player's
+//! team.
+//!
+//! In versus mode (`brkrs --versus`, `?versus` in the browser build) two players, Red and Blue,
+//! take turns with the paddle: every ball spawned gets the [`BallTeam`] of the player whose turn it
+//! is ([`VersusMode::turn`]), and losing a life passes the paddle to the other player. Red starts.
+//!
+//! The scoring systems consult [`TeamLedger`]:
+//!
+//! - A team brick only awards points (and extends chains) when a ball of its own team destroyed
+//!   it. Team bricks destroyed by a ball of the other team, or by anything but a ball hit
+//!   (explosions, wrecking ball debris), award nothing.
+//! - Untagged bricks score as usual, for the team of the ball that last hit them.
+//!
+//! [`TeamScores`] keeps each team's points for the HUD (`ui::team_score_display`): on the current
+//! level, or over the whole session in versus mode. The overall score in `ScoreState` keeps
+//! counting every point.
+//!
+//! Entries whose cells do not hold a brick a ball can destroy are skipped with a warning.
+
+use std::collections::HashMap;
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::CollisionEvent;
+use serde::{Deserialize, Serialize};
+
+use crate::launch::LaunchOptions;
+use crate::level_format::{
+    matrix_resolution, validate_cell_range_in, BUMPER, HAZARD_BRICK_91, INDESTRUCTIBLE_BRICK,
+    PADDLE_DESTROYABLE_BRICK, TIMED_BOMB_BRICK,
+};
+use crate::level_loader::{CurrentLevel, LevelDefinition};
+use crate::systems::respawn::LifeLostEvent;
+use crate::{Ball, Brick, BrickGridPos, GridResolution, MarkedForDespawn};
+
+/// Team a brick or ball belongs to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Team {
+    /// The single player's team, and the team of balls without a [`BallTeam`].
+    #[default]
+    Red,
+    /// The opposing team.
+    Blue,
+}
+
+impl Team {
+    /// Color of the team's brick stripes and HUD score.
+    pub fn color(self) -> Color {
+        match self {
+            Team::Red => Color::srgb(0.95, 0.3, 0.25),
+            Team::Blue => Color::srgb(0.3, 0.55, 1.0),
+        }
+    }
+
+    /// Name shown on the HUD.
+    pub fn label(self) -> &'static str {
+        match self {
+            Team::Red => "Red",
+            Team::Blue => "Blue",
+        }
+    }
+}
+
+/// Team color of some bricks as authored in a level file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamBricksDef {
+    /// Team the bricks award points to
+    pub team: Team,
+    /// Grid cells `(row, col)` of the bricks
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// Team color of a brick: only balls of this team score for destroying it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeamBrick(pub Team);
+
+/// Team a ball plays for; balls without one play for [`Team::Red`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BallTeam(pub Team);
+
+/// The team stripe drawn on top of a team brick.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeamStripe;
+
+/// Check a team entry against the level `matrix`.
+pub fn validate_team_bricks(def: &TeamBricksDef, matrix: &[Vec<u8>]) -> Result<(), String> {
+    if def.cells.is_empty() {
+        return Err("no cells given".to_string());
+    }
+    def.cells.iter().try_for_each(|&(row, col)| {
+        validate_cell_range_in((row, row), (col, col), matrix_resolution(matrix))?;
+        match matrix.get(row).and_then(|r| r.get(col)) {
+            Some(&(INDESTRUCTIBLE_BRICK | HAZARD_BRICK_91 | BUMPER)) => Err(format!(
+                "cell ({}, {}) holds an indestructible brick",
+                row, col
+            )),
+            Some(&(PADDLE_DESTROYABLE_BRICK | TIMED_BOMB_BRICK)) => Err(format!(
+                "cell ({}, {}) holds a brick balls do not destroy",
+                row, col
+            )),
+            Some(3..=255) => Ok(()),
+            _ => Err(format!("cell ({}, {}) holds no brick", row, col)),
+        }
+    })
+}
+
+/// Team colors of the current level's cells.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LevelTeams {
+    pub cells: HashMap<(usize, usize), Team>,
+}
+
+impl LevelTeams {
+    /// Load the team colors of a level, skipping (and logging) invalid entries. A later entry
+    /// overrides an earlier one for the same cell.
+    pub fn for_level(def: &LevelDefinition) -> Self {
+        let mut cells = HashMap::new();
+        for entry in &def.teams {
+            match validate_team_bricks(entry, &def.matrix) {
+                Ok(()) => cells.extend(entry.cells.iter().map(|&cell| (cell, entry.team))),
+                Err(e) => warn!(
+                    "Ignoring {} team bricks of level {}: {}",
+                    entry.team.label(),
+                    def.number,
+                    e
+                ),
+            }
+        }
+        Self { cells }
+    }
+
+    /// Whether the level has any team bricks (and so a score race).
+    pub fn is_race(&self) -> bool {
+        !self.cells.is_empty()
+    }
+}
+
+/// Whether this session is a versus race, and whose turn it is.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VersusMode {
+    pub active: bool,
+    /// Team of the player holding the paddle
+    pub turn: Team,
+}
+
+impl VersusMode {
+    /// The other player's turn.
+    pub fn pass_turn(&mut self) {
+        self.turn = match self.turn {
+            Team::Red => Team::Blue,
+            Team::Blue => Team::Red,
+        };
+    }
+}
+
+/// Points each team scored on the current level (the whole session in versus mode).
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TeamScores {
+    pub red: u32,
+    pub blue: u32,
+}
+
+impl TeamScores {
+    /// Points of `team`.
+    pub fn get(&self, team: Team) -> u32 {
+        match team {
+            Team::Red => self.red,
+            Team::Blue => self.blue,
+        }
+    }
+
+    /// Credit `points` to `team`.
+    pub fn add(&mut self, team: Team, points: u32) {
+        let score = match team {
+            Team::Red => &mut self.red,
+            Team::Blue => &mut self.blue,
+        };
+        *score = score.saturating_add(points);
+    }
+}
+
+/// Who destroyed a brick, as seen by the scoring systems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyedBrick {
+    /// Team color of the brick, if tagged
+    pub team: Option<Team>,
+    /// Team of the ball that last hit it, if a ball hit it
+    pub hit_by: Option<Team>,
+}
+
+impl DestroyedBrick {
+    /// Team credited with the brick's points, or `None` if it awards none.
+    pub fn credited_team(self) -> Option<Team> {
+        match self.team {
+            Some(team) => (self.hit_by == Some(team)).then_some(team),
+            None => Some(self.hit_by.unwrap_or_default()),
+        }
+    }
+}
+
+/// Ball hits and destroyed bricks the scoring systems consult to credit points.
+///
+/// `destroyed` holds the bricks marked for despawn in the current frame: it is refilled before
+/// `despawn_marked_entities` emits their `BrickDestroyed` messages.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TeamLedger {
+    /// Team of the ball that last hit each brick on the current level
+    pub last_hit: HashMap<Entity, Team>,
+    /// Bricks marked for despawn this frame
+    pub destroyed: HashMap<Entity, DestroyedBrick>,
+}
+
+impl TeamLedger {
+    /// Team credited for destroying `brick`, or `None` if it awards no points. Bricks not in the
+    /// ledger (such as those destroyed without being marked for despawn) score for the ball that
+    /// last hit them.
+    pub fn credited_team(&self, brick: Entity) -> Option<Team> {
+        match self.destroyed.get(&brick) {
+            Some(destroyed) => destroyed.credited_team(),
+            None => Some(self.last_hit.get(&brick).copied().unwrap_or_default()),
+        }
+    }
+}
+
+/// Shared mesh and materials of the team stripes.
+#[derive(Resource, Debug, Clone)]
+pub struct TeamStripeAssets {
+    pub mesh: Handle<Mesh>,
+    pub red: Handle<StandardMaterial>,
+    pub blue: Handle<StandardMaterial>,
+}
+
+impl TeamStripeAssets {
+    fn material(&self, team: Team) -> Handle<StandardMaterial> {
+        match team {
+            Team::Red => self.red.clone(),
+            Team::Blue => self.blue.clone(),
+        }
+    }
+}
+
+/// Reload the team colors when a level is loaded, starting a new race outside versus mode.
+pub fn load_level_teams(
+    current_level: Res<CurrentLevel>,
+    versus: Res<VersusMode>,
+    mut teams: ResMut<LevelTeams>,
+    mut scores: ResMut<TeamScores>,
+    mut ledger: ResMut<TeamLedger>,
+) {
+    *teams = LevelTeams::for_level(&current_level.0);
+    if !versus.active {
+        *scores = TeamScores::default();
+    }
+    ledger.last_hit.clear();
+}
+
+/// Turn versus mode on from the launch options.
+pub fn init_versus_mode(launch: Option<Res<LaunchOptions>>, mut versus: ResMut<VersusMode>) {
+    versus.active = launch
+        .as_deref()
+        .map_or_else(|| LaunchOptions::from_env().versus, |launch| launch.versus);
+    if versus.active {
+        info!("Playing a versus race, {} starts", versus.turn.label());
+    }
+}
+
+/// Give newly spawned balls the team of the player whose turn it is.
+pub fn assign_ball_teams(
+    mut commands: Commands,
+    versus: Res<VersusMode>,
+    balls: Query<Entity, (Added<Ball>, Without<BallTeam>)>,
+) {
+    if !versus.active {
+        return;
+    }
+    for entity in balls.iter() {
+        commands.entity(entity).insert(BallTeam(versus.turn));
+    }
+}
+
+/// Pass the paddle to the other player when a life is lost.
+pub fn pass_turn_on_life_lost(
+    mut losses: MessageReader<LifeLostEvent>,
+    mut versus: ResMut<VersusMode>,
+) {
+    // Balls lost in the same frame cost one life, and pass the turn once
+    if losses.read().count() > 0 && versus.active {
+        versus.pass_turn();
+        info!("{}'s turn", versus.turn.label());
+    }
+}
+
+/// Give newly spawned bricks their team color.
+pub fn assign_brick_teams(
+    mut commands: Commands,
+    teams: Res<LevelTeams>,
+    bricks: Query<(Entity, &BrickGridPos), (With<Brick>, Added<BrickGridPos>)>,
+) {
+    for (entity, pos) in bricks.iter() {
+        if let Some(&team) = teams.cells.get(&(pos.row, pos.col)) {
+            commands.entity(entity).insert(TeamBrick(team));
+        }
+    }
+}
+
+/// Remember the team of the ball hitting each brick.
+pub fn record_team_hits(
+    mut collisions: MessageReader<CollisionEvent>,
+    balls: Query<Option<&BallTeam>, With<Ball>>,
+    bricks: Query<(), With<Brick>>,
+    mut ledger: ResMut<TeamLedger>,
+) {
+    for event in collisions.read() {
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        for (ball, brick) in [(*e1, *e2), (*e2, *e1)] {
+            if let (Ok(team), true) = (balls.get(ball), bricks.contains(brick)) {
+                ledger
+                    .last_hit
+                    .insert(brick, team.map(|t| t.0).unwrap_or_default());
+            }
+        }
+    }
+}
+
+/// Note who destroyed the bricks marked for despawn this frame, before they are despawned.
+pub fn settle_destroyed_bricks(
+    mut ledger: ResMut<TeamLedger>,
+    marked: Query<(Entity, Option<&TeamBrick>), (With<Brick>, Added<MarkedForDespawn>)>,
+) {
+    ledger.destroyed.clear();
+    for (entity, team) in marked.iter() {
+        let destroyed = DestroyedBrick {
+            team: team.map(|t| t.0),
+            hit_by: ledger.last_hit.get(&entity).copied(),
+        };
+        ledger.destroyed.insert(entity, destroyed);
+    }
+}
+
+fn setup_team_stripe_assets(
+    mut commands: Commands,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    let mut material = |team: Team| {
+        materials.add(StandardMaterial {
+            base_color: team.color(),
+            emissive: team.color().to_linear() * 0.3,
+            ..default()
+        })
+    };
+    let (red, blue) = (material(Team::Red), material(Team::Blue));
+    let standard = GridResolution::default();
+    commands.insert_resource(TeamStripeAssets {
+        // Sized for standard cells; brick faces are 90% of the cell (see `level_loader::spawn_brick`)
+        mesh: meshes.add(Cuboid::new(
+            standard.cell_height() * 0.3,
+            0.05,
+            standard.cell_width() * 0.8,
+        )),
+        red,
+        blue,
+    });
+}
+
+/// Draw the stripe of newly tagged team bricks.
+pub fn spawn_team_stripes(
+    mut commands: Commands,
+    assets: Option<Res<TeamStripeAssets>>,
+    grid: Option<Res<GridResolution>>,
+    bricks: Query<(Entity, &TeamBrick), Added<TeamBrick>>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    // Fit the shared stripe mesh to the cells of the level's grid
+    let (grid, standard) = (
+        grid.as_deref().copied().unwrap_or_default(),
+        GridResolution::default(),
+    );
+    let scale = Vec3::new(
+        grid.cell_height() / standard.cell_height(),
+        1.0,
+        grid.cell_width() / standard.cell_width(),
+    );
+    for (entity, team) in bricks.iter() {
+        commands.entity(entity).with_children(|brick| {
+            brick.spawn((
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material(team.0)),
+                Transform::from_xyz(0.0, 0.28, 0.0).with_scale(scale),
+                TeamStripe,
+            ));
+        });
+    }
+}
+
+/// Plugin registering team bricks, versus turns and the per-team scores.
+pub struct TeamBricksPlugin;
+
+impl Plugin for TeamBricksPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelTeams>();
+        app.init_resource::<TeamScores>();
+        app.init_resource::<TeamLedger>();
+        app.init_resource::<VersusMode>();
+        app.add_message::<CollisionEvent>();
+        app.add_message::<LifeLostEvent>();
+        app.add_systems(Startup, (setup_team_stripe_assets, init_versus_mode));
+        app.add_systems(
+            Update,
+            (
+                pass_turn_on_life_lost,
+                // Respawned balls are spawned after the loss, so they go to the next player
+                assign_ball_teams,
+            )
+                .chain()
+                .before(record_team_hits),
+        );
+        app.add_systems(
+            Update,
+            (
+                load_level_teams.run_if(resource_exists_and_changed::<CurrentLevel>),
+                assign_brick_teams,
+                spawn_team_stripes,
+            )
+                .chain(),
+        );
+        app.add_systems(
+            Update,
+            (record_team_hits, settle_destroyed_bricks)
+                .chain()
+                // Every way of marking bricks has run; the scoring systems read the ledger after
+                // the bricks are despawned
+                .after(crate::mark_brick_on_ball_collision)
+                .after(crate::systems::explosion::detonate_explosives)
+                .after(crate::systems::wrecking_ball::knock_bricks_loose)
+                .before(crate::despawn_marked_entities),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(matrix: Vec<Vec<u8>>, teams: Vec<TeamBricksDef>) -> LevelDefinition {
+        let mut def: LevelDefinition =
+            ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+        def.matrix = matrix;
+        def.teams = teams;
+        def
+    }
+
+    #[test]
+    fn invalid_entries_are_skipped() {
+        let def = level(
+            vec![vec![20, 90, 57, 0]],
+            vec![
+                TeamBricksDef {
+                    team: Team::Red,
+                    cells: vec![(0, 0)],
+                },
+                TeamBricksDef {
+                    team: Team::Blue,
+                    cells: vec![(0, 1)],
+                },
+                TeamBricksDef {
+                    team: Team::Blue,
+                    cells: vec![(0, 2)],
+                },
+                TeamBricksDef {
+                    team: Team::Blue,
+                    cells: vec![(0, 3)],
+                },
+            ],
+        );
+        let teams = LevelTeams::for_level(&def);
+        assert_eq!(teams.cells, HashMap::from([((0, 0), Team::Red)]));
+        assert!(teams.is_race());
+    }
+
+    #[test]
+    fn teams_parse_from_ron() {
+        let def: LevelDefinition = ron::de::from_str(
+            "LevelDefinition(number: 1, matrix: [], teams: [(team: Blue, cells: [(3, 4)])])",
+        )
+        .unwrap();
+        assert_eq!(
+            def.teams,
+            vec![TeamBricksDef {
+                team: Team::Blue,
+                cells: vec![(3, 4)]
+            }]
+        );
+    }
+
+    #[test]
+    fn only_own_team_scores_team_bricks() {
+        let destroyed = |team, hit_by| DestroyedBrick { team, hit_by }.credited_team();
+        assert_eq!(
+            destroyed(Some(Team::Blue), Some(Team::Blue)),
+            Some(Team::Blue)
+        );
+        assert_eq!(destroyed(Some(Team::Blue), Some(Team::Red)), None);
+        // Not destroyed by a ball hit
+        assert_eq!(destroyed(Some(Team::Red), None), None);
+        // Untagged bricks score for whoever hit them, the player's team otherwise
+        assert_eq!(destroyed(None, Some(Team::Blue)), Some(Team::Blue));
+        assert_eq!(destroyed(None, None), Some(Team::Red));
+    }
+}
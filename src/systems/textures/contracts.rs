@@ -10,6 +10,7 @@ use super::loader::{
     AnimationDescriptor, LevelSwitchState, LevelTextureSet, ObjectClass, TextureManifest,
     TypeVariantDefinition, VisualAssetProfile,
 };
+use crate::systems::brick_shapes::BrickShape;
 
 /// Serializable view of the runtime texture manifest that matches the
 /// `/visual-assets/manifest` contract.
@@ -123,6 +124,8 @@ pub struct TypeVariantContract {
     pub emissive_color: Option<[f32; 4]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub animation: Option<AnimationDescriptorContract>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shape: Option<BrickShape>,
 }
 
 impl From<&TypeVariantDefinition> for TypeVariantContract {
@@ -136,6 +139,7 @@ impl From<&TypeVariantDefinition> for TypeVariantContract {
                 .animation
                 .as_ref()
                 .map(AnimationDescriptorContract::from),
+            shape: (variant.shape != BrickShape::Cuboid).then_some(variant.shape),
         }
     }
 }
@@ -12,9 +12,12 @@ use tracing::{debug, info, warn};
 
 use super::contracts::PreviewVisualAsset;
 use super::materials::TextureMaterialsPlugin;
+use super::models::ObjectModelsPlugin;
 use super::overrides::LevelOverridesPlugin;
+use crate::systems::brick_shapes::BrickShape;
 
-const TEXTURE_MANIFEST_PATH: &str = "textures/manifest.ron";
+/// Texture manifest, relative to `assets/`.
+pub const TEXTURE_MANIFEST_PATH: &str = "textures/manifest.ron";
 
 /// Plugin responsible for loading the texture manifest and related resources.
 pub struct TextureManifestPlugin;
@@ -23,6 +26,7 @@ impl Plugin for TextureManifestPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(TextureMaterialsPlugin);
         app.add_plugins(LevelOverridesPlugin);
+        app.add_plugins(ObjectModelsPlugin);
         app.init_asset::<RawTextureManifest>();
         app.register_asset_loader(TextureManifestLoader);
         app.init_resource::<TextureManifest>();
@@ -46,6 +50,7 @@ pub struct TextureManifest {
     pub type_variants: Vec<TypeVariantDefinition>,
     pub level_overrides: HashMap<u32, LevelTextureSet>,
     pub level_switch: Option<LevelSwitchState>,
+    pub models: ObjectModels,
 }
 
 impl TextureManifest {
@@ -61,6 +66,7 @@ impl TextureManifest {
             type_variants,
             level_overrides,
             level_switch,
+            models,
         } = raw;
         self.profiles = profiles
             .into_iter()
@@ -72,14 +78,47 @@ impl TextureManifest {
             .map(|set| (set.level_number, set))
             .collect();
         self.level_switch = level_switch;
+        self.models = models;
+    }
+
+    /// Layer a theme pack's manifest over this one: its profiles and type variants replace those
+    /// with the same id (object class and type id), its level overrides replace the manifest's for
+    /// the same level, and its models replace the manifest's where set.
+    pub fn layer(&mut self, theme: RawTextureManifest) {
+        for profile in theme.profiles {
+            self.profiles.insert(profile.id.clone(), profile);
+        }
+        for variant in theme.type_variants {
+            self.type_variants.retain(|existing| {
+                (existing.object_class, existing.type_id) != (variant.object_class, variant.type_id)
+            });
+            self.type_variants.push(variant);
+        }
+        for set in theme.level_overrides {
+            self.level_overrides.insert(set.level_number, set);
+        }
+        if theme.level_switch.is_some() {
+            self.level_switch = theme.level_switch;
+        }
+        if theme.models.paddle.is_some() {
+            self.models.paddle = theme.models.paddle;
+        }
+        if theme.models.ball.is_some() {
+            self.models.ball = theme.models.ball;
+        }
     }
 }
 
 #[derive(Resource, Clone)]
 struct TextureManifestHandle(pub Handle<RawTextureManifest>);
 
+/// Manifest of the active theme pack, layered over the texture manifest once both are loaded.
+#[derive(Resource, Clone)]
+pub struct ThemeManifestHandle(pub Handle<RawTextureManifest>);
+
 #[derive(Asset, TypePath, Debug, Clone, Deserialize)]
 pub struct RawTextureManifest {
+    #[serde(default)]
     pub profiles: Vec<VisualAssetProfile>,
     #[serde(default)]
     pub type_variants: Vec<TypeVariantDefinition>,
@@ -87,6 +126,8 @@ pub struct RawTextureManifest {
     pub level_overrides: Vec<LevelTextureSet>,
     #[serde(default)]
     pub level_switch: Option<LevelSwitchState>,
+    #[serde(default)]
+    pub models: ObjectModels,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -158,6 +199,33 @@ pub struct TypeVariantDefinition {
     pub emissive_color: Option<Color>,
     #[serde(default)]
     pub animation: Option<AnimationDescriptor>,
+    /// Mesh and collider shape of brick types; cuboid if omitted.
+    #[serde(default)]
+    pub shape: BrickShape,
+}
+
+/// glTF scenes drawn in place of the paddle and ball primitives.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct ObjectModels {
+    #[serde(default)]
+    pub paddle: Option<ModelDefinition>,
+    #[serde(default)]
+    pub ball: Option<ModelDefinition>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ModelDefinition {
+    /// Scene path relative to `assets/`; the first scene of the file if no `#Scene<n>` label
+    pub scene_path: String,
+    #[serde(default = "default_model_scale")]
+    pub scale: f32,
+    /// Offset from the physics entity, in its local space
+    #[serde(default)]
+    pub offset: Vec3,
+}
+
+fn default_model_scale() -> f32 {
+    1.0
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -260,6 +328,7 @@ fn load_texture_manifest(asset_server: Res<AssetServer>, mut commands: Commands)
 
 fn hydrate_manifest_resource(
     handle: Option<Res<TextureManifestHandle>>,
+    theme: Option<Res<ThemeManifestHandle>>,
     assets: Res<Assets<RawTextureManifest>>,
     mut manifest: ResMut<TextureManifest>,
     mut events: MessageReader<AssetEvent<RawTextureManifest>>,
@@ -270,26 +339,33 @@ fn hydrate_manifest_resource(
     };
 
     let asset_id = handle.0.id();
+    let theme_id = theme.as_ref().map(|theme| theme.0.id());
     let mut dirty = !*ready_once && assets.get(&handle.0).is_some();
 
     for event in events.read() {
-        if event.is_added(asset_id)
-            || event.is_modified(asset_id)
-            || event.is_loaded_with_dependencies(asset_id)
-        {
-            dirty = true;
+        for id in std::iter::once(asset_id).chain(theme_id) {
+            if event.is_added(id) || event.is_modified(id) || event.is_loaded_with_dependencies(id)
+            {
+                dirty = true;
+            }
         }
     }
 
     if dirty {
         if let Some(raw) = assets.get(&handle.0) {
             manifest.replace_with(raw.clone());
+            let theme_raw = theme.as_ref().and_then(|theme| assets.get(&theme.0));
+            let themed = theme_raw.is_some();
+            if let Some(theme_raw) = theme_raw {
+                manifest.layer(theme_raw.clone());
+            }
             *ready_once = true;
             info!(
                 target: "textures::manifest",
                 profiles = manifest.profiles.len(),
                 type_variants = manifest.type_variants.len(),
                 overrides = manifest.level_overrides.len(),
+                themed,
                 "Texture manifest hydrated"
             );
         }
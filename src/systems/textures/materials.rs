@@ -20,6 +20,7 @@ use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use tracing::{debug, info, warn};
 
 use super::loader::{TextureManifest, VisualAssetProfile};
+use crate::systems::brick_shapes::BrickShape;
 use crate::{Ball, BallTypeId};
 
 /// System set organization for texture overrides.
@@ -256,6 +257,7 @@ impl ProfileMaterialBank {
 #[derive(Resource, Default, Debug)]
 pub struct TypeVariantRegistry {
     map: HashMap<(ObjectClass, u8), Handle<StandardMaterial>>,
+    shapes: HashMap<u8, BrickShape>,
 }
 
 impl TypeVariantRegistry {
@@ -268,7 +270,11 @@ impl TypeVariantRegistry {
         mut materials: Option<&mut Assets<StandardMaterial>>,
     ) {
         self.map.clear();
+        self.shapes.clear();
         for variant in manifest.type_variants.iter() {
+            if variant.object_class == ObjectClass::Brick && variant.shape != BrickShape::Cuboid {
+                self.shapes.insert(variant.type_id, variant.shape);
+            }
             let profile_id = variant.profile_id.as_str();
 
             // Look up the profile from the manifest to create a variant-specific material
@@ -301,6 +307,11 @@ impl TypeVariantRegistry {
         self.map.get(&(class, type_id)).cloned()
     }
 
+    /// Shape of a brick type, [`BrickShape::Cuboid`] unless its variant names another.
+    pub fn shape(&self, type_id: u8) -> BrickShape {
+        self.shapes.get(&type_id).copied().unwrap_or_default()
+    }
+
     pub fn insert_for_tests(
         &mut self,
         class: ObjectClass,
@@ -309,6 +320,10 @@ impl TypeVariantRegistry {
     ) {
         self.map.insert((class, type_id), handle);
     }
+
+    pub fn insert_shape_for_tests(&mut self, type_id: u8, shape: BrickShape) {
+        self.shapes.insert(type_id, shape);
+    }
 }
 
 #[derive(Resource, Default)]
@@ -551,7 +566,7 @@ fn bake_material(
     materials.add(make_material(profile, asset_server, None))
 }
 
-fn manifest_asset_path(relative: &str) -> String {
+pub(crate) fn manifest_asset_path(relative: &str) -> String {
     if relative.starts_with("textures/") {
         relative.to_string()
     } else {
@@ -6,16 +6,18 @@
 pub mod contracts;
 pub mod loader;
 pub mod materials;
+pub mod models;
 pub mod overrides;
 
 pub use contracts::{PreviewProfileInput, PreviewVisualAsset, TextureManifestContract};
 pub use loader::{
-    LevelSwitchState, LevelTextureSet, ObjectClass, TextureManifest, TextureManifestPlugin,
-    TypeVariantDefinition, VisualAssetProfile,
+    LevelSwitchState, LevelTextureSet, ModelDefinition, ObjectClass, ObjectModels, TextureManifest,
+    TextureManifestPlugin, TypeVariantDefinition, VisualAssetProfile,
 };
 pub use materials::{
     baseline_material_handle, brick_type_material_handle, BaselineMaterialKind,
     CanonicalMaterialHandles, FallbackMaterial, FallbackRegistry, ProfileMaterialBank,
     TextureMaterialsPlugin, TypeVariantRegistry,
 };
+pub use models::{ObjectModel, ObjectModelsPlugin};
 pub use overrides::{LevelOverridesPlugin, LevelPresentation};
@@ -0,0 +1,184 @@
+//! glTF models for the paddle and ball.
+//!
+//! The texture manifest may name a glTF scene for the paddle and the ball:
+//!
+//! ```ron
+//! models: (
+//!     paddle: Some((scene_path: "models/paddle.glb", scale: 0.5)),
+//!     ball: Some((scene_path: "models/ball.glb#Scene1", offset: (0.0, 0.0, 0.0))),
+//! ),
+//! ```
+//!
+//! The scene is spawned as a child of the physics entity, so it follows its movement, rotation
+//! and paddle size effects while colliders and gameplay stay untouched. The primitive mesh is hidden
+//! while a model is attached and comes back when the manifest drops the model or the scene fails
+//! to load.
+
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use tracing::warn;
+
+use super::loader::{ModelDefinition, TextureManifest};
+use crate::{Ball, Paddle};
+
+/// Plugin attaching manifest models to the paddle and balls.
+pub struct ObjectModelsPlugin;
+
+impl Plugin for ObjectModelsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                attach_object_models,
+                fall_back_to_primitives,
+                hide_primitive_meshes,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Model attached to a paddle or ball.
+#[derive(Component, Debug, Clone)]
+pub struct ObjectModel {
+    pub definition: ModelDefinition,
+    pub scene: Handle<Scene>,
+    /// Child entity drawing the scene; `None` once the scene failed to load
+    pub root: Option<Entity>,
+}
+
+/// Primitive mesh kept aside while a model is drawn instead.
+#[derive(Component, Debug, Clone)]
+pub struct PrimitiveMesh(pub Handle<Mesh>);
+
+/// Asset path of a model's scene, defaulting to the first scene of the file.
+pub fn model_scene_path(definition: &ModelDefinition) -> String {
+    if definition.scene_path.contains('#') {
+        definition.scene_path.clone()
+    } else {
+        format!("{}#Scene0", definition.scene_path)
+    }
+}
+
+/// Attach the manifest's models to new paddles and balls, and swap them on all of them when the
+/// manifest changes.
+fn attach_object_models(
+    mut commands: Commands,
+    manifest: Option<Res<TextureManifest>>,
+    asset_server: Option<Res<AssetServer>>,
+    objects: Query<
+        (
+            Entity,
+            Has<Paddle>,
+            Option<&ObjectModel>,
+            Option<&PrimitiveMesh>,
+        ),
+        Or<(Added<Paddle>, Added<Ball>)>,
+    >,
+    all_objects: Query<
+        (
+            Entity,
+            Has<Paddle>,
+            Option<&ObjectModel>,
+            Option<&PrimitiveMesh>,
+        ),
+        Or<(With<Paddle>, With<Ball>)>,
+    >,
+) {
+    let (Some(manifest), Some(asset_server)) = (manifest, asset_server) else {
+        return;
+    };
+    let targets: Vec<_> = if manifest.is_changed() {
+        all_objects.iter().collect()
+    } else {
+        objects.iter().collect()
+    };
+    for (entity, is_paddle, current, primitive) in targets {
+        let wanted = if is_paddle {
+            manifest.models.paddle.as_ref()
+        } else {
+            manifest.models.ball.as_ref()
+        };
+        if current.map(|model| &model.definition) == wanted {
+            continue;
+        }
+        if let Some(current) = current {
+            if let Some(root) = current.root {
+                commands.entity(root).despawn();
+            }
+            commands.entity(entity).remove::<ObjectModel>();
+        }
+        if let Some(primitive) = primitive {
+            commands
+                .entity(entity)
+                .insert(Mesh3d(primitive.0.clone()))
+                .remove::<PrimitiveMesh>();
+        }
+        let Some(definition) = wanted else {
+            continue;
+        };
+        let scene: Handle<Scene> = asset_server.load(model_scene_path(definition));
+        let root = commands
+            .spawn((
+                SceneRoot(scene.clone()),
+                Transform::from_translation(definition.offset)
+                    .with_scale(Vec3::splat(definition.scale)),
+                ChildOf(entity),
+            ))
+            .id();
+        commands.entity(entity).insert(ObjectModel {
+            definition: definition.clone(),
+            scene,
+            root: Some(root),
+        });
+    }
+}
+
+/// Show the primitive again for models whose scene failed to load.
+fn fall_back_to_primitives(
+    mut commands: Commands,
+    asset_server: Option<Res<AssetServer>>,
+    mut models: Query<(Entity, &mut ObjectModel, Option<&PrimitiveMesh>)>,
+) {
+    let Some(asset_server) = asset_server else {
+        return;
+    };
+    for (entity, mut model, primitive) in models.iter_mut() {
+        let Some(root) = model.root else {
+            continue;
+        };
+        if !matches!(
+            asset_server.get_load_state(&model.scene),
+            Some(LoadState::Failed(_))
+        ) {
+            continue;
+        }
+        warn!(
+            target: "textures::models",
+            scene = %model.definition.scene_path,
+            "model failed to load; drawing the primitive instead"
+        );
+        commands.entity(root).despawn();
+        model.root = None;
+        if let Some(primitive) = primitive {
+            commands
+                .entity(entity)
+                .insert(Mesh3d(primitive.0.clone()))
+                .remove::<PrimitiveMesh>();
+        }
+    }
+}
+
+/// Keep the primitive mesh of modelled objects aside, including meshes swapped in later (e.g. by
+/// ball size effects).
+fn hide_primitive_meshes(mut commands: Commands, objects: Query<(Entity, &Mesh3d, &ObjectModel)>) {
+    for (entity, mesh, model) in objects.iter() {
+        if model.root.is_none() {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(PrimitiveMesh(mesh.0.clone()))
+            .remove::<Mesh3d>();
+    }
+}
@@ -0,0 +1,300 @@
+//! Theme packs: seasonal or holiday looks on top of the regular visuals.
+//!
+//! Packs are listed in `assets/themes/themes.ron`:
+//!
+//! ```ron
+//! (
+//!     packs: [
+//!         (
+//!             id: "winter",
+//!             name: "Winter",
+//!             texture_manifest: Some("themes/winter/manifest.ron"),
+//!             music: Some("themes/winter/music.ogg"),
+//!             sound_pack: Some("winter"),
+//!             particle_color: Some(Srgba((red: 0.85, green: 0.95, blue: 1.0, alpha: 1.0))),
+//!             dates: Some((from: (12, 1), to: (1, 6))),
+//!         ),
+//!     ],
+//! )
+//! ```
+//!
+//! One pack is active per session, chosen at startup: the pack named by `--theme ID` (`?theme=`
+//! in the browser), none for `--theme off`, otherwise the first pack whose `dates` (month, day;
+//! inclusive, may wrap the new year) include today. The browser build has no calendar and only
+//! uses packs chosen by name.
+//!
+//! The active pack
+//! - layers its texture manifest (a regular `manifest.ron`, paths relative to `assets/`) over
+//!   `assets/textures/manifest.ron`. Precedence, highest first: a level file's own `presentation`,
+//!   the pack's `level_overrides` for the level, the base manifest's `level_overrides`; the pack's
+//!   profiles and type variants replace base ones with the same id.
+//! - loops its music on the music bus (master x music volume, ducked by the mixer).
+//! - plays its sound pack (an id from `assets/audio/packs.ron`, see `systems::sound_packs`)
+//!   instead of the one chosen in the settings.
+//! - paints brick debris in its particle colour.
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::launch::LaunchOptions;
+use crate::systems::audio::{AudioBus, AudioConfig, AudioMixer};
+use crate::systems::brick_debris::BrickDebrisConfig;
+use crate::systems::textures::loader::ThemeManifestHandle;
+
+/// Path of the theme pack list.
+pub const THEME_PACKS_PATH: &str = "assets/themes/themes.ron";
+/// `--theme` value turning theme packs off.
+pub const THEME_OFF: &str = "off";
+
+/// Days of the year a pack is active on, as (month, day) pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct DateWindow {
+    pub from: (u32, u32),
+    /// Last day, inclusive; before `from` for windows spanning the new year
+    pub to: (u32, u32),
+}
+
+impl DateWindow {
+    /// Whether the (month, day) `date` lies in the window.
+    pub fn contains(&self, date: (u32, u32)) -> bool {
+        if self.from <= self.to {
+            self.from <= date && date <= self.to
+        } else {
+            date >= self.from || date <= self.to
+        }
+    }
+}
+
+/// A theme pack.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ThemePack {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// Texture manifest layered over the base one, relative to `assets/`
+    #[serde(default)]
+    pub texture_manifest: Option<String>,
+    /// Looping music, relative to `assets/`
+    #[serde(default)]
+    pub music: Option<String>,
+    /// Sound pack played instead of the configured one
+    #[serde(default)]
+    pub sound_pack: Option<String>,
+    /// Colour of brick debris
+    #[serde(default)]
+    pub particle_color: Option<Color>,
+    /// Days the pack is picked on automatically
+    #[serde(default)]
+    pub dates: Option<DateWindow>,
+}
+
+/// Theme packs available to the game.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ThemePacks {
+    #[serde(default)]
+    pub packs: Vec<ThemePack>,
+}
+
+impl ThemePacks {
+    /// Pack for a `--theme` `choice` on `today` (month, day): the named pack, none for
+    /// [`THEME_OFF`], otherwise the first pack whose dates include today.
+    pub fn select(&self, choice: Option<&str>, today: Option<(u32, u32)>) -> Option<&ThemePack> {
+        match choice {
+            Some(THEME_OFF) => None,
+            Some(id) => self.packs.iter().find(|pack| pack.id == id),
+            None => {
+                let today = today?;
+                self.packs
+                    .iter()
+                    .find(|pack| pack.dates.is_some_and(|dates| dates.contains(today)))
+            }
+        }
+    }
+}
+
+/// The theme pack of this session, if any.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct ActiveTheme(pub Option<ThemePack>);
+
+/// Marker for the theme's music player.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ThemeMusic;
+
+/// Today's (month, day) in UTC, or `None` without a system clock (browser build).
+pub fn today() -> Option<(u32, u32)> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs();
+        let (_, month, day) = civil_from_days((secs / 86_400) as i64);
+        Some((month, day))
+    }
+    #[cfg(target_arch = "wasm32")]
+    None
+}
+
+/// (year, month, day) of a count of days since 1970-01-01 (proleptic Gregorian calendar).
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Read the theme pack list into [`ThemePacks`].
+pub fn load_theme_packs(mut commands: Commands) {
+    #[cfg(not(target_arch = "wasm32"))]
+    let content = std::fs::read_to_string(THEME_PACKS_PATH).ok();
+    #[cfg(target_arch = "wasm32")]
+    let content = Some(include_str!("../../assets/themes/themes.ron").to_string());
+
+    let packs = match content.map(|c| ron::de::from_str::<ThemePacks>(&c)) {
+        Some(Ok(packs)) => {
+            info!(
+                "Loaded {} theme packs from {}",
+                packs.packs.len(),
+                THEME_PACKS_PATH
+            );
+            packs
+        }
+        Some(Err(e)) => {
+            warn!("Failed to parse theme packs: {}; playing without themes", e);
+            ThemePacks::default()
+        }
+        None => ThemePacks::default(),
+    };
+    commands.insert_resource(packs);
+}
+
+/// Pick the session's theme pack from the launch options and the date.
+pub fn select_theme_pack(
+    packs: Res<ThemePacks>,
+    launch: Option<Res<LaunchOptions>>,
+    mut active: ResMut<ActiveTheme>,
+) {
+    let choice = launch.as_ref().and_then(|launch| launch.theme.as_deref());
+    let pack = packs.select(choice, today()).cloned();
+    match (&pack, choice) {
+        (Some(pack), _) => info!("Using theme pack '{}'", pack.id),
+        (None, Some(id)) if id != THEME_OFF => {
+            warn!("Unknown theme pack '{}'; playing without themes", id)
+        }
+        _ => {}
+    }
+    active.0 = pack;
+}
+
+/// Apply the active pack: texture manifest layer, music and debris colour.
+pub fn apply_theme_pack(
+    mut commands: Commands,
+    active: Res<ActiveTheme>,
+    asset_server: Option<Res<AssetServer>>,
+    audio_sources: Option<Res<Assets<AudioSource>>>,
+    audio_config: Option<Res<AudioConfig>>,
+    mixer: Option<Res<AudioMixer>>,
+    debris: Option<ResMut<BrickDebrisConfig>>,
+    music: Query<Entity, With<ThemeMusic>>,
+) {
+    let pack = active.0.as_ref();
+    if let Some(mut debris) = debris {
+        debris.color = pack.and_then(|pack| pack.particle_color);
+    }
+    for entity in music.iter() {
+        commands.entity(entity).despawn();
+    }
+    commands.remove_resource::<ThemeManifestHandle>();
+    let (Some(pack), Some(asset_server)) = (pack, asset_server) else {
+        return;
+    };
+    if let Some(path) = &pack.texture_manifest {
+        commands.insert_resource(ThemeManifestHandle(asset_server.load(path)));
+    }
+    if let (Some(path), Some(_)) = (&pack.music, audio_sources) {
+        commands.spawn((
+            AudioPlayer::new(asset_server.load(path)),
+            PlaybackSettings::LOOP
+                .with_volume(music_volume(audio_config.as_deref(), mixer.as_deref())),
+            ThemeMusic,
+        ));
+    }
+}
+
+fn music_volume(config: Option<&AudioConfig>, mixer: Option<&AudioMixer>) -> Volume {
+    let volume = config.map_or(1.0, |config| config.bus_volume(AudioBus::Music));
+    let ducking = mixer.map_or(1.0, |mixer| mixer.gain(AudioBus::Music));
+    Volume::Linear(volume * ducking)
+}
+
+/// Follow volume, mute and ducking changes with the theme music.
+pub fn sync_theme_music_volume(
+    audio_config: Option<Res<AudioConfig>>,
+    mixer: Option<Res<AudioMixer>>,
+    mut sinks: Query<&mut AudioSink, With<ThemeMusic>>,
+) {
+    let config_changed = audio_config
+        .as_ref()
+        .is_some_and(|config| config.is_changed());
+    let mixer_changed = mixer.as_ref().is_some_and(|mixer| mixer.is_changed());
+    if !config_changed && !mixer_changed {
+        return;
+    }
+    for mut sink in sinks.iter_mut() {
+        sink.set_volume(music_volume(audio_config.as_deref(), mixer.as_deref()));
+    }
+}
+
+/// Plugin registering theme packs.
+pub struct ThemesPlugin;
+
+impl Plugin for ThemesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ThemePacks>();
+        app.init_resource::<ActiveTheme>();
+        app.add_systems(Startup, (load_theme_packs, select_theme_pack).chain());
+        app.add_systems(
+            Update,
+            (
+                apply_theme_pack.run_if(resource_changed::<ActiveTheme>),
+                sync_theme_music_volume,
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_convert_to_calendar_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(20_453), (2025, 12, 31));
+    }
+
+    #[test]
+    fn date_windows_may_wrap_the_new_year() {
+        let winter = DateWindow {
+            from: (12, 1),
+            to: (1, 6),
+        };
+        assert!(winter.contains((12, 24)) && winter.contains((1, 6)));
+        assert!(!winter.contains((1, 7)) && !winter.contains((11, 30)));
+
+        let spring = DateWindow {
+            from: (3, 20),
+            to: (4, 20),
+        };
+        assert!(spring.contains((4, 1)));
+        assert!(!spring.contains((12, 24)));
+    }
+}
@@ -0,0 +1,170 @@
+//! Timed powerup effects, handled uniformly.
+//!
+//! Every effect that runs out after a while (paddle size, reversed controls, rubber paddle, sticky
+//! paddle, ball size, fast ball, wrecking ball) implements [`TimedEffect`]: it names its
+//! [`PowerupKind`] and exposes its timer. Code that does not care which effect it looks at works through the trait:
+//! - [`tick_timed_effects`] counts the timers down. Each effect's plugin runs it with
+//!   `not_paused`, so the time left freezes while the game is paused.
+//! - [`ActiveEffects`] lists the running effects as [`EffectSnapshot`]s, for the effects HUD
+//!   (`ui::effects_hud`) and the savegame (`systems::autosave`).
+//! - [`restore_effect_timers`] puts the saved time left back on effects restored from a savegame.
+//!
+//! A new timed effect implements the trait, adds itself to [`ActiveEffects::snapshots`], and
+//! registers the generic systems for its component.
+
+use std::time::Duration;
+
+use bevy::ecs::component::Mutable;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::systems::ball_size::BallSizeEffect;
+use crate::systems::paddle_size::PaddleSizeEffect;
+use crate::systems::powerups::{FastBall, PowerupKind, ReversedControls, RubberPaddle};
+use crate::systems::sticky_paddle::StickyPaddle;
+use crate::systems::wrecking_ball::WreckingBall;
+use crate::{Ball, Paddle};
+
+/// A component holding an effect that ends when its timer finishes.
+pub trait TimedEffect: Component<Mutability = Mutable> {
+    /// The capsule this effect comes from.
+    fn kind(&self) -> PowerupKind;
+
+    fn timer(&self) -> &Timer;
+
+    fn timer_mut(&mut self) -> &mut Timer;
+
+    /// Seconds until the effect ends.
+    fn remaining_secs(&self) -> f32 {
+        self.timer().remaining_secs()
+    }
+
+    fn snapshot(&self) -> EffectSnapshot {
+        EffectSnapshot {
+            kind: self.kind(),
+            remaining_secs: self.remaining_secs(),
+        }
+    }
+}
+
+/// A running effect and its time left, as shown in the HUD and stored in savegames.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EffectSnapshot {
+    pub kind: PowerupKind,
+    pub remaining_secs: f32,
+}
+
+/// Read access to the timed effects of the paddle and the ball.
+#[derive(SystemParam)]
+pub struct ActiveEffects<'w, 's> {
+    paddles: Query<
+        'w,
+        's,
+        (
+            Option<&'static PaddleSizeEffect>,
+            Option<&'static ReversedControls>,
+            Option<&'static RubberPaddle>,
+            Option<&'static StickyPaddle>,
+        ),
+        With<Paddle>,
+    >,
+    balls: Query<
+        'w,
+        's,
+        (
+            Option<&'static BallSizeEffect>,
+            Option<&'static FastBall>,
+            Option<&'static WreckingBall>,
+        ),
+        With<Ball>,
+    >,
+}
+
+impl ActiveEffects<'_, '_> {
+    /// The running effects: the paddle's first, then the ball's.
+    pub fn snapshots(&self) -> Vec<EffectSnapshot> {
+        let mut effects = Vec::new();
+        if let Some((size, reversed, rubber, sticky)) = self.paddles.iter().next() {
+            effects.extend(size.map(TimedEffect::snapshot));
+            effects.extend(reversed.map(TimedEffect::snapshot));
+            effects.extend(rubber.map(TimedEffect::snapshot));
+            effects.extend(sticky.map(TimedEffect::snapshot));
+        }
+        if let Some((size, fast, wrecking)) = self.balls.iter().next() {
+            effects.extend(size.map(TimedEffect::snapshot));
+            effects.extend(fast.map(TimedEffect::snapshot));
+            effects.extend(wrecking.map(TimedEffect::snapshot));
+        }
+        effects
+    }
+}
+
+/// Time left of effects being restored from a savegame, applied as their components are added.
+#[derive(Resource, Debug, Default)]
+pub struct RestoredEffects(pub Vec<EffectSnapshot>);
+
+/// Count down the timers of effect `T`.
+pub fn tick_timed_effects<T: TimedEffect>(mut effects: Query<&mut T>, time: Res<Time>) {
+    for mut effect in effects.iter_mut() {
+        effect.timer_mut().tick(time.delta());
+    }
+}
+
+/// Remove finished effects `T` that need no clean-up beyond the component itself.
+pub fn remove_finished_effects<T: TimedEffect>(
+    mut commands: Commands,
+    effects: Query<(Entity, &T)>,
+) {
+    for (entity, effect) in effects.iter() {
+        if effect.timer().is_finished() {
+            commands.entity(entity).remove::<T>();
+        }
+    }
+}
+
+/// Give newly added effects `T` the time left saved for their kind.
+pub fn restore_effect_timers<T: TimedEffect>(
+    restored: Res<RestoredEffects>,
+    mut effects: Query<&mut T, Added<T>>,
+) {
+    if restored.0.is_empty() {
+        return;
+    }
+    for mut effect in effects.iter_mut() {
+        let kind = effect.kind();
+        if let Some(saved) = restored.0.iter().find(|saved| saved.kind == kind) {
+            let timer = effect.timer_mut();
+            timer.set_duration(Duration::from_secs_f32(saved.remaining_secs.max(0.0)));
+            timer.reset();
+        }
+    }
+}
+
+/// Forget the restored time left once the effects have picked it up.
+pub fn clear_restored_effects(mut restored: ResMut<RestoredEffects>) {
+    if !restored.0.is_empty() {
+        restored.0.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::paddle_size::SizeEffectType;
+
+    #[test]
+    fn snapshots_name_the_capsule_and_the_time_left() {
+        let mut effect = PaddleSizeEffect::new(SizeEffectType::Shrink);
+        effect.timer_mut().tick(Duration::from_secs(4));
+        let snapshot = effect.snapshot();
+        assert_eq!(snapshot.kind, PowerupKind::ShrinkPaddle);
+        assert!((snapshot.remaining_secs - 6.0).abs() < 1e-4);
+
+        let text = ron::ser::to_string(&snapshot).unwrap();
+        assert_eq!(
+            ron::de::from_str::<EffectSnapshot>(&text).unwrap(),
+            snapshot
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Tutorial levels with scripted prompts.
+//!
+//! A level file can script a tutorial: prompts shown one at a time (`ui::tutorial_prompt`), each
+//! waiting until the player has actually done what it asks:
+//!
+//! ```ron
+//! tutorial: [
+//!     (prompt: "Move the mouse to slide the paddle", until: MovePaddle),
+//!     (prompt: "Scroll the mouse wheel to turn the paddle", until: RotatePaddle),
+//!     (prompt: "Catch a falling capsule with the paddle", until: CatchPowerup),
+//! ],
+//! ```
+//!
+//! The level cannot be completed before its last step is done (`systems::completion`). Restarts of
+//! the same level keep the steps already done; should the counted bricks run out with steps still
+//! to do, the level starts over so the player can keep trying.
+//!
+//! The bundled tutorial is levels [`TUTORIAL_FIRST_LEVEL`] to 903, which lead into level 1 through
+//! the campaign manifest. It is played on first run: when the game starts at level 1 without a
+//! level chosen at launch, saved progress or a completed level, unless `skip_tutorial: true` is set
+//! in `config/gameplay.ron`.
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::gameplay_config::GameplayConfig;
+use crate::launch::LaunchOptions;
+use crate::level_loader::{CurrentLevel, LevelAdvanceState, RestartRequested};
+use crate::systems::autosave::{resume_saved_progress, AutosaveState};
+use crate::systems::level_switch::{
+    LevelSwitchDirection, LevelSwitchRequested, LevelSwitchSource, LevelSwitchState,
+};
+use crate::systems::powerups::PowerupCollected;
+use crate::systems::profiles::{CurrentProfile, ProfileData};
+use crate::ui::toasts::ShowToast;
+use crate::{Brick, CountsTowardsCompletion, Paddle};
+
+/// First level of the bundled tutorial.
+pub const TUTORIAL_FIRST_LEVEL: u32 = 901;
+/// Distance the paddle has to travel for [`TutorialGoal::MovePaddle`].
+pub const TUTORIAL_MOVE_DISTANCE: f32 = 2.0;
+/// Angle (degrees) the paddle has to turn for [`TutorialGoal::RotatePaddle`].
+pub const TUTORIAL_ROTATE_DEG: f32 = 15.0;
+
+/// What the player has to do to finish a tutorial step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TutorialGoal {
+    /// Slide the paddle by [`TUTORIAL_MOVE_DISTANCE`].
+    MovePaddle,
+    /// Turn the paddle by [`TUTORIAL_ROTATE_DEG`], the angle that gives the ball english.
+    RotatePaddle,
+    /// Catch a powerup capsule.
+    CatchPowerup,
+}
+
+/// One scripted prompt of a tutorial level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TutorialStep {
+    pub prompt: String,
+    pub until: TutorialGoal,
+}
+
+/// Tutorial steps of the current level and how far the player got.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct TutorialState {
+    /// Level the progress belongs to
+    pub level_number: Option<u32>,
+    pub steps: Vec<TutorialStep>,
+    /// Steps done so far
+    pub done: usize,
+    /// Paddle placement when the current step started
+    pub paddle_start: Option<Transform>,
+    /// The level was restarted for running out of bricks and has not reloaded yet
+    pub restarting: bool,
+}
+
+impl TutorialState {
+    /// The step being played, if any.
+    pub fn current(&self) -> Option<&TutorialStep> {
+        self.steps.get(self.done)
+    }
+
+    /// Steps still to do.
+    pub fn pending(&self) -> usize {
+        self.steps.len().saturating_sub(self.done)
+    }
+
+    /// Move on to the next step.
+    pub fn finish_step(&mut self) {
+        self.done += 1;
+        self.paddle_start = None;
+    }
+}
+
+/// Whether the paddle moved from `start` to `now` far enough for `goal`.
+pub fn paddle_goal_reached(goal: TutorialGoal, start: &Transform, now: &Transform) -> bool {
+    match goal {
+        TutorialGoal::MovePaddle => {
+            start.translation.distance(now.translation) >= TUTORIAL_MOVE_DISTANCE
+        }
+        TutorialGoal::RotatePaddle => {
+            start.rotation.angle_between(now.rotation) >= TUTORIAL_ROTATE_DEG.to_radians()
+        }
+        TutorialGoal::CatchPowerup => false,
+    }
+}
+
+/// Pick up the steps of a newly loaded level; restarts keep the progress.
+pub fn load_tutorial(current_level: Option<Res<CurrentLevel>>, mut state: ResMut<TutorialState>) {
+    let Some(level) = current_level.filter(|level| level.is_changed()) else {
+        return;
+    };
+    if state.level_number != Some(level.0.number) {
+        *state = TutorialState {
+            level_number: Some(level.0.number),
+            ..default()
+        };
+    }
+    state.steps = level.0.tutorial.clone();
+    // The paddle was placed anew
+    state.paddle_start = None;
+    state.restarting = false;
+}
+
+/// Finish the current step once the player did what it asks.
+pub fn track_tutorial_goals(
+    mut state: ResMut<TutorialState>,
+    mut collected: MessageReader<PowerupCollected>,
+    paddles: Query<&Transform, With<Paddle>>,
+) {
+    let caught = collected.read().count() > 0;
+    let Some(goal) = state.current().map(|step| step.until) else {
+        return;
+    };
+    let reached = match goal {
+        TutorialGoal::CatchPowerup => caught,
+        TutorialGoal::MovePaddle | TutorialGoal::RotatePaddle => {
+            let Some(paddle) = paddles.iter().next() else {
+                return;
+            };
+            match state.paddle_start {
+                Some(start) => paddle_goal_reached(goal, &start, paddle),
+                None => {
+                    state.paddle_start = Some(*paddle);
+                    false
+                }
+            }
+        }
+    };
+    if reached {
+        state.finish_step();
+    }
+}
+
+/// Start a tutorial level over when its counted bricks ran out before the last step was done.
+pub fn restart_stuck_tutorial(
+    mut commands: Commands,
+    mut state: ResMut<TutorialState>,
+    counted: Query<(), (With<Brick>, With<CountsTowardsCompletion>)>,
+    level_advance: Option<Res<LevelAdvanceState>>,
+    mut restarts: MessageWriter<RestartRequested>,
+    mut had_bricks: Local<bool>,
+) {
+    if state.pending() == 0 || state.restarting {
+        *had_bricks = false;
+        return;
+    }
+    // Bricks are not on the field yet while a level is being set up
+    if !counted.is_empty() {
+        *had_bricks = true;
+        return;
+    }
+    if !*had_bricks || level_advance.is_some_and(|advance| advance.active) {
+        return;
+    }
+    *had_bricks = false;
+    state.restarting = true;
+    restarts.write(RestartRequested::default());
+    commands.trigger(ShowToast::new(
+        "Out of bricks before the tutorial step was done: the level starts over.",
+    ));
+}
+
+/// Whether the game should open with the tutorial: level 1 was loaded by default, and nothing was
+/// ever completed or saved.
+pub fn is_first_run(
+    current_level: u32,
+    level_chosen: bool,
+    autosave: Option<&AutosaveState>,
+    profile: Option<&ProfileData>,
+) -> bool {
+    current_level == 1
+        && !level_chosen
+        && autosave.is_none_or(|state| {
+            state.resume.is_none() && state.saved.level.is_none() && state.saved.highest_level == 0
+        })
+        && profile.is_none_or(|data| data.highest_level == 0 && data.stats.levels_completed == 0)
+}
+
+/// Switch to the tutorial on first run, checking once at startup and once per profile switch.
+#[allow(clippy::too_many_arguments)]
+pub fn start_tutorial_on_first_run(
+    current_profile: Option<Res<CurrentProfile>>,
+    current_level: Option<Res<CurrentLevel>>,
+    autosave: Option<Res<AutosaveState>>,
+    profile: Option<Res<ProfileData>>,
+    gameplay: Option<Res<GameplayConfig>>,
+    launch: Option<Res<LaunchOptions>>,
+    levels: Option<Res<LevelSwitchState>>,
+    mut switches: MessageWriter<LevelSwitchRequested>,
+    mut checked: Local<bool>,
+) {
+    if current_profile.is_some_and(|p| p.is_changed() && !p.is_added()) {
+        *checked = false;
+    }
+    if *checked {
+        return;
+    }
+    let Some(current_level) = current_level else {
+        return;
+    };
+    *checked = true;
+    if gameplay.is_some_and(|config| config.skip_tutorial)
+        || !levels.is_some_and(|levels| levels.level(TUTORIAL_FIRST_LEVEL).is_some())
+    {
+        return;
+    }
+    let level_chosen = launch.as_deref().map_or_else(
+        || LaunchOptions::from_env().level_chosen(),
+        LaunchOptions::level_chosen,
+    );
+    if !is_first_run(
+        current_level.0.number,
+        level_chosen,
+        autosave.as_deref(),
+        profile.as_deref(),
+    ) {
+        return;
+    }
+    info!("First run: starting the tutorial");
+    switches.write(LevelSwitchRequested {
+        source: LevelSwitchSource::Tutorial,
+        direction: LevelSwitchDirection::Jump(TUTORIAL_FIRST_LEVEL),
+    });
+}
+
+/// Plugin registering tutorial levels.
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TutorialState>();
+        app.add_message::<PowerupCollected>();
+        app.add_message::<RestartRequested>();
+        app.add_message::<LevelSwitchRequested>();
+        app.add_systems(
+            Update,
+            (load_tutorial, track_tutorial_goals, restart_stuck_tutorial).chain(),
+        );
+        app.add_systems(
+            Update,
+            start_tutorial_on_first_run.after(resume_saved_progress),
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! Brick waves spawned during play (survival-style levels).
+//!
+//! A level can bring in more bricks after it has started. Each wave is a brick matrix in the level
+//! format plus the trigger that brings it in:
+//!
+//! ```ron
+//! waves: [
+//!     (trigger: AfterSeconds(30.0), matrix: [[0, 0, 20, 20, 0], [0, 20, 20, 20, 0]]),
+//!     (trigger: BricksBelow(10), matrix: [[3, 3, 3, 3, 3]]),
+//! ],
+//! ```
+//!
+//! - `AfterSeconds(s)` fires once the level clock reads `s` seconds (time since the level
+//!   started, not since the previous wave).
+//! - `BricksBelow(n)` fires once fewer than `n` bricks that count towards completion are left.
+//!
+//! Waves come in one at a time, in the order they are listed; a wave's trigger is only checked
+//! once the wave before it has arrived. Clearing the field brings in the next wave straight away,
+//! whatever its trigger, and a level with waves still to come is never complete.
+//!
+//! Wave bricks go through the same spawning path as the level's own bricks
+//! (`level_loader::spawn_bricks_only`). Cells that already hold a brick, or are covered by a ball
+//! or the paddle, are left empty. Nothing is checked before the level clock starts, so bricks are
+//! never dropped into a level that is still fading in.
+//!
+//! Waves whose trigger is not a sensible number, or whose matrix holds no bricks, are skipped with
+//! a warning.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "texture_manifest")]
+use crate::level_loader::TextureResources;
+use crate::level_loader::{spawn_bricks_only, CurrentLevel, LevelAdvanceState};
+use crate::physics_config::BrickPhysicsConfig;
+use crate::systems::level_clock::LevelClock;
+use crate::{
+    Ball, Brick, BrickGridPos, CountsTowardsCompletion, GridResolution, MarkedForDespawn, Paddle,
+    BALL_RADIUS, PADDLE_HEIGHT, PADDLE_RADIUS,
+};
+
+/// What brings a wave in.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WaveTrigger {
+    /// Level clock reading in seconds.
+    AfterSeconds(f32),
+    /// Counted bricks left on the field, exclusive.
+    BricksBelow(usize),
+}
+
+impl WaveTrigger {
+    /// Whether the trigger fires at `elapsed` with `bricks_remaining` counted bricks left.
+    pub fn is_met(&self, elapsed: Duration, bricks_remaining: usize) -> bool {
+        match self {
+            WaveTrigger::AfterSeconds(secs) => elapsed.as_secs_f32() >= *secs,
+            WaveTrigger::BricksBelow(threshold) => bricks_remaining < *threshold,
+        }
+    }
+}
+
+/// A brick wave as authored in a level file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Wave {
+    pub trigger: WaveTrigger,
+    /// Bricks to add, in the level matrix format (rows along +X, columns along -Z).
+    pub matrix: Vec<Vec<u8>>,
+}
+
+impl Wave {
+    /// Check the trigger and that the matrix holds at least one brick.
+    pub fn validate(&self) -> Result<(), String> {
+        match self.trigger {
+            WaveTrigger::AfterSeconds(secs) if !(secs.is_finite() && secs >= 0.0) => {
+                return Err(format!("wave time must not be negative, got {}", secs));
+            }
+            WaveTrigger::BricksBelow(0) => {
+                return Err("wave brick threshold must be at least 1".to_string());
+            }
+            _ => {}
+        }
+        if !self.matrix.iter().flatten().any(|&value| value >= 3) {
+            return Err("wave matrix holds no bricks".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Waves of the current level and how many of them have arrived.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct WaveSchedule {
+    pub waves: Vec<Wave>,
+    /// Index of the next wave to arrive
+    pub next: usize,
+}
+
+impl WaveSchedule {
+    /// Schedule the valid waves of a level, skipping (and logging) invalid ones.
+    pub fn from_waves(waves: &[Wave]) -> Self {
+        let waves = waves
+            .iter()
+            .enumerate()
+            .filter(|(index, wave)| match wave.validate() {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Ignoring wave {}: {}", index + 1, e);
+                    false
+                }
+            })
+            .map(|(_, wave)| wave.clone())
+            .collect();
+        Self { waves, next: 0 }
+    }
+
+    /// Waves still to come.
+    pub fn pending(&self) -> usize {
+        self.waves.len() - self.next
+    }
+
+    /// The next wave, if it should arrive now.
+    pub fn due(&self, elapsed: Duration, bricks_remaining: usize) -> Option<&Wave> {
+        let wave = self.waves.get(self.next)?;
+        (bricks_remaining == 0 || wave.trigger.is_met(elapsed, bricks_remaining)).then_some(wave)
+    }
+}
+
+/// Cells of `grid` touched by an axis-aligned rectangle centred on `position` with half extents
+/// (`half_x`, `half_z`).
+pub fn covered_cells(
+    grid: &GridResolution,
+    position: Vec3,
+    half_x: f32,
+    half_z: f32,
+) -> Vec<(usize, usize)> {
+    // Sample no further apart than a cell, so cells in the middle of a long shape are not missed
+    let offsets = |half: f32, cell: f32| {
+        let steps = (2.0 * half / cell).ceil().max(1.0) as usize;
+        (0..=steps).map(move |i| -half + 2.0 * half * i as f32 / steps as f32)
+    };
+    let mut cells = Vec::new();
+    for dx in offsets(half_x, grid.cell_height()) {
+        for dz in offsets(half_z, grid.cell_width()) {
+            if let Some(cell) = grid.cell_at(position + Vec3::new(dx, 0.0, dz)) {
+                if !cells.contains(&cell) {
+                    cells.push(cell);
+                }
+            }
+        }
+    }
+    cells
+}
+
+/// A copy of `matrix` with the `blocked` cells emptied.
+pub fn free_cells_only(matrix: &[Vec<u8>], blocked: &HashSet<(usize, usize)>) -> Vec<Vec<u8>> {
+    matrix
+        .iter()
+        .enumerate()
+        .map(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .map(|(col, &value)| {
+                    if blocked.contains(&(row, col)) {
+                        0
+                    } else {
+                        value
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Schedule the waves of a newly loaded (or restarted) level.
+pub fn load_wave_schedule(current_level: Res<CurrentLevel>, mut schedule: ResMut<WaveSchedule>) {
+    *schedule = WaveSchedule::from_waves(&current_level.0.waves);
+}
+
+/// Spawn the next wave once its trigger fires.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_due_waves(
+    mut schedule: ResMut<WaveSchedule>,
+    clock: Option<Res<LevelClock>>,
+    level_advance: Option<Res<LevelAdvanceState>>,
+    counted: Query<
+        (),
+        (
+            With<Brick>,
+            With<CountsTowardsCompletion>,
+            Without<MarkedForDespawn>,
+        ),
+    >,
+    bricks: Query<&BrickGridPos, With<Brick>>,
+    balls: Query<&Transform, With<Ball>>,
+    paddles: Query<&Transform, With<Paddle>>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    #[cfg(feature = "texture_manifest")] mut tex_res: TextureResources,
+    brick_config: Res<BrickPhysicsConfig>,
+    grid: Option<Res<GridResolution>>,
+) {
+    let Some(clock) = clock else {
+        return;
+    };
+    if clock.stopped || clock.elapsed.is_zero() {
+        return;
+    }
+    if level_advance.is_some_and(|advance| advance.active) {
+        return;
+    }
+    let Some(wave) = schedule.due(clock.elapsed, counted.iter().count()) else {
+        return;
+    };
+
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    let mut blocked: HashSet<(usize, usize)> =
+        bricks.iter().map(|pos| (pos.row, pos.col)).collect();
+    for transform in balls.iter() {
+        blocked.extend(covered_cells(
+            &grid,
+            transform.translation,
+            BALL_RADIUS,
+            BALL_RADIUS,
+        ));
+    }
+    for transform in paddles.iter() {
+        // The paddle capsule lies along Z
+        blocked.extend(covered_cells(
+            &grid,
+            transform.translation,
+            PADDLE_RADIUS,
+            PADDLE_HEIGHT / 2.0 + PADDLE_RADIUS,
+        ));
+    }
+    let matrix = free_cells_only(&wave.matrix, &blocked);
+
+    spawn_bricks_only(
+        &matrix,
+        &grid,
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        #[cfg(feature = "texture_manifest")]
+        tex_res.canonical.as_deref(),
+        #[cfg(feature = "texture_manifest")]
+        tex_res.fallback.as_deref_mut(),
+        #[cfg(feature = "texture_manifest")]
+        tex_res.type_registry.as_deref(),
+        &brick_config,
+    );
+    schedule.next += 1;
+    info!(
+        "Wave {} of {} arrived at {:.1}s",
+        schedule.next,
+        schedule.waves.len(),
+        clock.elapsed.as_secs_f32()
+    );
+}
+
+/// Plugin registering brick waves.
+pub struct WavesPlugin;
+
+impl Plugin for WavesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaveSchedule>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_systems(
+            Update,
+            (
+                load_wave_schedule.run_if(resource_exists_and_changed::<CurrentLevel>),
+                spawn_due_waves.run_if(crate::pause::not_paused),
+            )
+                .chain()
+                // Wave bricks must be on the field before completion is checked
+                .before(crate::level_loader::LevelAdvanceSystems),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wave(trigger: WaveTrigger) -> Wave {
+        Wave {
+            trigger,
+            matrix: vec![vec![0, 20, 3]],
+        }
+    }
+
+    #[test]
+    fn triggers_fire_on_time_or_thinning_field() {
+        let timed = WaveTrigger::AfterSeconds(30.0);
+        assert!(!timed.is_met(Duration::from_secs(29), 5));
+        assert!(timed.is_met(Duration::from_secs(30), 5));
+
+        let thin = WaveTrigger::BricksBelow(10);
+        assert!(!thin.is_met(Duration::ZERO, 10));
+        assert!(thin.is_met(Duration::ZERO, 9));
+    }
+
+    #[test]
+    fn waves_arrive_in_order_and_early_on_a_cleared_field() {
+        let mut schedule = WaveSchedule::from_waves(&[
+            wave(WaveTrigger::AfterSeconds(60.0)),
+            wave(WaveTrigger::BricksBelow(3)),
+        ]);
+        assert_eq!(schedule.pending(), 2);
+        // The second trigger is met, but the first wave has not arrived
+        assert!(schedule.due(Duration::from_secs(5), 1).is_none());
+        assert!(schedule.due(Duration::from_secs(5), 0).is_some());
+        schedule.next = 1;
+        assert!(schedule.due(Duration::from_secs(5), 3).is_none());
+        assert!(schedule.due(Duration::from_secs(5), 2).is_some());
+        schedule.next = 2;
+        assert_eq!(schedule.pending(), 0);
+        assert!(schedule.due(Duration::from_secs(500), 0).is_none());
+    }
+
+    #[test]
+    fn invalid_waves_are_skipped() {
+        let empty = Wave {
+            trigger: WaveTrigger::AfterSeconds(5.0),
+            matrix: vec![vec![0, 1, 2]],
+        };
+        assert!(empty.validate().is_err());
+        assert!(wave(WaveTrigger::AfterSeconds(-1.0)).validate().is_err());
+        assert!(wave(WaveTrigger::AfterSeconds(f32::NAN))
+            .validate()
+            .is_err());
+        assert!(wave(WaveTrigger::BricksBelow(0)).validate().is_err());
+
+        let schedule = WaveSchedule::from_waves(&[empty, wave(WaveTrigger::BricksBelow(4))]);
+        assert_eq!(schedule.waves, vec![wave(WaveTrigger::BricksBelow(4))]);
+    }
+
+    #[test]
+    fn blocked_cells_are_emptied() {
+        let blocked = HashSet::from([(0, 1)]);
+        assert_eq!(
+            free_cells_only(&[vec![3, 3], vec![3, 3]], &blocked),
+            vec![vec![3, 0], vec![3, 3]]
+        );
+    }
+
+    #[test]
+    fn covered_cells_include_neighbours_under_the_edges() {
+        let grid = GridResolution::default();
+        let center = grid.cell_center(4, 4);
+        assert_eq!(covered_cells(&grid, center, 0.1, 0.1), vec![(4, 4)]);
+        // Reaching over the cell boundary along Z touches the neighbouring columns
+        let cells = covered_cells(&grid, center, 0.1, 1.5);
+        assert!(cells.contains(&(4, 3)) && cells.contains(&(4, 5)));
+        // A shape longer than a cell covers the one under its middle too
+        let cells = covered_cells(&grid, center, 0.1, 1.8);
+        assert!(cells.contains(&(4, 4)));
+        assert_eq!(cells.len(), 3);
+    }
+}
@@ -0,0 +1,150 @@
+//! Save files in the browser: localStorage on wasm builds.
+//!
+//! Native builds keep progress, profiles, playlists and settings in files under `config/` (and
+//! `assets/levels/`). Browsers have no file system, so wasm builds store the same RON content in
+//! `localStorage`, one item per file, keyed by the file's path with [`STORAGE_PREFIX`] (e.g.
+//! `brkrs:config/progress.ron`). `WebStorageBackend` implements
+//! [`SaveBackend`](crate::systems::cloud_sync::SaveBackend) on top of it.
+//!
+//! Persistence modules read and write through [`read_save_file`] and [`write_save_file`], which
+//! pick the files or the browser storage, so they need no platform branches of their own. Stored
+//! in the browser:
+//! - the autosaved progress (`systems::autosave`)
+//! - profiles with their high scores and statistics (`systems::profiles`), listed under
+//!   `PROFILE_INDEX_KEY` (`systems::cloud_sync`) since there is no directory to scan
+//! - playlists (`systems::playlist`) and the graphics settings (`systems::post_processing`)
+//!
+//! The audio settings keep their older `brkrs_audio` item (`systems::audio`); the display settings
+//! do not apply in browsers (`systems::display`).
+//!
+//! localStorage is synchronous, which suits systems that load and save within a frame, and its
+//! quota of a few megabytes is plenty for these small files. IndexedDB would need asynchronous
+//! loading for no gain at this size, so it is not used.
+
+use std::path::Path;
+
+#[cfg(target_arch = "wasm32")]
+use bevy::prelude::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::systems::autosave::{read_recovering, write_atomic};
+#[cfg(target_arch = "wasm32")]
+use crate::systems::cloud_sync::{SaveBackend, SavedFile};
+
+/// Prefix of the localStorage items holding save files.
+pub const STORAGE_PREFIX: &str = "brkrs:";
+
+/// Save key of `path`: the path with forward slashes, as used by save backends.
+pub fn save_key(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// localStorage item holding the save file `key`.
+pub fn storage_item(key: &str) -> String {
+    format!("{STORAGE_PREFIX}{key}")
+}
+
+/// Save files in the browser's localStorage.
+///
+/// Each file is stored as a RON [`SavedFile`], like on a sync server (`systems::cloud_sync`), so
+/// its modification time is kept.
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebStorageBackend;
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Result<web_sys::Storage, String> {
+    web_sys::window()
+        .ok_or_else(|| "window object unavailable".to_string())?
+        .local_storage()
+        .map_err(|e| format!("localStorage unavailable: {e:?}"))?
+        .ok_or_else(|| "localStorage unavailable".to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+impl SaveBackend for WebStorageBackend {
+    fn read(&self, key: &str) -> Result<Option<SavedFile>, String> {
+        let item = local_storage()?
+            .get_item(&storage_item(key))
+            .map_err(|e| format!("failed to read {key}: {e:?}"))?;
+        item.map(|content| {
+            ron::de::from_str(&content).map_err(|e| format!("invalid save file {key}: {e}"))
+        })
+        .transpose()
+    }
+
+    fn write(&self, key: &str, file: &SavedFile) -> Result<(), String> {
+        let content =
+            ron::ser::to_string(file).map_err(|e| format!("failed to serialize {key}: {e}"))?;
+        // Fails when the storage quota is exhausted
+        local_storage()?
+            .set_item(&storage_item(key), &content)
+            .map_err(|e| format!("failed to write {key}: {e:?}"))
+    }
+}
+
+/// Read and parse the save file `path`; `None` when it is missing or `parse` rejects it.
+///
+/// Native builds recover from an interrupted write ([`read_recovering`](crate::systems::autosave::read_recovering)); wasm builds read the
+/// browser storage.
+pub fn read_save_file<T>(path: &Path, parse: impl Fn(&str) -> Option<T>) -> Option<T> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        read_recovering(path, parse)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let key = save_key(path);
+        match WebStorageBackend.read(&key) {
+            Ok(file) => file.and_then(|file| parse(&file.content)),
+            Err(e) => {
+                warn!("{}", e);
+                None
+            }
+        }
+    }
+}
+
+/// Write the save file `path`: atomically on native builds ([`write_atomic`](crate::systems::autosave::write_atomic)), to the browser
+/// storage on wasm builds.
+pub fn write_save_file(path: &Path, content: &str) -> Result<(), String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        write_atomic(path, content)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let file = SavedFile {
+            modified: (web_sys::js_sys::Date::now() / 1000.0) as u64,
+            content: content.to_string(),
+        };
+        WebStorageBackend.write(&save_key(path), &file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn items_are_keyed_by_the_file_path() {
+        let key = save_key(Path::new("config/profiles/Player 1/profile.ron"));
+        assert_eq!(key, "config/profiles/Player 1/profile.ron");
+        assert_eq!(
+            storage_item(&key),
+            "brkrs:config/profiles/Player 1/profile.ron"
+        );
+    }
+
+    #[test]
+    fn save_files_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config").join("graphics.ron");
+        write_save_file(&path, "(preset: High)").unwrap();
+        assert_eq!(
+            read_save_file(&path, |content| Some(content.to_string())).as_deref(),
+            Some("(preset: High)")
+        );
+        assert_eq!(read_save_file(&path, |_| None::<()>), None);
+    }
+}
@@ -0,0 +1,261 @@
+//! Wrecking ball mode: a temporary, very heavy ball that knocks bricks loose as physical debris.
+//!
+//! While a ball carries [`WreckingBall`], destructible bricks it destroys are not despawned
+//! straight away. They become `RigidBody::Dynamic` [`WreckingDebris`], are launched along the
+//! ball's travel direction, and are only marked for despawn once their short lifetime runs out.
+//! Debris that strikes other (simple) destructible bricks knocks them loose too, so one hit can
+//! set off a chain reaction.
+//!
+//! Scoring, drops, and audio are unaffected: debris is re-marked with `MarkedForDespawn` when it
+//! expires, so `despawn_marked_entities` still emits exactly one `BrickDestroyed` per brick.
+//!
+//! # Performance bounds
+//! At most [`WreckingBallConfig::max_debris`] debris bodies exist at once. Bricks destroyed beyond
+//! that cap are despawned the normal way, and chain reactions simply stop. The mode is disabled
+//! by default on wasm, where the extra dynamic bodies are too costly; wrecking ball capsules are
+//! not dropped while the mode is disabled.
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use crate::level_format::{is_explosive_brick, is_multi_hit_brick};
+use crate::systems::ball_size::{ball_collider_for, BallSizeEffect};
+use crate::systems::powerups::PowerupKind;
+use crate::systems::timed_effects::{tick_timed_effects, TimedEffect};
+use crate::{Ball, Brick, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn};
+
+/// Mass multiplier applied to a wrecking ball (via collider density).
+pub const WRECKING_MASS_MULTIPLIER: f32 = 8.0;
+/// Fraction of the ball's velocity transferred to a knocked-loose brick.
+pub const DEBRIS_LAUNCH_FACTOR: f32 = 0.6;
+
+/// Tuning and platform gate for wrecking ball mode.
+#[derive(Resource, Debug, Clone)]
+pub struct WreckingBallConfig {
+    /// Master switch. Defaults to `false` on wasm.
+    pub enabled: bool,
+    /// How long the ball stays in wrecking mode, in seconds.
+    pub duration: f32,
+    /// How long a brick flies around as debris before it is despawned, in seconds.
+    pub debris_lifetime: f32,
+    /// Upper bound on simultaneous debris bodies.
+    pub max_debris: usize,
+}
+
+impl Default for WreckingBallConfig {
+    fn default() -> Self {
+        Self {
+            enabled: cfg!(not(target_arch = "wasm32")),
+            duration: 8.0,
+            debris_lifetime: 1.2,
+            max_debris: 24,
+        }
+    }
+}
+
+/// Marks a ball as being in wrecking mode until the timer finishes.
+#[derive(Component, Debug, Clone)]
+pub struct WreckingBall {
+    pub timer: Timer,
+}
+
+impl WreckingBall {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+}
+
+impl TimedEffect for WreckingBall {
+    fn kind(&self) -> PowerupKind {
+        PowerupKind::WreckingBall
+    }
+
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// A brick knocked loose by a wrecking ball; despawned when the timer finishes.
+#[derive(Component, Debug, Clone)]
+pub struct WreckingDebris {
+    pub timer: Timer,
+}
+
+/// Put `ball` into wrecking mode (or restart its timer) and make it heavy.
+pub fn start_wrecking_ball(
+    commands: &mut Commands,
+    ball: Entity,
+    size_effect: Option<&BallSizeEffect>,
+    config: &WreckingBallConfig,
+) {
+    let (_, density) = ball_collider_for(size_effect.map(|e| e.effect_type));
+    commands.entity(ball).insert((
+        WreckingBall::new(config.duration),
+        ColliderMassProperties::Density(density * WRECKING_MASS_MULTIPLIER),
+    ));
+}
+
+/// Bricks that may be knocked loose (simple destructible bricks only). Multi-hit bricks keep
+/// their hit-count rules and explosive bricks detonate in place (see `explosion`).
+fn can_chain(type_id: u8) -> bool {
+    !is_multi_hit_brick(type_id) && !is_explosive_brick(type_id)
+}
+
+fn knock_loose(commands: &mut Commands, brick: Entity, linvel: Vec3, lifetime: f32) {
+    commands.entity(brick).remove::<MarkedForDespawn>().insert((
+        WreckingDebris {
+            timer: Timer::from_seconds(lifetime, TimerMode::Once),
+        },
+        RigidBody::Dynamic,
+        Velocity::linear(Vec3::new(linvel.x, 0.0, linvel.z)),
+        LockedAxes::TRANSLATION_LOCKED_Y,
+    ));
+}
+
+/// Turn bricks destroyed by a wrecking ball (and bricks struck by debris) into dynamic debris.
+///
+/// Runs after `mark_brick_on_ball_collision` so the bricks it marked this frame can be
+/// intercepted before `despawn_marked_entities`.
+pub fn knock_bricks_loose(
+    mut commands: Commands,
+    mut collisions: MessageReader<CollisionEvent>,
+    config: Res<WreckingBallConfig>,
+    wrecking_balls: Query<&Velocity, (With<Ball>, With<WreckingBall>)>,
+    debris: Query<&Velocity, With<WreckingDebris>>,
+    marked: Query<&BrickTypeId, (Added<MarkedForDespawn>, Without<WreckingDebris>)>,
+    chainable: Query<
+        &BrickTypeId,
+        (
+            With<Brick>,
+            With<CountsTowardsCompletion>,
+            Without<MarkedForDespawn>,
+            Without<WreckingDebris>,
+        ),
+    >,
+) {
+    if !config.enabled {
+        collisions.clear();
+        return;
+    }
+    let mut budget = config.max_debris.saturating_sub(debris.iter().count());
+    let mut loosened = Vec::new();
+    for event in collisions.read() {
+        let CollisionEvent::Started(e1, e2, _) = event else {
+            continue;
+        };
+        for (hitter, brick) in [(*e1, *e2), (*e2, *e1)] {
+            if budget == 0 || loosened.contains(&brick) {
+                continue;
+            }
+            let launch = if let Ok(velocity) = wrecking_balls.get(hitter) {
+                // Brick was destroyed by the ball this frame
+                marked
+                    .get(brick)
+                    .is_ok_and(|t| can_chain(t.0))
+                    .then_some(velocity.linvel)
+            } else if let Ok(velocity) = debris.get(hitter) {
+                // Debris slamming into an intact brick
+                chainable
+                    .get(brick)
+                    .is_ok_and(|t| can_chain(t.0))
+                    .then_some(velocity.linvel)
+            } else {
+                None
+            };
+            if let Some(linvel) = launch {
+                knock_loose(
+                    &mut commands,
+                    brick,
+                    linvel * DEBRIS_LAUNCH_FACTOR,
+                    config.debris_lifetime,
+                );
+                loosened.push(brick);
+                budget -= 1;
+            }
+        }
+    }
+}
+
+/// Count down debris lifetimes and hand expired debris back to the normal despawn path.
+pub fn expire_debris(
+    mut commands: Commands,
+    mut debris: Query<(Entity, &mut WreckingDebris), Without<MarkedForDespawn>>,
+    time: Res<Time>,
+) {
+    for (entity, mut piece) in debris.iter_mut() {
+        piece.timer.tick(time.delta());
+        if piece.timer.is_finished() {
+            commands.entity(entity).insert(MarkedForDespawn);
+        }
+    }
+}
+
+/// End wrecking mode when the timer runs out, restoring the ball's normal mass.
+pub fn end_wrecking_balls(
+    mut commands: Commands,
+    balls: Query<(Entity, &WreckingBall, Option<&BallSizeEffect>)>,
+) {
+    for (entity, wrecking, size_effect) in balls.iter() {
+        if wrecking.timer.is_finished() {
+            let (_, density) = ball_collider_for(size_effect.map(|e| e.effect_type));
+            commands
+                .entity(entity)
+                .remove::<WreckingBall>()
+                .insert(ColliderMassProperties::Density(density));
+        }
+    }
+}
+
+/// Plugin registering wrecking ball mode.
+pub struct WreckingBallPlugin;
+
+impl Plugin for WreckingBallPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WreckingBallConfig>();
+        // Ensure PauseState exists so `not_paused` passes in minimal test apps
+        app.init_resource::<crate::pause::PauseState>();
+        app.add_message::<CollisionEvent>();
+        app.add_systems(
+            Update,
+            (
+                expire_debris
+                    .before(crate::mark_brick_on_ball_collision)
+                    .run_if(crate::pause::not_paused),
+                knock_bricks_loose
+                    .after(crate::mark_brick_on_ball_collision)
+                    .before(crate::systems::powerups::PowerupSystems::Drop),
+                (
+                    tick_timed_effects::<WreckingBall>.run_if(crate::pause::not_paused),
+                    end_wrecking_balls,
+                )
+                    .chain(),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wasm_default_is_disabled() {
+        let config = WreckingBallConfig::default();
+        assert_eq!(config.enabled, cfg!(not(target_arch = "wasm32")));
+        assert!(config.max_debris > 0);
+    }
+
+    #[test]
+    fn multi_hit_and_explosive_bricks_do_not_chain() {
+        assert!(!can_chain(crate::level_format::MULTI_HIT_BRICK_1));
+        assert!(!can_chain(crate::level_format::BOMB_BRICK));
+        assert!(can_chain(crate::level_format::SIMPLE_BRICK));
+    }
+}
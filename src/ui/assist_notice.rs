@@ -0,0 +1,102 @@
+//! Assist mode notice.
+//!
+//! Purpose
+//! - Tells the player about the assist barrier (`src/systems/assist.rs`) at the top centre of the
+//!   screen: `Assist barrier ready` while it is up, and `Ball saved!` for a moment after any
+//!   ball-save shield sends a ball back.
+//!
+//! How it updates
+//! - `update_assist_notice` reacts to `BallSaved` messages, ticks the `Ball saved!` timer and
+//!   shows the readout only while there is something to say.
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::systems::assist::AssistShield;
+use crate::systems::ball_save::BallSaved;
+use crate::ui::fonts::UiFonts;
+
+/// How long `Ball saved!` stays on screen, in seconds.
+pub const BALL_SAVED_SECS: f32 = 2.0;
+
+const READY_TEXT: &str = "Assist barrier ready";
+const SAVED_TEXT: &str = "Ball saved!";
+
+/// The assist notice, with the time left on `Ball saved!`.
+#[derive(Component, Debug, Default)]
+pub struct AssistNoticeUi {
+    pub saved: Option<Timer>,
+}
+
+/// Spawn the (initially hidden) notice once fonts are ready.
+pub fn spawn_assist_notice(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<AssistNoticeUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.3, 0.9, 1.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(48.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Visibility::Hidden,
+        AssistNoticeUi::default(),
+    ));
+}
+
+/// Show the barrier state, or `Ball saved!` right after a save.
+pub fn update_assist_notice(
+    mut saves: MessageReader<BallSaved>,
+    time: Res<Time>,
+    shields: Query<(), With<AssistShield>>,
+    mut notices: Query<(&mut AssistNoticeUi, &mut Text, &mut Visibility)>,
+) {
+    let saved = saves.read().count() > 0;
+    for (mut notice, mut text, mut visibility) in notices.iter_mut() {
+        if saved {
+            notice.saved = Some(Timer::from_seconds(BALL_SAVED_SECS, TimerMode::Once));
+        }
+        if let Some(timer) = notice.saved.as_mut() {
+            timer.tick(time.delta());
+            if timer.is_finished() {
+                notice.saved = None;
+            }
+        }
+        let message = if notice.saved.is_some() {
+            Some(SAVED_TEXT)
+        } else if !shields.is_empty() {
+            Some(READY_TEXT)
+        } else {
+            None
+        };
+        match message {
+            Some(message) => {
+                if text.as_str() != message {
+                    **text = message.to_string();
+                }
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
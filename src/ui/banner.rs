@@ -0,0 +1,158 @@
+//! Announcement banners.
+//!
+//! Purpose
+//! - Large centre-screen text for level events (`LEVEL 5`). Any system announces one by
+//!   triggering [`ShowBanner`], so every announcement looks and moves the same.
+//!
+//! When it spawns
+//! - `on_show_banner` spawns the banner, replacing any banner still on screen so the latest
+//!   announcement is the one shown. It needs `UiFonts`; without fonts the announcement is skipped.
+//! - `announce_level_started` announces `LEVEL n` whenever a level starts.
+//!
+//! How it updates
+//! - `animate_banners` slides a banner in from the left over [`BANNER_SLIDE_SECS`], holds it, then
+//!   fades it out over [`BANNER_FADE_SECS`] and despawns it. Persistent banners slide in and stay
+//!   until replaced.
+
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::ui::fonts::UiFonts;
+
+/// How long a banner stays on screen, slide and fade included, in seconds.
+pub const BANNER_SECS: f32 = 2.0;
+/// How long a banner takes to slide into place, in seconds.
+pub const BANNER_SLIDE_SECS: f32 = 0.25;
+/// How long a banner takes to fade out, in seconds.
+pub const BANNER_FADE_SECS: f32 = 0.5;
+
+/// Distance a banner slides in from, in percent of the screen width.
+const SLIDE_DISTANCE: f32 = 40.0;
+const BANNER_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+
+/// Announce something with a centre-screen banner.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ShowBanner {
+    pub text: String,
+    /// Keep the banner up until another one replaces it instead of fading it out.
+    pub persistent: bool,
+}
+
+impl ShowBanner {
+    /// A banner that fades out after [`BANNER_SECS`].
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            persistent: false,
+        }
+    }
+
+    /// A banner that stays on screen.
+    pub fn persistent(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            persistent: true,
+        }
+    }
+}
+
+/// A banner on screen, with the time since it appeared.
+#[derive(Component, Debug)]
+pub struct Banner {
+    pub elapsed: f32,
+    pub persistent: bool,
+}
+
+/// Horizontal offset (percent of the screen width) and alpha of a banner `elapsed` seconds in.
+pub fn banner_pose(elapsed: f32, persistent: bool) -> (f32, f32) {
+    let slide = (elapsed / BANNER_SLIDE_SECS).clamp(0.0, 1.0);
+    // Ease out: fast at first, settling into place
+    let offset = -SLIDE_DISTANCE * (1.0 - slide).powi(2);
+    let alpha = if persistent {
+        slide
+    } else {
+        let fade = ((BANNER_SECS - elapsed) / BANNER_FADE_SECS).clamp(0.0, 1.0);
+        slide.min(fade)
+    };
+    (offset, alpha)
+}
+
+/// Spawn a banner, replacing the one on screen.
+pub fn on_show_banner(
+    trigger: On<ShowBanner>,
+    mut commands: Commands,
+    existing: Query<Entity, With<Banner>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    let event = trigger.event();
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+    let (offset, alpha) = banner_pose(0.0, event.persistent);
+    commands.spawn((
+        Text::new(event.text.clone()),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 64.0,
+            ..default()
+        },
+        TextColor(BANNER_COLOR.with_alpha(alpha)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(40.0),
+            left: Val::Percent(offset),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Banner {
+            elapsed: 0.0,
+            persistent: event.persistent,
+        },
+    ));
+}
+
+/// Announce each level as it starts.
+pub fn announce_level_started(trigger: On<crate::systems::LevelStarted>, mut commands: Commands) {
+    commands.trigger(ShowBanner::new(format!(
+        "LEVEL {}",
+        trigger.event().level_index
+    )));
+}
+
+/// Slide, fade and despawn banners.
+pub fn animate_banners(
+    mut commands: Commands,
+    mut banners: Query<(Entity, &mut Banner, &mut Node, &mut TextColor)>,
+    time: Res<Time>,
+) {
+    for (entity, mut banner, mut node, mut color) in banners.iter_mut() {
+        banner.elapsed += time.delta_secs();
+        if !banner.persistent && banner.elapsed >= BANNER_SECS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let (offset, alpha) = banner_pose(banner.elapsed, banner.persistent);
+        node.left = Val::Percent(offset);
+        color.0 = BANNER_COLOR.with_alpha(alpha);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn banners_slide_in_hold_and_fade() {
+        assert_eq!(banner_pose(0.0, false), (-SLIDE_DISTANCE, 0.0));
+        assert_eq!(banner_pose(1.0, false), (0.0, 1.0));
+        let (_, fading) = banner_pose(BANNER_SECS - BANNER_FADE_SECS / 2.0, false);
+        assert!((fading - 0.5).abs() < 1e-4);
+        assert_eq!(banner_pose(BANNER_SECS, false).1, 0.0);
+        assert_eq!(banner_pose(60.0, true), (0.0, 1.0));
+    }
+}
@@ -0,0 +1,240 @@
+//! Brick legend overlay
+//!
+//! Purpose
+//! - Helps new players read busy levels: while open, every brick is tinted by what it takes to
+//!   clear it, and a legend box explains the colours.
+//!
+//! Categories (`BrickCategory::of`)
+//! - Indestructible: bricks that do not count towards completion (indestructible, hazard 91,
+//!   bumpers).
+//! - Multi-hit: the multi-hit indices and bricks with an authored durability above one.
+//! - Powerup: bricks with an effect when destroyed (extra life, bombs).
+//! - Destructible: everything else.
+//!
+//! User flow
+//! - `C` opens the legend and closes it again. It stays closed while a menu is shown.
+//!
+//! Scheduling summary (Update, `UiSystems::Input`)
+//! - `toggle_brick_legend` updates `BrickLegendState`.
+//! - `tint_bricks_by_category` swaps brick materials for category tints and restores them.
+//! - `ensure_brick_legend` spawns/despawns the legend box when the state changes.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::level_format::{is_explosive_brick, is_multi_hit_brick, EXTRA_LIFE_BRICK};
+use crate::systems::durability::Durability;
+use crate::ui::focus::Focusable;
+use crate::ui::fonts::UiFonts;
+use crate::{Brick, BrickTypeId, CountsTowardsCompletion};
+
+/// Key opening and closing the legend.
+pub const BRICK_LEGEND_KEY: KeyCode = KeyCode::KeyC;
+
+/// What it takes to clear a brick, as shown by the legend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BrickCategory {
+    Destructible,
+    MultiHit,
+    Indestructible,
+    Powerup,
+}
+
+impl BrickCategory {
+    /// All categories, in legend order.
+    pub const ALL: [BrickCategory; 4] = [
+        BrickCategory::Destructible,
+        BrickCategory::MultiHit,
+        BrickCategory::Powerup,
+        BrickCategory::Indestructible,
+    ];
+
+    /// Category of a brick of `type_id`; `counts` tells whether it counts towards completion.
+    pub fn of(type_id: u8, counts: bool, durability: Option<u8>) -> Self {
+        if !counts {
+            BrickCategory::Indestructible
+        } else if is_multi_hit_brick(type_id) || durability.is_some_and(|hits| hits > 1) {
+            BrickCategory::MultiHit
+        } else if type_id == EXTRA_LIFE_BRICK || is_explosive_brick(type_id) {
+            BrickCategory::Powerup
+        } else {
+            BrickCategory::Destructible
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            BrickCategory::Destructible => Color::srgb(0.3, 0.85, 0.35),
+            BrickCategory::MultiHit => Color::srgb(1.0, 0.6, 0.1),
+            BrickCategory::Indestructible => Color::srgb(0.5, 0.5, 0.55),
+            BrickCategory::Powerup => Color::srgb(0.3, 0.6, 1.0),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BrickCategory::Destructible => "Destructible",
+            BrickCategory::MultiHit => "Multi-hit",
+            BrickCategory::Indestructible => "Indestructible",
+            BrickCategory::Powerup => "Powerup (extra life, bombs)",
+        }
+    }
+}
+
+/// Whether the legend is open.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BrickLegendState {
+    pub open: bool,
+}
+
+/// Material of a brick while the legend tints it.
+#[derive(Component, Debug, Clone)]
+pub struct LegendOriginalMaterial(pub Handle<StandardMaterial>);
+
+/// Marker for the legend box.
+#[derive(Component, Debug)]
+pub struct BrickLegendRoot;
+
+/// Toggle the legend with [`BRICK_LEGEND_KEY`], unless a menu is shown.
+pub fn toggle_brick_legend(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    menus: Query<(), With<Focusable>>,
+    mut state: ResMut<BrickLegendState>,
+) {
+    if keyboard.is_some_and(|k| k.just_pressed(BRICK_LEGEND_KEY)) && menus.is_empty() {
+        state.open = !state.open;
+    }
+}
+
+/// Tint bricks by category while the legend is open, and give them their materials back when it
+/// closes.
+///
+/// Materials the game swaps in while the legend is open (e.g. a multi-hit brick changing type)
+/// become the ones restored later.
+pub fn tint_bricks_by_category(
+    mut commands: Commands,
+    state: Res<BrickLegendState>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+    mut bricks: Query<
+        (
+            Entity,
+            &BrickTypeId,
+            Has<CountsTowardsCompletion>,
+            Option<&Durability>,
+            &mut MeshMaterial3d<StandardMaterial>,
+            Option<&mut LegendOriginalMaterial>,
+        ),
+        With<Brick>,
+    >,
+    mut tints: Local<HashMap<BrickCategory, Handle<StandardMaterial>>>,
+) {
+    if !state.open {
+        if state.is_changed() {
+            for (entity, _, _, _, mut material, original) in bricks.iter_mut() {
+                if let Some(original) = original {
+                    material.0 = original.0.clone();
+                    commands.entity(entity).remove::<LegendOriginalMaterial>();
+                }
+            }
+        }
+        return;
+    }
+    let Some(mut materials) = materials else {
+        return;
+    };
+    for (entity, type_id, counts, durability, mut material, original) in bricks.iter_mut() {
+        let category = BrickCategory::of(type_id.0, counts, durability.map(|d| d.0));
+        let tint = tints
+            .entry(category)
+            .or_insert_with(|| {
+                materials.add(StandardMaterial {
+                    base_color: category.color(),
+                    emissive: category.color().to_linear() * 0.4,
+                    ..default()
+                })
+            })
+            .clone();
+        if material.0 == tint {
+            continue;
+        }
+        // Keep the game's material unless it is the tint of the brick's previous category
+        if !tints.values().any(|handle| *handle == material.0) {
+            match original {
+                Some(mut original) => original.0 = material.0.clone(),
+                None => {
+                    commands
+                        .entity(entity)
+                        .insert(LegendOriginalMaterial(material.0.clone()));
+                }
+            }
+        }
+        material.0 = tint;
+    }
+}
+
+/// Spawn or despawn the legend box based on `BrickLegendState`.
+pub fn ensure_brick_legend(
+    state: Res<BrickLegendState>,
+    mut commands: Commands,
+    existing: Query<Entity, With<BrickLegendRoot>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    if !state.open {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !existing.is_empty() {
+        return;
+    }
+    let font = ui_fonts.map(|f| f.orbitron.clone()).unwrap_or_default();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(40.0),
+                left: Val::Px(40.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.85)),
+            BrickLegendRoot,
+        ))
+        .with_children(|panel| {
+            for category in BrickCategory::ALL {
+                panel
+                    .spawn(Node {
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Node {
+                                width: Val::Px(16.0),
+                                height: Val::Px(16.0),
+                                ..default()
+                            },
+                            BackgroundColor(category.color()),
+                        ));
+                        row.spawn((
+                            Text::new(category.label()),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+        });
+}
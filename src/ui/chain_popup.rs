@@ -0,0 +1,92 @@
+//! Chain bonus popup.
+//!
+//! Purpose
+//! - Shows `Chain x3! +50` whenever a same-type brick chain earns a bonus
+//!   (`ChainBonusAwarded`), then fades it out.
+//!
+//! When it spawns
+//! - `show_chain_popup` spawns the popup on the first bonus message of a frame, replacing any
+//!   popup still on screen so the latest chain length is always the one shown. It needs
+//!   `UiFonts`; without fonts the bonus is still scored, just not shown.
+//!
+//! How it updates
+//! - `fade_chain_popups` ticks each popup's timer, fades the text alpha over the second half of
+//!   its lifetime, and despawns it when the timer finishes.
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::systems::scoring::ChainBonusAwarded;
+use crate::ui::fonts::UiFonts;
+
+/// How long a popup stays on screen, in seconds.
+pub const CHAIN_POPUP_SECS: f32 = 1.2;
+
+const POPUP_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+/// A chain popup with its remaining lifetime.
+#[derive(Component, Debug)]
+pub struct ChainPopup {
+    pub timer: Timer,
+}
+
+/// Popup text for a chain bonus.
+pub fn format_chain_popup(bonus: &ChainBonusAwarded) -> String {
+    format!("Chain x{}! +{}", bonus.chain_length, bonus.points)
+}
+
+/// Spawn (or replace) the popup for the longest chain rewarded this frame.
+pub fn show_chain_popup(
+    mut commands: Commands,
+    mut bonuses: MessageReader<ChainBonusAwarded>,
+    existing: Query<Entity, With<ChainPopup>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    let Some(bonus) = bonuses.read().max_by_key(|b| b.chain_length).copied() else {
+        return;
+    };
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+    commands.spawn((
+        Text::new(format_chain_popup(&bonus)),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 36.0,
+            ..default()
+        },
+        TextColor(POPUP_COLOR),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(30.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        ChainPopup {
+            timer: Timer::from_seconds(CHAIN_POPUP_SECS, TimerMode::Once),
+        },
+    ));
+}
+
+/// Fade out and despawn chain popups.
+pub fn fade_chain_popups(
+    mut commands: Commands,
+    mut popups: Query<(Entity, &mut ChainPopup, &mut TextColor)>,
+    time: Res<Time>,
+) {
+    for (entity, mut popup, mut color) in popups.iter_mut() {
+        popup.timer.tick(time.delta());
+        if popup.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = (2.0 * (1.0 - popup.timer.fraction())).min(1.0);
+        color.0 = POPUP_COLOR.with_alpha(alpha);
+    }
+}
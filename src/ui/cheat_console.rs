@@ -0,0 +1,76 @@
+//! Cheat console line.
+//!
+//! Purpose
+//! - Shows the cheat console (`src/systems/cheat_console.rs`) at the bottom of the screen while it
+//!   is open: the line being typed and the result of the last command.
+//!
+//! How it updates
+//! - `update_cheat_console_display` rewrites the text and visibility when `CheatConsole` changes.
+
+use bevy::prelude::*;
+
+use crate::systems::cheat_console::CheatConsole;
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the console text.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CheatConsoleUi;
+
+/// Spawn the (initially hidden) console line once fonts are ready.
+pub fn spawn_cheat_console_display(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<CheatConsoleUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.85, 0.3)),
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(48.0),
+            left: Val::Px(12.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        Visibility::Hidden,
+        CheatConsoleUi,
+    ));
+}
+
+/// Keep the console line in sync with `CheatConsole`.
+pub fn update_cheat_console_display(
+    console: Option<Res<CheatConsole>>,
+    mut query: Query<(&mut Text, &mut Visibility), With<CheatConsoleUi>>,
+    added: Query<(), Added<CheatConsoleUi>>,
+) {
+    let Some(console) = console else {
+        return;
+    };
+    if !console.is_changed() && added.is_empty() {
+        return;
+    }
+    for (mut text, mut visibility) in query.iter_mut() {
+        if !console.open {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        **text = if console.output.is_empty() {
+            format!("> {}_", console.input)
+        } else {
+            format!("{}\n> {}_", console.output, console.input)
+        };
+        *visibility = Visibility::Inherited;
+    }
+}
@@ -0,0 +1,75 @@
+//! Checkpoint HUD.
+//!
+//! Purpose
+//! - Shows how many of the current level's checkpoints have been reached (`Checkpoint: 1/3`) in
+//!   the bottom-left corner. Levels without checkpoints show nothing.
+//!
+//! How it updates
+//! - `update_checkpoint_display` rewrites the text when `LevelCheckpoints`
+//!   (`src/systems/checkpoints.rs`) changes and hides the readout on levels without checkpoints.
+
+use bevy::prelude::*;
+
+use crate::systems::checkpoints::LevelCheckpoints;
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the checkpoint text.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CheckpointDisplayUi;
+
+/// Spawn the (initially hidden) checkpoint readout once fonts are ready.
+pub fn spawn_checkpoint_display(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<CheckpointDisplayUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 1.0, 0.6)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(12.0),
+            left: Val::Px(12.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        CheckpointDisplayUi,
+    ));
+}
+
+/// Keep the readout in sync with `LevelCheckpoints`.
+pub fn update_checkpoint_display(
+    checkpoints: Option<Res<LevelCheckpoints>>,
+    mut query: Query<(&mut Text, &mut Visibility), With<CheckpointDisplayUi>>,
+    added: Query<(), Added<CheckpointDisplayUi>>,
+) {
+    let Some(checkpoints) = checkpoints else {
+        return;
+    };
+    if !checkpoints.is_changed() && added.is_empty() {
+        return;
+    }
+    for (mut text, mut visibility) in query.iter_mut() {
+        if checkpoints.groups.is_empty() {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        **text = format!(
+            "Checkpoint: {}/{}",
+            checkpoints.reached_count(),
+            checkpoints.groups.len()
+        );
+        *visibility = Visibility::Inherited;
+    }
+}
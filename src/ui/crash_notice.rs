@@ -0,0 +1,102 @@
+//! Crash notice.
+//!
+//! Purpose
+//! - Tells the player, on the start after a crash, where the crash report was saved
+//!   (`systems::crash_report`) and asks them to attach it to a bug report.
+//!
+//! When it spawns
+//! - `spawn_crash_notice` shows the notice over everything else as soon as `UiFonts` is available,
+//!   while `PreviousCrash` exists (native builds only, reports are written to disk).
+//! - `handle_crash_notice_button` closes it; the `OK` button is `Focusable`, so Enter or the
+//!   gamepad works as well as the mouse.
+
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::systems::crash_report::PreviousCrash;
+use crate::ui::focus::spawn_menu_button;
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the crash notice root.
+#[derive(Component, Debug)]
+pub struct CrashNotice;
+
+/// The notice's `OK` button.
+#[derive(Component, Debug)]
+pub struct CrashNoticeButton;
+
+/// Text of the notice pointing out the report of `crash`.
+pub fn crash_notice_text(crash: &PreviousCrash) -> String {
+    format!(
+        "brkrs closed unexpectedly last time.\n\nA crash report was saved to\n{}\n\nAttaching it \
+         to a bug report helps fix the problem.",
+        crash.0.display()
+    )
+}
+
+/// Show the notice once fonts are ready.
+pub fn spawn_crash_notice(
+    mut commands: Commands,
+    crash: Option<Res<PreviousCrash>>,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<(), With<CrashNotice>>,
+) {
+    let (Some(crash), Some(fonts)) = (crash, ui_fonts) else {
+        return;
+    };
+    if !existing.is_empty() {
+        return;
+    }
+    let font = fonts.orbitron.clone();
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(i32::MAX),
+            CrashNotice,
+        ))
+        .with_children(|notice| {
+            notice.spawn((
+                Text::new(crash_notice_text(&crash)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    max_width: Val::Px(720.0),
+                    ..default()
+                },
+            ));
+            // Ahead of any other menu shown at startup
+            spawn_menu_button(notice, "OK", font, -1, CrashNoticeButton);
+        });
+}
+
+/// Close the notice.
+pub fn handle_crash_notice_button(
+    mut commands: Commands,
+    buttons: Query<&Interaction, (With<CrashNoticeButton>, Changed<Interaction>)>,
+    notices: Query<Entity, With<CrashNotice>>,
+) {
+    if !buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    commands.remove_resource::<PreviousCrash>();
+    for entity in notices.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,84 @@
+//! Active effects HUD.
+//!
+//! Purpose
+//! - Lists the timed effects currently applied to the paddle (size changes, reversed controls,
+//!   rubber surface, catch) and ball (size changes, speed-up, wrecking mode) with their remaining
+//!   seconds, e.g. `+ Enlarge 7s` or `- Fast ball 3s`.
+//! - Buffs are prefixed with `+`, debuffs with `-`, matching the capsule tints.
+//!
+//! When it spawns
+//! - `spawn_effects_hud` runs every Update and creates the HUD once (idempotent) when `UiFonts`
+//!   is available. It sits below the score display (`top: 68px, right: 12px`).
+//!
+//! How it updates
+//! - `update_effects_hud` rebuilds the text from the running effects (`systems::timed_effects`)
+//!   each frame, but only writes to `Text` when the rendered string differs, so change detection
+//!   stays quiet while no effect is active. Effect timers stop while the game is paused, and so
+//!   does the countdown.
+
+use bevy::prelude::*;
+
+use crate::systems::powerups::PowerupKind;
+use crate::systems::timed_effects::{ActiveEffects, EffectSnapshot};
+use crate::ui::fonts::UiFonts;
+
+/// Marker component for the active effects HUD text.
+#[derive(Component)]
+pub struct EffectsHud;
+
+/// Format one HUD line for an effect with `remaining` seconds left.
+pub fn format_effect_line(kind: PowerupKind, remaining: f32) -> String {
+    let sign = if kind.is_debuff() { '-' } else { '+' };
+    format!("{} {} {}s", sign, kind.label(), remaining.ceil() as u32)
+}
+
+/// Build the HUD text for the given effects (see `ActiveEffects`). Empty when nothing is active.
+pub fn describe_active_effects(effects: &[EffectSnapshot]) -> String {
+    effects
+        .iter()
+        .map(|effect| format_effect_line(effect.kind, effect.remaining_secs))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Spawns the effects HUD if it doesn't exist.
+pub fn spawn_effects_hud(
+    mut commands: Commands,
+    existing: Query<Entity, With<EffectsHud>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(68.0),
+            right: Val::Px(12.0),
+            ..default()
+        },
+        EffectsHud,
+    ));
+}
+
+/// Refresh the effects HUD text from the paddle's and the ball's active effects.
+pub fn update_effects_hud(effects: ActiveEffects, mut hud: Query<&mut Text, With<EffectsHud>>) {
+    let Ok(mut text) = hud.single_mut() else {
+        return;
+    };
+    let rendered = describe_active_effects(&effects.snapshots());
+    if text.0 != rendered {
+        text.0 = rendered;
+    }
+}
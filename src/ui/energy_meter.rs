@@ -0,0 +1,113 @@
+//! Paddle energy HUD.
+//!
+//! Purpose
+//! - Shows the paddle energy meter (`src/systems/energy.rs`) as a bar at the bottom centre of the
+//!   screen. The bar lights up once the meter holds enough for a ball-save shield.
+//!
+//! How it updates
+//! - `update_energy_meter` resizes and recolours the fill when `EnergyMeter` changes.
+
+use bevy::prelude::*;
+
+use crate::gameplay_config::GameplayConfig;
+use crate::systems::energy::{energy_config, EnergyMeter};
+use crate::ui::fonts::UiFonts;
+
+/// Width of the bar in pixels.
+const BAR_WIDTH: f32 = 200.0;
+/// Fill colour while a shield cannot be afforded.
+const CHARGING_COLOR: Color = Color::srgb(0.2, 0.4, 0.8);
+/// Fill colour once a shield can be afforded.
+const READY_COLOR: Color = Color::srgb(0.3, 0.9, 1.0);
+
+/// Marker for the energy meter root node.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EnergyMeterUi;
+
+/// Marker for the bar fill.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EnergyMeterFill;
+
+/// Spawn the energy bar once fonts are ready.
+pub fn spawn_energy_meter(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<EnergyMeterUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(12.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            EnergyMeterUi,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Energy"),
+                TextFont {
+                    font: fonts.orbitron.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(BAR_WIDTH),
+                        height: Val::Px(14.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(CHARGING_COLOR),
+                        EnergyMeterFill,
+                    ));
+                });
+        });
+}
+
+/// Keep the bar in sync with `EnergyMeter`.
+pub fn update_energy_meter(
+    meter: Option<Res<EnergyMeter>>,
+    gameplay: Option<Res<GameplayConfig>>,
+    mut fills: Query<(&mut Node, &mut BackgroundColor), With<EnergyMeterFill>>,
+    added: Query<(), Added<EnergyMeterFill>>,
+) {
+    let Some(meter) = meter else {
+        return;
+    };
+    if !meter.is_changed() && added.is_empty() {
+        return;
+    }
+    let config = energy_config(gameplay.as_deref());
+    for (mut node, mut color) in fills.iter_mut() {
+        node.width = Val::Percent(meter.fraction(config.max) * 100.0);
+        color.0 = if meter.energy >= config.shield_cost {
+            READY_COLOR
+        } else {
+            CHARGING_COLOR
+        };
+    }
+}
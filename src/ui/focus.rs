@@ -0,0 +1,234 @@
+//! Keyboard and gamepad focus for interactive UI.
+//!
+//! Purpose
+//! - Lets every interactive screen be driven without a mouse: arrows / Tab / D-pad move the focus,
+//!   Enter or the gamepad South button (A) activates the focused element.
+//!
+//! How screens opt in
+//! - Give each button a [`Focusable`] (alongside `Button`). Focus cycles through all focusables in
+//!   ascending `order`; the lowest one is focused automatically when nothing is.
+//! - Activation presses the focused button by setting its `Interaction` to `Pressed` for one frame,
+//!   so the screen's existing `Changed<Interaction>` handlers work unchanged.
+//!
+//! Scheduling
+//! - Runs in `PreUpdate` after Bevy's own UI focus pass, so handlers in `Update` see keyboard and
+//!   mouse presses the same way.
+//! - The focused element gets an [`Outline`] highlight, removed when focus moves on.
+
+use bevy::prelude::*;
+
+/// Keys moving the focus to the next element.
+pub const FOCUS_NEXT_KEYS: [KeyCode; 3] = [KeyCode::ArrowDown, KeyCode::ArrowRight, KeyCode::Tab];
+/// Keys moving the focus to the previous element; `Shift` + `Tab` does too.
+pub const FOCUS_PREVIOUS_KEYS: [KeyCode; 2] = [KeyCode::ArrowUp, KeyCode::ArrowLeft];
+/// Keys activating the focused element.
+pub const ACTIVATE_KEYS: [KeyCode; 2] = [KeyCode::Enter, KeyCode::NumpadEnter];
+
+/// Outline colour of the focused element.
+const FOCUS_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+/// Marks a UI element as reachable by keyboard / gamepad navigation.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Focusable {
+    /// Navigation order; lower comes first.
+    pub order: i32,
+}
+
+impl Focusable {
+    pub fn new(order: i32) -> Self {
+        Self { order }
+    }
+}
+
+/// The currently focused element, if any.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UiFocus {
+    pub focused: Option<Entity>,
+}
+
+/// Spawn a focusable text button as a child of a menu, with `marker` identifying its action.
+pub fn spawn_menu_button(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    font: Handle<Font>,
+    order: i32,
+    marker: impl Bundle,
+) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::axes(Val::Px(24.0), Val::Px(8.0)),
+                margin: UiRect::all(Val::Px(8.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.15, 0.15, 0.2, 0.9)),
+            Button,
+            Focusable::new(order),
+            marker,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font,
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Direction of a focus move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusMove {
+    Next,
+    Previous,
+}
+
+/// Focus after applying `step` to `current` within `ordered` (already sorted), wrapping around.
+///
+/// A missing or stale `current` resolves to the first element.
+pub fn step_focus(
+    ordered: &[Entity],
+    current: Option<Entity>,
+    step: Option<FocusMove>,
+) -> Option<Entity> {
+    let len = ordered.len();
+    let Some(index) = current.and_then(|c| ordered.iter().position(|e| *e == c)) else {
+        return ordered.first().copied();
+    };
+    let index = match step {
+        Some(FocusMove::Next) => (index + 1) % len,
+        Some(FocusMove::Previous) => (index + len - 1) % len,
+        None => index,
+    };
+    Some(ordered[index])
+}
+
+fn focus_move(
+    keyboard: Option<&ButtonInput<KeyCode>>,
+    gamepads: &Query<&Gamepad>,
+) -> Option<FocusMove> {
+    if let Some(keys) = keyboard {
+        let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        if keys.any_just_pressed(FOCUS_PREVIOUS_KEYS) || (shift && keys.just_pressed(KeyCode::Tab))
+        {
+            return Some(FocusMove::Previous);
+        }
+        if keys.any_just_pressed(FOCUS_NEXT_KEYS) {
+            return Some(FocusMove::Next);
+        }
+    }
+    for gamepad in gamepads.iter() {
+        if gamepad.any_just_pressed([GamepadButton::DPadUp, GamepadButton::DPadLeft]) {
+            return Some(FocusMove::Previous);
+        }
+        if gamepad.any_just_pressed([GamepadButton::DPadDown, GamepadButton::DPadRight]) {
+            return Some(FocusMove::Next);
+        }
+    }
+    None
+}
+
+fn activate_pressed(keyboard: Option<&ButtonInput<KeyCode>>, gamepads: &Query<&Gamepad>) -> bool {
+    keyboard.is_some_and(|keys| keys.any_just_pressed(ACTIVATE_KEYS))
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::South))
+}
+
+/// Move focus on navigation input, keeping it on an existing focusable.
+pub fn navigate_focus(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    gamepads: Query<&Gamepad>,
+    focusables: Query<(Entity, &Focusable)>,
+    mut focus: ResMut<UiFocus>,
+) {
+    let mut ordered: Vec<(i32, Entity)> = focusables.iter().map(|(e, f)| (f.order, e)).collect();
+    ordered.sort();
+    let ordered: Vec<Entity> = ordered.into_iter().map(|(_, e)| e).collect();
+    let step = focus_move(keyboard.as_deref(), &gamepads);
+    focus.set_if_neq(UiFocus {
+        focused: step_focus(&ordered, focus.focused, step),
+    });
+}
+
+/// Press the focused element on Enter / South, releasing it again the next frame.
+pub fn activate_focused(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    gamepads: Query<&Gamepad>,
+    focus: Res<UiFocus>,
+    mut interactions: Query<&mut Interaction, With<Focusable>>,
+    mut pressed: Local<Option<Entity>>,
+) {
+    if let Some(entity) = pressed.take() {
+        if let Ok(mut interaction) = interactions.get_mut(entity) {
+            interaction.set_if_neq(Interaction::None);
+        }
+    }
+    if !activate_pressed(keyboard.as_deref(), &gamepads) {
+        return;
+    }
+    let Some(entity) = focus.focused else {
+        return;
+    };
+    if let Ok(mut interaction) = interactions.get_mut(entity) {
+        *interaction = Interaction::Pressed;
+        *pressed = Some(entity);
+    }
+}
+
+/// Outline the focused element.
+pub fn highlight_focus(
+    mut commands: Commands,
+    focus: Res<UiFocus>,
+    outlined: Query<Entity, (With<Outline>, With<Focusable>)>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+    for entity in outlined.iter() {
+        if Some(entity) != focus.focused {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+    if let Some(entity) = focus.focused {
+        if let Ok(mut target) = commands.get_entity(entity) {
+            target.insert(Outline::new(Val::Px(3.0), Val::Px(2.0), FOCUS_COLOR));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn focus_wraps_in_both_directions() {
+        let ordered = [
+            Entity::from_raw_u32(1).unwrap(),
+            Entity::from_raw_u32(2).unwrap(),
+            Entity::from_raw_u32(3).unwrap(),
+        ];
+        let last = Some(ordered[2]);
+        assert_eq!(
+            step_focus(&ordered, last, Some(FocusMove::Next)),
+            Some(ordered[0])
+        );
+        assert_eq!(
+            step_focus(&ordered, Some(ordered[0]), Some(FocusMove::Previous)),
+            last
+        );
+        assert_eq!(step_focus(&ordered, last, None), last);
+    }
+
+    #[test]
+    fn stale_or_missing_focus_resets_to_first() {
+        let ordered = [Entity::from_raw_u32(5).unwrap()];
+        let gone = Some(Entity::from_raw_u32(9).unwrap());
+        assert_eq!(step_focus(&ordered, gone, None), Some(ordered[0]));
+        assert_eq!(step_focus(&[], gone, Some(FocusMove::Next)), None);
+    }
+}
@@ -0,0 +1,151 @@
+//! Game complete screen
+//!
+//! Purpose
+//! - Shows the end of the game (`systems::game_complete`): the final score, the level that
+//!   finished it and the credits, with focusable "Play again" and (native only) "Quit" buttons.
+//!
+//! When it spawns
+//! - `sync_game_complete_screen` reacts to `GameCompleteState` changes: it spawns the screen once
+//!   the game is complete (idempotent) and despawns it when a new game starts.
+//! - `handle_game_complete_buttons` asks for a new game on "Play again" and exits on "Quit".
+
+use bevy::ecs::message::MessageWriter;
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::systems::game_complete::{GameCompleteState, RestartGameRequested};
+use crate::ui::focus::spawn_menu_button;
+use crate::ui::fonts::UiFonts;
+
+/// Credits shown under the score.
+pub const CREDITS: &[&str] = &[
+    "brkrs by cleder and contributors",
+    "Built with Bevy and Rapier",
+    "Orbitron font by The Orbitron Project Authors",
+];
+
+/// Marker for the game complete screen root.
+#[derive(Component, Debug)]
+pub struct GameCompleteScreen;
+
+/// Buttons on the game complete screen.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameCompleteButton {
+    PlayAgain,
+    Quit,
+}
+
+/// Score lines of the screen.
+pub fn game_complete_text(state: &GameCompleteState) -> String {
+    format!(
+        "Final score: {}\nCleared level {}",
+        state.final_score, state.last_level
+    )
+}
+
+/// Spawn the screen when the game is complete and remove it when a new game starts.
+pub fn sync_game_complete_screen(
+    mut commands: Commands,
+    state: Option<Res<GameCompleteState>>,
+    existing: Query<Entity, With<GameCompleteScreen>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    let Some(state) = state.filter(|s| s.is_changed()) else {
+        return;
+    };
+    if !state.active {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        warn!("UiFonts resource missing; skipping game complete screen spawn");
+        return;
+    };
+    let font = fonts.orbitron.clone();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            GameCompleteScreen,
+        ))
+        .with_children(|menu| {
+            menu.spawn((
+                Text::new("GAME COMPLETE"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 80.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+            menu.spawn((
+                Text::new(game_complete_text(&state)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+            menu.spawn((
+                Text::new(CREDITS.join("\n")),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    margin: UiRect::vertical(Val::Px(24.0)),
+                    ..default()
+                },
+            ));
+            spawn_menu_button(
+                menu,
+                "Play again",
+                font.clone(),
+                0,
+                GameCompleteButton::PlayAgain,
+            );
+            #[cfg(not(target_arch = "wasm32"))]
+            spawn_menu_button(menu, "Quit", font, 1, GameCompleteButton::Quit);
+        });
+}
+
+/// Handle the game complete screen buttons.
+pub fn handle_game_complete_buttons(
+    interactions: Query<(&Interaction, &GameCompleteButton), Changed<Interaction>>,
+    mut restarts: MessageWriter<RestartGameRequested>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    for (interaction, button) in interactions.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            GameCompleteButton::PlayAgain => {
+                restarts.write(RestartGameRequested);
+            }
+            GameCompleteButton::Quit => {
+                app_exit.write(AppExit::Success);
+            }
+        }
+    }
+}
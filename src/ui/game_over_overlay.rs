@@ -1,13 +1,15 @@
 //! Game-over overlay UI
 //!
 //! Purpose
-//! - Shows a centered "Game over" message when the player runs out of lives.
+//! - Shows a centered "Game over" message when the player runs out of lives, with a focusable
+//!   "Quit" button on native builds (keyboard/gamepad players can activate it with Enter / A).
 //!
 //! When it spawns
 //! - `spawn_game_over_overlay` listens for `GameOverRequested` and only spawns when:
 //!   - The event is received, and
 //!   - `LivesState.lives_remaining == 0`, and
 //!   - No existing `GameOverOverlay` entity is present (idempotent), and
+//!   - No checkpoint has been reached (`systems::checkpoints` restarts from it instead), and
 //!   - `UiFonts` is available (logs a warning and defers otherwise).
 //!
 //! Scheduling and integration
@@ -23,13 +25,22 @@
 use bevy::prelude::*;
 use bevy::text::Justify;
 
+use crate::systems::checkpoints::LevelCheckpoints;
 use crate::systems::respawn::{GameOverRequested, LivesState};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ui::focus::spawn_menu_button;
 use crate::ui::fonts::UiFonts;
 
 /// Marker component for the game-over overlay UI entity.
 #[derive(Component, Debug)]
 pub struct GameOverOverlay;
 
+/// Buttons on the game-over overlay.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverButton {
+    Quit,
+}
+
 /// System that spawns the game-over overlay when GameOverRequested is emitted.
 ///
 /// Displays "Game over" centered on screen with large white text.
@@ -38,6 +49,7 @@ pub fn spawn_game_over_overlay(
     events: Option<MessageReader<GameOverRequested>>,
     existing: Query<Entity, With<GameOverOverlay>>,
     lives_state: Option<Res<LivesState>>,
+    checkpoints: Option<Res<LevelCheckpoints>>,
     ui_fonts: Option<Res<UiFonts>>,
 ) {
     // Only spawn if we receive a GameOverRequested event
@@ -56,6 +68,10 @@ pub fn spawn_game_over_overlay(
     if !existing.is_empty() || lives_state.lives_remaining != 0 {
         return;
     }
+    // The level restarts from its checkpoint instead
+    if checkpoints.is_some_and(|c| c.reached.is_some()) {
+        return;
+    }
 
     let Some(fonts) = ui_fonts else {
         warn!("UiFonts resource missing; skipping game over overlay spawn");
@@ -64,23 +80,47 @@ pub fn spawn_game_over_overlay(
 
     let font = fonts.orbitron.clone();
 
-    commands.spawn((
-        Text::new("Game over"),
-        TextFont {
-            font,
-            font_size: 80.0,
-            ..default()
-        },
-        TextColor(Color::WHITE),
-        TextLayout::new_with_justify(Justify::Center),
-        Node {
-            position_type: PositionType::Absolute,
-            width: Val::Percent(100.0),
-            height: Val::Percent(100.0),
-            justify_content: JustifyContent::Center,
-            align_items: AlignItems::Center,
-            ..default()
-        },
-        GameOverOverlay,
-    ));
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            GameOverOverlay,
+        ))
+        .with_children(|menu| {
+            menu.spawn((
+                Text::new("Game over"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 80.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+            #[cfg(not(target_arch = "wasm32"))]
+            spawn_menu_button(menu, "Quit", font, 0, GameOverButton::Quit);
+        });
+}
+
+/// System that handles the game-over overlay buttons.
+pub fn handle_game_over_buttons(
+    interactions: Query<(&Interaction, &GameOverButton), Changed<Interaction>>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    for (interaction, button) in interactions.iter() {
+        if *interaction == Interaction::Pressed {
+            match button {
+                GameOverButton::Quit => {
+                    app_exit.write(AppExit::Success);
+                }
+            }
+        }
+    }
 }
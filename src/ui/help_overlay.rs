@@ -0,0 +1,125 @@
+//! Keybinding help overlay
+//!
+//! Purpose
+//! - Lists every key binding of the game, so players don't have to look them up in the README.
+//!   The list is generated from the `InputMap` resource (`src/input_map.rs`): gameplay keys
+//!   first, then the keys that need cheat mode.
+//!
+//! User flow
+//! - `F1` or `?` (`Shift` + `/`) opens the overlay and closes it again.
+//!
+//! Scheduling summary (Update, `UiSystems::Input`)
+//! - `toggle_help_overlay` updates `HelpOverlayState`.
+//! - `ensure_help_overlay` spawns/despawns the overlay when the state changes.
+
+use bevy::prelude::*;
+
+use crate::input_map::{InputMap, KeyBinding, KeyChord};
+use crate::ui::fonts::UiFonts;
+
+/// Keys opening and closing the help.
+pub const HELP_KEYS: [KeyChord; 2] = [
+    KeyChord::key(KeyCode::F1),
+    KeyChord::with(KeyCode::ShiftLeft, KeyCode::Slash),
+];
+
+/// Whether the help is open.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HelpOverlayState {
+    pub open: bool,
+}
+
+/// Marker for the help overlay root.
+#[derive(Component, Debug)]
+pub struct HelpOverlayRoot;
+
+fn binding_lines<'a>(bindings: impl Iterator<Item = &'a KeyBinding>) -> Vec<String> {
+    bindings
+        .map(|binding| format!("{}  {}", binding.keys_label(), binding.action))
+        .collect()
+}
+
+/// Help text for the bindings of `map`.
+pub fn help_text(map: &InputMap) -> String {
+    let mut lines = binding_lines(map.gameplay());
+    lines.push(String::new());
+    lines.push("Cheat mode".to_string());
+    lines.extend(binding_lines(map.cheats()));
+    lines.join("\n")
+}
+
+fn help_pressed(keyboard: &ButtonInput<KeyCode>) -> bool {
+    HELP_KEYS.iter().any(|chord| {
+        keyboard.just_pressed(chord.key)
+            && chord
+                .modifier
+                .is_none_or(|_| crate::systems::level_switch::shift_held(keyboard))
+    })
+}
+
+/// Toggle the help with `F1` or `?`.
+pub fn toggle_help_overlay(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut state: ResMut<HelpOverlayState>,
+) {
+    if keyboard.is_some_and(|k| help_pressed(&k)) {
+        state.open = !state.open;
+    }
+}
+
+/// Spawn or despawn the overlay based on `HelpOverlayState`.
+pub fn ensure_help_overlay(
+    state: Res<HelpOverlayState>,
+    map: Res<InputMap>,
+    mut commands: Commands,
+    existing: Query<Entity, With<HelpOverlayRoot>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    if !state.open {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !existing.is_empty() {
+        return;
+    }
+    let font = ui_fonts.map(|f| f.orbitron.clone()).unwrap_or_default();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(40.0),
+                left: Val::Px(40.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.9)),
+            HelpOverlayRoot,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new("Keys"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            panel.spawn((
+                Text::new(help_text(&map)),
+                TextFont {
+                    font,
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
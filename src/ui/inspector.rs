@@ -0,0 +1,73 @@
+//! Entity inspector panel.
+//!
+//! Purpose
+//! - Shows the inspector (`src/systems/inspector.rs`) on the left of the screen while it is open:
+//!   the editable fields with the selected one marked, the selected ball or paddle and the active
+//!   size effects.
+//!
+//! How it updates
+//! - `update_inspector_display` rewrites the text and visibility when `InspectorState` changes.
+
+use bevy::prelude::*;
+
+use crate::systems::inspector::InspectorState;
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the inspector text.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InspectorUi;
+
+/// Spawn the (initially hidden) inspector panel once fonts are ready.
+pub fn spawn_inspector_display(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<InspectorUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 1.0, 0.8)),
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(120.0),
+            left: Val::Px(12.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        Visibility::Hidden,
+        InspectorUi,
+    ));
+}
+
+/// Keep the inspector panel in sync with `InspectorState`.
+pub fn update_inspector_display(
+    inspector: Option<Res<InspectorState>>,
+    mut query: Query<(&mut Text, &mut Visibility), With<InspectorUi>>,
+    added: Query<(), Added<InspectorUi>>,
+) {
+    let Some(inspector) = inspector else {
+        return;
+    };
+    if !inspector.is_changed() && added.is_empty() {
+        return;
+    }
+    for (mut text, mut visibility) in query.iter_mut() {
+        if !inspector.open {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        **text = inspector.text.clone();
+        *visibility = Visibility::Inherited;
+    }
+}
@@ -0,0 +1,270 @@
+//! ASCII layout dialog of the designer palette.
+//!
+//! Purpose
+//! - Shares brick layouts as plain text (`level_format::ascii`), e.g. in issues and chat.
+//!
+//! User flow
+//! - "Paste ASCII" in the palette opens an empty dialog. Type or paste a layout (legend lines
+//!   `<char> = <type>` first, then one line per grid row); Enter starts a new line, Ctrl+Enter
+//!   replaces the bricks on the field with the layout, Escape closes the dialog.
+//! - "Copy as ASCII" opens the dialog with the current bricks written as a layout, and logs it.
+//! - While the dialog is open, keystrokes go to it instead of the game. Parse errors are shown
+//!   below the text and leave the field untouched.
+//!
+//! Scheduling summary
+//! - `ascii_dialog_input` (PreUpdate) feeds keystrokes to `AsciiDialog` and sends
+//!   `ImportAsciiLayout` on Ctrl+Enter.
+//! - `handle_ascii_buttons`, `apply_ascii_import` and `update_ascii_dialog_display` run in Update
+//!   (`UiSystems::Input`).
+
+use bevy::ecs::message::{Message, MessageReader, MessageWriter};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+
+use crate::level_format::ascii::{matrix_from_ascii_in, matrix_to_ascii};
+use crate::level_format::BUMPER;
+use crate::level_loader::spawn_brick;
+use crate::physics_config::BrickPhysicsConfig;
+use crate::systems::textures::{ObjectClass, TypeVariantRegistry};
+use crate::ui::fonts::UiFonts;
+use crate::{Brick, BrickTypeId, GridResolution, MarkedForDespawn};
+
+/// Key hints shown in the dialog.
+pub const ASCII_DIALOG_HELP: &str = "Enter: new line | Ctrl+Enter: import | Esc: close";
+
+/// State of the ASCII layout dialog.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct AsciiDialog {
+    pub open: bool,
+    /// Layout being edited
+    pub text: String,
+    /// Result of the last import or copy
+    pub message: String,
+}
+
+/// Palette buttons opening the dialog.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsciiButton {
+    Paste,
+    Copy,
+}
+
+/// Replace the bricks on the field with a parsed layout.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ImportAsciiLayout {
+    /// Brick types on the level grid, 0 = empty
+    pub matrix: Vec<Vec<u8>>,
+}
+
+/// Marker for the dialog text.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AsciiDialogUi;
+
+/// Brick matrix of the bricks on the field, by the cell of `grid` under each brick.
+pub fn layout_matrix(
+    grid: &GridResolution,
+    bricks: impl IntoIterator<Item = (u8, Vec3)>,
+) -> Vec<Vec<u8>> {
+    let mut matrix = vec![vec![0; grid.cols]; grid.rows];
+    for (brick_type, position) in bricks {
+        if let Some((row, col)) = grid.cell_at(position) {
+            matrix[row][col] = brick_type;
+        }
+    }
+    matrix
+}
+
+/// Open the dialog from the palette buttons; "Copy as ASCII" fills it with the current layout.
+pub fn handle_ascii_buttons(
+    interactions: Query<(&Interaction, &AsciiButton), Changed<Interaction>>,
+    mut dialog: ResMut<AsciiDialog>,
+    bricks: Query<(&BrickTypeId, &Transform), (With<Brick>, Without<MarkedForDespawn>)>,
+    grid: Option<Res<GridResolution>>,
+) {
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    for (interaction, button) in interactions.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        dialog.open = true;
+        match button {
+            AsciiButton::Paste => {
+                dialog.text.clear();
+                dialog.message = "Paste or type a layout".to_string();
+            }
+            AsciiButton::Copy => {
+                let matrix = layout_matrix(
+                    &grid,
+                    bricks
+                        .iter()
+                        .map(|(brick_type, transform)| (brick_type.0, transform.translation)),
+                );
+                dialog.text = matrix_to_ascii(&matrix);
+                dialog.message = "Layout copied; select the text to share it".to_string();
+                info!("Level layout as ASCII:\n{}", dialog.text);
+            }
+        }
+    }
+}
+
+/// Feed keystrokes to the open dialog and import the layout on Ctrl+Enter.
+///
+/// Runs in `PreUpdate` before the cheat mode toggle and clears the keyboard state while the dialog
+/// is open, so typing does not trigger gameplay keys.
+pub fn ascii_dialog_input(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut key_events: MessageReader<KeyboardInput>,
+    mut dialog: ResMut<AsciiDialog>,
+    mut imports: MessageWriter<ImportAsciiLayout>,
+    grid: Option<Res<GridResolution>>,
+    mut control_held: Local<bool>,
+) {
+    if !dialog.open {
+        key_events.clear();
+        *control_held = false;
+        return;
+    }
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    let resolution = (grid.rows, grid.cols);
+    for event in key_events.read() {
+        if event.logical_key == Key::Control {
+            *control_held = event.state == ButtonState::Pressed;
+            continue;
+        }
+        if event.state != ButtonState::Pressed || !dialog.open {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter if *control_held => match matrix_from_ascii_in(&dialog.text, resolution) {
+                Ok(matrix) => {
+                    imports.write(ImportAsciiLayout { matrix });
+                    dialog.message = "Layout imported".to_string();
+                    dialog.open = false;
+                }
+                Err(e) => dialog.message = e,
+            },
+            Key::Enter => dialog.text.push('\n'),
+            Key::Escape => dialog.open = false,
+            Key::Backspace => {
+                dialog.text.pop();
+            }
+            Key::Space => dialog.text.push(' '),
+            Key::Character(text) if !*control_held => dialog.text.push_str(text),
+            _ => {}
+        }
+    }
+    keyboard.reset_all();
+}
+
+/// Replace the bricks on the field with an imported layout.
+pub fn apply_ascii_import(
+    mut imports: MessageReader<ImportAsciiLayout>,
+    bricks: Query<Entity, With<Brick>>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    brick_config: Res<BrickPhysicsConfig>,
+    registry: Option<Res<TypeVariantRegistry>>,
+    grid: Option<Res<GridResolution>>,
+    mut fallback_material: Local<Option<Handle<StandardMaterial>>>,
+) {
+    let Some(import) = imports.read().last() else {
+        return;
+    };
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    for entity in bricks.iter() {
+        commands.entity(entity).despawn();
+    }
+    let mut placed = 0;
+    for (row, line) in import.matrix.iter().take(grid.rows).enumerate() {
+        for (col, &brick_type) in line.iter().take(grid.cols).enumerate() {
+            if brick_type < 3 {
+                continue;
+            }
+            placed += 1;
+            if brick_type == BUMPER {
+                crate::systems::bumpers::spawn_bumper(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    &grid,
+                    row,
+                    col,
+                );
+                continue;
+            }
+            let material = registry
+                .as_ref()
+                .and_then(|r| r.get(ObjectClass::Brick, brick_type))
+                .unwrap_or_else(|| {
+                    fallback_material
+                        .get_or_insert_with(|| {
+                            materials.add(StandardMaterial {
+                                base_color: Color::srgb(0.9, 0.1, 0.1),
+                                ..default()
+                            })
+                        })
+                        .clone()
+                });
+            spawn_brick(
+                &mut commands,
+                &mut meshes,
+                material,
+                brick_type,
+                &grid,
+                row,
+                col,
+                &brick_config,
+            );
+        }
+    }
+    info!("Imported ASCII layout with {} bricks", placed);
+}
+
+/// Show the dialog while it is open: key hints, the layout and the last message.
+pub fn update_ascii_dialog_display(
+    mut commands: Commands,
+    dialog: Res<AsciiDialog>,
+    ui_fonts: Option<Res<UiFonts>>,
+    mut existing: Query<(Entity, &mut Text), With<AsciiDialogUi>>,
+) {
+    if !dialog.is_changed() {
+        return;
+    }
+    if !dialog.open {
+        for (entity, _) in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    let text = format!(
+        "ASCII layout ({ASCII_DIALOG_HELP})\n{}_\n{}",
+        dialog.text, dialog.message
+    );
+    if let Some((_, mut current)) = existing.iter_mut().next() {
+        current.0 = text;
+        return;
+    }
+    commands.spawn((
+        Text::new(text),
+        TextFont {
+            font: ui_fonts
+                .as_ref()
+                .map(|f| f.orbitron.clone())
+                .unwrap_or_default(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(48.0),
+            right: Val::Px(12.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        AsciiDialogUi,
+    ));
+}
@@ -0,0 +1,67 @@
+//! Level clock HUD.
+//!
+//! Purpose
+//! - Shows the current level's play time (`Time: m:ss.t`) at the top centre of the screen.
+//! - Optional: only spawned when `show_level_clock: true` is set in the gameplay config
+//!   (`GameplayConfig::show_level_clock`).
+//!
+//! How it updates
+//! - `update_level_clock_display` rewrites the text when `LevelClock` changes; the clock itself
+//!   stands still while paused, so the readout does too.
+
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::gameplay_config::GameplayConfig;
+use crate::systems::level_clock::{format_clock, LevelClock};
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the level clock text.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LevelClockDisplayUi;
+
+/// Spawn the clock readout once, if enabled in the gameplay config and fonts are ready.
+pub fn spawn_level_clock_display(
+    mut commands: Commands,
+    config: Option<Res<GameplayConfig>>,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<LevelClockDisplayUi>>,
+) {
+    if !existing.is_empty() || !config.is_some_and(|c| c.show_level_clock) {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands.spawn((
+        Text::new(format!("Time: {}", format_clock(Default::default()))),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 28.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        LevelClockDisplayUi,
+    ));
+}
+
+/// Keep the readout in sync with `LevelClock`.
+pub fn update_level_clock_display(
+    clock: Option<Res<LevelClock>>,
+    mut query: Query<&mut Text, With<LevelClockDisplayUi>>,
+) {
+    let Some(clock) = clock.filter(|c| c.is_changed()) else {
+        return;
+    };
+    for mut text in query.iter_mut() {
+        **text = format!("Time: {}", format_clock(clock.elapsed));
+    }
+}
@@ -0,0 +1,252 @@
+//! Level intro text (campaign storytelling).
+//!
+//! Purpose
+//! - Levels may define `intro` lines (`LevelDefinition::intro`). When such a level starts, the lines
+//!   are typed out one after another over a dimmed screen before gameplay begins.
+//!
+//! Lifecycle
+//! - `start_level_intro` inserts the [`LevelIntro`] resource and spawns the overlay the first time
+//!   a level number becomes current (restarting the same level does not replay it).
+//! - While `LevelIntro` exists, physics stays frozen (`pause::apply_pause_to_physics`), pausing is
+//!   blocked and the level clock does not run.
+//! - `play_level_intro` types each line at [`INTRO_CHARS_PER_SEC`] and moves on after
+//!   [`INTRO_LINE_HOLD_SECS`]. Enter / Space / left click / gamepad South finish the current line or
+//!   skip to the next one; Escape skips the whole intro. Once the last line is done the resource
+//!   and overlay are removed on the following frame.
+
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::level_loader::CurrentLevel;
+use crate::ui::fonts::UiFonts;
+
+/// Typing speed, in characters per second.
+pub const INTRO_CHARS_PER_SEC: f32 = 40.0;
+/// How long a fully typed line stays before the next one starts, in seconds.
+pub const INTRO_LINE_HOLD_SECS: f32 = 2.0;
+/// Keys finishing the current line or moving on to the next one (as do a click and gamepad South).
+pub const INTRO_ADVANCE_KEYS: [KeyCode; 3] = [KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space];
+/// Key skipping the whole intro.
+pub const INTRO_SKIP_KEY: KeyCode = KeyCode::Escape;
+
+/// Progress through the current level's intro. Present only while the intro is showing.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct LevelIntro {
+    pub lines: Vec<String>,
+    /// Index of the line being shown
+    pub line: usize,
+    /// Characters of the current line revealed so far
+    pub typed: f32,
+    /// Seconds the current line has been fully visible
+    pub held: f32,
+}
+
+impl LevelIntro {
+    pub fn new(lines: Vec<String>) -> Self {
+        Self {
+            lines,
+            line: 0,
+            typed: 0.0,
+            held: 0.0,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.line >= self.lines.len()
+    }
+
+    fn line_len(&self) -> usize {
+        self.lines.get(self.line).map_or(0, |l| l.chars().count())
+    }
+
+    fn line_complete(&self) -> bool {
+        self.typed as usize >= self.line_len()
+    }
+
+    /// The part of the current line typed so far.
+    pub fn visible_text(&self) -> &str {
+        let Some(line) = self.lines.get(self.line) else {
+            return "";
+        };
+        let end = line
+            .char_indices()
+            .nth(self.typed as usize)
+            .map_or(line.len(), |(i, _)| i);
+        &line[..end]
+    }
+
+    fn next_line(&mut self) {
+        self.line += 1;
+        self.typed = 0.0;
+        self.held = 0.0;
+    }
+
+    /// Advance typing and line holding by `dt` seconds.
+    pub fn tick(&mut self, dt: f32) {
+        if self.is_finished() {
+            return;
+        }
+        if !self.line_complete() {
+            self.typed = (self.typed + dt * INTRO_CHARS_PER_SEC).min(self.line_len() as f32);
+            return;
+        }
+        self.held += dt;
+        if self.held >= INTRO_LINE_HOLD_SECS {
+            self.next_line();
+        }
+    }
+
+    /// Player input: finish typing the current line, or move on if it is already complete.
+    pub fn advance(&mut self) {
+        if self.line_complete() {
+            self.next_line();
+        } else {
+            self.typed = self.line_len() as f32;
+        }
+    }
+
+    /// Player input: skip the rest of the intro.
+    pub fn skip(&mut self) {
+        self.line = self.lines.len();
+    }
+}
+
+/// Root node of the intro overlay.
+#[derive(Component, Debug)]
+pub struct LevelIntroOverlay;
+
+/// The typed text inside the intro overlay.
+#[derive(Component, Debug)]
+pub struct LevelIntroText;
+
+/// Start the intro when a level with `intro` lines becomes current.
+pub fn start_level_intro(
+    mut commands: Commands,
+    current_level: Option<Res<CurrentLevel>>,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<LevelIntroOverlay>>,
+    mut shown_for: Local<Option<u32>>,
+) {
+    let Some(level) = current_level.filter(|l| l.is_changed()) else {
+        return;
+    };
+    if *shown_for == Some(level.0.number) {
+        return;
+    }
+    // Without fonts the intro could not be read, so don't hold up gameplay for it
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    *shown_for = Some(level.0.number);
+    if level.0.intro.is_empty() {
+        return;
+    }
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+    commands.insert_resource(LevelIntro::new(level.0.intro.clone()));
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Percent(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            GlobalZIndex(i32::MAX - 2),
+            LevelIntroOverlay,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: fonts.orbitron.clone(),
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+                LevelIntroText,
+            ));
+        });
+}
+
+/// Type out the intro, handle advance / skip input and tear it down when done.
+pub fn play_level_intro(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    gamepads: Query<&Gamepad>,
+    intro: Option<ResMut<LevelIntro>>,
+    mut texts: Query<&mut Text, With<LevelIntroText>>,
+    overlays: Query<Entity, With<LevelIntroOverlay>>,
+) {
+    let Some(mut intro) = intro else {
+        return;
+    };
+    // Finished last frame: removing the resource a frame late keeps the key that ended the intro
+    // from also reaching gameplay input (e.g. Escape pausing the game)
+    if intro.is_finished() {
+        commands.remove_resource::<LevelIntro>();
+        for entity in overlays.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let keys = keyboard.as_deref();
+    if keys.is_some_and(|k| k.just_pressed(INTRO_SKIP_KEY)) {
+        intro.skip();
+        return;
+    }
+    let advance = keys.is_some_and(|k| k.any_just_pressed(INTRO_ADVANCE_KEYS))
+        || mouse.is_some_and(|m| m.just_pressed(MouseButton::Left))
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::South));
+    if advance {
+        intro.advance();
+    } else {
+        intro.tick(time.delta_secs());
+    }
+
+    let visible = intro.visible_text();
+    for mut text in texts.iter_mut() {
+        if text.0 != visible {
+            text.0 = visible.to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_type_out_then_hold_then_advance() {
+        let mut intro = LevelIntro::new(vec!["Héllo".into(), "World".into()]);
+        intro.tick(2.0 / INTRO_CHARS_PER_SEC);
+        assert_eq!(intro.visible_text(), "Hé");
+        intro.tick(1.0);
+        assert_eq!(intro.visible_text(), "Héllo");
+        intro.tick(INTRO_LINE_HOLD_SECS);
+        assert_eq!(intro.line, 1);
+        assert_eq!(intro.visible_text(), "");
+    }
+
+    #[test]
+    fn advance_completes_the_line_before_moving_on() {
+        let mut intro = LevelIntro::new(vec!["One".into(), "Two".into()]);
+        intro.advance();
+        assert_eq!(intro.visible_text(), "One");
+        intro.advance();
+        assert_eq!(intro.line, 1);
+        intro.skip();
+        assert!(intro.is_finished());
+    }
+}
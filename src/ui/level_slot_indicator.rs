@@ -0,0 +1,92 @@
+//! Level slot indicator.
+//!
+//! Purpose
+//! - While cheat mode is on, shows where the current level sits among the levels that `N`, `P`
+//!   and `Shift` + `L` switch between (`Slot 2/7`), under the level label in the top-left corner.
+//!   Next to the slot it says when switching stops at the ends instead of wrapping around.
+//!
+//! How it updates
+//! - `update_level_slot_indicator` rewrites the text when the cheat mode, the current level or the
+//!   `LevelSwitchState` changes, and hides the readout outside cheat mode.
+
+use bevy::prelude::*;
+
+use crate::level_loader::CurrentLevel;
+use crate::systems::cheat_mode::CheatModeState;
+use crate::systems::level_switch::LevelSwitchState;
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the level slot text.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LevelSlotIndicatorUi;
+
+/// Readout for level `number`, or `None` if it is not one of the switchable levels.
+pub fn level_slot_text(state: &LevelSwitchState, number: u32) -> Option<String> {
+    let (slot, total) = state.slot_position(number)?;
+    let edges = if state.wraps() { "" } else { " (no wrap)" };
+    Some(format!("Slot {}/{}{}", slot, total, edges))
+}
+
+/// Spawn the (initially hidden) indicator once fonts are ready.
+pub fn spawn_level_slot_indicator(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<LevelSlotIndicatorUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.6, 0.3)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(48.0),
+            left: Val::Px(12.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        LevelSlotIndicatorUi,
+    ));
+}
+
+/// Keep the indicator in sync with cheat mode, the current level and the level list.
+pub fn update_level_slot_indicator(
+    cheat: Option<Res<CheatModeState>>,
+    current_level: Option<Res<CurrentLevel>>,
+    switch_state: Option<Res<LevelSwitchState>>,
+    mut query: Query<(&mut Text, &mut Visibility), With<LevelSlotIndicatorUi>>,
+    added: Query<(), Added<LevelSlotIndicatorUi>>,
+) {
+    let changed = cheat.as_ref().is_some_and(|c| c.is_changed())
+        || current_level.as_ref().is_some_and(|l| l.is_changed())
+        || switch_state.as_ref().is_some_and(|s| s.is_changed());
+    if !changed && added.is_empty() {
+        return;
+    }
+    let text = match (cheat, current_level, switch_state) {
+        (Some(cheat), Some(level), Some(state)) if cheat.is_active() => {
+            level_slot_text(&state, level.0.number)
+        }
+        _ => None,
+    };
+    for (mut current, mut visibility) in query.iter_mut() {
+        match &text {
+            Some(text) => {
+                **current = text.clone();
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Log console.
+//!
+//! Purpose
+//! - Shows the recent log lines kept by `systems::logging` at the bottom left of the screen while
+//!   the console is open (cheat mode, `F12`), newest last, e.g. `WARN  level_switch: No level 75`.
+//!
+//! How it updates
+//! - `update_log_console` rewrites the text when `LogConsoleState` changes or new lines were
+//!   logged, showing `LOG_CONSOLE_ROWS` lines ending `scroll` lines before the newest one.
+
+use bevy::prelude::*;
+
+use crate::systems::logging::{LogBuffer, LogConsoleState, LogLine, LogLines, LOG_CONSOLE_ROWS};
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the log console text.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LogConsoleUi;
+
+/// One console row: the level and the last part of the target, e.g. `INFO  respawn: ...`.
+fn console_row(line: &LogLine) -> String {
+    let target = line.target.rsplit("::").next().unwrap_or(&line.target);
+    format!("{:<5} {}: {}", line.level.as_str(), target, line.message)
+}
+
+/// Text of the console: `rows` lines ending `scroll` lines before the newest one.
+pub fn log_console_text(lines: &LogLines, scroll: usize, rows: usize) -> String {
+    let shown: Vec<String> = lines
+        .lines
+        .iter()
+        .rev()
+        .skip(scroll)
+        .take(rows)
+        .map(console_row)
+        .collect();
+    if shown.is_empty() {
+        return "No log lines yet".to_string();
+    }
+    let mut text = shown.into_iter().rev().collect::<Vec<_>>().join("\n");
+    if scroll > 0 {
+        text.push_str(&format!("\n-- {scroll} newer lines below (End) --"));
+    }
+    text
+}
+
+/// Spawn the (initially hidden) console once fonts are ready.
+pub fn spawn_log_console(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<LogConsoleUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.85, 0.85, 0.85)),
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(12.0),
+            left: Val::Px(12.0),
+            max_width: Val::Percent(60.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        GlobalZIndex(i32::MAX - 2),
+        Visibility::Hidden,
+        LogConsoleUi,
+    ));
+}
+
+/// Keep the console in sync with the log and `LogConsoleState`.
+pub fn update_log_console(
+    console: Option<Res<LogConsoleState>>,
+    buffer: Option<Res<LogBuffer>>,
+    mut query: Query<(&mut Text, &mut Visibility), With<LogConsoleUi>>,
+    added: Query<(), Added<LogConsoleUi>>,
+    mut shown_written: Local<Option<u64>>,
+) {
+    let Some(console) = console else {
+        return;
+    };
+    if !console.open {
+        if console.is_changed() {
+            for (_, mut visibility) in query.iter_mut() {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+        *shown_written = None;
+        return;
+    }
+    let lines = buffer.map(|buffer| buffer.snapshot()).unwrap_or_default();
+    if !console.is_changed() && added.is_empty() && *shown_written == Some(lines.written) {
+        return;
+    }
+    *shown_written = Some(lines.written);
+    let message = log_console_text(&lines, console.scroll, LOG_CONSOLE_ROWS);
+    for (mut text, mut visibility) in query.iter_mut() {
+        **text = message.clone();
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
@@ -45,15 +45,43 @@ impl fmt::Display for UiSystemError {
 
 impl std::error::Error for UiSystemError {}
 
+pub mod assist_notice;
+pub mod banner;
+pub mod brick_legend;
+pub mod chain_popup;
+pub mod cheat_console;
 pub mod cheat_indicator;
+pub mod checkpoint_display;
+pub mod crash_notice;
+pub mod effects_hud;
+pub mod energy_meter;
+pub mod focus;
 pub mod fonts;
+pub mod game_complete_screen;
 pub mod game_over_overlay;
 pub mod gravity_indicator;
+pub mod help_overlay;
+pub mod inspector;
+pub mod level_ascii;
+pub mod level_clock_display;
+pub mod level_intro;
 pub mod level_label;
+pub mod level_slot_indicator;
 pub mod lives_counter;
+pub mod log_console;
 pub mod palette;
 pub mod pause_overlay;
+pub mod playlist_editor;
+pub mod playlist_summary;
+#[cfg(target_arch = "wasm32")]
+pub mod pointer_lock;
+pub mod preflight_panel;
+pub mod profile_select;
 pub mod score_display;
+pub mod sound_cues;
+pub mod team_score_display;
+pub mod toasts;
+pub mod tutorial_prompt;
 
 // ============================================================================
 // Result-Returning System Wrapper Pattern (Constitution VIII: Fallible Systems)
@@ -125,11 +153,48 @@ impl Plugin for UiPlugin {
         // Initialize resources
         app.init_resource::<palette::PaletteState>();
         app.init_resource::<palette::SelectedBrick>();
+        app.init_resource::<palette::SymmetryMode>();
+        app.init_resource::<level_ascii::AsciiDialog>();
+        app.init_resource::<focus::UiFocus>();
+        app.init_resource::<playlist_editor::PlaylistEditorState>();
+        app.init_resource::<help_overlay::HelpOverlayState>();
+        app.init_resource::<brick_legend::BrickLegendState>();
+        app.init_resource::<crate::input_map::InputMap>();
+        app.init_resource::<crate::systems::playlist::Playlist>();
+        app.add_message::<crate::systems::playlist::PlaylistStartRequested>();
+        app.add_message::<crate::systems::profiles::ProfileSelected>();
+        app.add_message::<crate::systems::scoring::ChainBonusAwarded>();
+        app.add_message::<crate::systems::respawn::LifeLostEvent>();
+        app.add_message::<crate::systems::powerups::PowerupCollected>();
+        app.add_message::<crate::systems::ball_save::BallSaved>();
+        app.add_message::<crate::systems::game_complete::RestartGameRequested>();
+        app.add_message::<level_ascii::ImportAsciiLayout>();
         app.insert_resource(level_label::AccessibilityAnnouncement::default());
 
         // UI asset initialization
         app.add_systems(Startup, setup_ui_assets);
 
+        // The ASCII layout dialog takes the keyboard before focus navigation and gameplay keys
+        app.add_systems(
+            PreUpdate,
+            level_ascii::ascii_dialog_input
+                .after(bevy::input::InputSystems)
+                .before(bevy::ui::UiSystems::Focus)
+                .before(crate::systems::cheat_mode::toggle_cheat_mode_input),
+        );
+
+        // Keyboard/gamepad focus, after Bevy's pointer focus so both drive `Interaction`
+        app.add_systems(
+            PreUpdate,
+            (
+                focus::navigate_focus,
+                focus::activate_focused,
+                focus::highlight_focus,
+            )
+                .chain()
+                .after(bevy::ui::UiSystems::Focus),
+        );
+
         // UI spawn systems
         app.add_systems(
             Update,
@@ -138,6 +203,17 @@ impl Plugin for UiPlugin {
                 lives_counter::spawn_lives_counter,
                 level_label::spawn_level_label,
                 gravity_indicator::spawn_gravity_indicator,
+                effects_hud::spawn_effects_hud,
+                level_clock_display::spawn_level_clock_display,
+                checkpoint_display::spawn_checkpoint_display,
+                team_score_display::spawn_team_score_display,
+                energy_meter::spawn_energy_meter,
+                assist_notice::spawn_assist_notice,
+                tutorial_prompt::spawn_tutorial_prompt,
+                level_slot_indicator::spawn_level_slot_indicator,
+                cheat_console::spawn_cheat_console_display,
+                inspector::spawn_inspector_display,
+                log_console::spawn_log_console,
             )
                 .in_set(UiSystems::Spawn),
         );
@@ -148,16 +224,48 @@ impl Plugin for UiPlugin {
             (
                 lives_counter::update_lives_counter,
                 game_over_overlay::spawn_game_over_overlay,
+                game_over_overlay::handle_game_over_buttons,
                 cheat_indicator::handle_cheat_indicator,
                 level_label::sync_with_current_level,
                 gravity_indicator::update_gravity_indicator,
+                effects_hud::update_effects_hud,
+                chain_popup::show_chain_popup,
+                chain_popup::fade_chain_popups,
+                banner::animate_banners,
+                sound_cues::spawn_sound_cues,
+                sound_cues::fade_sound_cues,
+                (
+                    level_clock_display::update_level_clock_display,
+                    checkpoint_display::update_checkpoint_display,
+                    team_score_display::update_team_score_display,
+                    energy_meter::update_energy_meter,
+                    assist_notice::update_assist_notice,
+                    tutorial_prompt::update_tutorial_prompt,
+                    level_slot_indicator::update_level_slot_indicator,
+                ),
+                cheat_console::update_cheat_console_display,
+                (
+                    inspector::update_inspector_display,
+                    log_console::update_log_console,
+                ),
+                playlist_summary::sync_playlist_summary,
+                playlist_summary::handle_playlist_summary_buttons,
+                (
+                    game_complete_screen::sync_game_complete_screen,
+                    game_complete_screen::handle_game_complete_buttons,
+                ),
+                (
+                    level_intro::start_level_intro,
+                    level_intro::play_level_intro,
+                )
+                    .chain(),
                 score_display::update_score_display_system
                     .after(crate::systems::scoring::detect_milestone_system),
             )
                 .in_set(UiSystems::Update),
         );
 
-        // Palette input systems
+        // Palette and playlist editor input systems
         app.add_systems(
             Update,
             (
@@ -167,12 +275,105 @@ impl Plugin for UiPlugin {
                 palette::update_palette_selection_feedback,
                 palette::update_ghost_preview,
                 palette::place_bricks_on_drag,
+                palette::pick_or_erase_bricks,
+                palette::handle_symmetry_button,
+                palette::update_symmetry_label,
+                (
+                    level_ascii::handle_ascii_buttons,
+                    level_ascii::apply_ascii_import,
+                    level_ascii::update_ascii_dialog_display,
+                )
+                    .chain(),
+                (
+                    palette::handle_drop_chance_buttons,
+                    palette::update_drop_chance_label,
+                )
+                    .chain(),
+                (
+                    playlist_editor::toggle_playlist_editor,
+                    playlist_editor::ensure_playlist_editor_ui,
+                    playlist_editor::handle_playlist_editor_buttons,
+                    playlist_editor::update_playlist_editor_text,
+                )
+                    .chain(),
+                (
+                    help_overlay::toggle_help_overlay,
+                    help_overlay::ensure_help_overlay,
+                )
+                    .chain(),
+                (
+                    brick_legend::toggle_brick_legend,
+                    brick_legend::tint_bricks_by_category,
+                    brick_legend::ensure_brick_legend,
+                )
+                    .chain(),
+            )
+                .in_set(UiSystems::Input),
+        );
+
+        // Profile selection at startup (profiles are stored on disk, so native only)
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(
+            Update,
+            (
+                profile_select::spawn_profile_select,
+                profile_select::handle_profile_select_buttons,
             )
+                .chain()
+                .in_set(UiSystems::Input),
+        );
+
+        // Notice of a crash report left by the previous session (written on native builds only)
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(
+            Update,
+            (
+                crash_notice::spawn_crash_notice,
+                crash_notice::handle_crash_notice_button,
+            )
+                .chain()
+                .in_set(UiSystems::Input),
+        );
+
+        // Missing assets found by the preflight check, in debug builds
+        #[cfg(all(not(target_arch = "wasm32"), debug_assertions))]
+        app.add_systems(
+            Update,
+            (
+                preflight_panel::spawn_preflight_panel,
+                preflight_panel::handle_preflight_panel_button,
+            )
+                .chain()
+                .in_set(UiSystems::Input),
+        );
+
+        // Mouse capture prompt, lock loss and fullscreen button in the browser; left alone when an
+        // embedding app owns the window
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(
+            Update,
+            (
+                pointer_lock::ensure_pointer_lock_banner,
+                pointer_lock::detect_pointer_lock_loss,
+                pointer_lock::update_pointer_lock_banner,
+                pointer_lock::handle_fullscreen_button,
+            )
+                .chain()
+                .run_if(crate::plugins::manages_window)
                 .in_set(UiSystems::Input),
         );
 
         // Observer for level started events
         app.add_observer(level_label::on_level_started);
+        // Announcement banners
+        app.add_observer(banner::on_show_banner);
+        app.add_observer(banner::announce_level_started);
+        // Toasts (adaptive hints)
+        app.add_observer(toasts::on_show_toast);
+        app.add_systems(
+            Update,
+            (toasts::expire_toasts, toasts::handle_toast_buttons).in_set(UiSystems::Input),
+        );
     }
 }
 
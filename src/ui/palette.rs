@@ -11,13 +11,23 @@
 //! - Click a preview to select a type (`handle_palette_selection`), which updates `SelectedBrick`.
 //! - A "ghost" preview follows the cursor over the grid (`update_ghost_preview`).
 //! - Hold the left mouse button and drag over grid cells to place bricks (`place_bricks_on_drag`).
+//! - The "Symmetry" button cycles `SymmetryMode` (off, mirror-X, mirror-Z, 4-way); placing a brick
+//!   also places its mirrored counterparts (`handle_symmetry_button`).
+//! - With no type selected, click an existing brick to select its type; right-click a brick to
+//!   erase it (`pick_or_erase_bricks`).
+//! - "Paste ASCII" and "Copy as ASCII" open the ASCII layout dialog (`ui::level_ascii`) to import
+//!   or share the layout as text.
+//! - The "Level Metadata" section adjusts the current level's powerup drop chance with `-`/`+`
+//!   buttons (`handle_drop_chance_buttons`); the first edit copies the global drop table into a
+//!   per-level override.
 //!
 //! Integration details
 //! - Uses `TypeVariantRegistry` when available to resolve `StandardMaterial` handles for previews;
 //!   falls back to simple colors if materials are not yet ready.
 //! - Text uses `UiFonts` when present; if missing (e.g., early WASM frames), the UI still spawns
 //!   with default font handles.
-//! - Grid conversion uses camera raycasting to map the cursor to world and grid coordinates.
+//! - Grid conversion and brick picking use camera rays (`systems::picking`): empty cells are found
+//!   on the ground plane, existing bricks by casting the ray against their colliders.
 //!
 //! Scheduling summary (Update)
 //! - `toggle_palette` updates `PaletteState`.
@@ -25,18 +35,24 @@
 //! - `handle_palette_selection` reacts to button `Interaction` changes.
 //! - `update_palette_selection_feedback` highlights the selected preview.
 //! - `update_ghost_preview` and `place_bricks_on_drag` manage visual feedback and placement.
+//! - `handle_symmetry_button` and `update_symmetry_label` cycle and display the symmetry mode.
+//! - `pick_or_erase_bricks` selects or erases the brick under the cursor.
+//! - `handle_drop_chance_buttons` and `update_drop_chance_label` edit and display the drop chance.
 
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
+use crate::gameplay_config::{DropTable, GameplayConfig, MAX_DROP_CHANCE};
 use crate::level_format::{INDESTRUCTIBLE_BRICK, SIMPLE_BRICK};
+use crate::level_loader::CurrentLevel;
+use crate::systems::picking::{cursor_to_grid, BrickPicker};
+use crate::systems::powerups::active_drop_table;
 use crate::systems::textures::loader::ObjectClass;
 use crate::systems::textures::TypeVariantRegistry;
+use crate::ui::focus::Focusable;
 use crate::ui::fonts::UiFonts;
-use crate::{
-    Brick, BrickTypeId, CountsTowardsCompletion, CELL_HEIGHT, CELL_WIDTH, GRID_HEIGHT, GRID_WIDTH,
-    PLANE_H, PLANE_W,
-};
+use crate::ui::level_ascii::AsciiButton;
+use crate::{Brick, BrickTypeId, CountsTowardsCompletion, GridResolution, PLANE_H, PLANE_W};
 use bevy_rapier3d::prelude::*;
 
 #[derive(Resource, Default)]
@@ -51,6 +67,65 @@ pub struct SelectedBrick {
     pub type_id: Option<u8>,
 }
 
+/// Symmetry used when placing bricks: every placed brick also paints its mirrored counterparts.
+///
+/// Mirror-X reflects across the middle of the X axis (rows, top/bottom on screen), mirror-Z across
+/// the middle of the Z axis (columns, left/right on screen); 4-way does both.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SymmetryMode {
+    #[default]
+    Off,
+    MirrorX,
+    MirrorZ,
+    FourWay,
+}
+
+impl SymmetryMode {
+    /// The mode after this one, in button order.
+    pub fn next(self) -> Self {
+        match self {
+            SymmetryMode::Off => SymmetryMode::MirrorX,
+            SymmetryMode::MirrorX => SymmetryMode::MirrorZ,
+            SymmetryMode::MirrorZ => SymmetryMode::FourWay,
+            SymmetryMode::FourWay => SymmetryMode::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SymmetryMode::Off => "Symmetry: Off",
+            SymmetryMode::MirrorX => "Symmetry: Mirror X",
+            SymmetryMode::MirrorZ => "Symmetry: Mirror Z",
+            SymmetryMode::FourWay => "Symmetry: 4-way",
+        }
+    }
+
+    /// Cells of `grid` painted when placing at `(grid_x, grid_z)`: the cell itself first, then its
+    /// mirrored counterparts. Cells on a mirror axis are listed once.
+    pub fn cells(self, grid: &GridResolution, grid_x: usize, grid_z: usize) -> Vec<(usize, usize)> {
+        let mirror_x = grid.rows - 1 - grid_x;
+        let mirror_z = grid.cols - 1 - grid_z;
+        let candidates = match self {
+            SymmetryMode::Off => vec![(grid_x, grid_z)],
+            SymmetryMode::MirrorX => vec![(grid_x, grid_z), (mirror_x, grid_z)],
+            SymmetryMode::MirrorZ => vec![(grid_x, grid_z), (grid_x, mirror_z)],
+            SymmetryMode::FourWay => vec![
+                (grid_x, grid_z),
+                (mirror_x, grid_z),
+                (grid_x, mirror_z),
+                (mirror_x, mirror_z),
+            ],
+        };
+        let mut cells = Vec::with_capacity(candidates.len());
+        for cell in candidates {
+            if !cells.contains(&cell) {
+                cells.push(cell);
+            }
+        }
+        cells
+    }
+}
+
 /// Cached ghost preview material handle (loaded once at startup).
 /// Used as fallback when `TypeVariantRegistry` doesn't have a material for the selected brick type.
 /// Constitution VIII: Asset Handle Reuse — load once, reuse everywhere.
@@ -91,6 +166,32 @@ pub struct PreviewViewport {
     pub material: Option<Handle<StandardMaterial>>,
 }
 
+/// Label showing the current level's powerup drop chance in the metadata section.
+#[derive(Component, Debug)]
+pub struct DropChanceLabel;
+
+/// Button that nudges the current level's drop chance by `delta`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DropChanceButton {
+    pub delta: f32,
+}
+
+/// Label showing the current [`SymmetryMode`].
+#[derive(Component, Debug)]
+pub struct SymmetryLabel;
+
+/// Button cycling the [`SymmetryMode`].
+#[derive(Component, Debug)]
+pub struct SymmetryButton;
+
+/// Step applied by the drop chance `-`/`+` buttons.
+pub const DROP_CHANCE_STEP: f32 = 0.05;
+
+/// Text shown by [`DropChanceLabel`] for a drop table.
+pub fn format_drop_chance(table: &DropTable) -> String {
+    format!("Drop chance: {:.0}%", table.chance * 100.0)
+}
+
 pub fn toggle_palette(_keyboard: Option<Res<ButtonInput<KeyCode>>>, _state: ResMut<PaletteState>) {
     // Binding for 'P' removed to reserve the key for previous-level control in cheat mode.
     // Palette can still be toggled via UI or other explicit commands if needed.
@@ -105,6 +206,9 @@ pub fn ensure_palette_ui(
     materials_res: Option<Res<'_, Assets<StandardMaterial>>>,
     mut meshes_res: Option<ResMut<'_, Assets<Mesh>>>,
     ui_fonts: Option<Res<UiFonts>>,
+    current_level: Option<Res<CurrentLevel>>,
+    gameplay: Option<Res<GameplayConfig>>,
+    symmetry: Option<Res<SymmetryMode>>,
     // meshes/materials optional (not present in every test harness) — keep function small for tests
 ) {
     if !state.is_changed() {
@@ -134,6 +238,11 @@ pub fn ensure_palette_ui(
                 .as_ref()
                 .and_then(|m| m.get(h).map(|mat| mat.base_color))
         });
+        let drop_chance_text = gameplay
+            .as_ref()
+            .map(|g| format_drop_chance(active_drop_table(current_level.as_deref(), g)))
+            .unwrap_or_else(|| format_drop_chance(&DropTable::disabled()));
+        let symmetry_text = symmetry.map_or(SymmetryMode::Off, |s| *s).label();
         // Use the project's lightweight text components (Text, TextFont, TextColor)
         // — this avoids pulling heavier UI style types into the test harness.
         commands
@@ -181,6 +290,7 @@ pub fn ensure_palette_ui(
                         material: material_20.clone(),
                     },
                     Button,
+                    Focusable::new(0),
                 ));
 
                 // Indestructible preview (type 90)
@@ -211,8 +321,114 @@ pub fn ensure_palette_ui(
                         material: material_90.clone(),
                     },
                     Button,
+                    Focusable::new(1),
                 ));
 
+                // Symmetry painting: the button cycles the mode, its label shows the current one
+                parent
+                    .spawn((
+                        Node {
+                            margin: UiRect::all(Val::Px(4.0)),
+                            padding: UiRect::horizontal(Val::Px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                        SymmetryButton,
+                        Button,
+                        Focusable::new(2),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new(symmetry_text),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            SymmetryLabel,
+                        ));
+                    });
+
+                // ASCII layouts: import from text or copy the current layout as text
+                for (order, (label, button)) in [
+                    ("Paste ASCII", AsciiButton::Paste),
+                    ("Copy as ASCII", AsciiButton::Copy),
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    parent
+                        .spawn((
+                            Node {
+                                margin: UiRect::all(Val::Px(4.0)),
+                                padding: UiRect::horizontal(Val::Px(4.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                            button,
+                            Button,
+                            Focusable::new(3 + order as i32),
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                Text::new(label),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                }
+
+                // Level metadata: per-level powerup drop chance
+                parent.spawn((
+                    Text::new("Level Metadata"),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                parent.spawn((
+                    Text::new(drop_chance_text),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    DropChanceLabel,
+                ));
+                for (order, (label, delta)) in [("-", -DROP_CHANCE_STEP), ("+", DROP_CHANCE_STEP)]
+                    .into_iter()
+                    .enumerate()
+                {
+                    parent
+                        .spawn((
+                            Node {
+                                width: Val::Px(24.0),
+                                height: Val::Px(24.0),
+                                margin: UiRect::all(Val::Px(4.0)),
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                            DropChanceButton { delta },
+                            Button,
+                            Focusable::new(5 + order as i32),
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                Text::new(label),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                }
+
                 // 3D previews will be spawned after the UI node is created to avoid conflicting
                 // mutable borrows of `commands` in the same scope.
             });
@@ -286,6 +502,90 @@ pub fn handle_palette_selection(
     }
 }
 
+/// Cycle the symmetry mode when the symmetry button is pressed.
+pub fn handle_symmetry_button(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<SymmetryButton>)>,
+    symmetry: Option<ResMut<SymmetryMode>>,
+) {
+    let Some(mut symmetry) = symmetry else {
+        return;
+    };
+    for interaction in interactions.iter() {
+        if *interaction == Interaction::Pressed {
+            *symmetry = symmetry.next();
+            info!("{}", symmetry.label());
+        }
+    }
+}
+
+/// Keep the symmetry label in sync with the mode.
+pub fn update_symmetry_label(
+    symmetry: Option<Res<SymmetryMode>>,
+    mut labels: Query<&mut Text, With<SymmetryLabel>>,
+) {
+    let Some(symmetry) = symmetry else {
+        return;
+    };
+    if !symmetry.is_changed() {
+        return;
+    }
+    for mut label in labels.iter_mut() {
+        label.0 = symmetry.label().to_string();
+    }
+}
+
+/// Adjust the current level's drop chance when a `-`/`+` button is pressed.
+///
+/// Levels without their own table get a copy of the global table first, so the edit only
+/// affects the level being designed.
+pub fn handle_drop_chance_buttons(
+    interactions: Query<(&Interaction, &DropChanceButton), Changed<Interaction>>,
+    current_level: Option<ResMut<CurrentLevel>>,
+    gameplay: Option<Res<GameplayConfig>>,
+) {
+    let (Some(mut current_level), Some(gameplay)) = (current_level, gameplay) else {
+        return;
+    };
+    // A changed `CurrentLevel` means a new level to every other system (clock, capsules, chains,
+    // autosave, ...), so the override is edited without flagging the level as changed
+    let level = &mut current_level.bypass_change_detection().0;
+    for (interaction, button) in interactions.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let table = level.drops.get_or_insert_with(|| gameplay.drops.clone());
+        table.chance = (table.chance + button.delta).clamp(0.0, MAX_DROP_CHANCE);
+        info!(
+            "Level {} drop chance set to {:.2}",
+            level.number, table.chance
+        );
+    }
+}
+
+/// Keep the drop chance label in sync with the current level.
+///
+/// Runs after `handle_drop_chance_buttons`, whose edits do not mark `CurrentLevel` as changed, so
+/// button presses refresh the label too.
+pub fn update_drop_chance_label(
+    current_level: Option<Res<CurrentLevel>>,
+    gameplay: Option<Res<GameplayConfig>>,
+    pressed: Query<(), (With<DropChanceButton>, Changed<Interaction>)>,
+    mut labels: Query<&mut Text, With<DropChanceLabel>>,
+) {
+    let Some(gameplay) = gameplay else {
+        return;
+    };
+    // Constitution VIII: Change-driven updates — rewrite on level, config or chance changes
+    let level_changed = current_level.as_ref().is_some_and(|l| l.is_changed());
+    if !level_changed && !gameplay.is_changed() && pressed.is_empty() {
+        return;
+    }
+    let text = format_drop_chance(active_drop_table(current_level.as_deref(), &gameplay));
+    for mut label in labels.iter_mut() {
+        label.0.clone_from(&text);
+    }
+}
+
 /// Resolve the base color to use for a palette preview, falling back to grey when the material is missing.
 fn base_color_for(
     material: &Option<Handle<StandardMaterial>>,
@@ -344,48 +644,6 @@ pub fn update_palette_selection_feedback(
     }
 }
 
-/// Convert cursor position to grid coordinates on the ground plane.
-/// Returns (grid_x, grid_z) indices if cursor is over the play area, or None if outside bounds.
-fn cursor_to_grid(
-    cursor_pos: Vec2,
-    _window: &Window,
-    camera_transform: &GlobalTransform,
-    camera: &Camera,
-) -> Option<(usize, usize)> {
-    // Use Camera::viewport_to_world to get ray from cursor
-    let ray = camera
-        .viewport_to_world(camera_transform, cursor_pos)
-        .ok()?;
-
-    // Intersect with ground plane (y=0)
-    let ray_direction = *ray.direction;
-    if ray_direction.y.abs() < 0.001 {
-        return None; // Ray is parallel to ground
-    }
-
-    let t = -ray.origin.y / ray_direction.y;
-    if t < 0.0 {
-        return None; // Intersection behind camera
-    }
-
-    let intersection = ray.origin + ray_direction * t;
-
-    // Convert world position to grid coordinates
-    // World space: X ∈ [-PLANE_H/2, PLANE_H/2], Z ∈ [-PLANE_W/2, PLANE_W/2]
-    // Grid space: X ∈ [0, GRID_HEIGHT), Z ∈ [0, GRID_WIDTH)
-    let x_normalized = (intersection.x + PLANE_H / 2.0) / PLANE_H;
-    let z_normalized = (intersection.z + PLANE_W / 2.0) / PLANE_W;
-
-    if !(0.0..1.0).contains(&x_normalized) || !(0.0..1.0).contains(&z_normalized) {
-        return None; // Outside play area
-    }
-
-    let grid_x = (x_normalized * GRID_HEIGHT as f32).floor() as usize;
-    let grid_z = (z_normalized * GRID_WIDTH as f32).floor() as usize;
-
-    Some((grid_x, grid_z))
-}
-
 /// Update ghost preview position to follow cursor on grid.
 pub fn update_ghost_preview(
     mut commands: Commands,
@@ -395,6 +653,7 @@ pub fn update_ghost_preview(
     ghost: Query<Entity, With<GhostPreview>>,
     registry: Option<Res<TypeVariantRegistry>>,
     cached_material: Option<Res<GhostPreviewMaterial>>,
+    grid: Option<Res<GridResolution>>,
     mut meshes: ResMut<Assets<Mesh>>,
 ) {
     let Ok(window) = window.single() else {
@@ -424,8 +683,8 @@ pub fn update_ghost_preview(
     };
 
     // Convert cursor to grid position
-    let Some((grid_x, grid_z)) = cursor_to_grid(cursor_pos, window, camera_transform, camera)
-    else {
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    let Some((grid_x, grid_z)) = cursor_to_grid(cursor_pos, camera_transform, camera, &grid) else {
         // Cursor outside play area - remove ghost
         for entity in ghost.iter() {
             commands.entity(entity).despawn();
@@ -434,8 +693,8 @@ pub fn update_ghost_preview(
     };
 
     // Calculate world position from grid coordinates
-    let world_x = -PLANE_H / 2.0 + (grid_x as f32 + 0.5) * CELL_HEIGHT;
-    let world_z = -PLANE_W / 2.0 + (grid_z as f32 + 0.5) * CELL_WIDTH;
+    let world_x = -PLANE_H / 2.0 + (grid_x as f32 + 0.5) * grid.cell_height();
+    let world_z = -PLANE_W / 2.0 + (grid_z as f32 + 0.5) * grid.cell_width();
     let world_pos = Vec3::new(world_x, 0.5, world_z);
 
     // Get material for this brick type from registry or use cached fallback
@@ -463,7 +722,11 @@ pub fn update_ghost_preview(
             .insert(Transform::from_translation(world_pos));
     } else {
         commands.spawn((
-            Mesh3d(meshes.add(Cuboid::new(CELL_HEIGHT * 0.9, 0.4, CELL_WIDTH * 0.9))),
+            Mesh3d(meshes.add(Cuboid::new(
+                grid.cell_height() * 0.9,
+                0.4,
+                grid.cell_width() * 0.9,
+            ))),
             MeshMaterial3d(material),
             Transform::from_translation(world_pos),
             GhostPreview,
@@ -471,16 +734,19 @@ pub fn update_ghost_preview(
     }
 }
 
-/// Place bricks when mouse is held and dragged over grid cells.
+/// Place bricks when mouse is held and dragged over grid cells, mirrored according to the
+/// [`SymmetryMode`].
 pub fn place_bricks_on_drag(
     mut commands: Commands,
     selected: Res<SelectedBrick>,
+    symmetry: Option<Res<SymmetryMode>>,
     mouse: Res<ButtonInput<MouseButton>>,
     window: Query<&Window, With<PrimaryWindow>>,
     camera_query: Query<(&GlobalTransform, &Camera), With<Camera3d>>,
     registry: Option<Res<TypeVariantRegistry>>,
     mut meshes: ResMut<Assets<Mesh>>,
     existing_bricks: Query<&Transform, With<Brick>>,
+    grid: Option<Res<GridResolution>>,
 ) {
     // Only place when left mouse button is held
     if !mouse.pressed(MouseButton::Left) {
@@ -506,50 +772,88 @@ pub fn place_bricks_on_drag(
     };
 
     // Convert cursor to grid position
-    let Some((grid_x, grid_z)) = cursor_to_grid(cursor_pos, window, camera_transform, camera)
-    else {
+    let grid = grid.as_deref().copied().unwrap_or_default();
+    let Some((grid_x, grid_z)) = cursor_to_grid(cursor_pos, camera_transform, camera, &grid) else {
         return;
     };
 
-    // Calculate world position
-    let world_x = -PLANE_H / 2.0 + (grid_x as f32 + 0.5) * CELL_HEIGHT;
-    let world_z = -PLANE_W / 2.0 + (grid_z as f32 + 0.5) * CELL_WIDTH;
-    let world_pos = Vec3::new(world_x, 0.5, world_z);
-
-    // Check if brick already exists at this position (within small tolerance)
-    const POSITION_TOLERANCE: f32 = 0.1;
-    for existing_transform in existing_bricks.iter() {
-        if existing_transform.translation.distance(world_pos) < POSITION_TOLERANCE {
-            return; // Brick already exists here
-        }
-    }
-
     // Get material for this brick type
     let material = registry
         .as_ref()
         .and_then(|r| r.get(ObjectClass::Brick, type_id));
 
-    // Spawn brick with appropriate components
-    let mut brick_entity = commands.spawn((
-        Mesh3d(meshes.add(Cuboid::new(CELL_HEIGHT * 0.9, 1.0, CELL_WIDTH * 0.9))),
-        Transform::from_translation(world_pos),
-        Collider::cuboid(CELL_HEIGHT * 0.45, 0.5, CELL_WIDTH * 0.45),
-        Brick,
-        BrickTypeId(type_id),
-    ));
-
-    // Add material if available
-    if let Some(mat) = material {
-        brick_entity.insert(MeshMaterial3d(mat));
-    }
+    let symmetry = symmetry.map_or(SymmetryMode::Off, |s| *s);
+    let (cell_height, cell_width) = (grid.cell_height(), grid.cell_width());
+    for (grid_x, grid_z) in symmetry.cells(&grid, grid_x, grid_z) {
+        // Calculate world position
+        let world_x = -PLANE_H / 2.0 + (grid_x as f32 + 0.5) * cell_height;
+        let world_z = -PLANE_W / 2.0 + (grid_z as f32 + 0.5) * cell_width;
+        let world_pos = Vec3::new(world_x, 0.5, world_z);
+
+        // Check if brick already exists at this position (within small tolerance)
+        const POSITION_TOLERANCE: f32 = 0.1;
+        if existing_bricks
+            .iter()
+            .any(|existing| existing.translation.distance(world_pos) < POSITION_TOLERANCE)
+        {
+            continue; // Brick already exists here
+        }
 
-    // Indestructible bricks (type 90) should NOT count towards completion
-    if type_id != 90 {
-        brick_entity.insert(CountsTowardsCompletion);
+        // Spawn brick with appropriate components
+        let mut brick_entity = commands.spawn((
+            Mesh3d(meshes.add(Cuboid::new(cell_height * 0.9, 1.0, cell_width * 0.9))),
+            Transform::from_translation(world_pos),
+            Collider::cuboid(cell_height * 0.45, 0.5, cell_width * 0.45),
+            Brick,
+            BrickTypeId(type_id),
+        ));
+
+        // Add material if available
+        if let Some(mat) = material.clone() {
+            brick_entity.insert(MeshMaterial3d(mat));
+        }
+
+        // Indestructible bricks (type 90) should NOT count towards completion
+        if type_id != 90 {
+            brick_entity.insert(CountsTowardsCompletion);
+        }
+
+        info!(
+            "Placed brick type {} at grid ({}, {})",
+            type_id, grid_x, grid_z
+        );
     }
+}
 
-    info!(
-        "Placed brick type {} at grid ({}, {})",
-        type_id, grid_x, grid_z
-    );
+/// While the palette is open, pick the brick under the cursor: a left click with no type selected
+/// selects the brick's type, a right click erases the brick.
+pub fn pick_or_erase_bricks(
+    mut commands: Commands,
+    palette: Res<PaletteState>,
+    mut selected: ResMut<SelectedBrick>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    picker: BrickPicker,
+    bricks: Query<&BrickTypeId, With<Brick>>,
+) {
+    if !palette.open {
+        return;
+    }
+    let select = selected.type_id.is_none() && mouse.just_pressed(MouseButton::Left);
+    let erase = mouse.just_pressed(MouseButton::Right);
+    if !select && !erase {
+        return;
+    }
+    let Some(brick) = picker.brick_under_cursor() else {
+        return;
+    };
+    let Ok(type_id) = bricks.get(brick) else {
+        return;
+    };
+    if erase {
+        commands.entity(brick).despawn();
+        info!("Erased brick type {} ({:?})", type_id.0, brick);
+    } else {
+        selected.type_id = Some(type_id.0);
+        info!("Selected brick type {} from the level", type_id.0);
+    }
 }
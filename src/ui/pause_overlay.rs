@@ -1,12 +1,21 @@
 //! Pause overlay UI component
 //!
 //! This module manages the visual overlay displayed when the game is paused.
-//! It provides functions to spawn and despawn the "PAUSED\nClick to Resume" message.
+//! It provides functions to spawn and despawn the "PAUSED\nClick to Resume" message, together
+//! with focusable "Resume", paddle english, game speed, lighting settings, sound pack and (native
+//! only) display settings and "Quit" buttons for keyboard/gamepad players.
 
 use bevy::prelude::*;
 use bevy::text::Justify;
+use bevy::window::Monitor;
 
+use crate::gameplay_config::GameplayConfig;
 use crate::pause::PauseState;
+use crate::systems::audio::AudioConfig;
+use crate::systems::display::DisplaySettings;
+use crate::systems::lighting::LightingSettings;
+use crate::systems::sound_packs::SoundPacks;
+use crate::ui::focus::spawn_menu_button;
 use crate::ui::fonts::UiFonts;
 use crate::ui::game_over_overlay::GameOverOverlay;
 
@@ -16,6 +25,56 @@ use crate::ui::game_over_overlay::GameOverOverlay;
 #[derive(Component, Debug)]
 pub struct PauseOverlay;
 
+/// Buttons on the pause overlay.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseMenuButton {
+    Resume,
+    /// Cycles how strongly the paddle steers the ball
+    English,
+    /// Cycles the casual game speeds
+    GameSpeed,
+    /// Cycles the shadow presets
+    Shadows,
+    /// Cycles the light brightness presets
+    Brightness,
+    /// Cycles the sound packs
+    SoundPack,
+    /// Cycles windowed, borderless and exclusive fullscreen
+    DisplayMode,
+    /// Cycles the monitors
+    Monitor,
+    /// Switches vsync on or off
+    VSync,
+    /// Cycles the frame caps
+    FrameCap,
+    Quit,
+}
+
+impl PauseMenuButton {
+    /// Label of the button, showing the current settings; `sound_pack` is the pack's name.
+    pub fn label(
+        self,
+        display: &DisplaySettings,
+        lighting: &LightingSettings,
+        gameplay: &GameplayConfig,
+        sound_pack: &str,
+    ) -> String {
+        match self {
+            Self::Resume => "Resume".to_string(),
+            Self::English => format!("English: {}", gameplay.english.label()),
+            Self::GameSpeed => format!("Speed: {}", gameplay.game_speed.label()),
+            Self::Shadows => format!("Shadows: {}", lighting.shadows.label()),
+            Self::Brightness => format!("Lights: {}", lighting.brightness.label()),
+            Self::SoundPack => format!("Sounds: {sound_pack}"),
+            Self::DisplayMode => format!("Display: {}", display.mode.label()),
+            Self::Monitor => format!("Monitor: {}", display.monitor_label()),
+            Self::VSync => format!("VSync: {}", if display.vsync { "On" } else { "Off" }),
+            Self::FrameCap => format!("Frame cap: {}", display.frame_cap_label()),
+            Self::Quit => "Quit".to_string(),
+        }
+    }
+}
+
 /// System that spawns the pause overlay UI when the game is paused.
 ///
 /// Only spawns if the overlay doesn't already exist (prevents duplicates).
@@ -26,6 +85,13 @@ pub fn spawn_pause_overlay(
     overlay_query: Query<(), With<PauseOverlay>>,
     game_over_query: Query<(), With<GameOverOverlay>>,
     ui_fonts: Option<Res<UiFonts>>,
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))] display: Option<
+        Res<DisplaySettings>,
+    >,
+    lighting: Option<Res<LightingSettings>>,
+    gameplay: Option<Res<GameplayConfig>>,
+    audio: Option<Res<AudioConfig>>,
+    sound_packs: Option<Res<SoundPacks>>,
 ) {
     // Don't spawn pause overlay if game-over is active
     if !game_over_query.is_empty() {
@@ -41,28 +107,68 @@ pub fn spawn_pause_overlay(
 
         let font = fonts.orbitron.clone();
 
-        commands.spawn((
-            Text::new("PAUSED\nClick to Resume"),
-            TextFont {
-                font,
-                font_size: 60.0,
-                ..default()
-            },
-            TextColor(Color::WHITE),
-            TextLayout::new_with_justify(Justify::Center),
-            Node {
-                position_type: PositionType::Absolute,
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            PauseOverlay,
-        ));
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                PauseOverlay,
+            ))
+            .with_children(|menu| {
+                menu.spawn((
+                    Text::new("PAUSED\nClick to Resume"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 60.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    TextLayout::new_with_justify(Justify::Center),
+                ));
+                spawn_menu_button(menu, "Resume", font.clone(), 0, PauseMenuButton::Resume);
+                let display = display.as_deref().copied().unwrap_or_default();
+                let lighting = lighting.as_deref().copied().unwrap_or_default();
+                let gameplay = gameplay.as_deref().cloned().unwrap_or_default();
+                let sound_pack = sound_pack_name(audio.as_deref(), sound_packs.as_deref());
+                for (order, button) in [
+                    (1, PauseMenuButton::English),
+                    (2, PauseMenuButton::GameSpeed),
+                    (3, PauseMenuButton::Shadows),
+                    (4, PauseMenuButton::Brightness),
+                    (5, PauseMenuButton::SoundPack),
+                ] {
+                    let label = button.label(&display, &lighting, &gameplay, &sound_pack);
+                    spawn_menu_button(menu, &label, font.clone(), order, button);
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    for (order, button) in [
+                        (6, PauseMenuButton::DisplayMode),
+                        (7, PauseMenuButton::Monitor),
+                        (8, PauseMenuButton::VSync),
+                        (9, PauseMenuButton::FrameCap),
+                    ] {
+                        let label = button.label(&display, &lighting, &gameplay, &sound_pack);
+                        spawn_menu_button(menu, &label, font.clone(), order, button);
+                    }
+                    spawn_menu_button(menu, "Quit", font, 10, PauseMenuButton::Quit);
+                }
+            });
     }
 }
 
+/// Name of the configured sound pack.
+fn sound_pack_name(audio: Option<&AudioConfig>, packs: Option<&SoundPacks>) -> String {
+    let id = audio.and_then(|audio| audio.sound_pack.as_deref());
+    packs.map_or_else(|| SoundPacks::default().name(id), |packs| packs.name(id))
+}
+
 /// System that despawns the pause overlay UI when the game resumes.
 ///
 /// Run condition: Only when PauseState is Active.
@@ -78,3 +184,108 @@ pub fn despawn_pause_overlay(
         }
     }
 }
+
+/// System that handles the pause overlay buttons (mouse, keyboard or gamepad activation).
+pub fn handle_pause_menu_buttons(
+    interactions: Query<(&Interaction, &PauseMenuButton), Changed<Interaction>>,
+    mut pause_state: ResMut<PauseState>,
+    mut app_exit: MessageWriter<AppExit>,
+    mut display: Option<ResMut<DisplaySettings>>,
+    mut lighting: Option<ResMut<LightingSettings>>,
+    mut gameplay: Option<ResMut<GameplayConfig>>,
+    mut audio: Option<ResMut<AudioConfig>>,
+    sound_packs: Option<Res<SoundPacks>>,
+    monitors: Query<(), With<Monitor>>,
+) {
+    for (interaction, button) in interactions.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            PauseMenuButton::Resume => *pause_state = PauseState::Active,
+            PauseMenuButton::English => {
+                if let Some(gameplay) = gameplay.as_mut() {
+                    gameplay.english = gameplay.english.next();
+                }
+            }
+            PauseMenuButton::GameSpeed => {
+                if let Some(gameplay) = gameplay.as_mut() {
+                    gameplay.game_speed = gameplay.game_speed.next();
+                }
+            }
+            PauseMenuButton::Shadows => {
+                if let Some(lighting) = lighting.as_mut() {
+                    lighting.shadows = lighting.shadows.next();
+                }
+            }
+            PauseMenuButton::Brightness => {
+                if let Some(lighting) = lighting.as_mut() {
+                    lighting.brightness = lighting.brightness.next();
+                }
+            }
+            PauseMenuButton::SoundPack => {
+                if let (Some(audio), Some(packs)) = (audio.as_mut(), sound_packs.as_ref()) {
+                    audio.sound_pack = packs.next(audio.sound_pack.as_deref());
+                }
+            }
+            PauseMenuButton::DisplayMode => {
+                if let Some(display) = display.as_mut() {
+                    let mode = display.mode.next();
+                    display.set_mode(mode);
+                }
+            }
+            PauseMenuButton::Monitor => {
+                if let Some(display) = display.as_mut() {
+                    display.next_monitor(monitors.iter().count());
+                }
+            }
+            PauseMenuButton::VSync => {
+                if let Some(display) = display.as_mut() {
+                    display.vsync = !display.vsync;
+                }
+            }
+            PauseMenuButton::FrameCap => {
+                if let Some(display) = display.as_mut() {
+                    display.next_frame_cap();
+                }
+            }
+            PauseMenuButton::Quit => {
+                app_exit.write(AppExit::Success);
+            }
+        }
+    }
+}
+
+/// System that keeps the settings buttons' labels in step with the settings.
+pub fn update_pause_menu_labels(
+    display: Option<Res<DisplaySettings>>,
+    lighting: Option<Res<LightingSettings>>,
+    gameplay: Option<Res<GameplayConfig>>,
+    audio: Option<Res<AudioConfig>>,
+    sound_packs: Option<Res<SoundPacks>>,
+    buttons: Query<(&PauseMenuButton, &Children)>,
+    added: Query<(), Added<PauseMenuButton>>,
+    mut texts: Query<&mut Text>,
+) {
+    let changed = display.as_ref().is_some_and(|d| d.is_changed())
+        || lighting.as_ref().is_some_and(|l| l.is_changed())
+        || gameplay.as_ref().is_some_and(|g| g.is_changed())
+        || audio.as_ref().is_some_and(|a| a.is_changed());
+    if !changed && added.is_empty() {
+        return;
+    }
+    let display = display.as_deref().copied().unwrap_or_default();
+    let lighting = lighting.as_deref().copied().unwrap_or_default();
+    let gameplay = gameplay.as_deref().cloned().unwrap_or_default();
+    let sound_pack = sound_pack_name(audio.as_deref(), sound_packs.as_deref());
+    for (button, children) in buttons.iter() {
+        if matches!(button, PauseMenuButton::Resume | PauseMenuButton::Quit) {
+            continue;
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = button.label(&display, &lighting, &gameplay, &sound_pack);
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Playlist editor UI
+//!
+//! Purpose
+//! - Builds the tournament playlist (`systems::playlist`) in game: pick any level file found in
+//!   `assets/levels` (campaign or custom), append or remove entries, save and start a run.
+//!
+//! User flow
+//! - Toggle the panel with `L` (`toggle_playlist_editor`).
+//! - `<` / `>` step through the discovered levels (`LevelSwitchState`) and "Add" appends the
+//!   picked one. "Remove last" and "Clear" edit the list; editing ends a running playlist.
+//! - "Save" writes the playlist file of the active profile; "Play" starts a run from the first
+//!   entry and closes the panel.
+//! - Every button is `Focusable`, so the panel works with keyboard / gamepad as well as the mouse.
+//!
+//! Scheduling summary (Update, `UiSystems::Input`)
+//! - `toggle_playlist_editor` updates `PlaylistEditorState`.
+//! - `ensure_playlist_editor_ui` spawns/despawns the panel when the state changes.
+//! - `handle_playlist_editor_buttons` applies button presses.
+//! - `update_playlist_editor_text` refreshes the picked level and the entry list.
+
+use bevy::prelude::*;
+
+use crate::systems::level_switch::LevelSwitchState;
+use crate::systems::playlist::{Playlist, PlaylistStartRequested};
+use crate::systems::profiles::CurrentProfile;
+use crate::ui::focus::spawn_menu_button;
+use crate::ui::fonts::UiFonts;
+
+/// Whether the editor is open and which discovered level is picked.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlaylistEditorState {
+    pub open: bool,
+    /// Index into `LevelSwitchState::ordered_levels`
+    pub selected: usize,
+}
+
+/// Marker for the editor panel root.
+#[derive(Component, Debug)]
+pub struct PlaylistEditorRoot;
+
+/// Text showing the picked level.
+#[derive(Component, Debug)]
+pub struct PlaylistPickerLabel;
+
+/// Text listing the playlist entries.
+#[derive(Component, Debug)]
+pub struct PlaylistEntriesText;
+
+/// Buttons on the editor panel.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistEditorButton {
+    PreviousLevel,
+    NextLevel,
+    Add,
+    RemoveLast,
+    Clear,
+    Save,
+    Play,
+    Close,
+}
+
+/// Toggle the editor with `L` (`Shift` + `L` switches to the previous level in cheat mode).
+pub fn toggle_playlist_editor(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut state: ResMut<PlaylistEditorState>,
+) {
+    if keyboard.is_some_and(|k| {
+        k.just_pressed(KeyCode::KeyL) && !crate::systems::level_switch::shift_held(&k)
+    }) {
+        state.open = !state.open;
+    }
+}
+
+/// Spawn or despawn the editor panel based on `PlaylistEditorState`.
+pub fn ensure_playlist_editor_ui(
+    state: Res<PlaylistEditorState>,
+    mut commands: Commands,
+    existing: Query<Entity, With<PlaylistEditorRoot>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    if !state.open {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !existing.is_empty() {
+        return;
+    }
+
+    let font = ui_fonts.map(|f| f.orbitron.clone()).unwrap_or_default();
+    let row = || Node {
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let text = |content: &str, size: f32| {
+        (
+            Text::new(content),
+            TextFont {
+                font: font.clone(),
+                font_size: size,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        )
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(40.0),
+                right: Val::Px(40.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.9)),
+            PlaylistEditorRoot,
+        ))
+        .with_children(|panel| {
+            panel.spawn(text("Playlist", 36.0));
+            panel.spawn(row()).with_children(|picker| {
+                spawn_menu_button(
+                    picker,
+                    "<",
+                    font.clone(),
+                    0,
+                    PlaylistEditorButton::PreviousLevel,
+                );
+                picker.spawn((text("", 28.0), PlaylistPickerLabel));
+                spawn_menu_button(
+                    picker,
+                    ">",
+                    font.clone(),
+                    1,
+                    PlaylistEditorButton::NextLevel,
+                );
+                spawn_menu_button(picker, "Add", font.clone(), 2, PlaylistEditorButton::Add);
+            });
+            panel.spawn((text("", 24.0), PlaylistEntriesText));
+            panel.spawn(row()).with_children(|edit| {
+                spawn_menu_button(
+                    edit,
+                    "Remove last",
+                    font.clone(),
+                    3,
+                    PlaylistEditorButton::RemoveLast,
+                );
+                spawn_menu_button(edit, "Clear", font.clone(), 4, PlaylistEditorButton::Clear);
+            });
+            panel.spawn(row()).with_children(|actions| {
+                #[cfg(not(target_arch = "wasm32"))]
+                spawn_menu_button(actions, "Save", font.clone(), 5, PlaylistEditorButton::Save);
+                spawn_menu_button(actions, "Play", font.clone(), 6, PlaylistEditorButton::Play);
+                spawn_menu_button(
+                    actions,
+                    "Close",
+                    font.clone(),
+                    7,
+                    PlaylistEditorButton::Close,
+                );
+            });
+        });
+}
+
+/// Apply editor button presses.
+pub fn handle_playlist_editor_buttons(
+    interactions: Query<(&Interaction, &PlaylistEditorButton), Changed<Interaction>>,
+    mut state: ResMut<PlaylistEditorState>,
+    mut playlist: ResMut<Playlist>,
+    levels: Option<Res<LevelSwitchState>>,
+    profile: Option<Res<CurrentProfile>>,
+    mut start: MessageWriter<PlaylistStartRequested>,
+) {
+    let slots = levels.as_deref().map_or(&[][..], |l| l.ordered_levels());
+    for (interaction, button) in interactions.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            PlaylistEditorButton::PreviousLevel if !slots.is_empty() => {
+                state.selected = (state.selected + slots.len() - 1) % slots.len();
+            }
+            PlaylistEditorButton::NextLevel if !slots.is_empty() => {
+                state.selected = (state.selected + 1) % slots.len();
+            }
+            PlaylistEditorButton::Add => {
+                if let Some(slot) = slots.get(state.selected) {
+                    playlist.push_level(slot.number);
+                }
+            }
+            PlaylistEditorButton::RemoveLast => {
+                playlist.pop_level();
+            }
+            PlaylistEditorButton::Clear => playlist.clear(),
+            PlaylistEditorButton::Save => {
+                if let Err(e) = crate::systems::playlist::save_playlist(
+                    &playlist.definition,
+                    profile.as_deref(),
+                ) {
+                    warn!("Failed to save playlist: {}", e);
+                }
+            }
+            PlaylistEditorButton::Play => {
+                start.write(PlaylistStartRequested);
+                state.open = false;
+            }
+            PlaylistEditorButton::Close => state.open = false,
+            PlaylistEditorButton::PreviousLevel | PlaylistEditorButton::NextLevel => {}
+        }
+    }
+}
+
+/// Text of the entry list, one numbered line per entry.
+pub fn playlist_entries_text(playlist: &Playlist) -> String {
+    if playlist.definition.levels.is_empty() {
+        return "(empty)".to_string();
+    }
+    playlist
+        .definition
+        .levels
+        .iter()
+        .enumerate()
+        .map(|(index, level)| format!("{}. Level {}", index + 1, level))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Refresh the picked level and the entry list.
+pub fn update_playlist_editor_text(
+    state: Res<PlaylistEditorState>,
+    playlist: Res<Playlist>,
+    levels: Option<Res<LevelSwitchState>>,
+    added: Query<(), Added<PlaylistEditorRoot>>,
+    mut picker: Query<&mut Text, (With<PlaylistPickerLabel>, Without<PlaylistEntriesText>)>,
+    mut entries: Query<&mut Text, (With<PlaylistEntriesText>, Without<PlaylistPickerLabel>)>,
+) {
+    if !state.is_changed() && !playlist.is_changed() && added.is_empty() {
+        return;
+    }
+    let picked = levels
+        .as_deref()
+        .and_then(|l| l.ordered_levels().get(state.selected))
+        .map_or_else(|| "-".to_string(), |slot| format!("Level {}", slot.number));
+    for mut text in picker.iter_mut() {
+        text.0 = picked.clone();
+    }
+    for mut text in entries.iter_mut() {
+        text.0 = playlist_entries_text(&playlist);
+    }
+}
@@ -0,0 +1,118 @@
+//! Playlist summary overlay
+//!
+//! Purpose
+//! - Shows the result of a finished playlist run (`systems::playlist`): the points earned on each
+//!   entry and the cumulative total, with a focusable "Close" button.
+//!
+//! When it spawns
+//! - `sync_playlist_summary` reacts to `Playlist` changes: it spawns the overlay once a run is
+//!   finished (idempotent) and despawns it when a new run starts.
+//! - `handle_playlist_summary_buttons` closes it on "Close".
+
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::systems::playlist::Playlist;
+use crate::ui::focus::spawn_menu_button;
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the summary overlay root.
+#[derive(Component, Debug)]
+pub struct PlaylistSummaryOverlay;
+
+/// Buttons on the summary overlay.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistSummaryButton {
+    Close,
+}
+
+/// Summary text: one line per entry and the total.
+pub fn playlist_summary_text(playlist: &Playlist) -> String {
+    let mut lines = vec![format!("{} complete", display_name(playlist))];
+    lines.extend(playlist.results.iter().enumerate().map(|(index, result)| {
+        format!("{}. Level {}: {}", index + 1, result.level, result.points)
+    }));
+    lines.push(format!("Total: {}", playlist.total_points()));
+    lines.join("\n")
+}
+
+fn display_name(playlist: &Playlist) -> &str {
+    match playlist.definition.name.as_str() {
+        "" => "Playlist",
+        name => name,
+    }
+}
+
+/// Spawn the summary when a run finishes and remove it when a new run starts.
+pub fn sync_playlist_summary(
+    mut commands: Commands,
+    playlist: Option<Res<Playlist>>,
+    existing: Query<Entity, With<PlaylistSummaryOverlay>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    let Some(playlist) = playlist.filter(|p| p.is_changed()) else {
+        return;
+    };
+    if !playlist.finished {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        warn!("UiFonts resource missing; skipping playlist summary spawn");
+        return;
+    };
+    let font = fonts.orbitron.clone();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            PlaylistSummaryOverlay,
+        ))
+        .with_children(|menu| {
+            menu.spawn((
+                Text::new(playlist_summary_text(&playlist)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+            spawn_menu_button(menu, "Close", font, 0, PlaylistSummaryButton::Close);
+        });
+}
+
+/// Close the summary overlay.
+pub fn handle_playlist_summary_buttons(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &PlaylistSummaryButton), Changed<Interaction>>,
+    overlays: Query<Entity, With<PlaylistSummaryOverlay>>,
+) {
+    for (interaction, button) in interactions.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            PlaylistSummaryButton::Close => {
+                for entity in overlays.iter() {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+    }
+}
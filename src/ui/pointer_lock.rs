@@ -0,0 +1,195 @@
+//! Mouse capture prompt and fullscreen button for the browser build (wasm only).
+//!
+//! Purpose
+//! - Browsers only lock the pointer after a click on the canvas, and release it on their own when
+//!   the player presses Escape or switches tabs, without the game seeing the key. A banner at the
+//!   top of the screen says "Click to capture mouse" whenever the pointer is free.
+//! - Losing the lock while playing pauses the game, so the ball does not keep moving while the
+//!   paddle cannot be controlled. Clicking resumes and captures the mouse again.
+//! - The banner's "Fullscreen" button toggles browser fullscreen. Browsers only allow that right
+//!   after a user gesture, so it is requested on the frame of the click, which browsers still
+//!   count as part of the gesture; the browser's own `F11` keeps working as well. Like any click,
+//!   it also captures the mouse.
+//!
+//! The banner is drawn with Bevy UI, not an HTML overlay, so the page needs no extra markup.
+
+use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, CursorOptions, PrimaryWindow};
+
+use crate::level_loader::LevelAdvanceState;
+use crate::pause::{pause_allowed, PauseState};
+use crate::systems::respawn::LivesState;
+use crate::ui::fonts::UiFonts;
+use crate::ui::level_intro::LevelIntro;
+use crate::ui::profile_select::ProfileSelectionOpen;
+
+/// Marker for the banner shown while the mouse is not captured.
+#[derive(Component, Debug)]
+pub struct PointerLockBanner;
+
+/// Marker for the fullscreen toggle button in the banner.
+#[derive(Component, Debug)]
+pub struct FullscreenButton;
+
+/// Marker for the label of [`FullscreenButton`].
+#[derive(Component, Debug)]
+pub struct FullscreenButtonLabel;
+
+fn document() -> Option<web_sys::Document> {
+    web_sys::window().and_then(|window| window.document())
+}
+
+fn pointer_locked() -> bool {
+    document().is_some_and(|document| document.pointer_lock_element().is_some())
+}
+
+fn fullscreen() -> bool {
+    document().is_some_and(|document| document.fullscreen_element().is_some())
+}
+
+/// Spawn the banner once the UI fonts are available (they load late on wasm).
+pub fn ensure_pointer_lock_banner(
+    mut commands: Commands,
+    banners: Query<(), With<PointerLockBanner>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    if !banners.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    let font = fonts.orbitron.clone();
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(12.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(16.0),
+                ..default()
+            },
+            PointerLockBanner,
+        ))
+        .with_children(|banner| {
+            banner.spawn((
+                Text::new("Click to capture mouse"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            // Not `Focusable`: it would take the keyboard focus from the menus
+            banner
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.2, 0.9)),
+                    Button,
+                    FullscreenButton,
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new("Fullscreen"),
+                        TextFont {
+                            font,
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        FullscreenButtonLabel,
+                    ));
+                });
+        });
+}
+
+/// Notice when the browser released the pointer: free the cursor and pause the game.
+///
+/// The lock is granted asynchronously after a click, so only a lock that was seen and then lost
+/// counts.
+pub fn detect_pointer_lock_loss(
+    mut was_locked: Local<bool>,
+    mut cursor_options: Single<&mut CursorOptions, With<PrimaryWindow>>,
+    mut pause_state: ResMut<PauseState>,
+    lives_state: Res<LivesState>,
+    level_advance: Res<LevelAdvanceState>,
+    intro: Option<Res<LevelIntro>>,
+    profile_selection: Option<Res<ProfileSelectionOpen>>,
+) {
+    let locked = pointer_locked();
+    if *was_locked && !locked {
+        cursor_options.grab_mode = CursorGrabMode::None;
+        cursor_options.visible = true;
+        if matches!(*pause_state, PauseState::Active)
+            && pause_allowed(
+                &lives_state,
+                &level_advance,
+                intro.is_some(),
+                profile_selection.is_some(),
+            )
+        {
+            info!("Mouse released by the browser; pausing");
+            *pause_state = PauseState::Paused {};
+        }
+    }
+    *was_locked = locked;
+}
+
+/// Show the banner while the pointer is free, and keep the button label in step with the
+/// browser's fullscreen state.
+pub fn update_pointer_lock_banner(
+    mut banners: Query<&mut Visibility, With<PointerLockBanner>>,
+    mut labels: Query<&mut Text, With<FullscreenButtonLabel>>,
+) {
+    let visibility = if pointer_locked() {
+        Visibility::Hidden
+    } else {
+        Visibility::Inherited
+    };
+    for mut banner in banners.iter_mut() {
+        banner.set_if_neq(visibility);
+    }
+    let label = if fullscreen() {
+        "Exit fullscreen"
+    } else {
+        "Fullscreen"
+    };
+    for mut text in labels.iter_mut() {
+        if text.0 != label {
+            text.0 = label.to_string();
+        }
+    }
+}
+
+/// Toggle browser fullscreen from the banner's button, within the click's user gesture.
+pub fn handle_fullscreen_button(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<FullscreenButton>)>,
+) {
+    if !interactions
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    let Some(document) = document() else {
+        return;
+    };
+    let result = if document.fullscreen_element().is_some() {
+        document.exit_fullscreen();
+        Ok(())
+    } else {
+        match document.document_element() {
+            Some(element) => element.request_fullscreen(),
+            None => Ok(()),
+        }
+    };
+    if let Err(e) = result {
+        warn!("Browser refused fullscreen: {:?}", e);
+    }
+}
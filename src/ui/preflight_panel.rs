@@ -0,0 +1,133 @@
+//! Asset preflight panel.
+//!
+//! Purpose
+//! - Shows the problems found by the asset preflight check (`systems::preflight`) in a corner of
+//!   the screen, so missing files are noticed while working on a level or asset rather than when
+//!   the game first needs them. The full report is in the log.
+//!
+//! When it spawns
+//! - Debug builds only, once `UiFonts` is available and `PreflightReport` has problems.
+//! - `handle_preflight_panel_button` closes it for the rest of the session; it leaves the game
+//!   playable while open.
+
+use bevy::prelude::*;
+
+use crate::systems::preflight::PreflightReport;
+use crate::ui::fonts::UiFonts;
+
+/// Problems listed in the panel; the rest are counted.
+pub const PANEL_PROBLEMS: usize = 8;
+
+/// Marker for the preflight panel root.
+#[derive(Component, Debug)]
+pub struct PreflightPanel;
+
+/// The panel's `Dismiss` button.
+#[derive(Component, Debug)]
+pub struct PreflightPanelButton;
+
+/// Text of the panel listing the problems of `report`.
+pub fn preflight_panel_text(report: &PreflightReport) -> String {
+    let mut text = format!(
+        "Asset preflight: {} problem(s) in {} files",
+        report.problems.len(),
+        report.checked
+    );
+    for problem in report.problems.iter().take(PANEL_PROBLEMS) {
+        text.push_str(&format!("\n[{}] {}", problem.kind, problem.message));
+    }
+    if report.problems.len() > PANEL_PROBLEMS {
+        text.push_str(&format!(
+            "\n... and {} more, see the log",
+            report.problems.len() - PANEL_PROBLEMS
+        ));
+    }
+    text
+}
+
+/// Show the panel once fonts are ready, if the check found problems.
+pub fn spawn_preflight_panel(
+    mut commands: Commands,
+    mut shown: Local<bool>,
+    report: Option<Res<PreflightReport>>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    if *shown {
+        return;
+    }
+    let (Some(report), Some(fonts)) = (report, ui_fonts) else {
+        return;
+    };
+    if report.is_clean() {
+        return;
+    }
+    *shown = true;
+    let font = fonts.orbitron.clone();
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(12.0),
+                right: Val::Px(12.0),
+                max_width: Val::Px(640.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexEnd,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.25, 0.12, 0.0, 0.9)),
+            GlobalZIndex(i32::MAX - 1),
+            PreflightPanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(preflight_panel_text(&report)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.5)),
+            ));
+            // Not `Focusable`: it would take the keyboard focus from the menus
+            panel
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.2, 0.9)),
+                    Button,
+                    PreflightPanelButton,
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new("Dismiss"),
+                        TextFont {
+                            font,
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// Close the panel.
+pub fn handle_preflight_panel_button(
+    mut commands: Commands,
+    buttons: Query<&Interaction, (With<PreflightPanelButton>, Changed<Interaction>)>,
+    panels: Query<Entity, With<PreflightPanel>>,
+) {
+    if !buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    for entity in panels.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,139 @@
+//! Profile selection screen
+//!
+//! Purpose
+//! - Lets players sharing a machine pick who is playing (`systems::profiles`) when the game
+//!   starts: one button per existing profile, "New profile" (creates the next free "Player N")
+//!   and "Play as guest" (keeps the shared default files).
+//!
+//! When it spawns
+//! - `spawn_profile_select` shows the screen once at startup, as soon as `UiFonts` is available
+//!   (native builds only, profiles are stored on disk).
+//! - While [`ProfileSelectionOpen`] exists, physics stays frozen (`pause::apply_pause_to_physics`)
+//!   and pausing is disabled.
+//! - `handle_profile_select_buttons` sends `ProfileSelected` for the chosen profile and closes the
+//!   screen. Every button is `Focusable`, so it works with keyboard / gamepad as well as the mouse.
+
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::systems::profiles::{list_profiles, new_profile_name, ProfileSelected};
+use crate::ui::focus::spawn_menu_button;
+use crate::ui::fonts::UiFonts;
+
+/// Present while the profile selection screen is showing.
+#[derive(Resource, Debug, Default)]
+pub struct ProfileSelectionOpen;
+
+/// Marker for the selection screen root.
+#[derive(Component, Debug)]
+pub struct ProfileSelectOverlay;
+
+/// Buttons on the selection screen.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub enum ProfileSelectButton {
+    Existing(String),
+    New,
+    Guest,
+}
+
+/// Show the selection screen once, when fonts are ready.
+pub fn spawn_profile_select(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    mut shown: Local<bool>,
+) {
+    if *shown {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    *shown = true;
+    let font = fonts.orbitron.clone();
+    let profiles = list_profiles();
+
+    commands.insert_resource(ProfileSelectionOpen);
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            GlobalZIndex(i32::MAX - 1),
+            ProfileSelectOverlay,
+        ))
+        .with_children(|menu| {
+            menu.spawn((
+                Text::new("Who is playing?"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+            let mut order = 0;
+            for name in &profiles {
+                spawn_menu_button(
+                    menu,
+                    name,
+                    font.clone(),
+                    order,
+                    ProfileSelectButton::Existing(name.clone()),
+                );
+                order += 1;
+            }
+            spawn_menu_button(
+                menu,
+                "New profile",
+                font.clone(),
+                order,
+                ProfileSelectButton::New,
+            );
+            spawn_menu_button(
+                menu,
+                "Play as guest",
+                font,
+                order + 1,
+                ProfileSelectButton::Guest,
+            );
+        });
+}
+
+/// Apply the chosen profile and close the screen.
+pub fn handle_profile_select_buttons(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &ProfileSelectButton), Changed<Interaction>>,
+    overlays: Query<Entity, With<ProfileSelectOverlay>>,
+    mut selections: MessageWriter<ProfileSelected>,
+) {
+    let Some(button) = interactions
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| button)
+    else {
+        return;
+    };
+    match button {
+        ProfileSelectButton::Existing(name) => {
+            selections.write(ProfileSelected { name: name.clone() });
+        }
+        ProfileSelectButton::New => {
+            selections.write(ProfileSelected {
+                name: new_profile_name(&list_profiles()),
+            });
+        }
+        ProfileSelectButton::Guest => {}
+    }
+    commands.remove_resource::<ProfileSelectionOpen>();
+    for entity in overlays.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,164 @@
+//! Visual sound cues (accessibility).
+//!
+//! Purpose
+//! - For deaf and hard-of-hearing players, important sounds are mirrored by a small coloured pip at
+//!   the screen edge, on the side the sound came from.
+//! - Enabled with `visual_cues: true` in the audio config (`AudioConfig::visual_cues`).
+//!
+//! When it spawns
+//! - `spawn_sound_cues` reads the same gameplay messages the game reacts to: `LifeLostEvent` (ball
+//!   lost, placed towards the ball) and `PowerupCollected` (powerup activated, placed towards the
+//!   paddle). The source's screen position is pushed out to the nearest screen edge.
+//! - Without a camera (or if the source is gone) the pip falls back to the bottom edge.
+//!
+//! How it updates
+//! - `fade_sound_cues` fades each pip out over [`SOUND_CUE_SECS`] and despawns it.
+
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+
+use crate::systems::audio::AudioConfig;
+use crate::systems::powerups::PowerupCollected;
+use crate::systems::respawn::LifeLostEvent;
+use crate::MainCamera;
+
+/// How long a pip stays on screen, in seconds.
+pub const SOUND_CUE_SECS: f32 = 1.0;
+/// Pip edge length in pixels.
+const PIP_SIZE: f32 = 18.0;
+/// Gap between a pip and the screen edge, as a fraction of the screen.
+const EDGE_MARGIN: f32 = 0.03;
+
+/// Which sound a pip stands in for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundCueKind {
+    BallLost,
+    PowerupActivated,
+}
+
+impl SoundCueKind {
+    pub fn color(self) -> Color {
+        match self {
+            SoundCueKind::BallLost => Color::srgb(1.0, 0.25, 0.2),
+            SoundCueKind::PowerupActivated => Color::srgb(0.3, 0.9, 0.4),
+        }
+    }
+}
+
+/// An on-screen pip with its remaining lifetime.
+#[derive(Component, Debug)]
+pub struct SoundCuePip {
+    pub kind: SoundCueKind,
+    pub timer: Timer,
+}
+
+/// Push a viewport position (fractions in `[0, 1]`, origin top-left) out to the screen edge it is
+/// closest to, keeping its direction from the centre. Centred sources go to the bottom edge.
+pub fn edge_position(viewport_fraction: Vec2) -> Vec2 {
+    let offset = viewport_fraction - Vec2::splat(0.5);
+    let reach = offset.abs().max_element();
+    let direction = if reach <= f32::EPSILON {
+        Vec2::new(0.0, 0.5)
+    } else {
+        offset / reach * 0.5
+    };
+    Vec2::splat(0.5) + direction * (1.0 - 2.0 * EDGE_MARGIN)
+}
+
+/// Screen-edge position (fractions) for a world-space sound source.
+fn cue_position(source: Option<Vec3>, camera: Option<(&Camera, &GlobalTransform)>) -> Vec2 {
+    let fraction = source
+        .zip(camera)
+        .and_then(|(world, (camera, camera_transform))| {
+            let viewport = camera.world_to_viewport(camera_transform, world).ok()?;
+            let size = camera.logical_viewport_size()?;
+            Some(viewport / size)
+        });
+    edge_position(fraction.unwrap_or(Vec2::new(0.5, 1.0)))
+}
+
+fn spawn_pip(commands: &mut Commands, kind: SoundCueKind, position: Vec2) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(position.x * 100.0),
+            top: Val::Percent(position.y * 100.0),
+            width: Val::Px(PIP_SIZE),
+            height: Val::Px(PIP_SIZE),
+            // Centre the pip on its position
+            margin: UiRect {
+                left: Val::Px(-PIP_SIZE / 2.0),
+                top: Val::Px(-PIP_SIZE / 2.0),
+                ..default()
+            },
+            ..default()
+        },
+        BackgroundColor(kind.color()),
+        SoundCuePip {
+            kind,
+            timer: Timer::from_seconds(SOUND_CUE_SECS, TimerMode::Once),
+        },
+    ));
+}
+
+/// Spawn a pip for every important sound event this frame (when visual cues are enabled).
+pub fn spawn_sound_cues(
+    mut commands: Commands,
+    mut lives_lost: MessageReader<LifeLostEvent>,
+    mut powerups: MessageReader<PowerupCollected>,
+    config: Option<Res<AudioConfig>>,
+    sources: Query<&GlobalTransform>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+) {
+    if !config.is_some_and(|c| c.visual_cues) {
+        lives_lost.clear();
+        powerups.clear();
+        return;
+    }
+    let camera = cameras.iter().next();
+    let source_of = |entity: Entity| sources.get(entity).ok().map(|t| t.translation());
+
+    for event in lives_lost.read() {
+        let position = cue_position(source_of(event.ball), camera);
+        spawn_pip(&mut commands, SoundCueKind::BallLost, position);
+    }
+    for event in powerups.read() {
+        let position = cue_position(source_of(event.paddle), camera);
+        spawn_pip(&mut commands, SoundCueKind::PowerupActivated, position);
+    }
+}
+
+/// Fade out and despawn sound cue pips.
+pub fn fade_sound_cues(
+    mut commands: Commands,
+    mut pips: Query<(Entity, &mut SoundCuePip, &mut BackgroundColor)>,
+    time: Res<Time>,
+) {
+    for (entity, mut pip, mut background) in pips.iter_mut() {
+        pip.timer.tick(time.delta());
+        if pip.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        background.0 = pip.kind.color().with_alpha(1.0 - pip.timer.fraction());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_are_pushed_to_the_nearest_edge() {
+        // Left of centre -> left edge, same height
+        let left = edge_position(Vec2::new(0.3, 0.5));
+        assert!((left - Vec2::new(EDGE_MARGIN, 0.5)).length() < 1e-5);
+        // Lower right, mostly down -> bottom edge
+        let bottom = edge_position(Vec2::new(0.6, 0.9));
+        assert!((bottom.y - (1.0 - EDGE_MARGIN)).abs() < 1e-5);
+        assert!(bottom.x > 0.5);
+        // Centre -> bottom centre
+        let centre = edge_position(Vec2::splat(0.5));
+        assert!((centre - Vec2::new(0.5, 1.0 - EDGE_MARGIN)).length() < 1e-5);
+    }
+}
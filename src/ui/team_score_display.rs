@@ -0,0 +1,95 @@
+//! Team score HUD.
+//!
+//! Purpose
+//! - Shows both teams' points in versus mode and on levels with team bricks
+//!   (`Red: 1200  Blue: 300`), centred below the level clock and assist notice, each in its team's
+//!   color. In versus mode the player waiting for their turn is dimmed. Other levels show nothing.
+//!
+//! How it updates
+//! - `update_team_score_display` rewrites both readouts when `TeamScores` or `VersusMode`
+//!   (`src/systems/team_bricks.rs`) change and hides the row when there is no race.
+
+use bevy::prelude::*;
+
+use crate::systems::team_bricks::{LevelTeams, Team, TeamScores, VersusMode};
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the row holding the team scores.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TeamScoreDisplayUi;
+
+/// Marker for one team's score text.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TeamScoreText(pub Team);
+
+/// Spawn the (initially hidden) team score row once fonts are ready.
+pub fn spawn_team_score_display(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<TeamScoreDisplayUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(16.0),
+                ..default()
+            },
+            Visibility::Hidden,
+            TeamScoreDisplayUi,
+        ))
+        .with_children(|row| {
+            for team in [Team::Red, Team::Blue] {
+                row.spawn((
+                    Text::new(format!("{}: 0", team.label())),
+                    TextFont {
+                        font: fonts.orbitron.clone(),
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(team.color()),
+                    TeamScoreText(team),
+                ));
+            }
+        });
+}
+
+/// Keep the row in sync with `TeamScores` and the versus turn.
+pub fn update_team_score_display(
+    scores: Option<Res<TeamScores>>,
+    teams: Option<Res<LevelTeams>>,
+    versus: Option<Res<VersusMode>>,
+    mut rows: Query<&mut Visibility, With<TeamScoreDisplayUi>>,
+    mut texts: Query<(&mut Text, &mut TextColor, &TeamScoreText)>,
+    added: Query<(), Added<TeamScoreDisplayUi>>,
+) {
+    let (Some(scores), Some(teams)) = (scores, teams) else {
+        return;
+    };
+    let versus_changed = versus.as_ref().is_some_and(|versus| versus.is_changed());
+    let versus = versus.as_deref().copied().unwrap_or_default();
+    if !scores.is_changed() && !teams.is_changed() && !versus_changed && added.is_empty() {
+        return;
+    }
+    for mut visibility in rows.iter_mut() {
+        *visibility = if teams.is_race() || versus.active {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    for (mut text, mut color, team) in texts.iter_mut() {
+        **text = format!("{}: {}", team.0.label(), scores.get(team.0));
+        let waiting = versus.active && versus.turn != team.0;
+        color.0 = team.0.color().with_alpha(if waiting { 0.45 } else { 1.0 });
+    }
+}
@@ -0,0 +1,189 @@
+//! Toast notifications.
+//!
+//! Purpose
+//! - Small, unobtrusive messages in the bottom-right corner that do not interrupt play. Any system
+//!   shows one by triggering [`ShowToast`]; the adaptive hints (`systems::hints`) use it for their
+//!   tips.
+//!
+//! When it spawns
+//! - `on_show_toast` adds a toast below the ones already on screen, up to [`MAX_TOASTS`] (the
+//!   oldest is dropped). A toast carrying a hint gets a "Don't show again" button. It needs
+//!   `UiFonts`; without fonts the toast is skipped.
+//!
+//! How it updates
+//! - `expire_toasts` despawns a toast after [`TOAST_SECS`].
+//! - `handle_toast_buttons` dismisses the toast's hint for good (`HintSettings`) and closes it.
+
+use bevy::prelude::*;
+
+use crate::systems::hints::{Hint, HintSettings};
+use crate::ui::fonts::UiFonts;
+
+/// How long a toast stays on screen, in seconds.
+pub const TOAST_SECS: f32 = 8.0;
+/// Toasts on screen at once.
+pub const MAX_TOASTS: usize = 3;
+
+const TOAST_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.1, 0.85);
+const TOAST_TEXT: Color = Color::srgb(0.9, 0.9, 0.95);
+
+/// Show a toast.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ShowToast {
+    pub text: String,
+    /// Hint the toast shows, offered to be dismissed for good
+    pub hint: Option<Hint>,
+}
+
+impl ShowToast {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            hint: None,
+        }
+    }
+
+    /// A toast showing `hint`.
+    pub fn hint(hint: Hint) -> Self {
+        Self {
+            text: hint.text().to_string(),
+            hint: Some(hint),
+        }
+    }
+}
+
+/// A toast on screen, with the time since it appeared.
+#[derive(Component, Debug)]
+pub struct Toast {
+    pub elapsed: f32,
+}
+
+/// Column holding the toasts.
+#[derive(Component, Debug)]
+pub struct ToastStack;
+
+/// "Don't show again" button of a hint toast.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DismissHintButton {
+    pub hint: Hint,
+    pub toast: Entity,
+}
+
+/// Spawn a toast, creating the stack on first use.
+pub fn on_show_toast(
+    trigger: On<ShowToast>,
+    mut commands: Commands,
+    stacks: Query<Entity, With<ToastStack>>,
+    toasts: Query<(Entity, &Toast)>,
+    ui_fonts: Option<Res<UiFonts>>,
+) {
+    let event = trigger.event();
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    let stack = stacks.iter().next().unwrap_or_else(|| {
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(24.0),
+                    right: Val::Px(24.0),
+                    max_width: Val::Px(360.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ToastStack,
+            ))
+            .id()
+    });
+    let mut on_screen: Vec<_> = toasts.iter().collect();
+    if on_screen.len() >= MAX_TOASTS {
+        on_screen.sort_by(|a, b| b.1.elapsed.total_cmp(&a.1.elapsed));
+        for (entity, _) in on_screen.iter().take(on_screen.len() + 1 - MAX_TOASTS) {
+            commands.entity(*entity).despawn();
+        }
+    }
+
+    let toast = commands
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(TOAST_BACKGROUND),
+            Toast { elapsed: 0.0 },
+            ChildOf(stack),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(event.text.clone()),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(TOAST_TEXT),
+        ChildOf(toast),
+    ));
+    if let Some(hint) = event.hint {
+        commands
+            .spawn((
+                Button,
+                Node {
+                    align_self: AlignSelf::FlexEnd,
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.2, 0.2, 0.3, 0.9)),
+                DismissHintButton { hint, toast },
+                ChildOf(toast),
+            ))
+            .with_children(|button| {
+                button.spawn((
+                    Text::new("Don't show again"),
+                    TextFont {
+                        font: fonts.orbitron.clone(),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(TOAST_TEXT),
+                ));
+            });
+    }
+}
+
+/// Despawn expired toasts.
+pub fn expire_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut Toast)>,
+) {
+    for (entity, mut toast) in toasts.iter_mut() {
+        toast.elapsed += time.delta_secs();
+        if toast.elapsed >= TOAST_SECS {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Dismiss a hint for good from its toast.
+pub fn handle_toast_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &DismissHintButton), Changed<Interaction>>,
+    settings: Option<ResMut<HintSettings>>,
+) {
+    let Some(mut settings) = settings else {
+        return;
+    };
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        settings.dismiss(button.hint);
+        // The toast may have expired this frame
+        commands.entity(button.toast).try_despawn();
+    }
+}
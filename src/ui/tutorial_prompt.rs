@@ -0,0 +1,88 @@
+//! Tutorial prompt.
+//!
+//! Purpose
+//! - Shows the tutorial step being played (`src/systems/tutorial.rs`) at the top centre of the
+//!   screen, with how far through the level's steps the player is: `Move the mouse to slide the
+//!   paddle (1/3)`.
+//!
+//! How it updates
+//! - `update_tutorial_prompt` follows `TutorialState` changes and hides the prompt on levels
+//!   without a tutorial and once the last step is done.
+
+use bevy::prelude::*;
+use bevy::text::Justify;
+
+use crate::systems::tutorial::TutorialState;
+use crate::ui::fonts::UiFonts;
+
+/// Marker for the tutorial prompt.
+#[derive(Component, Debug)]
+pub struct TutorialPromptUi;
+
+/// Text of the prompt for `state`, if a step is being played.
+pub fn tutorial_prompt_text(state: &TutorialState) -> Option<String> {
+    state
+        .current()
+        .map(|step| format!("{} ({}/{})", step.prompt, state.done + 1, state.steps.len()))
+}
+
+/// Spawn the (initially hidden) prompt once fonts are ready.
+pub fn spawn_tutorial_prompt(
+    mut commands: Commands,
+    ui_fonts: Option<Res<UiFonts>>,
+    existing: Query<Entity, With<TutorialPromptUi>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let Some(fonts) = ui_fonts else {
+        return;
+    };
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: fonts.orbitron.clone(),
+            font_size: 28.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.9, 0.4)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(90.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Visibility::Hidden,
+        TutorialPromptUi,
+    ));
+}
+
+/// Show the current tutorial step.
+pub fn update_tutorial_prompt(
+    state: Option<Res<TutorialState>>,
+    mut prompts: Query<(&mut Text, &mut Visibility), With<TutorialPromptUi>>,
+    added: Query<(), Added<TutorialPromptUi>>,
+) {
+    let Some(state) = state else {
+        return;
+    };
+    if !state.is_changed() && added.is_empty() {
+        return;
+    }
+    let message = tutorial_prompt_text(&state);
+    for (mut text, mut visibility) in prompts.iter_mut() {
+        match &message {
+            Some(message) => {
+                if text.as_str() != message {
+                    **text = message.clone();
+                }
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::{CollisionEvent, Velocity};
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+use brkrs::gameplay_config::{Difficulty, GameplayConfig};
+use brkrs::systems::angle_guard::AngleGuardPlugin;
+use brkrs::{Ball, Border};
+
+fn bounce_off_top_wall(difficulty: Difficulty) -> Vec3 {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(AngleGuardPlugin);
+    app.insert_resource(GameplayConfig {
+        difficulty,
+        ..Default::default()
+    });
+    let wall = app
+        .world_mut()
+        .spawn((Border, Transform::from_xyz(-17.5, 0.0, 0.0)))
+        .id();
+    // Just bounced off the top wall, now skimming along it
+    let ball = app
+        .world_mut()
+        .spawn((Ball, Velocity::linear(Vec3::new(0.1, 0.0, -12.0))))
+        .id();
+
+    app.world_mut().write_message(CollisionEvent::Started(
+        wall,
+        ball,
+        CollisionEventFlags::empty(),
+    ));
+    app.update();
+
+    app.world().entity(ball).get::<Velocity>().unwrap().linvel
+}
+
+fn angle_deg(velocity: Vec3) -> f32 {
+    (velocity.x / velocity.length()).asin().to_degrees()
+}
+
+#[test]
+fn shallow_wall_bounce_is_steepened_per_difficulty() {
+    let config = GameplayConfig::default();
+    let easy = bounce_off_top_wall(Difficulty::Easy);
+    let hard = bounce_off_top_wall(Difficulty::Hard);
+
+    let expected_easy = config.angle_guard.min_angle_deg(Difficulty::Easy);
+    let expected_hard = config.angle_guard.min_angle_deg(Difficulty::Hard);
+    assert!((angle_deg(easy) - expected_easy).abs() < 0.01);
+    assert!((angle_deg(hard) - expected_hard).abs() < 0.01);
+    assert!(expected_easy > expected_hard);
+
+    // Speed and direction along the wall are kept
+    assert!((easy.length() - Vec3::new(0.1, 0.0, -12.0).length()).abs() < 1e-3);
+    assert!(easy.z < 0.0);
+}
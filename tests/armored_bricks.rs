@@ -0,0 +1,86 @@
+use bevy::{app::App, prelude::*, MinimalPlugins};
+use bevy_rapier3d::prelude::CollisionEvent;
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+
+use brkrs::level_format::{ARMORED_BRICK_BOTTOM, ARMORED_BRICK_LEFT};
+use brkrs::systems::armored_bricks::{ArmorArrow, ArmoredBricksPlugin};
+use brkrs::{Ball, Brick, BrickGridPos, BrickTypeId, CountsTowardsCompletion, GridResolution};
+
+fn armored_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_message::<CollisionEvent>();
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    brkrs::register_brick_collision_systems(&mut app);
+    app.add_plugins(ArmoredBricksPlugin);
+    app.update();
+    app
+}
+
+fn spawn_brick(app: &mut App, brick_type: u8) -> Entity {
+    let entity = app
+        .world_mut()
+        .spawn((
+            Brick,
+            BrickTypeId(brick_type),
+            BrickGridPos { row: 5, col: 5 },
+            CountsTowardsCompletion,
+            Transform::from_translation(GridResolution::default().cell_center(5, 5)),
+        ))
+        .id();
+    app.update();
+    entity
+}
+
+/// Hit `brick` with a ball touching it at `offset` from its centre.
+fn hit_from(app: &mut App, brick: Entity, offset: Vec3) {
+    let position = GridResolution::default().cell_center(5, 5) + offset;
+    let ball = app
+        .world_mut()
+        .spawn((Ball, Transform::from_translation(position)))
+        .id();
+    app.world_mut().write_message(CollisionEvent::Started(
+        ball,
+        brick,
+        CollisionEventFlags::empty(),
+    ));
+    app.update();
+}
+
+#[test]
+fn armored_brick_ignores_hits_on_armored_sides() {
+    let mut app = armored_test_app();
+    let brick = spawn_brick(&mut app, ARMORED_BRICK_BOTTOM);
+    // From above (towards row 0) and from the left (towards column 0)
+    hit_from(&mut app, brick, Vec3::new(-1.0, 0.0, 0.0));
+    hit_from(&mut app, brick, Vec3::new(0.0, 0.0, 1.2));
+    assert!(app.world().get_entity(brick).is_ok());
+}
+
+#[test]
+fn armored_brick_breaks_on_its_weak_side() {
+    let mut app = armored_test_app();
+    let brick = spawn_brick(&mut app, ARMORED_BRICK_BOTTOM);
+    // From below, the paddle side
+    hit_from(&mut app, brick, Vec3::new(1.0, 0.0, 0.2));
+    assert!(app.world().get_entity(brick).is_err());
+
+    let brick = spawn_brick(&mut app, ARMORED_BRICK_LEFT);
+    hit_from(&mut app, brick, Vec3::new(0.0, 0.0, 1.2));
+    assert!(app.world().get_entity(brick).is_err());
+}
+
+#[test]
+fn armored_bricks_get_an_arrow() {
+    let mut app = armored_test_app();
+    let armored = spawn_brick(&mut app, ARMORED_BRICK_BOTTOM);
+    spawn_brick(&mut app, 20);
+    let world = app.world_mut();
+    let arrows: Vec<Entity> = world
+        .query_filtered::<&ChildOf, With<ArmorArrow>>()
+        .iter(world)
+        .map(|parent| parent.parent())
+        .collect();
+    assert_eq!(arrows, vec![armored], "only armored bricks get an arrow");
+}
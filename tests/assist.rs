@@ -0,0 +1,63 @@
+mod common;
+
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::gameplay_config::GameplayConfig;
+use brkrs::systems::assist::{AssistPlugin, AssistShield};
+use brkrs::systems::ball_save::BallSaveShield;
+
+use common::level;
+
+fn assist_test_app(assist: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(AssistPlugin);
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(GameplayConfig {
+        assist,
+        ..default()
+    });
+    app.insert_resource(level(1));
+    app.update();
+    app
+}
+
+fn assist_shields(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(), (With<AssistShield>, With<BallSaveShield>)>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn every_level_gets_one_barrier() {
+    let mut app = assist_test_app(true);
+    assert_eq!(assist_shields(&mut app), 1);
+
+    // An unused barrier is replaced, not stacked
+    app.insert_resource(level(2));
+    app.update();
+    assert_eq!(assist_shields(&mut app), 1);
+
+    // Used up: nothing more until the next level
+    let world = app.world_mut();
+    let shield = world
+        .query_filtered::<Entity, With<AssistShield>>()
+        .single(world)
+        .unwrap();
+    world.despawn(shield);
+    app.update();
+    assert_eq!(assist_shields(&mut app), 0);
+    app.insert_resource(level(3));
+    app.update();
+    assert_eq!(assist_shields(&mut app), 1);
+}
+
+#[test]
+fn no_barrier_without_assist() {
+    let mut app = assist_test_app(false);
+    assert_eq!(assist_shields(&mut app), 0);
+}
@@ -2,7 +2,7 @@
 //!
 //! Tests for the AudioConfig resource and related audio system components.
 
-use brkrs::systems::{AudioConfig, SoundType};
+use brkrs::systems::{AudioBus, AudioConfig, SoundType};
 
 #[test]
 fn audio_config_default_has_full_volume() {
@@ -68,6 +68,15 @@ fn audio_config_is_valid_checks_volume_range() {
     assert!(at_one.is_valid());
 }
 
+#[test]
+fn audio_config_without_bus_volumes_parses_at_full_bus_volume() {
+    let config: AudioConfig = ron::de::from_str("(master_volume: 0.6, muted: false)").unwrap();
+    assert_eq!(config.sfx_volume, 1.0);
+    assert_eq!(config.music_volume, 1.0);
+    assert_eq!(config.ui_volume, 1.0);
+    assert_eq!(config.bus_volume(AudioBus::Music), 0.6);
+}
+
 #[test]
 fn sound_type_has_all_expected_variants() {
     // Test that all variants exist and are distinct
@@ -124,3 +124,70 @@ fn graceful_degradation_app_initializes_without_audio_assets() {
     // must be safe and deterministic.
     assert!(assets.sounds.is_empty());
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+mod pause_safety {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::window::WindowMode;
+    use brkrs::pause::PauseState;
+    use brkrs::signals::UiBeep;
+    use brkrs::systems::audio::{ActiveSounds, SoundType, MAX_QUEUED_SOUND_SECS};
+    use std::time::Duration;
+
+    fn paused_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AudioPlugin)
+            .add_message::<BrickDestroyed>()
+            .init_resource::<AudioConfig>();
+        app.update();
+
+        let mut assets = AudioAssets::default();
+        assets
+            .sounds
+            .insert(SoundType::BrickDestroy, Handle::default());
+        assets.sounds.insert(SoundType::UiBeep, Handle::default());
+        app.insert_resource(assets);
+        app.insert_resource(PauseState::Paused {
+            window_mode_before_pause: WindowMode::Windowed,
+        });
+        app
+    }
+
+    fn count(app: &App, sound_type: SoundType) -> u8 {
+        app.world().resource::<ActiveSounds>().count(sound_type)
+    }
+
+    #[test]
+    fn gameplay_sounds_are_dropped_while_paused_but_ui_beeps_play() {
+        let mut app = paused_app();
+        app.world_mut().write_message(BrickDestroyed {
+            brick_entity: Entity::PLACEHOLDER,
+            brick_type: 20,
+            destroyed_by: None,
+        });
+        app.world_mut().write_message(UiBeep);
+        app.update();
+        app.update();
+
+        assert_eq!(count(&app, SoundType::BrickDestroy), 0);
+        assert_eq!(count(&app, SoundType::UiBeep), 1);
+    }
+
+    #[test]
+    fn sounds_that_never_start_are_dropped_after_threshold() {
+        let mut app = paused_app();
+        app.world_mut().write_message(UiBeep);
+        app.update();
+        assert_eq!(count(&app, SoundType::UiBeep), 1);
+
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            MAX_QUEUED_SOUND_SECS,
+        )));
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(count(&app, SoundType::UiBeep), 0);
+    }
+}
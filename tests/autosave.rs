@@ -0,0 +1,167 @@
+mod common;
+
+use bevy::ecs::message::Messages;
+use bevy::{app::App, prelude::*};
+use brkrs::systems::autosave::{
+    load_progress, read_recovering, save_progress, temp_path, write_atomic, AutosaveState,
+    PROGRESS_FILE,
+};
+use brkrs::systems::level_switch::{LevelSwitchDirection, LevelSwitchRequested};
+use brkrs::systems::powerups::{PowerupKind, PowerupPlugin, ReversedControls};
+use brkrs::systems::profiles::{profile_dir, ProfileSelected};
+use brkrs::systems::respawn::LivesState;
+use brkrs::systems::scoring::ScoreState;
+use brkrs::systems::timed_effects::TimedEffect;
+use brkrs::systems::{AutosavePlugin, LevelCompleted, ProfilesPlugin, SavedProgress};
+use brkrs::Paddle;
+
+use common::level;
+
+/// Removes the profile directory when the test ends, even on failure.
+struct ProfileDirGuard(String);
+
+impl Drop for ProfileDirGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(profile_dir(&self.0));
+    }
+}
+
+fn autosave_app(name: &str) -> App {
+    autosave_app_with(name, |_| {})
+}
+
+fn autosave_app_with(name: &str, setup: impl FnOnce(&mut App)) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins((ProfilesPlugin, AutosavePlugin));
+    setup(&mut app);
+    app.insert_resource(level(1));
+    app.insert_resource(ScoreState::default());
+    app.insert_resource(LivesState::default());
+    app.update();
+    app.world_mut().write_message(ProfileSelected {
+        name: name.to_string(),
+    });
+    app.update();
+    app
+}
+
+#[test]
+fn interrupted_writes_are_recovered_or_discarded() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("progress.ron");
+    let parse = |content: &str| content.starts_with("ok").then(|| content.to_string());
+
+    write_atomic(&path, "ok 1").unwrap();
+    assert!(!temp_path(&path).exists(), "the temporary file is renamed");
+    assert_eq!(read_recovering(&path, parse), Some("ok 1".to_string()));
+
+    // Crash while writing: the save file is intact, the partial leftover is dropped
+    std::fs::write(temp_path(&path), "o").unwrap();
+    assert_eq!(read_recovering(&path, parse), Some("ok 1".to_string()));
+    assert!(!temp_path(&path).exists());
+
+    // Unreadable save file with a complete leftover: the leftover is moved into place
+    std::fs::write(&path, "garbage").unwrap();
+    std::fs::write(temp_path(&path), "ok 2").unwrap();
+    assert_eq!(read_recovering(&path, parse), Some("ok 2".to_string()));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "ok 2");
+    assert!(!temp_path(&path).exists());
+}
+
+#[test]
+fn progress_is_saved_once_the_next_level_is_loaded() {
+    let name = format!("Autosave {}", std::process::id());
+    let _guard = ProfileDirGuard(name.clone());
+    let mut app = autosave_app(&name);
+    let path = profile_dir(&name).join(PROGRESS_FILE);
+
+    app.world_mut().resource_mut::<ScoreState>().current_score = 900;
+    app.world_mut().resource_mut::<LivesState>().lives_remaining = 2;
+    app.world_mut().trigger(LevelCompleted { level_index: 1 });
+    app.update();
+    assert_eq!(load_progress(&path), None, "waits for the next level");
+
+    app.insert_resource(level(2));
+    app.update();
+    assert_eq!(
+        load_progress(&path),
+        Some(SavedProgress {
+            level: Some(2),
+            score: 900,
+            lives: 2,
+            highest_level: 1,
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn saved_progress_is_resumed_when_the_profile_is_selected() {
+    let name = format!("Resume {}", std::process::id());
+    let _guard = ProfileDirGuard(name.clone());
+    save_progress(
+        &profile_dir(&name).join(PROGRESS_FILE),
+        &SavedProgress {
+            level: Some(3),
+            score: 12_000,
+            lives: 4,
+            highest_level: 2,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let mut app = autosave_app(&name);
+    app.update();
+
+    let score = app.world().resource::<ScoreState>();
+    assert_eq!(score.current_score, 12_000);
+    assert_eq!(
+        score.last_milestone_reached, 2,
+        "no extra lives for milestones reached before the save"
+    );
+    assert_eq!(app.world().resource::<LivesState>().lives_remaining, 4);
+    let switches: Vec<LevelSwitchRequested> = app
+        .world_mut()
+        .resource_mut::<Messages<LevelSwitchRequested>>()
+        .drain()
+        .collect();
+    assert_eq!(switches.len(), 1);
+    assert_eq!(switches[0].direction, LevelSwitchDirection::Jump(3));
+}
+
+#[test]
+fn running_effects_are_saved_on_exit_and_caught_again_on_resume() {
+    let name = format!("Effects {}", std::process::id());
+    let _guard = ProfileDirGuard(name.clone());
+    let path = profile_dir(&name).join(PROGRESS_FILE);
+    let mut app = autosave_app(&name);
+    app.world_mut().resource_mut::<AutosaveState>().saved.level = Some(1);
+
+    let mut reversed = ReversedControls::default();
+    reversed.timer_mut().tick(std::time::Duration::from_secs(2));
+    app.world_mut().spawn((Paddle, reversed));
+    app.world_mut().write_message(AppExit::Success);
+    app.update();
+
+    let saved = load_progress(&path).expect("progress saved on exit");
+    assert_eq!(saved.level, Some(1));
+    assert_eq!(saved.effects.len(), 1);
+    assert_eq!(saved.effects[0].kind, PowerupKind::ReverseControls);
+    assert!((saved.effects[0].remaining_secs - 3.0).abs() < 0.1);
+
+    // Next session: the effect is caught again with the time it had left
+    let mut app = autosave_app_with(&name, |app| {
+        app.add_plugins(PowerupPlugin);
+    });
+    let paddle = app.world_mut().spawn((Paddle, Transform::default())).id();
+    for _ in 0..3 {
+        app.update();
+    }
+    let restored = app
+        .world()
+        .get::<ReversedControls>(paddle)
+        .expect("effect restored");
+    assert!((restored.remaining_secs() - 3.0).abs() < 0.1);
+}
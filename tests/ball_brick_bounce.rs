@@ -0,0 +1,93 @@
+//! Bricks that survive a ball hit produce a bounce, destroyed bricks do not.
+
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::CollisionEvent;
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+use brkrs::level_format::{INDESTRUCTIBLE_BRICK, MULTI_HIT_BRICK_4, SIMPLE_BRICK};
+use brkrs::signals::BallBrickBounce;
+use brkrs::{
+    despawn_marked_entities, detect_ball_brick_bounces, mark_brick_on_ball_collision, Ball, Brick,
+    BrickTypeId, CountsTowardsCompletion,
+};
+
+/// Bricks and brick types of the bounces seen so far.
+#[derive(Resource, Default)]
+struct Bounces(Vec<(Entity, u8)>);
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_message::<CollisionEvent>()
+        .add_message::<brkrs::signals::BrickDestroyed>()
+        .init_resource::<Bounces>()
+        .add_observer(
+            |bounce: On<BallBrickBounce>, mut bounces: ResMut<Bounces>| {
+                bounces
+                    .0
+                    .push((bounce.event().brick_entity, bounce.event().brick_type));
+            },
+        )
+        .add_systems(
+            Update,
+            (
+                mark_brick_on_ball_collision,
+                detect_ball_brick_bounces,
+                despawn_marked_entities,
+            )
+                .chain(),
+        );
+    app
+}
+
+fn spawn_brick(app: &mut App, type_id: u8) -> Entity {
+    let mut brick = app
+        .world_mut()
+        .spawn((Brick, BrickTypeId(type_id), Transform::default()));
+    if type_id != INDESTRUCTIBLE_BRICK {
+        brick.insert(CountsTowardsCompletion);
+    }
+    brick.id()
+}
+
+fn hit(app: &mut App, ball: Entity, brick: Entity) {
+    app.world_mut().write_message(CollisionEvent::Started(
+        ball,
+        brick,
+        CollisionEventFlags::empty(),
+    ));
+    app.update();
+}
+
+#[test]
+fn surviving_bricks_bounce_the_ball() {
+    let mut app = test_app();
+    let ball = app.world_mut().spawn((Ball, Transform::default())).id();
+    let wall = spawn_brick(&mut app, INDESTRUCTIBLE_BRICK);
+    let multi_hit = spawn_brick(&mut app, MULTI_HIT_BRICK_4);
+
+    hit(&mut app, ball, wall);
+    hit(&mut app, multi_hit, ball);
+
+    assert_eq!(
+        app.world().resource::<Bounces>().0,
+        vec![
+            (wall, INDESTRUCTIBLE_BRICK),
+            (multi_hit, MULTI_HIT_BRICK_4 - 1)
+        ]
+    );
+}
+
+#[test]
+fn destroyed_bricks_do_not_bounce() {
+    let mut app = test_app();
+    let ball = app.world_mut().spawn((Ball, Transform::default())).id();
+    let brick = spawn_brick(&mut app, SIMPLE_BRICK);
+
+    hit(&mut app, ball, brick);
+
+    assert!(app.world().get_entity(brick).is_err());
+    assert!(app.world().resource::<Bounces>().0.is_empty());
+}
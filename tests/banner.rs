@@ -0,0 +1,70 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use brkrs::systems::LevelStarted;
+use brkrs::ui::banner::{
+    animate_banners, announce_level_started, on_show_banner, Banner, ShowBanner, BANNER_SECS,
+};
+use brkrs::ui::fonts::UiFonts;
+
+use std::time::Duration;
+
+fn banner_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(UiFonts {
+            orbitron: Handle::default(),
+        })
+        .add_observer(on_show_banner)
+        .add_observer(announce_level_started)
+        .add_systems(Update, animate_banners);
+    app.update();
+    app
+}
+
+fn advance(app: &mut App, secs: f32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        secs,
+    )));
+    app.update();
+}
+
+fn banner_texts(app: &mut App) -> Vec<String> {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Text, With<Banner>>()
+        .iter(world)
+        .map(|text| text.0.clone())
+        .collect()
+}
+
+#[test]
+fn level_start_is_announced_then_fades_away() {
+    let mut app = banner_test_app();
+    app.world_mut().trigger(LevelStarted { level_index: 5 });
+    app.update();
+    assert_eq!(banner_texts(&mut app), vec!["LEVEL 5".to_string()]);
+
+    // A newer announcement replaces the one on screen
+    app.world_mut().trigger(ShowBanner::new("BONUS!"));
+    app.update();
+    assert_eq!(banner_texts(&mut app), vec!["BONUS!".to_string()]);
+
+    for _ in 0..((BANNER_SECS / 0.2) as usize + 2) {
+        advance(&mut app, 0.2);
+    }
+    assert!(banner_texts(&mut app).is_empty());
+}
+
+#[test]
+fn persistent_banner_stays() {
+    let mut app = banner_test_app();
+    app.world_mut()
+        .trigger(ShowBanner::persistent("GAME COMPLETE"));
+    for _ in 0..((BANNER_SECS / 0.2) as usize + 2) {
+        advance(&mut app, 0.2);
+    }
+    assert_eq!(banner_texts(&mut app), vec!["GAME COMPLETE".to_string()]);
+}
@@ -0,0 +1,110 @@
+//! Blast bricks (index 50) destroy every brick within the configured radius and flash.
+
+use bevy::app::App;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use brkrs::gameplay_config::{BlastConfig, GameplayConfig};
+use brkrs::level_format::{BLAST_BRICK, BOMB_BRICK, SIMPLE_BRICK};
+use brkrs::systems::explosion::{
+    BlastFlash, BlastReach, BrickExploded, ExplosionPlugin, FLASH_SECONDS,
+};
+use brkrs::{Brick, BrickGridPos, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn};
+
+use std::time::Duration;
+
+fn test_app(radius: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(ExplosionPlugin)
+        .insert_resource(GameplayConfig {
+            blast: BlastConfig { radius },
+            ..Default::default()
+        });
+    app.update();
+    app
+}
+
+fn advance(app: &mut App, secs: f32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        secs,
+    )));
+    app.update();
+}
+
+/// A brick at `row`, `col` of a grid of 1.5 x 2.0 cells.
+fn spawn_brick(app: &mut App, type_id: u8, row: usize, col: usize) -> Entity {
+    app.world_mut()
+        .spawn((
+            Brick,
+            BrickTypeId(type_id),
+            BrickGridPos { row, col },
+            CountsTowardsCompletion,
+            Transform::from_xyz(row as f32 * 1.5, 2.0, col as f32 * 2.0),
+        ))
+        .id()
+}
+
+fn is_marked(app: &App, entity: Entity) -> bool {
+    app.world().entity(entity).contains::<MarkedForDespawn>()
+}
+
+fn flashes(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&BlastFlash>()
+        .iter(app.world())
+        .count()
+}
+
+#[test]
+fn blast_destroys_bricks_within_the_radius() {
+    let mut app = test_app(5.0);
+    let blast = spawn_brick(&mut app, BLAST_BRICK, 10, 10);
+    let three_rows = spawn_brick(&mut app, SIMPLE_BRICK, 13, 10);
+    let two_cols = spawn_brick(&mut app, SIMPLE_BRICK, 10, 12);
+    let three_cols = spawn_brick(&mut app, SIMPLE_BRICK, 10, 13);
+
+    app.world_mut().entity_mut(blast).insert(MarkedForDespawn);
+    advance(&mut app, 0.016);
+
+    assert!(is_marked(&app, three_rows), "4.5 away");
+    assert!(is_marked(&app, two_cols), "4.0 away");
+    assert!(!is_marked(&app, three_cols), "6.0 away");
+
+    let exploded: Vec<BrickExploded> = app
+        .world_mut()
+        .resource_mut::<Messages<BrickExploded>>()
+        .drain()
+        .collect();
+    assert_eq!(exploded.len(), 1);
+    assert_eq!(exploded[0].brick, blast);
+    assert_eq!(exploded[0].reach, BlastReach::Radius(5.0));
+    assert_eq!(flashes(&mut app), 1);
+
+    let frames = (FLASH_SECONDS / 0.1).ceil() as usize + 1;
+    for _ in 0..frames {
+        advance(&mut app, 0.1);
+    }
+    assert_eq!(flashes(&mut app), 0);
+}
+
+#[test]
+fn blasts_chain_with_bombs_both_ways() {
+    let mut app = test_app(3.0);
+    let bomb = spawn_brick(&mut app, BOMB_BRICK, 4, 4);
+    // Next to the bomb: caught in its blast, goes off with its own radius
+    let blast = spawn_brick(&mut app, BLAST_BRICK, 4, 5);
+    // Two rows (3.0) from the blast brick, out of the bomb's reach
+    let far = spawn_brick(&mut app, SIMPLE_BRICK, 6, 5);
+    let untouched = spawn_brick(&mut app, SIMPLE_BRICK, 4, 8);
+
+    app.world_mut().entity_mut(bomb).insert(MarkedForDespawn);
+    advance(&mut app, 0.016);
+
+    assert!(is_marked(&app, blast));
+    assert!(is_marked(&app, far));
+    assert!(!is_marked(&app, untouched));
+    assert_eq!(flashes(&mut app), 2);
+}
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use bevy::{app::App, prelude::*, time::TimeUpdateStrategy};
+use brkrs::systems::brick_debris::{BrickDebrisConfig, BrickDebrisPlugin, DebrisPiece, DebrisPool};
+use brkrs::{Brick, MarkedForDespawn};
+
+fn debris_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(BrickDebrisPlugin)
+        .insert_resource(Assets::<Mesh>::default())
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    app.update();
+    app
+}
+
+fn destroy_brick(app: &mut App, at: Vec3) {
+    let brick = app
+        .world_mut()
+        .spawn((
+            Brick,
+            MarkedForDespawn,
+            Transform::from_translation(at),
+            GlobalTransform::from_translation(at),
+        ))
+        .id();
+    app.world_mut().entity_mut(brick).despawn();
+}
+
+fn flying_pieces(app: &mut App) -> Vec<Vec3> {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Transform, With<DebrisPiece>>()
+        .iter(world)
+        .map(|t| t.translation)
+        .collect()
+}
+
+#[test]
+fn destroyed_bricks_burst_into_falling_debris_that_is_reused() {
+    let mut app = debris_app();
+    let start = Vec3::new(1.0, 2.0, 3.0);
+    destroy_brick(&mut app, start);
+    app.update();
+
+    let pieces = flying_pieces(&mut app);
+    assert!((3..=6).contains(&pieces.len()), "{} pieces", pieces.len());
+
+    for _ in 0..4 {
+        app.update();
+    }
+    // Pieces fall under gravity
+    assert!(flying_pieces(&mut app).iter().all(|p| p.y < start.y));
+
+    for _ in 0..8 {
+        app.update();
+    }
+    assert!(flying_pieces(&mut app).is_empty());
+    let pool = app.world().resource::<DebrisPool>();
+    assert_eq!(pool.active(), 0);
+    let idle = pool.idle();
+    assert_eq!(idle, pieces.len());
+
+    // The next brick takes its pieces from the pool first
+    destroy_brick(&mut app, start);
+    app.update();
+    let reused = flying_pieces(&mut app).len();
+    let pool = app.world().resource::<DebrisPool>();
+    assert_eq!(pool.idle(), idle.saturating_sub(reused));
+}
+
+#[test]
+fn debris_is_capped_and_skips_unmarked_bricks() {
+    let mut app = debris_app();
+    app.insert_resource(BrickDebrisConfig {
+        max_alive: 8,
+        ..Default::default()
+    });
+
+    // Cleared by a level switch, not destroyed
+    let brick = app.world_mut().spawn((Brick, Transform::default())).id();
+    app.world_mut().entity_mut(brick).despawn();
+    app.update();
+    assert!(flying_pieces(&mut app).is_empty());
+
+    for _ in 0..5 {
+        destroy_brick(&mut app, Vec3::ZERO);
+    }
+    app.update();
+    assert_eq!(flying_pieces(&mut app).len(), 8);
+}
@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+
+use bevy::{app::App, prelude::*};
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::brick_groups::{BrickGroup, BrickGroupsPlugin, GroupLock, GroupLocked};
+use brkrs::{Brick, BrickGridPos, CountsTowardsCompletion};
+
+/// Level with group 1 at row 0 and group 2, locked until group 1 is cleared, at row 1.
+fn locked_level() -> LevelDefinition {
+    let mut def: LevelDefinition =
+        ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+    def.matrix = vec![vec![20, 20], vec![20, 20]];
+    def.groups = BTreeMap::from([(1, vec![(0, 0), (0, 1)]), (2, vec![(1, 0), (1, 1)])]);
+    def.group_locks = vec![GroupLock {
+        group: 2,
+        until_cleared: 1,
+    }];
+    def
+}
+
+fn groups_test_app() -> (App, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(CurrentLevel(locked_level()));
+    app.add_plugins(BrickGroupsPlugin);
+    let bricks = [(0, 0), (0, 1), (1, 0), (1, 1)]
+        .into_iter()
+        .map(|(row, col)| {
+            app.world_mut()
+                .spawn((Brick, BrickGridPos { row, col }, CountsTowardsCompletion))
+                .id()
+        })
+        .collect();
+    app.update();
+    app.update();
+    (app, bricks)
+}
+
+fn is_locked(app: &App, brick: Entity) -> bool {
+    let entity = app.world().entity(brick);
+    entity.contains::<GroupLocked>() && !entity.contains::<CountsTowardsCompletion>()
+}
+
+#[test]
+fn bricks_are_tagged_with_their_group() {
+    let (app, bricks) = groups_test_app();
+    let groups: Vec<u32> = bricks
+        .iter()
+        .map(|&b| app.world().get::<BrickGroup>(b).unwrap().0)
+        .collect();
+    assert_eq!(groups, vec![1, 1, 2, 2]);
+}
+
+#[test]
+fn locked_group_unlocks_once_its_key_group_is_cleared() {
+    let (mut app, bricks) = groups_test_app();
+    assert!(!is_locked(&app, bricks[0]));
+    assert!(is_locked(&app, bricks[2]));
+    assert!(is_locked(&app, bricks[3]));
+
+    app.world_mut().despawn(bricks[0]);
+    app.update();
+    assert!(is_locked(&app, bricks[2]), "group 1 still has a brick");
+
+    app.world_mut().despawn(bricks[1]);
+    app.update();
+    for &brick in &bricks[2..] {
+        let entity = app.world().entity(brick);
+        assert!(!entity.contains::<GroupLocked>());
+        assert!(entity.contains::<CountsTowardsCompletion>());
+    }
+}
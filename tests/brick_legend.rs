@@ -0,0 +1,141 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::level_format::{
+    BOMB_BRICK, EXTRA_LIFE_BRICK, INDESTRUCTIBLE_BRICK, MULTI_HIT_BRICK_1, SIMPLE_BRICK,
+};
+use brkrs::ui::brick_legend::{
+    ensure_brick_legend, tint_bricks_by_category, toggle_brick_legend, BrickCategory,
+    BrickLegendRoot, BrickLegendState, LegendOriginalMaterial, BRICK_LEGEND_KEY,
+};
+use brkrs::ui::focus::Focusable;
+use brkrs::ui::fonts::UiFonts;
+use brkrs::{Brick, BrickTypeId, CountsTowardsCompletion};
+
+fn legend_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(Assets::<StandardMaterial>::default())
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<BrickLegendState>()
+        .insert_resource(UiFonts {
+            orbitron: Handle::default(),
+        })
+        .add_systems(
+            Update,
+            (
+                toggle_brick_legend,
+                tint_bricks_by_category,
+                ensure_brick_legend,
+            )
+                .chain(),
+        );
+    app.update();
+    app
+}
+
+fn press_legend_key(app: &mut App) {
+    {
+        let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        input.reset_all();
+        input.press(BRICK_LEGEND_KEY);
+    }
+    app.update();
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .reset_all();
+}
+
+fn spawn_brick(app: &mut App, type_id: u8) -> (Entity, Handle<StandardMaterial>) {
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            Brick,
+            BrickTypeId(type_id),
+            CountsTowardsCompletion,
+            MeshMaterial3d(material.clone()),
+        ))
+        .id();
+    (entity, material)
+}
+
+fn material_of(app: &App, entity: Entity) -> Handle<StandardMaterial> {
+    app.world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap()
+        .0
+        .clone()
+}
+
+fn legends(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(), With<BrickLegendRoot>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn bricks_fall_into_legend_categories() {
+    use BrickCategory::*;
+    assert_eq!(BrickCategory::of(SIMPLE_BRICK, true, None), Destructible);
+    assert_eq!(BrickCategory::of(MULTI_HIT_BRICK_1, true, None), MultiHit);
+    assert_eq!(BrickCategory::of(SIMPLE_BRICK, true, Some(3)), MultiHit);
+    assert_eq!(BrickCategory::of(EXTRA_LIFE_BRICK, true, None), Powerup);
+    assert_eq!(BrickCategory::of(BOMB_BRICK, true, None), Powerup);
+    assert_eq!(
+        BrickCategory::of(INDESTRUCTIBLE_BRICK, false, None),
+        Indestructible
+    );
+}
+
+#[test]
+fn legend_key_tints_bricks_and_restores_their_materials() {
+    let mut app = legend_test_app();
+    let (simple, simple_material) = spawn_brick(&mut app, SIMPLE_BRICK);
+    let (other, _) = spawn_brick(&mut app, SIMPLE_BRICK);
+    let (multi, multi_material) = spawn_brick(&mut app, MULTI_HIT_BRICK_1);
+
+    press_legend_key(&mut app);
+    assert_eq!(legends(&mut app), 1);
+    let tint = material_of(&app, simple);
+    assert_ne!(tint, simple_material);
+    assert_eq!(material_of(&app, other), tint);
+    assert_ne!(material_of(&app, multi), tint);
+    assert_eq!(
+        app.world().get::<LegendOriginalMaterial>(simple).unwrap().0,
+        simple_material
+    );
+
+    // The game swaps a material while the legend is open
+    let swapped = app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial::default());
+    app.world_mut()
+        .get_mut::<MeshMaterial3d<StandardMaterial>>(simple)
+        .unwrap()
+        .0 = swapped.clone();
+    app.update();
+    assert_eq!(material_of(&app, simple), tint);
+
+    press_legend_key(&mut app);
+    assert_eq!(legends(&mut app), 0);
+    assert_eq!(material_of(&app, simple), swapped);
+    assert_eq!(material_of(&app, multi), multi_material);
+    assert!(app.world().get::<LegendOriginalMaterial>(simple).is_none());
+}
+
+#[test]
+fn legend_stays_closed_while_a_menu_is_shown() {
+    let mut app = legend_test_app();
+    app.world_mut().spawn(Focusable::new(0));
+    press_legend_key(&mut app);
+    assert!(!app.world().resource::<BrickLegendState>().open);
+    assert_eq!(legends(&mut app), 0);
+}
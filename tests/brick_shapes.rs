@@ -0,0 +1,79 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::Collider;
+use brkrs::systems::brick_shapes::{BrickShape, BrickShapesPlugin};
+use brkrs::systems::textures::TypeVariantRegistry;
+use brkrs::{Brick, BrickTypeId};
+
+fn shapes_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(Assets::<Mesh>::default())
+        .add_plugins(BrickShapesPlugin);
+    let mut registry = TypeVariantRegistry::default();
+    registry.insert_shape_for_tests(30, BrickShape::Wedge);
+    registry.insert_shape_for_tests(31, BrickShape::Dome);
+    app.insert_resource(registry);
+    app.update();
+    app
+}
+
+fn spawn_brick(app: &mut App, type_id: u8) -> Entity {
+    app.world_mut()
+        .spawn((
+            Brick,
+            BrickTypeId(type_id),
+            Mesh3d::default(),
+            Collider::cuboid(0.5, 0.25, 0.5),
+        ))
+        .id()
+}
+
+#[test]
+fn bricks_take_the_shape_of_their_type() {
+    let mut app = shapes_test_app();
+    let wedge = spawn_brick(&mut app, 30);
+    let plain = spawn_brick(&mut app, 20);
+    app.update();
+
+    let world = app.world();
+    assert_eq!(world.get::<BrickShape>(wedge), Some(&BrickShape::Wedge));
+    let collider = world.get::<Collider>(wedge).unwrap();
+    assert!(collider.as_convex_polyhedron().is_some());
+    assert_ne!(world.get::<Mesh3d>(wedge).unwrap().0, Handle::default());
+
+    assert_eq!(world.get::<BrickShape>(plain), None);
+    assert!(world.get::<Collider>(plain).unwrap().as_cuboid().is_some());
+}
+
+#[test]
+fn type_changes_reshape_bricks() {
+    let mut app = shapes_test_app();
+    let brick = spawn_brick(&mut app, 31);
+    app.update();
+    assert_eq!(
+        app.world().get::<BrickShape>(brick),
+        Some(&BrickShape::Dome)
+    );
+
+    app.world_mut().get_mut::<BrickTypeId>(brick).unwrap().0 = 20;
+    app.update();
+    let world = app.world();
+    assert_eq!(world.get::<BrickShape>(brick), Some(&BrickShape::Cuboid));
+    assert!(world.get::<Collider>(brick).unwrap().as_cuboid().is_some());
+}
+
+#[test]
+fn shaped_bricks_share_meshes() {
+    let mut app = shapes_test_app();
+    let a = spawn_brick(&mut app, 30);
+    let b = spawn_brick(&mut app, 30);
+    app.update();
+    let world = app.world();
+    assert_eq!(
+        world.get::<Mesh3d>(a).unwrap().0,
+        world.get::<Mesh3d>(b).unwrap().0
+    );
+}
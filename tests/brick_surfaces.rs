@@ -0,0 +1,62 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::{Friction, Restitution};
+use brkrs::gameplay_config::GameplayConfig;
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::brick_surfaces::{BrickSurface, BrickSurfacesPlugin};
+use brkrs::{Brick, BrickTypeId};
+
+fn surfaces_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(BrickSurfacesPlugin);
+    let mut gameplay = GameplayConfig::default();
+    gameplay.brick_surfaces.insert(
+        90,
+        BrickSurface {
+            restitution: Some(1.6),
+            friction: None,
+        },
+    );
+    app.insert_resource(gameplay);
+    let def: LevelDefinition = ron::de::from_str(
+        "LevelDefinition(number: 1, matrix: [], brick_surfaces: {20: (restitution: 0.4, friction: 1.8)})",
+    )
+    .unwrap();
+    app.insert_resource(CurrentLevel(def));
+    app.update();
+    app
+}
+
+fn spawn_brick(app: &mut App, type_id: u8) -> Entity {
+    app.world_mut()
+        .spawn((
+            Brick,
+            BrickTypeId(type_id),
+            Restitution::coefficient(1.0),
+            Friction::coefficient(1.0),
+        ))
+        .id()
+}
+
+fn surface(app: &App, brick: Entity) -> (f32, f32) {
+    let world = app.world();
+    (
+        world.get::<Restitution>(brick).unwrap().coefficient,
+        world.get::<Friction>(brick).unwrap().coefficient,
+    )
+}
+
+#[test]
+fn spawned_bricks_take_the_surface_of_their_type() {
+    let mut app = surfaces_test_app();
+    let metal = spawn_brick(&mut app, 90);
+    let sponge = spawn_brick(&mut app, 20);
+    let plain = spawn_brick(&mut app, 3);
+    app.update();
+    assert_eq!(surface(&app, metal), (1.6, 1.0));
+    assert_eq!(surface(&app, sponge), (0.4, 1.8));
+    assert_eq!(surface(&app, plain), (1.0, 1.0));
+}
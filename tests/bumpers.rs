@@ -0,0 +1,94 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::{app::App, prelude::*};
+use bevy_rapier3d::prelude::{CollisionEvent, Velocity};
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+use brkrs::systems::bumpers::{
+    spawn_bumper, Bumper, BumperSquash, BumpersPlugin, BUMPER_KICK_SPEED, BUMPER_POINTS,
+};
+use brkrs::systems::scoring::ScoreState;
+use brkrs::{Ball, Brick, CountsTowardsCompletion, GridResolution};
+
+fn bumper_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ScoreState::default());
+    app.add_plugins(BumpersPlugin);
+    app.update();
+    app
+}
+
+#[test]
+fn ball_is_kicked_away_from_the_bumper() {
+    let mut app = bumper_test_app();
+    let bumper = app
+        .world_mut()
+        .spawn((Bumper, Transform::from_xyz(0.0, 2.0, 0.0)))
+        .id();
+    // Ball to the bumper's +x side, still heading into it
+    let ball = app
+        .world_mut()
+        .spawn((
+            Ball,
+            Transform::from_xyz(1.0, 2.0, 0.0),
+            Velocity::linear(Vec3::new(-5.0, 0.0, 3.0)),
+        ))
+        .id();
+    app.world_mut().write_message(CollisionEvent::Started(
+        ball,
+        bumper,
+        CollisionEventFlags::empty(),
+    ));
+    app.update();
+
+    let velocity = app.world().get::<Velocity>(ball).unwrap().linvel;
+    assert!((velocity.x - BUMPER_KICK_SPEED).abs() < 1e-4);
+    assert_eq!(velocity.z, 3.0, "tangential speed is kept");
+    assert_eq!(
+        app.world().resource::<ScoreState>().current_score,
+        BUMPER_POINTS
+    );
+    assert!(app.world().get::<BumperSquash>(bumper).is_some());
+}
+
+#[test]
+fn squash_animation_returns_to_rest() {
+    let mut app = bumper_test_app();
+    let bumper = app
+        .world_mut()
+        .spawn((
+            Bumper,
+            Transform::default(),
+            BumperSquash {
+                timer: Timer::from_seconds(0.0, TimerMode::Once),
+            },
+        ))
+        .id();
+    app.update();
+    assert!(app.world().get::<BumperSquash>(bumper).is_none());
+    assert_eq!(
+        app.world().get::<Transform>(bumper).unwrap().scale,
+        Vec3::ONE
+    );
+}
+
+#[test]
+fn spawned_bumpers_never_count_towards_completion() {
+    let mut app = bumper_test_app();
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.world_mut()
+        .run_system_once(
+            |mut commands: Commands,
+             mut meshes: ResMut<Assets<Mesh>>,
+             mut materials: ResMut<Assets<StandardMaterial>>| {
+                let grid = GridResolution::default();
+                spawn_bumper(&mut commands, &mut meshes, &mut materials, &grid, 3, 4);
+            },
+        )
+        .unwrap();
+    let world = app.world_mut();
+    let mut bumpers =
+        world.query_filtered::<Has<CountsTowardsCompletion>, (With<Bumper>, With<Brick>)>();
+    let counted: Vec<bool> = bumpers.iter(world).collect();
+    assert_eq!(counted, vec![false]);
+}
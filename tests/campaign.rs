@@ -0,0 +1,101 @@
+use bevy::{app::App, input::InputPlugin, prelude::*};
+use bevy_rapier3d::prelude::RapierConfiguration;
+use brkrs::level_format::SECRET_EXIT_BRICK;
+use brkrs::level_loader::{CurrentLevel, LevelAdvanceState, LevelLoaderPlugin};
+use brkrs::signals::BrickDestroyed;
+use brkrs::systems::campaign::{
+    CampaignLink, CampaignManifest, CampaignPlugin, SecretExitState, CAMPAIGN_MANIFEST_PATH,
+};
+use brkrs::systems::respawn::SpawnPoints;
+use brkrs::systems::LevelSwitchPlugin;
+use brkrs::{Brick, CountsTowardsCompletion, GameProgress};
+
+fn campaign_test_app() -> App {
+    std::env::set_var("BK_LEVEL", "1");
+    let mut app = App::new();
+    app.insert_resource(brkrs::physics_config::BallPhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::PaddlePhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    app.add_plugins((MinimalPlugins, InputPlugin));
+    app.insert_resource(GameProgress::default());
+    app.insert_resource(LevelAdvanceState::default());
+    app.insert_resource(SpawnPoints::default());
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.world_mut().spawn(RapierConfiguration::new(1.0));
+    app.add_plugins((LevelSwitchPlugin, LevelLoaderPlugin, CampaignPlugin));
+    app.update();
+    app.update();
+    // Level 1 branches to level 3 through its secret exit
+    app.insert_resource(CampaignManifest {
+        levels: [(
+            1,
+            CampaignLink {
+                next: None,
+                secret_exit: Some(3),
+            },
+        )]
+        .into(),
+    });
+    app
+}
+
+fn destroy_secret_exit(app: &mut App) {
+    app.world_mut().write_message(BrickDestroyed {
+        brick_entity: Entity::PLACEHOLDER,
+        brick_type: SECRET_EXIT_BRICK,
+        destroyed_by: None,
+    });
+    app.update();
+}
+
+fn clear_level(app: &mut App) {
+    let world = app.world_mut();
+    let bricks: Vec<Entity> = world
+        .query_filtered::<Entity, (With<Brick>, With<CountsTowardsCompletion>)>()
+        .iter(world)
+        .collect();
+    for brick in bricks {
+        app.world_mut().despawn(brick);
+    }
+    app.update();
+}
+
+fn pending_level(app: &App) -> Option<u32> {
+    app.world()
+        .resource::<LevelAdvanceState>()
+        .pending
+        .as_ref()
+        .map(|def| def.number)
+}
+
+#[test]
+fn completing_without_the_secret_exit_follows_the_main_path() {
+    let mut app = campaign_test_app();
+    clear_level(&mut app);
+    assert_eq!(pending_level(&app), Some(2));
+}
+
+#[test]
+fn destroyed_secret_exit_routes_to_the_branch() {
+    let mut app = campaign_test_app();
+    destroy_secret_exit(&mut app);
+    assert!(app.world().resource::<SecretExitState>().found);
+    clear_level(&mut app);
+    assert_eq!(pending_level(&app), Some(3));
+}
+
+#[test]
+fn reloading_the_level_forgets_the_secret_exit() {
+    let mut app = campaign_test_app();
+    destroy_secret_exit(&mut app);
+    app.world_mut().resource_mut::<CurrentLevel>().set_changed();
+    app.update();
+    assert!(!app.world().resource::<SecretExitState>().found);
+}
+
+#[test]
+fn shipped_campaign_manifest_parses() {
+    let content = std::fs::read_to_string(CAMPAIGN_MANIFEST_PATH).unwrap();
+    ron::de::from_str::<CampaignManifest>(&content).unwrap();
+}
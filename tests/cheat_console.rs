@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::level_format::SIMPLE_BRICK;
+use brkrs::physics_config::BrickPhysicsConfig;
+use brkrs::systems::cheat_console::{parse_cheat_command, CheatCommand};
+use brkrs::systems::cheat_mode::CheatModeState;
+use brkrs::systems::{CheatConsole, CheatConsolePlugin, SpawnBrickRequested};
+use brkrs::{BrickGridPos, BrickTypeId, CountsTowardsCompletion};
+
+fn console_app(cheat_active: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin));
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(BrickPhysicsConfig::default());
+    app.insert_resource(CheatModeState {
+        active: cheat_active,
+        activated_at: None,
+    });
+    app.add_plugins(CheatConsolePlugin);
+    app
+}
+
+fn spawned_bricks(app: &mut App) -> Vec<(u8, BrickGridPos, bool)> {
+    let mut query = app.world_mut().query::<(
+        &BrickTypeId,
+        &BrickGridPos,
+        Option<&CountsTowardsCompletion>,
+    )>();
+    query
+        .iter(app.world())
+        .map(|(type_id, pos, counts)| (type_id.0, *pos, counts.is_some()))
+        .collect()
+}
+
+#[test]
+fn spawnbrick_commands_are_parsed_and_validated() {
+    assert_eq!(
+        parse_cheat_command("spawnbrick 42 3 7"),
+        Ok(CheatCommand::SpawnBrick {
+            brick_type: 42,
+            row: 3,
+            col: 7,
+        })
+    );
+    assert_eq!(parse_cheat_command("  help "), Ok(CheatCommand::Help));
+    assert!(parse_cheat_command("spawnbrick 20 1").is_err());
+    assert!(
+        parse_cheat_command("spawnbrick 2 1 1").is_err(),
+        "paddle and ball markers are not bricks"
+    );
+    assert!(parse_cheat_command("spawnbrick 20 99 1").is_err());
+    assert!(parse_cheat_command("spawnbrick 20 1 x").is_err());
+    assert!(parse_cheat_command("teleport").is_err());
+    assert!(parse_cheat_command("").is_err());
+}
+
+#[test]
+fn requested_bricks_are_spawned_into_free_cells() {
+    let mut app = console_app(true);
+    app.update();
+
+    app.world_mut().write_message(SpawnBrickRequested {
+        brick_type: 90,
+        row: 4,
+        col: 5,
+    });
+    app.world_mut().write_message(SpawnBrickRequested {
+        brick_type: SIMPLE_BRICK,
+        row: 4,
+        col: 5,
+    });
+    app.update();
+
+    let bricks = spawned_bricks(&mut app);
+    assert_eq!(
+        bricks,
+        vec![(90, BrickGridPos { row: 4, col: 5 }, false)],
+        "the occupied cell keeps the first brick, indestructible bricks do not count"
+    );
+    assert!(app
+        .world()
+        .resource::<CheatConsole>()
+        .output
+        .contains("occupied"));
+}
+
+#[test]
+fn requests_are_ignored_outside_cheat_mode() {
+    let mut app = console_app(false);
+    app.update();
+
+    app.world_mut().write_message(SpawnBrickRequested {
+        brick_type: SIMPLE_BRICK,
+        row: 0,
+        col: 0,
+    });
+    app.update();
+
+    assert!(spawned_bricks(&mut app).is_empty());
+}
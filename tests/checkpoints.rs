@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use bevy::{app::App, input::InputPlugin, prelude::*};
+use bevy_rapier3d::prelude::RapierConfiguration;
+use brkrs::level_loader::{LevelAdvanceState, LevelLoaderPlugin, RestartRequested};
+use brkrs::systems::checkpoints::{CheckpointsPlugin, LevelCheckpoints};
+use brkrs::systems::respawn::{GameOverRequested, LivesState, SpawnPoints};
+use brkrs::systems::scoring::ScoreState;
+use brkrs::systems::LevelSwitchPlugin;
+use brkrs::{Brick, BrickGridPos, CountsTowardsCompletion, GameProgress};
+
+fn checkpoint_test_app() -> App {
+    std::env::set_var("BK_LEVEL", "1");
+    let mut app = App::new();
+    app.insert_resource(brkrs::physics_config::BallPhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::PaddlePhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    app.add_plugins((MinimalPlugins, InputPlugin));
+    app.insert_resource(GameProgress::default());
+    app.insert_resource(LevelAdvanceState::default());
+    app.insert_resource(SpawnPoints::default());
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(ScoreState::default());
+    app.insert_resource(LivesState {
+        lives_remaining: 3,
+        on_last_life: false,
+    });
+    app.world_mut().spawn(RapierConfiguration::new(1.0));
+    app.add_plugins((LevelSwitchPlugin, LevelLoaderPlugin, CheckpointsPlugin));
+    app.update();
+    app.update();
+    app
+}
+
+fn counted_bricks(app: &mut App) -> Vec<(Entity, BrickGridPos)> {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(Entity, &BrickGridPos), (With<Brick>, With<CountsTowardsCompletion>)>()
+        .iter(world)
+        .map(|(entity, pos)| (entity, *pos))
+        .collect()
+}
+
+/// Make the cell of the first counted brick level 1's only checkpoint.
+fn single_brick_checkpoint(app: &mut App) -> (Entity, BrickGridPos) {
+    let first = counted_bricks(app)[0];
+    app.insert_resource(LevelCheckpoints {
+        level_number: Some(1),
+        groups: vec![HashSet::from([(first.1.row, first.1.col)])],
+        reached: None,
+    });
+    first
+}
+
+#[test]
+fn clearing_a_group_snapshots_the_remaining_bricks() {
+    let mut app = checkpoint_test_app();
+    let total = counted_bricks(&mut app).len();
+    let (brick, _) = single_brick_checkpoint(&mut app);
+    app.update();
+    assert!(app.world().resource::<LevelCheckpoints>().reached.is_none());
+
+    app.world_mut().resource_mut::<ScoreState>().current_score = 40;
+    app.world_mut().despawn(brick);
+    app.update();
+    let checkpoints = app.world().resource::<LevelCheckpoints>();
+    let reached = checkpoints.reached.as_ref().expect("checkpoint reached");
+    assert_eq!(reached.index, 0);
+    assert_eq!(reached.score, 40);
+    assert!(reached.bricks.len() >= total - 1);
+    assert_eq!(checkpoints.reached_count(), 1);
+}
+
+#[test]
+fn game_over_restarts_from_the_checkpoint() {
+    let mut app = checkpoint_test_app();
+    let total = counted_bricks(&mut app).len();
+    let (brick, pos) = single_brick_checkpoint(&mut app);
+    app.world_mut().resource_mut::<ScoreState>().current_score = 40;
+    app.world_mut().despawn(brick);
+    app.update();
+
+    // Progress past the checkpoint, then lose every life
+    let (other, _) = counted_bricks(&mut app)[0];
+    app.world_mut().despawn(other);
+    app.world_mut().resource_mut::<ScoreState>().current_score = 90;
+    app.world_mut().resource_mut::<LivesState>().lives_remaining = 0;
+    app.world_mut()
+        .write_message(GameOverRequested { remaining_lives: 0 });
+    app.update();
+    app.update();
+
+    let bricks = counted_bricks(&mut app);
+    assert_eq!(bricks.len(), total - 1, "bricks come back as they were");
+    assert!(bricks.iter().all(|(_, p)| *p != pos));
+    assert_eq!(app.world().resource::<LivesState>().lives_remaining, 3);
+    assert_eq!(app.world().resource::<ScoreState>().current_score, 40);
+    assert!(app.world().resource::<LevelCheckpoints>().reached.is_some());
+}
+
+#[test]
+fn full_restart_discards_checkpoints() {
+    let mut app = checkpoint_test_app();
+    let total = counted_bricks(&mut app).len();
+    let (brick, _) = single_brick_checkpoint(&mut app);
+    app.world_mut().despawn(brick);
+    app.update();
+    assert!(app.world().resource::<LevelCheckpoints>().reached.is_some());
+
+    app.world_mut().write_message(RestartRequested::default());
+    app.update();
+    app.update();
+    assert!(app.world().resource::<LevelCheckpoints>().reached.is_none());
+    assert_eq!(counted_bricks(&mut app).len(), total);
+}
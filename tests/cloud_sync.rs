@@ -0,0 +1,100 @@
+use brkrs::systems::cloud_sync::{
+    backup_key, merge_profile_index, remote_backend, save_keys, sync_file, LocalFileBackend,
+    SaveBackend, SavedFile, SyncConfig, SyncOutcome, PROFILE_INDEX_KEY,
+};
+
+const KEY: &str = "config/audio.ron";
+
+fn saved(modified: u64, content: &str) -> SavedFile {
+    SavedFile {
+        modified,
+        content: content.to_string(),
+    }
+}
+
+fn read(backend: &LocalFileBackend, key: &str) -> Option<SavedFile> {
+    backend.read(key).unwrap()
+}
+
+#[test]
+fn the_latest_copy_wins_and_the_replaced_one_is_backed_up() {
+    let local_dir = tempfile::tempdir().unwrap();
+    let remote_dir = tempfile::tempdir().unwrap();
+    let local = LocalFileBackend::new(local_dir.path());
+    let remote = LocalFileBackend::new(remote_dir.path());
+
+    assert_eq!(
+        sync_file(&local, &remote, KEY).unwrap(),
+        SyncOutcome::Missing
+    );
+
+    local.write(KEY, &saved(100, "local")).unwrap();
+    assert_eq!(read(&local, KEY), Some(saved(100, "local")));
+    assert_eq!(
+        sync_file(&local, &remote, KEY).unwrap(),
+        SyncOutcome::Uploaded
+    );
+    assert_eq!(read(&remote, KEY), Some(saved(100, "local")));
+    assert_eq!(
+        sync_file(&local, &remote, KEY).unwrap(),
+        SyncOutcome::UpToDate
+    );
+
+    // Newer on the remote: downloaded, the local copy is kept as a backup
+    remote.write(KEY, &saved(200, "remote")).unwrap();
+    assert_eq!(
+        sync_file(&local, &remote, KEY).unwrap(),
+        SyncOutcome::Downloaded
+    );
+    assert_eq!(read(&local, KEY), Some(saved(200, "remote")));
+    assert_eq!(read(&local, &backup_key(KEY)), Some(saved(100, "local")));
+
+    // Newer locally: uploaded, the remote copy is kept as a backup
+    local.write(KEY, &saved(300, "edited")).unwrap();
+    assert_eq!(
+        sync_file(&local, &remote, KEY).unwrap(),
+        SyncOutcome::Uploaded
+    );
+    assert_eq!(read(&remote, KEY), Some(saved(300, "edited")));
+    assert_eq!(read(&remote, &backup_key(KEY)), Some(saved(200, "remote")));
+}
+
+#[test]
+fn profiles_from_other_machines_are_merged_into_the_index() {
+    let remote_dir = tempfile::tempdir().unwrap();
+    let remote = LocalFileBackend::new(remote_dir.path());
+    remote
+        .write(PROFILE_INDEX_KEY, &saved(1, r#"["Ann"]"#))
+        .unwrap();
+
+    let names = merge_profile_index(&remote, &["Bob".to_string()]).unwrap();
+    assert_eq!(names, vec!["Ann".to_string(), "Bob".to_string()]);
+    let index: Vec<String> =
+        ron::de::from_str(&read(&remote, PROFILE_INDEX_KEY).unwrap().content).unwrap();
+    assert_eq!(index, names);
+
+    let keys = save_keys(&names);
+    assert!(keys.contains(&"config/audio.ron".to_string()));
+    assert!(keys.contains(&"config/profiles/Ann/profile.ron".to_string()));
+    assert!(keys.contains(&"config/profiles/Bob/playlist.ron".to_string()));
+}
+
+#[test]
+fn sync_needs_a_remote_url() {
+    assert!(remote_backend(&SyncConfig::default()).is_err());
+    assert!(!SyncConfig::default().enabled, "sync is opt-in");
+}
+
+#[test]
+fn credentials_are_only_sent_over_https() {
+    let config = SyncConfig {
+        enabled: true,
+        url: "http://dav.example.com/brkrs".to_string(),
+        username: Some("player".to_string()),
+        password: Some("secret".to_string()),
+    };
+    let error = remote_backend(&config)
+        .err()
+        .expect("plain http is refused");
+    assert!(error.contains("https"), "{error}");
+}
@@ -0,0 +1,11 @@
+//! Fixtures shared by the integration tests (`mod common;`).
+
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+
+/// Empty level `number`, as a level file with only `number` and an empty `matrix` would load.
+pub fn level(number: u32) -> CurrentLevel {
+    CurrentLevel(LevelDefinition {
+        number,
+        ..Default::default()
+    })
+}
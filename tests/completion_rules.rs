@@ -0,0 +1,100 @@
+use bevy::{app::App, input::InputPlugin, prelude::*};
+use bevy_rapier3d::prelude::RapierConfiguration;
+use brkrs::level_loader::{CurrentLevel, LevelAdvanceState, LevelLoaderPlugin};
+use brkrs::systems::completion::{CompletionPlugin, CompletionRule, LevelCompletion};
+use brkrs::systems::respawn::SpawnPoints;
+use brkrs::systems::scoring::ScoreState;
+use brkrs::systems::LevelSwitchPlugin;
+use brkrs::{Brick, CountsTowardsCompletion, GameProgress};
+
+fn completion_test_app() -> App {
+    std::env::set_var("BK_LEVEL", "1");
+    let mut app = App::new();
+    app.insert_resource(brkrs::physics_config::BallPhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::PaddlePhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    app.add_plugins((MinimalPlugins, InputPlugin));
+    app.insert_resource(GameProgress::default());
+    app.insert_resource(LevelAdvanceState::default());
+    app.insert_resource(SpawnPoints::default());
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(ScoreState::default());
+    app.world_mut().spawn(RapierConfiguration::new(1.0));
+    app.add_plugins((LevelSwitchPlugin, LevelLoaderPlugin, CompletionPlugin));
+    app.update();
+    app.update();
+    app
+}
+
+fn set_rule(app: &mut App, rule: CompletionRule) {
+    app.world_mut().resource_mut::<CurrentLevel>().0.completion = rule;
+    app.update();
+}
+
+fn counted_bricks(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<Entity, (With<Brick>, With<CountsTowardsCompletion>)>()
+        .iter(world)
+        .count()
+}
+
+fn advancing(app: &App) -> bool {
+    app.world().resource::<LevelAdvanceState>().active
+}
+
+#[test]
+fn target_score_completes_the_level_with_bricks_left() {
+    let mut app = completion_test_app();
+    assert!(counted_bricks(&mut app) > 0);
+    set_rule(&mut app, CompletionRule::TargetScore(100));
+    assert_eq!(
+        app.world().resource::<LevelCompletion>().rule,
+        CompletionRule::TargetScore(100)
+    );
+    assert!(!advancing(&app));
+
+    app.world_mut().resource_mut::<ScoreState>().current_score = 100;
+    app.update();
+    assert!(advancing(&app));
+    app.update();
+    assert_eq!(counted_bricks(&mut app), 0, "leftover bricks are cleared");
+}
+
+#[test]
+fn brick_percentage_counts_against_the_bricks_at_level_start() {
+    let mut app = completion_test_app();
+    let total = counted_bricks(&mut app);
+    set_rule(&mut app, CompletionRule::BrickPercent(50.0));
+    assert_eq!(app.world().resource::<LevelCompletion>().peak_bricks, total);
+
+    let world = app.world_mut();
+    let bricks: Vec<Entity> = world
+        .query_filtered::<Entity, (With<Brick>, With<CountsTowardsCompletion>)>()
+        .iter(world)
+        .collect();
+    let half = total.div_ceil(2);
+    for &brick in &bricks[..half - 1] {
+        app.world_mut().despawn(brick);
+    }
+    app.update();
+    assert!(!advancing(&app));
+
+    app.world_mut().despawn(bricks[half - 1]);
+    app.update();
+    assert!(advancing(&app));
+}
+
+#[test]
+fn invalid_rules_fall_back_to_all_bricks() {
+    let mut app = completion_test_app();
+    set_rule(&mut app, CompletionRule::BrickPercent(250.0));
+    assert_eq!(
+        app.world().resource::<LevelCompletion>().rule,
+        CompletionRule::AllBricks
+    );
+    app.world_mut().resource_mut::<ScoreState>().current_score = 1_000_000;
+    app.update();
+    assert!(!advancing(&app));
+}
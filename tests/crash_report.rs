@@ -0,0 +1,79 @@
+use bevy::app::App;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::signals::BrickDestroyed;
+use brkrs::systems::crash_report::{
+    take_previous_crash, write_crash_report, CrashLog, CrashReportPlugin, TELEMETRY_CAPACITY,
+};
+use brkrs::systems::rng::GameRng;
+
+fn crash_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(GameRng::from_seed(42))
+        .add_plugins(CrashReportPlugin);
+    let def: LevelDefinition = ron::de::from_str("LevelDefinition(number: 7, matrix: [])").unwrap();
+    app.insert_resource(CurrentLevel(def));
+    app.update();
+    app
+}
+
+fn destroy_brick(app: &mut App, brick_type: u8) {
+    app.world_mut()
+        .resource_mut::<Messages<BrickDestroyed>>()
+        .write(BrickDestroyed {
+            brick_entity: Entity::PLACEHOLDER,
+            brick_type,
+            destroyed_by: None,
+        });
+    app.update();
+}
+
+#[test]
+fn the_log_follows_the_game_and_its_events() {
+    let mut app = crash_test_app();
+    destroy_brick(&mut app, 20);
+
+    let context = app.world().resource::<CrashLog>().snapshot();
+    assert_eq!(context.level, Some(7));
+    assert_eq!(context.seed, Some(42));
+    let events: Vec<&str> = context.events.iter().map(|e| e.text.as_str()).collect();
+    assert_eq!(events.len(), 2, "{events:?}");
+    assert_eq!(events[0], "Level 7 loaded");
+    assert!(events[1].contains("brick_type: 20"), "{events:?}");
+
+    for _ in 0..TELEMETRY_CAPACITY {
+        destroy_brick(&mut app, 21);
+    }
+    let context = app.world().resource::<CrashLog>().snapshot();
+    assert_eq!(context.events.len(), TELEMETRY_CAPACITY);
+    assert!(context
+        .events
+        .iter()
+        .all(|e| e.text.contains("brick_type: 21")));
+}
+
+#[test]
+fn reports_hold_the_state_and_are_pointed_out_once() {
+    let mut app = crash_test_app();
+    destroy_brick(&mut app, 20);
+    let report = app
+        .world()
+        .resource::<CrashLog>()
+        .snapshot()
+        .report("boom at src/lib.rs:1:1", "2026-10-17_14-03-59");
+    assert!(report.contains("panic: boom at src/lib.rs:1:1"));
+    assert!(report.contains("level: 7\nseed: 42\nscore: unknown\n"));
+    assert!(report.contains("active effects:\n  none\n"));
+    assert!(report.contains("last 2 gameplay events"));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_crash_report(dir.path(), "2026-10-17_14-03-59", &report).unwrap();
+    assert_eq!(path, dir.path().join("crash-2026-10-17_14-03-59.txt"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), report);
+    assert_eq!(take_previous_crash(dir.path()), Some(path));
+    assert_eq!(take_previous_crash(dir.path()), None);
+}
@@ -0,0 +1,115 @@
+use bevy::{app::App, prelude::*, MinimalPlugins};
+use bevy_rapier3d::prelude::CollisionEvent;
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+
+use brkrs::level_format::SIMPLE_BRICK;
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::durability::{
+    Durability, DurabilityDef, DurabilityPlugin, DurableBrickHit, HealthPip,
+};
+use brkrs::{Ball, Brick, BrickGridPos, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn};
+
+/// Level with simple bricks at (0, 0) and (0, 1); the first takes 7 hits.
+fn durable_level() -> LevelDefinition {
+    let mut def: LevelDefinition =
+        ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+    def.matrix = vec![vec![SIMPLE_BRICK, SIMPLE_BRICK]];
+    def.durability = vec![DurabilityDef {
+        hits: 7,
+        cells: vec![(0, 0)],
+    }];
+    def
+}
+
+/// Hits seen by [`DurableBrickHit`] observers, as `hits_left`.
+#[derive(Resource, Default)]
+struct ObservedHits(Vec<u8>);
+
+fn durability_test_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_message::<CollisionEvent>();
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(CurrentLevel(durable_level()));
+    app.init_resource::<ObservedHits>();
+    app.add_observer(
+        |trigger: On<DurableBrickHit>, mut hits: ResMut<ObservedHits>| {
+            hits.0.push(trigger.event().hits_left);
+        },
+    );
+    brkrs::register_brick_collision_systems(&mut app);
+    app.add_plugins(DurabilityPlugin);
+    let [durable, simple] = [0, 1].map(|col| {
+        app.world_mut()
+            .spawn((
+                Brick,
+                BrickTypeId(SIMPLE_BRICK),
+                BrickGridPos { row: 0, col },
+                CountsTowardsCompletion,
+                Transform::default(),
+            ))
+            .id()
+    });
+    app.update();
+    app.update();
+    (app, durable, simple)
+}
+
+fn hit(app: &mut App, brick: Entity) {
+    let ball = app.world_mut().spawn(Ball).id();
+    app.world_mut().write_message(CollisionEvent::Started(
+        ball,
+        brick,
+        CollisionEventFlags::empty(),
+    ));
+    app.update();
+}
+
+fn pips(app: &mut App, brick: Entity) -> usize {
+    let world = app.world_mut();
+    world
+        .query::<(&HealthPip, &ChildOf)>()
+        .iter(world)
+        .filter(|(_, parent)| parent.parent() == brick)
+        .count()
+}
+
+#[test]
+fn bricks_get_their_authored_durability() {
+    let (mut app, durable, simple) = durability_test_app();
+    assert_eq!(app.world().get::<Durability>(durable), Some(&Durability(7)));
+    assert!(app.world().get::<Durability>(simple).is_none());
+    assert_eq!(pips(&mut app, durable), 7);
+    assert_eq!(pips(&mut app, simple), 0);
+}
+
+#[test]
+fn durable_brick_breaks_on_its_last_hit() {
+    let (mut app, durable, _) = durability_test_app();
+    for _ in 0..6 {
+        hit(&mut app, durable);
+        assert!(!app.world().entity(durable).contains::<MarkedForDespawn>());
+    }
+    assert_eq!(app.world().get::<Durability>(durable), Some(&Durability(1)));
+    assert_eq!(
+        app.world().resource::<ObservedHits>().0,
+        vec![6, 5, 4, 3, 2, 1]
+    );
+    assert_eq!(pips(&mut app, durable), 1, "one pip per hit left");
+
+    hit(&mut app, durable);
+    assert!(
+        app.world().get_entity(durable).is_err(),
+        "the seventh hit destroys the brick"
+    );
+    assert_eq!(app.world().resource::<ObservedHits>().0.len(), 6);
+}
+
+#[test]
+fn plain_bricks_still_break_on_the_first_hit() {
+    let (mut app, _, simple) = durability_test_app();
+    hit(&mut app, simple);
+    assert!(app.world().get_entity(simple).is_err());
+    assert!(app.world().resource::<ObservedHits>().0.is_empty());
+}
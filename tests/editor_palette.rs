@@ -63,6 +63,7 @@ fn palette_test_app() -> App {
                 profile_id: "brick/type20".to_string(),
                 emissive_color: None,
                 animation: None,
+                shape: Default::default(),
             },
             TypeVariantDefinition {
                 object_class: ObjectClass::Brick,
@@ -70,10 +71,12 @@ fn palette_test_app() -> App {
                 profile_id: "brick/indestructible".to_string(),
                 emissive_color: None,
                 animation: None,
+                shape: Default::default(),
             },
         ],
         level_overrides: Default::default(),
         level_switch: None,
+        models: Default::default(),
     };
 
     // Rebuild registry to pick up our mappings
@@ -390,3 +393,75 @@ fn click_selects_palette_item_and_updates_resource() {
         }
     }
 }
+
+#[test]
+fn symmetry_modes_paint_mirrored_cells() {
+    use brkrs::ui::palette::SymmetryMode;
+    use brkrs::GridResolution;
+
+    let grid = GridResolution::default();
+    assert_eq!(SymmetryMode::Off.cells(&grid, 2, 3), vec![(2, 3)]);
+    assert_eq!(
+        SymmetryMode::MirrorX.cells(&grid, 2, 3),
+        vec![(2, 3), (17, 3)]
+    );
+    assert_eq!(
+        SymmetryMode::MirrorZ.cells(&grid, 2, 3),
+        vec![(2, 3), (2, 16)]
+    );
+    assert_eq!(
+        SymmetryMode::FourWay.cells(&grid, 2, 3),
+        vec![(2, 3), (17, 3), (2, 16), (17, 16)]
+    );
+    // Mirrors follow the level grid
+    assert_eq!(
+        SymmetryMode::FourWay.cells(&GridResolution::new(40, 30), 2, 3),
+        vec![(2, 3), (37, 3), (2, 26), (37, 26)]
+    );
+    // Every mode comes round again through the button
+    let mut mode = SymmetryMode::Off;
+    for _ in 0..4 {
+        mode = mode.next();
+    }
+    assert_eq!(mode, SymmetryMode::Off);
+}
+
+#[test]
+fn symmetry_button_cycles_the_mode_and_its_label() {
+    use brkrs::ui::palette::{
+        handle_symmetry_button, update_symmetry_label, PaletteState, SymmetryButton, SymmetryLabel,
+        SymmetryMode,
+    };
+
+    let mut app = palette_test_app();
+    app.init_resource::<SymmetryMode>();
+    app.add_systems(
+        Update,
+        (handle_symmetry_button, update_symmetry_label).chain(),
+    );
+    app.world_mut().resource_mut::<PaletteState>().open = true;
+    app.update();
+
+    let button = {
+        let world = app.world_mut();
+        world
+            .query_filtered::<Entity, With<SymmetryButton>>()
+            .single(world)
+            .expect("palette should have a symmetry button")
+    };
+    app.world_mut()
+        .entity_mut(button)
+        .insert(Interaction::Pressed);
+    app.update();
+
+    assert_eq!(
+        *app.world().resource::<SymmetryMode>(),
+        SymmetryMode::MirrorX
+    );
+    let world = app.world_mut();
+    let label = world
+        .query_filtered::<&Text, With<SymmetryLabel>>()
+        .single(world)
+        .unwrap();
+    assert_eq!(label.0, "Symmetry: Mirror X");
+}
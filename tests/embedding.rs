@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy::window::WindowMode;
+use bevy::MinimalPlugins;
+use bevy_rapier3d::prelude::{NoUserData, RapierPhysicsPlugin};
+
+use brkrs::plugins::manages_window;
+use brkrs::systems::CheatModePlugin;
+use brkrs::ui::UiPlugin;
+use brkrs::{BrkrsConfig, BrkrsPlugins};
+
+#[derive(Resource, Default)]
+struct Ran(bool);
+
+fn mark_ran(mut ran: ResMut<Ran>) {
+    ran.0 = true;
+}
+
+fn run_condition_app(config: Option<BrkrsConfig>) -> bool {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<Ran>();
+    if let Some(config) = config {
+        app.insert_resource(config);
+    }
+    app.add_systems(Update, mark_ran.run_if(manages_window));
+    app.update();
+    app.world().resource::<Ran>().0
+}
+
+#[test]
+fn config_builder_overrides_the_standalone_defaults() {
+    let standalone = BrkrsConfig::default();
+    assert_eq!(standalone.title, "brkrs");
+    assert!(standalone.add_default_plugins);
+    assert!(standalone.manage_window);
+
+    let embedded = BrkrsConfig::default()
+        .with_title("Mini-game")
+        .with_window_mode(WindowMode::Windowed)
+        .with_default_plugins(false)
+        .with_window_management(false);
+    assert_eq!(embedded.title, "Mini-game");
+    assert_eq!(embedded.window_mode, WindowMode::Windowed);
+    assert!(!embedded.add_default_plugins);
+    assert!(!embedded.manage_window);
+}
+
+#[test]
+fn plugin_group_members_can_be_disabled() {
+    let group = BrkrsPlugins::default().build();
+    assert!(group.enabled::<RapierPhysicsPlugin<NoUserData>>());
+    assert!(group.enabled::<CheatModePlugin>());
+    assert!(group.enabled::<UiPlugin>());
+
+    let group = group.disable::<RapierPhysicsPlugin<NoUserData>>();
+    assert!(group.contains::<RapierPhysicsPlugin<NoUserData>>());
+    assert!(!group.enabled::<RapierPhysicsPlugin<NoUserData>>());
+}
+
+#[test]
+fn window_management_follows_the_config() {
+    assert!(run_condition_app(None), "managed when no config is present");
+    assert!(run_condition_app(Some(BrkrsConfig::default())));
+    assert!(!run_condition_app(Some(
+        BrkrsConfig::default().with_window_management(false)
+    )));
+}
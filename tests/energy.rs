@@ -0,0 +1,113 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use brkrs::gameplay_config::EnergyConfig;
+use brkrs::signals::BrickDestroyed;
+use brkrs::systems::ball_save::BallSaveShield;
+use brkrs::systems::energy::{EnergyMeter, EnergyPlugin, PaddleDash, DASH_KEY, SHIELD_KEY};
+use brkrs::systems::multi_hit::MultiHitBrickHit;
+use brkrs::systems::respawn::GameOverRequested;
+use brkrs::Paddle;
+
+fn energy_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(EnergyPlugin);
+    app.init_resource::<ButtonInput<KeyCode>>();
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.update();
+    app
+}
+
+fn press(app: &mut App, key: KeyCode) {
+    let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    input.release_all();
+    input.clear();
+    input.press(key);
+}
+
+fn energy(app: &App) -> f32 {
+    app.world().resource::<EnergyMeter>().energy
+}
+
+fn shields(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(), With<BallSaveShield>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn brick_hits_charge_the_meter() {
+    let mut app = energy_test_app();
+    let per_hit = EnergyConfig::default().per_hit;
+    let brick = app.world_mut().spawn_empty().id();
+    app.world_mut().write_message(BrickDestroyed {
+        brick_entity: brick,
+        brick_type: 20,
+        destroyed_by: None,
+    });
+    app.world_mut().trigger(MultiHitBrickHit {
+        entity: brick,
+        previous_type: 13,
+        new_type: 12,
+    });
+    app.update();
+    assert_eq!(energy(&app), 2.0 * per_hit);
+}
+
+#[test]
+fn shield_is_bought_once_and_only_when_affordable() {
+    let mut app = energy_test_app();
+    let config = EnergyConfig::default();
+    app.world_mut().resource_mut::<EnergyMeter>().energy = config.shield_cost - 1.0;
+    press(&mut app, SHIELD_KEY);
+    app.update();
+    assert_eq!(shields(&mut app), 0);
+
+    app.world_mut().resource_mut::<EnergyMeter>().energy = config.max;
+    press(&mut app, SHIELD_KEY);
+    app.update();
+    assert_eq!(shields(&mut app), 1);
+    assert_eq!(energy(&app), config.max - config.shield_cost);
+
+    // A second shield is not stacked on the first
+    press(&mut app, SHIELD_KEY);
+    app.update();
+    assert_eq!(shields(&mut app), 1);
+    assert_eq!(energy(&app), config.max - config.shield_cost);
+}
+
+#[test]
+fn dash_pushes_the_paddle_for_a_moment() {
+    let mut app = energy_test_app();
+    let config = EnergyConfig::default();
+    let paddle = app.world_mut().spawn(Paddle).id();
+    app.world_mut().resource_mut::<EnergyMeter>().energy = config.dash_cost;
+    press(&mut app, DASH_KEY);
+    app.update();
+    let dash = *app.world().get::<PaddleDash>(paddle).unwrap();
+    assert!((dash.velocity.length() - config.dash_speed).abs() < 1e-4);
+    assert_eq!(energy(&app), 0.0);
+
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(
+        std::time::Duration::from_secs_f32(config.dash_secs),
+    ));
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(app.world().get::<PaddleDash>(paddle).is_none());
+}
+
+#[test]
+fn game_over_empties_the_meter() {
+    let mut app = energy_test_app();
+    app.world_mut().resource_mut::<EnergyMeter>().energy = 50.0;
+    app.world_mut()
+        .write_message(GameOverRequested { remaining_lives: 0 });
+    app.update();
+    assert_eq!(energy(&app), 0.0);
+}
@@ -38,6 +38,7 @@ fn test_app() -> App {
     app.insert_resource(ScoreState {
         current_score: 0,
         last_milestone_reached: 0,
+        ..Default::default()
     });
 
     // Register brick collision systems
@@ -0,0 +1,110 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::Damping;
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::floor_zones::{
+    FloorZoneDef, FloorZoneKind, FloorZones, FloorZonesPlugin, ICE_DAMPING_SCALE,
+    STICKY_DAMPING_BONUS,
+};
+use brkrs::Ball;
+
+fn level_with_zones(zones: Vec<FloorZoneDef>) -> CurrentLevel {
+    CurrentLevel(LevelDefinition {
+        number: 1,
+        matrix: vec![vec![0; 20]; 20],
+        floor_zones: Some(zones),
+        ..Default::default()
+    })
+}
+
+fn spawn_ball(app: &mut App, x: f32, z: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ball,
+            Transform::from_xyz(x, 2.0, z),
+            Damping {
+                linear_damping: 0.5,
+                angular_damping: 0.5,
+            },
+        ))
+        .id()
+}
+
+fn linear_damping(app: &App, ball: Entity) -> f32 {
+    app.world()
+        .entity(ball)
+        .get::<Damping>()
+        .unwrap()
+        .linear_damping
+}
+
+#[test]
+fn zones_follow_level_and_adjust_damping() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(FloorZonesPlugin);
+    // Ice over the top half (rows 0-9 => x < 0), sticky over row 19
+    app.insert_resource(level_with_zones(vec![
+        FloorZoneDef {
+            kind: FloorZoneKind::Ice,
+            rows: (0, 9),
+            cols: (0, 19),
+        },
+        FloorZoneDef {
+            kind: FloorZoneKind::Sticky,
+            rows: (19, 19),
+            cols: (0, 19),
+        },
+    ]));
+    let on_ice = spawn_ball(&mut app, -5.0, 3.0);
+    let on_sticky = spawn_ball(&mut app, 14.5, 0.0);
+    let plain = spawn_ball(&mut app, 5.0, 0.0);
+
+    app.update();
+
+    assert_eq!(app.world().resource::<FloorZones>().zones.len(), 2);
+    assert!((linear_damping(&app, on_ice) - 0.5 * ICE_DAMPING_SCALE).abs() < 1e-6);
+    assert!((linear_damping(&app, on_sticky) - (0.5 + STICKY_DAMPING_BONUS)).abs() < 1e-6);
+    assert_eq!(linear_damping(&app, plain), 0.5);
+
+    // A level without zones clears them
+    app.insert_resource(level_with_zones(Vec::new()));
+    app.update();
+    assert!(app.world().resource::<FloorZones>().zones.is_empty());
+}
+
+#[test]
+fn zones_use_the_level_grid() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(FloorZonesPlugin);
+    // On a 40x40 grid row 39 is the last 0.75 units along X, and ranges past 19 are valid
+    let mut level = level_with_zones(vec![
+        FloorZoneDef {
+            kind: FloorZoneKind::Ice,
+            rows: (0, 19),
+            cols: (0, 39),
+        },
+        FloorZoneDef {
+            kind: FloorZoneKind::Sticky,
+            rows: (39, 39),
+            cols: (20, 39),
+        },
+    ]);
+    level.0.resolution = Some((40, 40));
+    level.0.matrix = vec![vec![0; 40]; 40];
+    app.insert_resource(level);
+    let on_ice = spawn_ball(&mut app, -5.0, 3.0);
+    let on_sticky = spawn_ball(&mut app, 14.6, -5.0);
+    // Row 38, which a 20x20 grid would have counted as row 19
+    let plain = spawn_ball(&mut app, 14.0, -5.0);
+
+    app.update();
+
+    assert_eq!(app.world().resource::<FloorZones>().zones.len(), 2);
+    assert!((linear_damping(&app, on_ice) - 0.5 * ICE_DAMPING_SCALE).abs() < 1e-6);
+    assert!((linear_damping(&app, on_sticky) - (0.5 + STICKY_DAMPING_BONUS)).abs() < 1e-6);
+    assert_eq!(linear_damping(&app, plain), 0.5);
+}
@@ -0,0 +1,63 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::ExternalForce;
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::force_fields::{ForceFieldDef, ForceFieldsPlugin};
+use brkrs::Ball;
+
+fn level_with_fields(fields: Vec<ForceFieldDef>) -> CurrentLevel {
+    CurrentLevel(LevelDefinition {
+        number: 1,
+        matrix: vec![vec![0; 20]; 20],
+        force_fields: Some(fields),
+        ..Default::default()
+    })
+}
+
+fn force_on(app: &App, ball: Entity) -> Option<Vec3> {
+    app.world()
+        .entity(ball)
+        .get::<ExternalForce>()
+        .map(|f| f.force)
+}
+
+#[test]
+fn balls_inside_a_field_are_pushed_until_they_leave() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(ForceFieldsPlugin);
+    // Crosswind over rows 0-9 (x < 0), pushing towards -Z
+    app.insert_resource(level_with_fields(vec![ForceFieldDef {
+        rows: (0, 9),
+        cols: (0, 19),
+        direction: (0.0, -2.0),
+        strength: 4.0,
+        oscillation: None,
+    }]));
+    let inside = app
+        .world_mut()
+        .spawn((Ball, Transform::from_xyz(-5.0, 2.0, 0.0)))
+        .id();
+    let outside = app
+        .world_mut()
+        .spawn((Ball, Transform::from_xyz(5.0, 2.0, 0.0)))
+        .id();
+
+    app.update();
+
+    let force = force_on(&app, inside).expect("ball in the field gets a force");
+    assert!((force - Vec3::new(0.0, 0.0, -4.0)).length() < 1e-5);
+    assert_eq!(force_on(&app, outside), None);
+
+    // Leaving the field clears the force
+    app.world_mut()
+        .entity_mut(inside)
+        .get_mut::<Transform>()
+        .unwrap()
+        .translation
+        .x = 5.0;
+    app.update();
+    assert_eq!(force_on(&app, inside), Some(Vec3::ZERO));
+}
@@ -0,0 +1,79 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use brkrs::gameplay_config::GameplayConfig;
+use brkrs::systems::fov_kick::{FovKick, FovKickPlugin, FOV_KICK_MAX_DEG, FOV_KICK_MIN_IMPULSE};
+use brkrs::{MainCamera, WallHit};
+
+use std::time::Duration;
+
+fn fov_test_app(reduced_motion: bool) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(FovKickPlugin);
+    app.insert_resource(GameplayConfig {
+        reduced_motion,
+        ..default()
+    });
+    let camera = app
+        .world_mut()
+        .spawn((
+            MainCamera,
+            Projection::Perspective(PerspectiveProjection::default()),
+        ))
+        .id();
+    app.update();
+    (app, camera)
+}
+
+fn fov(app: &App, camera: Entity) -> f32 {
+    match app.world().get::<Projection>(camera).unwrap() {
+        Projection::Perspective(perspective) => perspective.fov,
+        _ => unreachable!("test camera is perspective"),
+    }
+}
+
+fn hit_wall(app: &mut App, impulse: f32) {
+    app.world_mut().trigger(WallHit {
+        impulse: Vec3::new(0.0, 0.0, impulse),
+    });
+}
+
+fn advance(app: &mut App, secs: f32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        secs,
+    )));
+    app.update();
+}
+
+#[test]
+fn hard_wall_hit_kicks_fov_then_recovers() {
+    let (mut app, camera) = fov_test_app(false);
+    let base = fov(&app, camera);
+
+    hit_wall(&mut app, 1_000.0);
+    advance(&mut app, 0.01);
+    let kicked = fov(&app, camera) - base;
+    assert!(kicked > 0.0, "FOV should widen");
+    assert!(kicked <= FOV_KICK_MAX_DEG.to_radians());
+
+    for _ in 0..5 {
+        advance(&mut app, 0.1);
+    }
+    assert!(!app.world().entity(camera).contains::<FovKick>());
+    assert_eq!(fov(&app, camera), base);
+}
+
+#[test]
+fn soft_hits_and_reduced_motion_do_not_kick() {
+    let (mut app, camera) = fov_test_app(false);
+    hit_wall(&mut app, FOV_KICK_MIN_IMPULSE * 0.5);
+    advance(&mut app, 0.01);
+    assert!(!app.world().entity(camera).contains::<FovKick>());
+
+    let (mut app, camera) = fov_test_app(true);
+    hit_wall(&mut app, 1_000.0);
+    advance(&mut app, 0.01);
+    assert!(!app.world().entity(camera).contains::<FovKick>());
+}
@@ -0,0 +1,82 @@
+use bevy::ecs::message::Messages;
+use bevy::{app::App, prelude::*};
+use brkrs::systems::game_complete::{
+    GameCompletePlugin, GameCompleteState, GameCompleted, RestartGameRequested,
+};
+use brkrs::systems::level_switch::{
+    LevelSwitchDirection, LevelSwitchRequested, LevelSwitchSource, LevelSwitchState,
+};
+use brkrs::systems::respawn::LivesState;
+use brkrs::systems::scoring::ScoreState;
+use brkrs::{Ball, Paddle};
+
+use std::path::Path;
+
+fn game_complete_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(GameCompletePlugin);
+    let mut switch_state = LevelSwitchState::default();
+    switch_state.set_levels(Path::new("assets/levels"), &[3, 1, 2]);
+    app.insert_resource(switch_state);
+    app.insert_resource(ScoreState {
+        current_score: 4_200,
+        ..Default::default()
+    });
+    app.insert_resource(LivesState {
+        lives_remaining: 1,
+        ..Default::default()
+    });
+    app
+}
+
+#[test]
+fn completing_the_game_stops_play_and_records_the_score() {
+    let mut app = game_complete_app();
+    app.world_mut().spawn(Paddle);
+    app.world_mut().spawn(Ball);
+
+    app.world_mut().trigger(GameCompleted { last_level: 3 });
+    app.update();
+
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Paddle>().iter(world).count(), 0);
+    assert_eq!(world.query::<&Ball>().iter(world).count(), 0);
+    assert_eq!(
+        *app.world().resource::<GameCompleteState>(),
+        GameCompleteState {
+            active: true,
+            final_score: 4_200,
+            last_level: 3,
+        }
+    );
+}
+
+#[test]
+fn playing_again_starts_a_fresh_game_at_the_first_level() {
+    let mut app = game_complete_app();
+    app.world_mut().trigger(GameCompleted { last_level: 3 });
+    app.update();
+
+    app.world_mut().write_message(RestartGameRequested);
+    app.update();
+
+    assert!(!app.world().resource::<GameCompleteState>().active);
+    assert_eq!(app.world().resource::<ScoreState>().current_score, 0);
+    assert_eq!(
+        app.world().resource::<LivesState>().lives_remaining,
+        LivesState::default().lives_remaining
+    );
+    let switches: Vec<LevelSwitchRequested> = app
+        .world_mut()
+        .resource_mut::<Messages<LevelSwitchRequested>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        switches,
+        vec![LevelSwitchRequested {
+            source: LevelSwitchSource::NewGame,
+            direction: LevelSwitchDirection::Jump(1),
+        }]
+    );
+}
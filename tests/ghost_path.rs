@@ -0,0 +1,107 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use brkrs::systems::ghost_path::{
+    GhostOverlayToggle, GhostPathOverlay, GhostPathPlugin, GhostStore, LevelGhost,
+};
+use brkrs::systems::sim_speed::SimSpeed;
+use brkrs::systems::LevelCompleted;
+use brkrs::Ball;
+
+use common::level;
+
+fn ghost_test_app(dir: &std::path::Path) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(GhostPathPlugin);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(GhostStore {
+        dir: dir.to_path_buf(),
+    });
+    app.insert_resource(level(3));
+    app.update();
+    app
+}
+
+/// Play level 3 for `frames` frames with the ball moving along +Z, then complete it.
+fn complete_run(app: &mut App, frames: usize) {
+    app.insert_resource(level(3));
+    let ball = app.world_mut().spawn((Ball, Transform::default())).id();
+    for _ in 0..frames {
+        app.update();
+        app.world_mut()
+            .get_mut::<Transform>(ball)
+            .unwrap()
+            .translation
+            .z += 1.0;
+    }
+    app.world_mut().trigger(LevelCompleted { level_index: 3 });
+    app.update();
+    app.world_mut().despawn(ball);
+}
+
+fn overlays(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(), With<GhostPathOverlay>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn completed_run_becomes_the_level_ghost() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut app = ghost_test_app(dir.path());
+    complete_run(&mut app, 10);
+
+    let ghost = app.world().resource::<LevelGhost>().0.clone().unwrap();
+    assert_eq!(ghost.level, 3);
+    assert!(ghost.points.len() >= 5);
+    assert!(dir.path().join("level_003.ron").exists());
+
+    // A fresh session loads the saved ghost
+    let mut reloaded = ghost_test_app(dir.path());
+    assert_eq!(reloaded.world().resource::<LevelGhost>().0, Some(ghost));
+    assert_eq!(overlays(&mut reloaded), 0, "hidden until asked for");
+    reloaded.insert_resource(GhostOverlayToggle(true));
+    reloaded.update();
+    assert_eq!(overlays(&mut reloaded), 1);
+}
+
+#[test]
+fn only_faster_runs_replace_the_ghost() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut app = ghost_test_app(dir.path());
+    complete_run(&mut app, 10);
+    let first = app.world().resource::<LevelGhost>().0.clone().unwrap();
+
+    complete_run(&mut app, 20);
+    assert_eq!(app.world().resource::<LevelGhost>().0, Some(first.clone()));
+
+    complete_run(&mut app, 5);
+    let faster = app.world().resource::<LevelGhost>().0.clone().unwrap();
+    assert!(faster.duration < first.duration);
+}
+
+#[test]
+fn other_game_speeds_save_no_ghost() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut app = ghost_test_app(dir.path());
+    app.insert_resource(SimSpeed {
+        game_speed: 0.8,
+        ..default()
+    });
+    complete_run(&mut app, 10);
+
+    assert_eq!(app.world().resource::<LevelGhost>().0, None);
+    assert!(!dir.path().join("level_003.ron").exists());
+}
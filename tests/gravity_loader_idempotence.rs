@@ -13,10 +13,7 @@ fn test_loader_does_not_overwrite_current_after_initial_load() {
         number: 42,
         gravity: Some((2.0, 0.0, 0.0)),
         matrix: vec![vec![]],
-        #[cfg(feature = "texture_manifest")]
-        presentation: None,
-        description: None,
-        author: None,
+        ..Default::default()
     };
 
     app.insert_resource(CurrentLevel(level_def));
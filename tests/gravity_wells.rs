@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::Velocity;
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::gravity_wells::{GravityWellDef, GravityWellSwirl, GravityWellsPlugin};
+use brkrs::systems::respawn::{LifeLossCause, LifeLostEvent};
+use brkrs::{Ball, GridResolution};
+
+fn level_with_wells(wells: Vec<GravityWellDef>) -> CurrentLevel {
+    let def: LevelDefinition = ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+    CurrentLevel(LevelDefinition {
+        gravity_wells: wells,
+        ..def
+    })
+}
+
+/// Losses reported through [`LifeLostEvent`].
+#[derive(Resource, Default)]
+struct Losses(Vec<LifeLossCause>);
+
+fn collect_losses(mut events: MessageReader<LifeLostEvent>, mut losses: ResMut<Losses>) {
+    losses.0.extend(events.read().map(|event| event.cause));
+}
+
+/// App with a well at the centre of cell (10, 10).
+fn well_test_app(consumes: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(GravityWellsPlugin);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        16,
+    )));
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.init_resource::<Losses>();
+    app.add_systems(PostUpdate, collect_losses);
+    app.insert_resource(level_with_wells(vec![GravityWellDef {
+        row: 10,
+        col: 10,
+        strength: 20.0,
+        consumes,
+    }]));
+    app.update();
+    app
+}
+
+fn spawn_ball(app: &mut App, offset: Vec3) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ball,
+            Transform::from_translation(GridResolution::default().cell_center(10, 10) + offset),
+            Velocity::zero(),
+        ))
+        .id()
+}
+
+#[test]
+fn wells_pull_balls_towards_them() {
+    let mut app = well_test_app(false);
+    let ball = spawn_ball(&mut app, Vec3::new(-3.0, 0.0, 0.0));
+    app.update();
+    app.update();
+    let velocity = app.world().get::<Velocity>(ball).unwrap().linvel;
+    assert!(velocity.x > 0.0, "pulled towards the well: {velocity:?}");
+    assert!(velocity.z.abs() < 1e-5);
+
+    let world = app.world_mut();
+    let swirls = world
+        .query_filtered::<(), With<GravityWellSwirl>>()
+        .iter(world)
+        .count();
+    assert_eq!(swirls, 1);
+}
+
+#[test]
+fn consuming_well_swallows_balls_at_its_centre() {
+    let mut app = well_test_app(true);
+    let far = spawn_ball(&mut app, Vec3::new(-3.0, 0.0, 0.0));
+    let centre = spawn_ball(&mut app, Vec3::new(0.1, 0.0, 0.0));
+    app.update();
+    assert!(app.world().get_entity(centre).is_err());
+    assert!(app.world().get_entity(far).is_ok());
+    assert_eq!(
+        app.world().resource::<Losses>().0,
+        vec![LifeLossCause::GravityWell]
+    );
+}
+
+#[test]
+fn plain_well_never_swallows_balls() {
+    let mut app = well_test_app(false);
+    let centre = spawn_ball(&mut app, Vec3::new(0.1, 0.0, 0.0));
+    app.update();
+    assert!(app.world().get_entity(centre).is_ok());
+    assert!(app.world().resource::<Losses>().0.is_empty());
+}
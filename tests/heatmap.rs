@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use brkrs::systems::cheat_mode::CheatModeState;
+use brkrs::systems::heatmap::{BallHeatmap, HeatmapOverlay, HeatmapPlugin};
+use brkrs::{Ball, BallFrozen};
+
+use std::time::Duration;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(HeatmapPlugin)
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .init_resource::<Assets<Image>>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    // First update has a zero delta
+    app.update();
+    app
+}
+
+fn press_h(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyH);
+    app.update();
+    let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    input.release(KeyCode::KeyH);
+    input.clear();
+}
+
+fn overlay_visibility(app: &mut App) -> Option<Visibility> {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Visibility, With<HeatmapOverlay>>()
+        .iter(world)
+        .next()
+        .copied()
+}
+
+#[test]
+fn moving_balls_heat_up_the_cells_they_cross() {
+    let mut app = test_app();
+    app.world_mut()
+        .spawn((Ball, Transform::from_xyz(0.0, 2.0, 0.0)));
+    app.world_mut()
+        .spawn((Ball, BallFrozen, Transform::from_xyz(-10.0, 2.0, -15.0)));
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let heatmap = app.world().resource::<BallHeatmap>();
+    let (x, z) = heatmap.cell_at(Vec3::ZERO).unwrap();
+    assert!((heatmap.value(x, z) - 0.5).abs() < 1e-4);
+    let (fx, fz) = heatmap.cell_at(Vec3::new(-10.0, 0.0, -15.0)).unwrap();
+    assert_eq!(heatmap.value(fx, fz), 0.0, "frozen balls are not recorded");
+}
+
+#[test]
+fn h_toggles_the_overlay_only_in_cheat_mode() {
+    let mut app = test_app();
+    press_h(&mut app);
+    assert_eq!(overlay_visibility(&mut app), None);
+
+    app.insert_resource(CheatModeState {
+        active: true,
+        activated_at: None,
+    });
+    press_h(&mut app);
+    assert_eq!(overlay_visibility(&mut app), Some(Visibility::Visible));
+    press_h(&mut app);
+    assert_eq!(overlay_visibility(&mut app), Some(Visibility::Hidden));
+}
@@ -0,0 +1,68 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::input_map::InputMap;
+use brkrs::ui::fonts::UiFonts;
+use brkrs::ui::help_overlay::{
+    ensure_help_overlay, help_text, toggle_help_overlay, HelpOverlayRoot, HelpOverlayState,
+};
+
+fn help_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<HelpOverlayState>()
+        .init_resource::<InputMap>()
+        .insert_resource(UiFonts {
+            orbitron: Handle::default(),
+        })
+        .add_systems(Update, (toggle_help_overlay, ensure_help_overlay).chain());
+    app.update();
+    app
+}
+
+fn press(app: &mut App, keys: &[KeyCode]) {
+    {
+        let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        input.reset_all();
+        for key in keys {
+            input.press(*key);
+        }
+    }
+    app.update();
+}
+
+fn overlays(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(), With<HelpOverlayRoot>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn f1_and_question_mark_toggle_the_help() {
+    let mut app = help_test_app();
+    press(&mut app, &[KeyCode::F1]);
+    assert_eq!(overlays(&mut app), 1);
+
+    // A plain slash is not a question mark
+    press(&mut app, &[KeyCode::Slash]);
+    assert_eq!(overlays(&mut app), 1);
+
+    press(&mut app, &[KeyCode::ShiftRight, KeyCode::Slash]);
+    assert_eq!(overlays(&mut app), 0);
+}
+
+#[test]
+fn help_lists_the_input_map() {
+    let text = help_text(&InputMap::default());
+    assert!(
+        text.contains("F1 / Shift + /  Show or hide this help"),
+        "{text}"
+    );
+    assert!(text.contains("S  Ball-save shield"), "{text}");
+    let cheats = text.split("Cheat mode").nth(1).expect("cheat section");
+    assert!(cheats.contains("P / Shift + L  Previous level"), "{text}");
+}
@@ -0,0 +1,77 @@
+use bevy::app::App;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::systems::hints::{
+    count_lost_balls, show_due_hints, Hint, HintSettings, HintStats, LOSSES_BEFORE_HINT,
+};
+use brkrs::systems::respawn::{LifeLossCause, LifeLostEvent, SpawnTransform};
+use brkrs::ui::toasts::ShowToast;
+
+#[derive(Resource, Default)]
+struct Shown(Vec<Option<Hint>>);
+
+fn hints_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_message::<LifeLostEvent>()
+        .init_resource::<HintSettings>()
+        .init_resource::<HintStats>()
+        .init_resource::<Shown>()
+        .add_observer(|trigger: On<ShowToast>, mut shown: ResMut<Shown>| {
+            shown.0.push(trigger.event().hint);
+        })
+        .add_systems(Update, (count_lost_balls, show_due_hints).chain());
+    app.update();
+    app
+}
+
+fn lose_ball(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<Messages<LifeLostEvent>>()
+        .write(LifeLostEvent {
+            ball: Entity::PLACEHOLDER,
+            cause: LifeLossCause::LowerGoal,
+            ball_spawn: SpawnTransform::new(Vec3::ZERO, Quat::IDENTITY),
+        });
+    app.update();
+}
+
+#[test]
+fn repeated_losses_bring_up_a_tip_once() {
+    let mut app = hints_test_app();
+    for _ in 1..LOSSES_BEFORE_HINT {
+        lose_ball(&mut app);
+    }
+    assert!(app.world().resource::<Shown>().0.is_empty());
+
+    lose_ball(&mut app);
+    lose_ball(&mut app);
+    assert_eq!(
+        app.world().resource::<Shown>().0,
+        vec![Some(Hint::RepeatedLosses)]
+    );
+}
+
+#[test]
+fn dismissed_hints_stay_hidden() {
+    let mut app = hints_test_app();
+    app.world_mut()
+        .resource_mut::<HintSettings>()
+        .dismiss(Hint::RepeatedLosses);
+    for _ in 0..LOSSES_BEFORE_HINT {
+        lose_ball(&mut app);
+    }
+    assert!(app.world().resource::<Shown>().0.is_empty());
+}
+
+#[test]
+fn hint_settings_round_trip_through_ron() {
+    let mut settings = HintSettings::default();
+    settings.dismiss(Hint::ShallowBounces);
+    settings.dismiss(Hint::ShallowBounces);
+    let text = ron::ser::to_string(&settings).unwrap();
+    let parsed: HintSettings = ron::de::from_str(&text).unwrap();
+    assert_eq!(parsed.dismissed, vec![Hint::ShallowBounces]);
+}
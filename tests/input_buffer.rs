@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::{app::App, prelude::*, time::TimeUpdateStrategy};
+use brkrs::systems::input_buffer::{InputBufferPlugin, PaddleCatchUp};
+use brkrs::systems::InputLocked;
+use brkrs::{mouse_to_plane, Paddle};
+
+fn buffer_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputBufferPlugin)
+        .init_resource::<AccumulatedMouseMotion>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+    app.update();
+    app
+}
+
+fn move_mouse(app: &mut App, delta: Vec2, frames: usize) {
+    for _ in 0..frames {
+        app.world_mut()
+            .resource_mut::<AccumulatedMouseMotion>()
+            .delta = delta;
+        app.update();
+    }
+}
+
+#[test]
+fn movement_during_the_lock_is_caught_up_on_unlock() {
+    let mut app = buffer_app();
+    let paddle = app.world_mut().spawn((Paddle, InputLocked)).id();
+
+    move_mouse(&mut app, Vec2::new(20.0, 0.0), 3);
+    app.world_mut().entity_mut(paddle).remove::<InputLocked>();
+    app.world_mut().flush();
+
+    let catch_up = *app.world().get::<PaddleCatchUp>(paddle).unwrap();
+    let steered = mouse_to_plane(Vec2::X);
+    assert!(catch_up.remaining.normalize().dot(steered) > 0.999);
+
+    // Played out (by `move_paddle`) and removed
+    let mut catch_up = catch_up;
+    while !catch_up.is_finished() {
+        catch_up.step(0.05);
+    }
+    app.world_mut().entity_mut(paddle).insert(catch_up);
+    move_mouse(&mut app, Vec2::ZERO, 1);
+    assert!(app.world().get::<PaddleCatchUp>(paddle).is_none());
+}
+
+#[test]
+fn stale_movement_is_dropped() {
+    let mut app = buffer_app();
+    let paddle = app.world_mut().spawn((Paddle, InputLocked)).id();
+
+    move_mouse(&mut app, Vec2::new(20.0, 0.0), 2);
+    // Holding still for longer than the buffer window
+    move_mouse(&mut app, Vec2::ZERO, 6);
+    app.world_mut().entity_mut(paddle).remove::<InputLocked>();
+    app.world_mut().flush();
+    assert!(app.world().get::<PaddleCatchUp>(paddle).is_none());
+}
+
+#[test]
+fn unlocked_movement_is_not_buffered() {
+    let mut app = buffer_app();
+    let paddle = app.world_mut().spawn(Paddle).id();
+
+    move_mouse(&mut app, Vec2::new(0.0, 15.0), 3);
+    app.world_mut().entity_mut(paddle).insert(InputLocked);
+    app.world_mut().entity_mut(paddle).remove::<InputLocked>();
+    app.world_mut().flush();
+    assert!(app.world().get::<PaddleCatchUp>(paddle).is_none());
+}
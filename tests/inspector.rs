@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+use bevy_rapier3d::prelude::{Friction, Restitution, Velocity};
+
+use brkrs::physics_config::{BallPhysicsConfig, PaddlePhysicsConfig};
+use brkrs::systems::cheat_mode::CheatModeState;
+use brkrs::systems::inspector::{InspectorField, INSPECTOR_FIELDS};
+use brkrs::systems::respawn::LivesState;
+use brkrs::systems::{InspectorPlugin, InspectorState};
+use brkrs::{Ball, GravityConfiguration};
+
+fn inspector_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ButtonInput::<KeyCode>::default());
+    app.insert_resource(CheatModeState {
+        active: true,
+        activated_at: None,
+    });
+    app.insert_resource(GravityConfiguration::default());
+    app.insert_resource(BallPhysicsConfig::default());
+    app.insert_resource(PaddlePhysicsConfig::default());
+    app.insert_resource(LivesState::default());
+    app.add_plugins(InspectorPlugin);
+    let ball = app
+        .world_mut()
+        .spawn((
+            Ball,
+            Velocity::linear(Vec3::new(3.0, 0.0, -4.0)),
+            Restitution::coefficient(0.9),
+            Friction::coefficient(2.0),
+        ))
+        .id();
+    app.update();
+    (app, ball)
+}
+
+fn press(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+    app.update();
+    let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keyboard.release(key);
+    keyboard.clear();
+}
+
+fn select_field(app: &mut App, field: InspectorField) {
+    let target = INSPECTOR_FIELDS.iter().position(|f| *f == field).unwrap();
+    while app.world().resource::<InspectorState>().field != target {
+        press(app, KeyCode::ArrowDown);
+    }
+}
+
+#[test]
+fn inspector_edits_resources_and_the_selected_ball() {
+    let (mut app, ball) = inspector_app();
+
+    press(&mut app, KeyCode::KeyI);
+    let inspector = app.world().resource::<InspectorState>();
+    assert!(inspector.open);
+    assert_eq!(inspector.selected, Some(ball), "the only body is selected");
+    assert!(inspector.text.contains("Gravity X"));
+
+    press(&mut app, KeyCode::ArrowRight);
+    assert_eq!(
+        app.world().resource::<GravityConfiguration>().current.x,
+        1.0
+    );
+
+    // Tuning edits reach the config and the live ball
+    select_field(&mut app, InspectorField::BallRestitution);
+    press(&mut app, KeyCode::ArrowRight);
+    let restitution = app.world().resource::<BallPhysicsConfig>().restitution;
+    assert!((restitution - 0.95).abs() < 1e-4);
+    assert_eq!(
+        app.world().get::<Restitution>(ball).unwrap().coefficient,
+        restitution
+    );
+
+    // Friction is at its upper bound, so raising it is rejected
+    select_field(&mut app, InspectorField::BallFriction);
+    press(&mut app, KeyCode::ArrowRight);
+    assert_eq!(app.world().resource::<BallPhysicsConfig>().friction, 2.0);
+    assert_eq!(app.world().get::<Friction>(ball).unwrap().coefficient, 2.0);
+
+    select_field(&mut app, InspectorField::VelocityZ);
+    press(&mut app, KeyCode::ArrowLeft);
+    assert_eq!(
+        app.world().get::<Velocity>(ball).unwrap().linvel,
+        Vec3::new(3.0, 0.0, -5.0)
+    );
+
+    // Leaving cheat mode closes the inspector
+    app.world_mut().resource_mut::<CheatModeState>().active = false;
+    app.update();
+    assert!(!app.world().resource::<InspectorState>().open);
+}
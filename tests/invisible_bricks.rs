@@ -0,0 +1,113 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::CollisionEvent;
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+use brkrs::level_format::{INVISIBLE_BRICK, SIMPLE_BRICK};
+use brkrs::systems::invisible_bricks::{Concealed, InvisibleBricksPlugin, Revealing};
+use brkrs::{
+    despawn_marked_entities, mark_brick_on_ball_collision, Ball, Brick, BrickTypeId,
+    CountsTowardsCompletion,
+};
+
+use std::time::Duration;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InvisibleBricksPlugin)
+        .add_message::<brkrs::signals::BrickDestroyed>()
+        .add_systems(
+            Update,
+            (mark_brick_on_ball_collision, despawn_marked_entities).chain(),
+        );
+    app
+}
+
+fn spawn_brick(app: &mut App, type_id: u8, x: f32, z: f32) -> Entity {
+    let mut brick = app.world_mut().spawn((
+        Brick,
+        BrickTypeId(type_id),
+        CountsTowardsCompletion,
+        Transform::from_xyz(x, 2.0, z),
+    ));
+    if type_id == INVISIBLE_BRICK {
+        brick.insert((Concealed, Visibility::Hidden));
+    }
+    brick.id()
+}
+
+fn spawn_ball(app: &mut App, x: f32, z: f32) -> Entity {
+    app.world_mut()
+        .spawn((Ball, Transform::from_xyz(x, 2.0, z)))
+        .id()
+}
+
+#[test]
+fn passing_ball_reveals_and_fades_in() {
+    let mut app = test_app();
+    spawn_brick(&mut app, SIMPLE_BRICK, 10.0, 10.0);
+    let hidden = spawn_brick(&mut app, INVISIBLE_BRICK, 0.0, 0.0);
+    let ball = spawn_ball(&mut app, 0.0, -8.0);
+
+    app.update();
+    assert!(app.world().entity(hidden).contains::<Concealed>());
+
+    app.world_mut()
+        .entity_mut(ball)
+        .get_mut::<Transform>()
+        .unwrap()
+        .translation
+        .z = -1.5;
+    app.update();
+    let brick = app.world().entity(hidden);
+    assert!(!brick.contains::<Concealed>());
+    assert!(brick.contains::<Revealing>());
+    assert_eq!(brick.get::<Visibility>(), Some(&Visibility::Inherited));
+
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        0.2,
+    )));
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(!app.world().entity(hidden).contains::<Revealing>());
+}
+
+#[test]
+fn first_hit_reveals_without_destroying() {
+    let mut app = test_app();
+    spawn_brick(&mut app, SIMPLE_BRICK, 10.0, 10.0);
+    let hidden = spawn_brick(&mut app, INVISIBLE_BRICK, 0.0, 0.0);
+    // Far away, so only the (simulated) hit can reveal it
+    let ball = spawn_ball(&mut app, -12.0, -15.0);
+
+    let hit = CollisionEvent::Started(ball, hidden, CollisionEventFlags::empty());
+    app.world_mut().write_message(hit);
+    app.update();
+    assert!(
+        app.world().get_entity(hidden).is_ok(),
+        "first hit reveals only"
+    );
+    assert!(!app.world().entity(hidden).contains::<Concealed>());
+
+    app.world_mut().write_message(hit);
+    app.update();
+    assert!(
+        app.world().get_entity(hidden).is_err(),
+        "second hit destroys"
+    );
+}
+
+#[test]
+fn last_hidden_bricks_are_revealed() {
+    let mut app = test_app();
+    let hidden = spawn_brick(&mut app, INVISIBLE_BRICK, 0.0, 0.0);
+    spawn_ball(&mut app, -12.0, -15.0);
+
+    app.update();
+
+    assert!(!app.world().entity(hidden).contains::<Concealed>());
+}
@@ -0,0 +1,168 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use bevy::window::WindowMode;
+use bevy::MinimalPlugins;
+use clap::Parser;
+
+use brkrs::launch::{
+    apply_launch_options, level_file_path, Cli, DEFAULT_LEVELS_DIR, SURVIVAL_ARENA_PATH,
+};
+use brkrs::systems::rng::GameRng;
+use brkrs::{BrkrsConfig, LaunchOptions};
+
+fn parse(args: &[&str]) -> LaunchOptions {
+    Cli::try_parse_from(std::iter::once("brkrs").chain(args.iter().copied()))
+        .unwrap()
+        .into()
+}
+
+#[test]
+fn level_takes_a_number_or_a_file() {
+    let numbered = parse(&["--level", "3"]);
+    assert_eq!(numbered.level, Some(3));
+    assert_eq!(numbered.start_level_path(), "assets/levels/level_003.ron");
+    assert!(numbered.level_chosen());
+
+    let file = parse(&["--level", "my_levels/castle.ron"]);
+    assert_eq!(file.level, None);
+    assert_eq!(file.level_path, Some(PathBuf::from("my_levels/castle.ron")));
+    assert_eq!(file.start_level_path(), "my_levels/castle.ron");
+}
+
+#[test]
+fn level_option_beats_the_level_path_variable() {
+    // `--level-path` is the hidden flag behind `BK_LEVEL_PATH`; passing it keeps the test off the
+    // process environment
+    let numbered = parse(&["--level-path", "tests/castle.ron", "--level", "3"]);
+    assert_eq!(numbered.level_path, None);
+    assert_eq!(numbered.start_level_path(), "assets/levels/level_003.ron");
+
+    let file = parse(&[
+        "--level-path",
+        "tests/castle.ron",
+        "--level",
+        "my_levels/pit.ron",
+    ]);
+    assert_eq!(file.start_level_path(), "my_levels/pit.ron");
+
+    let fallback = parse(&["--level-path", "tests/castle.ron"]);
+    assert_eq!(fallback.start_level_path(), "tests/castle.ron");
+}
+
+#[test]
+fn survival_starts_in_its_arena() {
+    let survival = parse(&["--survival"]);
+    assert!(survival.survival);
+    assert_eq!(survival.start_level_path(), SURVIVAL_ARENA_PATH);
+    assert!(
+        survival.level_chosen(),
+        "saved campaign progress is not resumed"
+    );
+
+    let custom = parse(&["--survival", "--level", "my_levels/pit.ron"]);
+    assert_eq!(custom.start_level_path(), "my_levels/pit.ron");
+    assert!(LaunchOptions::from_query("?survival").survival);
+}
+
+#[test]
+fn versus_is_a_launch_option() {
+    assert!(parse(&["--versus"]).versus);
+    assert!(!parse(&[]).versus);
+    assert!(LaunchOptions::from_query("?versus").versus);
+    assert!(!LaunchOptions::from_query("?versus=0").versus);
+}
+
+#[test]
+fn options_default_to_the_bundled_levels() {
+    let options = parse(&[]);
+    assert_eq!(options.levels_dir, Path::new(DEFAULT_LEVELS_DIR));
+    assert!(!options.windowed && !options.mute && !options.headless);
+    assert_eq!(options.replay, None);
+    assert_eq!((options.tas, options.record_tas), (None, None));
+
+    let custom = parse(&[
+        "--levels-dir",
+        "mods/levels",
+        "--level",
+        "2",
+        "--seed",
+        "42",
+    ]);
+    assert_eq!(custom.start_level_path(), "mods/levels/level_002.ron");
+    assert_eq!(custom.seed, Some(42));
+    assert_eq!(
+        level_file_path(Path::new("mods/levels"), 12),
+        "mods/levels/level_012.ron"
+    );
+
+    let tas = parse(&["--tas", "bug.tas", "--record-tas", "branch.tas"]);
+    assert_eq!(tas.tas, Some(PathBuf::from("bug.tas")));
+    assert_eq!(tas.record_tas, Some(PathBuf::from("branch.tas")));
+    assert_eq!(tas.playback_path(), Some(Path::new("bug.tas")));
+
+    let replay = parse(&["--replay", "session.tas"]);
+    assert_eq!(replay.playback_path(), Some(Path::new("session.tas")));
+    assert!(Cli::try_parse_from(["brkrs", "--replay", "a.tas", "--tas", "b.tas"]).is_err());
+}
+
+#[test]
+fn theme_is_chosen_on_the_command_line_or_in_the_query() {
+    assert_eq!(
+        parse(&["--theme", "winter"]).theme.as_deref(),
+        Some("winter")
+    );
+    assert_eq!(
+        LaunchOptions::from_query("?level=2&theme=off")
+            .theme
+            .as_deref(),
+        Some("off")
+    );
+    assert_eq!(LaunchOptions::from_query("?theme").theme, None);
+}
+
+#[test]
+fn headless_conflicts_with_windowed() {
+    assert!(Cli::try_parse_from(["brkrs", "--headless", "--windowed"]).is_err());
+    assert!(Cli::try_parse_from(["brkrs", "--level"]).is_err());
+}
+
+#[test]
+fn launch_options_adjust_the_config() {
+    let windowed = BrkrsConfig::default().with_launch_options(parse(&["--windowed"]));
+    assert_eq!(windowed.window_mode, WindowMode::Windowed);
+    assert!(windowed.manage_window);
+
+    let headless = BrkrsConfig::default().with_launch_options(parse(&["--headless", "--mute"]));
+    assert!(!headless.manage_window);
+    assert!(headless.launch.headless);
+    assert!(headless.launch.mute);
+}
+
+#[test]
+fn seed_option_reseeds_the_game_rng() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(GameRng::from_seed(1));
+    app.insert_resource(LaunchOptions {
+        seed: Some(42),
+        ..default()
+    });
+    app.add_systems(Startup, apply_launch_options);
+    app.update();
+    assert_eq!(app.world().resource::<GameRng>().seed(), 42);
+}
+
+#[test]
+fn query_string_deep_links_into_a_level() {
+    let options = LaunchOptions::from_query("?level=5&seed=42&mute");
+    assert_eq!(options.level, Some(5));
+    assert_eq!(options.seed, Some(42));
+    assert!(options.mute);
+    assert_eq!(options.start_level_path(), "assets/levels/level_005.ron");
+
+    // Invalid and unknown parameters are ignored
+    let options = LaunchOptions::from_query("level=five&utm_source=x&mute=0");
+    assert_eq!(options, LaunchOptions::default());
+    assert_eq!(LaunchOptions::from_query(""), LaunchOptions::default());
+}
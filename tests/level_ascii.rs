@@ -0,0 +1,74 @@
+use bevy::{app::App, prelude::*};
+use brkrs::level_format::ascii::matrix_from_ascii;
+use brkrs::level_format::{INDESTRUCTIBLE_BRICK, SIMPLE_BRICK};
+use brkrs::physics_config::BrickPhysicsConfig;
+use brkrs::ui::level_ascii::{
+    apply_ascii_import, handle_ascii_buttons, AsciiButton, AsciiDialog, ImportAsciiLayout,
+};
+use brkrs::{Brick, BrickGridPos, BrickTypeId, GridResolution};
+
+fn ascii_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(Assets::<Mesh>::default())
+        .insert_resource(Assets::<StandardMaterial>::default())
+        .insert_resource(BrickPhysicsConfig::default())
+        .init_resource::<AsciiDialog>()
+        .add_message::<ImportAsciiLayout>()
+        .add_systems(Update, (handle_ascii_buttons, apply_ascii_import).chain());
+    app
+}
+
+#[test]
+fn copy_as_ascii_writes_the_bricks_on_the_field() {
+    let mut app = ascii_app();
+    app.world_mut().spawn((
+        Brick,
+        BrickTypeId(SIMPLE_BRICK),
+        Transform::from_translation(GridResolution::default().cell_center(0, 0)),
+    ));
+    app.world_mut().spawn((
+        Brick,
+        BrickTypeId(INDESTRUCTIBLE_BRICK),
+        Transform::from_translation(GridResolution::default().cell_center(1, 2)),
+    ));
+    app.world_mut()
+        .spawn((AsciiButton::Copy, Interaction::Pressed));
+    app.update();
+
+    let dialog = app.world().resource::<AsciiDialog>();
+    assert!(dialog.open);
+    let lines: Vec<&str> = dialog.text.lines().collect();
+    assert_eq!(lines.len(), 20);
+    assert!(lines[0].starts_with("#..."));
+    assert!(lines[1].starts_with("..X."));
+}
+
+#[test]
+fn imported_layouts_replace_the_bricks() {
+    let mut app = ascii_app();
+    app.world_mut().spawn((
+        Brick,
+        BrickTypeId(SIMPLE_BRICK),
+        Transform::from_translation(GridResolution::default().cell_center(5, 5)),
+    ));
+    let matrix = matrix_from_ascii("a = 57\n#.a\n").unwrap();
+    app.world_mut().write_message(ImportAsciiLayout { matrix });
+    app.update();
+
+    let world = app.world_mut();
+    let mut bricks: Vec<(usize, usize, u8)> = world
+        .query_filtered::<(&BrickGridPos, &BrickTypeId), With<Brick>>()
+        .iter(world)
+        .map(|(pos, brick_type)| (pos.row, pos.col, brick_type.0))
+        .collect();
+    bricks.sort();
+    assert_eq!(bricks, [(0, 0, SIMPLE_BRICK), (0, 2, 57)]);
+    assert_eq!(
+        world
+            .query_filtered::<(), With<Brick>>()
+            .iter(world)
+            .count(),
+        2
+    );
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use brkrs::gameplay_config::GameplayConfig;
+use brkrs::level_loader::{CurrentLevel, LevelAdvanceState};
+use brkrs::pause::PauseState;
+use brkrs::systems::level_clock::{LevelClock, LevelClockPlugin};
+use brkrs::systems::sim_speed::SimSpeed;
+use brkrs::systems::LevelCompleted;
+use brkrs::ui::palette::{handle_drop_chance_buttons, DropChanceButton};
+
+use std::time::Duration;
+
+use common::level;
+
+const FRAME: Duration = Duration::from_millis(100);
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(LevelClockPlugin)
+        .init_resource::<PauseState>()
+        .init_resource::<LevelAdvanceState>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+        .insert_resource(level(1));
+    // First frame has no delta; start counting from here
+    app.update();
+    app
+}
+
+fn run_frames(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+fn elapsed(app: &App) -> Duration {
+    app.world().resource::<LevelClock>().elapsed
+}
+
+#[test]
+fn clock_counts_gameplay_time_only() {
+    let mut app = test_app();
+    run_frames(&mut app, 10);
+    assert_eq!(elapsed(&app), FRAME * 10);
+
+    app.insert_resource(PauseState::Paused {
+        #[cfg(not(target_arch = "wasm32"))]
+        window_mode_before_pause: bevy::window::WindowMode::Windowed,
+    });
+    run_frames(&mut app, 5);
+    app.insert_resource(PauseState::Active);
+    app.world_mut().resource_mut::<LevelAdvanceState>().active = true;
+    run_frames(&mut app, 5);
+    assert_eq!(
+        elapsed(&app),
+        FRAME * 10,
+        "paused and transition frames don't count"
+    );
+}
+
+#[test]
+fn completion_stops_and_records_then_next_level_restarts() {
+    let mut app = test_app();
+    run_frames(&mut app, 20);
+    app.world_mut().trigger(LevelCompleted { level_index: 1 });
+    run_frames(&mut app, 5);
+
+    let clock = app.world().resource::<LevelClock>();
+    assert!(clock.stopped);
+    assert_eq!(clock.last_completed, Some((1, FRAME * 20)));
+    assert_eq!(clock.best_times.get(&1), Some(&(FRAME * 20)));
+
+    app.insert_resource(level(2));
+    run_frames(&mut app, 3);
+    let clock = app.world().resource::<LevelClock>();
+    assert_eq!(clock.level_number, Some(2));
+    assert!(!clock.stopped);
+    // The frame that starts the new level counts too
+    assert_eq!(clock.elapsed, FRAME * 3);
+}
+
+#[test]
+fn other_game_speeds_set_no_best_times() {
+    let mut app = test_app();
+    app.insert_resource(SimSpeed {
+        game_speed: 0.8,
+        ..default()
+    });
+    run_frames(&mut app, 20);
+    app.world_mut().trigger(LevelCompleted { level_index: 1 });
+    app.update();
+
+    let clock = app.world().resource::<LevelClock>();
+    assert_eq!(clock.last_completed, Some((1, FRAME * 20)));
+    assert!(clock.best_times.is_empty());
+}
+
+#[test]
+fn drop_chance_edits_keep_the_level_clock_running() {
+    let mut app = test_app();
+    app.init_resource::<GameplayConfig>()
+        .add_systems(Update, handle_drop_chance_buttons);
+    run_frames(&mut app, 10);
+
+    // A newly spawned pressed button counts as a press in its first frame
+    app.world_mut()
+        .spawn((Interaction::Pressed, DropChanceButton { delta: 0.05 }));
+    run_frames(&mut app, 2);
+
+    let drops = app.world().resource::<CurrentLevel>().0.drops.as_ref();
+    let expected = GameplayConfig::default().drops.chance + 0.05;
+    assert!(drops.is_some_and(|table| (table.chance - expected).abs() < 1e-6));
+    assert_eq!(
+        elapsed(&app),
+        FRAME * 12,
+        "the edit must not restart the level"
+    );
+}
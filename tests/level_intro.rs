@@ -0,0 +1,100 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::level_loader::CurrentLevel;
+use brkrs::ui::fonts::UiFonts;
+use brkrs::ui::level_intro::{
+    play_level_intro, start_level_intro, LevelIntro, LevelIntroOverlay, LevelIntroText,
+};
+
+fn level(number: u32, intro: &[&str]) -> CurrentLevel {
+    let mut level = common::level(number);
+    level.0.intro = intro.iter().map(|line| line.to_string()).collect();
+    level
+}
+
+fn test_app(current: CurrentLevel) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .init_resource::<ButtonInput<KeyCode>>()
+        .insert_resource(UiFonts {
+            orbitron: Handle::default(),
+        })
+        .insert_resource(current)
+        .add_systems(Update, (start_level_intro, play_level_intro).chain());
+    app
+}
+
+fn press(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+    app.update();
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(key);
+    keys.clear();
+}
+
+fn intro_text(app: &mut App) -> String {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Text, With<LevelIntroText>>()
+        .single(world)
+        .map(|text| text.0.clone())
+        .unwrap_or_default()
+}
+
+fn overlay_count(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<Entity, With<LevelIntroOverlay>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn intro_advances_on_input_and_ends() {
+    let mut app = test_app(level(1, &["The bricks are back.", "Break them all."]));
+    app.update();
+    assert!(app.world().contains_resource::<LevelIntro>());
+    assert_eq!(overlay_count(&mut app), 1);
+
+    // First press completes the line, second moves to the next
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(intro_text(&mut app), "The bricks are back.");
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(intro_text(&mut app), "Break them all.");
+
+    press(&mut app, KeyCode::Enter);
+    // Torn down on the frame after the last line
+    app.update();
+    assert!(!app.world().contains_resource::<LevelIntro>());
+    assert_eq!(overlay_count(&mut app), 0);
+}
+
+#[test]
+fn escape_skips_and_restart_does_not_replay() {
+    let mut app = test_app(level(2, &["One", "Two", "Three"]));
+    app.update();
+    press(&mut app, KeyCode::Escape);
+    // Still present on the skip frame so Escape can't also pause the game
+    assert!(app.world().contains_resource::<LevelIntro>());
+    app.update();
+    assert!(!app.world().contains_resource::<LevelIntro>());
+
+    // Restarting the same level re-inserts CurrentLevel without replaying the intro
+    app.insert_resource(level(2, &["One", "Two", "Three"]));
+    app.update();
+    assert!(!app.world().contains_resource::<LevelIntro>());
+}
+
+#[test]
+fn levels_without_intro_start_immediately() {
+    let mut app = test_app(level(3, &[]));
+    app.update();
+    assert!(!app.world().contains_resource::<LevelIntro>());
+    assert_eq!(overlay_count(&mut app), 0);
+}
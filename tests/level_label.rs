@@ -114,12 +114,8 @@ fn spawn_shows_current_level_if_present() {
     app.insert_resource(brkrs::level_loader::CurrentLevel(
         brkrs::level_loader::LevelDefinition {
             number: 5,
-            gravity: None,
             matrix: vec![vec![]],
-            #[cfg(feature = "texture_manifest")]
-            presentation: None,
-            description: None,
-            author: None,
+            ..Default::default()
         },
     ));
 
@@ -168,12 +164,8 @@ fn updates_when_current_level_changes() {
     app.insert_resource(brkrs::level_loader::CurrentLevel(
         brkrs::level_loader::LevelDefinition {
             number: 7,
-            gravity: None,
             matrix: vec![vec![]],
-            #[cfg(feature = "texture_manifest")]
-            presentation: None,
-            description: None,
-            author: None,
+            ..Default::default()
         },
     ));
 
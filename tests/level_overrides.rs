@@ -62,6 +62,7 @@ fn manifest_with_baseline_profiles() -> TextureManifest {
         type_variants: vec![],
         level_overrides: Default::default(),
         level_switch: None,
+        models: Default::default(),
     }
 }
 
@@ -0,0 +1,101 @@
+use bevy::log::tracing;
+use bevy::log::tracing_subscriber::layer::SubscriberExt;
+use bevy::log::tracing_subscriber::Registry;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::systems::cheat_mode::CheatModeState;
+use brkrs::systems::logging::{
+    LogBuffer, LogCaptureLayer, LogConsoleState, LOG_CONSOLE_KEY, LOG_CONSOLE_ROWS,
+};
+use brkrs::systems::{LoggingConfig, LoggingPlugin};
+use brkrs::ui::log_console::log_console_text;
+
+fn capture(config: LoggingConfig, log: impl FnOnce()) -> LogBuffer {
+    let buffer = LogBuffer::default();
+    let subscriber = Registry::default().with(LogCaptureLayer {
+        buffer: buffer.clone(),
+        config,
+    });
+    tracing::subscriber::with_default(subscriber, log);
+    buffer
+}
+
+fn press(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+    app.update();
+    let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keyboard.release(key);
+    keyboard.clear();
+}
+
+#[test]
+fn console_keeps_the_lines_of_its_targets() {
+    let config = LoggingConfig {
+        console_targets: vec![
+            "brkrs::systems::respawn".to_string(),
+            "brkrs::systems::level_switch".to_string(),
+        ],
+        ..default()
+    };
+    let buffer = capture(config, || {
+        tracing::info!(target: "brkrs::systems::respawn", "Respawn scheduled");
+        tracing::warn!(target: "brkrs::systems::level_switch", level = 75, "No such level");
+        tracing::info!(target: "brkrs::systems::scoring", "Milestone reached");
+    });
+
+    let lines = buffer.snapshot();
+    assert_eq!(lines.written, 2);
+    assert_eq!(lines.lines[0].message, "Respawn scheduled");
+    assert_eq!(lines.lines[1].message, "No such level level=75");
+    assert_eq!(
+        log_console_text(&lines, 0, LOG_CONSOLE_ROWS),
+        "INFO  respawn: Respawn scheduled\nWARN  level_switch: No such level level=75"
+    );
+}
+
+#[test]
+fn console_opens_in_cheat_mode_and_scrolls_back() {
+    let buffer = capture(LoggingConfig::default(), || {
+        for i in 0..LOG_CONSOLE_ROWS * 2 {
+            tracing::info!(target: "brkrs::systems::respawn", "line {i}");
+        }
+    });
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ButtonInput::<KeyCode>::default());
+    app.insert_resource(CheatModeState::default());
+    app.insert_resource(buffer.clone());
+    app.add_plugins(LoggingPlugin);
+    app.update();
+
+    // Dev only: nothing happens outside cheat mode
+    press(&mut app, LOG_CONSOLE_KEY);
+    assert!(!app.world().resource::<LogConsoleState>().open);
+
+    app.world_mut().resource_mut::<CheatModeState>().active = true;
+    press(&mut app, LOG_CONSOLE_KEY);
+    assert!(app.world().resource::<LogConsoleState>().open);
+
+    press(&mut app, KeyCode::PageUp);
+    press(&mut app, KeyCode::PageUp);
+    press(&mut app, KeyCode::PageUp);
+    let scroll = app.world().resource::<LogConsoleState>().scroll;
+    assert_eq!(
+        scroll, LOG_CONSOLE_ROWS,
+        "scrolling stops at the oldest line"
+    );
+    let text = log_console_text(&buffer.snapshot(), scroll, LOG_CONSOLE_ROWS);
+    assert!(text.starts_with("INFO  respawn: line 0\n"), "{text}");
+    assert!(text.ends_with(&format!("-- {LOG_CONSOLE_ROWS} newer lines below (End) --")));
+
+    press(&mut app, KeyCode::End);
+    assert_eq!(app.world().resource::<LogConsoleState>().scroll, 0);
+
+    // Leaving cheat mode closes the console
+    app.world_mut().resource_mut::<CheatModeState>().active = false;
+    app.update();
+    assert!(!app.world().resource::<LogConsoleState>().open);
+}
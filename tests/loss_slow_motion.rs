@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use bevy::{app::App, prelude::*, time::TimeUpdateStrategy};
+use bevy_rapier3d::prelude::Velocity;
+use brkrs::systems::loss_slowmo::{LossSlowMotion, LossSlowMotionPlugin, SLOW_MOTION_FACTOR};
+use brkrs::systems::spawning::MainCamera;
+use brkrs::{Ball, LowerGoal};
+
+const CAMERA_POSITION: Vec3 = Vec3::new(0.0, 37.0, 0.0);
+
+fn slow_motion_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(LossSlowMotionPlugin)
+        .init_resource::<ButtonInput<KeyCode>>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+    app.world_mut()
+        .spawn((LowerGoal, Transform::from_xyz(15.5, 0.0, 0.0)));
+    app.world_mut()
+        .spawn((MainCamera, Transform::from_translation(CAMERA_POSITION)));
+    app.update();
+    app
+}
+
+fn spawn_ball(app: &mut App, x: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ball,
+            Transform::from_xyz(x, 2.0, 0.0),
+            Velocity::linear(Vec3::X * 10.0),
+        ))
+        .id()
+}
+
+fn relative_speed(app: &App) -> f32 {
+    app.world().resource::<Time<Virtual>>().relative_speed()
+}
+
+fn camera_position(app: &mut App) -> Vec3 {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Transform, With<MainCamera>>()
+        .single(world)
+        .unwrap()
+        .translation
+}
+
+#[test]
+fn last_ball_near_the_goal_slows_time_and_zooms_until_it_is_lost() {
+    let mut app = slow_motion_app();
+    let ball = spawn_ball(&mut app, 13.5);
+    app.update();
+    app.update();
+
+    assert!(app.world().resource::<LossSlowMotion>().is_active());
+    assert!((relative_speed(&app) - SLOW_MOTION_FACTOR).abs() < 1e-6);
+    let zoomed = camera_position(&mut app);
+    assert!(zoomed.y < CAMERA_POSITION.y && zoomed.x > 0.0);
+
+    // The loss resolves
+    app.world_mut().entity_mut(ball).despawn();
+    app.update();
+    assert!(!app.world().resource::<LossSlowMotion>().is_active());
+    assert_eq!(relative_speed(&app), 1.0);
+    assert_eq!(camera_position(&mut app), CAMERA_POSITION);
+}
+
+#[test]
+fn slow_motion_can_be_skipped() {
+    let mut app = slow_motion_app();
+    spawn_ball(&mut app, 14.0);
+    app.update();
+    assert!(app.world().resource::<LossSlowMotion>().is_active());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::Space);
+    app.update();
+    assert!(!app.world().resource::<LossSlowMotion>().is_active());
+    assert_eq!(relative_speed(&app), 1.0);
+    assert_eq!(camera_position(&mut app), CAMERA_POSITION);
+}
+
+#[test]
+fn other_balls_in_play_or_distant_balls_do_not_trigger_it() {
+    let mut app = slow_motion_app();
+    spawn_ball(&mut app, 14.0);
+    spawn_ball(&mut app, 0.0);
+    app.update();
+    assert!(!app.world().resource::<LossSlowMotion>().is_active());
+
+    let mut app = slow_motion_app();
+    spawn_ball(&mut app, 5.0);
+    app.update();
+    assert!(!app.world().resource::<LossSlowMotion>().is_active());
+    assert_eq!(relative_speed(&app), 1.0);
+}
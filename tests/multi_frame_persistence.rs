@@ -47,10 +47,7 @@ fn gravity_change_persists_with_loader_running() {
         number: 1,
         gravity: Some((2.0, 0.0, 0.0)),
         matrix: vec![vec![]],
-        #[cfg(feature = "texture_manifest")]
-        presentation: None,
-        description: None,
-        author: None,
+        ..Default::default()
     };
 
     app.insert_resource(CurrentLevel(level_def));
@@ -190,10 +187,7 @@ fn sequential_gravity_changes_persist() {
         number: 1,
         gravity: Some((0.0, 0.0, 0.0)),
         matrix: vec![vec![]],
-        #[cfg(feature = "texture_manifest")]
-        presentation: None,
-        description: None,
-        author: None,
+        ..Default::default()
     };
 
     app.insert_resource(CurrentLevel(level_def));
@@ -274,10 +268,7 @@ fn level_transition_resets_gravity_then_persists() {
         number: 1,
         gravity: Some((2.0, 0.0, 0.0)),
         matrix: vec![vec![]],
-        #[cfg(feature = "texture_manifest")]
-        presentation: None,
-        description: None,
-        author: None,
+        ..Default::default()
     };
 
     app.insert_resource(CurrentLevel(level_1));
@@ -324,10 +315,7 @@ fn level_transition_resets_gravity_then_persists() {
         number: 2,
         gravity: Some((5.0, 0.0, 0.0)),
         matrix: vec![vec![]],
-        #[cfg(feature = "texture_manifest")]
-        presentation: None,
-        description: None,
-        author: None,
+        ..Default::default()
     };
     app.insert_resource(CurrentLevel(level_2));
 
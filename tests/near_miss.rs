@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use brkrs::systems::near_miss::{BrickNearMiss, NearMissFlash, NearMissPlugin};
+use brkrs::Brick;
+
+use std::time::Duration;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(NearMissPlugin)
+        .init_resource::<Assets<StandardMaterial>>();
+    app
+}
+
+#[test]
+fn missed_brick_flashes_on_its_own_material_then_restores() {
+    let mut app = test_app();
+    let shared = app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial::default());
+    let brick = app
+        .world_mut()
+        .spawn((Brick, MeshMaterial3d(shared.clone())))
+        .id();
+    let neighbour = app
+        .world_mut()
+        .spawn((Brick, MeshMaterial3d(shared.clone())))
+        .id();
+
+    let ball = app.world_mut().spawn_empty().id();
+    app.world_mut().write_message(BrickNearMiss { brick, ball });
+    app.update();
+
+    let glow = app
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(brick)
+        .unwrap()
+        .0
+        .clone();
+    assert_ne!(glow, shared, "the flash must not touch the shared material");
+    assert_eq!(
+        app.world()
+            .get::<MeshMaterial3d<StandardMaterial>>(neighbour)
+            .unwrap()
+            .0,
+        shared
+    );
+    app.update();
+    let materials = app.world().resource::<Assets<StandardMaterial>>();
+    assert!(materials.get(&glow).unwrap().emissive.red > 0.0);
+    assert_eq!(materials.get(&shared).unwrap().emissive, LinearRgba::BLACK);
+
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        0.2,
+    )));
+    for _ in 0..4 {
+        app.update();
+    }
+    assert!(!app.world().entity(brick).contains::<NearMissFlash>());
+    assert_eq!(
+        app.world()
+            .get::<MeshMaterial3d<StandardMaterial>>(brick)
+            .unwrap()
+            .0,
+        shared
+    );
+    assert!(app
+        .world()
+        .resource::<Assets<StandardMaterial>>()
+        .get(&glow)
+        .is_none());
+}
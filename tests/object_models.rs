@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use brkrs::systems::textures::loader::{ModelDefinition, TextureManifest};
+use brkrs::systems::textures::{ObjectModel, ObjectModelsPlugin};
+use brkrs::{Ball, Paddle};
+
+fn models_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .init_asset::<Scene>()
+        .init_resource::<TextureManifest>()
+        .add_plugins(ObjectModelsPlugin);
+    app.update();
+    app
+}
+
+fn model(scene_path: &str) -> ModelDefinition {
+    ModelDefinition {
+        scene_path: scene_path.to_string(),
+        scale: 1.0,
+        offset: Vec3::ZERO,
+    }
+}
+
+fn spawn_object(app: &mut App, object: impl Bundle) -> Entity {
+    let mesh = app
+        .world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .add(Sphere::new(0.3).mesh());
+    app.world_mut().spawn((Mesh3d(mesh), object)).id()
+}
+
+#[test]
+fn objects_keep_their_primitives_without_models() {
+    let mut app = models_test_app();
+    let paddle = spawn_object(&mut app, Paddle);
+    let ball = spawn_object(&mut app, Ball);
+    app.update();
+
+    for entity in [paddle, ball] {
+        assert!(app.world().get::<Mesh3d>(entity).is_some());
+        assert!(app.world().get::<ObjectModel>(entity).is_none());
+    }
+}
+
+#[test]
+fn manifest_models_follow_manifest_changes() {
+    let mut app = models_test_app();
+    let paddle = spawn_object(&mut app, Paddle);
+    let ball = spawn_object(&mut app, Ball);
+    app.world_mut()
+        .resource_mut::<TextureManifest>()
+        .models
+        .paddle = Some(model("models/paddle.glb"));
+    app.update();
+
+    let attached = app.world().get::<ObjectModel>(paddle).unwrap();
+    assert_eq!(attached.definition.scene_path, "models/paddle.glb");
+    assert!(app.world().get::<ObjectModel>(ball).is_none());
+    assert!(app.world().get::<Mesh3d>(ball).is_some());
+
+    app.world_mut()
+        .resource_mut::<TextureManifest>()
+        .models
+        .paddle = None;
+    app.update();
+    assert!(app.world().get::<ObjectModel>(paddle).is_none());
+    assert!(app.world().get::<Mesh3d>(paddle).is_some());
+    assert!(app
+        .world()
+        .get::<Children>(paddle)
+        .is_none_or(|c| c.is_empty()));
+}
+
+#[test]
+fn scenes_that_fail_to_load_fall_back_to_primitives() {
+    let mut app = models_test_app();
+    app.world_mut()
+        .resource_mut::<TextureManifest>()
+        .models
+        .ball = Some(model("models/does_not_exist.glb"));
+    let ball = spawn_object(&mut app, Ball);
+
+    for _ in 0..200 {
+        app.update();
+        if app
+            .world()
+            .get::<ObjectModel>(ball)
+            .is_some_and(|model| model.root.is_none())
+        {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert!(app.world().get::<ObjectModel>(ball).unwrap().root.is_none());
+    assert!(app.world().get::<Mesh3d>(ball).is_some());
+}
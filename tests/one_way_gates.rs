@@ -0,0 +1,92 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::Velocity;
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::one_way_gates::{
+    GateDef, GateDirection, GateVisual, OneWayGates, OneWayGatesPlugin,
+};
+use brkrs::Ball;
+
+fn level_with_gates(gates: Vec<GateDef>) -> CurrentLevel {
+    CurrentLevel(LevelDefinition {
+        number: 1,
+        matrix: vec![vec![0; 20]; 20],
+        gates: Some(gates),
+        ..Default::default()
+    })
+}
+
+/// App with an `Up` gate across row 10, columns 0-19 (world x = 0.75).
+fn gate_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(OneWayGatesPlugin);
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(level_with_gates(vec![GateDef {
+        rows: (10, 10),
+        cols: (0, 19),
+        direction: GateDirection::Up,
+    }]));
+    app.update();
+    app
+}
+
+fn spawn_ball(app: &mut App, x: f32, vx: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ball,
+            Transform::from_xyz(x, 2.0, 0.0),
+            Velocity::linear(Vec3::new(vx, 0.0, 1.0)),
+        ))
+        .id()
+}
+
+fn move_ball(app: &mut App, ball: Entity, x: f32) {
+    app.world_mut()
+        .get_mut::<Transform>(ball)
+        .unwrap()
+        .translation
+        .x = x;
+    app.update();
+}
+
+#[test]
+fn gate_and_visuals_are_built_for_the_level() {
+    let mut app = gate_test_app();
+    assert_eq!(app.world().resource::<OneWayGates>().gates.len(), 1);
+    let world = app.world_mut();
+    let visuals = world
+        .query_filtered::<Entity, With<GateVisual>>()
+        .iter(world)
+        .count();
+    assert_eq!(visuals, 2, "bar and debug arrow");
+}
+
+#[test]
+fn ball_moving_with_the_gate_passes() {
+    let mut app = gate_test_app();
+    // Below the gate (larger x), heading up (-x)
+    let ball = spawn_ball(&mut app, 1.5, -10.0);
+    app.update();
+    move_ball(&mut app, ball, 0.2);
+    let transform = app.world().get::<Transform>(ball).unwrap();
+    assert_eq!(transform.translation.x, 0.2);
+    assert_eq!(app.world().get::<Velocity>(ball).unwrap().linvel.x, -10.0);
+}
+
+#[test]
+fn ball_moving_against_the_gate_bounces_back() {
+    let mut app = gate_test_app();
+    // Above the gate (smaller x), heading down (+x)
+    let ball = spawn_ball(&mut app, -0.5, 10.0);
+    app.update();
+    move_ball(&mut app, ball, 1.2);
+    let x = app.world().get::<Transform>(ball).unwrap().translation.x;
+    assert!(x < 0.75, "ball is put back above the gate, got x = {x}");
+    let velocity = app.world().get::<Velocity>(ball).unwrap().linvel;
+    assert_eq!(velocity.x, -10.0);
+    assert_eq!(velocity.z, 1.0, "speed along the gate is kept");
+}
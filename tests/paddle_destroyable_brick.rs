@@ -32,6 +32,7 @@ fn test_app() -> App {
     app.insert_resource(ScoreState {
         current_score: 0,
         last_milestone_reached: 0,
+        ..Default::default()
     });
     app.insert_resource(SpawnPoints::default());
     app.insert_resource(FrameLossState::default());
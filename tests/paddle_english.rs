@@ -0,0 +1,100 @@
+//! Paddle "english": the ball is steered the way the mouse moves the paddle.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::ExternalImpulse;
+
+use brkrs::gameplay_config::{EnglishLevel, GameplayConfig};
+use brkrs::physics_config::PaddlePhysicsConfig;
+use brkrs::{mouse_to_plane, on_paddle_ball_hit, Ball, BallHit, Paddle};
+
+/// Frame time the mouse movement is spread over.
+const DELTA_SECS: f32 = 1.0 / 60.0;
+
+fn english_app(english: EnglishLevel) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(PaddlePhysicsConfig::default())
+        .insert_resource(GameplayConfig {
+            english,
+            ..Default::default()
+        })
+        .add_observer(on_paddle_ball_hit);
+    app.world_mut().spawn(Paddle);
+    app
+}
+
+/// Impulse the ball gets when the paddle hits it while the mouse moves by `mouse_delta`.
+fn english_for(english: EnglishLevel, mouse_delta: Vec2) -> Vec3 {
+    let mut app = english_app(english);
+    let ball = app
+        .world_mut()
+        .spawn((Ball, ExternalImpulse::default()))
+        .id();
+    app.world_mut().trigger(BallHit {
+        impulse: mouse_to_plane(mouse_delta) / DELTA_SECS,
+        ball,
+    });
+    app.update();
+    app.world().get::<ExternalImpulse>(ball).unwrap().impulse
+}
+
+#[test]
+fn ball_is_steered_along_the_paddle_movement() {
+    for mouse_delta in [
+        Vec2::new(12.0, 0.0),
+        Vec2::new(-12.0, 0.0),
+        Vec2::new(0.0, 8.0),
+        Vec2::new(-5.0, -9.0),
+    ] {
+        let impulse = english_for(EnglishLevel::Normal, mouse_delta);
+        // The paddle moves along `mouse_to_plane` too (`move_paddle`)
+        let paddle_direction = mouse_to_plane(mouse_delta).normalize();
+        assert!(
+            impulse.normalize().dot(paddle_direction) > 0.999,
+            "mouse {mouse_delta:?}: impulse {impulse:?}, paddle {paddle_direction:?}"
+        );
+        assert_eq!(impulse.y, 0.0);
+    }
+    // Mouse right moves the paddle (and the ball) towards -Z
+    assert!(english_for(EnglishLevel::Normal, Vec2::X).z < 0.0);
+}
+
+#[test]
+fn english_level_scales_the_steering() {
+    let mouse_delta = Vec2::new(10.0, 4.0);
+    let low = english_for(EnglishLevel::Low, mouse_delta).length();
+    let normal = english_for(EnglishLevel::Normal, mouse_delta).length();
+    let high = english_for(EnglishLevel::High, mouse_delta).length();
+    assert!(low < normal && normal < high, "{low} {normal} {high}");
+
+    let expected = mouse_to_plane(mouse_delta).length() / DELTA_SECS
+        * PaddlePhysicsConfig::default().spin_transfer;
+    assert!((normal - expected).abs() < 1e-4);
+    assert_eq!(EnglishLevel::High.next(), EnglishLevel::Low);
+}
+
+#[test]
+fn other_balls_are_not_steered() {
+    let mut app = english_app(EnglishLevel::High);
+    let hit = app
+        .world_mut()
+        .spawn((Ball, ExternalImpulse::default()))
+        .id();
+    let other = app
+        .world_mut()
+        .spawn((Ball, ExternalImpulse::default()))
+        .id();
+    app.world_mut().trigger(BallHit {
+        impulse: mouse_to_plane(Vec2::new(6.0, 0.0)) / DELTA_SECS,
+        ball: hit,
+    });
+    app.update();
+    assert_ne!(
+        app.world().get::<ExternalImpulse>(hit).unwrap().impulse,
+        Vec3::ZERO
+    );
+    assert_eq!(
+        app.world().get::<ExternalImpulse>(other).unwrap().impulse,
+        Vec3::ZERO
+    );
+}
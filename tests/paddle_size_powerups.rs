@@ -1,66 +1,139 @@
 use bevy::app::App;
-use bevy::ecs::message::Messages;
+use bevy::ecs::message::MessageReader;
 use bevy::prelude::*;
 use bevy::MinimalPlugins;
-use bevy_rapier3d::prelude::CollisionEvent;
-use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+use bevy_rapier3d::prelude::{Collider, ColliderScale};
 
+use brkrs::gameplay_config::{GameplayConfig, PaddleSizeConfig, SizeStackPolicy};
 use brkrs::systems::paddle_size::{
-    PaddleSizeEffect, PaddleSizePlugin, SizeEffectType, BRICK_TYPE_30, BRICK_TYPE_32,
+    paddle_collider_for, PaddleColliderScale, PaddleResizing, PaddleSizeEffect,
+    PaddleSizeEffectApplied, PaddleSizePlugin, SizeEffectType, BRICK_TYPE_30, BRICK_TYPE_32,
+    MAX_EFFECT_DURATION, PADDLE_RESIZE_DURATION,
 };
-use brkrs::{Ball, Brick, BrickTypeId, Paddle};
+use brkrs::systems::powerups::{PowerupCapsule, PowerupPlugin};
+use brkrs::{Brick, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn, Paddle};
 
 use std::time::Duration;
 
+/// Widths of the `PaddleSizeEffectApplied` messages, kept across frames.
+#[derive(Resource, Default)]
+struct AppliedWidths(Vec<f32>);
+
+fn record_applied_widths(
+    mut applied: MessageReader<PaddleSizeEffectApplied>,
+    mut widths: ResMut<AppliedWidths>,
+) {
+    widths
+        .0
+        .extend(applied.read().map(|applied| applied.new_width));
+}
+
 fn test_app() -> App {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins)
         .insert_resource(Assets::<Mesh>::default())
         .insert_resource(Assets::<StandardMaterial>::default())
-        .add_message::<CollisionEvent>()
-        .add_plugins(PaddleSizePlugin);
+        .init_resource::<AppliedWidths>()
+        .add_plugins((PaddleSizePlugin, PowerupPlugin))
+        .add_systems(Update, record_applied_widths);
+    // Run Startup once so the config loader does not overwrite the test configs
+    app.update();
+    app
+}
+
+/// Test app using `policy` for repeated size effects, with up to 2 stacks.
+fn app_with_policy(policy: SizeStackPolicy) -> App {
+    let mut app = test_app();
+    app.insert_resource(GameplayConfig {
+        paddle_size: PaddleSizeConfig {
+            policy,
+            max_stacks: 2,
+        },
+        ..GameplayConfig::default()
+    });
     app
 }
 
-fn advance_time(app: &mut App, delta_secs: f32) {
-    let mut time = app.world_mut().resource_mut::<Time>();
-    time.advance_by(Duration::from_secs_f32(delta_secs));
-    // Note: After advancing, we need to call app.update() for systems to see the delta
+/// Destroy a brick of `brick_type` right above `paddle`, then run the frames in which its
+/// capsule drops and is caught.
+fn hit_brick(app: &mut App, paddle: Entity, brick_type: u8) {
+    let position = app.world().get::<Transform>(paddle).unwrap().translation;
+    app.world_mut().spawn((
+        Brick,
+        BrickTypeId(brick_type),
+        CountsTowardsCompletion,
+        Transform::from_translation(position),
+        MarkedForDespawn,
+    ));
+    app.update();
+    app.update();
+}
+
+fn capsule_count(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query::<&PowerupCapsule>().iter(world).count()
+}
+
+/// Widths of the `PaddleSizeEffectApplied` messages written since the last call.
+fn applied_widths(app: &mut App) -> Vec<f32> {
+    std::mem::take(&mut app.world_mut().resource_mut::<AppliedWidths>().0)
+}
+
+fn tick_effect(app: &mut App, paddle: Entity, secs: f32) {
+    app.world_mut()
+        .entity_mut(paddle)
+        .get_mut::<PaddleSizeEffect>()
+        .unwrap()
+        .timer
+        .tick(Duration::from_secs_f32(secs));
+}
+
+/// Run the paddle length animation to its end.
+fn finish_resize(app: &mut App, paddle: Entity) {
+    if let Some(mut resizing) = app.world_mut().get_mut::<PaddleResizing>(paddle) {
+        resizing
+            .timer
+            .tick(Duration::from_secs_f32(PADDLE_RESIZE_DURATION));
+    }
+    app.update();
 }
 
-fn trigger_collision(app: &mut App, e1: Entity, e2: Entity) {
+fn paddle_transform() -> Transform {
+    Transform::from_xyz(0.0, 2.0, 0.0)
+        .with_rotation(Quat::from_rotation_x(-std::f32::consts::PI / 2.0))
+}
+
+/// Spawn a paddle lying along the Z axis like the level loader does.
+fn spawn_paddle(app: &mut App) -> Entity {
+    app.world_mut().spawn((Paddle, paddle_transform())).id()
+}
+
+/// Spawn a paddle like the level loader does, with its base collider.
+fn spawn_physical_paddle(app: &mut App) -> Entity {
     app.world_mut()
-        .resource_mut::<Messages<CollisionEvent>>()
-        .write(CollisionEvent::Started(
-            e1,
-            e2,
-            CollisionEventFlags::empty(),
-        ));
+        .spawn((Paddle, paddle_transform(), paddle_collider_for(Vec3::ONE)))
+        .id()
+}
+
+/// Whether the paddle's collider contains the world point `offset` away from its center.
+fn collider_reaches(app: &App, paddle: Entity, offset: Vec3) -> bool {
+    let transform = app.world().get::<Transform>(paddle).unwrap();
+    let collider = app.world().get::<Collider>(paddle).unwrap();
+    collider.contains_point(
+        transform.translation,
+        transform.rotation,
+        transform.translation + offset,
+    )
 }
 
 #[test]
 fn brick_30_shrinks_paddle() {
     let mut app = test_app();
 
-    // Spawn paddle
-    let paddle = app
-        .world_mut()
-        .spawn((Paddle, Transform::from_scale(Vec3::ONE)))
-        .id();
-
-    // Spawn ball
-    let ball = app.world_mut().spawn(Ball).id();
+    let paddle = spawn_paddle(&mut app);
 
-    // Spawn shrink brick (type 30)
-    let brick = app
-        .world_mut()
-        .spawn((Brick, BrickTypeId(BRICK_TYPE_30)))
-        .id();
-
-    // Trigger collision between ball and brick
-    trigger_collision(&mut app, ball, brick);
-    advance_time(&mut app, 0.016);
-    app.update();
+    // Catch the capsule of the shrink brick (type 30)
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
 
     // Verify paddle has PaddleSizeEffect component
     let effect = app
@@ -80,6 +153,7 @@ fn brick_30_shrinks_paddle() {
     );
 
     // Verify paddle transform scale (70% = 14 units / 20 base = 0.7)
+    finish_resize(&mut app, paddle);
     let transform = app
         .world()
         .entity(paddle)
@@ -97,25 +171,10 @@ fn brick_30_shrinks_paddle() {
 fn brick_32_enlarges_paddle() {
     let mut app = test_app();
 
-    // Spawn paddle
-    let paddle = app
-        .world_mut()
-        .spawn((Paddle, Transform::from_scale(Vec3::ONE)))
-        .id();
-
-    // Spawn ball
-    let ball = app.world_mut().spawn(Ball).id();
-
-    // Spawn enlarge brick (type 32)
-    let brick = app
-        .world_mut()
-        .spawn((Brick, BrickTypeId(BRICK_TYPE_32)))
-        .id();
+    let paddle = spawn_paddle(&mut app);
 
-    // Trigger collision between ball and brick
-    trigger_collision(&mut app, ball, brick);
-    advance_time(&mut app, 0.016);
-    app.update();
+    // Catch the capsule of the enlarge brick (type 32)
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
 
     // Verify paddle has PaddleSizeEffect component
     let effect = app
@@ -131,6 +190,7 @@ fn brick_32_enlarges_paddle() {
     );
 
     // Verify paddle transform scale (150% = 30 units / 20 base = 1.5)
+    finish_resize(&mut app, paddle);
     let transform = app
         .world()
         .entity(paddle)
@@ -144,26 +204,35 @@ fn brick_32_enlarges_paddle() {
     );
 }
 
+#[test]
+fn powerup_brick_effect_waits_for_the_catch() {
+    let mut app = test_app();
+    let paddle = spawn_paddle(&mut app);
+
+    // A shrink brick destroyed far up the field only drops its capsule
+    app.world_mut().spawn((
+        Brick,
+        BrickTypeId(BRICK_TYPE_30),
+        CountsTowardsCompletion,
+        Transform::from_xyz(-10.0, 2.0, 0.0),
+        MarkedForDespawn,
+    ));
+    app.update();
+    app.update();
+
+    assert_eq!(capsule_count(&mut app), 1);
+    assert!(app.world().get::<PaddleSizeEffect>(paddle).is_none());
+    assert!(applied_widths(&mut app).is_empty());
+}
+
 #[test]
 fn effect_expires_after_duration() {
     let mut app = test_app();
 
-    // Spawn paddle
-    let paddle = app
-        .world_mut()
-        .spawn((Paddle, Transform::from_scale(Vec3::ONE)))
-        .id();
-
-    // Spawn ball and brick
-    let ball = app.world_mut().spawn(Ball).id();
-    let brick = app
-        .world_mut()
-        .spawn((Brick, BrickTypeId(BRICK_TYPE_30)))
-        .id();
-
-    // Trigger collision
-    trigger_collision(&mut app, ball, brick);
-    app.update();
+    let paddle = spawn_paddle(&mut app);
+
+    // Catch the shrink capsule
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
 
     // Verify effect is active
     assert!(
@@ -193,6 +262,7 @@ fn effect_expires_after_duration() {
     );
 
     // Verify paddle scale restored to 1.0
+    finish_resize(&mut app, paddle);
     let transform = app
         .world()
         .entity(paddle)
@@ -210,25 +280,10 @@ fn effect_expires_after_duration() {
 fn new_effect_replaces_old_effect() {
     let mut app = test_app();
 
-    // Spawn paddle
-    let paddle = app
-        .world_mut()
-        .spawn((Paddle, Transform::from_scale(Vec3::ONE)))
-        .id();
-
-    // Spawn ball
-    let ball = app.world_mut().spawn(Ball).id();
+    let paddle = spawn_paddle(&mut app);
 
-    // Spawn shrink brick (type 30)
-    let brick_shrink = app
-        .world_mut()
-        .spawn((Brick, BrickTypeId(BRICK_TYPE_30)))
-        .id();
-
-    // First collision: shrink
-    trigger_collision(&mut app, ball, brick_shrink);
-    advance_time(&mut app, 0.016);
-    app.update();
+    // First capsule: shrink
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
 
     // Verify shrink effect
     let effect = app
@@ -238,16 +293,8 @@ fn new_effect_replaces_old_effect() {
         .expect("Should have shrink effect");
     assert_eq!(effect.effect_type, SizeEffectType::Shrink);
 
-    // Spawn enlarge brick (type 32)
-    let brick_enlarge = app
-        .world_mut()
-        .spawn((Brick, BrickTypeId(BRICK_TYPE_32)))
-        .id();
-
-    // Second collision: enlarge (should replace shrink)
-    trigger_collision(&mut app, ball, brick_enlarge);
-    advance_time(&mut app, 0.016);
-    app.update();
+    // Second capsule: enlarge (should replace shrink)
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
 
     // Verify enlarge effect replaced shrink
     let effect = app
@@ -262,6 +309,7 @@ fn new_effect_replaces_old_effect() {
     );
 
     // Verify paddle scale is now 1.5 (enlarged)
+    finish_resize(&mut app, paddle);
     let transform = app
         .world()
         .entity(paddle)
@@ -278,24 +326,10 @@ fn new_effect_replaces_old_effect() {
 fn timer_resets_on_different_brick_type() {
     let mut app = test_app();
 
-    // Spawn paddle
-    let paddle = app
-        .world_mut()
-        .spawn((Paddle, Transform::from_scale(Vec3::ONE)))
-        .id();
+    let paddle = spawn_paddle(&mut app);
 
-    // Spawn ball
-    let ball = app.world_mut().spawn(Ball).id();
-
-    // First shrink brick (type 30)
-    let brick1 = app
-        .world_mut()
-        .spawn((Brick, BrickTypeId(BRICK_TYPE_30)))
-        .id();
-
-    // First collision
-    trigger_collision(&mut app, ball, brick1);
-    app.update();
+    // First capsule
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
 
     // Manually tick timer by 5 seconds
     app.world_mut()
@@ -320,15 +354,8 @@ fn timer_resets_on_different_brick_type() {
         remaining_secs
     );
 
-    // Second enlarge brick (type 32, different from first)
-    let brick2 = app
-        .world_mut()
-        .spawn((Brick, BrickTypeId(BRICK_TYPE_32)))
-        .id();
-
-    // Second collision - should reset timer with new effect type
-    trigger_collision(&mut app, ball, brick2);
-    app.update();
+    // Second capsule - should reset timer with new effect type
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
 
     // Verify timer reset to full duration with enlarge effect
     let effect = app
@@ -348,3 +375,218 @@ fn timer_resets_on_different_brick_type() {
         remaining_secs
     );
 }
+
+#[test]
+fn refresh_policy_restarts_the_timer() {
+    let mut app = app_with_policy(SizeStackPolicy::Refresh);
+    let paddle = spawn_paddle(&mut app);
+
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
+    tick_effect(&mut app, paddle, 6.0);
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
+
+    let effect = app.world().get::<PaddleSizeEffect>(paddle).unwrap();
+    assert_eq!(effect.stacks, 1);
+    assert!(effect.timer.remaining_secs() > 9.9);
+    finish_resize(&mut app, paddle);
+    let scale = app.world().get::<Transform>(paddle).unwrap().scale.y;
+    assert!((scale - 0.7).abs() < 0.01, "got {scale}");
+    let widths = applied_widths(&mut app);
+    assert_eq!(widths.len(), 2);
+    assert!(widths.iter().all(|width| (width - 14.0).abs() < 0.01));
+}
+
+#[test]
+fn extend_policy_adds_to_the_time_left() {
+    let mut app = app_with_policy(SizeStackPolicy::Extend);
+    let paddle = spawn_paddle(&mut app);
+
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
+    tick_effect(&mut app, paddle, 4.0);
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
+
+    let remaining = app
+        .world()
+        .get::<PaddleSizeEffect>(paddle)
+        .unwrap()
+        .timer
+        .remaining_secs();
+    assert!(
+        remaining > 15.9 && remaining <= 16.0,
+        "6s left plus a full duration, got {remaining}"
+    );
+
+    // Extensions stop at the longest duration
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
+    let effect = app.world().get::<PaddleSizeEffect>(paddle).unwrap();
+    assert!(effect.timer.remaining_secs() <= MAX_EFFECT_DURATION);
+    assert!(effect.timer.remaining_secs() > MAX_EFFECT_DURATION - 0.1);
+    assert_eq!(effect.stacks, 1);
+    finish_resize(&mut app, paddle);
+    let scale = app.world().get::<Transform>(paddle).unwrap().scale.y;
+    assert!((scale - 1.5).abs() < 0.01, "got {scale}");
+    assert_eq!(applied_widths(&mut app), vec![30.0; 4]);
+}
+
+#[test]
+fn stack_policy_compounds_sizes_up_to_the_limits() {
+    let mut app = app_with_policy(SizeStackPolicy::Stack);
+    let paddle = spawn_paddle(&mut app);
+
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
+    // Already at max_stacks: only the timer restarts
+    tick_effect(&mut app, paddle, 3.0);
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
+
+    let effect = app.world().get::<PaddleSizeEffect>(paddle).unwrap();
+    assert_eq!(effect.stacks, 2);
+    assert!(effect.timer.remaining_secs() > 9.9);
+    finish_resize(&mut app, paddle);
+    let scale = app.world().get::<Transform>(paddle).unwrap().scale.y;
+    assert!((scale - 0.49).abs() < 0.01, "got {scale}");
+    let widths = applied_widths(&mut app);
+    assert_eq!(widths.len(), 3);
+    assert!((widths[0] - 14.0).abs() < 0.01);
+    assert!((widths[1] - 9.8).abs() < 0.01 && (widths[2] - 9.8).abs() < 0.01);
+
+    // An opposite effect starts over; a second enlarge is capped at the maximum width
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
+    assert_eq!(
+        app.world().get::<PaddleSizeEffect>(paddle).unwrap().stacks,
+        1
+    );
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
+    let effect = app.world().get::<PaddleSizeEffect>(paddle).unwrap();
+    assert_eq!(effect.effect_type, SizeEffectType::Enlarge);
+    assert_eq!(effect.stacks, 2);
+    finish_resize(&mut app, paddle);
+    let scale = app.world().get::<Transform>(paddle).unwrap().scale.y;
+    assert!((scale - 2.0).abs() < 0.01, "got {scale}");
+    assert_eq!(applied_widths(&mut app), vec![30.0, 40.0]);
+}
+
+#[test]
+fn paddle_length_is_animated_both_ways() {
+    let mut app = test_app();
+    let paddle = spawn_paddle(&mut app);
+
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
+    let resizing = app.world().get::<PaddleResizing>(paddle).unwrap();
+    assert_eq!(resizing.start_scale, 1.0);
+    assert_eq!(resizing.target_scale, 1.5);
+
+    // Halfway through, the ease-out curve is past the middle but not at the target yet
+    app.world_mut()
+        .get_mut::<PaddleResizing>(paddle)
+        .unwrap()
+        .timer
+        .tick(Duration::from_secs_f32(PADDLE_RESIZE_DURATION / 2.0));
+    app.update();
+    let scale = app.world().get::<Transform>(paddle).unwrap().scale.y;
+    assert!(scale > 1.25 && scale < 1.5, "got {scale}");
+
+    finish_resize(&mut app, paddle);
+    assert_eq!(app.world().get::<Transform>(paddle).unwrap().scale.y, 1.5);
+    assert!(app.world().get::<PaddleResizing>(paddle).is_none());
+
+    // Expiry animates back to the base length
+    tick_effect(&mut app, paddle, 10.1);
+    app.update();
+    let resizing = app.world().get::<PaddleResizing>(paddle).unwrap();
+    assert_eq!(resizing.target_scale, 1.0);
+    assert!(app.world().get::<Transform>(paddle).unwrap().scale.y > 1.0);
+    finish_resize(&mut app, paddle);
+    assert_eq!(app.world().get::<Transform>(paddle).unwrap().scale.y, 1.0);
+}
+
+#[test]
+fn base_collider_matches_the_spawned_paddle() {
+    let collider = paddle_collider_for(Vec3::ONE);
+    let capsule = collider.as_capsule().unwrap();
+    // Capsule3d::new(PADDLE_RADIUS, PADDLE_HEIGHT) of the paddle mesh
+    assert!((capsule.half_height() - 1.5).abs() < 1e-5);
+    assert!((capsule.radius() - 0.3).abs() < 1e-5);
+}
+
+#[test]
+fn collider_follows_paddle_length_changes() {
+    let mut app = test_app();
+    let paddle = spawn_physical_paddle(&mut app);
+    app.update();
+
+    // The base capsule reaches 1.8 units from its center along the paddle, 0.3 across it
+    assert!(matches!(
+        app.world().get::<ColliderScale>(paddle),
+        Some(ColliderScale::Absolute(scale)) if *scale == Vec3::ONE
+    ));
+    assert!(collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 1.75)));
+    assert!(!collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 1.85)));
+
+    // Enlarged to 150%: the ends reach 2.7 units, the cross-section is unchanged
+    hit_brick(&mut app, paddle, BRICK_TYPE_32);
+    finish_resize(&mut app, paddle);
+    assert!(collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 2.65)));
+    assert!(collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, -2.65)));
+    assert!(!collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 2.75)));
+    assert!(collider_reaches(&app, paddle, Vec3::new(0.25, 0.0, 0.0)));
+    assert!(!collider_reaches(&app, paddle, Vec3::new(0.35, 0.0, 0.0)));
+    // The round ends stay round instead of being stretched with the length
+    let capsule = app.world().get::<Collider>(paddle).unwrap().as_capsule();
+    assert!((capsule.unwrap().radius() - 0.3).abs() < 1e-5);
+
+    // Halfway back to the base length after expiry, the collider is in between
+    tick_effect(&mut app, paddle, 10.1);
+    app.update();
+    app.world_mut()
+        .get_mut::<PaddleResizing>(paddle)
+        .unwrap()
+        .timer
+        .tick(Duration::from_secs_f32(PADDLE_RESIZE_DURATION / 2.0));
+    app.update();
+    assert!(collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 1.85)));
+    assert!(!collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 2.65)));
+
+    finish_resize(&mut app, paddle);
+    assert!(!collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 1.85)));
+    assert_eq!(
+        app.world().get::<PaddleColliderScale>(paddle),
+        Some(&PaddleColliderScale(Vec3::ONE))
+    );
+
+    // Shrunk to 70%: the ends reach 1.26 units
+    hit_brick(&mut app, paddle, BRICK_TYPE_30);
+    finish_resize(&mut app, paddle);
+    assert!(collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 1.2)));
+    assert!(!collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 1.3)));
+}
+
+#[test]
+fn collider_follows_paddle_growth() {
+    let mut app = test_app();
+    let paddle = spawn_physical_paddle(&mut app);
+
+    // Respawn growth scales the paddle uniformly (`PaddleGrowing`)
+    app.world_mut().get_mut::<Transform>(paddle).unwrap().scale = Vec3::splat(0.5);
+    app.update();
+
+    let capsule = app.world().get::<Collider>(paddle).unwrap().as_capsule();
+    let capsule = capsule.unwrap();
+    assert!((capsule.half_height() - 0.75).abs() < 1e-5);
+    assert!((capsule.radius() - 0.15).abs() < 1e-5);
+    assert!(collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 0.85)));
+    assert!(!collider_reaches(&app, paddle, Vec3::new(0.0, 0.0, 0.95)));
+
+    // Moving the paddle keeps the collider
+    app.world_mut()
+        .get_mut::<Transform>(paddle)
+        .unwrap()
+        .translation
+        .x += 1.0;
+    app.update();
+    assert_eq!(
+        app.world().get::<PaddleColliderScale>(paddle),
+        Some(&PaddleColliderScale(Vec3::splat(0.5)))
+    );
+}
@@ -44,6 +44,7 @@ fn paddle_physics_config_valid() {
         friction: 0.5,
         linear_damping: 0.1,
         angular_damping: 0.1,
+        spin_transfer: 0.001,
     };
     assert!(config.validate().is_ok());
 }
@@ -55,6 +56,7 @@ fn paddle_physics_config_invalid() {
         friction: -0.5,
         linear_damping: 0.1,
         angular_damping: 0.1,
+        spin_transfer: 0.001,
     };
     assert!(config.validate().is_err());
 }
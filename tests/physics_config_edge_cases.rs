@@ -1,4 +1,6 @@
-use brkrs::physics_config::{BallPhysicsConfig, BrickPhysicsConfig, PaddlePhysicsConfig};
+use brkrs::physics_config::{
+    BallPhysicsConfig, BrickPhysicsConfig, PaddlePhysicsConfig, MAX_SPIN_TRANSFER,
+};
 
 #[test]
 fn ball_physics_config_edge_cases() {
@@ -83,6 +85,7 @@ fn paddle_physics_config_edge_cases() {
         friction: 0.5,
         linear_damping: 0.1,
         angular_damping: 0.1,
+        spin_transfer: 0.001,
     };
     assert!(nan.validate().is_err());
     let inf = PaddlePhysicsConfig {
@@ -90,6 +93,7 @@ fn paddle_physics_config_edge_cases() {
         friction: 0.5,
         linear_damping: 0.1,
         angular_damping: 0.1,
+        spin_transfer: 0.001,
     };
     assert!(inf.validate().is_err());
     let neg_inf = PaddlePhysicsConfig {
@@ -97,6 +101,7 @@ fn paddle_physics_config_edge_cases() {
         friction: 0.5,
         linear_damping: 0.1,
         angular_damping: 0.1,
+        spin_transfer: 0.001,
     };
     assert!(neg_inf.validate().is_err());
     let nan_friction = PaddlePhysicsConfig {
@@ -104,6 +109,7 @@ fn paddle_physics_config_edge_cases() {
         friction: f32::NAN,
         linear_damping: 0.1,
         angular_damping: 0.1,
+        spin_transfer: 0.001,
     };
     assert!(nan_friction.validate().is_err());
     let inf_damping = PaddlePhysicsConfig {
@@ -111,6 +117,7 @@ fn paddle_physics_config_edge_cases() {
         friction: 0.5,
         linear_damping: f32::INFINITY,
         angular_damping: 0.1,
+        spin_transfer: 0.001,
     };
     assert!(inf_damping.validate().is_err());
     let nan_angular = PaddlePhysicsConfig {
@@ -118,6 +125,7 @@ fn paddle_physics_config_edge_cases() {
         friction: 0.5,
         linear_damping: 0.1,
         angular_damping: f32::NAN,
+        spin_transfer: 0.001,
     };
     assert!(nan_angular.validate().is_err());
     let zero = PaddlePhysicsConfig {
@@ -125,6 +133,7 @@ fn paddle_physics_config_edge_cases() {
         friction: 0.0,
         linear_damping: 0.0,
         angular_damping: 0.0,
+        spin_transfer: 0.0,
     };
     assert!(zero.validate().is_ok());
 
@@ -133,6 +142,7 @@ fn paddle_physics_config_edge_cases() {
         friction: 2.0,
         linear_damping: 10.0,
         angular_damping: 10.0,
+        spin_transfer: MAX_SPIN_TRANSFER,
     };
     assert!(max.validate().is_ok());
 
@@ -141,8 +151,20 @@ fn paddle_physics_config_edge_cases() {
         friction: 3.0,
         linear_damping: -0.1,
         angular_damping: 20.0,
+        spin_transfer: 0.001,
     };
     assert!(invalid.validate().is_err());
+
+    let slippery = PaddlePhysicsConfig {
+        spin_transfer: MAX_SPIN_TRANSFER * 2.0,
+        ..Default::default()
+    };
+    assert!(slippery.validate().is_err());
+    let backwards = PaddlePhysicsConfig {
+        spin_transfer: -0.001,
+        ..Default::default()
+    };
+    assert!(backwards.validate().is_err());
 }
 
 #[test]
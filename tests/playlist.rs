@@ -0,0 +1,113 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::{app::App, input::InputPlugin, prelude::*};
+use bevy_rapier3d::prelude::RapierConfiguration;
+use brkrs::level_loader::{CurrentLevel, LevelAdvanceState, LevelLoaderPlugin};
+use brkrs::systems::campaign::CampaignRoute;
+use brkrs::systems::playlist::{
+    Playlist, PlaylistEntryResult, PlaylistPlugin, PlaylistStartRequested,
+};
+use brkrs::systems::respawn::SpawnPoints;
+use brkrs::systems::scoring::ScoreState;
+use brkrs::systems::{LevelCompleted, LevelSwitchPlugin};
+use brkrs::ui::fonts::UiFonts;
+use brkrs::ui::playlist_summary::{sync_playlist_summary, PlaylistSummaryOverlay};
+use brkrs::GameProgress;
+
+fn next_level(app: &mut App, current: u32) -> Option<u32> {
+    app.world_mut()
+        .run_system_once(move |route: CampaignRoute| route.next_level(current))
+        .unwrap()
+}
+
+#[test]
+fn starting_a_run_resets_the_score_and_jumps_to_the_first_entry() {
+    std::env::set_var("BK_LEVEL", "1");
+    let mut app = App::new();
+    app.insert_resource(brkrs::physics_config::BallPhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::PaddlePhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    app.add_plugins((MinimalPlugins, InputPlugin));
+    app.insert_resource(GameProgress::default());
+    app.insert_resource(LevelAdvanceState::default());
+    app.insert_resource(SpawnPoints::default());
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.world_mut().spawn(RapierConfiguration::new(1.0));
+    app.add_plugins((LevelSwitchPlugin, LevelLoaderPlugin, PlaylistPlugin));
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<CurrentLevel>().0.number, 1);
+
+    app.insert_resource(Playlist::new("cup", vec![2, 1]));
+    app.insert_resource(ScoreState {
+        current_score: 900,
+        ..Default::default()
+    });
+    app.world_mut().write_message(PlaylistStartRequested);
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<CurrentLevel>().0.number, 2);
+    assert_eq!(app.world().resource::<ScoreState>().current_score, 0);
+    let playlist = app.world().resource::<Playlist>();
+    assert_eq!(playlist.current_entry(), Some(2));
+    assert_eq!(playlist.next_entry(), Some(1));
+}
+
+#[test]
+fn completed_entries_are_recorded_and_the_last_one_ends_the_game() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PlaylistPlugin);
+    app.insert_resource(UiFonts {
+        orbitron: Handle::default(),
+    });
+    app.add_systems(Update, sync_playlist_summary);
+    // Let startup load the (absent) playlist file before installing the test playlist
+    app.update();
+    let mut playlist = Playlist::new("cup", vec![2, 5]);
+    playlist.start();
+    app.insert_resource(playlist);
+    app.insert_resource(ScoreState {
+        current_score: 300,
+        level_start_score: 100,
+        ..Default::default()
+    });
+    app.update();
+
+    assert_eq!(next_level(&mut app, 2), Some(5), "playlist order wins");
+    assert_eq!(next_level(&mut app, 9), Some(10), "off-playlist levels");
+
+    app.world_mut().trigger(LevelCompleted { level_index: 2 });
+    assert_eq!(next_level(&mut app, 5), None, "last entry ends the game");
+    {
+        let mut score = app.world_mut().resource_mut::<ScoreState>();
+        score.level_start_score = 300;
+        score.current_score = 350;
+    }
+    app.world_mut().trigger(LevelCompleted { level_index: 5 });
+    app.update();
+
+    let playlist = app.world().resource::<Playlist>();
+    assert!(playlist.finished);
+    assert_eq!(
+        playlist.results,
+        vec![
+            PlaylistEntryResult {
+                level: 2,
+                points: 200
+            },
+            PlaylistEntryResult {
+                level: 5,
+                points: 50
+            },
+        ]
+    );
+    assert_eq!(playlist.total_points(), 250);
+    let world = app.world_mut();
+    let summaries = world
+        .query_filtered::<(), With<PlaylistSummaryOverlay>>()
+        .iter(world)
+        .count();
+    assert_eq!(summaries, 1);
+}
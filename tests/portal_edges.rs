@@ -0,0 +1,111 @@
+mod common;
+
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::{CollisionEvent, Sensor, Velocity};
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+use brkrs::level_loader::CurrentLevel;
+use brkrs::systems::portal_edges::{PortalEdgesPlugin, PortalShimmer};
+use brkrs::{Ball, Border, SideWall};
+
+fn level(wrap_sides: bool) -> CurrentLevel {
+    let mut level = common::level(1);
+    level.0.wrap_sides = wrap_sides;
+    level
+}
+
+/// App with the two side walls of `spawn_border` (at z = -22.5 and 22.5).
+fn portal_test_app(wrap_sides: bool) -> (App, [Entity; 2]) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(PortalEdgesPlugin);
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(level(wrap_sides));
+    let walls = [-22.5, 22.5].map(|z| {
+        app.world_mut()
+            .spawn((Border, SideWall, Transform::from_xyz(0.0, 0.0, z)))
+            .id()
+    });
+    app.update();
+    app.update();
+    (app, walls)
+}
+
+fn enter_wall(app: &mut App, wall: Entity, z: f32, vz: f32) -> Entity {
+    let ball = app
+        .world_mut()
+        .spawn((
+            Ball,
+            Transform::from_xyz(3.0, 2.0, z),
+            Velocity::linear(Vec3::new(1.0, 0.0, vz)),
+        ))
+        .id();
+    app.world_mut().write_message(CollisionEvent::Started(
+        ball,
+        wall,
+        CollisionEventFlags::SENSOR,
+    ));
+    app.update();
+    ball
+}
+
+fn shimmers(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(), With<PortalShimmer>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn wrapping_level_turns_side_walls_into_portals() {
+    let (mut app, walls) = portal_test_app(true);
+    for wall in walls {
+        assert!(app.world().entity(wall).contains::<Sensor>());
+    }
+    assert_eq!(shimmers(&mut app), 2);
+
+    let ball = enter_wall(&mut app, walls[1], 19.8, 5.0);
+    let transform = app.world().get::<Transform>(ball).unwrap();
+    assert!(
+        transform.translation.z < -19.0,
+        "ball wrapped to the left edge, got z = {}",
+        transform.translation.z
+    );
+    assert_eq!(transform.translation.x, 3.0);
+    let velocity = app.world().get::<Velocity>(ball).unwrap();
+    assert_eq!(velocity.linvel, Vec3::new(1.0, 0.0, 5.0), "velocity kept");
+}
+
+#[test]
+fn balls_heading_back_in_are_not_wrapped() {
+    let (mut app, walls) = portal_test_app(true);
+    let ball = enter_wall(&mut app, walls[0], -19.7, 5.0);
+    assert_eq!(
+        app.world().get::<Transform>(ball).unwrap().translation.z,
+        -19.7
+    );
+}
+
+#[test]
+fn side_walls_stay_solid_without_wrapping() {
+    let (mut app, walls) = portal_test_app(false);
+    for wall in walls {
+        assert!(!app.world().entity(wall).contains::<Sensor>());
+    }
+    assert_eq!(shimmers(&mut app), 0);
+
+    // Switching to a wrapping level and back restores the walls
+    app.insert_resource(level(true));
+    app.update();
+    app.update();
+    assert!(app.world().entity(walls[0]).contains::<Sensor>());
+    app.insert_resource(level(false));
+    app.update();
+    app.update();
+    assert!(!app.world().entity(walls[0]).contains::<Sensor>());
+    assert_eq!(shimmers(&mut app), 0);
+}
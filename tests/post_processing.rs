@@ -0,0 +1,64 @@
+use bevy::post_process::bloom::Bloom;
+use bevy::post_process::effect_stack::ChromaticAberration;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::systems::post_processing::{
+    PostProcessingConfig, PostProcessingPlugin, PostProcessingPreset, Vignette,
+};
+use brkrs::MainCamera;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(PostProcessingPlugin);
+    app
+}
+
+fn vignettes(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<Entity, With<Vignette>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn preset_changes_are_applied_to_the_camera() {
+    let mut app = test_app();
+    let camera = app.world_mut().spawn(MainCamera).id();
+    // Startup loads the config (defaults when there is no config file)
+    app.update();
+    app.insert_resource(PostProcessingConfig {
+        preset: PostProcessingPreset::High,
+        ..Default::default()
+    });
+    app.update();
+    assert!(app.world().get::<Bloom>(camera).is_some());
+    assert!(app.world().get::<ChromaticAberration>(camera).is_some());
+    assert_eq!(vignettes(&mut app), 1);
+
+    app.world_mut()
+        .resource_mut::<PostProcessingConfig>()
+        .preset = PostProcessingPreset::Off;
+    app.update();
+    assert!(app.world().get::<Bloom>(camera).is_none());
+    assert!(app.world().get::<ChromaticAberration>(camera).is_none());
+    assert_eq!(vignettes(&mut app), 0);
+}
+
+#[test]
+fn cameras_spawned_later_pick_up_the_effects() {
+    let mut app = test_app();
+    app.update();
+    app.insert_resource(PostProcessingConfig {
+        preset: PostProcessingPreset::Medium,
+        ..Default::default()
+    });
+    app.update();
+    let camera = app.world_mut().spawn(MainCamera).id();
+    app.update();
+    assert!(app.world().get::<Bloom>(camera).is_some());
+    // The vignette is spawned once, not per camera
+    assert_eq!(vignettes(&mut app), 1);
+}
@@ -0,0 +1,366 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::{
+    Collider, ColliderMassProperties, CollisionEvent, Friction, Restitution, RigidBody, Velocity,
+};
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+use brkrs::gameplay_config::{DropEntry, DropTable, GameplayConfig};
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::physics_config::PaddlePhysicsConfig;
+use brkrs::systems::ball_size::{BallSizeEffect, BallSizePlugin, BALL_GROW_MULTIPLIER};
+use brkrs::systems::paddle_size::{PaddleSizeEffect, SizeEffectType};
+use brkrs::systems::powerups::{
+    FastBall, PaddleSurface, PowerupCapsule, PowerupKind, PowerupPlugin, ReversedControls,
+    RubberPaddle, BALL_SPEED_UP_FACTOR,
+};
+use brkrs::systems::timed_effects::TimedEffect;
+use brkrs::systems::wrecking_ball::{
+    WreckingBall, WreckingBallPlugin, WreckingDebris, WRECKING_MASS_MULTIPLIER,
+};
+use brkrs::ui::effects_hud::describe_active_effects;
+use brkrs::{Ball, Brick, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn, Paddle};
+
+use std::time::Duration;
+
+fn always_enlarge() -> DropTable {
+    DropTable {
+        chance: 1.0,
+        entries: vec![DropEntry {
+            kind: PowerupKind::EnlargePaddle,
+            weight: 1,
+        }],
+    }
+}
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins((
+        PowerupPlugin,
+        BallSizePlugin,
+        WreckingBallPlugin,
+    ));
+    // Run Startup once so the config loader does not overwrite the test config below
+    app.update();
+    app.insert_resource(GameplayConfig {
+        drops: always_enlarge(),
+        ..Default::default()
+    });
+    app
+}
+
+fn advance(app: &mut App, secs: f32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        secs,
+    )));
+    app.update();
+}
+
+fn paddle_transform(x: f32) -> Transform {
+    Transform::from_xyz(x, 2.0, 0.0)
+        .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+}
+
+fn spawn_marked_brick(app: &mut App, x: f32, z: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Brick,
+            BrickTypeId(20),
+            CountsTowardsCompletion,
+            Transform::from_xyz(x, 2.0, z),
+            MarkedForDespawn,
+        ))
+        .id()
+}
+
+fn capsules(app: &mut App) -> Vec<(Transform, PowerupCapsule)> {
+    let world = app.world_mut();
+    let mut query = world.query::<(&Transform, &PowerupCapsule)>();
+    query.iter(world).map(|(t, c)| (*t, *c)).collect()
+}
+
+#[test]
+fn destroyed_brick_drops_capsule_at_its_position() {
+    let mut app = test_app();
+    spawn_marked_brick(&mut app, -5.0, 4.0);
+
+    app.update();
+
+    let found = capsules(&mut app);
+    assert_eq!(found.len(), 1, "expected exactly one capsule");
+    let (transform, capsule) = found[0];
+    assert_eq!(capsule.kind, PowerupKind::EnlargePaddle);
+    assert!((transform.translation.z - 4.0).abs() < 1e-4);
+}
+
+#[test]
+fn indestructible_bricks_never_drop() {
+    let mut app = test_app();
+    app.world_mut().spawn((
+        Brick,
+        BrickTypeId(90),
+        Transform::from_xyz(0.0, 2.0, 0.0),
+        MarkedForDespawn,
+    ));
+
+    app.update();
+
+    assert!(capsules(&mut app).is_empty());
+}
+
+#[test]
+fn level_override_replaces_global_table() {
+    let mut app = test_app();
+    app.insert_resource(CurrentLevel(LevelDefinition {
+        number: 1,
+        matrix: vec![vec![]],
+        drops: Some(DropTable::disabled()),
+        ..Default::default()
+    }));
+    app.update();
+
+    spawn_marked_brick(&mut app, 0.0, 0.0);
+    app.update();
+
+    assert!(capsules(&mut app).is_empty());
+}
+
+#[test]
+fn paddle_catches_capsule_and_applies_effect() {
+    let mut app = test_app();
+    let paddle = app.world_mut().spawn((Paddle, paddle_transform(10.0))).id();
+    spawn_marked_brick(&mut app, 8.0, 0.0);
+    app.update();
+    assert_eq!(capsules(&mut app).len(), 1);
+
+    // 2 units at 6 u/s: a few frames is enough to reach the paddle
+    for _ in 0..6 {
+        advance(&mut app, 0.1);
+    }
+
+    assert!(capsules(&mut app).is_empty(), "capsule should be consumed");
+    let effect = app
+        .world()
+        .entity(paddle)
+        .get::<PaddleSizeEffect>()
+        .expect("paddle should be enlarged");
+    assert_eq!(effect.effect_type, SizeEffectType::Enlarge);
+}
+
+#[test]
+fn missed_capsule_is_despawned_past_goal() {
+    let mut app = test_app();
+    // Paddle on the opposite side so it cannot catch the capsule
+    app.world_mut().spawn((Paddle, paddle_transform(10.0)));
+    spawn_marked_brick(&mut app, 12.0, -15.0);
+    app.update();
+    assert_eq!(capsules(&mut app).len(), 1);
+
+    for _ in 0..10 {
+        advance(&mut app, 0.2);
+    }
+
+    assert!(
+        capsules(&mut app).is_empty(),
+        "missed capsule should be gone"
+    );
+}
+
+fn catch_capsule(app: &mut App, paddle: Entity, kind: PowerupKind) {
+    let at = app
+        .world()
+        .entity(paddle)
+        .get::<Transform>()
+        .unwrap()
+        .translation;
+    app.world_mut()
+        .spawn((PowerupCapsule { kind }, Transform::from_translation(at)));
+    advance(app, 0.016);
+}
+
+#[test]
+fn reverse_controls_debuff_expires() {
+    let mut app = test_app();
+    let paddle = app.world_mut().spawn((Paddle, paddle_transform(10.0))).id();
+
+    catch_capsule(&mut app, paddle, PowerupKind::ReverseControls);
+    assert!(app.world().entity(paddle).contains::<ReversedControls>());
+
+    // 5 second debuff; virtual time clamps each frame to 0.25s so step in 0.2s increments
+    for _ in 0..30 {
+        advance(&mut app, 0.2);
+    }
+    assert!(!app.world().entity(paddle).contains::<ReversedControls>());
+}
+
+#[test]
+fn rubber_paddle_bounces_harder_then_wears_off() {
+    let mut app = test_app();
+    let config = PaddlePhysicsConfig::default();
+    app.insert_resource(config.clone());
+    let paddle = app
+        .world_mut()
+        .spawn((
+            Paddle,
+            paddle_transform(10.0),
+            Restitution::coefficient(config.restitution),
+            Friction::coefficient(config.friction),
+        ))
+        .id();
+
+    catch_capsule(&mut app, paddle, PowerupKind::RubberPaddle);
+    let rubber = PaddleSurface::of(&config, true);
+    let entity = app.world().entity(paddle);
+    assert!(entity.contains::<RubberPaddle>());
+    assert_eq!(
+        entity.get::<Restitution>().unwrap().coefficient,
+        rubber.restitution
+    );
+    assert_eq!(
+        entity.get::<Friction>().unwrap().coefficient,
+        rubber.friction
+    );
+
+    // 8 second effect, stepped in 0.2s increments
+    for _ in 0..45 {
+        advance(&mut app, 0.2);
+    }
+    let entity = app.world().entity(paddle);
+    assert!(!entity.contains::<RubberPaddle>());
+    assert_eq!(
+        entity.get::<Restitution>().unwrap().coefficient,
+        config.restitution
+    );
+    assert_eq!(
+        entity.get::<Friction>().unwrap().coefficient,
+        config.friction
+    );
+}
+
+#[test]
+fn speed_up_debuff_accelerates_balls_until_it_expires() {
+    let mut app = test_app();
+    let paddle = app.world_mut().spawn((Paddle, paddle_transform(10.0))).id();
+    let start = Vec3::new(-4.0, 0.0, 2.0);
+    let ball = app.world_mut().spawn((Ball, Velocity::linear(start))).id();
+    let linvel = |app: &App| app.world().entity(ball).get::<Velocity>().unwrap().linvel;
+
+    catch_capsule(&mut app, paddle, PowerupKind::SpeedUpBall);
+    assert!(linvel(&app).distance(start * BALL_SPEED_UP_FACTOR) < 1e-4);
+    let fast = app
+        .world()
+        .entity(ball)
+        .get::<FastBall>()
+        .expect("ball should be fast");
+    let hud = describe_active_effects(&[fast.snapshot()]);
+    assert!(hud.contains("- Fast ball 8s"), "unexpected HUD text: {hud}");
+
+    // A second capsule restarts the timer but does not speed the ball up again
+    catch_capsule(&mut app, paddle, PowerupKind::SpeedUpBall);
+    assert!(linvel(&app).distance(start * BALL_SPEED_UP_FACTOR) < 1e-4);
+
+    // 8 second effect, stepped in 0.2s increments
+    for _ in 0..45 {
+        advance(&mut app, 0.2);
+    }
+    assert!(!app.world().entity(ball).contains::<FastBall>());
+    assert!(linvel(&app).distance(start) < 1e-4);
+}
+
+#[test]
+fn shrink_debuff_shows_in_effects_hud() {
+    let mut app = test_app();
+    let paddle = app.world_mut().spawn((Paddle, paddle_transform(10.0))).id();
+
+    catch_capsule(&mut app, paddle, PowerupKind::ShrinkPaddle);
+    catch_capsule(&mut app, paddle, PowerupKind::ReverseControls);
+
+    let entity = app.world().entity(paddle);
+    let effect = entity
+        .get::<PaddleSizeEffect>()
+        .expect("paddle should shrink");
+    assert_eq!(effect.effect_type, SizeEffectType::Shrink);
+
+    let reversed = entity
+        .get::<ReversedControls>()
+        .expect("controls should be reversed");
+    let hud = describe_active_effects(&[effect.snapshot(), reversed.snapshot()]);
+    assert!(hud.contains("- Shrink"), "unexpected HUD text: {hud}");
+    assert!(hud.contains("- Reversed 5s"), "unexpected HUD text: {hud}");
+}
+
+fn ball_radius(app: &App, ball: Entity) -> f32 {
+    app.world()
+        .entity(ball)
+        .get::<Collider>()
+        .and_then(|c| c.as_ball().map(|b| b.radius()))
+        .expect("ball collider")
+}
+
+#[test]
+fn grow_ball_replaces_collider_and_restores_on_expiry() {
+    let mut app = test_app();
+    let paddle = app.world_mut().spawn((Paddle, paddle_transform(10.0))).id();
+    let ball = app
+        .world_mut()
+        .spawn((Ball, Velocity::zero(), Collider::ball(0.3)))
+        .id();
+
+    catch_capsule(&mut app, paddle, PowerupKind::GrowBall);
+    advance(&mut app, 0.016);
+
+    assert!(app.world().entity(ball).contains::<BallSizeEffect>());
+    assert!((ball_radius(&app, ball) - 0.3 * BALL_GROW_MULTIPLIER).abs() < 1e-4);
+
+    // 10 second effect, stepped in 0.25s frames
+    for _ in 0..45 {
+        advance(&mut app, 0.25);
+    }
+    assert!(!app.world().entity(ball).contains::<BallSizeEffect>());
+    assert!((ball_radius(&app, ball) - 0.3).abs() < 1e-4);
+}
+
+#[test]
+fn wrecking_ball_knocks_destroyed_brick_loose() {
+    let mut app = test_app();
+    app.insert_resource(GameplayConfig {
+        drops: DropTable::disabled(),
+        ..Default::default()
+    });
+    let paddle = app.world_mut().spawn((Paddle, paddle_transform(10.0))).id();
+    let ball = app
+        .world_mut()
+        .spawn((Ball, Velocity::linear(Vec3::new(-5.0, 0.0, 0.0))))
+        .id();
+
+    catch_capsule(&mut app, paddle, PowerupKind::WreckingBall);
+    assert!(app.world().entity(ball).contains::<WreckingBall>());
+    match app.world().entity(ball).get::<ColliderMassProperties>() {
+        Some(ColliderMassProperties::Density(d)) => {
+            assert!((d - WRECKING_MASS_MULTIPLIER).abs() < 1e-4)
+        }
+        other => panic!("unexpected mass properties: {other:?}"),
+    }
+
+    // Simulate the ball destroying a brick this frame
+    let brick = spawn_marked_brick(&mut app, -2.0, 0.0);
+    app.world_mut().write_message(CollisionEvent::Started(
+        ball,
+        brick,
+        CollisionEventFlags::empty(),
+    ));
+    advance(&mut app, 0.016);
+
+    let entity = app.world().entity(brick);
+    assert!(entity.contains::<WreckingDebris>());
+    assert!(!entity.contains::<MarkedForDespawn>());
+    assert_eq!(entity.get::<RigidBody>(), Some(&RigidBody::Dynamic));
+
+    // Debris lifetime is short; it is handed back to the normal despawn path afterwards
+    for _ in 0..8 {
+        advance(&mut app, 0.2);
+    }
+    assert!(app.world().entity(brick).contains::<MarkedForDespawn>());
+}
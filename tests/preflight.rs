@@ -0,0 +1,154 @@
+use std::fs;
+use std::path::Path;
+
+use brkrs::systems::preflight::{preflight, AssetKind, PreflightReport};
+use brkrs::ui::preflight_panel::{preflight_panel_text, PANEL_PROBLEMS};
+
+fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn messages(report: &PreflightReport, kind: AssetKind) -> Vec<&str> {
+    report
+        .problems
+        .iter()
+        .filter(|p| p.kind == kind)
+        .map(|p| p.message.as_str())
+        .collect()
+}
+
+#[test]
+fn the_bundled_assets_pass() {
+    let report = preflight(Path::new("assets"), Path::new("assets/levels"));
+    assert!(report.is_clean(), "{}", report.summary());
+    assert!(report.checked > 0);
+}
+
+#[test]
+fn missing_and_broken_assets_are_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let assets = dir.path();
+    let levels = assets.join("levels");
+    write(assets, "levels/manifest.ron", "(levels: [1, 2])");
+    write(
+        assets,
+        "levels/level_001.ron",
+        "LevelDefinition(number: 1, matrix: [[0]])",
+    );
+    write(assets, "levels/level_003.ron", "LevelDefinition(number: 3");
+    write(
+        assets,
+        "levels/campaign.ron",
+        "(levels: { 1: (secret_exit: Some(9)) })",
+    );
+    write(
+        assets,
+        "textures/manifest.ron",
+        r#"(profiles: [(id: "brick/stone", albedo_path: "stone.png", normal_path: Some("stone_n.png"))])"#,
+    );
+    write(assets, "textures/stone.png", "");
+    write(
+        assets,
+        "audio/manifest.ron",
+        r#"AudioManifest(
+            sounds: { BrickDestroy: "brick.ogg", WallBounce: "wall.ogg" },
+            brick_sounds: { 20: "brick.ogg", 25: "clang.ogg" },
+        )"#,
+    );
+    write(assets, "audio/brick.ogg", "");
+    write(
+        assets,
+        "audio/packs.ron",
+        r#"(packs: [(id: "retro", folder: "audio/packs/retro")])"#,
+    );
+
+    let report = preflight(assets, &levels);
+    let level_problems = messages(&report, AssetKind::Level);
+    assert_eq!(level_problems.len(), 4, "{level_problems:?}");
+    assert!(level_problems
+        .iter()
+        .any(|m| m.contains("level_003.ron does not parse")));
+    assert!(level_problems
+        .iter()
+        .any(|m| m.starts_with("listed in the level manifest") && m.contains("level_002.ron")));
+    assert!(level_problems
+        .iter()
+        .any(|m| m.contains("level_003.ron is not in") && m.contains("browser")));
+    assert!(level_problems
+        .iter()
+        .any(|m| m.contains("campaign routes level 1") && m.contains("level_009.ron")));
+
+    let texture_problems = messages(&report, AssetKind::Texture);
+    assert_eq!(texture_problems.len(), 1, "{texture_problems:?}");
+    assert!(texture_problems[0].starts_with("normal map of profile `brick/stone`"));
+    assert!(texture_problems[0].contains("stone_n.png"));
+
+    let sound_problems = messages(&report, AssetKind::Sound);
+    assert!(sound_problems
+        .iter()
+        .any(|m| m.starts_with("WallBounce") && m.contains("wall.ogg is missing")));
+    assert!(sound_problems
+        .iter()
+        .any(|m| m.starts_with("PaddleHit has no sound")));
+    assert!(sound_problems
+        .iter()
+        .any(|m| m.starts_with("MerkabaWall (placeholder)")));
+    assert!(!sound_problems.iter().any(|m| m.starts_with("BrickDestroy")));
+    assert!(sound_problems
+        .iter()
+        .any(|m| m.starts_with("destruction sound of brick 25") && m.contains("clang.ogg")));
+    assert!(!sound_problems
+        .iter()
+        .any(|m| m.starts_with("destruction sound of brick 20")));
+    assert!(sound_problems
+        .iter()
+        .any(|m| m.starts_with("sound pack `retro`") && m.contains("manifest.ron is missing")));
+
+    let summary = report.summary();
+    assert!(summary.starts_with(&format!(
+        "Asset preflight: {} files checked, {} problem(s)",
+        report.checked,
+        report.problems.len()
+    )));
+    assert!(summary.contains("\n  [texture] normal map"));
+}
+
+#[test]
+fn missing_manifests_are_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let report = preflight(dir.path(), &dir.path().join("levels"));
+    let problems: Vec<&str> = report.problems.iter().map(|p| p.message.as_str()).collect();
+    assert_eq!(problems.len(), 3, "{problems:?}");
+    assert!(problems[0].starts_with("level manifest"));
+    assert!(problems[1].starts_with("texture manifest"));
+    assert!(problems[2].starts_with("audio manifest"));
+}
+
+#[test]
+fn the_panel_lists_the_first_problems() {
+    let dir = tempfile::tempdir().unwrap();
+    let levels = dir.path().join("levels");
+    write(dir.path(), "levels/manifest.ron", "(levels: [])");
+    for number in 1..=(PANEL_PROBLEMS as u32 + 3) {
+        write(
+            dir.path(),
+            &format!("levels/level_{number:03}.ron"),
+            &format!("LevelDefinition(number: {number}, matrix: [])"),
+        );
+    }
+    let mut report = PreflightReport::default();
+    brkrs::systems::preflight::check_levels(&mut report, dir.path(), &levels);
+    assert_eq!(report.problems.len(), PANEL_PROBLEMS + 3);
+
+    let text = preflight_panel_text(&report);
+    assert_eq!(text.lines().count(), 1 + PANEL_PROBLEMS + 1, "{text}");
+    assert!(text.starts_with(&format!(
+        "Asset preflight: {} problem(s) in {} files",
+        PANEL_PROBLEMS + 3,
+        PANEL_PROBLEMS + 3
+    )));
+    assert!(text.contains("[level] "));
+    assert!(text.ends_with("... and 3 more, see the log"));
+}
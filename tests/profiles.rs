@@ -0,0 +1,107 @@
+use bevy::{app::App, prelude::*};
+use brkrs::systems::playlist::{save_playlist, Playlist, PlaylistDefinition, PlaylistPlugin};
+use brkrs::systems::profiles::{
+    load_profile_data, profile_dir, CurrentProfile, ProfileData, ProfileSelected,
+};
+use brkrs::systems::respawn::GameOverRequested;
+use brkrs::systems::scoring::ScoreState;
+use brkrs::systems::{LevelCompleted, ProfilesPlugin};
+
+/// Removes the profile directory when the test ends, even on failure.
+struct ProfileDirGuard(String);
+
+impl Drop for ProfileDirGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(profile_dir(&self.0));
+    }
+}
+
+fn profile_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins((PlaylistPlugin, ProfilesPlugin));
+    app.update();
+    app
+}
+
+fn select(app: &mut App, name: &str) {
+    app.world_mut().write_message(ProfileSelected {
+        name: name.to_string(),
+    });
+    app.update();
+}
+
+#[test]
+fn progress_and_high_scores_are_saved_per_profile() {
+    let name = format!("Progress {}", std::process::id());
+    let _guard = ProfileDirGuard(name.clone());
+    let mut app = profile_app();
+
+    select(&mut app, &name);
+    assert_eq!(
+        app.world().resource::<CurrentProfile>().name.as_deref(),
+        Some(name.as_str())
+    );
+    assert!(
+        profile_dir(&name).is_dir(),
+        "selecting a profile creates it"
+    );
+
+    app.world_mut().trigger(LevelCompleted { level_index: 4 });
+    app.insert_resource(ScoreState {
+        current_score: 1200,
+        ..Default::default()
+    });
+    app.world_mut()
+        .write_message(GameOverRequested { remaining_lives: 0 });
+    app.update();
+
+    let data = app.world().resource::<ProfileData>().clone();
+    assert_eq!(data.highest_level, 4);
+    assert_eq!(data.stats.levels_completed, 1);
+    assert_eq!(data.stats.games_over, 1);
+    assert_eq!(data.high_scores, vec![1200]);
+
+    let saved = load_profile_data(&name);
+    assert_eq!(saved.highest_level, 4);
+    assert_eq!(saved.high_scores, vec![1200]);
+}
+
+#[test]
+fn switching_profile_loads_its_own_data_and_playlist() {
+    let first = format!("First {}", std::process::id());
+    let second = format!("Second {}", std::process::id());
+    let _guards = (
+        ProfileDirGuard(first.clone()),
+        ProfileDirGuard(second.clone()),
+    );
+    let mut app = profile_app();
+
+    select(&mut app, &first);
+    app.world_mut().trigger(LevelCompleted { level_index: 7 });
+    save_playlist(
+        &PlaylistDefinition {
+            name: "first cup".into(),
+            levels: vec![3, 7],
+        },
+        Some(app.world().resource::<CurrentProfile>()),
+    )
+    .unwrap();
+    app.update();
+
+    select(&mut app, &second);
+    assert_eq!(app.world().resource::<ProfileData>().highest_level, 0);
+    assert!(app
+        .world()
+        .resource::<Playlist>()
+        .definition
+        .levels
+        .is_empty());
+
+    select(&mut app, &first);
+    assert_eq!(app.world().resource::<ProfileData>().highest_level, 7);
+    assert_eq!(
+        app.world().resource::<Playlist>().definition.levels,
+        vec![3, 7]
+    );
+}
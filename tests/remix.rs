@@ -0,0 +1,71 @@
+use bevy::{app::App, input::InputPlugin, prelude::*};
+use bevy_rapier3d::prelude::RapierConfiguration;
+use brkrs::gameplay_config::{GameplayConfig, RemixConfig};
+use brkrs::level_loader::{LevelAdvanceState, LevelLoaderPlugin};
+use brkrs::systems::remix::RemixPlugin;
+use brkrs::systems::respawn::SpawnPoints;
+use brkrs::systems::LevelSwitchPlugin;
+use brkrs::{Brick, BrickGridPos, BrickTypeId, GameProgress};
+
+fn remix_test_app(remix: RemixConfig) -> App {
+    std::env::set_var("BK_LEVEL", "1");
+    let mut app = App::new();
+    app.insert_resource(brkrs::physics_config::BallPhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::PaddlePhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    app.add_plugins((MinimalPlugins, InputPlugin));
+    app.insert_resource(GameProgress::default());
+    app.insert_resource(LevelAdvanceState::default());
+    app.insert_resource(SpawnPoints::default());
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(GameplayConfig {
+        remix,
+        ..Default::default()
+    });
+    app.world_mut().spawn(RapierConfiguration::new(1.0));
+    app.add_plugins((LevelSwitchPlugin, LevelLoaderPlugin, RemixPlugin));
+    app.update();
+    app.update();
+    app
+}
+
+fn brick_types(app: &mut App) -> Vec<(BrickGridPos, u8)> {
+    let world = app.world_mut();
+    let mut types: Vec<(BrickGridPos, u8)> = world
+        .query_filtered::<(&BrickGridPos, &BrickTypeId), With<Brick>>()
+        .iter(world)
+        .map(|(pos, type_id)| (*pos, type_id.0))
+        .collect();
+    types.sort_by_key(|(pos, _)| (pos.row, pos.col));
+    types
+}
+
+#[test]
+fn full_remix_replaces_every_simple_brick() {
+    let mut app = remix_test_app(RemixConfig {
+        enabled: true,
+        fraction: 1.0,
+        seed: Some(3),
+        bricks: vec![11],
+    });
+    let types = brick_types(&mut app);
+    assert!(!types.is_empty());
+    assert!(types.iter().all(|(_, type_id)| *type_id == 11));
+}
+
+#[test]
+fn remix_is_off_by_default_and_reproducible_when_on() {
+    let mut plain = remix_test_app(RemixConfig::default());
+    assert!(brick_types(&mut plain).iter().all(|(_, t)| *t == 20));
+
+    let remix = RemixConfig {
+        enabled: true,
+        fraction: 0.5,
+        seed: Some(42),
+        ..RemixConfig::default()
+    };
+    let first = brick_types(&mut remix_test_app(remix.clone()));
+    let second = brick_types(&mut remix_test_app(remix));
+    assert_eq!(first, second);
+}
@@ -28,12 +28,8 @@ fn level_with_markers(
     }
     LevelDefinition {
         number: 1,
-        gravity: None,
         matrix,
-        description: None,
-        author: None,
-        #[cfg(feature = "texture_manifest")]
-        presentation: None,
+        ..Default::default()
     }
 }
 
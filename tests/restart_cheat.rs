@@ -131,3 +131,32 @@ fn restart_with_cheat_inactive_blocked_and_beeps() {
     let beeps = app.world().resource::<BeepCount>();
     assert!(beeps.0 >= 1, "Blocked restart should emit a UI beep");
 }
+
+#[test]
+fn restart_drops_points_from_the_current_attempt() {
+    use brkrs::systems::scoring::ScoreState;
+
+    let mut app = make_app();
+    app.add_plugins(CheatModePlugin);
+    app.world_mut()
+        .resource_mut::<brkrs::systems::cheat_mode::CheatModeState>()
+        .active = true;
+    // 1000 points carried over from earlier levels, 250 earned in this attempt
+    app.insert_resource(ScoreState {
+        current_score: 1250,
+        level_number: Some(1),
+        level_start_score: 1000,
+        ..Default::default()
+    });
+
+    {
+        let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        input.press(KeyCode::KeyR);
+    }
+    app.update();
+    app.update();
+
+    let score = app.world().resource::<ScoreState>();
+    assert_eq!(score.current_score, 1000);
+    assert_eq!(score.level_points(), 0);
+}
@@ -277,3 +277,149 @@ fn unified_brick_destroyed_signal_consumed_by_scoring() {
         "Scoring system should consume unified BrickDestroyed message"
     );
 }
+
+fn chain_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_message::<brkrs::signals::BrickDestroyed>();
+    app.add_message::<brkrs::systems::scoring::ChainBonusAwarded>();
+    app.insert_resource(brkrs::systems::scoring::ScoreState::default());
+    app.init_resource::<brkrs::systems::scoring::ChainTracker>();
+    app.add_systems(Update, brkrs::systems::scoring::track_brick_chains);
+    app
+}
+
+fn destroy_bricks(app: &mut App, types: &[u8]) {
+    let mut msgs = app
+        .world_mut()
+        .resource_mut::<Messages<brkrs::signals::BrickDestroyed>>();
+    for (i, brick_type) in types.iter().enumerate() {
+        msgs.write(brkrs::signals::BrickDestroyed {
+            brick_entity: Entity::from_raw_u32(i as u32 + 1).expect("entity id should construct"),
+            brick_type: *brick_type,
+            destroyed_by: None,
+        });
+    }
+}
+
+#[test]
+fn same_type_chain_awards_bonus_from_third_brick() {
+    use brkrs::systems::scoring::{chain_bonus_points, ChainBonusAwarded, CHAIN_BONUS_STEP};
+
+    let mut app = chain_test_app();
+    // Interleaved types chain independently
+    destroy_bricks(&mut app, &[20, 20, 10, 20, 20]);
+    app.update();
+
+    let score = app
+        .world()
+        .resource::<brkrs::systems::scoring::ScoreState>()
+        .current_score;
+    assert_eq!(score, chain_bonus_points(3) + chain_bonus_points(4));
+    assert_eq!(chain_bonus_points(3), CHAIN_BONUS_STEP);
+
+    let msgs = app.world().resource::<Messages<ChainBonusAwarded>>();
+    let mut cursor = msgs.get_cursor();
+    let lengths: Vec<u32> = cursor.read(msgs).map(|b| b.chain_length).collect();
+    assert_eq!(lengths, vec![3, 4]);
+}
+
+#[test]
+fn chain_breaks_after_window() {
+    use bevy::time::TimeUpdateStrategy;
+    use brkrs::systems::scoring::CHAIN_WINDOW_SECS;
+
+    let mut app = chain_test_app();
+    destroy_bricks(&mut app, &[20, 20]);
+    app.update();
+
+    // Let the window lapse (virtual time clamps each frame to 0.25s)
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(
+        std::time::Duration::from_secs_f32(0.25),
+    ));
+    for _ in 0..((CHAIN_WINDOW_SECS / 0.25) as usize + 2) {
+        app.update();
+    }
+    destroy_bricks(&mut app, &[20]);
+    app.update();
+
+    let score = app
+        .world()
+        .resource::<brkrs::systems::scoring::ScoreState>()
+        .current_score;
+    assert_eq!(score, 0, "a broken chain must not award a bonus");
+}
+
+fn level_score_app(level: u32) -> App {
+    let mut app = test_app();
+    app.insert_resource(level_at(level));
+    app.add_systems(
+        Update,
+        brkrs::systems::scoring::track_level_scores
+            .before(brkrs::systems::scoring::award_points_system),
+    );
+    app
+}
+
+fn level_at(number: u32) -> brkrs::level_loader::CurrentLevel {
+    let def = ron::de::from_str(&format!("LevelDefinition(number: {number}, matrix: [])"))
+        .expect("level definition should parse");
+    brkrs::level_loader::CurrentLevel(def)
+}
+
+#[test]
+fn score_persists_across_level_advance() {
+    use brkrs::systems::scoring::ScoreState;
+
+    let mut app = level_score_app(1);
+    destroy_bricks(&mut app, &[10, 10]); // 2 x 50
+    app.update();
+
+    app.insert_resource(level_at(2));
+    destroy_bricks(&mut app, &[20]); // 25, counted on level 2
+    app.update();
+
+    let score = app.world().resource::<ScoreState>();
+    assert_eq!(score.current_score, 125);
+    assert_eq!(score.level_number, Some(2));
+    assert_eq!(score.level_points(), 25);
+    assert_eq!(score.level_scores.get(&1), Some(&100));
+}
+
+#[test]
+fn level_restart_drops_only_the_current_attempt() {
+    use brkrs::systems::scoring::ScoreState;
+
+    let mut app = level_score_app(1);
+    destroy_bricks(&mut app, &[10]);
+    app.update();
+    app.insert_resource(level_at(2));
+    destroy_bricks(&mut app, &[20, 20]);
+    app.update();
+
+    let dropped = app.world_mut().resource_mut::<ScoreState>().restart_level();
+    assert_eq!(dropped, 50);
+    // Same level number after the restart: the attempt start is kept
+    app.update();
+    let score = app.world().resource::<ScoreState>();
+    assert_eq!(score.current_score, 50);
+    assert_eq!(score.level_start_score, 50);
+}
+
+#[test]
+fn campaign_restart_resets_score_and_bookkeeping() {
+    use brkrs::systems::scoring::ScoreState;
+
+    let mut app = level_score_app(1);
+    destroy_bricks(&mut app, &[10]);
+    app.update();
+    app.insert_resource(level_at(2));
+    app.update();
+
+    app.world_mut().resource_mut::<ScoreState>().reset();
+    let score = app.world().resource::<ScoreState>();
+    assert_eq!(score.current_score, 0);
+    assert_eq!(score.last_milestone_reached, 0);
+    assert!(score.level_scores.is_empty());
+    assert_eq!(score.level_number, None);
+}
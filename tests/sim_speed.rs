@@ -0,0 +1,176 @@
+use bevy::prelude::*;
+use bevy::window::WindowMode;
+use bevy::MinimalPlugins;
+use bevy_rapier3d::prelude::{RapierConfiguration, TimestepMode};
+
+use brkrs::gameplay_config::{GameSpeed, GameplayConfig};
+use brkrs::pause::PauseState;
+use brkrs::systems::cheat_mode::CheatModeState;
+use brkrs::systems::display::DisplaySettings;
+use brkrs::systems::sim_speed::{timestep_for, timestep_for_speed, BASE_MAX_DT};
+use brkrs::systems::survival::SurvivalMode;
+use brkrs::systems::{SimSpeed, SimSpeedPlugin};
+
+fn speed_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ButtonInput::<KeyCode>::default());
+    app.insert_resource(CheatModeState {
+        active: true,
+        activated_at: None,
+    });
+    app.add_plugins(SimSpeedPlugin);
+    app.update();
+    app
+}
+
+fn press(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+    app.update();
+    let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keyboard.release(key);
+    keyboard.clear();
+}
+
+#[test]
+fn speed_keys_scale_the_virtual_clock_and_physics_step() {
+    let mut app = speed_app();
+
+    press(&mut app, KeyCode::Digit1);
+    assert_eq!(app.world().resource::<SimSpeed>().speed, 0.25);
+    assert_eq!(
+        app.world().resource::<Time<Virtual>>().relative_speed(),
+        0.25
+    );
+    assert_eq!(
+        *app.world().resource::<TimestepMode>(),
+        timestep_for_speed(0.25)
+    );
+
+    press(&mut app, KeyCode::Digit4);
+    assert_eq!(
+        app.world().resource::<Time<Virtual>>().relative_speed(),
+        2.0
+    );
+    assert_eq!(
+        *app.world().resource::<TimestepMode>(),
+        TimestepMode::Variable {
+            max_dt: BASE_MAX_DT * 2.0,
+            time_scale: 1.0,
+            substeps: 2,
+        }
+    );
+
+    // Leaving cheat mode restores normal speed
+    app.world_mut().resource_mut::<CheatModeState>().active = false;
+    app.update();
+    assert_eq!(
+        app.world().resource::<Time<Virtual>>().relative_speed(),
+        1.0
+    );
+    assert_eq!(
+        *app.world().resource::<TimestepMode>(),
+        timestep_for_speed(1.0)
+    );
+}
+
+#[test]
+fn frame_step_runs_the_physics_once_while_paused() {
+    let mut app = speed_app();
+    let mut config = RapierConfiguration::new(1.0);
+    config.physics_pipeline_active = false;
+    let context = app.world_mut().spawn(config).id();
+
+    press(&mut app, KeyCode::Period);
+    assert!(
+        !app.world()
+            .get::<RapierConfiguration>(context)
+            .unwrap()
+            .physics_pipeline_active,
+        "no stepping while the game runs"
+    );
+
+    app.insert_resource(PauseState::Paused {
+        window_mode_before_pause: WindowMode::Windowed,
+    });
+    press(&mut app, KeyCode::Period);
+    assert!(
+        app.world()
+            .get::<RapierConfiguration>(context)
+            .unwrap()
+            .physics_pipeline_active
+    );
+    assert!(!app.world().resource::<SimSpeed>().step_requested);
+}
+
+#[test]
+fn frame_cap_stretches_the_physics_step_over_a_whole_frame() {
+    let mut app = speed_app();
+    app.insert_resource(DisplaySettings {
+        frame_cap: Some(30),
+        ..default()
+    });
+    app.update();
+    assert_eq!(
+        *app.world().resource::<TimestepMode>(),
+        TimestepMode::Variable {
+            max_dt: BASE_MAX_DT * 2.0,
+            time_scale: 1.0,
+            substeps: 2,
+        }
+    );
+
+    // Caps above 60 fps keep the default step, and speeds stack on top of the cap
+    assert_eq!(timestep_for(1.0, Some(144)), timestep_for_speed(1.0));
+    assert_eq!(
+        timestep_for(2.0, Some(30)),
+        TimestepMode::Variable {
+            max_dt: BASE_MAX_DT * 4.0,
+            time_scale: 1.0,
+            substeps: 4,
+        }
+    );
+}
+
+#[test]
+fn game_speed_scales_casual_play_but_not_survival() {
+    let mut app = speed_app();
+    app.insert_resource(GameplayConfig {
+        game_speed: GameSpeed(80),
+        ..default()
+    });
+    app.update();
+    assert_eq!(app.world().resource::<SimSpeed>().effective(), 0.8);
+    assert_eq!(
+        app.world().resource::<Time<Virtual>>().relative_speed(),
+        0.8
+    );
+
+    // Cheat speeds stack on top of the game speed
+    press(&mut app, KeyCode::Digit2);
+    assert_eq!(
+        app.world().resource::<Time<Virtual>>().relative_speed(),
+        0.4
+    );
+
+    app.world_mut().resource_mut::<GameplayConfig>().game_speed = GameSpeed(120);
+    press(&mut app, KeyCode::Digit3);
+    assert_eq!(
+        *app.world().resource::<TimestepMode>(),
+        timestep_for_speed(1.2)
+    );
+
+    // Survival runs are ranked and always play at 100%
+    app.insert_resource(SurvivalMode { active: true });
+    app.update();
+    assert_eq!(
+        app.world().resource::<Time<Virtual>>().relative_speed(),
+        1.0
+    );
+    assert_eq!(
+        *app.world().resource::<TimestepMode>(),
+        timestep_for_speed(1.0)
+    );
+}
@@ -0,0 +1,83 @@
+//! Switching sound packs reloads the audio asset handles.
+
+use bevy::prelude::*;
+
+use brkrs::systems::audio::AudioAssets;
+use brkrs::systems::sound_packs::{switch_sound_pack, SoundPacks};
+use brkrs::systems::themes::{ActiveTheme, ThemePack};
+use brkrs::systems::{AudioConfig, SoundType};
+
+fn sound_pack_app() -> App {
+    let mut app = App::new();
+    // Without `AudioPlugin`, which would save the config
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<AudioSource>()
+        .init_resource::<AudioAssets>()
+        .add_systems(Update, switch_sound_pack);
+    // The packs use the bundled default sounds
+    let packs: SoundPacks = ron::de::from_str(
+        r#"(packs: [
+            (id: "default", name: "Standard", folder: "audio"),
+            (id: "calm", name: "Calm", folder: "audio"),
+            (id: "festive", name: "Festive", folder: "audio/"),
+        ])"#,
+    )
+    .unwrap();
+    app.insert_resource(packs);
+    app.insert_resource(AudioConfig::default());
+    app.update();
+    app
+}
+
+fn brick_sound_path(app: &App) -> Option<String> {
+    app.world()
+        .resource::<AudioAssets>()
+        .get(SoundType::BrickDestroy)
+        .and_then(|handle| handle.path())
+        .map(|path| path.to_string())
+}
+
+#[test]
+fn the_configured_pack_is_loaded_without_restart() {
+    let mut app = sound_pack_app();
+    assert_eq!(app.world().resource::<AudioAssets>().pack, None);
+
+    app.world_mut().resource_mut::<AudioConfig>().sound_pack = Some("calm".to_string());
+    app.update();
+    let assets = app.world().resource::<AudioAssets>();
+    assert_eq!(assets.pack.as_deref(), Some("calm"));
+    assert!(assets.get(SoundType::MerkabaLoop).is_some());
+    assert_eq!(
+        brick_sound_path(&app).as_deref(),
+        Some("audio/brick_destroy.ogg")
+    );
+
+    app.world_mut().resource_mut::<AudioConfig>().sound_pack = None;
+    app.update();
+    assert_eq!(app.world().resource::<AudioAssets>().pack, None);
+    assert_eq!(
+        brick_sound_path(&app).as_deref(),
+        Some("audio/brick_destroy.ogg")
+    );
+}
+
+#[test]
+fn a_theme_pack_overrides_the_configured_pack() {
+    let mut app = sound_pack_app();
+    app.world_mut().resource_mut::<AudioConfig>().sound_pack = Some("calm".to_string());
+    let theme: ThemePack =
+        ron::de::from_str(r#"(id: "winter", sound_pack: Some("festive"))"#).unwrap();
+    app.insert_resource(ActiveTheme(Some(theme)));
+    app.update();
+    assert_eq!(
+        app.world().resource::<AudioAssets>().pack.as_deref(),
+        Some("festive")
+    );
+
+    app.insert_resource(ActiveTheme(None));
+    app.update();
+    assert_eq!(
+        app.world().resource::<AudioAssets>().pack.as_deref(),
+        Some("calm")
+    );
+}
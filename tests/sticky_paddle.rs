@@ -0,0 +1,133 @@
+//! The catch capsule makes balls stick to the paddle until a click (or the timeout) launches them.
+
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::{ColliderDisabled, ExternalImpulse, Velocity};
+use brkrs::physics_config::PaddlePhysicsConfig;
+use brkrs::systems::powerups::{PowerupCapsule, PowerupKind, PowerupPlugin};
+use brkrs::systems::sticky_paddle::{
+    BallStuck, StickyPaddle, StickyPaddlePlugin, STICKY_LAUNCH_SECS, STICKY_MIN_LAUNCH_SPEED,
+};
+use brkrs::{on_paddle_ball_hit, Ball, BallHit, Paddle};
+
+use std::time::Duration;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins((PowerupPlugin, StickyPaddlePlugin))
+        .insert_resource(PaddlePhysicsConfig::default())
+        .init_resource::<ButtonInput<MouseButton>>()
+        .add_observer(on_paddle_ball_hit);
+    app.update();
+    app
+}
+
+fn advance(app: &mut App, secs: f32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        secs,
+    )));
+    app.update();
+}
+
+/// A lying paddle at `x`, along Z, with the catch effect.
+fn sticky_paddle(app: &mut App, x: f32) -> Entity {
+    let transform = Transform::from_xyz(x, 2.0, 0.0)
+        .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2));
+    let paddle = app.world_mut().spawn((Paddle, transform)).id();
+    app.world_mut().spawn((
+        PowerupCapsule {
+            kind: PowerupKind::Catch,
+        },
+        Transform::from_translation(transform.translation),
+    ));
+    advance(app, 0.016);
+    assert!(app.world().entity(paddle).contains::<StickyPaddle>());
+    paddle
+}
+
+/// A ball at `at` moving down the field, hit by the paddle.
+fn hit_ball(app: &mut App, at: Vec3) -> Entity {
+    let ball = app
+        .world_mut()
+        .spawn((
+            Ball,
+            Transform::from_translation(at),
+            Velocity::linear(Vec3::new(15.0, 0.0, 0.0)),
+            ExternalImpulse::default(),
+        ))
+        .id();
+    app.world_mut().trigger(BallHit {
+        impulse: Vec3::new(0.0, 0.0, -50.0),
+        ball,
+    });
+    advance(app, 0.016);
+    ball
+}
+
+#[test]
+fn ball_rides_the_paddle_until_clicked() {
+    let mut app = test_app();
+    let paddle = sticky_paddle(&mut app, 10.0);
+    let ball = hit_ball(&mut app, Vec3::new(9.0, 2.0, 0.5));
+
+    let entity = app.world().entity(ball);
+    assert!(entity.contains::<BallStuck>());
+    assert!(entity.contains::<ColliderDisabled>());
+    assert_eq!(entity.get::<Velocity>().unwrap().linvel, Vec3::ZERO);
+    // No english for a caught ball
+    assert_eq!(entity.get::<ExternalImpulse>().unwrap().impulse, Vec3::ZERO);
+
+    app.world_mut()
+        .get_mut::<Transform>(paddle)
+        .unwrap()
+        .translation
+        .z = -2.0;
+    advance(&mut app, 0.016);
+    let at = app.world().get::<Transform>(ball).unwrap().translation;
+    assert!(at.distance(Vec3::new(9.0, 2.0, -1.5)) < 1e-4, "{at:?}");
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(MouseButton::Left);
+    advance(&mut app, 0.016);
+    let entity = app.world().entity(ball);
+    assert!(!entity.contains::<BallStuck>());
+    assert!(!entity.contains::<ColliderDisabled>());
+    let launched = entity.get::<Velocity>().unwrap().linvel;
+    assert!(launched.x < 0.0, "{launched:?}");
+    assert!((launched.length() - 15.0).abs() < 1e-3);
+}
+
+#[test]
+fn stuck_ball_launches_by_itself() {
+    let mut app = test_app();
+    sticky_paddle(&mut app, 10.0);
+    let ball = hit_ball(&mut app, Vec3::new(9.0, 2.0, 0.0));
+    assert!(app.world().entity(ball).contains::<BallStuck>());
+
+    let steps = (STICKY_LAUNCH_SECS / 0.2).ceil() as usize + 1;
+    for _ in 0..steps {
+        advance(&mut app, 0.2);
+    }
+    let entity = app.world().entity(ball);
+    assert!(!entity.contains::<BallStuck>());
+    let launched = entity.get::<Velocity>().unwrap().linvel;
+    assert!(launched.x < 0.0);
+    assert!(launched.length() >= STICKY_MIN_LAUNCH_SPEED);
+}
+
+#[test]
+fn plain_paddle_does_not_catch() {
+    let mut app = test_app();
+    let transform = Transform::from_xyz(10.0, 2.0, 0.0);
+    app.world_mut().spawn((Paddle, transform));
+    let ball = hit_ball(&mut app, Vec3::new(9.0, 2.0, 0.0));
+
+    let entity = app.world().entity(ball);
+    assert!(!entity.contains::<BallStuck>());
+    assert_ne!(entity.get::<ExternalImpulse>().unwrap().impulse, Vec3::ZERO);
+}
@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::Velocity;
+use brkrs::gameplay_config::SurvivalConfig;
+use brkrs::launch::SURVIVAL_ARENA_PATH;
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::level_clock::LevelClock;
+use brkrs::systems::survival::{SurvivalPlugin, SurvivalRun};
+use brkrs::systems::waves::{WaveSchedule, WaveTrigger, WavesPlugin};
+use brkrs::{Ball, BallFrozen, Brick, LaunchOptions};
+
+fn survival_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins((WavesPlugin, SurvivalPlugin));
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    app.insert_resource(LaunchOptions {
+        survival: true,
+        ..default()
+    });
+    app.insert_resource(LevelClock::default());
+    let def: LevelDefinition =
+        ron::de::from_str("LevelDefinition(number: 900, matrix: [])").unwrap();
+    app.insert_resource(CurrentLevel(def));
+    app.update();
+    app
+}
+
+fn set_clock(app: &mut App, secs: u64) {
+    app.world_mut().resource_mut::<LevelClock>().elapsed = Duration::from_secs(secs);
+}
+
+fn bricks(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(), With<Brick>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn bundled_arena_has_survival_waves() {
+    let content = std::fs::read_to_string(SURVIVAL_ARENA_PATH).unwrap();
+    let def: LevelDefinition = ron::de::from_str(&content).unwrap();
+    assert!(!def.waves.is_empty());
+    assert!(def.waves.iter().all(|wave| wave.validate().is_ok()));
+}
+
+#[test]
+fn a_wave_is_always_queued() {
+    let mut app = survival_test_app();
+    let schedule = app.world().resource::<WaveSchedule>();
+    assert_eq!(schedule.pending(), 1);
+    assert_eq!(schedule.waves[0].trigger, WaveTrigger::AfterSeconds(15.0));
+
+    // Keep the field from counting as cleared, which would bring the wave in early
+    app.world_mut()
+        .spawn((Brick, brkrs::CountsTowardsCompletion));
+    set_clock(&mut app, 5);
+    app.update();
+    assert_eq!(bricks(&mut app), 1);
+
+    set_clock(&mut app, 15);
+    app.update();
+    assert_eq!(bricks(&mut app), 1 + 20, "the default row of simple bricks");
+    let schedule = app.world().resource::<WaveSchedule>();
+    assert_eq!(schedule.pending(), 1, "the next wave is queued at once");
+    assert_eq!(schedule.waves[1].trigger, WaveTrigger::AfterSeconds(30.0));
+}
+
+#[test]
+fn balls_get_faster_every_stage() {
+    let mut app = survival_test_app();
+    let config = SurvivalConfig::default();
+    let moving = app
+        .world_mut()
+        .spawn((Ball, Velocity::linear(Vec3::new(1.0, 0.0, 0.0))))
+        .id();
+    let waiting = app.world_mut().spawn((Ball, Velocity::zero())).id();
+    let frozen = app
+        .world_mut()
+        .spawn((Ball, BallFrozen, Velocity::linear(Vec3::new(1.0, 0.0, 0.0))))
+        .id();
+    set_clock(&mut app, 1);
+    app.update();
+    let speed = |app: &App, ball| app.world().get::<Velocity>(ball).unwrap().linvel.length();
+    assert!((speed(&app, moving) - config.ball_speed_at(0)).abs() < 1e-4);
+    assert_eq!(speed(&app, waiting), 0.0);
+    assert_eq!(speed(&app, frozen), 1.0);
+
+    set_clock(&mut app, 30);
+    app.update();
+    assert_eq!(app.world().resource::<SurvivalRun>().stage, 1);
+    assert!((speed(&app, moving) - config.ball_speed_at(1)).abs() < 1e-4);
+}
+
+#[test]
+fn campaign_levels_keep_their_authored_waves() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins((WavesPlugin, SurvivalPlugin));
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    let def: LevelDefinition = ron::de::from_str(
+        "LevelDefinition(number: 1, matrix: [], waves: [(trigger: BricksBelow(3), matrix: [[20]])])",
+    )
+    .unwrap();
+    app.insert_resource(CurrentLevel(def));
+    app.update();
+    let schedule = app.world().resource::<WaveSchedule>();
+    assert_eq!(schedule.pending(), 1);
+    assert_eq!(schedule.waves[0].trigger, WaveTrigger::BricksBelow(3));
+}
@@ -0,0 +1,128 @@
+use bevy::app::App;
+use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::input::{InputPlugin, InputSystems};
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::systems::tas::{
+    play_tas_frame, record_tas_frame, TasButton, TasMovie, TasPlayback, TasPlugin, TasRecorder,
+};
+use brkrs::LaunchOptions;
+
+const MOVIE: &str = "\
+# restart twice, then nudge the paddle
+seed 7
+level 2
+2 0 0 0 KeyR
+1 0 0 0
+1 3.5 -1 0
+";
+
+fn playback_app(movie: TasMovie) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .insert_resource(LaunchOptions {
+            headless: true,
+            ..default()
+        })
+        .insert_resource(TasPlayback::new(movie))
+        .add_systems(
+            PreUpdate,
+            play_tas_frame
+                .run_if(resource_exists::<TasPlayback>)
+                .after(InputSystems),
+        );
+    app
+}
+
+fn key_state(app: &App, key: KeyCode) -> (bool, bool, bool) {
+    let keys = app.world().resource::<ButtonInput<KeyCode>>();
+    (
+        keys.pressed(key),
+        keys.just_pressed(key),
+        keys.just_released(key),
+    )
+}
+
+#[test]
+fn files_are_read_with_their_seed_level_and_frame_runs() {
+    let movie = TasMovie::parse(MOVIE).unwrap();
+    assert_eq!(movie.seed, Some(7));
+    assert_eq!(movie.level, Some(2));
+    assert_eq!(movie.frames.len(), 4);
+    assert_eq!(movie.frames[1].buttons, vec![TasButton::Key(KeyCode::KeyR)]);
+    assert_eq!(movie.frames[3].mouse, Vec2::new(3.5, -1.0));
+
+    let error = TasMovie::parse("1 0 0 0 KeyZ").unwrap_err();
+    assert!(
+        error.contains("line 1") && error.contains("KeyZ"),
+        "{error}"
+    );
+    assert!(TasMovie::parse("1 0 0").is_err());
+
+    // Menu, intro and slow motion keys can be recorded too
+    for name in ["Enter", "NumpadEnter", "Space", "ArrowRight"] {
+        assert!(TasButton::from_name(name).is_some(), "{name}");
+    }
+}
+
+#[test]
+fn playback_holds_and_releases_keys_frame_by_frame() {
+    let mut app = playback_app(TasMovie::parse(MOVIE).unwrap());
+
+    app.update();
+    assert_eq!(key_state(&app, KeyCode::KeyR), (true, true, false));
+    app.update();
+    assert_eq!(key_state(&app, KeyCode::KeyR), (true, false, false));
+    app.update();
+    assert_eq!(key_state(&app, KeyCode::KeyR), (false, false, true));
+    app.update();
+    assert_eq!(key_state(&app, KeyCode::KeyR), (false, false, false));
+    assert_eq!(
+        app.world().resource::<AccumulatedMouseMotion>().delta,
+        Vec2::new(3.5, -1.0)
+    );
+
+    // Out of frames: the headless game exits
+    app.update();
+    assert!(app.world().get_resource::<TasPlayback>().is_none());
+    assert_eq!(app.should_exit(), Some(AppExit::Success));
+}
+
+#[test]
+fn recording_a_playback_gives_the_same_file() {
+    let movie = TasMovie::parse(MOVIE).unwrap();
+    let mut app = playback_app(movie.clone());
+    app.insert_resource(TasRecorder::new("unused.tas".into()))
+        .add_systems(
+            PreUpdate,
+            record_tas_frame
+                .run_if(resource_exists::<TasRecorder>)
+                .after(play_tas_frame),
+        );
+    for _ in 0..movie.frames.len() {
+        app.update();
+    }
+    let recorded = &app.world().resource::<TasRecorder>().movie;
+    assert_eq!(recorded.frames, movie.frames);
+}
+
+#[test]
+fn replay_option_plays_back_a_recorded_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.tas");
+    std::fs::write(&path, MOVIE).unwrap();
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .insert_resource(LaunchOptions {
+            replay: Some(path),
+            ..default()
+        })
+        .add_plugins(TasPlugin);
+
+    let playback = app.world().resource::<TasPlayback>();
+    assert_eq!(playback.movie, TasMovie::parse(MOVIE).unwrap());
+    let launch = app.world().resource::<LaunchOptions>();
+    assert_eq!((launch.seed, launch.level), (Some(7), Some(2)));
+}
@@ -0,0 +1,202 @@
+use bevy::{app::App, prelude::*, MinimalPlugins};
+use bevy_rapier3d::prelude::CollisionEvent;
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+
+use brkrs::launch::LaunchOptions;
+use brkrs::level_format::SIMPLE_BRICK;
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::respawn::{LifeLossCause, LifeLostEvent, SpawnTransform};
+use brkrs::systems::scoring::{chain_bonus_points, ChainBonusAwarded, ChainTracker, ScoreState};
+use brkrs::systems::team_bricks::{
+    BallTeam, Team, TeamBrick, TeamBricksDef, TeamBricksPlugin, TeamScores, TeamStripe, VersusMode,
+};
+use brkrs::{Ball, Brick, BrickGridPos, BrickTypeId, CountsTowardsCompletion};
+
+/// Level with four simple bricks: Red at (0, 0), Blue at (0, 1) and (0, 2), (0, 3) untagged.
+fn race_level() -> LevelDefinition {
+    let mut def: LevelDefinition =
+        ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+    def.matrix = vec![vec![SIMPLE_BRICK; 4]];
+    def.teams = vec![
+        TeamBricksDef {
+            team: Team::Red,
+            cells: vec![(0, 0)],
+        },
+        TeamBricksDef {
+            team: Team::Blue,
+            cells: vec![(0, 1), (0, 2)],
+        },
+    ];
+    def
+}
+
+fn team_test_app() -> (App, [Entity; 4]) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_message::<CollisionEvent>();
+    app.add_message::<brkrs::signals::BrickDestroyed>();
+    app.add_message::<ChainBonusAwarded>();
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(CurrentLevel(race_level()));
+    app.init_resource::<ScoreState>();
+    app.init_resource::<ChainTracker>();
+    brkrs::register_brick_collision_systems(&mut app);
+    app.add_plugins(TeamBricksPlugin);
+    app.add_systems(
+        Update,
+        (
+            brkrs::systems::scoring::award_points_system,
+            brkrs::systems::scoring::track_brick_chains,
+        )
+            .chain()
+            .after(brkrs::despawn_marked_entities),
+    );
+    let bricks = [0, 1, 2, 3].map(|col| {
+        app.world_mut()
+            .spawn((
+                Brick,
+                BrickTypeId(SIMPLE_BRICK),
+                BrickGridPos { row: 0, col },
+                CountsTowardsCompletion,
+                Transform::default(),
+            ))
+            .id()
+    });
+    app.update();
+    app.update();
+    (app, bricks)
+}
+
+fn hit(app: &mut App, brick: Entity, team: Option<Team>) {
+    let ball = app.world_mut().spawn(Ball).id();
+    if let Some(team) = team {
+        app.world_mut().entity_mut(ball).insert(BallTeam(team));
+    }
+    app.world_mut().write_message(CollisionEvent::Started(
+        ball,
+        brick,
+        CollisionEventFlags::empty(),
+    ));
+    app.update();
+}
+
+#[test]
+fn bricks_get_their_team_and_stripe() {
+    let (mut app, bricks) = team_test_app();
+    let teams = bricks.map(|brick| app.world().get::<TeamBrick>(brick).map(|t| t.0));
+    assert_eq!(
+        teams,
+        [Some(Team::Red), Some(Team::Blue), Some(Team::Blue), None]
+    );
+    let world = app.world_mut();
+    let stripes = world
+        .query_filtered::<(), With<TeamStripe>>()
+        .iter(world)
+        .count();
+    assert_eq!(stripes, 3);
+}
+
+#[test]
+fn team_bricks_only_score_for_their_own_team() {
+    let (mut app, [red, blue, blue_too, plain]) = team_test_app();
+
+    // A ball without a team plays for Red: the Blue brick breaks but scores nothing
+    hit(&mut app, blue, None);
+    assert!(app.world().get_entity(blue).is_err());
+    assert_eq!(app.world().resource::<ScoreState>().current_score, 0);
+
+    hit(&mut app, red, None);
+    hit(&mut app, blue_too, Some(Team::Blue));
+    // Untagged bricks score for whoever breaks them
+    hit(&mut app, plain, Some(Team::Blue));
+
+    // Three scoring bricks of one type make a chain; the off-color brick does not count
+    let chain = chain_bonus_points(3);
+    let scores = *app.world().resource::<TeamScores>();
+    assert_eq!(
+        scores,
+        TeamScores {
+            red: 25,
+            blue: 50 + chain
+        }
+    );
+    assert_eq!(
+        app.world().resource::<ScoreState>().current_score,
+        75 + chain
+    );
+}
+
+#[test]
+fn loading_a_level_resets_the_race() {
+    let (mut app, [red, ..]) = team_test_app();
+    hit(&mut app, red, None);
+    assert_eq!(app.world().resource::<TeamScores>().red, 25);
+
+    app.insert_resource(CurrentLevel(race_level()));
+    app.update();
+    assert_eq!(*app.world().resource::<TeamScores>(), TeamScores::default());
+}
+
+fn versus_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(LaunchOptions {
+        versus: true,
+        ..default()
+    });
+    app.add_plugins(TeamBricksPlugin);
+    app.update();
+    app
+}
+
+fn spawn_ball_team(app: &mut App) -> Option<Team> {
+    let ball = app.world_mut().spawn(Ball).id();
+    app.update();
+    app.world().get::<BallTeam>(ball).map(|team| team.0)
+}
+
+fn lose_life(app: &mut App) {
+    let ball = app.world_mut().spawn_empty().id();
+    app.world_mut().write_message(LifeLostEvent {
+        ball,
+        cause: LifeLossCause::LowerGoal,
+        ball_spawn: SpawnTransform::new(Vec3::ZERO, Quat::IDENTITY),
+    });
+}
+
+#[test]
+fn versus_players_take_turns_after_each_lost_life() {
+    let mut app = versus_app();
+    assert!(app.world().resource::<VersusMode>().active);
+    assert_eq!(spawn_ball_team(&mut app), Some(Team::Red));
+
+    // The respawned ball belongs to the other player
+    lose_life(&mut app);
+    assert_eq!(spawn_ball_team(&mut app), Some(Team::Blue));
+    assert_eq!(app.world().resource::<VersusMode>().turn, Team::Blue);
+
+    lose_life(&mut app);
+    assert_eq!(spawn_ball_team(&mut app), Some(Team::Red));
+}
+
+#[test]
+fn versus_scores_carry_over_to_the_next_level() {
+    let mut app = versus_app();
+    app.world_mut()
+        .resource_mut::<TeamScores>()
+        .add(Team::Blue, 300);
+    app.insert_resource(CurrentLevel(race_level()));
+    app.update();
+    assert_eq!(app.world().resource::<TeamScores>().blue, 300);
+}
+
+#[test]
+fn balls_have_no_team_outside_versus_mode() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(LaunchOptions::default());
+    app.add_plugins(TeamBricksPlugin);
+    app.update();
+    assert_eq!(spawn_ball_team(&mut app), None);
+}
@@ -0,0 +1,97 @@
+use std::fs;
+
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::launch::LaunchOptions;
+use brkrs::systems::brick_debris::BrickDebrisConfig;
+use brkrs::systems::textures::loader::{LevelTextureSet, RawTextureManifest, TextureManifest};
+use brkrs::systems::themes::{
+    apply_theme_pack, select_theme_pack, ActiveTheme, ThemePack, ThemePacks, THEME_PACKS_PATH,
+};
+
+fn shipped_packs() -> ThemePacks {
+    ron::de::from_str(&fs::read_to_string(THEME_PACKS_PATH).unwrap()).unwrap()
+}
+
+fn read_manifest(path: &str) -> RawTextureManifest {
+    ron::de::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn id(pack: Option<&ThemePack>) -> Option<&str> {
+    pack.map(|pack| pack.id.as_str())
+}
+
+#[test]
+fn packs_are_picked_by_name_or_by_date() {
+    let packs = shipped_packs();
+    assert_eq!(id(packs.select(None, Some((12, 24)))), Some("winter"));
+    assert_eq!(id(packs.select(None, Some((1, 2)))), Some("winter"));
+    assert_eq!(id(packs.select(None, Some((7, 14)))), None);
+    assert_eq!(id(packs.select(None, None)), None);
+    assert_eq!(
+        id(packs.select(Some("winter"), Some((7, 14)))),
+        Some("winter")
+    );
+    assert_eq!(id(packs.select(Some("off"), Some((12, 24)))), None);
+    assert_eq!(id(packs.select(Some("spooky"), Some((12, 24)))), None);
+}
+
+#[test]
+fn theme_manifests_layer_over_the_base_manifest() {
+    let mut manifest = TextureManifest::from_raw(read_manifest("assets/textures/manifest.ron"));
+    let base_ball = manifest.profiles["ball/default"].albedo_path.clone();
+    manifest.level_overrides.insert(
+        2,
+        LevelTextureSet {
+            level_number: 2,
+            ground_profile: Some("ground/nsTile007".to_string()),
+            ..Default::default()
+        },
+    );
+
+    let mut theme = read_manifest("assets/themes/winter/manifest.ron");
+    theme.level_overrides.push(LevelTextureSet {
+        level_number: 2,
+        ground_profile: Some("ground/default".to_string()),
+        ..Default::default()
+    });
+    manifest.layer(theme);
+
+    assert_eq!(
+        manifest.profiles["ground/default"].albedo_path,
+        "background/nsTile017.png"
+    );
+    assert_eq!(manifest.profiles["ball/default"].albedo_path, base_ball);
+    assert_eq!(
+        manifest.level_overrides[&2].ground_profile.as_deref(),
+        Some("ground/default")
+    );
+}
+
+#[test]
+fn the_chosen_pack_tints_debris() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(shipped_packs())
+        .insert_resource(LaunchOptions {
+            theme: Some("winter".to_string()),
+            ..default()
+        })
+        .init_resource::<ActiveTheme>()
+        .init_resource::<BrickDebrisConfig>()
+        .add_systems(Startup, select_theme_pack)
+        .add_systems(Update, apply_theme_pack);
+    app.update();
+
+    let active = app.world().resource::<ActiveTheme>();
+    assert_eq!(
+        active.0.as_ref().map(|pack| pack.id.as_str()),
+        Some("winter")
+    );
+    assert!(app.world().resource::<BrickDebrisConfig>().color.is_some());
+
+    app.world_mut().resource_mut::<ActiveTheme>().0 = None;
+    app.update();
+    assert_eq!(app.world().resource::<BrickDebrisConfig>().color, None);
+}
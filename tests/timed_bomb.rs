@@ -0,0 +1,88 @@
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::MinimalPlugins;
+
+use bevy_rapier3d::prelude::CollisionEvent;
+use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
+use brkrs::level_format::{BOMB_BRICK, SIMPLE_BRICK, TIMED_BOMB_BRICK};
+use brkrs::systems::explosion::{ExplosionPlugin, FuseTimer, FUSE_SECONDS};
+use brkrs::{Ball, Brick, BrickGridPos, BrickTypeId, CountsTowardsCompletion, MarkedForDespawn};
+
+use std::time::Duration;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(ExplosionPlugin);
+    app.update();
+    app
+}
+
+fn advance(app: &mut App, secs: f32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        secs,
+    )));
+    app.update();
+}
+
+fn spawn_brick(app: &mut App, type_id: u8, row: usize, col: usize) -> Entity {
+    app.world_mut()
+        .spawn((
+            Brick,
+            BrickTypeId(type_id),
+            BrickGridPos { row, col },
+            CountsTowardsCompletion,
+            Transform::default(),
+        ))
+        .id()
+}
+
+fn is_marked(app: &App, entity: Entity) -> bool {
+    app.world().entity(entity).contains::<MarkedForDespawn>()
+}
+
+#[test]
+fn ball_hit_lights_fuse_and_blast_destroys_neighbours() {
+    let mut app = test_app();
+    let ball = app.world_mut().spawn(Ball).id();
+    let bomb = spawn_brick(&mut app, TIMED_BOMB_BRICK, 5, 5);
+    let neighbour = spawn_brick(&mut app, SIMPLE_BRICK, 6, 6);
+    let distant = spawn_brick(&mut app, SIMPLE_BRICK, 5, 8);
+
+    app.world_mut().write_message(CollisionEvent::Started(
+        ball,
+        bomb,
+        CollisionEventFlags::empty(),
+    ));
+    advance(&mut app, 0.016);
+
+    assert!(app.world().entity(bomb).contains::<FuseTimer>());
+    assert!(!is_marked(&app, bomb), "the hit itself must not destroy it");
+    assert!(!is_marked(&app, neighbour));
+
+    // Step past the fuse in 0.2s frames (virtual time clamps larger steps)
+    let frames = (FUSE_SECONDS / 0.2).ceil() as usize + 2;
+    for _ in 0..frames {
+        advance(&mut app, 0.2);
+    }
+
+    assert!(is_marked(&app, bomb));
+    assert!(is_marked(&app, neighbour));
+    assert!(!is_marked(&app, distant));
+}
+
+#[test]
+fn destroyed_bomb_chains_through_lit_and_unlit_bombs() {
+    let mut app = test_app();
+    let bomb = spawn_brick(&mut app, BOMB_BRICK, 2, 2);
+    let timed = spawn_brick(&mut app, TIMED_BOMB_BRICK, 2, 3);
+    let end_of_chain = spawn_brick(&mut app, SIMPLE_BRICK, 2, 4);
+    let untouched = spawn_brick(&mut app, SIMPLE_BRICK, 2, 6);
+
+    app.world_mut().entity_mut(bomb).insert(MarkedForDespawn);
+    advance(&mut app, 0.016);
+
+    assert!(is_marked(&app, timed), "timed bomb detonates in the blast");
+    assert!(is_marked(&app, end_of_chain));
+    assert!(!is_marked(&app, untouched));
+}
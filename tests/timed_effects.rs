@@ -0,0 +1,90 @@
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+
+use bevy::{app::App, prelude::*, time::TimeUpdateStrategy};
+use bevy_rapier3d::prelude::CollisionEvent;
+use brkrs::pause::PauseState;
+use brkrs::systems::paddle_size::{PaddleSizeEffect, PaddleSizePlugin, SizeEffectType};
+use brkrs::systems::powerups::{PowerupKind, PowerupPlugin, ReversedControls};
+use brkrs::systems::timed_effects::{ActiveEffects, TimedEffect};
+use brkrs::Paddle;
+
+fn effects_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins((PowerupPlugin, PaddleSizePlugin))
+        .add_message::<CollisionEvent>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            500,
+        )));
+    app.update();
+    app
+}
+
+fn set_paused(app: &mut App, paused: bool) {
+    let state = if paused {
+        PauseState::Paused {
+            #[cfg(not(target_arch = "wasm32"))]
+            window_mode_before_pause: bevy::window::WindowMode::Windowed,
+        }
+    } else {
+        PauseState::Active
+    };
+    app.insert_resource(state);
+}
+
+fn time_left(app: &App, paddle: Entity) -> (f32, f32) {
+    let entity = app.world().entity(paddle);
+    (
+        entity.get::<PaddleSizeEffect>().unwrap().remaining_secs(),
+        entity.get::<ReversedControls>().unwrap().remaining_secs(),
+    )
+}
+
+#[test]
+fn effect_timers_freeze_while_paused() {
+    let mut app = effects_app();
+    let paddle = app
+        .world_mut()
+        .spawn((
+            Paddle,
+            Transform::default(),
+            PaddleSizeEffect::new(SizeEffectType::Enlarge),
+            ReversedControls::default(),
+        ))
+        .id();
+    app.update();
+    let running = time_left(&app, paddle);
+
+    set_paused(&mut app, true);
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(time_left(&app, paddle), running);
+
+    set_paused(&mut app, false);
+    app.update();
+    let resumed = time_left(&app, paddle);
+    assert!(resumed.0 < running.0 && resumed.1 < running.1);
+}
+
+#[test]
+fn active_effects_list_the_paddle_effects_with_their_time_left() {
+    let mut app = effects_app();
+    app.world_mut().spawn((
+        Paddle,
+        Transform::default(),
+        PaddleSizeEffect::new(SizeEffectType::Shrink),
+        ReversedControls::default(),
+    ));
+    let effects = app
+        .world_mut()
+        .run_system_once(|effects: ActiveEffects| effects.snapshots())
+        .unwrap();
+    let kinds: Vec<_> = effects.iter().map(|effect| effect.kind).collect();
+    assert_eq!(
+        kinds,
+        [PowerupKind::ShrinkPaddle, PowerupKind::ReverseControls]
+    );
+    assert!(effects.iter().all(|effect| effect.remaining_secs > 0.0));
+}
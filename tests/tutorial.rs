@@ -0,0 +1,141 @@
+use bevy::app::App;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::level_loader::LevelDefinition;
+use brkrs::systems::autosave::AutosaveState;
+use brkrs::systems::powerups::{PowerupCollected, PowerupKind};
+use brkrs::systems::profiles::ProfileData;
+use brkrs::systems::tutorial::{
+    is_first_run, paddle_goal_reached, track_tutorial_goals, TutorialGoal, TutorialState,
+    TutorialStep, TUTORIAL_FIRST_LEVEL, TUTORIAL_MOVE_DISTANCE,
+};
+use brkrs::Paddle;
+
+fn step(until: TutorialGoal) -> TutorialStep {
+    TutorialStep {
+        prompt: format!("{until:?}"),
+        until,
+    }
+}
+
+fn tutorial_test_app(steps: Vec<TutorialStep>) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_message::<PowerupCollected>()
+        .insert_resource(TutorialState {
+            level_number: Some(TUTORIAL_FIRST_LEVEL),
+            steps,
+            ..default()
+        })
+        .add_systems(Update, track_tutorial_goals);
+    app.world_mut().spawn((Paddle, Transform::default()));
+    app.update();
+    app
+}
+
+#[test]
+fn paddle_goals_need_enough_movement() {
+    let start = Transform::default();
+    let nudged = Transform::from_xyz(0.0, 0.0, TUTORIAL_MOVE_DISTANCE / 2.0);
+    let moved = Transform::from_xyz(0.0, 0.0, TUTORIAL_MOVE_DISTANCE);
+    assert!(!paddle_goal_reached(
+        TutorialGoal::MovePaddle,
+        &start,
+        &nudged
+    ));
+    assert!(paddle_goal_reached(
+        TutorialGoal::MovePaddle,
+        &start,
+        &moved
+    ));
+
+    let turned = Transform::from_rotation(Quat::from_rotation_y(30f32.to_radians()));
+    assert!(!paddle_goal_reached(
+        TutorialGoal::RotatePaddle,
+        &start,
+        &moved
+    ));
+    assert!(paddle_goal_reached(
+        TutorialGoal::RotatePaddle,
+        &start,
+        &turned
+    ));
+    assert!(!paddle_goal_reached(
+        TutorialGoal::CatchPowerup,
+        &start,
+        &turned
+    ));
+}
+
+#[test]
+fn steps_finish_in_order_when_their_event_happens() {
+    let mut app = tutorial_test_app(vec![
+        step(TutorialGoal::MovePaddle),
+        step(TutorialGoal::CatchPowerup),
+    ]);
+    // A capsule caught before its step does not count
+    app.world_mut()
+        .resource_mut::<Messages<PowerupCollected>>()
+        .write(PowerupCollected {
+            kind: PowerupKind::EnlargePaddle,
+            paddle: Entity::PLACEHOLDER,
+        });
+    app.update();
+    assert_eq!(app.world().resource::<TutorialState>().done, 0);
+
+    let world = app.world_mut();
+    let mut paddle = world.query_filtered::<&mut Transform, With<Paddle>>();
+    paddle.single_mut(world).unwrap().translation.z = TUTORIAL_MOVE_DISTANCE + 0.5;
+    app.update();
+    assert_eq!(app.world().resource::<TutorialState>().done, 1);
+
+    app.world_mut()
+        .resource_mut::<Messages<PowerupCollected>>()
+        .write(PowerupCollected {
+            kind: PowerupKind::EnlargePaddle,
+            paddle: Entity::PLACEHOLDER,
+        });
+    app.update();
+    let state = app.world().resource::<TutorialState>();
+    assert_eq!(state.pending(), 0);
+    assert!(state.current().is_none());
+}
+
+#[test]
+fn first_run_needs_a_fresh_start_at_level_one() {
+    let fresh = AutosaveState::default();
+    let profile = ProfileData::default();
+    assert!(is_first_run(1, false, Some(&fresh), Some(&profile)));
+    assert!(is_first_run(1, false, None, None));
+    assert!(!is_first_run(2, false, Some(&fresh), Some(&profile)));
+    assert!(!is_first_run(1, true, Some(&fresh), Some(&profile)));
+
+    let veteran = ProfileData {
+        highest_level: 3,
+        ..default()
+    };
+    assert!(!is_first_run(1, false, Some(&fresh), Some(&veteran)));
+}
+
+#[test]
+fn bundled_tutorial_levels_script_their_steps() {
+    let goals: Vec<Vec<TutorialGoal>> = (TUTORIAL_FIRST_LEVEL..TUTORIAL_FIRST_LEVEL + 3)
+        .map(|number| {
+            let path = format!("assets/levels/level_{number:03}.ron");
+            let content = std::fs::read_to_string(&path).expect("read tutorial level");
+            let def: LevelDefinition = ron::de::from_str(&content).expect("parse tutorial level");
+            assert_eq!(def.number, number);
+            def.tutorial.iter().map(|step| step.until).collect()
+        })
+        .collect();
+    assert_eq!(
+        goals,
+        vec![
+            vec![TutorialGoal::MovePaddle],
+            vec![TutorialGoal::RotatePaddle],
+            vec![TutorialGoal::CatchPowerup],
+        ]
+    );
+}
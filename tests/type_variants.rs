@@ -108,9 +108,11 @@ fn immediate_spawn_registry_populated_from_manifest() {
             profile_id: "ball/default".to_string(),
             emissive_color: None,
             animation: None,
+            shape: Default::default(),
         }],
         level_overrides: Default::default(),
         level_switch: None,
+        models: Default::default(),
     };
 
     // Rebuild registry from manifest - need to scope borrows properly
@@ -167,9 +169,11 @@ fn runtime_mutation_updates_registry_mapping() {
             profile_id: "ball/default".to_string(),
             emissive_color: None,
             animation: None,
+            shape: Default::default(),
         }],
         level_overrides: Default::default(),
         level_switch: None,
+        models: Default::default(),
     };
 
     // Rebuild registry from manifest
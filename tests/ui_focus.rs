@@ -0,0 +1,183 @@
+//! Keyboard navigation of focusable UI (pause overlay buttons).
+
+use bevy::prelude::*;
+use bevy::window::WindowMode;
+
+use brkrs::gameplay_config::{GameSpeed, GameplayConfig};
+use brkrs::pause::PauseState;
+use brkrs::systems::display::{DisplayMode, DisplaySettings};
+use brkrs::systems::lighting::{LightingSettings, ShadowQuality};
+use brkrs::systems::sound_packs::SoundPacks;
+use brkrs::systems::AudioConfig;
+use brkrs::ui::focus::{activate_focused, highlight_focus, navigate_focus, Focusable, UiFocus};
+use brkrs::ui::pause_overlay::{handle_pause_menu_buttons, PauseMenuButton};
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<UiFocus>()
+        .insert_resource(PauseState::Paused {
+            window_mode_before_pause: WindowMode::Windowed,
+        })
+        .add_systems(
+            Update,
+            (
+                navigate_focus,
+                activate_focused,
+                highlight_focus,
+                handle_pause_menu_buttons,
+            )
+                .chain(),
+        );
+    app
+}
+
+fn spawn_button(app: &mut App, order: i32, action: PauseMenuButton) -> Entity {
+    app.world_mut()
+        .spawn((Button, Focusable::new(order), action))
+        .id()
+}
+
+fn tap(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+    app.update();
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(key);
+    keys.clear();
+}
+
+#[test]
+fn keyboard_moves_focus_and_activates_resume() {
+    let mut app = test_app();
+    let quit = spawn_button(&mut app, 1, PauseMenuButton::Quit);
+    let resume = spawn_button(&mut app, 0, PauseMenuButton::Resume);
+
+    app.update();
+    assert_eq!(app.world().resource::<UiFocus>().focused, Some(resume));
+
+    tap(&mut app, KeyCode::ArrowDown);
+    assert_eq!(app.world().resource::<UiFocus>().focused, Some(quit));
+    app.update();
+    assert!(app.world().entity(quit).contains::<Outline>());
+    assert!(!app.world().entity(resume).contains::<Outline>());
+
+    // Wraps back to Resume, then Enter presses it
+    tap(&mut app, KeyCode::ArrowDown);
+    tap(&mut app, KeyCode::Enter);
+    assert_eq!(*app.world().resource::<PauseState>(), PauseState::Active);
+
+    // The synthetic press is released on the next frame
+    app.update();
+    assert_eq!(
+        app.world().entity(resume).get::<Interaction>(),
+        Some(&Interaction::None)
+    );
+}
+
+#[test]
+fn display_button_cycles_the_window_mode() {
+    let mut app = test_app();
+    app.init_resource::<DisplaySettings>();
+    let display = spawn_button(&mut app, 0, PauseMenuButton::DisplayMode);
+
+    app.update();
+    assert_eq!(app.world().resource::<UiFocus>().focused, Some(display));
+    tap(&mut app, KeyCode::Enter);
+    let settings = app.world().resource::<DisplaySettings>();
+    assert_eq!(settings.mode, DisplayMode::Exclusive);
+    assert_eq!(
+        PauseMenuButton::DisplayMode.label(
+            settings,
+            &LightingSettings::default(),
+            &GameplayConfig::default(),
+            "Standard"
+        ),
+        "Display: Exclusive"
+    );
+    // Changing the settings does not resume the game
+    assert!(matches!(
+        *app.world().resource::<PauseState>(),
+        PauseState::Paused { .. }
+    ));
+}
+
+#[test]
+fn shadows_button_cycles_the_shadow_preset() {
+    let mut app = test_app();
+    app.insert_resource(LightingSettings {
+        shadows: ShadowQuality::Medium,
+        ..Default::default()
+    });
+    spawn_button(&mut app, 0, PauseMenuButton::Shadows);
+
+    app.update();
+    tap(&mut app, KeyCode::Enter);
+    let lighting = app.world().resource::<LightingSettings>();
+    assert_eq!(lighting.shadows, ShadowQuality::High);
+    assert_eq!(
+        PauseMenuButton::Shadows.label(
+            &DisplaySettings::default(),
+            lighting,
+            &GameplayConfig::default(),
+            "Standard"
+        ),
+        "Shadows: High"
+    );
+}
+
+#[test]
+fn game_speed_button_cycles_the_speed() {
+    let mut app = test_app();
+    app.init_resource::<GameplayConfig>();
+    spawn_button(&mut app, 0, PauseMenuButton::GameSpeed);
+
+    app.update();
+    tap(&mut app, KeyCode::Enter);
+    let config = app.world().resource::<GameplayConfig>();
+    assert_eq!(config.game_speed, GameSpeed(110));
+    assert_eq!(
+        PauseMenuButton::GameSpeed.label(
+            &DisplaySettings::default(),
+            &LightingSettings::default(),
+            config,
+            "Standard"
+        ),
+        "Speed: 110%"
+    );
+}
+
+#[test]
+fn sound_pack_button_cycles_the_packs() {
+    let mut app = test_app();
+    let packs: SoundPacks = ron::de::from_str(
+        r#"(packs: [
+            (id: "default", name: "Standard", folder: "audio"),
+            (id: "retro", name: "Retro", folder: "audio/packs/retro"),
+        ])"#,
+    )
+    .unwrap();
+    app.insert_resource(packs).init_resource::<AudioConfig>();
+    spawn_button(&mut app, 0, PauseMenuButton::SoundPack);
+
+    app.update();
+    tap(&mut app, KeyCode::Enter);
+    let config = app.world().resource::<AudioConfig>();
+    assert_eq!(config.sound_pack.as_deref(), Some("retro"));
+    assert_eq!(
+        PauseMenuButton::SoundPack.label(
+            &DisplaySettings::default(),
+            &LightingSettings::default(),
+            &GameplayConfig::default(),
+            "Retro"
+        ),
+        "Sounds: Retro"
+    );
+
+    // Wraps around to the default pack
+    app.update();
+    tap(&mut app, KeyCode::Enter);
+    assert_eq!(app.world().resource::<AudioConfig>().sound_pack, None);
+}
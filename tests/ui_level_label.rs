@@ -20,12 +20,8 @@ use brkrs::ui::level_label::{
 fn test_level(number: u32) -> LevelDefinition {
     LevelDefinition {
         number,
-        gravity: None,
         matrix: vec![],
-        #[cfg(feature = "texture_manifest")]
-        presentation: None,
-        description: None,
-        author: None,
+        ..Default::default()
     }
 }
 
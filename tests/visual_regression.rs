@@ -0,0 +1,312 @@
+//! Screenshot-based visual regression tests.
+//
+// Renders levels headlessly (no window, the main camera draws into an image), captures one frame
+// once the level is spawned and its textures are loaded, and compares it with the golden image in
+// `tests/golden/` within a tolerance. Catches regressions in brick materials, decals and layout
+// math that the ECS-level tests cannot see.
+//
+// Rendering needs a GPU adapter (a software one such as lavapipe works), so the rendering test is
+// ignored by default:
+//
+//     cargo test --test visual_regression -- --ignored
+//
+// A missing golden image fails the test. Set `BK_UPDATE_GOLDEN=1` to write the golden images
+// instead of comparing, for new levels or after an intended visual change. The frames of the last
+// run are kept in `target/visual_regression/` for inspection. CI runs the test on a software
+// renderer (`.github/workflows/visual-regression.yml`).
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use bevy::asset::{LoadState, RenderAssetUsages};
+use bevy::camera::RenderTarget;
+use bevy::image::{CompressedImageFormats, ImageSampler, ImageType};
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy::render::view::screenshot::{save_to_disk, Screenshot, ScreenshotCaptured};
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+use bevy_rapier3d::prelude::*;
+use brkrs::{Brick, MainCamera};
+
+/// Levels rendered by the harness: the first campaign levels and the indestructible-brick level.
+const LEVELS: [u32; 3] = [1, 2, 997];
+const WIDTH: u32 = 640;
+const HEIGHT: u32 = 480;
+const GOLDEN_DIR: &str = "tests/golden";
+const OUTPUT_DIR: &str = "target/visual_regression";
+/// Largest per-channel difference that still counts as the same pixel (driver and dithering
+/// noise).
+const CHANNEL_TOLERANCE: u8 = 8;
+/// Share of pixels allowed to differ beyond `CHANNEL_TOLERANCE`.
+const MAX_DIFFERING_SHARE: f64 = 0.005;
+/// Frames to wait for the level and its textures before giving up.
+const MAX_FRAMES: usize = 600;
+
+/// Result of comparing two RGBA8 images of the same size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ImageDiff {
+    differing_pixels: usize,
+    total_pixels: usize,
+    max_delta: u8,
+}
+
+impl ImageDiff {
+    fn differing_share(&self) -> f64 {
+        self.differing_pixels as f64 / self.total_pixels.max(1) as f64
+    }
+
+    fn within(&self, max_share: f64) -> bool {
+        self.differing_share() <= max_share
+    }
+}
+
+/// Compare the RGB channels of two RGBA8 buffers; alpha is ignored, as golden images are saved
+/// without it.
+fn compare_rgba(
+    expected: &[u8],
+    actual: &[u8],
+    channel_tolerance: u8,
+) -> Result<ImageDiff, String> {
+    if expected.len() != actual.len() || expected.len() % 4 != 0 {
+        return Err(format!(
+            "image sizes differ: {} vs {} bytes",
+            expected.len(),
+            actual.len()
+        ));
+    }
+    let mut diff = ImageDiff {
+        differing_pixels: 0,
+        total_pixels: expected.len() / 4,
+        max_delta: 0,
+    };
+    for (e, a) in expected.chunks_exact(4).zip(actual.chunks_exact(4)) {
+        let delta = (0..3).map(|c| e[c].abs_diff(a[c])).max().unwrap_or(0);
+        diff.max_delta = diff.max_delta.max(delta);
+        if delta > channel_tolerance {
+            diff.differing_pixels += 1;
+        }
+    }
+    Ok(diff)
+}
+
+/// RGBA8 pixels of `image`.
+fn rgba8(image: &Image) -> Result<Vec<u8>, String> {
+    let converted = if image.texture_descriptor.format == TextureFormat::Rgba8UnormSrgb {
+        image.clone()
+    } else {
+        image
+            .convert(TextureFormat::Rgba8UnormSrgb)
+            .ok_or_else(|| format!("cannot convert {:?}", image.texture_descriptor.format))?
+    };
+    converted
+        .data
+        .ok_or_else(|| "image has no pixel data".to_string())
+}
+
+fn load_png(path: &Path) -> Result<Image, String> {
+    let bytes =
+        std::fs::read(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    Image::from_buffer(
+        &bytes,
+        ImageType::Extension("png"),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::default(),
+    )
+    .map_err(|e| format!("failed to decode {}: {e}", path.display()))
+}
+
+/// Headless app drawing the level into an image.
+fn render_app(level: u32) -> (App, Handle<Image>) {
+    std::env::set_var("BK_LEVEL", level.to_string());
+    let mut app = App::new();
+    app.insert_resource(brkrs::physics_config::BallPhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::PaddlePhysicsConfig::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    app.insert_resource(brkrs::GameProgress::default());
+    app.insert_resource(brkrs::level_loader::LevelAdvanceState::default());
+    app.insert_resource(brkrs::systems::respawn::SpawnPoints::default());
+    app.add_plugins(
+        DefaultPlugins
+            .set(ImagePlugin::default_nearest())
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                close_when_requested: false,
+                ..default()
+            })
+            .disable::<WinitPlugin>()
+            .disable::<bevy::log::LogPlugin>(),
+    );
+    app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default());
+    app.add_message::<CollisionEvent>();
+    app.add_plugins(brkrs::systems::LevelSwitchPlugin);
+    app.add_plugins(brkrs::level_loader::LevelLoaderPlugin);
+    #[cfg(feature = "texture_manifest")]
+    app.add_plugins(brkrs::systems::TextureManifestPlugin);
+    app.add_systems(
+        Startup,
+        (
+            brkrs::systems::spawning::spawn_camera,
+            brkrs::systems::spawning::spawn_ground_plane,
+            brkrs::systems::spawning::spawn_light,
+        )
+            .chain(),
+    );
+
+    let target = app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::new_target_texture(
+            WIDTH,
+            HEIGHT,
+            TextureFormat::Rgba8UnormSrgb,
+        ));
+    // Nothing moves between spawn and capture
+    app.world_mut().resource_mut::<Time<Virtual>>().pause();
+    app.update();
+    let world = app.world_mut();
+    let mut cameras = world.query_filtered::<&mut Camera, With<MainCamera>>();
+    for mut camera in cameras.iter_mut(world) {
+        camera.target = RenderTarget::Image(target.clone().into());
+    }
+    (app, target)
+}
+
+/// Whether every texture used by a material has finished loading (or failed to).
+fn textures_settled(world: &World) -> bool {
+    let server = world.resource::<AssetServer>();
+    world
+        .resource::<Assets<StandardMaterial>>()
+        .iter()
+        .flat_map(|(_, material)| {
+            [
+                material.base_color_texture.as_ref(),
+                material.normal_map_texture.as_ref(),
+                material.emissive_texture.as_ref(),
+                material.depth_map.as_ref(),
+            ]
+        })
+        .flatten()
+        .all(|handle| {
+            !matches!(
+                server.get_load_state(handle),
+                Some(LoadState::Loading | LoadState::NotLoaded)
+            )
+        })
+}
+
+/// Render `level` once it is spawned and textured, and return the captured frame.
+fn render_level(level: u32) -> Image {
+    let (mut app, target) = render_app(level);
+    let mut frames = 0;
+    loop {
+        app.update();
+        frames += 1;
+        let world = app.world_mut();
+        let spawned = world.query::<&Brick>().iter(world).next().is_some();
+        if spawned && textures_settled(app.world()) {
+            break;
+        }
+        assert!(frames < MAX_FRAMES, "level {level} did not finish loading");
+    }
+
+    std::fs::create_dir_all(OUTPUT_DIR).unwrap();
+    let captured: Arc<Mutex<Option<Image>>> = Arc::default();
+    let sink = captured.clone();
+    app.world_mut()
+        .spawn(Screenshot::image(target))
+        .observe(save_to_disk(output_path(level)))
+        .observe(move |capture: On<ScreenshotCaptured>| {
+            *sink.lock().unwrap() = Some(capture.image.clone());
+        });
+    // The frame after spawn is drawn, read back and handed to the observers a few frames later
+    for _ in 0..MAX_FRAMES {
+        app.update();
+        if let Some(image) = captured.lock().unwrap().take() {
+            return image;
+        }
+    }
+    panic!("no screenshot captured for level {level}");
+}
+
+fn golden_path(level: u32) -> PathBuf {
+    Path::new(GOLDEN_DIR).join(format!("level_{level:03}.png"))
+}
+
+fn output_path(level: u32) -> PathBuf {
+    Path::new(OUTPUT_DIR).join(format!("level_{level:03}.png"))
+}
+
+#[test]
+fn identical_images_match() {
+    let pixels = [10, 20, 30, 255, 200, 100, 0, 255];
+    let diff = compare_rgba(&pixels, &pixels, 0).unwrap();
+    assert_eq!(diff.differing_pixels, 0);
+    assert_eq!(diff.total_pixels, 2);
+    assert!(diff.within(0.0));
+}
+
+#[test]
+fn differences_beyond_the_tolerance_are_counted() {
+    let expected = [
+        10, 20, 30, 255, 200, 100, 0, 255, 0, 0, 0, 255, 50, 50, 50, 255,
+    ];
+    // Pixel 1 within tolerance, pixel 2 far off, pixel 4 differs in alpha only
+    let actual = [
+        10, 20, 30, 255, 204, 97, 2, 255, 90, 0, 0, 255, 50, 50, 50, 0,
+    ];
+    let diff = compare_rgba(&expected, &actual, 4).unwrap();
+    assert_eq!(diff.differing_pixels, 1);
+    assert_eq!(diff.max_delta, 90);
+    assert!(diff.within(0.25));
+    assert!(!diff.within(0.2));
+    assert!(compare_rgba(&expected, &actual[..8], 4).is_err());
+}
+
+#[test]
+#[ignore = "needs a GPU adapter; run with `cargo test --test visual_regression -- --ignored`"]
+fn levels_match_golden_images() {
+    let update = std::env::var_os("BK_UPDATE_GOLDEN").is_some();
+    let mut failures = Vec::new();
+    for level in LEVELS {
+        let frame = render_level(level);
+        let golden = golden_path(level);
+        if update {
+            // The PNG written by `save_to_disk` is the golden image
+            std::fs::create_dir_all(GOLDEN_DIR).unwrap();
+            std::fs::copy(output_path(level), &golden).unwrap();
+            println!("wrote golden image {}", golden.display());
+            continue;
+        }
+        if !golden.exists() {
+            failures.push(format!(
+                "level {level}: no golden image {} (frame in {}; BK_UPDATE_GOLDEN=1 writes it)",
+                golden.display(),
+                output_path(level).display()
+            ));
+            continue;
+        }
+        let expected = load_png(&golden).and_then(|image| rgba8(&image));
+        let result = expected
+            .and_then(|expected| compare_rgba(&expected, &rgba8(&frame)?, CHANNEL_TOLERANCE));
+        match result {
+            Ok(diff) if diff.within(MAX_DIFFERING_SHARE) => {}
+            Ok(diff) => failures.push(format!(
+                "level {level}: {:.2}% of pixels differ (max delta {}), see {}",
+                diff.differing_share() * 100.0,
+                diff.max_delta,
+                output_path(level).display()
+            )),
+            Err(e) => failures.push(format!("level {level}: {e}")),
+        }
+    }
+    std::env::remove_var("BK_LEVEL");
+    assert!(
+        failures.is_empty(),
+        "visual regressions:\n{}",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use bevy::app::App;
+use bevy::prelude::*;
+use bevy::MinimalPlugins;
+
+use brkrs::level_loader::{CurrentLevel, LevelDefinition};
+use brkrs::systems::level_clock::LevelClock;
+use brkrs::systems::waves::{Wave, WaveSchedule, WaveTrigger, WavesPlugin};
+use brkrs::{Ball, Brick, BrickGridPos, CountsTowardsCompletion, GridResolution};
+
+fn level_with_wave(trigger: WaveTrigger, matrix: Vec<Vec<u8>>) -> CurrentLevel {
+    let def: LevelDefinition = ron::de::from_str("LevelDefinition(number: 1, matrix: [])").unwrap();
+    CurrentLevel(LevelDefinition {
+        waves: vec![Wave { trigger, matrix }],
+        ..def
+    })
+}
+
+fn wave_test_app(level: CurrentLevel) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(WavesPlugin);
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(brkrs::physics_config::BrickPhysicsConfig::default());
+    app.insert_resource(LevelClock::default());
+    app.insert_resource(level);
+    app.update();
+    app
+}
+
+fn set_clock(app: &mut App, secs: u64) {
+    app.world_mut().resource_mut::<LevelClock>().elapsed = Duration::from_secs(secs);
+}
+
+fn brick_cells(app: &mut App) -> Vec<(usize, usize)> {
+    let world = app.world_mut();
+    let mut cells: Vec<_> = world
+        .query_filtered::<&BrickGridPos, With<Brick>>()
+        .iter(world)
+        .map(|pos| (pos.row, pos.col))
+        .collect();
+    cells.sort();
+    cells
+}
+
+#[test]
+fn timed_wave_arrives_once_the_clock_reaches_it() {
+    let mut app = wave_test_app(level_with_wave(
+        WaveTrigger::AfterSeconds(10.0),
+        vec![vec![0, 20, 20]],
+    ));
+    // Field is not cleared: the wave waits for its time
+    app.world_mut().spawn((
+        Brick,
+        CountsTowardsCompletion,
+        BrickGridPos { row: 5, col: 5 },
+    ));
+    set_clock(&mut app, 5);
+    app.update();
+    assert_eq!(brick_cells(&mut app), vec![(5, 5)]);
+    assert_eq!(app.world().resource::<WaveSchedule>().pending(), 1);
+
+    set_clock(&mut app, 10);
+    app.update();
+    assert_eq!(brick_cells(&mut app), vec![(0, 1), (0, 2), (5, 5)]);
+    assert_eq!(app.world().resource::<WaveSchedule>().pending(), 0);
+
+    // Each wave arrives only once
+    app.update();
+    assert_eq!(brick_cells(&mut app).len(), 3);
+}
+
+#[test]
+fn wave_skips_occupied_cells() {
+    let mut app = wave_test_app(level_with_wave(
+        WaveTrigger::BricksBelow(5),
+        vec![vec![0, 20, 20, 20]],
+    ));
+    app.world_mut().spawn((
+        Brick,
+        CountsTowardsCompletion,
+        BrickGridPos { row: 0, col: 1 },
+    ));
+    app.world_mut().spawn((
+        Ball,
+        Transform::from_translation(GridResolution::default().cell_center(0, 2)),
+    ));
+    set_clock(&mut app, 1);
+    app.update();
+    assert_eq!(brick_cells(&mut app), vec![(0, 1), (0, 3)]);
+}
+
+#[test]
+fn no_wave_before_the_level_clock_starts() {
+    let mut app = wave_test_app(level_with_wave(
+        WaveTrigger::AfterSeconds(0.0),
+        vec![vec![20]],
+    ));
+    app.update();
+    assert!(brick_cells(&mut app).is_empty());
+
+    set_clock(&mut app, 1);
+    app.update();
+    assert_eq!(brick_cells(&mut app), vec![(0, 0)]);
+}
@@ -0,0 +1,766 @@
+//! Reading, checking and writing the texture manifest (`assets/textures/manifest.ron`).
+//!
+//! The types mirror the game's `RawTextureManifest` closely enough to check and rewrite a
+//! manifest without building the game: colours, animations, shapes, the level switch state and
+//! models are kept as raw RON, exactly as written.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use ron::value::RawValue;
+use serde::{Deserialize, Deserializer};
+
+/// Texture manifest of the game, relative to the repository root.
+pub const DEFAULT_MANIFEST: &str = "assets/textures/manifest.ron";
+/// Asset directory the game loads from, relative to the repository root.
+pub const DEFAULT_ASSETS: &str = "assets";
+/// Image files a texture folder may hold.
+pub const TEXTURE_EXTENSIONS: [&str; 3] = ["png", "ktx2", "jpg"];
+
+type Raw = Box<RawValue>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Manifest {
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+    #[serde(default)]
+    pub type_variants: Vec<TypeVariant>,
+    #[serde(default)]
+    pub level_overrides: Vec<LevelOverride>,
+    #[serde(default)]
+    pub level_switch: Option<Raw>,
+    #[serde(default, deserialize_with = "some_raw")]
+    pub models: Option<Raw>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub albedo_path: String,
+    #[serde(default)]
+    pub normal_path: Option<String>,
+    #[serde(default)]
+    pub orm_path: Option<String>,
+    #[serde(default)]
+    pub emissive_path: Option<String>,
+    #[serde(default)]
+    pub depth_path: Option<String>,
+    #[serde(default = "default_roughness")]
+    pub roughness: f32,
+    #[serde(default)]
+    pub metallic: f32,
+    #[serde(default = "default_uv_scale")]
+    pub uv_scale: (f32, f32),
+    #[serde(default)]
+    pub uv_offset: (f32, f32),
+    #[serde(default = "default_depth_scale")]
+    pub depth_scale: f32,
+    #[serde(default)]
+    pub fallback_chain: Vec<String>,
+}
+
+fn default_roughness() -> f32 {
+    0.5
+}
+
+fn default_uv_scale() -> (f32, f32) {
+    (1.0, 1.0)
+}
+
+fn default_depth_scale() -> f32 {
+    0.1
+}
+
+impl Profile {
+    /// A profile with the game's defaults.
+    pub fn new(id: impl Into<String>, albedo_path: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            albedo_path: albedo_path.into(),
+            normal_path: None,
+            orm_path: None,
+            emissive_path: None,
+            depth_path: None,
+            roughness: default_roughness(),
+            metallic: 0.0,
+            uv_scale: default_uv_scale(),
+            uv_offset: (0.0, 0.0),
+            depth_scale: default_depth_scale(),
+            fallback_chain: Vec::new(),
+        }
+    }
+
+    /// Texture paths with the field naming them.
+    pub fn textures(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("albedo_path", Some(&self.albedo_path)),
+            ("normal_path", self.normal_path.as_ref()),
+            ("orm_path", self.orm_path.as_ref()),
+            ("emissive_path", self.emissive_path.as_ref()),
+            ("depth_path", self.depth_path.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(field, path)| Some((field, path?.as_str())))
+        .collect()
+    }
+
+    /// Whether the profile uses the ORM, emissive or depth maps, written out in full then.
+    fn has_extra_maps(&self) -> bool {
+        self.orm_path.is_some() || self.emissive_path.is_some() || self.depth_path.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+pub enum ObjectClass {
+    Ball,
+    Brick,
+    Merkaba,
+}
+
+impl ObjectClass {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        match text.to_ascii_lowercase().as_str() {
+            "ball" => Ok(Self::Ball),
+            "brick" => Ok(Self::Brick),
+            "merkaba" => Ok(Self::Merkaba),
+            _ => Err(format!(
+                "Unknown object class `{text}` (Ball, Brick or Merkaba)"
+            )),
+        }
+    }
+
+    /// Prefix of the class's profile ids, e.g. `brick` in `brick/type20`.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Ball => "ball",
+            Self::Brick => "brick",
+            Self::Merkaba => "merkaba",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TypeVariant {
+    pub object_class: ObjectClass,
+    pub type_id: u8,
+    pub profile_id: String,
+    #[serde(default)]
+    pub emissive_color: Option<Raw>,
+    #[serde(default)]
+    pub animation: Option<Raw>,
+    #[serde(default, deserialize_with = "some_raw")]
+    pub shape: Option<Raw>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LevelOverride {
+    pub level_number: u32,
+    #[serde(default)]
+    pub ground_profile: Option<String>,
+    #[serde(default)]
+    pub background_profile: Option<String>,
+    #[serde(default)]
+    pub sidewall_profile: Option<String>,
+    #[serde(default)]
+    pub tint: Option<Raw>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// A field that is not an `Option` in the game, kept raw when present.
+fn some_raw<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Raw>, D::Error> {
+    Raw::deserialize(deserializer).map(|raw| Some(raw.trim_boxed()))
+}
+
+pub fn parse_manifest(src: &str) -> Result<Manifest, String> {
+    ron::from_str(src).map_err(|e| format!("Invalid manifest: {e}"))
+}
+
+pub fn load_manifest(path: &Path) -> Result<(String, Manifest), String> {
+    let src = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let manifest = parse_manifest(&src).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok((src, manifest))
+}
+
+/// The comment lines heading `src`, kept when the manifest is rewritten.
+pub fn header_comments(src: &str) -> String {
+    src.lines()
+        .take_while(|line| line.trim_start().starts_with("//") || line.trim().is_empty())
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+/// Comment lines after the header, which a rewrite drops.
+pub fn dropped_comments(src: &str) -> usize {
+    let header = src
+        .lines()
+        .take_while(|line| line.trim_start().starts_with("//") || line.trim().is_empty())
+        .count();
+    src.lines()
+        .skip(header)
+        .filter(|line| line.trim_start().starts_with("//"))
+        .count()
+}
+
+fn string(text: &str) -> String {
+    format!("{text:?}")
+}
+
+fn optional_string(text: &Option<String>) -> String {
+    text.as_deref()
+        .map_or("None".to_string(), |t| format!("Some({})", string(t)))
+}
+
+fn optional_raw(raw: &Option<Raw>) -> String {
+    raw.as_ref().map_or("None".to_string(), |raw| {
+        format!("Some({})", raw.trim().get_ron())
+    })
+}
+
+fn pair((x, y): (f32, f32)) -> String {
+    format!("({x:?}, {y:?})")
+}
+
+impl Manifest {
+    /// The manifest in the repository's layout: four-space indents, one field per line, fields
+    /// in the game's order. The ORM, emissive and depth fields are only written for profiles
+    /// using one of those maps.
+    pub fn to_ron(&self) -> String {
+        let mut out = String::from("(\n    profiles: [\n");
+        for p in &self.profiles {
+            let _ = writeln!(out, "        (");
+            let _ = writeln!(out, "            id: {},", string(&p.id));
+            let _ = writeln!(out, "            albedo_path: {},", string(&p.albedo_path));
+            let _ = writeln!(
+                out,
+                "            normal_path: {},",
+                optional_string(&p.normal_path)
+            );
+            if p.has_extra_maps() {
+                let _ = writeln!(
+                    out,
+                    "            orm_path: {},",
+                    optional_string(&p.orm_path)
+                );
+                let _ = writeln!(
+                    out,
+                    "            emissive_path: {},",
+                    optional_string(&p.emissive_path)
+                );
+                let _ = writeln!(
+                    out,
+                    "            depth_path: {},",
+                    optional_string(&p.depth_path)
+                );
+            }
+            let _ = writeln!(out, "            roughness: {:?},", p.roughness);
+            let _ = writeln!(out, "            metallic: {:?},", p.metallic);
+            let _ = writeln!(out, "            uv_scale: {},", pair(p.uv_scale));
+            let _ = writeln!(out, "            uv_offset: {},", pair(p.uv_offset));
+            if p.has_extra_maps() || p.depth_scale != default_depth_scale() {
+                let _ = writeln!(out, "            depth_scale: {:?},", p.depth_scale);
+            }
+            let chain: Vec<String> = p
+                .fallback_chain
+                .iter()
+                .map(String::as_str)
+                .map(string)
+                .collect();
+            let _ = writeln!(out, "            fallback_chain: [{}],", chain.join(", "));
+            let _ = writeln!(out, "        ),");
+        }
+        out.push_str("    ],\n");
+        if !self.type_variants.is_empty() {
+            out.push_str("    type_variants: [\n");
+            for v in &self.type_variants {
+                let _ = writeln!(out, "        (");
+                let _ = writeln!(out, "            object_class: {:?},", v.object_class);
+                let _ = writeln!(out, "            type_id: {},", v.type_id);
+                let _ = writeln!(out, "            profile_id: {},", string(&v.profile_id));
+                let _ = writeln!(
+                    out,
+                    "            emissive_color: {},",
+                    optional_raw(&v.emissive_color)
+                );
+                let _ = writeln!(
+                    out,
+                    "            animation: {},",
+                    optional_raw(&v.animation)
+                );
+                if let Some(shape) = &v.shape {
+                    let _ = writeln!(out, "            shape: {},", shape.trim().get_ron());
+                }
+                let _ = writeln!(out, "        ),");
+            }
+            out.push_str("    ],\n");
+        }
+        if !self.level_overrides.is_empty() {
+            out.push_str("    level_overrides: [\n");
+            for o in &self.level_overrides {
+                let _ = writeln!(out, "        (");
+                let _ = writeln!(out, "            level_number: {},", o.level_number);
+                let _ = writeln!(
+                    out,
+                    "            ground_profile: {},",
+                    optional_string(&o.ground_profile)
+                );
+                let _ = writeln!(
+                    out,
+                    "            background_profile: {},",
+                    optional_string(&o.background_profile)
+                );
+                let _ = writeln!(
+                    out,
+                    "            sidewall_profile: {},",
+                    optional_string(&o.sidewall_profile)
+                );
+                let _ = writeln!(out, "            tint: {},", optional_raw(&o.tint));
+                let _ = writeln!(out, "            notes: {},", optional_string(&o.notes));
+                let _ = writeln!(out, "        ),");
+            }
+            out.push_str("    ],\n");
+        }
+        if let Some(level_switch) = &self.level_switch {
+            let _ = writeln!(
+                out,
+                "    level_switch: Some({}),",
+                level_switch.trim().get_ron()
+            );
+        }
+        if let Some(models) = &self.models {
+            let _ = writeln!(out, "    models: {},", models.trim().get_ron());
+        }
+        out.push_str(")\n");
+        out
+    }
+
+    pub fn profile(&self, id: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.id == id)
+    }
+}
+
+/// `src` rewritten in the repository's layout, keeping its header comments.
+pub fn normalize(src: &str) -> Result<String, String> {
+    let manifest = parse_manifest(src)?;
+    Ok(format!("{}{}", header_comments(src), manifest.to_ron()))
+}
+
+/// File of a texture path as the game resolves it: relative to `assets/textures/` unless it
+/// starts with `textures/`.
+pub fn texture_file(assets: &Path, texture: &str) -> PathBuf {
+    if texture.starts_with("textures/") {
+        assets.join(texture)
+    } else {
+        assets.join("textures").join(texture)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A problem `validate` found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Issue {
+    fn error(message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            message,
+        }
+    }
+
+    fn warning(message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message,
+        }
+    }
+}
+
+/// Check `manifest` against the textures under `assets`.
+///
+/// Missing texture files, duplicate profile ids and type variants mapped twice are errors.
+/// References to profiles the manifest does not define are warnings, since a theme manifest
+/// refers to the base manifest's profiles.
+pub fn validate(manifest: &Manifest, assets: &Path) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut ids = HashSet::new();
+    for profile in &manifest.profiles {
+        if !ids.insert(profile.id.as_str()) {
+            issues.push(Issue::error(format!(
+                "profile `{}` is defined twice",
+                profile.id
+            )));
+        }
+        for (field, texture) in profile.textures() {
+            let file = texture_file(assets, texture);
+            if !file.is_file() {
+                issues.push(Issue::error(format!(
+                    "profile `{}`: {} `{}` not found at {}",
+                    profile.id,
+                    field,
+                    texture,
+                    file.display()
+                )));
+            }
+        }
+    }
+    let mut unknown = |owner: String, id: &str| {
+        if !ids.contains(id) {
+            issues.push(Issue::warning(format!(
+                "{owner} refers to profile `{id}`, which this manifest does not define"
+            )));
+        }
+    };
+    for profile in &manifest.profiles {
+        for id in &profile.fallback_chain {
+            unknown(format!("profile `{}` (fallback_chain)", profile.id), id);
+        }
+    }
+    for variant in &manifest.type_variants {
+        unknown(
+            format!("{:?} type {}", variant.object_class, variant.type_id),
+            &variant.profile_id,
+        );
+    }
+    for o in &manifest.level_overrides {
+        for id in [
+            &o.ground_profile,
+            &o.background_profile,
+            &o.sidewall_profile,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            unknown(format!("level {} override", o.level_number), id);
+        }
+    }
+    let mut variants = HashSet::new();
+    for variant in &manifest.type_variants {
+        if !variants.insert((variant.object_class, variant.type_id)) {
+            issues.push(Issue::error(format!(
+                "{:?} type {} is mapped twice",
+                variant.object_class, variant.type_id
+            )));
+        }
+    }
+    issues
+}
+
+/// Profiles by id with the type variants using them, in manifest order.
+pub fn list(manifest: &Manifest) -> String {
+    let mut used: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for v in &manifest.type_variants {
+        used.entry(v.profile_id.as_str())
+            .or_default()
+            .push(format!("{:?} {}", v.object_class, v.type_id));
+    }
+    let width = manifest
+        .profiles
+        .iter()
+        .map(|p| p.id.len())
+        .max()
+        .unwrap_or(0);
+    let mut out = String::new();
+    for profile in &manifest.profiles {
+        let maps: Vec<&str> = profile
+            .textures()
+            .into_iter()
+            .skip(1)
+            .map(|(field, _)| field.trim_end_matches("_path"))
+            .collect();
+        let _ = write!(out, "{:<width$}  {}", profile.id, profile.albedo_path);
+        if !maps.is_empty() {
+            let _ = write!(out, " (+{})", maps.join(", "));
+        }
+        if let Some(variants) = used.get(profile.id.as_str()) {
+            let _ = write!(out, "  <- {}", variants.join(", "));
+        }
+        out.push('\n');
+    }
+    let _ = writeln!(
+        out,
+        "{} profiles, {} type variants, {} level overrides",
+        manifest.profiles.len(),
+        manifest.type_variants.len(),
+        manifest.level_overrides.len()
+    );
+    out
+}
+
+/// Whether a word of the file name `name` starts with one of `words`, e.g. `displacement` for
+/// `disp` in `lava_displacement.png`.
+fn name_has(name: &str, words: &[&str]) -> bool {
+    let stem = Path::new(name).file_stem().map_or(String::new(), |stem| {
+        stem.to_string_lossy().to_ascii_lowercase()
+    });
+    stem.split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|part| words.iter().any(|word| part.starts_with(word)))
+}
+
+/// The map a texture file in a folder is taken for, by its name.
+fn texture_role(name: &str) -> &'static str {
+    if name_has(name, &["normal", "bump"]) {
+        "normal_path"
+    } else if name_has(name, &["orm"]) {
+        "orm_path"
+    } else if name_has(name, &["emissive", "emmisive", "glow"]) {
+        "emissive_path"
+    } else if name_has(name, &["depth", "height", "disp"]) {
+        "depth_path"
+    } else {
+        "albedo_path"
+    }
+}
+
+/// A profile made from the textures in `folder` (relative to `assets/textures/`), told apart by
+/// their names: `*normal*` / `*bump*`, `*orm*`, `*emissive*` / `*glow*` and `*depth*` /
+/// `*height*` / `*disp*` files; the remaining one is the albedo, or the one named `*albedo*`,
+/// `*base*`, `*color*` or `*diffuse*` when there are several.
+///
+/// Other fields take the game's defaults, and the profile falls back to the class's default
+/// profile (e.g. `brick/default`).
+pub fn profile_from_folder(
+    assets: &Path,
+    folder: &str,
+    id: &str,
+    class: ObjectClass,
+) -> Result<Profile, String> {
+    let folder = folder.trim_end_matches('/');
+    let dir = texture_file(assets, folder);
+    let entries =
+        std::fs::read_dir(&dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| {
+            Path::new(name).extension().is_some_and(|ext| {
+                TEXTURE_EXTENSIONS.contains(&ext.to_string_lossy().to_ascii_lowercase().as_str())
+            })
+        })
+        .collect();
+    names.sort();
+
+    let mut roles: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for name in names {
+        roles.entry(texture_role(&name)).or_default().push(name);
+    }
+    let mut albedo = roles.remove("albedo_path").unwrap_or_default();
+    if albedo.len() > 1 {
+        albedo.retain(|name| name_has(name, &["albedo", "base", "colo", "diffuse"]));
+    }
+    let albedo = match albedo.as_slice() {
+        [albedo] => albedo.clone(),
+        [] => {
+            return Err(format!(
+                "No albedo texture in {} (expected one image, or one named *albedo*, *base*, \
+                 *color* or *diffuse*)",
+                dir.display()
+            ))
+        }
+        several => {
+            return Err(format!(
+                "Several albedo candidates in {}: {}",
+                dir.display(),
+                several.join(", ")
+            ))
+        }
+    };
+    let path = |name: &str| format!("{folder}/{name}");
+    let mut profile = Profile::new(id, path(&albedo));
+    for (role, files) in roles {
+        if files.len() > 1 {
+            return Err(format!(
+                "Several {} candidates in {}: {}",
+                role,
+                dir.display(),
+                files.join(", ")
+            ));
+        }
+        let file = Some(path(&files[0]));
+        match role {
+            "normal_path" => profile.normal_path = file,
+            "orm_path" => profile.orm_path = file,
+            "emissive_path" => profile.emissive_path = file,
+            _ => profile.depth_path = file,
+        }
+    }
+    let fallback = format!("{}/default", class.prefix());
+    if profile.id != fallback {
+        profile.fallback_chain.push(fallback);
+    }
+    Ok(profile)
+}
+
+/// Add `profile` and map `class` type `type_id` to it.
+pub fn add_variant(
+    manifest: &mut Manifest,
+    profile: Profile,
+    class: ObjectClass,
+    type_id: u8,
+) -> Result<(), String> {
+    if manifest.profile(&profile.id).is_some() {
+        return Err(format!("Profile `{}` already exists", profile.id));
+    }
+    if let Some(existing) = manifest
+        .type_variants
+        .iter()
+        .find(|v| v.object_class == class && v.type_id == type_id)
+    {
+        return Err(format!(
+            "{:?} type {} is already mapped to `{}`",
+            class, type_id, existing.profile_id
+        ));
+    }
+    manifest.type_variants.push(TypeVariant {
+        object_class: class,
+        type_id,
+        profile_id: profile.id.clone(),
+        emissive_color: None,
+        animation: None,
+        shape: None,
+    });
+    manifest.profiles.push(profile);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"// Header comment
+(
+  profiles: [
+    (id: "brick/default", albedo_path: "brick.png", normal_path: None, roughness: 0.7,
+     fallback_chain: []),
+    (
+      id: "brick/glow",
+      albedo_path: "glow/base.png",
+      emissive_path: Some("glow/emissive.png"),
+      fallback_chain: ["brick/default", "brick/missing"],
+    ),
+  ],
+  type_variants: [
+    (object_class: Brick, type_id: 3, profile_id: "brick/glow",
+     emissive_color: Some(Srgba((red: 1.0, green: 0.5, blue: 0.0, alpha: 1.0))),
+     animation: None, shape: Wedge),
+  ],
+  // trailing comment
+  models: (paddle: Some((scene_path: "models/paddle.glb"))),
+)
+"#;
+
+    #[test]
+    fn normalizing_keeps_raw_values_and_is_stable() {
+        let normalized = normalize(MANIFEST).unwrap();
+        assert!(normalized.starts_with("// Header comment\n(\n    profiles: [\n"));
+        assert!(normalized.contains(
+            "            id: \"brick/default\",\n            albedo_path: \"brick.png\",\n            normal_path: None,\n            roughness: 0.7,\n"
+        ));
+        assert!(normalized.contains("            orm_path: None,\n            emissive_path: Some(\"glow/emissive.png\"),\n"));
+        assert!(normalized.contains("            depth_scale: 0.1,\n"));
+        assert!(normalized
+            .contains("            fallback_chain: [\"brick/default\", \"brick/missing\"],\n"));
+        assert!(normalized.contains(
+            "            emissive_color: Some(Srgba((red: 1.0, green: 0.5, blue: 0.0, alpha: 1.0))),\n"
+        ));
+        assert!(normalized.contains("            shape: Wedge,\n"));
+        assert!(normalized
+            .contains("    models: (paddle: Some((scene_path: \"models/paddle.glb\"))),\n"));
+        assert_eq!(
+            parse_manifest(&normalized).unwrap(),
+            parse_manifest(MANIFEST).unwrap()
+        );
+        assert_eq!(normalize(&normalized).unwrap(), normalized);
+        assert_eq!(dropped_comments(MANIFEST), 1);
+    }
+
+    #[test]
+    fn validation_reports_missing_files_duplicates_and_unknown_profiles() {
+        let assets = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(assets.path().join("textures/glow")).unwrap();
+        std::fs::write(assets.path().join("textures/brick.png"), "").unwrap();
+        std::fs::write(assets.path().join("textures/glow/base.png"), "").unwrap();
+        let mut manifest = parse_manifest(MANIFEST).unwrap();
+        manifest
+            .type_variants
+            .push(manifest.type_variants[0].clone());
+
+        let issues = validate(&manifest, assets.path());
+        let errors: Vec<&str> = issues
+            .iter()
+            .filter(|i| i.severity == Severity::Error)
+            .map(|i| i.message.as_str())
+            .collect();
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(errors[0].contains("emissive_path `glow/emissive.png` not found"));
+        assert_eq!(errors[1], "Brick type 3 is mapped twice");
+        let warnings: Vec<&Issue> = issues
+            .iter()
+            .filter(|i| i.severity == Severity::Warning)
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("`brick/missing`"));
+    }
+
+    #[test]
+    fn variants_are_added_from_texture_folders() {
+        let assets = tempfile::tempdir().unwrap();
+        let folder = assets.path().join("textures/bricks/lava");
+        std::fs::create_dir_all(&folder).unwrap();
+        for name in [
+            "lava_base.png",
+            "lava_normal.png",
+            "lava_glow.png",
+            "notes.txt",
+        ] {
+            std::fs::write(folder.join(name), "").unwrap();
+        }
+        let profile = profile_from_folder(
+            assets.path(),
+            "bricks/lava/",
+            "brick/lava",
+            ObjectClass::Brick,
+        )
+        .unwrap();
+        assert_eq!(profile.albedo_path, "bricks/lava/lava_base.png");
+        assert_eq!(
+            profile.normal_path.as_deref(),
+            Some("bricks/lava/lava_normal.png")
+        );
+        assert_eq!(
+            profile.emissive_path.as_deref(),
+            Some("bricks/lava/lava_glow.png")
+        );
+        assert_eq!(profile.orm_path, None);
+        assert_eq!(profile.fallback_chain, vec!["brick/default".to_string()]);
+
+        let mut manifest = parse_manifest(MANIFEST).unwrap();
+        add_variant(&mut manifest, profile.clone(), ObjectClass::Brick, 45).unwrap();
+        assert_eq!(manifest.type_variants[1].profile_id, "brick/lava");
+        assert!(validate(&manifest, assets.path())
+            .iter()
+            .all(|issue| !issue.message.contains("brick/lava")));
+        assert!(add_variant(&mut manifest, profile, ObjectClass::Brick, 46).is_err());
+        let other = Profile::new("brick/other", "x.png");
+        assert!(add_variant(&mut manifest, other, ObjectClass::Brick, 3).is_err());
+
+        std::fs::write(folder.join("lava_color.png"), "").unwrap();
+        assert!(
+            profile_from_folder(assets.path(), "bricks/lava", "b", ObjectClass::Brick)
+                .unwrap_err()
+                .contains("Several albedo candidates")
+        );
+    }
+}
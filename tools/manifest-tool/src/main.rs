@@ -0,0 +1,169 @@
+use manifest_tool::{
+    add_variant, dropped_comments, header_comments, list, load_manifest, normalize,
+    profile_from_folder, validate, ObjectClass, Severity, DEFAULT_ASSETS, DEFAULT_MANIFEST,
+};
+use std::path::PathBuf;
+use std::{env, fs};
+
+const USAGE: &str = "\
+Usage: manifest-tool <command> [--manifest <file>] [--assets <dir>] ...
+
+Commands:
+  list                         profiles, their textures and the type variants using them
+  validate                     check texture files, duplicate ids and profile references
+  add-variant <folder> --type-id <n> [--class Brick|Ball|Merkaba] [--id <profile id>] [--dry-run]
+                               add a profile from the textures in <folder> (relative to
+                               assets/textures/) and map the type to it
+  normalize [--check]          rewrite the manifest in the repository's layout; --check only
+                               reports whether it would change
+
+  --manifest <file>            default: assets/textures/manifest.ron
+  --assets <dir>               asset directory texture paths resolve in, default: assets";
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}\n\n{USAGE}");
+    std::process::exit(2);
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{message}");
+    std::process::exit(1);
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let Some(command) = args.next() else {
+        usage_error("No command given");
+    };
+    if command == "-h" || command == "--help" {
+        println!("{USAGE}");
+        return;
+    }
+    let mut manifest_path = PathBuf::from(DEFAULT_MANIFEST);
+    let mut assets = PathBuf::from(DEFAULT_ASSETS);
+    let mut folder = None;
+    let mut type_id = None;
+    let mut class = ObjectClass::Brick;
+    let mut id = None;
+    let mut dry_run = false;
+    let mut check = false;
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .unwrap_or_else(|| usage_error(&format!("{flag} needs a value")))
+        };
+        match arg.as_str() {
+            "--manifest" => manifest_path = PathBuf::from(value("--manifest")),
+            "--assets" => assets = PathBuf::from(value("--assets")),
+            "--type-id" => {
+                let text = value("--type-id");
+                type_id = Some(text.parse::<u8>().unwrap_or_else(|_| {
+                    usage_error(&format!("`{text}` is not a type id (0-255)"))
+                }));
+            }
+            "--class" => {
+                class = ObjectClass::parse(&value("--class")).unwrap_or_else(|e| usage_error(&e))
+            }
+            "--id" => id = Some(value("--id")),
+            "--dry-run" => dry_run = true,
+            "--check" => check = true,
+            flag if flag.starts_with("--") => usage_error(&format!("Unknown option {flag}")),
+            _ if command == "add-variant" && folder.is_none() => folder = Some(arg),
+            _ => usage_error(&format!("Unexpected argument `{arg}`")),
+        }
+    }
+
+    let (src, mut manifest) = load_manifest(&manifest_path).unwrap_or_else(|e| fail(&e));
+    match command.as_str() {
+        "list" => print!("{}", list(&manifest)),
+        "validate" => {
+            let issues = validate(&manifest, &assets);
+            let mut errors = 0;
+            for issue in &issues {
+                let label = match issue.severity {
+                    Severity::Error => {
+                        errors += 1;
+                        "error"
+                    }
+                    Severity::Warning => "warning",
+                };
+                println!("{label}: {}", issue.message);
+            }
+            println!(
+                "{}: {} profile(s), {} error(s), {} warning(s)",
+                manifest_path.display(),
+                manifest.profiles.len(),
+                errors,
+                issues.len() - errors
+            );
+            if errors > 0 {
+                std::process::exit(1);
+            }
+        }
+        "add-variant" => {
+            let folder = folder.unwrap_or_else(|| usage_error("add-variant needs a folder"));
+            let type_id = type_id.unwrap_or_else(|| usage_error("add-variant needs --type-id"));
+            let id = id.unwrap_or_else(|| {
+                let name = folder
+                    .trim_end_matches('/')
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or("");
+                format!("{}/{}", class.prefix(), name)
+            });
+            let profile =
+                profile_from_folder(&assets, &folder, &id, class).unwrap_or_else(|e| fail(&e));
+            add_variant(&mut manifest, profile, class, type_id).unwrap_or_else(|e| fail(&e));
+            let text = format!("{}{}", header_comments(&src), manifest.to_ron());
+            if dry_run {
+                print!("{text}");
+                return;
+            }
+            if dropped_comments(&src) > 0 {
+                eprintln!(
+                    "warning: {} comment line(s) inside the manifest were dropped",
+                    dropped_comments(&src)
+                );
+            }
+            fs::write(&manifest_path, text).unwrap_or_else(|e| {
+                fail(&format!(
+                    "Failed to write {}: {}",
+                    manifest_path.display(),
+                    e
+                ))
+            });
+            println!(
+                "Added profile `{id}` for {class:?} type {type_id} to {}",
+                manifest_path.display()
+            );
+        }
+        "normalize" => {
+            let normalized = normalize(&src).unwrap_or_else(|e| fail(&e));
+            if normalized == src {
+                println!("{} is normalized", manifest_path.display());
+                return;
+            }
+            if check {
+                fail(&format!(
+                    "{} is not normalized; run `manifest-tool normalize`",
+                    manifest_path.display()
+                ));
+            }
+            if dropped_comments(&src) > 0 {
+                eprintln!(
+                    "warning: {} comment line(s) inside the manifest were dropped",
+                    dropped_comments(&src)
+                );
+            }
+            fs::write(&manifest_path, normalized).unwrap_or_else(|e| {
+                fail(&format!(
+                    "Failed to write {}: {}",
+                    manifest_path.display(),
+                    e
+                ))
+            });
+            println!("Normalized {}", manifest_path.display());
+        }
+        other => usage_error(&format!("Unknown command `{other}`")),
+    }
+}
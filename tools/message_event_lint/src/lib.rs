@@ -1,10 +1,25 @@
-use std::path::Path;
-use syn::{visit::Visit, Expr, File, ItemFn, Type, TypePath};
+use std::path::{Path, PathBuf};
+use syn::{visit::Visit, Expr, File, GenericArgument, ItemFn, PathArguments, Type, TypePath};
+use walkdir::WalkDir;
 
-#[derive(Debug, PartialEq, Eq)]
+/// An immediate side effect in a function writing messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SideEffect {
+    /// 1-based line of the call
+    pub line: usize,
+    /// The source line of the call, trimmed
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Finding {
     pub file: String,
     pub fn_name: String,
+    /// 1-based line of the function's signature
+    pub line: usize,
+    /// Message types written, as named in `MessageWriter<T>`
+    pub messages: Vec<String>,
+    pub side_effects: Vec<SideEffect>,
 }
 
 struct FnVisitor {
@@ -20,8 +35,10 @@ impl FnVisitor {
 }
 
 struct BodyVisitor {
+    messages: Vec<String>,
     has_message_writer: bool,
-    has_side_effect: bool,
+    /// Lines of the side-effect calls
+    side_effects: Vec<usize>,
 }
 
 impl<'ast> Visit<'ast> for BodyVisitor {
@@ -29,17 +46,23 @@ impl<'ast> Visit<'ast> for BodyVisitor {
         // detect local MessageWriter mentions in types
         if i.path.segments.iter().any(|s| s.ident == "MessageWriter") {
             self.has_message_writer = true;
+            for message in message_types(&i.path) {
+                if !self.messages.contains(&message) {
+                    self.messages.push(message);
+                }
+            }
         }
         syn::visit::visit_type_path(self, i);
     }
 
     fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
         // detect commands.spawn(), commands.entity(), asset_server.load(), audio.play(), .play()
+        let mut side_effect = false;
         if let Expr::Path(ref p) = *node.receiver {
             if let Some(ident) = p.path.segments.last() {
                 match ident.ident.to_string().as_str() {
                     "commands" | "asset_server" | "audio" => {
-                        self.has_side_effect = true;
+                        side_effect = true;
                     }
                     _ => {}
                 }
@@ -49,11 +72,14 @@ impl<'ast> Visit<'ast> for BodyVisitor {
             let method = node.method.to_string();
             match method.as_str() {
                 "spawn" | "entity" | "spawn_batch" | "insert_resource" | "despawn" | "play" => {
-                    self.has_side_effect = true;
+                    side_effect = true;
                 }
                 _ => {}
             }
         }
+        if side_effect {
+            self.side_effects.push(node.method.span().start().line);
+        }
         syn::visit::visit_expr_method_call(self, node);
     }
 }
@@ -62,24 +88,41 @@ impl<'ast> Visit<'ast> for FnVisitor {
     fn visit_item_fn(&mut self, i: &'ast ItemFn) {
         let fn_name = i.sig.ident.to_string();
         // Check if any argument is MessageWriter
-        let has_mw_arg = i.sig.inputs.iter().any(|input| {
+        let mut messages = Vec::new();
+        let mut has_mw_arg = false;
+        for input in &i.sig.inputs {
             if let syn::FnArg::Typed(pat_ty) = input {
-                matches_message_writer(&*pat_ty.ty)
-            } else {
-                false
+                if let Some(types) = message_writer_types(&pat_ty.ty) {
+                    messages.extend(types);
+                    has_mw_arg = true;
+                }
             }
-        });
+        }
 
         let mut body_visitor = BodyVisitor {
+            messages,
             has_message_writer: has_mw_arg,
-            has_side_effect: false,
+            side_effects: Vec::new(),
         };
         body_visitor.visit_block(&i.block);
+        // Chained calls are visited outermost first
+        body_visitor.side_effects.sort_unstable();
+        body_visitor.side_effects.dedup();
 
-        if body_visitor.has_message_writer && body_visitor.has_side_effect {
+        if body_visitor.has_message_writer && !body_visitor.side_effects.is_empty() {
             self.findings.push(Finding {
                 file: "<in-memory>".to_string(),
                 fn_name: fn_name.clone(),
+                line: i.sig.ident.span().start().line,
+                messages: body_visitor.messages,
+                side_effects: body_visitor
+                    .side_effects
+                    .into_iter()
+                    .map(|line| SideEffect {
+                        line,
+                        snippet: String::new(),
+                    })
+                    .collect(),
             });
         }
 
@@ -88,29 +131,156 @@ impl<'ast> Visit<'ast> for FnVisitor {
     }
 }
 
-fn matches_message_writer(ty: &Type) -> bool {
+/// `T` of every `MessageWriter<T>` segment of `path`.
+fn message_types(path: &syn::Path) -> Vec<String> {
+    path.segments
+        .iter()
+        .filter(|s| s.ident == "MessageWriter")
+        .filter_map(|s| match &s.arguments {
+            PathArguments::AngleBracketed(args) => Some(args.args.iter()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|arg| match arg {
+            GenericArgument::Type(Type::Path(tp)) => {
+                tp.path.segments.last().map(|s| s.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The message types of `ty` when it is a `MessageWriter`.
+fn message_writer_types(ty: &Type) -> Option<Vec<String>> {
     match ty {
-        Type::Path(tp) => tp.path.segments.iter().any(|s| s.ident == "MessageWriter"),
-        _ => false,
+        Type::Path(tp) if tp.path.segments.iter().any(|s| s.ident == "MessageWriter") => {
+            Some(message_types(&tp.path))
+        }
+        _ => None,
     }
 }
 
-pub fn analyze_file(path: &Path, src: &str) -> Vec<Finding> {
+/// Findings of the source `src` of `path`, or why it does not parse.
+pub fn try_analyze_file(path: &Path, src: &str) -> Result<Vec<Finding>, syn::Error> {
     let mut v = FnVisitor::new(src);
-    let file: File = syn::parse_file(src).unwrap_or_else(|_| {
-        // if parse fails, return empty
-        File {
-            shebang: None,
-            attrs: Vec::new(),
-            items: Vec::new(),
-        }
-    });
+    let file: File = syn::parse_file(src)?;
     v.visit_file(&file);
-    // Note: item_fn check_and_reset uses in-memory path; we'll overwrite with real path
+    let lines: Vec<&str> = src.lines().collect();
+    // Findings are collected with an in-memory path; fill in the real path and the snippets
     let mut out = v.findings;
     for f in &mut out {
         f.file = path.to_string_lossy().to_string();
+        for effect in &mut f.side_effects {
+            effect.snippet = lines
+                .get(effect.line.saturating_sub(1))
+                .map_or("", |line| line.trim())
+                .to_string();
+        }
     }
+    Ok(out)
+}
+
+pub fn analyze_file(path: &Path, src: &str) -> Vec<Finding> {
+    // if parse fails, return empty
+    try_analyze_file(path, src).unwrap_or_default()
+}
+
+/// Rust files to lint: files given as is, and every `.rs` file under the directories given,
+/// skipping `target` and hidden directories, in path order.
+pub fn rust_files(paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for path in paths {
+        if !path.is_dir() {
+            files.push(path.clone());
+            continue;
+        }
+        let walker = WalkDir::new(path)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                let name = entry.file_name().to_string_lossy();
+                entry.depth() == 0 || !(name.starts_with('.') || name == "target")
+            });
+        for entry in walker {
+            match entry {
+                Ok(entry)
+                    if entry.file_type().is_file()
+                        && entry.path().extension().is_some_and(|ext| ext == "rs") =>
+                {
+                    files.push(entry.into_path());
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("warning: {e}"),
+            }
+        }
+    }
+    files
+}
+
+/// Findings accepted on review, one `path::function` per line; `#` starts a comment.
+///
+/// The path matches the end of the reported file path (`systems/audio.rs::play_sounds` also
+/// matches `./src/systems/audio.rs`), and an entry without a path allows the function in any
+/// file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Allowlist {
+    entries: Vec<(Option<String>, String)>,
+}
+
+impl Allowlist {
+    pub fn parse(text: &str) -> Self {
+        let entries = text
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or("").trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| match line.rsplit_once("::") {
+                Some((path, fn_name)) => (Some(path.replace('\\', "/")), fn_name.to_string()),
+                None => (None, line.to_string()),
+            })
+            .collect();
+        Self { entries }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        std::fs::read_to_string(path)
+            .map(|text| Self::parse(&text))
+            .map_err(|e| format!("Failed to read allowlist {}: {}", path.display(), e))
+    }
+
+    pub fn allows(&self, finding: &Finding) -> bool {
+        let file = finding.file.replace('\\', "/");
+        self.entries.iter().any(|(path, fn_name)| {
+            *fn_name == finding.fn_name
+                && path.as_ref().is_none_or(|path| {
+                    file == *path || file.ends_with(&format!("/{}", path.trim_start_matches("./")))
+                })
+        })
+    }
+}
+
+/// How to split the function of `finding` into a producer writing the messages and a consumer
+/// system reading them and doing the side effects.
+pub fn split_suggestion(finding: &Finding) -> String {
+    let fn_name = &finding.fn_name;
+    let message = finding.messages.first().map_or("T", String::as_str);
+    let consumer = format!("apply_{fn_name}");
+    let mut out = format!(
+        "suggestion: split `{fn_name}` into a producer and a consumer system:\n\
+         \x20   fn {fn_name}(mut writer: MessageWriter<{message}>, ...) {{\n\
+         \x20       // decide, then only `writer.write(..)`\n\
+         \x20   }}\n\
+         \x20   fn {consumer}(mut messages: MessageReader<{message}>, mut commands: Commands, ...) {{\n\
+         \x20       for message in messages.read() {{\n"
+    );
+    for effect in &finding.side_effects {
+        out.push_str(&format!(
+            "            // from line {}: {}\n",
+            effect.line, effect.snippet
+        ));
+    }
+    out.push_str(&format!(
+        "        }}\n    }}\nand schedule them in order: `({fn_name}, {consumer}).chain()`\n"
+    ));
     out
 }
 
@@ -163,4 +333,65 @@ mod tests {
         assert_eq!(findings.len(), 1);
         assert_eq!(findings[0].fn_name, "on_event");
     }
+
+    #[test]
+    fn findings_carry_lines_snippets_and_message_types() {
+        let src = "fn example(\n    mut writer: MessageWriter<bevy::Foo>,\n    mut commands: Commands,\n) {\n    writer.write(Foo);\n    commands.spawn(Bar);\n    commands\n        .entity(e)\n        .despawn();\n}\n";
+        let findings = try_analyze_file(Path::new("src/example.rs"), src).unwrap();
+        assert_eq!(findings.len(), 1);
+        let f = &findings[0];
+        assert_eq!(f.file, "src/example.rs");
+        assert_eq!(f.line, 1);
+        assert_eq!(f.messages, vec!["Foo".to_string()]);
+        let lines: Vec<usize> = f.side_effects.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![6, 8, 9]);
+        assert_eq!(f.side_effects[0].snippet, "commands.spawn(Bar);");
+
+        let suggestion = split_suggestion(f);
+        assert!(suggestion.contains("fn apply_example(mut messages: MessageReader<Foo>"));
+        assert!(suggestion.contains("// from line 6: commands.spawn(Bar);"));
+        assert!(suggestion.contains("(example, apply_example).chain()"));
+    }
+
+    #[test]
+    fn unparsable_files_are_errors() {
+        assert!(try_analyze_file(Path::new("broken.rs"), "fn (").is_err());
+        assert!(analyze_file(Path::new("broken.rs"), "fn (").is_empty());
+    }
+
+    #[test]
+    fn allowlist_matches_path_suffixes_and_bare_names() {
+        let allow =
+            Allowlist::parse("# reviewed\nsystems/audio.rs::play_sounds\nspawn_all # anywhere\n\n");
+        let finding = |file: &str, fn_name: &str| Finding {
+            file: file.to_string(),
+            fn_name: fn_name.to_string(),
+            line: 1,
+            messages: Vec::new(),
+            side_effects: Vec::new(),
+        };
+        assert!(allow.allows(&finding("src/systems/audio.rs", "play_sounds")));
+        assert!(allow.allows(&finding("systems/audio.rs", "play_sounds")));
+        assert!(!allow.allows(&finding("src/systems/myaudio.rs", "play_sounds")));
+        assert!(!allow.allows(&finding("src/systems/audio.rs", "other")));
+        assert!(allow.allows(&finding("src/lib.rs", "spawn_all")));
+    }
+
+    #[test]
+    fn directories_are_walked_for_rust_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("systems")).unwrap();
+        std::fs::create_dir_all(root.join("target")).unwrap();
+        std::fs::write(root.join("lib.rs"), "").unwrap();
+        std::fs::write(root.join("systems/audio.rs"), "").unwrap();
+        std::fs::write(root.join("systems/notes.md"), "").unwrap();
+        std::fs::write(root.join("target/generated.rs"), "").unwrap();
+
+        let files = rust_files(&[root.to_path_buf()]);
+        assert_eq!(
+            files,
+            vec![root.join("lib.rs"), root.join("systems/audio.rs")]
+        );
+    }
 }
@@ -1,26 +1,114 @@
-use message_event_lint::analyze_file;
+use message_event_lint::{rust_files, split_suggestion, try_analyze_file, Allowlist};
 use std::{env, fs, path::PathBuf};
 
+/// Allowlist read when `--allow` is not given and it exists.
+const DEFAULT_ALLOWLIST: &str = "brkrs-lint.allow";
+
+const USAGE: &str = "\
+Usage: cargo brkrs-lint [--allow <file>] [--suggest] [<path>...]
+
+Flags functions that write a MessageWriter<T> and also have immediate side effects (spawning,
+despawning, inserting resources, loading assets, playing audio).
+
+  <path>...        files or directories to scan (default: src)
+  --allow <file>   findings accepted on review, one `path::function` per line
+                   (default: brkrs-lint.allow when it exists)
+  --suggest        show how to split each function into a producer and a consumer system";
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}\n\n{USAGE}");
+    std::process::exit(2);
+}
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Usage: message_event_lint <file.rs> [<file2.rs> ...]");
-        std::process::exit(2);
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    // Run as `cargo brkrs-lint`, cargo passes the subcommand name first
+    if args.first().is_some_and(|arg| arg == "brkrs-lint") {
+        args.remove(0);
     }
+    let mut paths = Vec::new();
+    let mut allow_path = None;
+    let mut suggest = false;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            "--suggest" => suggest = true,
+            "--allow" => match args.next() {
+                Some(path) => allow_path = Some(PathBuf::from(path)),
+                None => usage_error("--allow needs a file"),
+            },
+            flag if flag.starts_with("--") => usage_error(&format!("Unknown option {flag}")),
+            path => paths.push(PathBuf::from(path)),
+        }
+    }
+    if paths.is_empty() {
+        paths.push(PathBuf::from("src"));
+    }
+    let allow_path =
+        allow_path.or_else(|| Some(PathBuf::from(DEFAULT_ALLOWLIST)).filter(|p| p.is_file()));
+    let allowlist = match allow_path.map(|path| Allowlist::load(&path)) {
+        Some(Ok(allowlist)) => allowlist,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        }
+        None => Allowlist::default(),
+    };
+
+    let files = rust_files(&paths);
     let mut total = 0;
-    for f in &args[1..] {
-        let p = PathBuf::from(f);
-        if let Ok(src) = fs::read_to_string(&p) {
-            let findings = analyze_file(&p, &src);
-            for f in findings {
-                println!(
-                    "{}: function `{}` likely misuses MessageWriter with an immediate side-effect",
-                    f.file, f.fn_name
-                );
-                total += 1;
+    let mut allowed = 0;
+    for p in &files {
+        let src = match fs::read_to_string(p) {
+            Ok(src) => src,
+            Err(e) => {
+                eprintln!("warning: failed to read {}: {}", p.display(), e);
+                continue;
+            }
+        };
+        let findings = match try_analyze_file(p, &src) {
+            Ok(findings) => findings,
+            Err(e) => {
+                eprintln!("warning: failed to parse {}: {}", p.display(), e);
+                continue;
+            }
+        };
+        for f in findings {
+            if allowlist.allows(&f) {
+                allowed += 1;
+                continue;
+            }
+            let written = if f.messages.is_empty() {
+                "MessageWriter".to_string()
+            } else {
+                format!("MessageWriter<{}>", f.messages.join(", "))
+            };
+            println!(
+                "{}:{}: function `{}` writes {} and has immediate side effects:",
+                f.file, f.line, f.fn_name, written
+            );
+            for effect in &f.side_effects {
+                println!("    {:>5} | {}", effect.line, effect.snippet);
+            }
+            if suggest {
+                for line in split_suggestion(&f).lines() {
+                    println!("  {line}");
+                }
             }
+            println!();
+            total += 1;
         }
     }
+    eprintln!(
+        "{} finding(s) in {} file(s) scanned, {} allowed",
+        total,
+        files.len(),
+        allowed
+    );
     if total > 0 {
         std::process::exit(2);
     }
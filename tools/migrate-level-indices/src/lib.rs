@@ -0,0 +1,372 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Brick index changes applied in one pass, old index to new index.
+///
+/// All changes read the original matrix, so `3=20,20=3` swaps the two indices.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Mapping(pub BTreeMap<u8, u8>);
+
+impl Mapping {
+    /// Add `from` -> `to`; an index may only be mapped once.
+    pub fn insert(&mut self, from: u8, to: u8) -> Result<(), String> {
+        match self.0.insert(from, to) {
+            Some(previous) if previous != to => Err(format!(
+                "Index {from} is mapped twice ({from}={previous} and {from}={to})"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Add the pairs of a `--map` value, e.g. `3=20,4=21`.
+    pub fn parse_into(&mut self, spec: &str) -> Result<(), String> {
+        for pair in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+        {
+            let Some((from, to)) = pair.split_once('=') else {
+                return Err(format!("Expected old=new, got `{pair}`"));
+            };
+            self.insert(parse_index(from)?, parse_index(to)?)?;
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A brick index, 0-255.
+pub fn parse_index(text: &str) -> Result<u8, String> {
+    text.trim()
+        .parse()
+        .map_err(|_| format!("`{}` is not a brick index (0-255)", text.trim()))
+}
+
+/// A line the migration changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineChange {
+    /// 1-based line number
+    pub line: usize,
+    pub old: String,
+    pub new: String,
+}
+
+/// A migrated level file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Migration {
+    pub text: String,
+    pub changes: Vec<LineChange>,
+    /// Cells changed per (old, new) pair
+    pub cells: BTreeMap<(u8, u8), usize>,
+}
+
+/// Byte range of the `matrix: [...]` value in `src`, brackets included.
+fn matrix_range(src: &str) -> Option<(usize, usize)> {
+    let start = src.match_indices("matrix").find_map(|(at, key)| {
+        let before = src[..at].chars().next_back();
+        if before.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        let rest = src[at + key.len()..].trim_start().strip_prefix(':')?;
+        let value = rest.trim_start();
+        value.starts_with('[').then(|| src.len() - value.len())
+    })?;
+    let mut depth = 0usize;
+    for (offset, c) in src[start..].char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((start, start + offset + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Apply `map` to the brick matrix of the level file `src`, leaving everything else (and the
+/// matrix's layout) as it is. `None` when the file has no matrix.
+pub fn migrate(src: &str, map: &Mapping) -> Option<Migration> {
+    let (start, end) = matrix_range(src)?;
+    let matrix = &src[start..end];
+    let mut cells = BTreeMap::new();
+    let mut migrated = String::with_capacity(matrix.len());
+    let mut digits = String::new();
+    let mut flush = |digits: &mut String, out: &mut String| {
+        if digits.is_empty() {
+            return;
+        }
+        match digits
+            .parse::<u8>()
+            .ok()
+            .and_then(|old| Some((old, *map.0.get(&old)?)))
+        {
+            Some((old, new)) if old != new => {
+                *cells.entry((old, new)).or_insert(0) += 1;
+                out.push_str(&new.to_string());
+            }
+            _ => out.push_str(digits),
+        }
+        digits.clear();
+    };
+    for c in matrix.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+        } else {
+            flush(&mut digits, &mut migrated);
+            migrated.push(c);
+        }
+    }
+    flush(&mut digits, &mut migrated);
+
+    let text = format!("{}{}{}", &src[..start], migrated, &src[end..]);
+    let changes = src
+        .lines()
+        .zip(text.lines())
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(index, (old, new))| LineChange {
+            line: index + 1,
+            old: old.to_string(),
+            new: new.to_string(),
+        })
+        .collect();
+    Some(Migration {
+        text,
+        changes,
+        cells,
+    })
+}
+
+/// The changed lines of `path` as a diff.
+pub fn diff(path: &Path, changes: &[LineChange]) -> String {
+    let mut out = format!(
+        "--- {}\n+++ {} (migrated)\n",
+        path.display(),
+        path.display()
+    );
+    for change in changes {
+        out.push_str(&format!(
+            "@@ line {} @@\n-{}\n+{}\n",
+            change.line, change.old, change.new
+        ));
+    }
+    out
+}
+
+/// Whether `name` matches `pattern`, where `*` is any run of characters and `?` any one.
+pub fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // matches[j]: pattern so far matches name[..j]
+    let mut matches = vec![false; name.len() + 1];
+    matches[0] = true;
+    for p in &pattern {
+        let mut next = vec![false; name.len() + 1];
+        for j in 0..=name.len() {
+            next[j] = match p {
+                '*' => matches[j] || (j > 0 && next[j - 1]),
+                '?' => j > 0 && matches[j - 1],
+                c => j > 0 && matches[j - 1] && name[j - 1] == *c,
+            };
+        }
+        matches = next;
+    }
+    matches[name.len()]
+}
+
+fn has_wildcard(text: &str) -> bool {
+    text.contains(['*', '?'])
+}
+
+/// `.ron` files under `dir`, recursively, in path order.
+fn ron_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|e| e.path())
+        .collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            ron_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "ron") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Level files named by the arguments: files as given, every `.ron` file under directories, and
+/// the files matching a wildcard in the last path component (e.g. `assets/levels/level_0*.ron`,
+/// for shells that do not expand it). Each file is listed once.
+pub fn level_files(args: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    for arg in args {
+        let path = Path::new(arg);
+        if has_wildcard(arg) {
+            let pattern = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let dir = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+            if has_wildcard(&dir.to_string_lossy()) {
+                return Err(format!(
+                    "`{arg}`: wildcards are only supported in the file name"
+                ));
+            }
+            let entries = std::fs::read_dir(dir)
+                .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
+            let mut matched: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| {
+                    entry.path().is_file()
+                        && wildcard_match(&pattern, &entry.file_name().to_string_lossy())
+                })
+                .map(|entry| dir.join(entry.file_name()))
+                .collect();
+            if matched.is_empty() {
+                return Err(format!("No files match `{arg}`"));
+            }
+            matched.sort();
+            files.extend(matched);
+        } else if path.is_dir() {
+            ron_files(path, &mut files)?;
+        } else {
+            files.push(path.to_path_buf());
+        }
+    }
+    let mut seen = HashSet::new();
+    files.retain(|file| seen.insert(file.clone()));
+    Ok(files)
+}
+
+/// Totals over a run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub scanned: usize,
+    pub changed: usize,
+    pub without_matrix: Vec<PathBuf>,
+    pub failed: usize,
+    pub cells: BTreeMap<(u8, u8), usize>,
+}
+
+impl Summary {
+    /// Count the result of migrating `path`.
+    pub fn add(&mut self, path: &Path, migration: Option<&Migration>) {
+        self.scanned += 1;
+        let Some(migration) = migration else {
+            self.without_matrix.push(path.to_path_buf());
+            return;
+        };
+        if !migration.changes.is_empty() {
+            self.changed += 1;
+        }
+        for (pair, count) in &migration.cells {
+            *self.cells.entry(*pair).or_insert(0) += count;
+        }
+    }
+
+    pub fn report(&self, map: &Mapping, dry_run: bool) -> String {
+        let mut out = format!(
+            "Scanned {} file(s): {} changed, {} without a matrix, {} failed{}\n",
+            self.scanned,
+            self.changed,
+            self.without_matrix.len(),
+            self.failed,
+            if dry_run {
+                " (dry run, nothing written)"
+            } else {
+                ""
+            }
+        );
+        for (from, to) in &map.0 {
+            let count = self.cells.get(&(*from, *to)).copied().unwrap_or(0);
+            out.push_str(&format!("  {from} -> {to}: {count} cell(s)\n"));
+        }
+        for path in &self.without_matrix {
+            out.push_str(&format!("  skipped (no matrix): {}\n", path.display()));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEVEL: &str = "LevelDefinition(\n  number: 3,\n  matrix: [\n    [0,3,20,3],\n    [0,0,0,0],\n    [4,0,0,3],\n  ],\n  description: Some(\"3 rows\"),\n)\n";
+
+    fn map(spec: &str) -> Mapping {
+        let mut map = Mapping::default();
+        map.parse_into(spec).unwrap();
+        map
+    }
+
+    #[test]
+    fn maps_are_parsed_and_checked() {
+        assert_eq!(map("3=20, 4=21").0, BTreeMap::from([(3, 20), (4, 21)]));
+        let mut twice = map("3=20");
+        assert!(twice.parse_into("3=21").is_err());
+        assert!(twice.parse_into("3=20").is_ok());
+        assert!(Mapping::default().parse_into("3-20").is_err());
+        assert!(Mapping::default().parse_into("3=300").is_err());
+    }
+
+    #[test]
+    fn only_matrix_cells_change_in_one_pass() {
+        let migration = migrate(LEVEL, &map("3=20,20=3,4=21")).unwrap();
+        assert!(migration.text.contains("number: 3,"));
+        assert!(migration.text.contains("Some(\"3 rows\")"));
+        assert!(migration.text.contains("[0,20,3,20],"));
+        assert!(migration.text.contains("[21,0,0,20],"));
+        assert_eq!(
+            migration.cells,
+            BTreeMap::from([((3, 20), 3), ((20, 3), 1), ((4, 21), 1)])
+        );
+        let lines: Vec<usize> = migration.changes.iter().map(|c| c.line).collect();
+        assert_eq!(lines, vec![4, 6]);
+
+        assert!(migrate("(number: 1)", &map("3=20")).is_none());
+    }
+
+    #[test]
+    fn diffs_show_the_changed_lines() {
+        let migration = migrate(LEVEL, &map("4=21")).unwrap();
+        assert_eq!(
+            diff(Path::new("level_003.ron"), &migration.changes),
+            "--- level_003.ron\n+++ level_003.ron (migrated)\n@@ line 6 @@\n-    [4,0,0,3],\n+    [21,0,0,3],\n"
+        );
+    }
+
+    #[test]
+    fn wildcards_match_file_names() {
+        assert!(wildcard_match("level_*.ron", "level_001.ron"));
+        assert!(wildcard_match("level_00?.ron", "level_001.ron"));
+        assert!(!wildcard_match("level_00?.ron", "level_0010.ron"));
+        assert!(!wildcard_match("*.ron", "level_001.ron.bak"));
+        assert!(wildcard_match("*", ""));
+    }
+
+    #[test]
+    fn summary_counts_cells_per_pair() {
+        let map = map("3=20,5=6");
+        let mut summary = Summary::default();
+        summary.add(Path::new("a.ron"), migrate(LEVEL, &map).as_ref());
+        summary.add(Path::new("campaign.ron"), None);
+        assert_eq!(
+            summary.report(&map, true),
+            "Scanned 2 file(s): 1 changed, 1 without a matrix, 0 failed (dry run, nothing written)\n  3 -> 20: 3 cell(s)\n  5 -> 6: 0 cell(s)\n  skipped (no matrix): campaign.ron\n"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use migrate_level_indices::{diff, level_files, migrate, parse_index, Mapping, Summary};
+use std::{env, fs};
+
+const USAGE: &str = "\
+Usage: migrate-level-indices [--from <old> --to <new>] [--map old=new,...] [--dry-run] [--backup]
+                             <file|dir|glob>...
+
+Rewrites brick indices in the `matrix` of level files, leaving the rest of each file untouched.
+
+  --from <old> --to <new>  one index change
+  --map old=new,...        several changes in one pass (may be repeated, combines with --from/--to)
+  --dry-run                print the changed lines of each file instead of writing them
+  --backup                 keep the original of each changed file as <file>.bak
+  <file|dir|glob>          level files; directories are searched for .ron files, and `*` / `?`
+                           in the file name are expanded, e.g. 'assets/levels/level_0*.ron'";
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}\n\n{USAGE}");
+    std::process::exit(2);
+}
+
+fn main() {
+    let mut map = Mapping::default();
+    let mut from = None;
+    let mut to = None;
+    let mut dry_run = false;
+    let mut backup = false;
+    let mut paths = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .unwrap_or_else(|| usage_error(&format!("{flag} needs a value")))
+        };
+        let result = match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            "--from" => parse_index(&value("--from")).map(|index| from = Some(index)),
+            "--to" => parse_index(&value("--to")).map(|index| to = Some(index)),
+            "--map" => map.parse_into(&value("--map")),
+            "--dry-run" => {
+                dry_run = true;
+                Ok(())
+            }
+            "--backup" => {
+                backup = true;
+                Ok(())
+            }
+            flag if flag.starts_with("--") => Err(format!("Unknown option {flag}")),
+            _ => {
+                paths.push(arg);
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            usage_error(&e);
+        }
+    }
+    match (from, to) {
+        (Some(from), Some(to)) => map.insert(from, to).unwrap_or_else(|e| usage_error(&e)),
+        (None, None) => {}
+        _ => usage_error("--from and --to go together"),
+    }
+    if map.is_empty() {
+        usage_error("Nothing to migrate: give --from/--to or --map");
+    }
+    if paths.is_empty() {
+        usage_error("No level files given");
+    }
+    let files = level_files(&paths).unwrap_or_else(|e| usage_error(&e));
+
+    let mut summary = Summary::default();
+    for path in &files {
+        let src = match fs::read_to_string(path) {
+            Ok(src) => src,
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", path.display(), e);
+                summary.failed += 1;
+                continue;
+            }
+        };
+        let migration = migrate(&src, &map);
+        summary.add(path, migration.as_ref());
+        let Some(migration) = migration.filter(|m| !m.changes.is_empty()) else {
+            continue;
+        };
+        if dry_run {
+            print!("{}", diff(path, &migration.changes));
+            continue;
+        }
+        if backup {
+            let backup_path = format!("{}.bak", path.display());
+            if let Err(e) = fs::write(&backup_path, &src) {
+                eprintln!(
+                    "Failed to write backup {backup_path}: {e}; {} left as is",
+                    path.display()
+                );
+                summary.failed += 1;
+                continue;
+            }
+        }
+        match fs::write(path, &migration.text) {
+            Ok(()) => println!("Migrated {}", path.display()),
+            Err(e) => {
+                eprintln!("Failed to write {}: {}", path.display(), e);
+                summary.failed += 1;
+            }
+        }
+    }
+    print!("{}", summary.report(&map, dry_run));
+    if summary.failed > 0 {
+        std::process::exit(1);
+    }
+}